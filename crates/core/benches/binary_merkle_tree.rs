@@ -2,7 +2,7 @@
 
 use std::iter::repeat_with;
 
-use binius_core::merkle_tree::{BinaryMerkleTreeProver, MerkleTreeProver};
+use binius_core::merkle_tree::{KAryMerkleTreeProver, MerkleTreeProver};
 use binius_field::{BinaryField128b, Field};
 use binius_hash::{
 	PseudoCompressionFunction, Vision32Compression, Vision32ParallelDigest, VisionHasherDigest,
@@ -17,12 +17,12 @@ const LOG_ELEMS_IN_LEAF: usize = 4;
 
 type F = BinaryField128b;
 
-fn bench_binary_merkle_tree<H, C>(c: &mut Criterion, compression: C, hash_name: &str)
+fn bench_merkle_tree<H, C, const ARITY: usize>(c: &mut Criterion, compression: C, hash_name: &str)
 where
 	H: ParallelDigest<Digest: BlockSizeUser + FixedOutputReset>,
-	C: PseudoCompressionFunction<Output<H::Digest>, 2> + Sync,
+	C: PseudoCompressionFunction<Output<H::Digest>, ARITY> + Sync,
 {
-	let merkle_prover = BinaryMerkleTreeProver::<_, H, C>::new(compression);
+	let merkle_prover = KAryMerkleTreeProver::<_, H, C, ARITY>::new(compression);
 	let mut rng = rand::rng();
 	let data: Vec<F> = repeat_with(|| Field::random(&mut rng))
 		.take(1 << (LOG_ELEMS + LOG_ELEMS_IN_LEAF))
@@ -42,16 +42,14 @@ where
 }
 
 fn bench_groestl_merkle_tree(c: &mut Criterion) {
-	bench_binary_merkle_tree::<Groestl256, _>(c, Groestl256ByteCompression, "Grøstl-256");
+	bench_merkle_tree::<Groestl256, _, 2>(c, Groestl256ByteCompression, "Grøstl-256");
+	bench_merkle_tree::<Groestl256, _, 4>(c, Groestl256ByteCompression, "Grøstl-256 4-ary");
 }
 
 fn bench_vision_merkle_tree(c: &mut Criterion) {
-	bench_binary_merkle_tree::<VisionHasherDigest, _>(c, Vision32Compression, "Vision-32");
-	bench_binary_merkle_tree::<Vision32ParallelDigest, _>(
-		c,
-		Vision32Compression,
-		"Vision-32-Parallel",
-	);
+	bench_merkle_tree::<VisionHasherDigest, _, 2>(c, Vision32Compression, "Vision-32");
+	bench_merkle_tree::<VisionHasherDigest, _, 4>(c, Vision32Compression, "Vision-32 4-ary");
+	bench_merkle_tree::<Vision32ParallelDigest, _, 2>(c, Vision32Compression, "Vision-32-Parallel");
 }
 
 criterion_main!(binary_merkle_tree);
@@ -0,0 +1,153 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Compares the FRI-Binius and Ligero multilinear polynomial commitment schemes.
+//!
+//! The "PCS Comparison" group measures the prover, with the throughput in bytes of committed data.
+//! The "PCS Verification" group measures the verifier, with the throughput in bytes of proof, and
+//! its benchmark IDs include the proof size of each scheme.
+
+use std::iter::repeat_with;
+
+use binius_core::{
+	fiat_shamir::HasherChallenger,
+	merkle_tree::{BinaryMerkleTreeProver, BinaryMerkleTreeScheme},
+	pcs::{self, Params},
+	piop::{
+		FRIPCS, FRIPCSProver, LigeroPCS, LigeroPCSProver, MultilinearPCS, MultilinearPCSProver,
+	},
+};
+use binius_field::{
+	Field, PackedField, TowerField,
+	arch::OptimalUnderlier128b,
+	as_packed_field::{PackScalar, PackedType},
+};
+use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+use binius_math::{B1, B32, B128, MultilinearExtension};
+use criterion::{BatchSize, BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+
+type U = OptimalUnderlier128b;
+type P = PackedType<U, B128>;
+type Challenger_ = HasherChallenger<Groestl256>;
+type MerkleProver = BinaryMerkleTreeProver<B128, Groestl256, Groestl256ByteCompression>;
+type MerkleScheme = BinaryMerkleTreeScheme<B128, Groestl256, Groestl256ByteCompression>;
+
+const LOG_INV_RATE: usize = 1;
+const SECURITY_BITS: usize = 100;
+
+fn random_multilin<FSub: Field>(n_vars: usize) -> MultilinearExtension<PackedType<U, FSub>>
+where
+	U: PackScalar<FSub>,
+{
+	let mut rng = rand::rng();
+	let evals = repeat_with(|| PackedType::<U, FSub>::random(&mut rng))
+		.take(1 << n_vars.saturating_sub(PackedType::<U, FSub>::LOG_WIDTH))
+		.collect();
+	MultilinearExtension::new(n_vars, evals).unwrap()
+}
+
+fn fri_pcs() -> (FRIPCSProver<B128, B32, MerkleProver>, FRIPCS<B128, B32, MerkleScheme>) {
+	(
+		FRIPCSProver::new(
+			MerkleProver::new(Groestl256ByteCompression),
+			LOG_INV_RATE,
+			SECURITY_BITS,
+		),
+		FRIPCS::new(MerkleScheme::new(Groestl256ByteCompression), LOG_INV_RATE, SECURITY_BITS),
+	)
+}
+
+fn ligero_pcs() -> (LigeroPCSProver<B128, B32, MerkleProver>, LigeroPCS<B128, B32, MerkleScheme>) {
+	(
+		LigeroPCSProver::new(
+			MerkleProver::new(Groestl256ByteCompression),
+			LOG_INV_RATE,
+			SECURITY_BITS,
+		),
+		LigeroPCS::new(MerkleScheme::new(Groestl256ByteCompression), LOG_INV_RATE, SECURITY_BITS),
+	)
+}
+
+fn bench_pcs<PCSProver, PCS>(
+	c: &mut Criterion,
+	scheme: &str,
+	make_pcs: impl Fn() -> (PCSProver, PCS),
+	params: &Params,
+	multilin: &MultilinearExtension<PackedType<U, B1>>,
+) where
+	PCSProver: MultilinearPCSProver<P, PCS = PCS>,
+	PCS: MultilinearPCS<B128>,
+{
+	let n_vars = multilin.n_vars();
+	let multilins = std::slice::from_ref(multilin);
+	let point = repeat_with(|| <B128 as Field>::random(rand::rng()))
+		.take(n_vars)
+		.collect::<Vec<_>>();
+
+	let make_pcs_prover = || make_pcs().0;
+	let mut group = c.benchmark_group("PCS Comparison");
+	group.throughput(Throughput::Bytes((1 << n_vars) / 8));
+	group.bench_function(BenchmarkId::new(format!("{scheme}/commit"), n_vars), |b| {
+		b.iter(|| pcs::commit_with_pcs::<P, _, _, _, _>(params, make_pcs_prover(), multilins));
+	});
+	group.bench_function(BenchmarkId::new(format!("{scheme}/open"), n_vars), |b| {
+		b.iter_batched(
+			|| {
+				pcs::commit_with_pcs::<P, _, _, _, _>(params, make_pcs_prover(), multilins)
+					.unwrap()
+					.1
+			},
+			|committed| committed.open::<Challenger_>(&point).unwrap(),
+			BatchSize::LargeInput,
+		);
+	});
+	group.finish();
+
+	let (_, pcs) = make_pcs();
+	let (commitment, committed) =
+		pcs::commit_with_pcs::<P, _, _, _, _>(params, make_pcs_prover(), multilins).unwrap();
+	let (values, proof) = committed.open::<Challenger_>(&point).unwrap();
+	let proof_size = proof.get_proof_size();
+
+	let mut group = c.benchmark_group("PCS Verification");
+	group.throughput(Throughput::Bytes(proof_size as u64));
+	group.bench_function(
+		BenchmarkId::new(
+			format!("{scheme}/verify"),
+			format!("n_vars={n_vars}/proof_size={proof_size}"),
+		),
+		|b| {
+			b.iter_batched(
+				|| proof.clone(),
+				|proof| {
+					pcs::verify_with_pcs::<_, _, Challenger_>(
+						params,
+						&pcs,
+						&commitment,
+						&point,
+						&values,
+						proof,
+					)
+					.unwrap()
+				},
+				BatchSize::SmallInput,
+			);
+		},
+	);
+	group.finish();
+}
+
+fn bench_fri_vs_ligero(c: &mut Criterion) {
+	for n_vars in [16, 20, 24] {
+		let params = pcs::setup(n_vars, B1::TOWER_LEVEL, SECURITY_BITS)
+			.unwrap()
+			.with_log_inv_rate(LOG_INV_RATE)
+			.unwrap();
+		let multilin = random_multilin::<B1>(n_vars);
+
+		bench_pcs(c, "fri", fri_pcs, &params, &multilin);
+		bench_pcs(c, "ligero", ligero_pcs, &params, &multilin);
+	}
+}
+
+criterion_main!(pcs_compare);
+criterion_group!(pcs_compare, bench_fri_vs_ligero);
@@ -62,11 +62,13 @@ use crate::{constraint_system::TableId, oracle::OracleId, witness::MultilinearEx
 pub type ChannelId = usize;
 
 #[derive(Debug, Clone, Copy, SerializeBytes, DeserializeBytes, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OracleOrConst<F: Field> {
 	Oracle(OracleId),
 	Const { base: F, tower_level: usize },
 }
 #[derive(Debug, Clone, SerializeBytes, DeserializeBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Flush<F: TowerField> {
 	pub table_id: TableId,
 	pub log_values_per_row: usize,
@@ -78,6 +80,7 @@ pub struct Flush<F: TowerField> {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, SerializeBytes, DeserializeBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Boundary<F: TowerField> {
 	pub values: Vec<F>,
 	pub channel_id: ChannelId,
@@ -86,6 +89,7 @@ pub struct Boundary<F: TowerField> {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, SerializeBytes, DeserializeBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FlushDirection {
 	Push,
 	Pull,
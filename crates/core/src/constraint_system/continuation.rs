@@ -0,0 +1,379 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Continuations, which prove a long computation as a chain of segment proofs.
+//!
+//! A computation whose witness does not fit in memory at once can be split into segments, each
+//! proven separately against the same constraint system with its own table sizes. Segment `i`
+//! starts from the state segment `i - 1` ends at. A state is a list of [`Boundary`] values: the
+//! verifier pushes the start state of a segment into its channels and pulls the end state back
+//! out. A system opts in by exposing its state through channel flushes, which M3 systems that
+//! take their initial and final state as boundaries already do.
+//!
+//! A chain is proven with [`prove_segments`], which generates the witness of one segment at a
+//! time and proves it with [`prove`](super::prove), using the boundaries returned by
+//! [`Segment::boundaries`] and the digest returned by [`Segment::digest`] in place of the
+//! constraint system digest. The segment digest commits to the constraint system, the position
+//! of the segment and the hashes of its start and end states, so a segment proof is only valid
+//! at the position in the chain it was created for. The chain is checked with
+//! [`verify_segments`].
+//!
+//! The rows of a table are split between segments with [`segment_sizes`], which respects the
+//! [`TableSizeSpec`] of the table.
+
+use binius_compute::{
+	ComputeData, ComputeLayer,
+	alloc::{ComputeAllocator, HostBumpAllocator},
+	cpu::{CpuMemory, alloc::CpuComputeAllocator},
+};
+use binius_field::{
+	PackedField, PackedFieldIndexable, RepackedExtension, TowerField,
+	as_packed_field::PackedType,
+	linear_transformation::PackedTransformationFactory,
+	tower::{PackedTop, ProverTowerFamily, ProverTowerUnderlier, TowerFamily, TowerUnderlier},
+};
+use binius_hal::ComputationBackend;
+use binius_hash::{PseudoCompressionFunction, multi_digest::ParallelDigest};
+use binius_utils::{SerializationMode, SerializeBytes, bail};
+use digest::{Digest, FixedOutputReset, Output, OutputSizeUser, core_api::BlockSizeUser};
+
+use super::{
+	ConstraintSystem, Proof, TableSizeSpec,
+	channel::{Boundary, FlushDirection},
+	common::{FExt, FFastExt},
+	error::{Error, VerificationError},
+	prove, verify,
+};
+use crate::{fiat_shamir::Challenger, witness::MultilinearExtensionIndex};
+
+/// A segment of a computation, along with the states it starts and ends at.
+///
+/// Both states are given in their start form, as boundaries pushing the state into channels.
+#[derive(Debug, Clone)]
+pub struct Segment<F: TowerField> {
+	index: usize,
+	start: Vec<Boundary<F>>,
+	end: Vec<Boundary<F>>,
+}
+
+impl<F: TowerField> Segment<F> {
+	/// Creates the segment at position `index` in a chain.
+	pub fn new(
+		index: usize,
+		start: Vec<Boundary<F>>,
+		end: Vec<Boundary<F>>,
+	) -> Result<Self, Error> {
+		check_state(&start)?;
+		check_state(&end)?;
+		Ok(Self { index, start, end })
+	}
+
+	pub fn index(&self) -> usize {
+		self.index
+	}
+
+	pub fn start(&self) -> &[Boundary<F>] {
+		&self.start
+	}
+
+	pub fn end(&self) -> &[Boundary<F>] {
+		&self.end
+	}
+
+	/// Returns the boundaries to prove and verify the segment with.
+	///
+	/// These are the boundaries shared by all segments, followed by the start state and the end
+	/// state with its direction reversed.
+	pub fn boundaries(&self, shared: &[Boundary<F>]) -> Vec<Boundary<F>> {
+		let end = self.end.iter().map(|boundary| Boundary {
+			direction: FlushDirection::Pull,
+			..boundary.clone()
+		});
+		shared
+			.iter()
+			.chain(&self.start)
+			.cloned()
+			.chain(end)
+			.collect()
+	}
+
+	/// Returns the digest the segment is proven against, in place of the constraint system
+	/// digest.
+	pub fn digest<Hash: Digest>(&self, constraint_system_digest: &Output<Hash>) -> Output<Hash> {
+		Hash::new()
+			.chain_update(constraint_system_digest)
+			.chain_update((self.index as u64).to_le_bytes())
+			.chain_update(state_digest::<Hash, _>(&self.start))
+			.chain_update(state_digest::<Hash, _>(&self.end))
+			.finalize()
+	}
+}
+
+/// Returns the hash of a segment state.
+pub fn state_digest<Hash: Digest, F: TowerField>(state: &[Boundary<F>]) -> Output<Hash> {
+	let mut buf = Vec::new();
+	state
+		.serialize(&mut buf, SerializationMode::CanonicalTower)
+		.expect("boundaries are serializable");
+	Hash::digest(&buf)
+}
+
+/// Splits the `n_rows` rows of a table into the table sizes of consecutive segments.
+///
+/// Every segment holds at most `2^max_log_size` rows, and its size satisfies `spec`: segments of
+/// tables with a power-of-two size hold the remainder in decreasing powers of two, and segments of
+/// tables with a fixed size all hold exactly the fixed size, which must divide `n_rows` and be at
+/// most `2^max_log_size`.
+pub fn segment_sizes(
+	spec: TableSizeSpec,
+	n_rows: usize,
+	max_log_size: usize,
+) -> Result<Vec<usize>, Error> {
+	let max_size = 1 << max_log_size;
+	let n_full = n_rows / max_size;
+	let remainder = n_rows % max_size;
+	let sizes = match spec {
+		TableSizeSpec::Arbitrary => std::iter::repeat_n(max_size, n_full)
+			.chain((remainder != 0).then_some(remainder))
+			.collect(),
+		TableSizeSpec::PowerOfTwo => std::iter::repeat_n(max_size, n_full)
+			.chain(
+				(0..max_log_size)
+					.rev()
+					.map(|log_size| remainder & (1 << log_size))
+					.filter(|&size| size != 0),
+			)
+			.collect(),
+		TableSizeSpec::Fixed { log_size } => {
+			let size = 1 << log_size;
+			if size > max_size {
+				bail!(Error::SegmentSizeExceedsMax {
+					segment_size: size,
+					max_size
+				});
+			}
+			if n_rows % size != 0 {
+				bail!(Error::SegmentRowsNotDivisible {
+					n_rows,
+					segment_size: size
+				});
+			}
+			vec![size; n_rows / size]
+		}
+	};
+	Ok(sizes)
+}
+
+fn check_state<F: TowerField>(state: &[Boundary<F>]) -> Result<(), Error> {
+	if state
+		.iter()
+		.any(|boundary| boundary.direction != FlushDirection::Push)
+	{
+		bail!(Error::SegmentStateDirection);
+	}
+	Ok(())
+}
+
+/// The proof of one segment in a chain.
+#[derive(Debug, Clone)]
+pub struct SegmentProof<F: TowerField> {
+	/// The state the segment ends at, which is the start state of the next segment.
+	pub end: Vec<Boundary<F>>,
+	pub proof: Proof,
+}
+
+/// The witness of one segment, as generated for [`prove_segments`].
+pub struct SegmentWitness<'a, P: PackedField, F: TowerField> {
+	/// The state the segment ends at, which is the start state of the next segment.
+	pub end: Vec<Boundary<F>>,
+	pub table_sizes: Vec<usize>,
+	pub witness: MultilinearExtensionIndex<'a, P>,
+}
+
+/// Proves a computation as a chain of `n_segments` segment proofs.
+///
+/// The first segment starts at `initial_state`. `generate_witness` is called with the index and
+/// the start state of each segment in turn, and returns the witness of the segment allocated from
+/// the given allocator. A witness is proven and dropped before the next one is generated, and
+/// every segment reuses the memory of `witness_allocator`, so only one segment is held in memory
+/// at a time. The other arguments are as in [`prove`].
+#[allow(clippy::too_many_arguments)]
+pub fn prove_segments<
+	Hal,
+	U,
+	Tower,
+	Hash,
+	Compress,
+	Challenger_,
+	Backend,
+	HostAllocatorType,
+	DeviceAllocatorType,
+	GenerateWitness,
+	E,
+>(
+	compute_data: &mut ComputeData<Tower::B128, Hal, HostAllocatorType, DeviceAllocatorType>,
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	log_inv_rate: usize,
+	security_bits: usize,
+	constraint_system_digest: &Output<Hash::Digest>,
+	boundaries: &[Boundary<FExt<Tower>>],
+	initial_state: &[Boundary<FExt<Tower>>],
+	n_segments: usize,
+	witness_allocator: &mut CpuComputeAllocator<PackedType<U, FExt<Tower>>>,
+	mut generate_witness: GenerateWitness,
+	backend: &Backend,
+	min_security_bits: Option<usize>,
+) -> Result<Vec<SegmentProof<FExt<Tower>>>, E>
+where
+	Hal: ComputeLayer<Tower::B128> + Default,
+	U: ProverTowerUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+	Tower::B128:
+		binius_math::TowerTop + binius_math::PackedTop + PackedTop<Tower> + From<FFastExt<Tower>>,
+	Hash: ParallelDigest,
+	Hash::Digest: BlockSizeUser + FixedOutputReset + Send + Sync + Clone,
+	Compress: PseudoCompressionFunction<Output<Hash::Digest>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+	Backend: ComputationBackend,
+	PackedType<U, Tower::B128>: PackedTop<Tower>
+		+ PackedFieldIndexable
+		+ RepackedExtension<PackedType<U, Tower::B1>>
+		+ RepackedExtension<PackedType<U, Tower::B8>>
+		+ RepackedExtension<PackedType<U, Tower::B16>>
+		+ RepackedExtension<PackedType<U, Tower::B32>>
+		+ RepackedExtension<PackedType<U, Tower::B64>>
+		+ RepackedExtension<PackedType<U, Tower::B128>>
+		+ PackedTransformationFactory<PackedType<U, Tower::FastB128>>
+		+ binius_math::PackedTop,
+	PackedType<U, Tower::FastB128>: PackedTransformationFactory<PackedType<U, Tower::B128>>,
+	HostAllocatorType: ComputeAllocator<Tower::B128, CpuMemory>,
+	DeviceAllocatorType: ComputeAllocator<Tower::B128, Hal::DevMem>,
+	GenerateWitness:
+		for<'a> FnMut(
+			usize,
+			&[Boundary<FExt<Tower>>],
+			&'a HostBumpAllocator<'a, PackedType<U, FExt<Tower>>>,
+		) -> Result<SegmentWitness<'a, PackedType<U, FExt<Tower>>, FExt<Tower>>, E>,
+	E: From<Error>,
+{
+	if n_segments == 0 {
+		bail!(Error::EmptySegmentChain);
+	}
+
+	let mut start = initial_state.to_vec();
+	let mut proofs = Vec::with_capacity(n_segments);
+	for index in 0..n_segments {
+		let allocator = witness_allocator.into_bump_allocator();
+		let SegmentWitness {
+			end,
+			table_sizes,
+			witness,
+		} = generate_witness(index, &start, &allocator)?;
+		let segment = Segment::new(index, start, end)?;
+		let proof = prove::<_, U, Tower, Hash, Compress, Challenger_, _, _, _>(
+			compute_data,
+			constraint_system,
+			log_inv_rate,
+			security_bits,
+			&segment.digest::<Hash::Digest>(constraint_system_digest),
+			&segment.boundaries(boundaries),
+			&table_sizes,
+			witness,
+			backend,
+			min_security_bits,
+		)?;
+		start = segment.end;
+		proofs.push(SegmentProof {
+			end: start.clone(),
+			proof,
+		});
+	}
+	Ok(proofs)
+}
+
+/// Verifies a chain of segment proofs.
+///
+/// The first segment starts at `initial_state` and the last segment must end at `final_state`.
+/// `boundaries` are the boundaries shared by all segments. `min_security_bits` is checked for
+/// every segment as in [`verify`].
+#[allow(clippy::too_many_arguments)]
+pub fn verify_segments<U, Tower, Hash, Compress, Challenger_>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	log_inv_rate: usize,
+	security_bits: usize,
+	constraint_system_digest: &Output<Hash>,
+	boundaries: &[Boundary<FExt<Tower>>],
+	initial_state: &[Boundary<FExt<Tower>>],
+	final_state: &[Boundary<FExt<Tower>>],
+	segments: Vec<SegmentProof<FExt<Tower>>>,
+	min_security_bits: Option<usize>,
+) -> Result<(), Error>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	Tower::B128: binius_math::TowerTop + binius_math::PackedTop + PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser + OutputSizeUser,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+{
+	if segments.is_empty() {
+		bail!(Error::EmptySegmentChain);
+	}
+
+	let mut start = initial_state.to_vec();
+	for (index, SegmentProof { end, proof }) in segments.into_iter().enumerate() {
+		let segment = Segment::new(index, start, end)?;
+		verify::<U, Tower, Hash, Compress, Challenger_>(
+			constraint_system,
+			log_inv_rate,
+			security_bits,
+			&segment.digest::<Hash>(constraint_system_digest),
+			&segment.boundaries(boundaries),
+			proof,
+			min_security_bits,
+		)?;
+		start = segment.end;
+	}
+
+	if start != final_state {
+		bail!(VerificationError::SegmentFinalStateMismatch);
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use assert_matches::assert_matches;
+
+	use super::*;
+
+	#[test]
+	fn test_segment_sizes() {
+		assert_eq!(segment_sizes(TableSizeSpec::Arbitrary, 1000, 8).unwrap(), [256, 256, 256, 232]);
+		assert_eq!(
+			segment_sizes(TableSizeSpec::PowerOfTwo, 1000, 8).unwrap(),
+			[256, 256, 256, 128, 64, 32, 8]
+		);
+		assert_eq!(
+			segment_sizes(TableSizeSpec::Fixed { log_size: 6 }, 256, 8).unwrap(),
+			[64, 64, 64, 64]
+		);
+	}
+
+	#[test]
+	fn test_segment_sizes_fixed_size_errors() {
+		assert_matches!(
+			segment_sizes(TableSizeSpec::Fixed { log_size: 6 }, 100, 8),
+			Err(Error::SegmentRowsNotDivisible {
+				n_rows: 100,
+				segment_size: 64
+			})
+		);
+		assert_matches!(
+			segment_sizes(TableSizeSpec::Fixed { log_size: 9 }, 1024, 8),
+			Err(Error::SegmentSizeExceedsMax {
+				segment_size: 512,
+				max_size: 256
+			})
+		);
+	}
+}
@@ -60,6 +60,26 @@ pub enum Error {
 	#[error("flush selector oracle {selector} incompatible with oracle {id}")]
 	IncompatibleFlushSelector { id: OracleId, selector: OracleId },
 
+	#[error("a segment chain must contain at least one segment")]
+	EmptySegmentChain,
+
+	#[error("segment state boundaries must push into channels")]
+	SegmentStateDirection,
+
+	#[error("{n_rows} rows cannot be split into segments of fixed size {segment_size}")]
+	SegmentRowsNotDivisible { n_rows: usize, segment_size: usize },
+
+	#[error("fixed segment size {segment_size} exceeds the maximum segment size {max_size}")]
+	SegmentSizeExceedsMax {
+		segment_size: usize,
+		max_size: usize,
+	},
+
+	#[error(
+		"the proven security level of {achieved:.1} bits is below the required {required} bits"
+	)]
+	InsufficientSecurity { required: usize, achieved: f64 },
+
 	#[error("Non-zero oracles contain zeros")]
 	Zeros,
 
@@ -131,4 +151,6 @@ pub enum VerificationError {
 		"Channel with id={id} is not balanced. Pushes and pulls do not contain the same elements"
 	)]
 	ChannelUnbalanced { id: ChannelId },
+	#[error("the last segment of the chain does not end at the expected final state")]
+	SegmentFinalStateMismatch,
 }
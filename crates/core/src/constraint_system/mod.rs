@@ -2,9 +2,11 @@
 
 pub mod channel;
 mod common;
+pub mod continuation;
 pub mod error;
 pub mod exp;
 mod prove;
+pub mod soundness;
 pub mod validate;
 mod verify;
 
@@ -17,8 +19,8 @@ use binius_utils::{SerializationMode, SerializeBytes};
 use channel::Flush;
 use digest::{Digest, Output};
 use exp::Exp;
-pub use prove::prove;
-pub use verify::verify;
+pub use prove::{prove, prove_with_pcs};
+pub use verify::{verify, verify_with_pcs};
 
 use crate::{
 	constraint_system::error::Error,
@@ -34,7 +36,12 @@ use crate::{
 /// constraints against a witness, as well as enabling generic prove/verify
 #[derive(Debug, Clone, SerializeBytes, DeserializeBytes)]
 #[deserialize_bytes(eval_generics(F = BinaryField128b))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConstraintSystem<F: TowerField> {
+	#[cfg_attr(
+		feature = "serde",
+		serde(bound(deserialize = "SymbolicMultilinearOracleSet<F>: serde::Deserialize<'de>"))
+	)]
 	pub oracles: SymbolicMultilinearOracleSet<F>,
 	pub table_constraints: Vec<ConstraintSet<F>>,
 	pub non_zero_oracle_ids: Vec<OracleId>,
@@ -95,7 +102,19 @@ impl<F: TowerField> ConstraintSystem<F> {
 
 /// Constraint system proof that has been serialized into bytes
 #[derive(Debug, Clone)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(transparent)
+)]
 pub struct Proof {
+	#[cfg_attr(
+		feature = "serde",
+		serde(
+			serialize_with = "binius_utils::serde_encoding::serialize_bytes",
+			deserialize_with = "binius_utils::serde_encoding::deserialize_bytes"
+		)
+	)]
 	pub transcript: Vec<u8>,
 }
 
@@ -112,6 +131,7 @@ pub type TableId = usize;
 /// Tables can have size restrictions, where certain columns, specifically structured columns,
 /// are only allowed for certain size specifications.
 #[derive(Debug, Copy, Clone, SerializeBytes, DeserializeBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TableSizeSpec {
 	/// The table size may be arbitrary.
 	Arbitrary,
@@ -19,10 +19,9 @@ use binius_math::{
 	IsomorphicEvaluationDomainFactory, MLEDirectAdapter, MultilinearExtension, MultilinearPoly,
 };
 use binius_maybe_rayon::prelude::*;
-use binius_ntt::SingleThreadedNTT;
 use binius_utils::{bail, checked_arithmetics::log2_ceil_usize};
 use bytemuck::zeroed_vec;
-use digest::{FixedOutputReset, Output, core_api::BlockSizeUser};
+use digest::{FixedOutputReset, Output, OutputSizeUser, core_api::BlockSizeUser};
 use itertools::chain;
 use tracing::instrument;
 use tracing_profile::utils::emit_max_rss;
@@ -31,6 +30,7 @@ use super::{
 	ConstraintSystem, Proof,
 	channel::Boundary,
 	error::Error,
+	soundness,
 	verify::{make_flush_oracles, max_n_vars_and_skip_rounds},
 };
 use crate::{
@@ -47,13 +47,12 @@ use crate::{
 		Constraint, ConstraintSetBuilder, MultilinearOracleSet, MultilinearPolyVariant, OracleId,
 		SizedConstraintSet,
 	},
-	piop,
+	piop::{self, MultilinearPCSProver},
 	protocols::{
 		evalcheck::{
 			ConstraintSetEqIndPoint, EvalPoint, EvalcheckMultilinearClaim,
 			subclaims::{MemoizedData, prove_mlecheck_with_switchover},
 		},
-		fri::CommitOutput,
 		gkr_exp,
 		gkr_gpa::{self, GrandProductBatchProveOutput, GrandProductWitness},
 		greedy_evalcheck::{self, GreedyEvalcheckProveOutput},
@@ -69,8 +68,10 @@ use crate::{
 };
 
 /// Generates a proof that a witness satisfies a constraint system with the standard FRI PCS.
+///
+/// If `min_security_bits` is given, the prover refuses to run when the proven security level
+/// computed by [`soundness::analyze`] is lower.
 #[allow(clippy::too_many_arguments)]
-#[instrument("constraint_system::prove", skip_all, level = "debug")]
 pub fn prove<
 	Hal,
 	U,
@@ -89,8 +90,9 @@ pub fn prove<
 	constraint_system_digest: &Output<Hash::Digest>,
 	boundaries: &[Boundary<FExt<Tower>>],
 	table_sizes: &[usize],
-	mut witness: MultilinearExtensionIndex<PackedType<U, FExt<Tower>>>,
+	witness: MultilinearExtensionIndex<PackedType<U, FExt<Tower>>>,
 	backend: &Backend,
+	min_security_bits: Option<usize>,
 ) -> Result<Proof, Error>
 where
 	Hal: ComputeLayer<Tower::B128> + Default,
@@ -118,6 +120,78 @@ where
 	PackedType<U, Tower::FastB128>: PackedTransformationFactory<PackedType<U, Tower::B128>>,
 	HostAllocatorType: ComputeAllocator<Tower::B128, CpuMemory>,
 	DeviceAllocatorType: ComputeAllocator<Tower::B128, Hal::DevMem>,
+{
+	let pcs_prover = piop::FRIPCSProver::<_, FEncode<Tower>, _>::new(
+		BinaryMerkleTreeProver::<_, Hash, _>::new(Compress::default()),
+		log_inv_rate,
+		security_bits,
+	);
+	prove_with_pcs::<_, U, Tower, Hash::Digest, _, Challenger_, _, _, _>(
+		compute_data,
+		constraint_system,
+		&pcs_prover,
+		constraint_system_digest,
+		boundaries,
+		table_sizes,
+		witness,
+		backend,
+		min_security_bits,
+	)
+}
+
+/// Generates a proof that a witness satisfies a constraint system, committing to the witness with
+/// the given multilinear polynomial commitment scheme.
+///
+/// If `min_security_bits` is given, the prover refuses to run when the proven security level
+/// computed by [`soundness::analyze`] is lower.
+#[allow(clippy::too_many_arguments)]
+#[instrument("constraint_system::prove", skip_all, level = "debug")]
+pub fn prove_with_pcs<
+	Hal,
+	U,
+	Tower,
+	Hash,
+	PCSProver,
+	Challenger_,
+	Backend,
+	HostAllocatorType,
+	DeviceAllocatorType,
+>(
+	compute_data: &mut ComputeData<Tower::B128, Hal, HostAllocatorType, DeviceAllocatorType>,
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	pcs_prover: &PCSProver,
+	constraint_system_digest: &Output<Hash>,
+	boundaries: &[Boundary<FExt<Tower>>],
+	table_sizes: &[usize],
+	mut witness: MultilinearExtensionIndex<PackedType<U, FExt<Tower>>>,
+	backend: &Backend,
+	min_security_bits: Option<usize>,
+) -> Result<Proof, Error>
+where
+	Hal: ComputeLayer<Tower::B128> + Default,
+	U: ProverTowerUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+	Tower::B128:
+		binius_math::TowerTop + binius_math::PackedTop + PackedTop<Tower> + From<FFastExt<Tower>>,
+	Hash: OutputSizeUser,
+	PCSProver: MultilinearPCSProver<PackedType<U, FExt<Tower>>>,
+	Challenger_: Challenger + Default,
+	Backend: ComputationBackend,
+	// REVIEW: Consider changing TowerFamily and associated traits to shorten/remove these bounds
+	PackedType<U, Tower::B128>: PackedTop<Tower>
+		+ PackedFieldIndexable
+		// REVIEW: remove this bound after piop::commit is adjusted
+		+ RepackedExtension<PackedType<U, Tower::B1>>
+		+ RepackedExtension<PackedType<U, Tower::B8>>
+		+ RepackedExtension<PackedType<U, Tower::B16>>
+		+ RepackedExtension<PackedType<U, Tower::B32>>
+		+ RepackedExtension<PackedType<U, Tower::B64>>
+		+ RepackedExtension<PackedType<U, Tower::B128>>
+		+ PackedTransformationFactory<PackedType<U, Tower::FastB128>>
+		+ binius_math::PackedTop,
+	PackedType<U, Tower::FastB128>: PackedTransformationFactory<PackedType<U, Tower::B128>>,
+	HostAllocatorType: ComputeAllocator<Tower::B128, CpuMemory>,
+	DeviceAllocatorType: ComputeAllocator<Tower::B128, Hal::DevMem>,
 {
 	tracing::debug!(
 		arch = env::consts::ARCH,
@@ -139,6 +213,10 @@ where
 	} = constraint_system.clone();
 
 	constraint_system.check_table_sizes(table_sizes)?;
+	if let Some(min_security_bits) = min_security_bits {
+		soundness::analyze::<Tower, Hash, _>(constraint_system, table_sizes, pcs_prover)?
+			.check(min_security_bits)?;
+	}
 	let mut oracles = oracles.instantiate(table_sizes)?;
 
 	// Prepare the constraint system for proving:
@@ -197,9 +275,6 @@ where
 	drop(witness_span);
 
 	// Commit polynomials
-	let merkle_prover = BinaryMerkleTreeProver::<_, Hash, _>::new(Compress::default());
-	let merkle_scheme = merkle_prover.scheme();
-
 	let (commit_meta, oracle_to_commit_index) = piop::make_oracle_commit_meta(&oracles)?;
 	let committed_multilins = piop::collect_committed_witnesses::<U, _>(
 		&commit_meta,
@@ -208,24 +283,10 @@ where
 		&witness,
 	)?;
 
-	let fri_params = piop::make_commit_params_with_optimal_arity::<_, FEncode<Tower>, _>(
-		&commit_meta,
-		merkle_scheme,
-		security_bits,
-		log_inv_rate,
-	)?;
-	let ntt = SingleThreadedNTT::with_subspace(fri_params.rs_code().subspace())?
-		.precompute_twiddles()
-		.multithreaded();
-
 	let commit_span =
 		tracing::info_span!("[phase] Commit", phase = "commit", perfetto_category = "phase.main")
 			.entered();
-	let CommitOutput {
-		commitment,
-		committed,
-		codeword,
-	} = piop::commit(&fri_params, &ntt, &merkle_prover, &committed_multilins)?;
+	let (commitment, committed) = pcs_prover.commit(&commit_meta, &committed_multilins)?;
 	emit_max_rss();
 	drop(commit_span);
 
@@ -573,14 +634,10 @@ where
 	)
 	.entered();
 
-	piop::prove(
+	pcs_prover.prove(
 		compute_data,
-		&fri_params,
-		&ntt,
-		&merkle_prover,
 		&commit_meta,
 		committed,
-		&codeword,
 		&committed_multilins,
 		transparent_multilins,
 		&piop_sumcheck_claims,
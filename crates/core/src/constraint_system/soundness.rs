@@ -0,0 +1,361 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Concrete soundness analysis of constraint system proofs.
+//!
+//! The security parameter passed to [`super::prove`] and [`super::verify`] only determines the
+//! number of PCS test queries. The other subprotocols each contribute a soundness error that
+//! depends on the field size, the constraint degrees and the table sizes. [`analyze`] bounds
+//! these errors for a constraint system instantiated with concrete table sizes, and the overall
+//! error is their sum by the union bound.
+//!
+//! The bounds follow the Schwartz–Zippel style analyses of the interactive protocols and are
+//! conservative where several claims are batched into one protocol instance.
+
+use std::fmt;
+
+use binius_field::{BinaryField, TowerField, tower::TowerFamily};
+use binius_math::CompositionPoly;
+use binius_utils::checked_arithmetics::log2_ceil_usize;
+use digest::OutputSizeUser;
+use itertools::Itertools;
+
+use super::{
+	ConstraintSystem,
+	channel::OracleOrConst,
+	common::{FDomain, FExt},
+	error::Error,
+	verify::max_n_vars_and_skip_rounds,
+};
+use crate::{
+	oracle::{MultilinearOracleSet, MultilinearPolyVariant, SizedConstraintSet},
+	piop::{self, PCSSoundness, SoundnessError},
+	protocols::sumcheck::constraint_set_zerocheck_claim,
+};
+
+/// A subprotocol of the constraint system proof that contributes to the soundness error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Subprotocol {
+	/// The GKR-based exponentiation argument.
+	Exponentiation,
+	/// The GKR grand product arguments for non-zero checks and channel flushes.
+	GrandProduct,
+	/// The multiset equality of pushes and pulls on every channel.
+	ChannelBalancing,
+	/// The zerocheck on the table constraints, including the univariate skip.
+	Zerocheck,
+	/// The sumchecks that reduce evaluation claims on virtual oracles.
+	Evalcheck,
+	/// The ring-switching reduction from small-field to large-field evaluation claims.
+	RingSwitch,
+	/// The opening of the committed polynomials.
+	PCS,
+	/// Collisions of the transcript and commitment hash function.
+	HashCollision,
+}
+
+impl fmt::Display for Subprotocol {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let name = match self {
+			Self::Exponentiation => "exponentiation",
+			Self::GrandProduct => "grand product",
+			Self::ChannelBalancing => "channel balancing",
+			Self::Zerocheck => "zerocheck",
+			Self::Evalcheck => "evalcheck",
+			Self::RingSwitch => "ring switch",
+			Self::PCS => "PCS",
+			Self::HashCollision => "hash collision",
+		};
+		f.write_str(name)
+	}
+}
+
+/// The soundness errors of all subprotocols of a constraint system proof.
+#[derive(Debug, Clone)]
+pub struct SoundnessReport {
+	errors: Vec<(Subprotocol, SoundnessError)>,
+}
+
+impl SoundnessReport {
+	/// The soundness error of each subprotocol.
+	pub fn errors(&self) -> &[(Subprotocol, SoundnessError)] {
+		&self.errors
+	}
+
+	/// The soundness error of a single subprotocol.
+	pub fn error(&self, subprotocol: Subprotocol) -> SoundnessError {
+		self.errors
+			.iter()
+			.find(|(s, _)| *s == subprotocol)
+			.map(|(_, error)| *error)
+			.unwrap_or_default()
+	}
+
+	/// The soundness error of the whole protocol, by the union bound over the subprotocols.
+	pub fn total(&self) -> SoundnessError {
+		self.errors.iter().map(|(_, error)| *error).sum()
+	}
+
+	pub fn proven_security_bits(&self) -> f64 {
+		self.total().proven_bits()
+	}
+
+	pub fn conjectured_security_bits(&self) -> f64 {
+		self.total().conjectured_bits()
+	}
+
+	/// Returns an error if the proven security level is below `min_security_bits`.
+	pub fn check(&self, min_security_bits: usize) -> Result<(), Error> {
+		let achieved = self.proven_security_bits();
+		if achieved < min_security_bits as f64 {
+			return Err(Error::InsufficientSecurity {
+				required: min_security_bits,
+				achieved,
+			});
+		}
+		Ok(())
+	}
+}
+
+impl fmt::Display for SoundnessReport {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for (subprotocol, error) in &self.errors {
+			writeln!(
+				f,
+				"{subprotocol:>18}: {:6.1} bits proven, {:6.1} bits conjectured",
+				error.proven_bits(),
+				error.conjectured_bits()
+			)?;
+		}
+		write!(
+			f,
+			"{:>18}: {:6.1} bits proven, {:6.1} bits conjectured",
+			"total",
+			self.proven_security_bits(),
+			self.conjectured_security_bits()
+		)
+	}
+}
+
+/// Bounds the soundness error of proving a constraint system with the given table sizes.
+///
+/// The PCS determines the opening error from its rate and security parameters, and `Hash` is the
+/// hash function of the Fiat–Shamir transcript.
+pub fn analyze<Tower, Hash, PCS>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	table_sizes: &[usize],
+	pcs: &PCS,
+) -> Result<SoundnessReport, Error>
+where
+	Tower: TowerFamily,
+	Hash: OutputSizeUser,
+	PCS: PCSSoundness,
+{
+	constraint_system.check_table_sizes(table_sizes)?;
+	let oracles = constraint_system.oracles.instantiate(table_sizes)?;
+
+	let field_size = 2.0_f64.powi(FExt::<Tower>::N_BITS as i32);
+	let exact = |numerator: usize| SoundnessError::exact(numerator as f64 / field_size);
+
+	let (commit_meta, _) = piop::make_oracle_commit_meta(&oracles)?;
+
+	let hash_bits = Hash::output_size() * 8;
+	let errors = vec![
+		(
+			Subprotocol::Exponentiation,
+			exact(exponentiation_numerator(constraint_system, &oracles)),
+		),
+		(
+			Subprotocol::GrandProduct,
+			exact(grand_product_numerator(constraint_system, &oracles, table_sizes)?),
+		),
+		(
+			Subprotocol::ChannelBalancing,
+			exact(channel_balancing_numerator(constraint_system, &oracles, table_sizes)?),
+		),
+		(
+			Subprotocol::Zerocheck,
+			exact(zerocheck_numerator::<Tower>(constraint_system, table_sizes)?),
+		),
+		(Subprotocol::Evalcheck, exact(evalcheck_numerator(&oracles))),
+		(Subprotocol::RingSwitch, exact(ring_switch_numerator(&oracles))),
+		(Subprotocol::PCS, pcs.soundness_error(&commit_meta)?),
+		(
+			Subprotocol::HashCollision,
+			SoundnessError::exact(2.0_f64.powi(-((hash_bits / 2) as i32))),
+		),
+	];
+	Ok(SoundnessReport { errors })
+}
+
+/// Each exponentiation bit layer is an eq-indicator sumcheck over the result oracle's variables.
+fn exponentiation_numerator<F: TowerField>(
+	constraint_system: &ConstraintSystem<F>,
+	oracles: &MultilinearOracleSet<F>,
+) -> usize {
+	constraint_system
+		.exponents
+		.iter()
+		.filter(|exp| !oracles.is_zero_sized(exp.exp_result_id))
+		.map(|exp| {
+			// The round polynomials have the composition degree plus one for the eq indicator.
+			let degree = match exp.base {
+				OracleOrConst::Const { .. } => 3,
+				OracleOrConst::Oracle(_) => 5,
+			};
+			exp.bits_ids.len() * (degree * exp.n_vars(oracles) + 1)
+		})
+		.sum()
+}
+
+/// Layer `k` of the batched grand product verification runs a degree-3 sumcheck over `k`
+/// variables, batches the claims of that layer and reduces the two evaluations at the end.
+fn grand_product_numerator<F: TowerField>(
+	constraint_system: &ConstraintSystem<F>,
+	oracles: &MultilinearOracleSet<F>,
+	table_sizes: &[usize],
+) -> Result<usize, Error> {
+	let non_zero_n_vars = constraint_system
+		.non_zero_oracle_ids
+		.iter()
+		.filter(|&&id| !oracles.is_zero_sized(id))
+		.map(|&id| oracles.n_vars(id));
+	let flush_n_vars = flush_n_vars(constraint_system, oracles, table_sizes)?
+		.into_iter()
+		.map(|(_, n_vars)| n_vars);
+	let n_vars = non_zero_n_vars.chain(flush_n_vars).collect::<Vec<_>>();
+
+	let max_n_vars = n_vars.iter().copied().max().unwrap_or(0);
+	Ok((0..max_n_vars)
+		.map(|layer| {
+			let n_active = n_vars.iter().filter(|&&n_vars| n_vars > layer).count();
+			3 * layer + n_active + 1
+		})
+		.sum())
+}
+
+/// The channel products are polynomials in the mixing and permutation challenges with degree at
+/// most the total flushed width.
+fn channel_balancing_numerator<F: TowerField>(
+	constraint_system: &ConstraintSystem<F>,
+	oracles: &MultilinearOracleSet<F>,
+	table_sizes: &[usize],
+) -> Result<usize, Error> {
+	let flush_n_vars = flush_n_vars(constraint_system, oracles, table_sizes)?;
+	Ok(flush_n_vars
+		.into_iter()
+		.into_group_map_by(|&(flush_index, _)| constraint_system.flushes[flush_index].channel_id)
+		.into_values()
+		.map(|flushes| {
+			let width = flushes
+				.iter()
+				.map(|&(flush_index, _)| constraint_system.flushes[flush_index].oracles.len())
+				.max()
+				.unwrap_or(0);
+			let n_values = flushes
+				.iter()
+				.map(|&(flush_index, n_vars)| {
+					(constraint_system.flushes[flush_index].multiplicity as usize) << n_vars
+				})
+				.sum::<usize>();
+			n_values * width
+		})
+		.sum())
+}
+
+/// Returns the index and number of variables of every flush of a non-empty table.
+fn flush_n_vars<F: TowerField>(
+	constraint_system: &ConstraintSystem<F>,
+	oracles: &MultilinearOracleSet<F>,
+	table_sizes: &[usize],
+) -> Result<Vec<(usize, usize)>, Error> {
+	constraint_system
+		.flushes
+		.iter()
+		.enumerate()
+		.filter(|(_, flush)| table_sizes[flush.table_id] > 0)
+		.map(|(flush_index, flush)| {
+			let oracle_id = flush
+				.oracles
+				.iter()
+				.find_map(|oracle| match oracle {
+					OracleOrConst::Oracle(id) => Some(*id),
+					OracleOrConst::Const { .. } => None,
+				})
+				.ok_or(Error::EmptyFlushOracles)?;
+			Ok((flush_index, oracles.n_vars(oracle_id)))
+		})
+		.collect()
+}
+
+/// The zerocheck batches all constraints, reduces them with the eq indicator, runs the univariate
+/// skip round over the first `skip_rounds` variables and sumchecks the remaining variables.
+fn zerocheck_numerator<Tower: TowerFamily>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	table_sizes: &[usize],
+) -> Result<usize, Error> {
+	let zerocheck_claims = constraint_system
+		.table_constraints
+		.iter()
+		.filter(|constraint_set| table_sizes[constraint_set.table_id] > 0)
+		.map(|constraint_set| {
+			let n_vars = constraint_set.log_values_per_row
+				+ log2_ceil_usize(table_sizes[constraint_set.table_id]);
+			let (claim, _) = constraint_set_zerocheck_claim(SizedConstraintSet::new(
+				n_vars,
+				constraint_set.clone(),
+			))?;
+			Ok(claim)
+		})
+		.collect::<Result<Vec<_>, Error>>()?;
+
+	let (max_n_vars, skip_rounds) =
+		max_n_vars_and_skip_rounds(&zerocheck_claims, <FDomain<Tower>>::N_BITS);
+	let degree = zerocheck_claims
+		.iter()
+		.map(|claim| claim.max_individual_degree())
+		.max()
+		.unwrap_or(0);
+	let n_compositions = zerocheck_claims
+		.iter()
+		.map(|claim| claim.composite_zeros().len())
+		.sum::<usize>();
+
+	let n_multilinear_rounds = max_n_vars - skip_rounds;
+	let eq_err = n_multilinear_rounds;
+	let batching_err = n_compositions;
+	let univariate_err = degree << skip_rounds;
+	let sumcheck_err = n_multilinear_rounds * (degree + 1);
+	let univariatizing_err = 2 * skip_rounds;
+	Ok(eq_err + batching_err + univariate_err + sumcheck_err + univariatizing_err)
+}
+
+/// Shifted and packed oracles are reduced with a bivariate sumcheck and composite oracles with an
+/// MLE-check, each at most once per evaluation point.
+fn evalcheck_numerator<F: TowerField>(oracles: &MultilinearOracleSet<F>) -> usize {
+	oracles
+		.polys()
+		.filter(|oracle| !oracles.is_zero_sized(oracle.id))
+		.map(|oracle| match &oracle.variant {
+			MultilinearPolyVariant::Shifted(_) | MultilinearPolyVariant::Packed(_) => {
+				2 * oracle.n_vars + 1
+			}
+			MultilinearPolyVariant::Composite(composite) => {
+				let degree = CompositionPoly::<F>::degree(composite.c());
+				(degree + 1) * oracle.n_vars + 1
+			}
+			_ => 0,
+		})
+		.sum()
+}
+
+/// Ring switching batches the tensor algebra rows of each committed oracle's evaluation claim.
+fn ring_switch_numerator<F: TowerField>(oracles: &MultilinearOracleSet<F>) -> usize {
+	oracles
+		.polys()
+		.filter(|oracle| {
+			matches!(oracle.variant, MultilinearPolyVariant::Committed)
+				&& !oracles.is_zero_sized(oracle.id)
+		})
+		.map(|oracle| F::TOWER_LEVEL.saturating_sub(oracle.tower_level) + 1)
+		.sum()
+}
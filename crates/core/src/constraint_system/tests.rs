@@ -8,7 +8,10 @@ use binius_math::{B1, B128, MLEDirectAdapter, MLEEmbeddingAdapter, MultilinearEx
 
 use crate::{
 	constraint_system::{
+		TableSizeSpec,
 		channel::{Flush, FlushDirection, OracleOrConst},
+		continuation::segment_sizes,
+		error::Error,
 		prove::make_masked_flush_witnesses,
 	},
 	oracle::MultilinearOracleSet,
@@ -60,3 +63,85 @@ fn test_make_masked_flush_witnesses_handles_small_n_vars() {
 	)
 	.unwrap();
 }
+
+#[test]
+fn test_segment_sizes() {
+	assert_eq!(segment_sizes(TableSizeSpec::Arbitrary, 1300, 9).unwrap(), vec![512, 512, 276]);
+	assert_eq!(
+		segment_sizes(TableSizeSpec::PowerOfTwo, 1300, 9).unwrap(),
+		vec![512, 512, 256, 16, 4]
+	);
+	assert_eq!(segment_sizes(TableSizeSpec::PowerOfTwo, 1024, 9).unwrap(), vec![512, 512]);
+	assert_eq!(segment_sizes(TableSizeSpec::Fixed { log_size: 8 }, 1024, 9).unwrap(), vec![256; 4]);
+	assert!(matches!(
+		segment_sizes(TableSizeSpec::Fixed { log_size: 8 }, 1300, 9),
+		Err(Error::SegmentRowsNotDivisible { .. })
+	));
+	assert!(
+		segment_sizes(TableSizeSpec::Arbitrary, 0, 9)
+			.unwrap()
+			.is_empty()
+	);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_roundtrip() {
+	use super::{ConstraintSystem, Proof, TableSizeSpec, channel::Boundary};
+	use crate::oracle::SymbolicMultilinearOracleSet;
+
+	type F = B128;
+
+	let boundary = Boundary {
+		values: vec![F::ONE, F::new(0x1234)],
+		channel_id: 1,
+		direction: FlushDirection::Pull,
+		multiplicity: 3,
+	};
+	let json = serde_json::to_string(&boundary).unwrap();
+	assert_eq!(
+		json,
+		r#"{"values":["00000000000000000000000000000001","00000000000000000000000000001234"],"channel_id":1,"direction":"Pull","multiplicity":3}"#
+	);
+	assert_eq!(serde_json::from_str::<Boundary<F>>(&json).unwrap(), boundary);
+
+	let mut cbor = Vec::new();
+	ciborium::into_writer(&boundary, &mut cbor).unwrap();
+	assert_eq!(ciborium::from_reader::<Boundary<F>, _>(cbor.as_slice()).unwrap(), boundary);
+
+	let constraint_system = ConstraintSystem::<F> {
+		oracles: SymbolicMultilinearOracleSet::new(),
+		table_constraints: vec![],
+		non_zero_oracle_ids: vec![],
+		flushes: vec![Flush {
+			table_id: 0,
+			log_values_per_row: 0,
+			oracles: vec![OracleOrConst::Const {
+				base: F::ONE,
+				tower_level: 0,
+			}],
+			channel_id: 1,
+			direction: FlushDirection::Push,
+			selectors: vec![],
+			multiplicity: 3,
+		}],
+		exponents: vec![],
+		channel_count: 2,
+		table_size_specs: vec![TableSizeSpec::Fixed { log_size: 4 }],
+	};
+	let json = serde_json::to_string(&constraint_system).unwrap();
+	assert!(json.contains(
+		r#""oracles":[{"Const":{"base":"00000000000000000000000000000001","tower_level":0}}]"#
+	));
+	assert!(json.contains(r#""table_size_specs":[{"Fixed":{"log_size":4}}]"#));
+	let deserialized = serde_json::from_str::<ConstraintSystem<F>>(&json).unwrap();
+	assert_eq!(serde_json::to_string(&deserialized).unwrap(), json);
+	assert_eq!(deserialized.table_size_specs.len(), 1);
+
+	let proof = Proof {
+		transcript: vec![0xab, 0x01],
+	};
+	let json = serde_json::to_string(&proof).unwrap();
+	assert_eq!(json, r#""ab01""#);
+	assert_eq!(serde_json::from_str::<Proof>(&json).unwrap().transcript, proof.transcript);
+}
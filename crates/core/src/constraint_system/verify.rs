@@ -18,6 +18,7 @@ use super::{
 	channel::{Boundary, OracleOrConst},
 	error::{Error, VerificationError},
 	exp::{self, reorder_exponents},
+	soundness,
 };
 use crate::{
 	constraint_system::{
@@ -31,7 +32,7 @@ use crate::{
 		ConstraintSetBuilder, MultilinearOracleSet, MultilinearPolyVariant, OracleId,
 		SizedConstraintSet,
 	},
-	piop,
+	piop::{self, MultilinearPCS},
 	protocols::{
 		evalcheck::{EvalPoint, EvalcheckMultilinearClaim},
 		gkr_exp,
@@ -50,7 +51,9 @@ use crate::{
 };
 
 /// Verifies a proof against a constraint system.
-#[instrument("constraint_system::verify", skip_all, level = "debug")]
+///
+/// If `min_security_bits` is given, the verifier rejects the proof when the proven security level
+/// computed by [`soundness::analyze`] for the claimed table sizes is lower.
 #[allow(clippy::too_many_arguments)]
 pub fn verify<U, Tower, Hash, Compress, Challenger_>(
 	constraint_system: &ConstraintSystem<FExt<Tower>>,
@@ -59,6 +62,7 @@ pub fn verify<U, Tower, Hash, Compress, Challenger_>(
 	constraint_system_digest: &Output<Hash>,
 	boundaries: &[Boundary<FExt<Tower>>],
 	proof: Proof,
+	min_security_bits: Option<usize>,
 ) -> Result<(), Error>
 where
 	U: TowerUnderlier<Tower>,
@@ -67,6 +71,43 @@ where
 	Hash: Digest + BlockSizeUser + OutputSizeUser,
 	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
 	Challenger_: Challenger + Default,
+{
+	let pcs = piop::FRIPCS::<_, FEncode<Tower>, _>::new(
+		BinaryMerkleTreeScheme::<_, Hash, _>::new(Compress::default()),
+		log_inv_rate,
+		security_bits,
+	);
+	verify_with_pcs::<U, Tower, Hash, _, Challenger_>(
+		constraint_system,
+		&pcs,
+		constraint_system_digest,
+		boundaries,
+		proof,
+		min_security_bits,
+	)
+}
+
+/// Verifies a proof against a constraint system, where the witness is committed with the given
+/// multilinear polynomial commitment scheme.
+///
+/// If `min_security_bits` is given, the verifier rejects the proof when the proven security level
+/// computed by [`soundness::analyze`] for the claimed table sizes is lower.
+#[instrument("constraint_system::verify", skip_all, level = "debug")]
+pub fn verify_with_pcs<U, Tower, Hash, PCS, Challenger_>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	pcs: &PCS,
+	constraint_system_digest: &Output<Hash>,
+	boundaries: &[Boundary<FExt<Tower>>],
+	proof: Proof,
+	min_security_bits: Option<usize>,
+) -> Result<(), Error>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	Tower::B128: binius_math::TowerTop + binius_math::PackedTop + PackedTop<Tower>,
+	Hash: OutputSizeUser,
+	PCS: MultilinearPCS<FExt<Tower>>,
+	Challenger_: Challenger + Default,
 {
 	let ConstraintSystem {
 		oracles,
@@ -91,6 +132,10 @@ where
 	let table_sizes: Vec<usize> = reader.read_vec(table_count)?;
 
 	constraint_system.check_table_sizes(&table_sizes)?;
+	if let Some(min_security_bits) = min_security_bits {
+		soundness::analyze::<Tower, Hash, _>(constraint_system, &table_sizes, pcs)?
+			.check(min_security_bits)?;
+	}
 	let mut oracles = oracles.instantiate(&table_sizes)?;
 
 	// Prepare the constraint system for proving:
@@ -121,18 +166,11 @@ where
 	// GKR exp multiplication
 	reorder_exponents(&mut exponents, &oracles);
 
-	let merkle_scheme = BinaryMerkleTreeScheme::<_, Hash, _>::new(Compress::default());
 	let (commit_meta, oracle_to_commit_index) = piop::make_oracle_commit_meta(&oracles)?;
-	let fri_params = piop::make_commit_params_with_optimal_arity::<_, FEncode<Tower>, _>(
-		&commit_meta,
-		&merkle_scheme,
-		security_bits,
-		log_inv_rate,
-	)?;
 
 	// Read polynomial commitment polynomials
 	let mut reader = transcript.message();
-	let commitment = reader.read::<Output<Hash>>()?;
+	let commitment = reader.read::<PCS::Commitment>()?;
 
 	let exp_challenge = transcript.sample_vec(exp::max_n_vars(&exponents, &oracles));
 
@@ -253,15 +291,7 @@ where
 	} = ring_switch::verify(&system, &mut transcript)?;
 
 	// Prove evaluation claims using PIOP compiler
-	piop::verify(
-		&commit_meta,
-		&merkle_scheme,
-		&fri_params,
-		&commitment,
-		&transparents,
-		&piop_sumcheck_claims,
-		&mut transcript,
-	)?;
+	pcs.verify(&commit_meta, &commitment, &transparents, &piop_sumcheck_claims, &mut transcript)?;
 
 	transcript.finalize()?;
 
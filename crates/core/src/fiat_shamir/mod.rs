@@ -2,10 +2,12 @@
 
 mod hasher_challenger;
 mod sampling;
+mod vision_challenger;
 
 use bytes::{Buf, BufMut};
 pub use hasher_challenger::HasherChallenger;
 pub use sampling::*;
+pub use vision_challenger::VisionChallenger;
 
 /// A Fiat-Shamir challenger that can observe prover messages and sample verifier randomness.
 pub trait Challenger {
@@ -0,0 +1,386 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{cmp::min, mem};
+
+use binius_field::{
+	AESTowerField32b, BinaryField32b, ExtensionField, PackedAESBinaryField8x32b, PackedField,
+};
+use binius_hash::{Vision32bPermutation, permutation::Permutation};
+use bytes::{Buf, BufMut, buf::UninitSlice};
+
+use super::{CanSample, Challenger};
+
+/// The number of state elements overwritten by an absorbed block.
+const RATE: usize = 16;
+const RATE_BYTES: usize = RATE * mem::size_of::<u32>();
+
+/// The domain separation tags, which are added to the first capacity element before every
+/// permutation.
+///
+/// The last block of the domain separator and of every observed message is tagged with
+/// [`TAG_INIT`] and [`TAG_FINAL`] respectively, plus the number of message bytes in it, which
+/// makes the zero padding of the last block unambiguous.
+const TAG_ABSORB: u32 = 1;
+const TAG_SQUEEZE: u32 = 2;
+const TAG_INIT: u32 = 3;
+const TAG_FINAL: u32 = TAG_INIT + RATE_BYTES as u32;
+
+/// Permutation state, in the AES tower basis used by [`Vision32bPermutation`].
+type State = [PackedAESBinaryField8x32b; 3];
+
+/// Challenger type which implements [`Buf`] by squeezing the sponge.
+#[derive(Debug, Clone)]
+pub struct VisionSampler {
+	index: usize,
+	buffer: [u8; RATE_BYTES],
+	state: State,
+}
+
+/// Challenger type which implements [`BufMut`] by absorbing into the sponge.
+#[derive(Debug, Clone)]
+pub struct VisionObserver {
+	index: usize,
+	buffer: [u8; RATE_BYTES],
+	state: State,
+}
+
+/// Challenger over a duplex sponge with the Vision-32b permutation.
+///
+/// The sponge works in overwrite mode over 24 elements of [`BinaryField32b`], of which 16 form
+/// the rate. Observed bytes are read as little-endian 32-bit words, which are the coordinates of
+/// the observed tower field elements over [`BinaryField32b`] in the canonical serialization, and
+/// sampled bytes are produced the same way from the state. So in an arithmetized verifier every
+/// observed or sampled element of a tower field extending [`BinaryField32b`] maps directly onto
+/// state elements without any bit decomposition. [`Self::observe`] and the [`CanSample`]
+/// implementation give the same results as the byte interface for such elements.
+///
+/// Every permutation is domain separated by a tag in the capacity, which distinguishes the
+/// initialization, full absorbed blocks, the last absorbed block with its length and squeezing.
+#[derive(Debug, Clone)]
+pub enum VisionChallenger {
+	Observer(VisionObserver),
+	Sampler(VisionSampler),
+}
+
+impl VisionChallenger {
+	/// Creates a challenger whose state is bound to a protocol-specific domain separator.
+	pub fn new(domain: &[u8]) -> Self {
+		let mut observer = VisionObserver::default();
+		observer.put_slice(domain);
+		observer.finalize(TAG_INIT);
+		Self::Sampler(observer.into_sampler())
+	}
+
+	/// Observes field elements through their coordinates over [`BinaryField32b`].
+	pub fn observe<F: ExtensionField<BinaryField32b>>(&mut self, values: &[F]) {
+		let observer = self.observer();
+		for value in values {
+			for base in value.iter_bases() {
+				observer.put_u32_le(base.val());
+			}
+		}
+	}
+}
+
+impl Default for VisionChallenger {
+	fn default() -> Self {
+		Self::new(&[])
+	}
+}
+
+impl Challenger for VisionChallenger {
+	/// This returns the inner challenger which implements `[BufMut]`
+	fn observer(&mut self) -> &mut impl BufMut {
+		match self {
+			Self::Observer(observer) => observer,
+			Self::Sampler(sampler) => {
+				*self = Self::Observer(mem::take(sampler).into_observer());
+				match self {
+					Self::Observer(observer) => observer,
+					_ => unreachable!(),
+				}
+			}
+		}
+	}
+
+	/// This returns the inner challenger which implements [`Buf`].
+	fn sampler(&mut self) -> &mut impl Buf {
+		match self {
+			Self::Sampler(sampler) => sampler,
+			Self::Observer(observer) => {
+				*self = Self::Sampler(mem::take(observer).into_sampler());
+				match self {
+					Self::Sampler(sampler) => sampler,
+					_ => unreachable!(),
+				}
+			}
+		}
+	}
+}
+
+impl<F: ExtensionField<BinaryField32b>> CanSample<F> for VisionChallenger {
+	/// Samples an element from its coordinates over [`BinaryField32b`].
+	fn sample(&mut self) -> F {
+		let sampler = self.sampler();
+		F::from_bases((0..F::DEGREE).map(|_| BinaryField32b::new(sampler.get_u32_le())))
+			.expect("the number of bases is the degree of the extension")
+	}
+}
+
+impl Default for VisionSampler {
+	fn default() -> Self {
+		Self {
+			index: 0,
+			buffer: [0; RATE_BYTES],
+			state: State::default(),
+		}
+	}
+}
+
+impl Default for VisionObserver {
+	fn default() -> Self {
+		Self {
+			index: 0,
+			buffer: [0; RATE_BYTES],
+			state: State::default(),
+		}
+	}
+}
+
+impl VisionObserver {
+	/// Overwrites the rate with the buffer and permutes the state.
+	fn absorb(&mut self, tag: u32) {
+		let mut words = self
+			.buffer
+			.chunks_exact(4)
+			.map(|word| u32::from_le_bytes(word.try_into().expect("chunk is 4 bytes")));
+		for packed in &mut self.state[..RATE / PackedAESBinaryField8x32b::WIDTH] {
+			*packed = PackedAESBinaryField8x32b::from_scalars(
+				words
+					.by_ref()
+					.take(PackedAESBinaryField8x32b::WIDTH)
+					.map(|word| AESTowerField32b::from(BinaryField32b::new(word))),
+			);
+		}
+		permute(&mut self.state, tag);
+		self.index = 0;
+	}
+
+	/// Absorbs the buffered bytes as the last block of a message, padded with zeros.
+	fn finalize(&mut self, tag: u32) {
+		let len = self.index;
+		self.buffer[len..].fill(0);
+		self.absorb(tag + len as u32);
+	}
+
+	fn into_sampler(mut self) -> VisionSampler {
+		self.finalize(TAG_FINAL);
+		let mut sampler = VisionSampler {
+			index: 0,
+			buffer: [0; RATE_BYTES],
+			state: self.state,
+		};
+		sampler.fill_buffer();
+		sampler
+	}
+}
+
+impl VisionSampler {
+	/// Reads the rate of the state into the buffer.
+	fn fill_buffer(&mut self) {
+		let words = self.state[..RATE / PackedAESBinaryField8x32b::WIDTH]
+			.iter()
+			.flat_map(|packed| packed.iter())
+			.map(|scalar| BinaryField32b::from(scalar).val());
+		for (chunk, word) in self.buffer.chunks_exact_mut(4).zip(words) {
+			chunk.copy_from_slice(&word.to_le_bytes());
+		}
+		self.index = 0;
+	}
+
+	fn squeeze(&mut self) {
+		permute(&mut self.state, TAG_SQUEEZE);
+		self.fill_buffer();
+	}
+
+	fn into_observer(self) -> VisionObserver {
+		let mut observer = VisionObserver {
+			index: 0,
+			buffer: [0; RATE_BYTES],
+			state: self.state,
+		};
+		observer.put_u64_le(self.index as u64);
+		observer
+	}
+}
+
+fn permute(state: &mut State, tag: u32) {
+	let capacity = &mut state[RATE / PackedAESBinaryField8x32b::WIDTH];
+	capacity.set(0, capacity.get(0) + AESTowerField32b::from(BinaryField32b::new(tag)));
+	Vision32bPermutation::default().permute_mut(state);
+}
+
+impl Buf for VisionSampler {
+	fn remaining(&self) -> usize {
+		usize::MAX
+	}
+
+	fn chunk(&self) -> &[u8] {
+		&self.buffer[self.index..]
+	}
+
+	fn advance(&mut self, mut cnt: usize) {
+		// Must handle the case when `cnt` is 0
+		if self.index == RATE_BYTES {
+			self.squeeze();
+		}
+
+		while cnt > 0 {
+			let remaining = min(RATE_BYTES - self.index, cnt);
+			if remaining == 0 {
+				self.squeeze();
+				continue;
+			}
+			cnt -= remaining;
+			self.index += remaining;
+		}
+	}
+}
+
+unsafe impl BufMut for VisionObserver {
+	fn remaining_mut(&self) -> usize {
+		usize::MAX
+	}
+
+	unsafe fn advance_mut(&mut self, mut cnt: usize) {
+		while cnt > 0 {
+			let remaining = min(RATE_BYTES - self.index, cnt);
+			cnt -= remaining;
+			self.index += remaining;
+			if self.index == RATE_BYTES {
+				self.absorb(TAG_ABSORB);
+			}
+		}
+	}
+
+	fn chunk_mut(&mut self) -> &mut UninitSlice {
+		let buffer = &mut self.buffer[self.index..];
+		buffer.into()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField64b, BinaryField128b, Field};
+	use binius_utils::{DeserializeBytes, SerializationMode, SerializeBytes};
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use super::*;
+
+	fn sample_bytes(challenger: &mut VisionChallenger, len: usize) -> Vec<u8> {
+		let mut out = vec![0; len];
+		challenger.sampler().copy_to_slice(&mut out);
+		out
+	}
+
+	#[test]
+	fn test_native_and_byte_interfaces_agree() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let values = (0..7)
+			.map(|_| <BinaryField128b as Field>::random(&mut rng))
+			.collect::<Vec<_>>();
+
+		let mut native = VisionChallenger::default();
+		let mut bytes = VisionChallenger::default();
+
+		native.observe(&values);
+		for value in &values {
+			value
+				.serialize(bytes.observer(), SerializationMode::CanonicalTower)
+				.unwrap();
+		}
+
+		for _ in 0..10 {
+			let sampled: BinaryField128b = native.sample();
+			let deserialized =
+				BinaryField128b::deserialize(bytes.sampler(), SerializationMode::CanonicalTower)
+					.unwrap();
+			assert_eq!(sampled, deserialized);
+		}
+
+		native.observe(&[BinaryField64b::ONE]);
+		BinaryField64b::ONE
+			.serialize(bytes.observer(), SerializationMode::CanonicalTower)
+			.unwrap();
+		assert_eq!(
+			CanSample::<BinaryField32b>::sample(&mut native),
+			CanSample::<BinaryField32b>::sample(&mut bytes)
+		);
+	}
+
+	#[test]
+	fn test_chunking_does_not_matter() {
+		let observable = (0..=255).collect::<Vec<u8>>();
+
+		let mut whole = VisionChallenger::default();
+		whole.observer().put_slice(&observable);
+
+		let mut split = VisionChallenger::default();
+		for chunk in observable.chunks(7) {
+			split.observer().put_slice(chunk);
+		}
+
+		let whole_out = sample_bytes(&mut whole, 200);
+		let mut split_out = Vec::new();
+		for len in [1, 63, 64, 72] {
+			split_out.extend(sample_bytes(&mut split, len));
+		}
+		assert_eq!(whole_out, split_out);
+	}
+
+	#[test]
+	fn test_domain_separation() {
+		let mut outputs = Vec::new();
+		let mut record = |mut challenger: VisionChallenger| {
+			outputs.push(sample_bytes(&mut challenger, 32));
+		};
+
+		record(VisionChallenger::default());
+		record(VisionChallenger::new(b"protocol"));
+		record(VisionChallenger::new(b"protocol\0"));
+
+		// Messages that differ only in trailing zeros or block boundaries.
+		for message in [
+			&[][..],
+			&[0],
+			&[0, 0, 0, 0],
+			&[0; RATE_BYTES],
+			&[0; RATE_BYTES + 1],
+		] {
+			let mut challenger = VisionChallenger::default();
+			challenger.observer().put_slice(message);
+			record(challenger);
+		}
+
+		// Sampling and observing are not interchangeable.
+		let mut challenger = VisionChallenger::default();
+		sample_bytes(&mut challenger, RATE_BYTES);
+		challenger.observer();
+		record(challenger);
+
+		let mut challenger = VisionChallenger::default();
+		sample_bytes(&mut challenger, RATE_BYTES + 1);
+		record(challenger);
+
+		let mut challenger = VisionChallenger::default();
+		sample_bytes(&mut challenger, 1);
+		challenger.observer();
+		record(challenger);
+
+		for (i, a) in outputs.iter().enumerate() {
+			for b in &outputs[i + 1..] {
+				assert_ne!(a, b);
+			}
+		}
+	}
+}
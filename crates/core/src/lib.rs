@@ -17,6 +17,7 @@ pub mod constraint_system;
 pub mod fiat_shamir;
 pub mod merkle_tree;
 pub mod oracle;
+pub mod pcs;
 pub mod piop;
 pub mod polynomial;
 pub mod protocols;
@@ -11,28 +11,69 @@ use tracing::instrument;
 
 use super::errors::Error;
 
-/// A binary Merkle tree that commits batches of vectors.
+/// A Merkle tree with `ARITY`-to-1 compressions that commits batches of vectors.
 ///
 /// The vector entries at each index in a batch are hashed together into leaf digests. Then a
 /// Merkle tree is constructed over the leaf digests. The implementation requires that the vector
-/// lengths are all equal to each other and a power of two.
+/// lengths are all equal to each other and a power of two, and that `ARITY` is a power of two.
+///
+/// Layers are identified by their depth, which is the base-2 logarithm of their length, so that
+/// the leaves are at depth `log_len` and the root is at depth 0. Each compression step reduces
+/// the depth by `log2(ARITY)`. If `log2(ARITY)` does not divide `log_len`, the last step compresses
+/// fewer than `ARITY` digests into the root, padding the compression input with default digests.
 #[derive(Debug, Clone)]
-pub struct BinaryMerkleTree<D> {
+pub struct KAryMerkleTree<D, const ARITY: usize> {
 	/// Base-2 logarithm of the number of leaves
 	pub log_len: usize,
 	/// The inner nodes, arranged as a flattened array of layers with the root at the end
 	pub inner_nodes: Vec<D>,
 }
 
-pub fn build<F, H, C>(
+/// A Merkle tree with 2-to-1 compressions.
+pub type BinaryMerkleTree<D> = KAryMerkleTree<D, 2>;
+
+/// Returns the base-2 logarithm of a Merkle tree arity, checking that it is a power of two.
+pub(super) const fn log_arity<const ARITY: usize>() -> usize {
+	assert!(ARITY >= 2 && ARITY.is_power_of_two(), "Merkle tree arity must be a power of two");
+	ARITY.trailing_zeros() as usize
+}
+
+/// Returns the depths of the layers of a tree over `2^log_len` leaves, from the leaves to the
+/// root.
+pub(super) fn layer_depths(log_len: usize, log_arity: usize) -> impl Iterator<Item = usize> {
+	let partial_step = log_len % log_arity != 0;
+	(log_len % log_arity..=log_len)
+		.rev()
+		.step_by(log_arity)
+		.chain(partial_step.then_some(0))
+}
+
+/// Returns the number of sibling digests in a branch from the leaves up to the given layer.
+///
+/// Returns `None` if the tree has no layer at `layer_depth`.
+pub(super) fn branch_len(log_len: usize, layer_depth: usize, log_arity: usize) -> Option<usize> {
+	let mut depths = layer_depths(log_len, log_arity).peekable();
+	let mut len = 0;
+	while let Some(depth) = depths.next() {
+		if depth == layer_depth {
+			return Some(len);
+		}
+		if let Some(&next_depth) = depths.peek() {
+			len += (1 << (depth - next_depth)) - 1;
+		}
+	}
+	None
+}
+
+pub fn build<F, H, C, const ARITY: usize>(
 	compression: &C,
 	elements: &[F],
 	batch_size: usize,
-) -> Result<BinaryMerkleTree<Output<H::Digest>>, Error>
+) -> Result<KAryMerkleTree<Output<H::Digest>, ARITY>, Error>
 where
 	F: TowerField,
 	H: ParallelDigest<Digest: BlockSizeUser + FixedOutputReset>,
-	C: PseudoCompressionFunction<Output<H::Digest>, 2> + Sync,
+	C: PseudoCompressionFunction<Output<H::Digest>, ARITY> + Sync,
 {
 	if elements.len() % batch_size != 0 {
 		bail!(Error::IncorrectBatchSize);
@@ -53,17 +94,20 @@ where
 	)
 }
 
-fn internal_build<Digest, C>(
+fn internal_build<Digest, C, const ARITY: usize>(
 	compression: &C,
 	// Must either successfully initialize the passed in slice or return error
 	hash_leaves: impl FnOnce(&mut [MaybeUninit<Digest>]) -> Result<(), Error>,
 	log_len: usize,
-) -> Result<BinaryMerkleTree<Digest>, Error>
+) -> Result<KAryMerkleTree<Digest, ARITY>, Error>
 where
-	Digest: Clone + Send + Sync,
-	C: PseudoCompressionFunction<Digest, 2> + Sync,
+	Digest: Clone + Default + Send + Sync,
+	C: PseudoCompressionFunction<Digest, ARITY> + Sync,
 {
-	let total_length = (1 << (log_len + 1)) - 1;
+	let log_arity = log_arity::<ARITY>();
+	let total_length = layer_depths(log_len, log_arity)
+		.map(|depth| 1 << depth)
+		.sum();
 	let mut inner_nodes = Vec::with_capacity(total_length);
 
 	hash_leaves(&mut inner_nodes.spare_capacity_mut()[..(1 << log_len)])?;
@@ -74,8 +118,8 @@ where
 		// SAFETY: prev-layer was initialized by hash_leaves
 		slice_assume_init_mut(prev_layer)
 	};
-	for i in 1..(log_len + 1) {
-		let (next_layer, next_remaining) = remaining.split_at_mut(1 << (log_len - i));
+	for depth in layer_depths(log_len, log_arity).skip(1) {
+		let (next_layer, next_remaining) = remaining.split_at_mut(1 << depth);
 		remaining = next_remaining;
 
 		compress_layer(compression, prev_layer, next_layer);
@@ -92,22 +136,22 @@ where
 		// that doesn't play well with using split_at_mut on spare capacity.
 		inner_nodes.set_len(total_length);
 	}
-	Ok(BinaryMerkleTree {
+	Ok(KAryMerkleTree {
 		log_len,
 		inner_nodes,
 	})
 }
 
 #[instrument("BinaryMerkleTree::build", skip_all, level = "debug")]
-pub fn build_from_iterator<F, H, C, ParIter>(
+pub fn build_from_iterator<F, H, C, ParIter, const ARITY: usize>(
 	compression: &C,
 	iterated_chunks: ParIter,
 	log_len: usize,
-) -> Result<BinaryMerkleTree<Output<H::Digest>>, Error>
+) -> Result<KAryMerkleTree<Output<H::Digest>, ARITY>, Error>
 where
 	F: TowerField,
 	H: ParallelDigest<Digest: BlockSizeUser + FixedOutputReset>,
-	C: PseudoCompressionFunction<Output<H::Digest>, 2> + Sync,
+	C: PseudoCompressionFunction<Output<H::Digest>, ARITY> + Sync,
 	ParIter: IndexedParallelIterator<Item: IntoIterator<Item = F>>,
 {
 	internal_build(
@@ -117,7 +161,7 @@ where
 	)
 }
 
-impl<D: Clone> BinaryMerkleTree<D> {
+impl<D: Clone, const ARITY: usize> KAryMerkleTree<D, ARITY> {
 	pub fn root(&self) -> D {
 		self.inner_nodes
 			.last()
@@ -126,16 +170,21 @@ impl<D: Clone> BinaryMerkleTree<D> {
 	}
 
 	pub fn layer(&self, layer_depth: usize) -> Result<&[D], Error> {
-		if layer_depth > self.log_len {
-			bail!(Error::IncorrectLayerDepth);
+		let mut range_start = 0;
+		for depth in layer_depths(self.log_len, log_arity::<ARITY>()) {
+			if depth == layer_depth {
+				return Ok(&self.inner_nodes[range_start..range_start + (1 << layer_depth)]);
+			}
+			range_start += 1 << depth;
 		}
-		let range_start = self.inner_nodes.len() + 1 - (1 << (layer_depth + 1));
-
-		Ok(&self.inner_nodes[range_start..range_start + (1 << layer_depth)])
+		bail!(Error::IncorrectLayerDepth);
 	}
 
 	/// Get a Merkle branch for the given index
 	///
+	/// The branch lists, for every compression from the leaves up to the layer, the sibling
+	/// digests of the node on the path in order of their position.
+	///
 	/// Throws if the index is out of range
 	pub fn branch(&self, index: usize, layer_depth: usize) -> Result<Vec<D>, Error> {
 		if index >= 1 << self.log_len || layer_depth > self.log_len {
@@ -144,31 +193,61 @@ impl<D: Clone> BinaryMerkleTree<D> {
 			});
 		}
 
-		let branch = (0..self.log_len - layer_depth)
-			.map(|j| {
-				let node_index = (((1 << j) - 1) << (self.log_len + 1 - j)) | (index >> j) ^ 1;
-				self.inner_nodes[node_index].clone()
-			})
-			.collect();
+		let mut branch = Vec::new();
+		let mut index = index;
+		let mut layer_start = 0;
+		let mut depths = layer_depths(self.log_len, log_arity::<ARITY>()).peekable();
+		while let Some(depth) = depths.next() {
+			if depth == layer_depth {
+				return Ok(branch);
+			}
+			let Some(&next_depth) = depths.peek() else {
+				break;
+			};
 
-		Ok(branch)
+			let log_group_size = depth - next_depth;
+			let group_start = layer_start + ((index >> log_group_size) << log_group_size);
+			branch.extend(
+				(0..1 << log_group_size)
+					.filter(|&i| i != index % (1 << log_group_size))
+					.map(|i| self.inner_nodes[group_start + i].clone()),
+			);
+
+			index >>= log_group_size;
+			layer_start += 1 << depth;
+		}
+		bail!(Error::IncorrectLayerDepth);
 	}
 }
 
 #[tracing::instrument("MerkleTree::compress_layer", skip_all, level = "debug")]
-fn compress_layer<D, C>(compression: &C, prev_layer: &[D], next_layer: &mut [MaybeUninit<D>])
-where
-	D: Clone + Send + Sync,
-	C: PseudoCompressionFunction<D, 2> + Sync,
+fn compress_layer<D, C, const ARITY: usize>(
+	compression: &C,
+	prev_layer: &[D],
+	next_layer: &mut [MaybeUninit<D>],
+) where
+	D: Clone + Default + Send + Sync,
+	C: PseudoCompressionFunction<D, ARITY> + Sync,
 {
+	let group_size = prev_layer.len() / next_layer.len();
 	prev_layer
-		.par_chunks_exact(2)
+		.par_chunks_exact(group_size)
 		.zip(next_layer.par_iter_mut())
-		.for_each(|(prev_pair, next_digest)| {
-			next_digest.write(compression.compress(array::from_fn(|i| prev_pair[i].clone())));
+		.for_each(|(group, next_digest)| {
+			next_digest.write(compress_group(compression, group));
 		})
 }
 
+/// Compresses a group of at most `ARITY` digests, padding it with default digests.
+pub(super) fn compress_group<D, C, const ARITY: usize>(compression: &C, group: &[D]) -> D
+where
+	D: Clone + Default,
+	C: PseudoCompressionFunction<D, ARITY>,
+{
+	debug_assert!(group.len() <= ARITY);
+	compression.compress(array::from_fn(|i| group.get(i).cloned().unwrap_or_default()))
+}
+
 /// Hashes the elements in chunks of a vector into digests.
 ///
 /// Given a vector of elements and an output buffer of N hash digests, this splits the elements
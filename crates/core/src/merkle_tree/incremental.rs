@@ -0,0 +1,295 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::sync::Arc;
+
+use binius_hash::PseudoCompressionFunction;
+use binius_utils::bail;
+
+use super::{
+	errors::{Error, VerificationError},
+	merkle_tree_vcs::Commitment,
+};
+
+/// A binary Merkle tree over leaf digests that supports updates, appends and snapshots.
+///
+/// The tree has a capacity of `2^depth` leaves, of which the first `len` are occupied. Unoccupied
+/// leaves have the default digest, and the digests of empty subtrees are precomputed, so the
+/// tree only stores the paths to occupied leaves. When all leaves are occupied, the root is the
+/// same as the root of a [`super::BinaryMerkleTree`] over the same leaf digests, and branches use
+/// the same format.
+///
+/// Nodes are shared between versions of the tree. Updating a leaf rehashes and copies only the
+/// `depth` nodes on the path to the leaf, so [`Self::snapshot`] is cheap and old snapshots stay
+/// valid and can produce branches against their own roots.
+#[derive(Debug, Clone)]
+pub struct IncrementalMerkleTree<D, C> {
+	compression: C,
+	/// The digests of the empty subtrees, indexed by height.
+	empty_digests: Arc<Vec<D>>,
+	root: Node<D>,
+	depth: usize,
+	len: usize,
+}
+
+#[derive(Debug, Clone)]
+enum Node<D> {
+	Empty,
+	Leaf(D),
+	Inner(Arc<InnerNode<D>>),
+}
+
+#[derive(Debug)]
+struct InnerNode<D> {
+	digest: D,
+	children: [Node<D>; 2],
+}
+
+/// A proof that a leaf of a committed tree was replaced.
+///
+/// The siblings on the path to the leaf are not changed by the update, so a single branch
+/// authenticates both the old leaf against the old root and the new leaf against the new root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpdateProof<D> {
+	/// The index of the updated leaf.
+	pub index: usize,
+	/// The leaf digest before the update.
+	pub old_leaf: D,
+	/// The leaf digest after the update.
+	pub new_leaf: D,
+	/// The sibling digests on the path from the leaf to the root.
+	pub branch: Vec<D>,
+}
+
+impl<D, C> IncrementalMerkleTree<D, C>
+where
+	D: Clone + Default + Eq,
+	C: PseudoCompressionFunction<D, 2>,
+{
+	/// Creates an empty tree with a capacity of `2^depth` leaves.
+	pub fn new(compression: C, depth: usize) -> Self {
+		let mut empty_digests = Vec::with_capacity(depth + 1);
+		empty_digests.push(D::default());
+		let mut tree = Self {
+			compression,
+			empty_digests: Arc::new(empty_digests),
+			root: Node::Empty,
+			depth: 0,
+			len: 0,
+		};
+		while tree.depth < depth {
+			tree.grow();
+		}
+		tree
+	}
+
+	/// Base-2 logarithm of the capacity of the tree.
+	pub const fn depth(&self) -> usize {
+		self.depth
+	}
+
+	/// The number of occupied leaves.
+	pub const fn len(&self) -> usize {
+		self.len
+	}
+
+	pub const fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	pub fn root(&self) -> D {
+		self.digest(&self.root, self.depth)
+	}
+
+	pub fn commitment(&self) -> Commitment<D> {
+		Commitment {
+			root: self.root(),
+			depth: self.depth,
+		}
+	}
+
+	/// Returns a copy of the current version of the tree, which is unaffected by later updates.
+	pub fn snapshot(&self) -> Self
+	where
+		C: Clone,
+	{
+		self.clone()
+	}
+
+	/// Returns the digest of an occupied leaf.
+	pub fn leaf(&self, index: usize) -> Result<D, Error> {
+		self.check_index(index)?;
+
+		let mut node = &self.root;
+		for height in (0..self.depth).rev() {
+			match node {
+				Node::Inner(inner) => node = &inner.children[(index >> height) & 1],
+				_ => break,
+			}
+		}
+		Ok(match node {
+			Node::Leaf(digest) => digest.clone(),
+			_ => self.empty_digests[0].clone(),
+		})
+	}
+
+	/// Doubles the capacity of the tree.
+	///
+	/// The current tree becomes the left subtree of the new root, so the indices of the occupied
+	/// leaves do not change.
+	pub fn grow(&mut self) {
+		if self.empty_digests.len() == self.depth + 1 {
+			let empty = &self.empty_digests[self.depth];
+			let next = self.compression.compress([empty.clone(), empty.clone()]);
+			Arc::make_mut(&mut self.empty_digests).push(next);
+		}
+
+		if !matches!(self.root, Node::Empty) {
+			let children = [std::mem::replace(&mut self.root, Node::Empty), Node::Empty];
+			self.root = self.inner_node(children, self.depth + 1);
+		}
+		self.depth += 1;
+	}
+
+	/// Appends a leaf, growing the tree if it is full, and returns its index.
+	pub fn push(&mut self, leaf: D) -> usize {
+		if self.len == 1 << self.depth {
+			self.grow();
+		}
+
+		let index = self.len;
+		self.root = self.set(&self.root, self.depth, index, leaf);
+		self.len += 1;
+		index
+	}
+
+	/// Replaces an occupied leaf and returns a proof of the update.
+	pub fn update(&mut self, index: usize, leaf: D) -> Result<UpdateProof<D>, Error> {
+		let branch = self.branch(index)?;
+		let old_leaf = self.leaf(index)?;
+		self.root = self.set(&self.root, self.depth, index, leaf.clone());
+		Ok(UpdateProof {
+			index,
+			old_leaf,
+			new_leaf: leaf,
+			branch,
+		})
+	}
+
+	/// Returns the sibling digests on the path from an occupied leaf to the root.
+	pub fn branch(&self, index: usize) -> Result<Vec<D>, Error> {
+		self.check_index(index)?;
+
+		let mut branch = Vec::with_capacity(self.depth);
+		let mut node = &self.root;
+		for height in (0..self.depth).rev() {
+			let bit = (index >> height) & 1;
+			match node {
+				Node::Inner(inner) => {
+					branch.push(self.digest(&inner.children[bit ^ 1], height));
+					node = &inner.children[bit];
+				}
+				_ => {
+					branch.push(self.empty_digests[height].clone());
+					node = &Node::Empty;
+				}
+			}
+		}
+		branch.reverse();
+		Ok(branch)
+	}
+
+	fn check_index(&self, index: usize) -> Result<(), Error> {
+		if index >= self.len {
+			bail!(Error::IndexOutOfRange {
+				max: self.len.saturating_sub(1),
+			});
+		}
+		Ok(())
+	}
+
+	fn digest(&self, node: &Node<D>, height: usize) -> D {
+		match node {
+			Node::Empty => self.empty_digests[height].clone(),
+			Node::Leaf(digest) => digest.clone(),
+			Node::Inner(inner) => inner.digest.clone(),
+		}
+	}
+
+	fn inner_node(&self, children: [Node<D>; 2], height: usize) -> Node<D> {
+		let digest = self.compression.compress([
+			self.digest(&children[0], height - 1),
+			self.digest(&children[1], height - 1),
+		]);
+		Node::Inner(Arc::new(InnerNode { digest, children }))
+	}
+
+	/// Returns a copy of the subtree with the given leaf replaced, sharing the untouched nodes.
+	fn set(&self, node: &Node<D>, height: usize, index: usize, leaf: D) -> Node<D> {
+		if height == 0 {
+			return Node::Leaf(leaf);
+		}
+
+		let mut children = match node {
+			Node::Inner(inner) => inner.children.clone(),
+			_ => [Node::Empty, Node::Empty],
+		};
+		let bit = (index >> (height - 1)) & 1;
+		children[bit] = self.set(&children[bit], height - 1, index, leaf);
+		self.inner_node(children, height)
+	}
+}
+
+impl<D: Clone + Eq> UpdateProof<D> {
+	/// Verifies that the update transforms the tree committed by `old` into the one committed by
+	/// `new`.
+	pub fn verify<C: PseudoCompressionFunction<D, 2>>(
+		&self,
+		compression: &C,
+		old: &Commitment<D>,
+		new: &Commitment<D>,
+	) -> Result<(), Error> {
+		if old.depth != new.depth || self.branch.len() != old.depth {
+			bail!(VerificationError::IncorrectProofShape);
+		}
+
+		verify_branch(compression, old, self.index, self.old_leaf.clone(), &self.branch)?;
+		verify_branch(compression, new, self.index, self.new_leaf.clone(), &self.branch)
+	}
+}
+
+/// Verifies a branch, as returned by [`IncrementalMerkleTree::branch`], of a leaf digest.
+pub fn verify_branch<D, C>(
+	compression: &C,
+	commitment: &Commitment<D>,
+	index: usize,
+	leaf: D,
+	branch: &[D],
+) -> Result<(), Error>
+where
+	D: Clone + Eq,
+	C: PseudoCompressionFunction<D, 2>,
+{
+	if branch.len() != commitment.depth {
+		bail!(VerificationError::IncorrectProofShape);
+	}
+	if index.checked_shr(commitment.depth as u32).unwrap_or(0) != 0 {
+		bail!(Error::IndexOutOfRange {
+			max: (1 << commitment.depth) - 1,
+		});
+	}
+
+	let root = branch
+		.iter()
+		.enumerate()
+		.fold(leaf, |digest, (height, sibling)| {
+			compression.compress(if (index >> height) & 1 == 0 {
+				[digest, sibling.clone()]
+			} else {
+				[sibling.clone(), digest]
+			})
+		});
+	if root != commitment.root {
+		bail!(VerificationError::InvalidProof);
+	}
+	Ok(())
+}
@@ -2,14 +2,22 @@
 
 mod binary_merkle_tree;
 mod errors;
+mod incremental;
 #[allow(clippy::module_inception)]
 mod merkle_tree_vcs;
 mod prover;
 mod scheme;
+mod sparse;
 #[cfg(test)]
 mod tests;
 
 pub use binary_merkle_tree::*;
+pub use errors::{Error, VerificationError};
+pub use incremental::{IncrementalMerkleTree, UpdateProof, verify_branch};
 pub use merkle_tree_vcs::*;
-pub use prover::BinaryMerkleTreeProver;
-pub use scheme::BinaryMerkleTreeScheme;
+pub use prover::{BinaryMerkleTreeProver, KAryMerkleTreeProver};
+pub use scheme::{BinaryMerkleTreeScheme, KAryMerkleTreeScheme};
+pub use sparse::{
+	SPARSE_MERKLE_TREE_DEPTH, SparseMerkleKey, SparseMerkleProof, SparseMerkleTree,
+	SparseMerkleTreeScheme,
+};
@@ -8,35 +8,38 @@ use digest::{FixedOutputReset, Output, core_api::BlockSizeUser};
 use getset::Getters;
 
 use super::{
-	binary_merkle_tree::{self, BinaryMerkleTree},
+	binary_merkle_tree::{self, KAryMerkleTree},
 	errors::Error,
 	merkle_tree_vcs::{Commitment, MerkleTreeProver},
-	scheme::BinaryMerkleTreeScheme,
+	scheme::KAryMerkleTreeScheme,
 };
 use crate::transcript::TranscriptWriter;
 
 #[derive(Debug, Getters)]
-pub struct BinaryMerkleTreeProver<T, H: ParallelDigest, C> {
+pub struct KAryMerkleTreeProver<T, H: ParallelDigest, C, const ARITY: usize> {
 	#[getset(get = "pub")]
-	scheme: BinaryMerkleTreeScheme<T, H::Digest, C>,
+	scheme: KAryMerkleTreeScheme<T, H::Digest, C, ARITY>,
 }
 
-impl<T, C, H: ParallelDigest> BinaryMerkleTreeProver<T, H, C> {
+/// A Merkle tree prover with 2-to-1 compressions.
+pub type BinaryMerkleTreeProver<T, H, C> = KAryMerkleTreeProver<T, H, C, 2>;
+
+impl<T, C, H: ParallelDigest, const ARITY: usize> KAryMerkleTreeProver<T, H, C, ARITY> {
 	pub fn new(compression: C) -> Self {
 		Self {
-			scheme: BinaryMerkleTreeScheme::new(compression),
+			scheme: KAryMerkleTreeScheme::new(compression),
 		}
 	}
 }
 
-impl<F, H, C> MerkleTreeProver<F> for BinaryMerkleTreeProver<F, H, C>
+impl<F, H, C, const ARITY: usize> MerkleTreeProver<F> for KAryMerkleTreeProver<F, H, C, ARITY>
 where
 	F: TowerField,
 	H: ParallelDigest<Digest: BlockSizeUser + FixedOutputReset>,
-	C: PseudoCompressionFunction<Output<H::Digest>, 2> + Sync,
+	C: PseudoCompressionFunction<Output<H::Digest>, ARITY> + Sync,
 {
-	type Scheme = BinaryMerkleTreeScheme<F, H::Digest, C>;
-	type Committed = BinaryMerkleTree<Output<H::Digest>>;
+	type Scheme = KAryMerkleTreeScheme<F, H::Digest, C, ARITY>;
+	type Committed = KAryMerkleTree<Output<H::Digest>, ARITY>;
 
 	fn scheme(&self) -> &Self::Scheme {
 		&self.scheme
@@ -47,8 +50,11 @@ where
 		data: &[F],
 		batch_size: usize,
 	) -> Result<(Commitment<Output<H::Digest>>, Self::Committed), Error> {
-		let tree =
-			binary_merkle_tree::build::<_, H, _>(self.scheme.compression(), data, batch_size)?;
+		let tree = binary_merkle_tree::build::<_, H, _, ARITY>(
+			self.scheme.compression(),
+			data,
+			batch_size,
+		)?;
 
 		let commitment = Commitment {
 			root: tree.root(),
@@ -90,7 +96,7 @@ where
 	where
 		ParIter: IndexedParallelIterator<Item: IntoIterator<Item = F>>,
 	{
-		let tree = binary_merkle_tree::build_from_iterator::<F, H, C, _>(
+		let tree = binary_merkle_tree::build_from_iterator::<F, H, C, _, ARITY>(
 			self.scheme.compression(),
 			iterated_chunks,
 			log_len,
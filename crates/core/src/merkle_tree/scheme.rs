@@ -1,33 +1,35 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{array, fmt::Debug, marker::PhantomData};
+use std::{fmt::Debug, marker::PhantomData};
 
 use binius_field::TowerField;
 use binius_hash::{PseudoCompressionFunction, hash_serialize};
-use binius_utils::{
-	bail,
-	checked_arithmetics::{log2_ceil_usize, log2_strict_usize},
-};
+use binius_utils::{bail, checked_arithmetics::log2_strict_usize};
 use bytes::Buf;
 use digest::{Digest, Output, core_api::BlockSizeUser};
 use getset::Getters;
+use itertools::Itertools;
 
 use super::{
+	binary_merkle_tree::{branch_len, compress_group, layer_depths, log_arity},
 	errors::{Error, VerificationError},
 	merkle_tree_vcs::MerkleTreeScheme,
 };
 use crate::transcript::TranscriptReader;
 
 #[derive(Debug, Getters)]
-pub struct BinaryMerkleTreeScheme<T, H, C> {
+pub struct KAryMerkleTreeScheme<T, H, C, const ARITY: usize> {
 	#[getset(get = "pub")]
 	compression: C,
-	// This makes it so that `BinaryMerkleTreeScheme` remains Send + Sync
+	// This makes it so that `KAryMerkleTreeScheme` remains Send + Sync
 	// See https://doc.rust-lang.org/nomicon/phantom-data.html#table-of-phantomdata-patterns
 	_phantom: PhantomData<fn() -> (T, H)>,
 }
 
-impl<T, H, C> BinaryMerkleTreeScheme<T, H, C> {
+/// A Merkle tree scheme with 2-to-1 compressions.
+pub type BinaryMerkleTreeScheme<T, H, C> = KAryMerkleTreeScheme<T, H, C, 2>;
+
+impl<T, H, C, const ARITY: usize> KAryMerkleTreeScheme<T, H, C, ARITY> {
 	pub fn new(compression: C) -> Self {
 		Self {
 			compression,
@@ -36,17 +38,27 @@ impl<T, H, C> BinaryMerkleTreeScheme<T, H, C> {
 	}
 }
 
-impl<F, H, C> MerkleTreeScheme<F> for BinaryMerkleTreeScheme<F, H, C>
+impl<F, H, C, const ARITY: usize> MerkleTreeScheme<F> for KAryMerkleTreeScheme<F, H, C, ARITY>
 where
 	F: TowerField,
 	H: Digest + BlockSizeUser,
-	C: PseudoCompressionFunction<Output<H>, 2> + Sync,
+	C: PseudoCompressionFunction<Output<H>, ARITY> + Sync,
 {
 	type Digest = Output<H>;
 
 	/// This layer allows minimizing the proof size.
 	fn optimal_verify_layer(&self, n_queries: usize, tree_depth: usize) -> usize {
-		log2_ceil_usize(n_queries).min(tree_depth)
+		let log_arity = log_arity::<ARITY>();
+		let mut depths = layer_depths(tree_depth, log_arity).collect::<Vec<_>>();
+		depths.reverse();
+		depths
+			.into_iter()
+			.min_by_key(|&depth| {
+				let branch_len =
+					branch_len(tree_depth, depth, log_arity).expect("depth is a layer of the tree");
+				(1 << depth) + n_queries * branch_len
+			})
+			.expect("a tree has at least one layer")
 	}
 
 	fn proof_size(&self, len: usize, n_queries: usize, layer_depth: usize) -> Result<usize, Error> {
@@ -55,13 +67,11 @@ where
 		}
 
 		let log_len = log2_strict_usize(len);
-
-		if layer_depth > log_len {
+		let Some(branch_len) = branch_len(log_len, layer_depth, log_arity::<ARITY>()) else {
 			bail!(Error::IncorrectLayerDepth)
-		}
+		};
 
-		Ok(((log_len - layer_depth - 1) * n_queries + (1 << layer_depth))
-			* <H as Digest>::output_size())
+		Ok((branch_len * n_queries + (1 << layer_depth)) * <H as Digest>::output_size())
 	}
 
 	fn verify_vector(
@@ -128,15 +138,27 @@ where
 			});
 		}
 
+		let log_arity = log_arity::<ARITY>();
+		let Some(branch_len) = branch_len(tree_depth, layer_depth, log_arity) else {
+			bail!(Error::IncorrectLayerDepth)
+		};
+
 		let mut leaf_digest = hash_serialize::<F, H>(values)
 			.expect("values are of TowerField type which we expect to be serializable");
-		for branch_node in proof.read_vec(tree_depth - layer_depth)? {
-			leaf_digest = self.compression.compress(if index & 1 == 0 {
-				[leaf_digest, branch_node]
-			} else {
-				[branch_node, leaf_digest]
-			});
-			index >>= 1;
+		let mut branch = proof.read_vec(branch_len)?.into_iter();
+		let mut group = Vec::with_capacity(ARITY);
+		for (depth, next_depth) in layer_depths(tree_depth, log_arity)
+			.tuple_windows()
+			.take_while(|&(depth, _)| depth != layer_depth)
+		{
+			let log_group_size = depth - next_depth;
+			let position = index % (1 << log_group_size);
+
+			group.clear();
+			group.extend(branch.by_ref().take((1 << log_group_size) - 1));
+			group.insert(position, leaf_digest);
+			leaf_digest = compress_group(&self.compression, &group);
+			index >>= log_group_size;
 		}
 
 		(leaf_digest == layer_digests[index])
@@ -146,22 +168,25 @@ where
 }
 
 // Merkle-tree-like folding
-fn fold_digests_vector_inplace<C, D>(compression: &C, digests: &mut [D]) -> Result<(), Error>
+fn fold_digests_vector_inplace<C, D, const ARITY: usize>(
+	compression: &C,
+	digests: &mut [D],
+) -> Result<(), Error>
 where
-	C: PseudoCompressionFunction<D, 2> + Sync,
+	C: PseudoCompressionFunction<D, ARITY> + Sync,
 	D: Clone + Default + Send + Sync + Debug,
 {
 	if !digests.len().is_power_of_two() {
 		bail!(Error::PowerOfTwoLengthRequired);
 	}
 
-	let mut len = digests.len() / 2;
-
-	while len != 0 {
-		for i in 0..len {
-			digests[i] = compression.compress(array::from_fn(|j| digests[2 * i + j].clone()));
+	let log_len = log2_strict_usize(digests.len());
+	for (depth, next_depth) in layer_depths(log_len, log_arity::<ARITY>()).tuple_windows() {
+		let group_size = 1 << (depth - next_depth);
+		for i in 0..1 << next_depth {
+			digests[i] =
+				compress_group(compression, &digests[group_size * i..group_size * (i + 1)]);
 		}
-		len /= 2;
 	}
 
 	Ok(())
@@ -0,0 +1,436 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::sync::Arc;
+
+use binius_hash::PseudoCompressionFunction;
+use binius_utils::bail;
+
+use super::errors::{Error, VerificationError};
+
+/// A key of a [`SparseMerkleTree`].
+///
+/// The bits of the key, from the most significant bit of the first byte, select the path from the
+/// root to the leaf.
+pub type SparseMerkleKey = [u8; 32];
+
+/// The depth of a [`SparseMerkleTree`], which has a leaf for every [`SparseMerkleKey`].
+pub const SPARSE_MERKLE_TREE_DEPTH: usize = 256;
+
+/// The verifier side of a [`SparseMerkleTree`].
+///
+/// Holds the compression function and the digests of the empty subtrees of every height.
+#[derive(Debug, Clone)]
+pub struct SparseMerkleTreeScheme<D, C> {
+	compression: C,
+	/// The digests of the empty subtrees, indexed by height.
+	empty_digests: Arc<[D]>,
+}
+
+impl<D, C> SparseMerkleTreeScheme<D, C>
+where
+	D: Clone + Default + Eq,
+	C: PseudoCompressionFunction<D, 2>,
+{
+	pub fn new(compression: C) -> Self {
+		let mut empty_digests = Vec::with_capacity(SPARSE_MERKLE_TREE_DEPTH + 1);
+		empty_digests.push(D::default());
+		for height in 0..SPARSE_MERKLE_TREE_DEPTH {
+			let empty = &empty_digests[height];
+			let next = compression.compress([empty.clone(), empty.clone()]);
+			empty_digests.push(next);
+		}
+		Self {
+			compression,
+			empty_digests: empty_digests.into(),
+		}
+	}
+
+	pub const fn compression(&self) -> &C {
+		&self.compression
+	}
+
+	/// The root of the tree without any keys.
+	pub fn empty_root(&self) -> D {
+		self.empty_digests[SPARSE_MERKLE_TREE_DEPTH].clone()
+	}
+
+	/// Verifies that `key` maps to `value` in the tree with the given root.
+	pub fn verify_membership(
+		&self,
+		root: &D,
+		key: &SparseMerkleKey,
+		value: &D,
+		proof: &SparseMerkleProof<D>,
+	) -> Result<(), Error> {
+		if *value == D::default() {
+			bail!(VerificationError::InvalidProof);
+		}
+		self.verify(root, key, value.clone(), proof)
+	}
+
+	/// Verifies that `key` is not in the tree with the given root.
+	pub fn verify_non_membership(
+		&self,
+		root: &D,
+		key: &SparseMerkleKey,
+		proof: &SparseMerkleProof<D>,
+	) -> Result<(), Error> {
+		self.verify(root, key, D::default(), proof)
+	}
+
+	fn verify(
+		&self,
+		root: &D,
+		key: &SparseMerkleKey,
+		leaf: D,
+		proof: &SparseMerkleProof<D>,
+	) -> Result<(), Error> {
+		let n_non_empty = proof
+			.non_empty
+			.iter()
+			.map(|byte| byte.count_ones() as usize)
+			.sum::<usize>();
+		if n_non_empty != proof.siblings.len() {
+			bail!(VerificationError::IncorrectProofShape);
+		}
+
+		let mut siblings = proof.siblings.iter();
+		let mut digest = leaf;
+		for height in 0..SPARSE_MERKLE_TREE_DEPTH {
+			let sibling = if proof.is_non_empty(height) {
+				siblings.next().expect("number of siblings checked above")
+			} else {
+				&self.empty_digests[height]
+			};
+			digest = self.compress_child(digest, sibling.clone(), key, height + 1);
+		}
+
+		if digest != *root {
+			bail!(VerificationError::InvalidProof);
+		}
+		Ok(())
+	}
+
+	/// Compresses the child on the path to `key` of a node at `height` with its sibling.
+	fn compress_child(&self, child: D, sibling: D, key: &SparseMerkleKey, height: usize) -> D {
+		self.compression.compress(if key_bit(key, height) == 0 {
+			[child, sibling]
+		} else {
+			[sibling, child]
+		})
+	}
+
+	/// Hashes a subtree at `from_height` that is the only non-empty node in the subtree of the
+	/// ancestor at `to_height`.
+	fn hash_up(
+		&self,
+		mut digest: D,
+		key: &SparseMerkleKey,
+		from_height: usize,
+		to_height: usize,
+	) -> D {
+		for height in from_height..to_height {
+			digest =
+				self.compress_child(digest, self.empty_digests[height].clone(), key, height + 1);
+		}
+		digest
+	}
+}
+
+/// A compressed Merkle branch of a [`SparseMerkleTree`].
+///
+/// Most siblings on the path to a key are empty subtrees, so the proof only lists the non-empty
+/// siblings, ordered from the leaf to the root, along with a bitmap of their heights. The same
+/// proof format serves for membership and non-membership.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseMerkleProof<D> {
+	/// Bit `h` is set if the sibling at height `h` is not empty.
+	pub non_empty: [u8; SPARSE_MERKLE_TREE_DEPTH / 8],
+	/// The digests of the non-empty siblings, from the leaf to the root.
+	pub siblings: Vec<D>,
+}
+
+impl<D> SparseMerkleProof<D> {
+	fn is_non_empty(&self, height: usize) -> bool {
+		(self.non_empty[height / 8] >> (height % 8)) & 1 == 1
+	}
+}
+
+/// A Merkle tree with a leaf for every 256-bit key.
+///
+/// The tree maps keys to non-default value digests; all other leaves hold the default digest,
+/// so inserting the default digest removes the key. The root is the root of the complete binary
+/// tree of depth [`SPARSE_MERKLE_TREE_DEPTH`] over all leaves, which is computed with the
+/// precomputed digests of empty subtrees. A subtree with a single key is stored as a single node,
+/// so the size of the tree is linear in the number of keys.
+///
+/// Nodes are shared between clones of the tree, so cloning is cheap and clones are unaffected by
+/// later updates.
+#[derive(Debug, Clone)]
+pub struct SparseMerkleTree<D, C> {
+	scheme: SparseMerkleTreeScheme<D, C>,
+	root: Node<D>,
+	len: usize,
+}
+
+#[derive(Debug, Clone)]
+enum Node<D> {
+	Empty,
+	/// A subtree with a single key, along with the digest of the subtree.
+	Leaf(Arc<LeafNode<D>>),
+	Inner(Arc<InnerNode<D>>),
+}
+
+#[derive(Debug)]
+struct LeafNode<D> {
+	key: SparseMerkleKey,
+	value: D,
+	digest: D,
+}
+
+#[derive(Debug)]
+struct InnerNode<D> {
+	digest: D,
+	children: [Node<D>; 2],
+}
+
+impl<D, C> SparseMerkleTree<D, C>
+where
+	D: Clone + Default + Eq + Send + Sync,
+	C: PseudoCompressionFunction<D, 2> + Sync,
+{
+	/// Creates a tree without any keys.
+	pub fn new(compression: C) -> Self {
+		Self {
+			scheme: SparseMerkleTreeScheme::new(compression),
+			root: Node::Empty,
+			len: 0,
+		}
+	}
+
+	pub const fn scheme(&self) -> &SparseMerkleTreeScheme<D, C> {
+		&self.scheme
+	}
+
+	/// The number of keys in the tree.
+	pub const fn len(&self) -> usize {
+		self.len
+	}
+
+	pub const fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	pub fn root(&self) -> D {
+		self.digest(&self.root, SPARSE_MERKLE_TREE_DEPTH)
+	}
+
+	/// Returns the value of a key, or `None` if the key is not in the tree.
+	pub fn get(&self, key: &SparseMerkleKey) -> Option<&D> {
+		let mut node = &self.root;
+		for height in (1..=SPARSE_MERKLE_TREE_DEPTH).rev() {
+			match node {
+				Node::Empty => return None,
+				Node::Leaf(leaf) => return (leaf.key == *key).then_some(&leaf.value),
+				Node::Inner(inner) => node = &inner.children[key_bit(key, height)],
+			}
+		}
+		match node {
+			Node::Leaf(leaf) if leaf.key == *key => Some(&leaf.value),
+			_ => None,
+		}
+	}
+
+	/// Sets the value of a key and returns the previous value.
+	pub fn insert(&mut self, key: SparseMerkleKey, value: D) -> Option<D> {
+		let old = self.get(&key).cloned();
+		self.update_batch([(key, Some(value))]);
+		old
+	}
+
+	/// Removes a key and returns its value.
+	pub fn remove(&mut self, key: &SparseMerkleKey) -> Option<D> {
+		let old = self.get(key).cloned();
+		self.update_batch([(*key, None)]);
+		old
+	}
+
+	/// Inserts and removes a batch of keys.
+	///
+	/// A value of `None` removes the key. If a key appears several times, the last update takes
+	/// effect. Disjoint subtrees are rehashed in parallel, and the nodes shared by the paths to
+	/// several keys are rehashed only once.
+	pub fn update_batch(
+		&mut self,
+		updates: impl IntoIterator<Item = (SparseMerkleKey, Option<D>)>,
+	) {
+		let mut updates = updates
+			.into_iter()
+			.map(|(key, value)| (key, value.filter(|value| *value != D::default())))
+			.collect::<Vec<_>>();
+		// A stable sort keeps the updates of a key in order, so the last one is kept.
+		updates.sort_by(|(a, _), (b, _)| a.cmp(b));
+		updates.reverse();
+		updates.dedup_by(|(a, _), (b, _)| a == b);
+		updates.reverse();
+
+		for (key, value) in &updates {
+			match (self.get(key).is_some(), value.is_some()) {
+				(false, true) => self.len += 1,
+				(true, false) => self.len -= 1,
+				_ => {}
+			}
+		}
+		self.root = self.update_node(&self.root, SPARSE_MERKLE_TREE_DEPTH, &updates);
+	}
+
+	/// Returns a proof of the value of a key, which is a non-membership proof if the key is not
+	/// in the tree.
+	pub fn prove(&self, key: &SparseMerkleKey) -> SparseMerkleProof<D> {
+		let mut non_empty = [0u8; SPARSE_MERKLE_TREE_DEPTH / 8];
+		let mut siblings = Vec::new();
+		let mut push_sibling = |height: usize, digest: D| {
+			non_empty[height / 8] |= 1 << (height % 8);
+			siblings.push(digest);
+		};
+
+		let mut node = &self.root;
+		for height in (1..=SPARSE_MERKLE_TREE_DEPTH).rev() {
+			match node {
+				Node::Empty => break,
+				Node::Leaf(leaf) => {
+					// The only non-empty sibling below is the subtree of the other key at the
+					// height at which the paths diverge.
+					if leaf.key != *key {
+						let diverge = (1..=height)
+							.rev()
+							.find(|&h| key_bit(&leaf.key, h) != key_bit(key, h))
+							.expect("keys differ");
+						let digest =
+							self.scheme
+								.hash_up(leaf.value.clone(), &leaf.key, 0, diverge - 1);
+						push_sibling(diverge - 1, digest);
+					}
+					break;
+				}
+				Node::Inner(inner) => {
+					let bit = key_bit(key, height);
+					let sibling = &inner.children[bit ^ 1];
+					if !matches!(sibling, Node::Empty) {
+						push_sibling(height - 1, self.digest(sibling, height - 1));
+					}
+					node = &inner.children[bit];
+				}
+			}
+		}
+
+		siblings.reverse();
+		SparseMerkleProof {
+			non_empty,
+			siblings,
+		}
+	}
+
+	fn digest(&self, node: &Node<D>, height: usize) -> D {
+		match node {
+			Node::Empty => self.scheme.empty_digests[height].clone(),
+			Node::Leaf(leaf) => leaf.digest.clone(),
+			Node::Inner(inner) => inner.digest.clone(),
+		}
+	}
+
+	fn leaf_node(&self, key: SparseMerkleKey, value: D, height: usize) -> Node<D> {
+		let digest = self.scheme.hash_up(value.clone(), &key, 0, height);
+		Node::Leaf(Arc::new(LeafNode { key, value, digest }))
+	}
+
+	/// Returns a copy of the subtree at `height` with the sorted updates applied.
+	fn update_node(
+		&self,
+		node: &Node<D>,
+		height: usize,
+		updates: &[(SparseMerkleKey, Option<D>)],
+	) -> Node<D> {
+		if updates.is_empty() {
+			return node.clone();
+		}
+
+		match node {
+			Node::Empty => {
+				let mut inserts = updates.iter().filter(|(_, value)| value.is_some());
+				match (inserts.next(), inserts.next()) {
+					(None, _) => Node::Empty,
+					(Some((key, Some(value))), None) => self.leaf_node(*key, value.clone(), height),
+					_ => self.split(&[Node::Empty, Node::Empty], height, updates),
+				}
+			}
+			Node::Leaf(leaf) => {
+				// Merge the key of the leaf into the updates, unless it is overwritten.
+				let index = updates.partition_point(|(key, _)| *key < leaf.key);
+				if updates.get(index).is_some_and(|(key, _)| *key == leaf.key) {
+					return self.update_node(&Node::Empty, height, updates);
+				}
+				let mut merged = Vec::with_capacity(updates.len() + 1);
+				merged.extend_from_slice(&updates[..index]);
+				merged.push((leaf.key, Some(leaf.value.clone())));
+				merged.extend_from_slice(&updates[index..]);
+				self.update_node(&Node::Empty, height, &merged)
+			}
+			Node::Inner(inner) => self.split(&inner.children, height, updates),
+		}
+	}
+
+	/// Applies the updates to the children of an inner node at `height` and rehashes it.
+	fn split(
+		&self,
+		children: &[Node<D>; 2],
+		height: usize,
+		updates: &[(SparseMerkleKey, Option<D>)],
+	) -> Node<D> {
+		let mid = updates.partition_point(|(key, _)| key_bit(key, height) == 0);
+		let (left, right) = updates.split_at(mid);
+		let children = if left.is_empty() || right.is_empty() {
+			[
+				self.update_node(&children[0], height - 1, left),
+				self.update_node(&children[1], height - 1, right),
+			]
+		} else {
+			binius_maybe_rayon::join(
+				|| self.update_node(&children[0], height - 1, left),
+				|| self.update_node(&children[1], height - 1, right),
+			)
+			.into()
+		};
+
+		match children {
+			[Node::Empty, Node::Empty] => Node::Empty,
+			// A single key below is stored as a leaf at this height.
+			[Node::Leaf(leaf), Node::Empty] | [Node::Empty, Node::Leaf(leaf)] => {
+				let digest = self.scheme.compress_child(
+					leaf.digest.clone(),
+					self.scheme.empty_digests[height - 1].clone(),
+					&leaf.key,
+					height,
+				);
+				Node::Leaf(Arc::new(LeafNode {
+					key: leaf.key,
+					value: leaf.value.clone(),
+					digest,
+				}))
+			}
+			children => {
+				let digest = self.scheme.compression.compress([
+					self.digest(&children[0], height - 1),
+					self.digest(&children[1], height - 1),
+				]);
+				Node::Inner(Arc::new(InnerNode { digest, children }))
+			}
+		}
+	}
+}
+
+/// Returns the bit of the key that selects the child of a node at `height`.
+fn key_bit(key: &SparseMerkleKey, height: usize) -> usize {
+	let depth = SPARSE_MERKLE_TREE_DEPTH - height;
+	((key[depth / 8] >> (7 - depth % 8)) & 1) as usize
+}
@@ -4,10 +4,21 @@ use core::slice;
 use std::iter::repeat_with;
 
 use binius_field::{BinaryField16b, Field};
-use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
-use rand::{SeedableRng, rngs::StdRng};
+use binius_hash::{
+	PseudoCompressionFunction, Vision32Compression, VisionHasherDigest,
+	groestl::{Groestl256, Groestl256ByteCompression},
+	keccak::{Keccak256Compression, Keccak256Parallel},
+	multi_digest::ParallelDigest,
+	sha2::Sha256Compression,
+};
+use binius_utils::checked_arithmetics::log2_ceil_usize;
+use digest::{FixedOutputReset, Output, core_api::BlockSizeUser};
+use rand::{Rng, SeedableRng, rngs::StdRng};
 
-use super::{BinaryMerkleTreeProver, MerkleTreeProver, MerkleTreeScheme};
+use super::{
+	BinaryMerkleTreeProver, BinaryMerkleTreeScheme, IncrementalMerkleTree, KAryMerkleTreeProver,
+	MerkleTreeProver, MerkleTreeScheme, SparseMerkleKey, SparseMerkleTree, verify_branch,
+};
 use crate::{fiat_shamir::HasherChallenger, transcript::ProverTranscript};
 
 #[test]
@@ -100,3 +111,342 @@ fn test_binary_merkle_vcs_verify_vector() {
 		.verify_vector(&commitment.root, &data, 1)
 		.unwrap();
 }
+
+fn check_kary_merkle_vcs_commit_layer_prove_open<H, C, const ARITY: usize>(
+	compression: C,
+	log_len: usize,
+) where
+	H: ParallelDigest<Digest: BlockSizeUser + FixedOutputReset>,
+	C: PseudoCompressionFunction<Output<H::Digest>, ARITY> + Sync,
+{
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let mr_prover = KAryMerkleTreeProver::<_, H, _, ARITY>::new(compression);
+
+	let data = repeat_with(|| Field::random(&mut rng))
+		.take(1 << log_len)
+		.collect::<Vec<BinaryField16b>>();
+	let (commitment, tree) = mr_prover.commit(&data, 1).unwrap();
+
+	assert_eq!(commitment.root, tree.root());
+	mr_prover
+		.scheme()
+		.verify_vector(&commitment.root, &data, 1)
+		.unwrap();
+
+	let layer_depths = (0..=log_len)
+		.filter(|&layer_depth| mr_prover.layer(&tree, layer_depth).is_ok())
+		.collect::<Vec<_>>();
+	assert!(layer_depths.contains(&0) && layer_depths.contains(&log_len));
+	assert!(layer_depths.contains(&mr_prover.scheme().optimal_verify_layer(8, log_len)));
+
+	for layer_depth in layer_depths {
+		let layer = mr_prover.layer(&tree, layer_depth).unwrap();
+		mr_prover
+			.scheme()
+			.verify_layer(&commitment.root, layer_depth, layer)
+			.unwrap();
+		for (i, value) in data.iter().enumerate() {
+			let mut proof_writer = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+			mr_prover
+				.prove_opening(&tree, layer_depth, i, &mut proof_writer.message())
+				.unwrap();
+			let proof_size = mr_prover
+				.scheme()
+				.proof_size(1 << log_len, 1, layer_depth)
+				.unwrap();
+			assert_eq!(proof_writer.finalize().len(), proof_size - (layer.len() * 32));
+
+			let mut proof_writer = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+			mr_prover
+				.prove_opening(&tree, layer_depth, i, &mut proof_writer.message())
+				.unwrap();
+			let mut proof_reader = proof_writer.into_verifier();
+			mr_prover
+				.scheme()
+				.verify_opening(
+					i,
+					slice::from_ref(value),
+					layer_depth,
+					log_len,
+					layer,
+					&mut proof_reader.message(),
+				)
+				.unwrap();
+
+			if layer_depth < log_len {
+				let mut proof_writer = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+				mr_prover
+					.prove_opening(&tree, layer_depth, i, &mut proof_writer.message())
+					.unwrap();
+				let mut proof_reader = proof_writer.into_verifier();
+				let wrong_value = *value + BinaryField16b::ONE;
+				assert!(
+					mr_prover
+						.scheme()
+						.verify_opening(
+							i,
+							slice::from_ref(&wrong_value),
+							layer_depth,
+							log_len,
+							layer,
+							&mut proof_reader.message(),
+						)
+						.is_err()
+				);
+			}
+		}
+	}
+}
+
+#[test]
+fn test_4ary_merkle_vcs_commit_layer_prove_open_correctly() {
+	for log_len in [0, 1, 4, 5] {
+		check_kary_merkle_vcs_commit_layer_prove_open::<Groestl256, _, 4>(
+			Groestl256ByteCompression,
+			log_len,
+		);
+	}
+	check_kary_merkle_vcs_commit_layer_prove_open::<VisionHasherDigest, _, 4>(
+		Vision32Compression,
+		5,
+	);
+	check_kary_merkle_vcs_commit_layer_prove_open::<Groestl256, _, 2>(Groestl256ByteCompression, 5);
+}
+
+#[test]
+fn test_keccak_merkle_vcs_commit_layer_prove_open_correctly() {
+	check_kary_merkle_vcs_commit_layer_prove_open::<Keccak256Parallel, _, 2>(
+		Keccak256Compression,
+		5,
+	);
+	check_kary_merkle_vcs_commit_layer_prove_open::<Keccak256Parallel, _, 4>(
+		Keccak256Compression,
+		4,
+	);
+}
+
+#[test]
+fn test_binary_merkle_optimal_verify_layer() {
+	let scheme =
+		BinaryMerkleTreeScheme::<BinaryField16b, Groestl256, _>::new(Groestl256ByteCompression);
+	for tree_depth in 0..12 {
+		for n_queries in 1..200 {
+			assert_eq!(
+				scheme.optimal_verify_layer(n_queries, tree_depth),
+				log2_ceil_usize(n_queries).min(tree_depth)
+			);
+		}
+	}
+}
+
+#[test]
+fn test_incremental_merkle_tree_matches_binary_merkle_tree() {
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let data = repeat_with(|| Field::random(&mut rng))
+		.take(16)
+		.collect::<Vec<BinaryField16b>>();
+	let tree = super::build::<_, Groestl256, _, 2>(&Groestl256ByteCompression, &data, 1).unwrap();
+	let leaves = tree.layer(4).unwrap();
+
+	let mut incremental = IncrementalMerkleTree::new(Groestl256ByteCompression, 0);
+	for (i, leaf) in leaves.iter().enumerate() {
+		assert_eq!(incremental.push(*leaf), i);
+	}
+	assert_eq!(incremental.depth(), 4);
+	assert_eq!(incremental.len(), 16);
+	assert_eq!(incremental.root(), tree.root());
+
+	for (i, leaf) in leaves.iter().enumerate() {
+		assert_eq!(incremental.leaf(i).unwrap(), *leaf);
+		let branch = incremental.branch(i).unwrap();
+		assert_eq!(branch, tree.branch(i, 0).unwrap());
+		verify_branch(&Groestl256ByteCompression, &incremental.commitment(), i, *leaf, &branch)
+			.unwrap();
+	}
+	assert!(incremental.leaf(16).is_err());
+}
+
+#[test]
+fn test_incremental_merkle_tree_partial() {
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let mut tree = IncrementalMerkleTree::new(Groestl256ByteCompression, 3);
+	let empty_root = tree.root();
+	assert!(tree.is_empty());
+
+	let leaves = repeat_with(|| Output::<Groestl256>::from(rng.random::<[u8; 32]>()))
+		.take(5)
+		.collect::<Vec<_>>();
+	for leaf in &leaves {
+		tree.push(*leaf);
+	}
+	assert_eq!(tree.depth(), 3);
+	assert_ne!(tree.root(), empty_root);
+
+	// A partially filled tree commits to the unoccupied leaves as default digests.
+	let mut full = IncrementalMerkleTree::new(Groestl256ByteCompression, 3);
+	for i in 0..8 {
+		full.push(leaves.get(i).copied().unwrap_or_default());
+	}
+	assert_eq!(tree.root(), full.root());
+
+	let commitment = tree.commitment();
+	for (i, leaf) in leaves.iter().enumerate() {
+		let branch = tree.branch(i).unwrap();
+		verify_branch(&Groestl256ByteCompression, &commitment, i, *leaf, &branch).unwrap();
+		assert!(
+			verify_branch(&Groestl256ByteCompression, &commitment, i ^ 1, *leaf, &branch).is_err()
+		);
+	}
+}
+
+#[test]
+fn test_incremental_merkle_tree_update_and_snapshot() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let mut random_leaf = || Output::<Groestl256>::from(rng.random::<[u8; 32]>());
+
+	let mut tree = IncrementalMerkleTree::new(Groestl256ByteCompression, 0);
+	for _ in 0..6 {
+		tree.push(random_leaf());
+	}
+	let snapshot = tree.snapshot();
+	let old = tree.commitment();
+
+	let new_leaf = random_leaf();
+	let proof = tree.update(3, new_leaf).unwrap();
+	let new = tree.commitment();
+	assert_eq!(proof.old_leaf, snapshot.leaf(3).unwrap());
+	assert_eq!(tree.leaf(3).unwrap(), new_leaf);
+	proof
+		.verify(&Groestl256ByteCompression, &old, &new)
+		.unwrap();
+
+	// The snapshot keeps the old root and its branches verify against it.
+	assert_eq!(snapshot.commitment(), old);
+	for i in 0..snapshot.len() {
+		let branch = snapshot.branch(i).unwrap();
+		verify_branch(&Groestl256ByteCompression, &old, i, snapshot.leaf(i).unwrap(), &branch)
+			.unwrap();
+	}
+
+	let mut tampered = proof.clone();
+	tampered.new_leaf = random_leaf();
+	assert!(
+		tampered
+			.verify(&Groestl256ByteCompression, &old, &new)
+			.is_err()
+	);
+	assert!(
+		proof
+			.verify(&Groestl256ByteCompression, &new, &old)
+			.is_err()
+	);
+	assert!(tree.update(6, new_leaf).is_err());
+}
+
+fn check_sparse_merkle_tree<C>(compression: C)
+where
+	C: PseudoCompressionFunction<Output<Groestl256>, 2> + Clone + Sync,
+{
+	let mut rng = StdRng::seed_from_u64(0);
+	let entries = repeat_with(|| {
+		(rng.random::<SparseMerkleKey>(), Output::<Groestl256>::from(rng.random::<[u8; 32]>()))
+	})
+	.take(8)
+	.collect::<Vec<_>>();
+	let absent = repeat_with(|| rng.random::<SparseMerkleKey>())
+		.take(3)
+		.collect::<Vec<_>>();
+
+	let mut tree = SparseMerkleTree::new(compression.clone());
+	let empty_root = tree.root();
+	assert_eq!(empty_root, tree.scheme().empty_root());
+	for (key, value) in &entries {
+		assert_eq!(tree.insert(*key, *value), None);
+	}
+	assert_eq!(tree.len(), entries.len());
+
+	// The root does not depend on the order of the updates.
+	let mut batched = SparseMerkleTree::new(compression);
+	batched.update_batch(
+		entries
+			.iter()
+			.rev()
+			.map(|(key, value)| (*key, Some(*value))),
+	);
+	assert_eq!(batched.root(), tree.root());
+	assert_eq!(batched.len(), entries.len());
+
+	let root = tree.root();
+	let scheme = tree.scheme().clone();
+	for (key, value) in &entries {
+		assert_eq!(tree.get(key), Some(value));
+		let proof = tree.prove(key);
+		scheme.verify_membership(&root, key, value, &proof).unwrap();
+		assert!(scheme.verify_non_membership(&root, key, &proof).is_err());
+		assert!(
+			scheme
+				.verify_membership(&empty_root, key, value, &proof)
+				.is_err()
+		);
+	}
+	for key in &absent {
+		assert_eq!(tree.get(key), None);
+		let proof = tree.prove(key);
+		scheme.verify_non_membership(&root, key, &proof).unwrap();
+		assert!(
+			scheme
+				.verify_membership(&root, key, &entries[0].1, &proof)
+				.is_err()
+		);
+	}
+
+	// Keys sharing a long prefix are proven with a sibling deep in the tree.
+	let mut neighbor = entries[0].0;
+	neighbor[31] ^= 1;
+	let proof = tree.prove(&neighbor);
+	assert_eq!(proof.siblings.len(), tree.prove(&entries[0].0).siblings.len() + 1);
+	scheme
+		.verify_non_membership(&root, &neighbor, &proof)
+		.unwrap();
+	tree.insert(neighbor, entries[1].1);
+	scheme
+		.verify_membership(&tree.root(), &neighbor, &entries[1].1, &tree.prove(&neighbor))
+		.unwrap();
+	tree.remove(&neighbor);
+	assert_eq!(tree.root(), root);
+
+	// Overwriting and removing keys in a batch.
+	let new_value = Output::<Groestl256>::from(rng.random::<[u8; 32]>());
+	batched.update_batch([
+		(entries[0].0, None),
+		(entries[1].0, Some(Output::<Groestl256>::default())),
+		(entries[2].0, None),
+		(entries[2].0, Some(new_value)),
+	]);
+	assert_eq!(batched.len(), entries.len() - 2);
+	assert_eq!(batched.get(&entries[0].0), None);
+	assert_eq!(batched.get(&entries[1].0), None);
+	assert_eq!(batched.get(&entries[2].0), Some(&new_value));
+
+	batched.update_batch(entries.iter().map(|(key, _)| (*key, None)));
+	assert!(batched.is_empty());
+	assert_eq!(batched.root(), empty_root);
+}
+
+#[test]
+fn test_sparse_merkle_tree_groestl() {
+	check_sparse_merkle_tree(Groestl256ByteCompression);
+}
+
+#[test]
+fn test_sparse_merkle_tree_vision() {
+	check_sparse_merkle_tree(Vision32Compression);
+}
+
+#[test]
+fn test_sparse_merkle_tree_sha256() {
+	check_sparse_merkle_tree(Sha256Compression::default());
+}
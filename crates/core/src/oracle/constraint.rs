@@ -18,6 +18,7 @@ pub type TypeErasedComposition<P> = Arc<dyn CompositionPoly<P>>;
 /// Constraint is a type erased composition along with a predicate on its values on the boolean
 /// hypercube
 #[derive(Debug, Clone, SerializeBytes, DeserializeBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Constraint<F: Field> {
 	pub name: String,
 	pub composition: ArithCircuit<F>,
@@ -27,6 +28,7 @@ pub struct Constraint<F: Field> {
 /// Predicate can either be a sum of values of a composition on the hypercube (sumcheck) or equality
 /// to zero on the hypercube (zerocheck)
 #[derive(Clone, Debug, SerializeBytes, DeserializeBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ConstraintPredicate<F: Field> {
 	Sum(F),
 	Zero,
@@ -60,6 +62,7 @@ impl<F: Field> SizedConstraintSet<F> {
 /// Constraint set is a group of constraints that operate over the same set of oracle-identified
 /// multilinears. The multilinears are expected to be of the same size.
 #[derive(Debug, Clone, SerializeBytes, DeserializeBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConstraintSet<F: Field> {
 	pub table_id: TableId,
 	pub log_values_per_row: usize,
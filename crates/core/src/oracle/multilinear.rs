@@ -676,6 +676,9 @@ impl DeserializeBytes for TransparentPolyOracle<BinaryField128b> {
 	}
 }
 
+#[cfg(feature = "serde")]
+binius_utils::impl_serde_via_bytes!(<F: TowerField> TransparentPolyOracle<F>);
+
 impl<F: TowerField> TransparentPolyOracle<F> {
 	pub fn new(poly: Arc<dyn MultivariatePoly<F>>) -> Result<Self, Error> {
 		if poly.binary_tower_level() > F::TOWER_LEVEL {
@@ -776,6 +779,7 @@ impl ZeroPadded {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, SerializeBytes, DeserializeBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ShiftVariant {
 	CircularLeft,
 	LogicalLeft,
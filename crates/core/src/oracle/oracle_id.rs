@@ -8,6 +8,11 @@ use binius_utils::{DeserializeBytes, SerializationError, SerializationMode, Seri
 ///
 /// This is essentially an index.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(transparent)
+)]
 pub struct OracleId(usize);
 
 impl OracleId {
@@ -26,16 +26,22 @@ use crate::{
 
 #[derive(Debug, Clone, PartialEq, Eq, SerializeBytes, DeserializeBytes)]
 #[deserialize_bytes(eval_generics(F = BinaryField128b))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SymbolicMultilinearOracle<F: TowerField> {
 	pub id: OracleId,
 	pub name: Option<String>,
 	pub table_id: TableId,
 	pub log_values_per_row: usize,
 	pub tower_level: usize,
+	#[cfg_attr(
+		feature = "serde",
+		serde(bound(deserialize = "SymbolicMultilinearPolyVariant<F>: serde::Deserialize<'de>"))
+	)]
 	pub variant: SymbolicMultilinearPolyVariant<F>,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, SerializeBytes, DeserializeBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ProjectionVariant {
 	/// Project values starting at the given index.
 	Offset(usize),
@@ -44,8 +50,15 @@ pub enum ProjectionVariant {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, SerializeBytes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SymbolicMultilinearPolyVariant<F: TowerField> {
 	Committed,
+	/// Transparent polynomials are type-erased, so they are serialized as opaque bytes, which can
+	/// only be deserialized for the fields that have a transparent polynomial registry.
+	#[cfg_attr(
+		feature = "serde",
+		serde(bound(deserialize = "TransparentPolyOracle<F>: DeserializeBytes"))
+	)]
 	Transparent(TransparentPolyOracle<F>),
 	/// A structured virtual polynomial is one that can be evaluated succinctly by a verifier.
 	///
@@ -150,7 +163,12 @@ impl DeserializeBytes for SymbolicMultilinearPolyVariant<BinaryField128b> {
 
 #[derive(Default, Debug, Clone, SerializeBytes, DeserializeBytes)]
 #[deserialize_bytes(eval_generics(F = BinaryField128b))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SymbolicMultilinearOracleSet<F: TowerField> {
+	#[cfg_attr(
+		feature = "serde",
+		serde(bound(deserialize = "SymbolicMultilinearOracle<F>: serde::Deserialize<'de>"))
+	)]
 	oracles: Vec<SymbolicMultilinearOracle<F>>,
 }
 
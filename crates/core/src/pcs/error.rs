@@ -0,0 +1,35 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_compute::{alloc, layer};
+
+use crate::{piop, ring_switch, transcript};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	#[error("tower level {tower_level} exceeds the maximum of {max}")]
+	TowerLevelTooHigh { tower_level: usize, max: usize },
+	#[error("the Reed–Solomon code rate must be less than one")]
+	InvalidLogInvRate,
+	#[error("cannot commit to an empty batch of multilinears")]
+	EmptyBatch,
+	#[error("multilinear has {got} variables, the parameters allow at most {max}")]
+	TooManyVariables { max: usize, got: usize },
+	#[error("multilinear coefficients have tower level {got}, the parameters require {expected}")]
+	TowerLevelMismatch { expected: usize, got: usize },
+	#[error("evaluation point has {got} coordinates, the batch requires {expected}")]
+	IncorrectPointLength { expected: usize, got: usize },
+	#[error("got {got} claimed evaluations for a batch of {expected} multilinears")]
+	IncorrectNumberOfValues { expected: usize, got: usize },
+	#[error("binius_math error: {0}")]
+	Math(#[from] binius_math::Error),
+	#[error("PIOP compiler error: {0}")]
+	PIOP(#[from] piop::Error),
+	#[error("ring switch error: {0}")]
+	RingSwitch(#[from] ring_switch::Error),
+	#[error("transcript error: {0}")]
+	Transcript(#[from] transcript::Error),
+	#[error("compute error: {0}")]
+	ComputeError(#[from] layer::Error),
+	#[error("allocation error: {0}")]
+	AllocError(#[from] alloc::Error),
+}
@@ -0,0 +1,230 @@
+// Copyright 2025 Irreducible Inc.
+
+//! A standalone multilinear polynomial commitment scheme.
+//!
+//! This module packages the FRI-Binius compiler from [`crate::piop`] and the batched
+//! ring-switching reduction from [`crate::ring_switch`] into a plain polynomial commitment
+//! interface, for applications that want to use Binius only as a PCS:
+//!
+//! 1) [`setup`] fixes the maximum number of variables, the tower level of the coefficients and the
+//!    security level,
+//! 2) [`commit`] commits to a batch of multilinears, which may have different numbers of variables,
+//! 3) [`Committed::open`] evaluates the whole batch at one point and proves the evaluations,
+//! 4) [`verify`] checks the evaluations against the commitment.
+//!
+//! A multilinear with $n$ variables in a batch of polynomials opened at a point
+//! $(z_0, \ldots, z_{\ell-1})$ is evaluated at the prefix $(z_0, \ldots, z_{n-1})$.
+//!
+//! Commitments, parameters and proofs all implement [`SerializeBytes`] and [`DeserializeBytes`].
+
+mod error;
+mod prove;
+#[cfg(test)]
+mod tests;
+mod verify;
+
+use std::iter;
+
+use binius_field::TowerField;
+use binius_macros::{DeserializeBytes, SerializeBytes};
+use binius_math::B128;
+use binius_utils::{
+	DeserializeBytes, SerializationError, SerializationMode, SerializeBytes,
+	sparse_index::SparseIndex,
+};
+use bytes::{Buf, BufMut};
+pub use error::*;
+use getset::CopyGetters;
+pub use prove::*;
+pub use verify::*;
+
+use crate::{
+	oracle::{MultilinearOracleSet, OracleId},
+	piop::{self, CommitMeta},
+	protocols::evalcheck::EvalcheckMultilinearClaim,
+	transcript::TranscriptWriter,
+};
+
+/// The binary logarithm of the inverse Reed–Solomon code rate used by [`setup`].
+pub const DEFAULT_LOG_INV_RATE: usize = 1;
+
+/// Public parameters of the commitment scheme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, CopyGetters, SerializeBytes, DeserializeBytes)]
+#[getset(get_copy = "pub")]
+pub struct Params {
+	/// The maximum number of variables of a committed multilinear.
+	n_vars: usize,
+	/// The tower level of the field the multilinear coefficients are defined over.
+	tower_level: usize,
+	/// The target security level in bits.
+	security_bits: usize,
+	/// The binary logarithm of the inverse Reed–Solomon code rate.
+	log_inv_rate: usize,
+}
+
+impl Params {
+	/// Returns the parameters with a different Reed–Solomon code rate.
+	///
+	/// Lower rates result in smaller proofs at the cost of more expensive commitments.
+	pub fn with_log_inv_rate(self, log_inv_rate: usize) -> Result<Self, Error> {
+		if log_inv_rate == 0 {
+			return Err(Error::InvalidLogInvRate);
+		}
+		Ok(Self {
+			log_inv_rate,
+			..self
+		})
+	}
+
+	/// Lays out a batch of committed multilinears with the given numbers of variables.
+	fn layout<F: TowerField>(&self, n_vars: &[usize]) -> Result<BatchLayout<F>, Error> {
+		if n_vars.is_empty() {
+			return Err(Error::EmptyBatch);
+		}
+		if let Some(&got) = n_vars.iter().find(|&&n_vars| n_vars > self.n_vars) {
+			return Err(Error::TooManyVariables {
+				max: self.n_vars,
+				got,
+			});
+		}
+
+		let mut oracles = MultilinearOracleSet::new();
+		let oracle_ids = n_vars
+			.iter()
+			.map(|&n_vars| oracles.add_committed(n_vars, self.tower_level))
+			.collect();
+		let (commit_meta, oracle_to_commit_index) = piop::make_oracle_commit_meta(&oracles)?;
+		Ok(BatchLayout {
+			oracles,
+			oracle_ids,
+			commit_meta,
+			oracle_to_commit_index,
+		})
+	}
+}
+
+/// The committed oracles for a batch, one per multilinear in the order given by the user.
+#[derive(Debug)]
+struct BatchLayout<F: TowerField> {
+	oracles: MultilinearOracleSet<F>,
+	oracle_ids: Vec<OracleId>,
+	commit_meta: CommitMeta,
+	oracle_to_commit_index: SparseIndex<usize>,
+}
+
+impl<F: TowerField> BatchLayout<F> {
+	/// Builds the evaluation claims for the batch opened at `point`.
+	fn eval_claims(&self, point: &[F], values: &[F]) -> Vec<EvalcheckMultilinearClaim<F>> {
+		iter::zip(&self.oracle_ids, values)
+			.map(|(&id, &eval)| EvalcheckMultilinearClaim {
+				id,
+				eval_point: point[..self.oracles.n_vars(id)].to_vec().into(),
+				eval,
+			})
+			.collect()
+	}
+}
+
+/// Checks that the evaluation point has as many coordinates as the largest multilinear in the
+/// batch.
+fn check_point_len<F>(n_vars: &[usize], point: &[F]) -> Result<(), Error> {
+	let expected = n_vars.iter().copied().max().unwrap_or(0);
+	if point.len() != expected {
+		return Err(Error::IncorrectPointLength {
+			expected,
+			got: point.len(),
+		});
+	}
+	Ok(())
+}
+
+/// Writes the public inputs of an opening to the transcript.
+fn observe_opening<F: TowerField, Digest: SerializeBytes>(
+	mut writer: TranscriptWriter<impl BufMut>,
+	params: &Params,
+	commitment: &Commitment<Digest>,
+	point: &[F],
+	values: &[F],
+) {
+	writer.write(params);
+	writer.write(commitment);
+	writer.write_scalar_slice(point);
+	writer.write_scalar_slice(values);
+}
+
+/// Chooses the public parameters for committing to multilinears with up to `n_vars` variables
+/// over the tower field at level `tower_level`.
+pub fn setup(n_vars: usize, tower_level: usize, security_bits: usize) -> Result<Params, Error> {
+	if tower_level > B128::TOWER_LEVEL {
+		return Err(Error::TowerLevelTooHigh {
+			tower_level,
+			max: B128::TOWER_LEVEL,
+		});
+	}
+	Ok(Params {
+		n_vars,
+		tower_level,
+		security_bits,
+		log_inv_rate: DEFAULT_LOG_INV_RATE,
+	})
+}
+
+/// A commitment to a batch of multilinear polynomials.
+///
+/// The commitment records the number of variables of every polynomial in the batch, in the order
+/// they were passed to [`commit`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Commitment<Digest> {
+	pub n_vars: Vec<usize>,
+	pub digest: Digest,
+}
+
+impl<Digest: SerializeBytes> SerializeBytes for Commitment<Digest> {
+	fn serialize(
+		&self,
+		mut write_buf: impl BufMut,
+		mode: SerializationMode,
+	) -> Result<(), SerializationError> {
+		self.n_vars.serialize(&mut write_buf, mode)?;
+		self.digest.serialize(&mut write_buf, mode)
+	}
+}
+
+impl<Digest: DeserializeBytes> DeserializeBytes for Commitment<Digest> {
+	fn deserialize(
+		mut read_buf: impl Buf,
+		mode: SerializationMode,
+	) -> Result<Self, SerializationError>
+	where
+		Self: Sized,
+	{
+		Ok(Self {
+			n_vars: DeserializeBytes::deserialize(&mut read_buf, mode)?,
+			digest: DeserializeBytes::deserialize(&mut read_buf, mode)?,
+		})
+	}
+}
+
+/// An evaluation proof that has been serialized into bytes.
+#[derive(Debug, Clone, PartialEq, Eq, SerializeBytes, DeserializeBytes)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(transparent)
+)]
+pub struct Proof {
+	#[cfg_attr(
+		feature = "serde",
+		serde(
+			serialize_with = "binius_utils::serde_encoding::serialize_bytes",
+			deserialize_with = "binius_utils::serde_encoding::deserialize_bytes"
+		)
+	)]
+	pub transcript: Vec<u8>,
+}
+
+impl Proof {
+	pub fn get_proof_size(&self) -> usize {
+		self.transcript.len()
+	}
+}
@@ -0,0 +1,239 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{collections::BTreeMap, ops::Deref};
+
+use binius_compute::{ComputeHolder, cpu::layer::CpuLayerHolder};
+use binius_field::{
+	PackedField, PackedFieldIndexable, RepackedExtension, TowerField,
+	as_packed_field::{PackScalar, PackedType},
+};
+use binius_hash::{PseudoCompressionFunction, multi_digest::ParallelDigest};
+use binius_math::{
+	B32, MLEEmbeddingAdapter, MultilinearExtension, MultilinearPoly, MultilinearQuery, PackedTop,
+	TowerTop, TowerUnderlier,
+};
+use digest::{FixedOutputReset, Output, core_api::BlockSizeUser};
+
+use super::{BatchLayout, Commitment, Error, Params, Proof, check_point_len, observe_opening};
+use crate::{
+	fiat_shamir::Challenger,
+	merkle_tree::BinaryMerkleTreeProver,
+	piop::{FRIPCSProver, MultilinearPCS, MultilinearPCSProver},
+	protocols::evalcheck::subclaims::MemoizedData,
+	ring_switch::{self, EvalClaimSystem},
+	transcript::ProverTranscript,
+	witness::MultilinearWitness,
+};
+
+const MIN_HOST_MEM_SIZE: usize = 1 << 8;
+
+/// The FRI-Binius PIOP compiler instantiated with binary Merkle trees, as used by [`commit`].
+pub type FRIPCSProverFor<F, Hash, Compress> =
+	FRIPCSProver<F, B32, BinaryMerkleTreeProver<F, Hash, Compress>>;
+
+/// Prover state for a committed batch of multilinears, returned by [`commit`].
+pub struct Committed<'a, P, PCSProver>
+where
+	P: PackedField<Scalar: TowerField>,
+	PCSProver: MultilinearPCSProver<P>,
+{
+	params: Params,
+	commitment: Commitment<<PCSProver::PCS as MultilinearPCS<P::Scalar>>::Commitment>,
+	layout: BatchLayout<P::Scalar>,
+	/// The committed multilinears, in the commit order of the layout.
+	multilins: Vec<MultilinearWitness<'a, P>>,
+	pcs_prover: PCSProver,
+	pcs_committed: PCSProver::Committed,
+}
+
+/// Commits to a batch of multilinear polynomials using FRI-Binius.
+///
+/// All multilinears must be defined over the tower field at the level fixed in `params` and have
+/// at most `params.n_vars()` variables. They may be passed in any order.
+#[allow(clippy::type_complexity)]
+pub fn commit<'a, U, F, FSub, Data, Hash, Compress>(
+	params: &Params,
+	multilins: &'a [MultilinearExtension<PackedType<U, FSub>, Data>],
+) -> Result<
+	(
+		Commitment<Output<Hash::Digest>>,
+		Committed<'a, PackedType<U, F>, FRIPCSProverFor<F, Hash, Compress>>,
+	),
+	Error,
+>
+where
+	U: TowerUnderlier + PackScalar<F> + PackScalar<FSub>,
+	F: TowerTop + PackedTop<Scalar = F>,
+	FSub: TowerField,
+	PackedType<U, F>: PackedFieldIndexable + PackedTop + RepackedExtension<PackedType<U, FSub>>,
+	Data: Deref<Target = [PackedType<U, FSub>]> + Send + Sync,
+	Hash: ParallelDigest<Digest: BlockSizeUser + FixedOutputReset>,
+	Compress: PseudoCompressionFunction<Output<Hash::Digest>, 2> + Default + Sync,
+{
+	let pcs_prover = FRIPCSProver::new(
+		BinaryMerkleTreeProver::new(Compress::default()),
+		params.log_inv_rate(),
+		params.security_bits(),
+	);
+	commit_with_pcs(params, pcs_prover, multilins)
+}
+
+/// Commits to a batch of multilinear polynomials using the given PIOP compiler.
+///
+/// See [`commit`] for the requirements on the multilinears.
+#[allow(clippy::type_complexity)]
+pub fn commit_with_pcs<'a, P, FSub, PSub, Data, PCSProver>(
+	params: &Params,
+	pcs_prover: PCSProver,
+	multilins: &'a [MultilinearExtension<PSub, Data>],
+) -> Result<
+	(
+		Commitment<<PCSProver::PCS as MultilinearPCS<P::Scalar>>::Commitment>,
+		Committed<'a, P, PCSProver>,
+	),
+	Error,
+>
+where
+	P: PackedField<Scalar: TowerField> + RepackedExtension<PSub>,
+	FSub: TowerField,
+	PSub: PackedField<Scalar = FSub>,
+	Data: Deref<Target = [PSub]> + Send + Sync,
+	PCSProver: MultilinearPCSProver<P>,
+{
+	if FSub::TOWER_LEVEL != params.tower_level() {
+		return Err(Error::TowerLevelMismatch {
+			expected: params.tower_level(),
+			got: FSub::TOWER_LEVEL,
+		});
+	}
+
+	let n_vars = multilins.iter().map(|mle| mle.n_vars()).collect::<Vec<_>>();
+	let layout = params.layout::<P::Scalar>(&n_vars)?;
+
+	let mut committed_multilins = vec![None; multilins.len()];
+	for (&id, mle) in layout.oracle_ids.iter().zip(multilins) {
+		let commit_idx = *layout
+			.oracle_to_commit_index
+			.get(id.index())
+			.expect("all oracles in the layout are committed");
+		committed_multilins[commit_idx] =
+			Some(MLEEmbeddingAdapter::<_, P, _>::from(mle.to_ref()).upcast_arc_dyn());
+	}
+	let committed_multilins = committed_multilins
+		.into_iter()
+		.map(|multilin| multilin.expect("the commit index is a bijection"))
+		.collect::<Vec<_>>();
+
+	let (digest, pcs_committed) = pcs_prover.commit(&layout.commit_meta, &committed_multilins)?;
+	let commitment = Commitment { n_vars, digest };
+
+	let committed = Committed {
+		params: *params,
+		commitment: commitment.clone(),
+		layout,
+		multilins: committed_multilins,
+		pcs_prover,
+		pcs_committed,
+	};
+	Ok((commitment, committed))
+}
+
+impl<P, PCSProver> Committed<'_, P, PCSProver>
+where
+	P: PackedFieldIndexable<Scalar: TowerTop + PackedTop<Scalar = P::Scalar>> + PackedTop,
+	PCSProver: MultilinearPCSProver<P>,
+{
+	/// Evaluates every multilinear in the batch at `point` and proves the evaluations.
+	///
+	/// A multilinear with fewer variables than `point` has coordinates is evaluated at the
+	/// corresponding prefix of `point`. Returns the evaluations, in the order the multilinears were
+	/// passed to [`commit`], along with the proof.
+	///
+	/// This consumes the prover state, as the underlying opening protocol may only be run once
+	/// per commitment.
+	pub fn open<Challenger_>(self, point: &[P::Scalar]) -> Result<(Vec<P::Scalar>, Proof), Error>
+	where
+		Challenger_: Challenger + Default,
+	{
+		let Self {
+			params,
+			commitment,
+			layout,
+			multilins,
+			pcs_prover,
+			pcs_committed,
+		} = self;
+
+		check_point_len(&commitment.n_vars, point)?;
+
+		let mut queries = BTreeMap::new();
+		for &n_vars in &commitment.n_vars {
+			queries
+				.entry(n_vars)
+				.or_insert_with(|| MultilinearQuery::<P>::expand(&point[..n_vars]));
+		}
+		let values = layout
+			.oracle_ids
+			.iter()
+			.map(|id| {
+				let commit_idx = layout
+					.oracle_to_commit_index
+					.get(id.index())
+					.expect("all oracles in the layout are committed");
+				let multilin = &multilins[*commit_idx];
+				multilin.evaluate(queries[&multilin.n_vars()].to_ref())
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+
+		let mut transcript = ProverTranscript::<Challenger_>::new();
+		observe_opening(transcript.observe(), &params, &commitment, point, &values);
+
+		let eval_claims = layout.eval_claims(point, &values);
+		let system = EvalClaimSystem::new(
+			&layout.oracles,
+			&layout.commit_meta,
+			&layout.oracle_to_commit_index,
+			&eval_claims,
+		)?;
+
+		// On the device, the opening protocol allocates the packed batch, the transparent
+		// polynomials, the folded sumcheck multilinears and the FRI codewords, which all fit in
+		// eight times the codeword length, plus a tensor expansion of up to the extension degree
+		// per claim for ring-switching. The host only holds small per-round buffers.
+		let total_vars = layout.commit_meta.total_vars();
+		let total_multilins = layout.commit_meta.total_multilins();
+		let dev_mem_size = (1 << (total_vars + params.log_inv_rate() + 3))
+			+ total_multilins * (1 << <P::Scalar as TowerField>::TOWER_LEVEL);
+		let host_mem_size = MIN_HOST_MEM_SIZE + 2 * total_multilins * (total_vars + 1).pow(2);
+		let mut compute_holder = CpuLayerHolder::<P::Scalar>::new(host_mem_size, dev_mem_size);
+		let compute_data = compute_holder.to_data();
+
+		let ring_switch::ReducedWitness {
+			transparents,
+			sumcheck_claims,
+		} = ring_switch::prove(
+			&system,
+			&multilins,
+			&mut transcript,
+			MemoizedData::new(),
+			compute_data.hal,
+			&compute_data.dev_alloc,
+			&compute_data.host_alloc,
+		)?;
+
+		pcs_prover.prove(
+			&compute_data,
+			&layout.commit_meta,
+			pcs_committed,
+			&multilins,
+			transparents,
+			&sumcheck_claims,
+			&mut transcript,
+		)?;
+
+		let proof = Proof {
+			transcript: transcript.finalize(),
+		};
+		Ok((values, proof))
+	}
+}
@@ -0,0 +1,242 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::iter::repeat_with;
+
+use binius_field::{
+	Field, PackedField, RepackedExtension, TowerField,
+	arch::OptimalUnderlier128b,
+	as_packed_field::{PackScalar, PackedType},
+};
+use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+use binius_math::{B1, B8, B16, B32, B128, MultilinearExtension, MultilinearQuery};
+use binius_utils::{DeserializeBytes, SerializationMode, SerializeBytes};
+use digest::Output;
+use rand::{SeedableRng, rngs::StdRng};
+
+use super::*;
+use crate::{
+	fiat_shamir::HasherChallenger,
+	merkle_tree::{BinaryMerkleTreeProver, BinaryMerkleTreeScheme},
+	piop::{
+		FRIPCS, FRIPCSProver, LigeroPCS, LigeroPCSProver, MultilinearPCS, MultilinearPCSProver,
+	},
+};
+
+type U = OptimalUnderlier128b;
+type Challenger_ = HasherChallenger<Groestl256>;
+
+const SECURITY_BITS: usize = 32;
+
+fn random_multilins<FSub: Field>(
+	rng: &mut StdRng,
+	n_varss: &[usize],
+) -> Vec<MultilinearExtension<PackedType<U, FSub>>>
+where
+	U: PackScalar<FSub>,
+{
+	n_varss
+		.iter()
+		.map(|&n_vars| {
+			let evals = repeat_with(|| PackedType::<U, FSub>::random(&mut *rng))
+				.take(1 << n_vars.saturating_sub(PackedType::<U, FSub>::LOG_WIDTH))
+				.collect();
+			MultilinearExtension::new(n_vars, evals).unwrap()
+		})
+		.collect()
+}
+
+fn random_point(rng: &mut StdRng, n_vars: usize) -> Vec<B128> {
+	repeat_with(|| <B128 as Field>::random(&mut *rng))
+		.take(n_vars)
+		.collect()
+}
+
+type MerkleProver = BinaryMerkleTreeProver<B128, Groestl256, Groestl256ByteCompression>;
+type MerkleScheme = BinaryMerkleTreeScheme<B128, Groestl256, Groestl256ByteCompression>;
+
+fn fri_pcs(
+	params: &Params,
+) -> (FRIPCSProver<B128, B32, MerkleProver>, FRIPCS<B128, B32, MerkleScheme>) {
+	(
+		FRIPCSProver::new(
+			MerkleProver::new(Groestl256ByteCompression),
+			params.log_inv_rate(),
+			params.security_bits(),
+		),
+		FRIPCS::new(
+			MerkleScheme::new(Groestl256ByteCompression),
+			params.log_inv_rate(),
+			params.security_bits(),
+		),
+	)
+}
+
+fn ligero_pcs(
+	params: &Params,
+) -> (LigeroPCSProver<B128, B32, MerkleProver>, LigeroPCS<B128, B32, MerkleScheme>) {
+	(
+		LigeroPCSProver::new(
+			MerkleProver::new(Groestl256ByteCompression),
+			params.log_inv_rate(),
+			params.security_bits(),
+		),
+		LigeroPCS::new(
+			MerkleScheme::new(Groestl256ByteCompression),
+			params.log_inv_rate(),
+			params.security_bits(),
+		),
+	)
+}
+
+/// Commits to random multilinears with the commitment scheme returned by `pcs`, opens them at a
+/// random point and verifies the evaluations.
+fn commit_open_verify<FSub, PCSProver, PCS>(
+	n_varss: &[usize],
+	pcs: impl FnOnce(&Params) -> (PCSProver, PCS),
+) -> Result<(), Error>
+where
+	FSub: TowerField,
+	U: PackScalar<FSub>,
+	PackedType<U, B128>: RepackedExtension<PackedType<U, FSub>>,
+	PCSProver: MultilinearPCSProver<PackedType<U, B128>, PCS = PCS>,
+	PCS: MultilinearPCS<B128>,
+{
+	let mut rng = StdRng::seed_from_u64(0);
+	let max_n_vars = n_varss.iter().copied().max().unwrap();
+
+	let params = setup(max_n_vars, FSub::TOWER_LEVEL, SECURITY_BITS)?;
+	let (pcs_prover, pcs) = pcs(&params);
+	let multilins = random_multilins::<FSub>(&mut rng, n_varss);
+	let (commitment, committed) =
+		commit_with_pcs::<PackedType<U, B128>, _, _, _, _>(&params, pcs_prover, &multilins)?;
+
+	let point = random_point(&mut rng, max_n_vars);
+	let (values, proof) = committed.open::<Challenger_>(&point)?;
+
+	for (multilin, value) in multilins.iter().zip(&values) {
+		let query = MultilinearQuery::<B128>::expand(&point[..multilin.n_vars()]);
+		assert_eq!(multilin.evaluate(&query).unwrap(), *value);
+	}
+
+	verify_with_pcs::<B128, _, Challenger_>(&params, &pcs, &commitment, &point, &values, proof)
+}
+
+#[test]
+fn test_commit_open_verify_single() {
+	commit_open_verify::<B16, _, _>(&[12], fri_pcs).unwrap();
+}
+
+#[test]
+fn test_commit_open_verify_batch_of_different_sizes() {
+	commit_open_verify::<B1, _, _>(&[10, 14, 2, 14, 8, 7], fri_pcs).unwrap();
+	commit_open_verify::<B8, _, _>(&[3, 11, 9, 11], fri_pcs).unwrap();
+	commit_open_verify::<B32, _, _>(&[14, 5, 14, 12], fri_pcs).unwrap();
+}
+
+#[test]
+fn test_commit_open_verify_extension_field() {
+	commit_open_verify::<B128, _, _>(&[6, 4, 9], fri_pcs).unwrap();
+}
+
+#[test]
+fn test_verify_rejects_incorrect_value() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let params = setup(10, B8::TOWER_LEVEL, SECURITY_BITS).unwrap();
+	let multilins = random_multilins::<B8>(&mut rng, &[10, 7]);
+	let (commitment, committed) =
+		commit::<U, B128, B8, _, Groestl256, Groestl256ByteCompression>(&params, &multilins)
+			.unwrap();
+
+	let point = random_point(&mut rng, 10);
+	let (mut values, proof) = committed.open::<Challenger_>(&point).unwrap();
+	values[1] += B128::ONE;
+
+	let result = verify::<B128, Groestl256, Groestl256ByteCompression, Challenger_>(
+		&params,
+		&commitment,
+		&point,
+		&values,
+		proof,
+	);
+	assert!(result.is_err());
+}
+
+#[test]
+fn test_serialized_commitment_and_proof_verify() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let params = setup(9, B1::TOWER_LEVEL, SECURITY_BITS)
+		.unwrap()
+		.with_log_inv_rate(2)
+		.unwrap();
+	let multilins = random_multilins::<B1>(&mut rng, &[9, 8]);
+	let (commitment, committed) =
+		commit::<U, B128, B1, _, Groestl256, Groestl256ByteCompression>(&params, &multilins)
+			.unwrap();
+	let point = random_point(&mut rng, 9);
+	let (values, proof) = committed.open::<Challenger_>(&point).unwrap();
+
+	let mut buf = Vec::new();
+	params
+		.serialize(&mut buf, SerializationMode::Native)
+		.unwrap();
+	commitment
+		.serialize(&mut buf, SerializationMode::Native)
+		.unwrap();
+	proof
+		.serialize(&mut buf, SerializationMode::Native)
+		.unwrap();
+
+	let mut read_buf = buf.as_slice();
+	let params = Params::deserialize(&mut read_buf, SerializationMode::Native).unwrap();
+	let commitment =
+		Commitment::<Output<Groestl256>>::deserialize(&mut read_buf, SerializationMode::Native)
+			.unwrap();
+	let proof = Proof::deserialize(&mut read_buf, SerializationMode::Native).unwrap();
+	assert!(read_buf.is_empty());
+
+	verify::<B128, Groestl256, Groestl256ByteCompression, Challenger_>(
+		&params,
+		&commitment,
+		&point,
+		&values,
+		proof,
+	)
+	.unwrap();
+}
+
+#[test]
+fn test_commit_rejects_mismatched_tower_level() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let params = setup(8, B8::TOWER_LEVEL, SECURITY_BITS).unwrap();
+	let multilins = random_multilins::<B16>(&mut rng, &[8]);
+	let result =
+		commit::<U, B128, B16, _, Groestl256, Groestl256ByteCompression>(&params, &multilins);
+	assert!(matches!(
+		result,
+		Err(Error::TowerLevelMismatch {
+			expected: 3,
+			got: 4
+		})
+	));
+}
+
+#[test]
+fn test_commit_rejects_too_many_variables() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let params = setup(8, B8::TOWER_LEVEL, SECURITY_BITS).unwrap();
+	let multilins = random_multilins::<B8>(&mut rng, &[8, 9]);
+	let result =
+		commit::<U, B128, B8, _, Groestl256, Groestl256ByteCompression>(&params, &multilins);
+	assert!(matches!(result, Err(Error::TooManyVariables { max: 8, got: 9 })));
+}
+
+#[test]
+fn test_ligero_commit_open_verify_batch_of_different_sizes() {
+	commit_open_verify::<B1, _, _>(&[10, 14, 2, 14, 8, 7], ligero_pcs).unwrap();
+	commit_open_verify::<B32, _, _>(&[14, 5, 14, 12], ligero_pcs).unwrap();
+}
+
+#[test]
+fn test_ligero_commit_open_verify_extension_field() {
+	commit_open_verify::<B128, _, _>(&[6, 4, 9], ligero_pcs).unwrap();
+}
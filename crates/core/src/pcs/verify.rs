@@ -0,0 +1,89 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_hash::PseudoCompressionFunction;
+use binius_math::{B32, PackedTop, TowerTop};
+use digest::{Digest, Output, OutputSizeUser, core_api::BlockSizeUser};
+
+use super::{Commitment, Error, Params, Proof, check_point_len, observe_opening};
+use crate::{
+	fiat_shamir::Challenger,
+	merkle_tree::BinaryMerkleTreeScheme,
+	piop::{FRIPCS, MultilinearPCS},
+	ring_switch::{self, EvalClaimSystem},
+	transcript::VerifierTranscript,
+};
+
+/// Verifies the evaluations of a batch of multilinears committed with [`super::commit`].
+pub fn verify<F, Hash, Compress, Challenger_>(
+	params: &Params,
+	commitment: &Commitment<Output<Hash>>,
+	point: &[F],
+	values: &[F],
+	proof: Proof,
+) -> Result<(), Error>
+where
+	F: TowerTop + PackedTop<Scalar = F>,
+	Hash: Digest + BlockSizeUser + OutputSizeUser,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+{
+	let pcs = FRIPCS::<F, B32, _>::new(
+		BinaryMerkleTreeScheme::<_, Hash, _>::new(Compress::default()),
+		params.log_inv_rate(),
+		params.security_bits(),
+	);
+	verify_with_pcs::<_, _, Challenger_>(params, &pcs, commitment, point, values, proof)
+}
+
+/// Verifies the evaluations of a batch of multilinears committed with
+/// [`super::commit_with_pcs`].
+pub fn verify_with_pcs<F, PCS, Challenger_>(
+	params: &Params,
+	pcs: &PCS,
+	commitment: &Commitment<PCS::Commitment>,
+	point: &[F],
+	values: &[F],
+	proof: Proof,
+) -> Result<(), Error>
+where
+	F: TowerTop + PackedTop<Scalar = F>,
+	PCS: MultilinearPCS<F>,
+	Challenger_: Challenger + Default,
+{
+	check_point_len(&commitment.n_vars, point)?;
+	if values.len() != commitment.n_vars.len() {
+		return Err(Error::IncorrectNumberOfValues {
+			expected: commitment.n_vars.len(),
+			got: values.len(),
+		});
+	}
+
+	let layout = params.layout::<F>(&commitment.n_vars)?;
+
+	let mut transcript = VerifierTranscript::<Challenger_>::new(proof.transcript);
+	observe_opening(transcript.observe(), params, commitment, point, values);
+
+	let eval_claims = layout.eval_claims(point, values);
+	let system = EvalClaimSystem::new(
+		&layout.oracles,
+		&layout.commit_meta,
+		&layout.oracle_to_commit_index,
+		&eval_claims,
+	)?;
+
+	let ring_switch::ReducedClaim {
+		transparents,
+		sumcheck_claims,
+	} = ring_switch::verify(&system, &mut transcript)?;
+
+	pcs.verify(
+		&layout.commit_meta,
+		&commitment.digest,
+		&transparents,
+		&sumcheck_claims,
+		&mut transcript,
+	)?;
+
+	transcript.finalize()?;
+	Ok(())
+}
@@ -5,7 +5,7 @@ use binius_compute::layer;
 use crate::{
 	oracle::OracleId,
 	polynomial,
-	protocols::{fri, sumcheck},
+	protocols::{fri, ligero, sumcheck},
 	reed_solomon, transcript, witness,
 };
 
@@ -37,6 +37,8 @@ pub enum Error {
 	Polynomial(#[from] polynomial::Error),
 	#[error("FRI error: {0}")]
 	FRI(#[from] fri::Error),
+	#[error("Ligero error: {0}")]
+	Ligero(#[from] ligero::Error),
 	#[error("Sumcheck error: {0}")]
 	Sumcheck(#[from] sumcheck::Error),
 	#[error("witness error: {0}")]
@@ -0,0 +1,288 @@
+// Copyright 2025 Irreducible Inc.
+
+//! The compilation of a multilinear polynomial IOP to an IP using a Ligero-style commitment.
+//!
+//! The committed batch is laid out exactly as in the FRI-Binius compiler, but the interpolating
+//! multilinear is committed with [`crate::protocols::ligero`] instead of FRI. The batched sumcheck
+//! on the PIOP claims runs to completion first, reducing the claims to an evaluation of the
+//! interpolating multilinear at the sumcheck challenges, which the prover then opens directly.
+
+use std::borrow::Borrow;
+
+use binius_compute::{
+	ComputeData, ComputeLayer, FSlice, SizedSlice, alloc::ComputeAllocator, cpu::CpuMemory,
+};
+use binius_field::{
+	BinaryField, ExtensionField, PackedExtension, PackedField, PackedFieldIndexable, TowerField,
+};
+use binius_math::MultilinearPoly;
+use binius_ntt::{AdditiveNTT, SingleThreadedNTT};
+use binius_utils::{
+	DeserializeBytes, SerializeBytes, checked_arithmetics::checked_log_2,
+	sorting::is_sorted_ascending,
+};
+use bytemuck::zeroed_vec;
+use tracing::instrument;
+
+use super::{
+	error::{Error, VerificationError},
+	prove::{
+		copy_packed_committed_to_device, make_sumcheck_provers, merge_multilins, packed_committed,
+	},
+	verify::{
+		CommitMeta, PIOPSumcheckClaim, make_sumcheck_claim_descs, make_sumcheck_claims,
+		verify_piecewise_eval,
+	},
+};
+use crate::{
+	fiat_shamir::{CanSample, Challenger},
+	merkle_tree::{MerkleTreeProver, MerkleTreeScheme},
+	oracle::OracleId,
+	polynomial::MultivariatePoly,
+	protocols::{
+		fri::CommitOutput,
+		ligero::{self, LigeroParams},
+		sumcheck::{
+			front_loaded::BatchVerifier as SumcheckBatchVerifier,
+			prove::front_loaded::BatchProver as SumcheckBatchProver,
+		},
+	},
+	transcript::{ProverTranscript, VerifierTranscript},
+};
+
+/// Choose Ligero parameters for a committed batch based on protocol parameters.
+///
+/// ## Arguments
+///
+/// * `commit_meta` - the metadata about the committed batch of multilinears.
+/// * `merkle_scheme` - the Merkle tree commitment scheme for the columns.
+/// * `security_bits` - the target security level in bits.
+/// * `log_inv_rate` - the binary logarithm of the inverse Reed–Solomon code rate.
+pub fn make_ligero_params<F, FEncode, MTScheme>(
+	commit_meta: &CommitMeta,
+	merkle_scheme: &MTScheme,
+	security_bits: usize,
+	log_inv_rate: usize,
+) -> Result<LigeroParams<F, FEncode>, Error>
+where
+	F: BinaryField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+	MTScheme: MerkleTreeScheme<F>,
+{
+	// As with FRI, choose the parameters from the NTT with the maximum domain size.
+	let ntt = SingleThreadedNTT::<FEncode>::new(FEncode::N_BITS)?;
+	let params = LigeroParams::choose_with_optimal_batch_size(
+		&ntt,
+		merkle_scheme,
+		commit_meta.total_vars(),
+		security_bits,
+		log_inv_rate,
+	)?;
+	Ok(params)
+}
+
+/// Commits a batch of multilinear polynomials with a Ligero commitment.
+///
+/// See [`super::commit`] for the requirements on the multilinears.
+pub fn commit<F, FEncode, P, M, NTT, MTScheme, MTProver>(
+	ligero_params: &LigeroParams<F, FEncode>,
+	ntt: &NTT,
+	merkle_prover: &MTProver,
+	multilins: &[M],
+) -> Result<CommitOutput<P, MTScheme::Digest, MTProver::Committed>, Error>
+where
+	F: TowerField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+	P: PackedField<Scalar = F> + PackedExtension<FEncode>,
+	M: MultilinearPoly<P>,
+	NTT: AdditiveNTT<FEncode> + Sync,
+	MTScheme: MerkleTreeScheme<F>,
+	MTProver: MerkleTreeProver<F, Scheme = MTScheme>,
+{
+	let packed_multilins = multilins
+		.iter()
+		.enumerate()
+		.map(|(i, unpacked_committed)| {
+			packed_committed(OracleId::from_index(i), unpacked_committed)
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+	if !is_sorted_ascending(packed_multilins.iter().map(|mle| mle.n_vars())) {
+		return Err(Error::CommittedsNotSorted);
+	}
+
+	let output = ligero::commit_with(ligero_params, ntt, merkle_prover, |message_buffer| {
+		merge_multilins(&packed_multilins, message_buffer)
+	})?;
+
+	Ok(output)
+}
+
+/// Proves a batch of sumcheck claims that are products of committed polynomials from a batch
+/// committed with [`commit`] and transparent polynomials.
+#[allow(clippy::too_many_arguments)]
+pub fn prove<
+	Hal,
+	F,
+	FEncode,
+	P,
+	M,
+	MTScheme,
+	MTProver,
+	Challenger_,
+	HostComputeAllocatorType,
+	DeviceComputeAllocatorType,
+>(
+	compute_data: &ComputeData<F, Hal, HostComputeAllocatorType, DeviceComputeAllocatorType>,
+	ligero_params: &LigeroParams<F, FEncode>,
+	merkle_prover: &MTProver,
+	commit_meta: &CommitMeta,
+	committed: MTProver::Committed,
+	codeword: &[P],
+	committed_multilins: &[M],
+	transparent_multilins: Vec<FSlice<'_, F, Hal>>,
+	claims: &[PIOPSumcheckClaim<F>],
+	transcript: &mut ProverTranscript<Challenger_>,
+) -> Result<(), Error>
+where
+	F: TowerField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+	P: PackedFieldIndexable<Scalar = F>,
+	M: MultilinearPoly<P> + Send + Sync,
+	MTScheme: MerkleTreeScheme<F, Digest: SerializeBytes>,
+	MTProver: MerkleTreeProver<F, Scheme = MTScheme>,
+	Challenger_: Challenger,
+	Hal: ComputeLayer<F>,
+	HostComputeAllocatorType: ComputeAllocator<F, CpuMemory>,
+	DeviceComputeAllocatorType: ComputeAllocator<F, Hal::DevMem>,
+{
+	let host_alloc = &compute_data.host_alloc;
+	let dev_alloc = &compute_data.dev_alloc;
+	let hal = compute_data.hal;
+
+	let sumcheck_claim_descs = make_sumcheck_claim_descs(
+		commit_meta,
+		transparent_multilins
+			.iter()
+			.map(|poly| checked_log_2(poly.len())),
+		claims,
+	)?;
+
+	let packed_committed_fslices =
+		copy_packed_committed_to_device(hal, dev_alloc, committed_multilins)?;
+	let sumcheck_provers = make_sumcheck_provers(
+		hal,
+		dev_alloc,
+		host_alloc,
+		&sumcheck_claim_descs,
+		&packed_committed_fslices,
+		&transparent_multilins,
+	)?;
+
+	let mut sumcheck_batch_prover = SumcheckBatchProver::new(sumcheck_provers, transcript)?;
+	let mut challenges = Vec::with_capacity(commit_meta.total_vars());
+	for round in 0..commit_meta.total_vars() {
+		let _span =
+			tracing::debug_span!("PIOP Compiler Round", phase = "piop_compiler", round = round)
+				.entered();
+
+		sumcheck_batch_prover.send_round_proof(&mut transcript.message())?;
+		let challenge = transcript.sample();
+		sumcheck_batch_prover.receive_challenge(challenge)?;
+		challenges.push(challenge);
+	}
+	sumcheck_batch_prover.finish(&mut transcript.message())?;
+
+	// The codeword no longer holds the message, so merge the committed multilinears again.
+	let packed_multilins = committed_multilins
+		.iter()
+		.enumerate()
+		.map(|(i, unpacked_committed)| {
+			packed_committed(OracleId::from_index(i), unpacked_committed)
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+	let mut message = zeroed_vec(1 << ligero_params.log_msg_len().saturating_sub(P::LOG_WIDTH));
+	merge_multilins(&packed_multilins, &mut message);
+
+	ligero::prove_evaluation(
+		ligero_params,
+		merkle_prover,
+		&message,
+		codeword,
+		&committed,
+		&challenges,
+		transcript,
+	)?;
+
+	Ok(())
+}
+
+/// Verifies a batch of sumcheck claims that are products of committed polynomials from a batch
+/// committed with [`commit`] and transparent polynomials.
+///
+/// See [`super::verify`] for the description of the arguments.
+#[instrument("piop::ligero::verify", skip_all)]
+pub fn verify<'a, F, FEncode, Challenger_, MTScheme>(
+	commit_meta: &CommitMeta,
+	merkle_scheme: &MTScheme,
+	ligero_params: &LigeroParams<F, FEncode>,
+	commitment: &MTScheme::Digest,
+	transparents: &[impl Borrow<dyn MultivariatePoly<F> + 'a>],
+	claims: &[PIOPSumcheckClaim<F>],
+	transcript: &mut VerifierTranscript<Challenger_>,
+) -> Result<(), Error>
+where
+	F: TowerField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+	Challenger_: Challenger,
+	MTScheme: MerkleTreeScheme<F, Digest: DeserializeBytes>,
+{
+	let sumcheck_claim_descs = make_sumcheck_claim_descs(
+		commit_meta,
+		transparents.iter().map(|poly| poly.borrow().n_vars()),
+		claims,
+	)?;
+	let sumcheck_claims = make_sumcheck_claims(&sumcheck_claim_descs)?;
+
+	let n_rounds = commit_meta.total_vars();
+	let mut sumcheck_verifier = SumcheckBatchVerifier::new(&sumcheck_claims, transcript)?;
+	let mut multilinear_evals = Vec::with_capacity(sumcheck_claims.len());
+	let mut challenges = Vec::with_capacity(n_rounds);
+	for _ in 0..n_rounds {
+		let mut reader = transcript.message();
+		while let Some(claim_multilinear_evals) = sumcheck_verifier.try_finish_claim(&mut reader)? {
+			multilinear_evals.push(claim_multilinear_evals);
+		}
+		sumcheck_verifier.receive_round_proof(&mut reader)?;
+
+		let challenge = transcript.sample();
+		challenges.push(challenge);
+		sumcheck_verifier.finish_round(challenge)?;
+	}
+
+	let mut reader = transcript.message();
+	while let Some(claim_multilinear_evals) = sumcheck_verifier.try_finish_claim(&mut reader)? {
+		multilinear_evals.push(claim_multilinear_evals);
+	}
+	sumcheck_verifier.finish()?;
+
+	// Verify the committed evals against the opened evaluation.
+	let piecewise_eval = verify_piecewise_eval(
+		commit_meta,
+		&sumcheck_claim_descs,
+		multilinear_evals,
+		transparents,
+		&challenges,
+	)?;
+	let ligero_eval = ligero::verify_evaluation(
+		ligero_params,
+		merkle_scheme,
+		commitment,
+		&challenges,
+		transcript,
+	)?;
+	if piecewise_eval != ligero_eval {
+		return Err(VerificationError::IncorrectSumcheckEvaluation.into());
+	}
+
+	Ok(())
+}
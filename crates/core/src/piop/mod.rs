@@ -29,11 +29,18 @@
 //! At the end of the interleaved sumcheck-FRI invocation, the verifier tests consistency of the
 //! claimed piecewise evaluations against the final FRI output.
 //!
+//! Callers that should not depend on this particular scheme go through the [`MultilinearPCS`] and
+//! [`MultilinearPCSProver`] traits, of which [`FRIPCS`] and [`FRIPCSProver`] wrap the functions in
+//! this module. The [`ligero`] submodule compiles the same PIOP with a Ligero-style commitment
+//! instead of FRI, wrapped by [`LigeroPCS`] and [`LigeroPCSProver`].
+//!
 //! [DP24]: <https://eprint.iacr.org/2024/504>
 
 pub mod commit;
 mod error;
+pub mod ligero;
 mod logging;
+mod pcs;
 mod prove;
 #[cfg(test)]
 mod tests;
@@ -42,5 +49,6 @@ mod verify;
 
 pub use commit::*;
 pub use error::*;
+pub use pcs::*;
 pub use prove::*;
 pub use verify::{CommitMeta, PIOPSumcheckClaim, make_commit_params_with_optimal_arity, verify};
@@ -0,0 +1,686 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Multilinear polynomial commitment schemes at the PIOP layer.
+//!
+//! The PIOP compiler reduces the evaluation claims on committed oracles to a batch of
+//! [`PIOPSumcheckClaim`]s, each a sum over the hypercube of a committed multilinear times a
+//! transparent multilinear. A [`MultilinearPCS`] is anything that can commit to a batch of
+//! multilinears laid out according to a [`CommitMeta`] and then prove such a batch of sumcheck
+//! claims against the commitment. [`FRIPCS`] is the FRI-Binius scheme from [DP24] and
+//! [`LigeroPCS`] is a Ligero-style tensor code scheme, which has faster proving and verification
+//! but larger proofs.
+//!
+//! As with [`MerkleTreeScheme`] and [`MerkleTreeProver`], the scheme is split into a verifier
+//! trait and a prover trait, with the prover naming its verifier through an associated type.
+//! Opening proofs are written to and read from the Fiat-Shamir transcript.
+//!
+//! [DP24]: <https://eprint.iacr.org/2024/504>
+
+use std::{borrow::Borrow, iter::Sum, marker::PhantomData, ops::Add};
+
+use binius_compute::{ComputeData, ComputeLayer, FSlice, alloc::ComputeAllocator, cpu::CpuMemory};
+use binius_field::{
+	BinaryField, ExtensionField, Field, PackedExtension, PackedField, PackedFieldIndexable,
+	TowerField,
+};
+use binius_math::MultilinearPoly;
+use binius_ntt::{MultithreadedNTT, SingleThreadedNTT, twiddle::PrecomputedTwiddleAccess};
+use binius_utils::{DeserializeBytes, SerializeBytes};
+
+use super::{
+	error::Error,
+	ligero::{self, make_ligero_params},
+	prove,
+	verify::{self, CommitMeta, PIOPSumcheckClaim, make_commit_params_with_optimal_arity},
+};
+use crate::{
+	fiat_shamir::Challenger,
+	merkle_tree::{MerkleTreeProver, MerkleTreeScheme},
+	polynomial::MultivariatePoly,
+	protocols::{
+		fri::{CommitOutput, FRIParams},
+		ligero::LigeroParams,
+	},
+	transcript::{ProverTranscript, VerifierTranscript},
+};
+
+/// An upper bound on the probability that a verifier accepts a false statement.
+///
+/// The proven bound follows from the proximity gap results the protocol analysis relies on, while
+/// the conjectured bound assumes the Reed–Solomon codes are list-decodable up to capacity.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct SoundnessError {
+	pub proven: f64,
+	pub conjectured: f64,
+}
+
+impl SoundnessError {
+	/// A soundness error with the same proven and conjectured bound.
+	pub const fn exact(error: f64) -> Self {
+		Self {
+			proven: error,
+			conjectured: error,
+		}
+	}
+
+	/// The proven number of bits of security, ie. $-\log_2$ of the proven error.
+	pub fn proven_bits(&self) -> f64 {
+		-self.proven.log2()
+	}
+
+	/// The conjectured number of bits of security, ie. $-\log_2$ of the conjectured error.
+	pub fn conjectured_bits(&self) -> f64 {
+		-self.conjectured.log2()
+	}
+}
+
+impl Add for SoundnessError {
+	type Output = Self;
+
+	fn add(self, rhs: Self) -> Self {
+		Self {
+			proven: self.proven + rhs.proven,
+			conjectured: self.conjectured + rhs.conjectured,
+		}
+	}
+}
+
+impl Sum for SoundnessError {
+	fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+		iter.fold(Self::default(), Add::add)
+	}
+}
+
+/// A commitment scheme that can report the soundness error of its opening proofs.
+pub trait PCSSoundness {
+	/// Returns the soundness error of proving evaluations of a committed batch, including the
+	/// sumcheck that reduces the PIOP claims to the opening.
+	fn soundness_error(&self, commit_meta: &CommitMeta) -> Result<SoundnessError, Error>;
+}
+
+/// The verifier side of a multilinear polynomial commitment scheme.
+pub trait MultilinearPCS<F: Field>: PCSSoundness {
+	/// The commitment to a batch of multilinears, sent to the verifier through the transcript.
+	type Commitment: Clone + SerializeBytes + DeserializeBytes;
+
+	/// Verifies a batch of sumcheck claims over products of committed and transparent
+	/// polynomials.
+	///
+	/// ## Arguments
+	///
+	/// * `commit_meta` - the metadata about the committed batch of multilinears.
+	/// * `commitment` - the commitment to the batch, as read from the transcript.
+	/// * `transparents` - the transparent polynomials referenced by the claims.
+	/// * `claims` - the sumcheck claims to verify.
+	/// * `transcript` - the verifier transcript to read the opening proof from.
+	fn verify<'a, Challenger_: Challenger>(
+		&self,
+		commit_meta: &CommitMeta,
+		commitment: &Self::Commitment,
+		transparents: &[impl Borrow<dyn MultivariatePoly<F> + 'a>],
+		claims: &[PIOPSumcheckClaim<F>],
+		transcript: &mut VerifierTranscript<Challenger_>,
+	) -> Result<(), Error>;
+}
+
+/// The prover side of a multilinear polynomial commitment scheme.
+pub trait MultilinearPCSProver<P: PackedField<Scalar: TowerField>>: PCSSoundness {
+	/// The verifier side of the scheme.
+	type PCS: MultilinearPCS<P::Scalar>;
+
+	/// Prover state for a committed batch, consumed when proving the openings.
+	type Committed;
+
+	/// Commits a batch of multilinear polynomials.
+	///
+	/// The multilinears may be defined over subfields of the scalar field and must be in
+	/// ascending order by the number of variables in the packed multilinear, matching
+	/// `commit_meta`.
+	#[allow(clippy::type_complexity)]
+	fn commit<M>(
+		&self,
+		commit_meta: &CommitMeta,
+		multilins: &[M],
+	) -> Result<(<Self::PCS as MultilinearPCS<P::Scalar>>::Commitment, Self::Committed), Error>
+	where
+		M: MultilinearPoly<P> + Send + Sync;
+
+	/// Proves a batch of sumcheck claims over products of committed and transparent
+	/// polynomials.
+	///
+	/// The arguments corresponding to the committed multilinears must be those passed to and
+	/// returned by [`Self::commit`].
+	#[allow(clippy::too_many_arguments)]
+	fn prove<Hal, M, Challenger_, HostComputeAllocatorType, DeviceComputeAllocatorType>(
+		&self,
+		compute_data: &ComputeData<
+			P::Scalar,
+			Hal,
+			HostComputeAllocatorType,
+			DeviceComputeAllocatorType,
+		>,
+		commit_meta: &CommitMeta,
+		committed: Self::Committed,
+		committed_multilins: &[M],
+		transparent_multilins: Vec<FSlice<'_, P::Scalar, Hal>>,
+		claims: &[PIOPSumcheckClaim<P::Scalar>],
+		transcript: &mut ProverTranscript<Challenger_>,
+	) -> Result<(), Error>
+	where
+		Hal: ComputeLayer<P::Scalar>,
+		M: MultilinearPoly<P> + Send + Sync,
+		Challenger_: Challenger,
+		HostComputeAllocatorType: ComputeAllocator<P::Scalar, CpuMemory>,
+		DeviceComputeAllocatorType: ComputeAllocator<P::Scalar, Hal::DevMem>;
+}
+
+/// The FRI-Binius polynomial commitment scheme, verifier side.
+///
+/// The FRI parameters are derived from the commit metadata, the target security level and the
+/// Reed–Solomon rate with [`make_commit_params_with_optimal_arity`].
+#[derive(Debug)]
+pub struct FRIPCS<F, FEncode, MTScheme> {
+	merkle_scheme: MTScheme,
+	log_inv_rate: usize,
+	security_bits: usize,
+	_marker: PhantomData<(F, FEncode)>,
+}
+
+impl<F, FEncode, MTScheme> FRIPCS<F, FEncode, MTScheme>
+where
+	F: BinaryField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+	MTScheme: MerkleTreeScheme<F>,
+{
+	pub fn new(merkle_scheme: MTScheme, log_inv_rate: usize, security_bits: usize) -> Self {
+		Self {
+			merkle_scheme,
+			log_inv_rate,
+			security_bits,
+			_marker: PhantomData,
+		}
+	}
+
+	pub fn merkle_scheme(&self) -> &MTScheme {
+		&self.merkle_scheme
+	}
+
+	/// Returns the FRI parameters used for a committed batch.
+	pub fn fri_params(&self, commit_meta: &CommitMeta) -> Result<FRIParams<F, FEncode>, Error> {
+		make_commit_params_with_optimal_arity(
+			commit_meta,
+			&self.merkle_scheme,
+			self.security_bits,
+			self.log_inv_rate,
+		)
+	}
+}
+
+impl<F, FEncode, MTScheme> PCSSoundness for FRIPCS<F, FEncode, MTScheme>
+where
+	F: BinaryField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+	MTScheme: MerkleTreeScheme<F>,
+{
+	fn soundness_error(&self, commit_meta: &CommitMeta) -> Result<SoundnessError, Error> {
+		Ok(fri_soundness_error(&self.fri_params(commit_meta)?))
+	}
+}
+
+impl<F, FEncode, MTScheme> MultilinearPCS<F> for FRIPCS<F, FEncode, MTScheme>
+where
+	F: TowerField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+	MTScheme: MerkleTreeScheme<F, Digest: SerializeBytes + DeserializeBytes>,
+{
+	type Commitment = MTScheme::Digest;
+
+	fn verify<'a, Challenger_: Challenger>(
+		&self,
+		commit_meta: &CommitMeta,
+		commitment: &Self::Commitment,
+		transparents: &[impl Borrow<dyn MultivariatePoly<F> + 'a>],
+		claims: &[PIOPSumcheckClaim<F>],
+		transcript: &mut VerifierTranscript<Challenger_>,
+	) -> Result<(), Error> {
+		let fri_params = self.fri_params(commit_meta)?;
+		verify::verify(
+			commit_meta,
+			&self.merkle_scheme,
+			&fri_params,
+			commitment,
+			transparents,
+			claims,
+			transcript,
+		)
+	}
+}
+
+/// The FRI-Binius polynomial commitment scheme, prover side.
+#[derive(Debug)]
+pub struct FRIPCSProver<F, FEncode, MTProver> {
+	merkle_prover: MTProver,
+	log_inv_rate: usize,
+	security_bits: usize,
+	_marker: PhantomData<(F, FEncode)>,
+}
+
+impl<F, FEncode, MTProver> FRIPCSProver<F, FEncode, MTProver>
+where
+	F: BinaryField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+	MTProver: MerkleTreeProver<F>,
+{
+	pub fn new(merkle_prover: MTProver, log_inv_rate: usize, security_bits: usize) -> Self {
+		Self {
+			merkle_prover,
+			log_inv_rate,
+			security_bits,
+			_marker: PhantomData,
+		}
+	}
+
+	pub fn merkle_prover(&self) -> &MTProver {
+		&self.merkle_prover
+	}
+
+	/// Returns the FRI parameters used for a committed batch.
+	pub fn fri_params(&self, commit_meta: &CommitMeta) -> Result<FRIParams<F, FEncode>, Error> {
+		make_commit_params_with_optimal_arity(
+			commit_meta,
+			self.merkle_prover.scheme(),
+			self.security_bits,
+			self.log_inv_rate,
+		)
+	}
+}
+
+impl<F, FEncode, MTProver> PCSSoundness for FRIPCSProver<F, FEncode, MTProver>
+where
+	F: BinaryField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+	MTProver: MerkleTreeProver<F>,
+{
+	fn soundness_error(&self, commit_meta: &CommitMeta) -> Result<SoundnessError, Error> {
+		Ok(fri_soundness_error(&self.fri_params(commit_meta)?))
+	}
+}
+
+/// Prover state for a batch committed with [`FRIPCSProver`].
+///
+/// The encoding NTT is kept alongside the codeword so that its twiddles are only computed once
+/// per batch.
+#[derive(Debug)]
+pub struct FRICommitted<F: BinaryField, FEncode: BinaryField, P, Committed> {
+	fri_params: FRIParams<F, FEncode>,
+	ntt: MultithreadedNTT<FEncode, PrecomputedTwiddleAccess<FEncode>>,
+	committed: Committed,
+	codeword: Vec<P>,
+}
+
+impl<FEncode, P, MTProver> MultilinearPCSProver<P> for FRIPCSProver<P::Scalar, FEncode, MTProver>
+where
+	FEncode: BinaryField,
+	P: PackedField<Scalar: TowerField + ExtensionField<FEncode>>
+		+ PackedExtension<<P as PackedField>::Scalar, PackedSubfield = P>
+		+ PackedExtension<FEncode>
+		+ PackedFieldIndexable,
+	MTProver: MerkleTreeProver<
+			P::Scalar,
+			Scheme: MerkleTreeScheme<P::Scalar, Digest: SerializeBytes + DeserializeBytes>,
+		>,
+{
+	type PCS = FRIPCS<P::Scalar, FEncode, MTProver::Scheme>;
+	type Committed = FRICommitted<P::Scalar, FEncode, P, MTProver::Committed>;
+
+	fn commit<M>(
+		&self,
+		commit_meta: &CommitMeta,
+		multilins: &[M],
+	) -> Result<(<Self::PCS as MultilinearPCS<P::Scalar>>::Commitment, Self::Committed), Error>
+	where
+		M: MultilinearPoly<P> + Send + Sync,
+	{
+		let fri_params = self.fri_params(commit_meta)?;
+		let ntt = SingleThreadedNTT::with_subspace(fri_params.rs_code().subspace())?
+			.precompute_twiddles()
+			.multithreaded();
+
+		let CommitOutput {
+			commitment,
+			committed,
+			codeword,
+		} = prove::commit(&fri_params, &ntt, &self.merkle_prover, multilins)?;
+
+		let committed = FRICommitted {
+			fri_params,
+			ntt,
+			committed,
+			codeword,
+		};
+		Ok((commitment, committed))
+	}
+
+	fn prove<Hal, M, Challenger_, HostComputeAllocatorType, DeviceComputeAllocatorType>(
+		&self,
+		compute_data: &ComputeData<
+			P::Scalar,
+			Hal,
+			HostComputeAllocatorType,
+			DeviceComputeAllocatorType,
+		>,
+		commit_meta: &CommitMeta,
+		committed: Self::Committed,
+		committed_multilins: &[M],
+		transparent_multilins: Vec<FSlice<'_, P::Scalar, Hal>>,
+		claims: &[PIOPSumcheckClaim<P::Scalar>],
+		transcript: &mut ProverTranscript<Challenger_>,
+	) -> Result<(), Error>
+	where
+		Hal: ComputeLayer<P::Scalar>,
+		M: MultilinearPoly<P> + Send + Sync,
+		Challenger_: Challenger,
+		HostComputeAllocatorType: ComputeAllocator<P::Scalar, CpuMemory>,
+		DeviceComputeAllocatorType: ComputeAllocator<P::Scalar, Hal::DevMem>,
+	{
+		let FRICommitted {
+			fri_params,
+			ntt,
+			committed,
+			codeword,
+		} = committed;
+
+		prove::prove(
+			compute_data,
+			&fri_params,
+			&ntt,
+			&self.merkle_prover,
+			commit_meta,
+			committed,
+			&codeword,
+			committed_multilins,
+			transparent_multilins,
+			claims,
+			transcript,
+		)
+	}
+}
+
+/// A Ligero-style polynomial commitment scheme, verifier side.
+///
+/// The Ligero parameters are derived from the commit metadata, the target security level and the
+/// Reed–Solomon rate with [`make_ligero_params`].
+#[derive(Debug)]
+pub struct LigeroPCS<F, FEncode, MTScheme> {
+	merkle_scheme: MTScheme,
+	log_inv_rate: usize,
+	security_bits: usize,
+	_marker: PhantomData<(F, FEncode)>,
+}
+
+impl<F, FEncode, MTScheme> LigeroPCS<F, FEncode, MTScheme>
+where
+	F: BinaryField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+	MTScheme: MerkleTreeScheme<F>,
+{
+	pub fn new(merkle_scheme: MTScheme, log_inv_rate: usize, security_bits: usize) -> Self {
+		Self {
+			merkle_scheme,
+			log_inv_rate,
+			security_bits,
+			_marker: PhantomData,
+		}
+	}
+
+	pub fn merkle_scheme(&self) -> &MTScheme {
+		&self.merkle_scheme
+	}
+
+	/// Returns the Ligero parameters used for a committed batch.
+	pub fn ligero_params(
+		&self,
+		commit_meta: &CommitMeta,
+	) -> Result<LigeroParams<F, FEncode>, Error> {
+		make_ligero_params(commit_meta, &self.merkle_scheme, self.security_bits, self.log_inv_rate)
+	}
+}
+
+impl<F, FEncode, MTScheme> PCSSoundness for LigeroPCS<F, FEncode, MTScheme>
+where
+	F: BinaryField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+	MTScheme: MerkleTreeScheme<F>,
+{
+	fn soundness_error(&self, commit_meta: &CommitMeta) -> Result<SoundnessError, Error> {
+		Ok(ligero_soundness_error(&self.ligero_params(commit_meta)?))
+	}
+}
+
+impl<F, FEncode, MTScheme> MultilinearPCS<F> for LigeroPCS<F, FEncode, MTScheme>
+where
+	F: TowerField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+	MTScheme: MerkleTreeScheme<F, Digest: SerializeBytes + DeserializeBytes>,
+{
+	type Commitment = MTScheme::Digest;
+
+	fn verify<'a, Challenger_: Challenger>(
+		&self,
+		commit_meta: &CommitMeta,
+		commitment: &Self::Commitment,
+		transparents: &[impl Borrow<dyn MultivariatePoly<F> + 'a>],
+		claims: &[PIOPSumcheckClaim<F>],
+		transcript: &mut VerifierTranscript<Challenger_>,
+	) -> Result<(), Error> {
+		let ligero_params = self.ligero_params(commit_meta)?;
+		ligero::verify(
+			commit_meta,
+			&self.merkle_scheme,
+			&ligero_params,
+			commitment,
+			transparents,
+			claims,
+			transcript,
+		)
+	}
+}
+
+/// A Ligero-style polynomial commitment scheme, prover side.
+#[derive(Debug)]
+pub struct LigeroPCSProver<F, FEncode, MTProver> {
+	merkle_prover: MTProver,
+	log_inv_rate: usize,
+	security_bits: usize,
+	_marker: PhantomData<(F, FEncode)>,
+}
+
+impl<F, FEncode, MTProver> LigeroPCSProver<F, FEncode, MTProver>
+where
+	F: BinaryField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+	MTProver: MerkleTreeProver<F>,
+{
+	pub fn new(merkle_prover: MTProver, log_inv_rate: usize, security_bits: usize) -> Self {
+		Self {
+			merkle_prover,
+			log_inv_rate,
+			security_bits,
+			_marker: PhantomData,
+		}
+	}
+
+	pub fn merkle_prover(&self) -> &MTProver {
+		&self.merkle_prover
+	}
+
+	/// Returns the Ligero parameters used for a committed batch.
+	pub fn ligero_params(
+		&self,
+		commit_meta: &CommitMeta,
+	) -> Result<LigeroParams<F, FEncode>, Error> {
+		make_ligero_params(
+			commit_meta,
+			self.merkle_prover.scheme(),
+			self.security_bits,
+			self.log_inv_rate,
+		)
+	}
+}
+
+impl<F, FEncode, MTProver> PCSSoundness for LigeroPCSProver<F, FEncode, MTProver>
+where
+	F: BinaryField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+	MTProver: MerkleTreeProver<F>,
+{
+	fn soundness_error(&self, commit_meta: &CommitMeta) -> Result<SoundnessError, Error> {
+		Ok(ligero_soundness_error(&self.ligero_params(commit_meta)?))
+	}
+}
+
+/// Prover state for a batch committed with [`LigeroPCSProver`].
+#[derive(Debug)]
+pub struct LigeroCommitted<F: BinaryField, FEncode: BinaryField, P, Committed> {
+	ligero_params: LigeroParams<F, FEncode>,
+	committed: Committed,
+	codeword: Vec<P>,
+}
+
+impl<FEncode, P, MTProver> MultilinearPCSProver<P> for LigeroPCSProver<P::Scalar, FEncode, MTProver>
+where
+	FEncode: BinaryField,
+	P: PackedField<Scalar: TowerField + ExtensionField<FEncode>>
+		+ PackedExtension<FEncode>
+		+ PackedFieldIndexable,
+	MTProver: MerkleTreeProver<
+			P::Scalar,
+			Scheme: MerkleTreeScheme<P::Scalar, Digest: SerializeBytes + DeserializeBytes>,
+		>,
+{
+	type PCS = LigeroPCS<P::Scalar, FEncode, MTProver::Scheme>;
+	type Committed = LigeroCommitted<P::Scalar, FEncode, P, MTProver::Committed>;
+
+	fn commit<M>(
+		&self,
+		commit_meta: &CommitMeta,
+		multilins: &[M],
+	) -> Result<(<Self::PCS as MultilinearPCS<P::Scalar>>::Commitment, Self::Committed), Error>
+	where
+		M: MultilinearPoly<P> + Send + Sync,
+	{
+		let ligero_params = self.ligero_params(commit_meta)?;
+		let ntt = SingleThreadedNTT::with_subspace(ligero_params.rs_code().subspace())?
+			.precompute_twiddles()
+			.multithreaded();
+
+		let CommitOutput {
+			commitment,
+			committed,
+			codeword,
+		} = ligero::commit(&ligero_params, &ntt, &self.merkle_prover, multilins)?;
+
+		let committed = LigeroCommitted {
+			ligero_params,
+			committed,
+			codeword,
+		};
+		Ok((commitment, committed))
+	}
+
+	fn prove<Hal, M, Challenger_, HostComputeAllocatorType, DeviceComputeAllocatorType>(
+		&self,
+		compute_data: &ComputeData<
+			P::Scalar,
+			Hal,
+			HostComputeAllocatorType,
+			DeviceComputeAllocatorType,
+		>,
+		commit_meta: &CommitMeta,
+		committed: Self::Committed,
+		committed_multilins: &[M],
+		transparent_multilins: Vec<FSlice<'_, P::Scalar, Hal>>,
+		claims: &[PIOPSumcheckClaim<P::Scalar>],
+		transcript: &mut ProverTranscript<Challenger_>,
+	) -> Result<(), Error>
+	where
+		Hal: ComputeLayer<P::Scalar>,
+		M: MultilinearPoly<P> + Send + Sync,
+		Challenger_: Challenger,
+		HostComputeAllocatorType: ComputeAllocator<P::Scalar, CpuMemory>,
+		DeviceComputeAllocatorType: ComputeAllocator<P::Scalar, Hal::DevMem>,
+	{
+		let LigeroCommitted {
+			ligero_params,
+			committed,
+			codeword,
+		} = committed;
+
+		ligero::prove(
+			compute_data,
+			&ligero_params,
+			&self.merkle_prover,
+			commit_meta,
+			committed,
+			&codeword,
+			committed_multilins,
+			transparent_multilins,
+			claims,
+			transcript,
+		)
+	}
+}
+
+/// The soundness error of the FRI-Binius opening, following the same analysis as
+/// [`crate::protocols::fri::calculate_n_test_queries`].
+///
+/// Each query catches a far codeword with probability at least $(1 - \rho) / 2$ within the
+/// unique decoding radius, or $1 - \rho$ assuming list-decodability up to capacity.
+fn fri_soundness_error<F, FEncode>(fri_params: &FRIParams<F, FEncode>) -> SoundnessError
+where
+	F: BinaryField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+{
+	let code = fri_params.rs_code();
+	let field_size = 2.0_f64.powi(F::N_BITS as i32);
+	let n_vars = code.log_dim() + fri_params.log_batch_size();
+	let sumcheck_err = (2 * n_vars) as f64 / field_size;
+	// Every fold round pays |S^{(i)}| / |F| for the domain it folds. The rounds which batch the
+	// interleaved codeword all fold over the initial domain.
+	let folding_err = (0..fri_params.n_fold_rounds())
+		.map(|round| code.len() >> round.saturating_sub(fri_params.log_batch_size()))
+		.sum::<usize>() as f64
+		/ field_size;
+
+	let n_queries = fri_params.n_test_queries() as i32;
+	let rate = 2.0_f64.powi(-(code.log_inv_rate() as i32));
+	let proven_query_err = (0.5 * (1.0 + rate)).powi(n_queries);
+	let conjectured_query_err = rate.powi(n_queries);
+	SoundnessError {
+		proven: sumcheck_err + folding_err + proven_query_err,
+		conjectured: sumcheck_err + folding_err + conjectured_query_err,
+	}
+}
+
+/// The soundness error of the Ligero opening, following the same analysis as
+/// [`crate::protocols::ligero::calculate_n_test_queries`].
+fn ligero_soundness_error<F, FEncode>(ligero_params: &LigeroParams<F, FEncode>) -> SoundnessError
+where
+	F: BinaryField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+{
+	let code = ligero_params.rs_code();
+	let field_size = 2.0_f64.powi(F::N_BITS as i32);
+	let n_vars = code.log_dim() + ligero_params.log_batch_size();
+	let sumcheck_err = (2 * n_vars) as f64 / field_size;
+	let proximity_gap_err = (2 * ligero_params.log_batch_size() * code.len()) as f64 / field_size;
+
+	let n_queries = ligero_params.n_test_queries() as i32;
+	let relative_distance = (code.len() - code.dim() + 1) as f64 / code.len() as f64;
+	let proven_query_err = (1.0 - relative_distance / 3.0).powi(n_queries);
+	let conjectured_query_err = (1.0 - relative_distance).powi(n_queries);
+	SoundnessError {
+		proven: sumcheck_err + proximity_gap_err + proven_query_err,
+		conjectured: sumcheck_err + proximity_gap_err + conjectured_query_err,
+	}
+}
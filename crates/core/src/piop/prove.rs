@@ -24,7 +24,9 @@ use itertools::{Itertools, chain};
 
 use super::{
 	error::Error,
-	verify::{PIOPSumcheckClaim, make_sumcheck_claim_descs},
+	verify::{
+		PIOPSumcheckClaim, SumcheckClaimDesc, make_sumcheck_claim_descs, non_empty_sumcheck_descs,
+	},
 };
 use crate::{
 	fiat_shamir::{CanSample, Challenger},
@@ -75,7 +77,7 @@ fn reverse_index_bits<T: Copy>(collection: &mut impl RandomAccessSequenceMut<T>)
 // * multilinears are sorted in ascending order by number of packed variables
 // * `message_buffer` is initialized to all zeros
 // * `message_buffer` is larger than the total number of scalars in the multilinears
-fn merge_multilins<F, P, Data>(
+pub(super) fn merge_multilins<F, P, Data>(
 	multilins: &[MultilinearExtension<P, Data>],
 	message_buffer: &mut [P],
 ) where
@@ -222,9 +224,50 @@ where
 		claims,
 	)?;
 
-	// The committed multilinears provided by argument are committed *small field* multilinears.
-	// Create multilinears representing the packed polynomials here. Eventually, we would like to
-	// refactor the calling code so that the PIOP only handles *big field* multilinear witnesses.
+	let packed_committed_fslices =
+		copy_packed_committed_to_device(hal, dev_alloc, committed_multilins)?;
+	let sumcheck_provers = make_sumcheck_provers(
+		hal,
+		dev_alloc,
+		host_alloc,
+		&sumcheck_claim_descs,
+		&packed_committed_fslices,
+		&transparent_multilins,
+	)?;
+
+	prove_interleaved_fri_sumcheck(
+		hal,
+		dev_alloc,
+		commit_meta.total_vars(),
+		fri_params,
+		ntt,
+		merkle_prover,
+		sumcheck_provers,
+		codeword,
+		&committed,
+		transcript,
+	)?;
+
+	Ok(())
+}
+
+/// Copies the packed committed multilinears to device memory.
+///
+/// The committed multilinears provided by argument are committed *small field* multilinears.
+/// This creates multilinears representing the packed polynomials. Eventually, we would like to
+/// refactor the calling code so that the PIOP only handles *big field* multilinear witnesses.
+pub(super) fn copy_packed_committed_to_device<'a, Hal, F, P, M, DeviceComputeAllocatorType>(
+	hal: &Hal,
+	dev_alloc: &'a DeviceComputeAllocatorType,
+	committed_multilins: &[M],
+) -> Result<Vec<FSlice<'a, F, Hal>>, Error>
+where
+	F: TowerField,
+	P: PackedFieldIndexable<Scalar = F>,
+	M: MultilinearPoly<P>,
+	Hal: ComputeLayer<F>,
+	DeviceComputeAllocatorType: ComputeAllocator<F, Hal::DevMem>,
+{
 	let packed_committed_multilins = committed_multilins
 		.iter()
 		.enumerate()
@@ -234,13 +277,13 @@ where
 		})
 		.collect::<Result<Vec<_>, _>>()?;
 
-	let copy_span = tracing::debug_span!(
+	let _copy_span = tracing::debug_span!(
 		"[task] Copy polynomials to device memory",
 		phase = "piop_compiler",
 		perfetto_category = "phase.sub",
 	)
 	.entered();
-	let packed_committed_fslices = packed_committed_multilins
+	packed_committed_multilins
 		.iter()
 		.map(|packed_committed_multilin| {
 			let hypercube_evals = packed_committed_multilin
@@ -255,51 +298,63 @@ where
 			)?;
 			Ok(Hal::DevMem::to_const(allocated_mem))
 		})
-		.collect::<Result<Vec<_>, Error>>()?;
-
-	drop(copy_span);
-
-	let non_empty_sumcheck_descs = sumcheck_claim_descs
-		.iter()
-		.enumerate()
-		// Keep sumcheck claims with >0 committed multilinears, even with 0 composite claims. This
-		// indicates unconstrained columns, but we still need the final evaluations from the
-		// sumcheck prover in order to derive the final FRI value.
-		.filter(|(_, desc)| !desc.committed_indices.is_empty());
-
-	let mut sumcheck_provers = vec![];
-
-	for (n_vars, desc) in non_empty_sumcheck_descs {
-		let multilins = chain!(
-			packed_committed_fslices[desc.committed_indices.clone()]
-				.iter()
-				.map(|fslice| Hal::DevMem::narrow(fslice)),
-			transparent_multilins[desc.transparent_indices.clone()]
-				.iter()
-				.map(|fslice| Hal::DevMem::narrow(fslice))
-		)
-		.collect::<Vec<_>>();
-
-		let claim = SumcheckClaim::new(n_vars, multilins.len(), desc.composite_sums.clone())?;
-
-		sumcheck_provers
-			.push(BivariateSumcheckProver::new(hal, dev_alloc, host_alloc, &claim, multilins)?);
-	}
+		.collect::<Result<Vec<_>, Error>>()
+}
 
-	prove_interleaved_fri_sumcheck(
-		hal,
-		dev_alloc,
-		commit_meta.total_vars(),
-		fri_params,
-		ntt,
-		merkle_prover,
-		sumcheck_provers,
-		codeword,
-		&committed,
-		transcript,
-	)?;
+/// Creates a bivariate sumcheck prover for each number of variables with committed multilinears.
+#[allow(clippy::type_complexity)]
+pub(super) fn make_sumcheck_provers<
+	'a,
+	'b,
+	Hal,
+	F,
+	HostComputeAllocatorType,
+	DeviceComputeAllocatorType,
+>(
+	hal: &'a Hal,
+	dev_alloc: &'a DeviceComputeAllocatorType,
+	host_alloc: &'a HostComputeAllocatorType,
+	sumcheck_claim_descs: &[SumcheckClaimDesc<F>],
+	packed_committed_fslices: &'b [FSlice<'_, F, Hal>],
+	transparent_multilins: &'b [FSlice<'_, F, Hal>],
+) -> Result<
+	Vec<
+		BivariateSumcheckProver<
+			'a,
+			'b,
+			F,
+			Hal,
+			DeviceComputeAllocatorType,
+			HostComputeAllocatorType,
+		>,
+	>,
+	Error,
+>
+where
+	'a: 'b,
+	F: TowerField,
+	Hal: ComputeLayer<F>,
+	HostComputeAllocatorType: ComputeAllocator<F, CpuMemory>,
+	DeviceComputeAllocatorType: ComputeAllocator<F, Hal::DevMem>,
+{
+	non_empty_sumcheck_descs(sumcheck_claim_descs)
+		.map(|(n_vars, desc)| {
+			let multilins = chain!(
+				packed_committed_fslices[desc.committed_indices.clone()]
+					.iter()
+					.map(|fslice| Hal::DevMem::narrow(fslice)),
+				transparent_multilins[desc.transparent_indices.clone()]
+					.iter()
+					.map(|fslice| Hal::DevMem::narrow(fslice))
+			)
+			.collect::<Vec<_>>();
 
-	Ok(())
+			let claim = SumcheckClaim::new(n_vars, multilins.len(), desc.composite_sums.clone())?;
+			let prover =
+				BivariateSumcheckProver::new(hal, dev_alloc, host_alloc, &claim, multilins)?;
+			Ok(prover)
+		})
+		.collect()
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -458,7 +513,7 @@ where
 /// $n < \kappa$, which is when a polynomial is too full to have even a single packed evaluation,
 /// the polynomial is extended by padding with more variables, which corresponds to repeating its
 /// subcube evaluations.
-fn packed_committed<F, P, M>(
+pub(super) fn packed_committed<F, P, M>(
 	id: OracleId,
 	unpacked_committed: &M,
 ) -> Result<MultilinearExtension<P, Cow<'_, [P]>>, Error>
@@ -305,25 +305,7 @@ where
 		claims,
 	)?;
 
-	let non_empty_sumcheck_descs = sumcheck_claim_descs
-		.iter()
-		.enumerate()
-		// Keep sumcheck claims with >0 committed multilinears, even with 0 composite claims. This
-		// indicates unconstrained columns, but we still need the final evaluations from the
-		// sumcheck prover in order to derive the final FRI value.
-		.filter(|(_n_vars, desc)| !desc.committed_indices.is_empty());
-	let sumcheck_claims = non_empty_sumcheck_descs
-		.clone()
-		.map(|(n_vars, desc)| {
-			// Make a single sumcheck claim with compositions of the committed and transparent
-			// polynomials with `n_vars` variables
-			SumcheckClaim::new(
-				n_vars,
-				desc.committed_indices.len() + desc.transparent_indices.len(),
-				desc.composite_sums.clone(),
-			)
-		})
-		.collect::<Result<Vec<_>, _>>()?;
+	let sumcheck_claims = make_sumcheck_claims(&sumcheck_claim_descs)?;
 
 	// Interleaved front-loaded sumcheck
 	let BatchInterleavedSumcheckFRIOutput {
@@ -339,27 +321,78 @@ where
 		transcript,
 	)?;
 
-	let mut piecewise_evals = verify_transparent_evals(
+	// Verify the committed evals against the FRI final value.
+	let piecewise_eval = verify_piecewise_eval(
 		commit_meta,
-		non_empty_sumcheck_descs,
+		&sumcheck_claim_descs,
 		multilinear_evals,
 		transparents,
 		&challenges,
 	)?;
+	if piecewise_eval != fri_final {
+		return Err(VerificationError::IncorrectSumcheckEvaluation.into());
+	}
+
+	Ok(())
+}
+
+/// Returns the sumcheck claim descriptions that have committed multilinears, along with their
+/// number of variables.
+pub(super) fn non_empty_sumcheck_descs<F: Field>(
+	sumcheck_claim_descs: &[SumcheckClaimDesc<F>],
+) -> impl Iterator<Item = (usize, &SumcheckClaimDesc<F>)> {
+	sumcheck_claim_descs
+		.iter()
+		.enumerate()
+		// Keep sumcheck claims with >0 committed multilinears, even with 0 composite claims. This
+		// indicates unconstrained columns, but we still need the final evaluations from the
+		// sumcheck prover in order to derive the final opening value.
+		.filter(|(_n_vars, desc)| !desc.committed_indices.is_empty())
+}
+
+/// Makes a single sumcheck claim for each number of variables with committed multilinears, with
+/// compositions of the committed and transparent polynomials with that number of variables.
+pub(super) fn make_sumcheck_claims<F: Field>(
+	sumcheck_claim_descs: &[SumcheckClaimDesc<F>],
+) -> Result<Vec<SumcheckClaim<F, IndexComposition<BivariateProduct, 2>>>, Error> {
+	let claims = non_empty_sumcheck_descs(sumcheck_claim_descs)
+		.map(|(n_vars, desc)| {
+			SumcheckClaim::new(
+				n_vars,
+				desc.committed_indices.len() + desc.transparent_indices.len(),
+				desc.composite_sums.clone(),
+			)
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+	Ok(claims)
+}
+
+/// Verifies the transparent evaluations output by the batched sumcheck and returns the
+/// evaluation of the committed batch, as a single interpolating multilinear, at the sumcheck
+/// challenges.
+pub(super) fn verify_piecewise_eval<'a, F: Field>(
+	commit_meta: &CommitMeta,
+	sumcheck_claim_descs: &[SumcheckClaimDesc<F>],
+	multilinear_evals: Vec<Vec<F>>,
+	transparents: &[impl Borrow<dyn MultivariatePoly<F> + 'a>],
+	challenges: &[F],
+) -> Result<F, Error> {
+	let mut piecewise_evals = verify_transparent_evals(
+		commit_meta,
+		non_empty_sumcheck_descs(sumcheck_claim_descs),
+		multilinear_evals,
+		transparents,
+		challenges,
+	)?;
 
-	// Verify the committed evals against the FRI final value.
 	piecewise_evals.reverse();
 	let n_pieces_by_vars = sumcheck_claim_descs
 		.iter()
 		.map(|desc| desc.n_committed())
 		.collect::<Vec<_>>();
 	let piecewise_eval =
-		evaluate_piecewise_multilinear(&challenges, &n_pieces_by_vars, &mut piecewise_evals)?;
-	if piecewise_eval != fri_final {
-		return Err(VerificationError::IncorrectSumcheckEvaluation.into());
-	}
-
-	Ok(())
+		evaluate_piecewise_multilinear(challenges, &n_pieces_by_vars, &mut piecewise_evals)?;
+	Ok(piecewise_eval)
 }
 
 // Verify the transparent evals and collect the committed evals.
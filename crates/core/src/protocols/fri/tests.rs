@@ -11,19 +11,23 @@ use binius_field::{
 	underlier::UnderlierType,
 };
 use binius_hal::{ComputationBackendExt, make_portable_backend};
-use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+use binius_hash::{
+	PseudoCompressionFunction,
+	groestl::{Groestl256, Groestl256ByteCompression},
+};
 use binius_math::{MultilinearExtension, MultilinearQuery, TowerTop, fold_right};
 use binius_maybe_rayon::prelude::ParallelIterator;
 use binius_ntt::{AdditiveNTT, NTTShape, SingleThreadedNTT, fri::fold_interleaved};
 use binius_utils::checked_arithmetics::log2_strict_usize;
 use bytemuck::zeroed_vec;
+use digest::Output;
 use proptest::prelude::*;
 use rand::prelude::*;
 
 use super::to_par_scalar_big_chunks;
 use crate::{
 	fiat_shamir::{CanSample, HasherChallenger},
-	merkle_tree::{BinaryMerkleTreeProver, MerkleTreeProver},
+	merkle_tree::{KAryMerkleTreeProver, MerkleTreeProver},
 	protocols::fri::{
 		self, CommitOutput, FRIFolder, FRIParams, FRIVerifier, FoldRoundOutput,
 		to_par_scalar_small_chunks,
@@ -107,10 +111,32 @@ fn test_commit_prove_verify_success<U, F, FA>(
 	FA: BinaryField,
 	PackedType<U, F>: PackedField,
 	PackedType<U, FA>: PackedField,
+{
+	test_commit_prove_verify_success_with_merkle_arity::<U, F, FA, 2>(
+		log_dimension,
+		log_inv_rate,
+		log_batch_size,
+		arities,
+	);
+}
+
+fn test_commit_prove_verify_success_with_merkle_arity<U, F, FA, const MERKLE_ARITY: usize>(
+	log_dimension: usize,
+	log_inv_rate: usize,
+	log_batch_size: usize,
+	arities: &[usize],
+) where
+	U: UnderlierType + PackScalar<F> + PackScalar<FA>,
+	F: TowerField + ExtensionField<FA> + PackedField<Scalar = F> + TowerTop,
+	FA: BinaryField,
+	PackedType<U, F>: PackedField,
+	PackedType<U, FA>: PackedField,
+	Groestl256ByteCompression: PseudoCompressionFunction<Output<Groestl256>, MERKLE_ARITY>,
 {
 	let mut rng = StdRng::seed_from_u64(0);
 
-	let merkle_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+	let merkle_prover =
+		KAryMerkleTreeProver::<_, Groestl256, _, MERKLE_ARITY>::new(Groestl256ByteCompression);
 
 	let committed_rs_code = ReedSolomonCode::<FA>::new(log_dimension, log_inv_rate).unwrap();
 
@@ -255,6 +281,20 @@ fn test_commit_prove_verify_success_128b_higher_arity() {
 	);
 }
 
+#[test]
+fn test_commit_prove_verify_success_128b_4ary_merkle() {
+	let log_dimension = 8;
+	let log_inv_rate = 2;
+	let arities = [3, 2, 1];
+
+	test_commit_prove_verify_success_with_merkle_arity::<
+		OptimalUnderlier128b,
+		BinaryField128b,
+		BinaryField16b,
+		4,
+	>(log_dimension, log_inv_rate, 0, &arities);
+}
+
 #[test]
 fn test_commit_prove_verify_success_128b_interleaved() {
 	let log_dimension = 6;
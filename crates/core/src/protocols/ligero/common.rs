@@ -0,0 +1,192 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::marker::PhantomData;
+
+use binius_field::{BinaryField, ExtensionField};
+use binius_ntt::AdditiveNTT;
+use getset::{CopyGetters, Getters};
+
+use crate::{
+	merkle_tree::MerkleTreeScheme, protocols::ligero::Error,
+	reed_solomon::reed_solomon::ReedSolomonCode,
+};
+
+/// Parameters for a Ligero commitment to an interleaved codeword.
+#[derive(Debug, Getters, CopyGetters)]
+pub struct LigeroParams<F, FA>
+where
+	F: BinaryField,
+	FA: BinaryField,
+{
+	/// The Reed–Solomon code each row of the message matrix is encoded with.
+	#[getset(get = "pub")]
+	rs_code: ReedSolomonCode<FA>,
+	/// The binary logarithm of the number of rows of the message matrix.
+	#[getset(get_copy = "pub")]
+	log_batch_size: usize,
+	/// The number of column queries required during the opening.
+	#[getset(get_copy = "pub")]
+	n_test_queries: usize,
+	_marker: PhantomData<F>,
+}
+
+impl<F, FA> LigeroParams<F, FA>
+where
+	F: BinaryField + ExtensionField<FA>,
+	FA: BinaryField,
+{
+	pub fn new(rs_code: ReedSolomonCode<FA>, log_batch_size: usize, n_test_queries: usize) -> Self {
+		Self {
+			rs_code,
+			log_batch_size,
+			n_test_queries,
+			_marker: PhantomData,
+		}
+	}
+
+	/// Choose commit parameters based on protocol parameters, selecting the shape of the message
+	/// matrix that minimizes the proof size.
+	///
+	/// ## Arguments
+	///
+	/// * `ntt` - the NTT whose domain the Reed–Solomon code is defined over.
+	/// * `merkle_scheme` - the Merkle tree commitment scheme for the columns.
+	/// * `log_msg_len` - the binary logarithm of the length of the message to commit.
+	/// * `security_bits` - the target security level in bits.
+	/// * `log_inv_rate` - the binary logarithm of the inverse Reed–Solomon code rate.
+	pub fn choose_with_optimal_batch_size(
+		ntt: &impl AdditiveNTT<FA>,
+		merkle_scheme: &impl MerkleTreeScheme<F>,
+		log_msg_len: usize,
+		security_bits: usize,
+		log_inv_rate: usize,
+	) -> Result<Self, Error> {
+		let mut best: Option<(usize, Self)> = None;
+		for log_batch_size in 0..=log_msg_len {
+			let log_dim = log_msg_len - log_batch_size;
+			if log_dim + log_inv_rate > ntt.log_domain_size() {
+				continue;
+			}
+
+			let rs_code = ReedSolomonCode::with_ntt_subspace(ntt, log_dim, log_inv_rate)?;
+			// Taller matrices have larger proximity gap errors, so the security target may be
+			// unattainable for some shapes but not others.
+			let Ok(n_test_queries) =
+				calculate_n_test_queries::<F, _>(security_bits, &rs_code, log_batch_size)
+			else {
+				continue;
+			};
+
+			let params = Self::new(rs_code, log_batch_size, n_test_queries);
+			let proof_size = params.proof_size(merkle_scheme)?;
+			if best
+				.as_ref()
+				.is_none_or(|(best_proof_size, _)| proof_size < *best_proof_size)
+			{
+				best = Some((proof_size, params));
+			}
+		}
+
+		best.map(|(_, params)| params).ok_or(Error::ParameterError)
+	}
+
+	/// The binary logarithm of the length of the committed message.
+	pub fn log_msg_len(&self) -> usize {
+		self.rs_code.log_dim() + self.log_batch_size
+	}
+
+	/// The binary logarithm of the number of committed columns.
+	pub fn log_n_columns(&self) -> usize {
+		self.rs_code.log_len()
+	}
+
+	/// The depth of the Merkle tree layer the verifier checks once for all queries.
+	pub fn optimal_layer_depth(&self, merkle_scheme: &impl MerkleTreeScheme<F>) -> usize {
+		merkle_scheme.optimal_verify_layer(self.n_test_queries, self.log_n_columns())
+	}
+
+	/// Returns the byte-size of an opening proof.
+	///
+	/// The proof consists of the combined row, the opened columns, and the Merkle opening proofs
+	/// of the columns.
+	pub fn proof_size(&self, merkle_scheme: &impl MerkleTreeScheme<F>) -> Result<usize, Error> {
+		let merkle_proof_size = merkle_scheme
+			.proof_size(
+				1 << self.log_n_columns(),
+				self.n_test_queries,
+				self.optimal_layer_depth(merkle_scheme),
+			)
+			.map_err(|err| Error::VectorCommit(Box::new(err)))?;
+		let n_scalars = self.rs_code.dim() + (self.n_test_queries << self.log_batch_size);
+		Ok(n_scalars * size_of::<F>() + merkle_proof_size)
+	}
+}
+
+/// Calculates the number of column queries required to achieve a target security level.
+///
+/// The combined row is the combination of the rows by a tensor of verifier challenges, so by the
+/// proximity gap for tensor combinations from [DP23] Section 3, the combined row is far from the
+/// code whenever the committed matrix is, except with probability $2 \cdot m \cdot n / |T_{\tau}|$,
+/// where $2^m$ is the number of rows and $n$ the block length. Each column query then detects a
+/// matrix that is far from the code, meaning more than a third of the minimum distance, with
+/// probability at least $\delta / 3$, where $\delta$ is the relative minimum distance.
+///
+/// Throws [`Error::ParameterError`] if the security level is unattainable given the code
+/// parameters.
+///
+/// [DP23]: <https://eprint.iacr.org/2023/1784>
+pub fn calculate_n_test_queries<F, FEncode>(
+	security_bits: usize,
+	code: &ReedSolomonCode<FEncode>,
+	log_batch_size: usize,
+) -> Result<usize, Error>
+where
+	F: BinaryField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+{
+	let field_size = 2.0_f64.powi(F::N_BITS as i32);
+	// 2 ⋅ m ⋅ n / |T_{τ}|
+	let proximity_gap_err = (2 * log_batch_size * code.len()) as f64 / field_size;
+	let relative_distance = (code.len() - code.dim() + 1) as f64 / code.len() as f64;
+	let per_query_err = 1.0 - relative_distance / 3.0;
+	let allowed_query_err = 2.0_f64.powi(-(security_bits as i32)) - proximity_gap_err;
+	if allowed_query_err <= 0.0 {
+		return Err(Error::ParameterError);
+	}
+	let n_queries = allowed_query_err.log(per_query_err).ceil() as usize;
+	Ok(n_queries)
+}
+
+#[cfg(test)]
+mod tests {
+	use assert_matches::assert_matches;
+	use binius_field::{BinaryField32b, BinaryField128b};
+
+	use super::*;
+
+	#[test]
+	fn test_calculate_n_test_queries() {
+		let security_bits = 96;
+		let rs_code = ReedSolomonCode::new(20, 1).unwrap();
+		let n_test_queries =
+			calculate_n_test_queries::<BinaryField128b, BinaryField32b>(security_bits, &rs_code, 8)
+				.unwrap();
+		assert_eq!(n_test_queries, 366);
+
+		let rs_code = ReedSolomonCode::new(20, 2).unwrap();
+		let n_test_queries =
+			calculate_n_test_queries::<BinaryField128b, BinaryField32b>(security_bits, &rs_code, 8)
+				.unwrap();
+		assert_eq!(n_test_queries, 232);
+	}
+
+	#[test]
+	fn test_calculate_n_test_queries_unsatisfiable() {
+		let security_bits = 128;
+		let rs_code = ReedSolomonCode::<BinaryField32b>::new(10, 1).unwrap();
+		assert_matches!(
+			calculate_n_test_queries::<BinaryField128b, _>(security_bits, &rs_code, 8),
+			Err(Error::ParameterError)
+		);
+	}
+}
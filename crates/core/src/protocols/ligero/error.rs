@@ -0,0 +1,29 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_ntt::Error as NttError;
+
+use crate::{reed_solomon, transcript};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	#[error("cannot calculate parameters satisfying the security target")]
+	ParameterError,
+	#[error("conflicting or incorrect constructor argument: {0}")]
+	InvalidArgs(String),
+	#[error("Reed-Solomon encoding error: {0}")]
+	CodeError(#[from] reed_solomon::Error),
+	#[error("Reed-Solomon encoding error: {0}")]
+	EncodeError(#[from] NttError),
+	#[error("vector commit error: {0}")]
+	VectorCommit(#[source] Box<dyn std::error::Error + Send + Sync>),
+	#[error("verification error: {0}")]
+	Verification(#[from] VerificationError),
+	#[error("transcript error: {0}")]
+	TranscriptError(#[from] transcript::Error),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum VerificationError {
+	#[error("the combined row is inconsistent with the opened column at index {index}")]
+	IncorrectColumnCombination { index: usize },
+}
@@ -0,0 +1,38 @@
+// Copyright 2025 Irreducible Inc.
+
+//! A Ligero-style polynomial commitment scheme for multilinears over binary fields.
+//!
+//! This is the tensor-code commitment scheme of [AHIV17] and [GLSTW23], in the form analyzed for
+//! binary towers in [DP23] Section 3. The $2^{\ell}$ coefficients of a multilinear are arranged
+//! into a matrix with $2^m$ rows and $2^{\ell - m}$ columns, each row is encoded with a
+//! Reed–Solomon code, and the prover commits to the columns of the encoded matrix with a Merkle
+//! tree. In the layout of [`crate::protocols::fri`], the encoded matrix is exactly an interleaved
+//! codeword with a batch size of $2^m$, where each Merkle leaf is the batch of symbols at one
+//! position.
+//!
+//! To open the multilinear at a point $(r_0, \ldots, r_{\ell-1})$, the prover sends the combination
+//! of the rows by the tensor expansion of $(r_0, \ldots, r_{m-1})$ in the clear. The verifier
+//! encodes the combined row and checks it against the same combination of a number of randomly
+//! sampled columns, then evaluates the combined row as a multilinear at
+//! $(r_m, \ldots, r_{\ell-1})$. The point must be sampled by the verifier after the commitment,
+//! which is always the case when the scheme is used by the [`crate::piop`] compiler.
+//!
+//! Compared to FRI, the prover skips all folding rounds and the verifier does a single
+//! encoding of size square-root of the message, at the cost of proofs which grow with the
+//! square-root of the message length.
+//!
+//! [AHIV17]: <https://eprint.iacr.org/2022/1608>
+//! [GLSTW23]: <https://eprint.iacr.org/2021/1043>
+//! [DP23]: <https://eprint.iacr.org/2023/1784>
+
+mod common;
+mod error;
+mod prove;
+#[cfg(test)]
+mod tests;
+mod verify;
+
+pub use common::{LigeroParams, calculate_n_test_queries};
+pub use error::*;
+pub use prove::*;
+pub use verify::*;
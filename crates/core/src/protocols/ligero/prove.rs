@@ -0,0 +1,157 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::{
+	BinaryField, ExtensionField, PackedExtension, PackedField, TowerField,
+	packed::iter_packed_slice_with_offset,
+};
+use binius_math::MultilinearQuery;
+use binius_maybe_rayon::prelude::*;
+use binius_ntt::AdditiveNTT;
+use binius_utils::{SerializeBytes, bail};
+use bytemuck::zeroed_vec;
+use tracing::instrument;
+
+use super::{common::LigeroParams, error::Error};
+use crate::{
+	fiat_shamir::{CanSampleBits, Challenger},
+	merkle_tree::{MerkleTreeProver, MerkleTreeScheme},
+	protocols::fri::{CommitOutput, to_par_scalar_big_chunks, to_par_scalar_small_chunks},
+	transcript::ProverTranscript,
+};
+
+/// Encodes and commits the input message with a closure for writing the message.
+///
+/// The message is written in the interleaved layout, where the scalar at index
+/// `(column << log_batch_size) | row` is the entry of the message matrix at that row and column.
+///
+/// ## Arguments
+///
+/// * `params` - common Ligero protocol parameters.
+/// * `ntt` - the NTT to use for encoding the rows.
+/// * `merkle_prover` - the Merkle tree prover to use for committing the columns
+/// * `message_writer` - a closure that writes the interleaved message to encode and commit
+#[instrument(skip_all, name = "ligero::commit_with", level = "debug")]
+pub fn commit_with<F, FA, P, NTT, MerkleProver, VCS>(
+	params: &LigeroParams<F, FA>,
+	ntt: &NTT,
+	merkle_prover: &MerkleProver,
+	message_writer: impl FnOnce(&mut [P]),
+) -> Result<CommitOutput<P, VCS::Digest, MerkleProver::Committed>, Error>
+where
+	F: BinaryField + ExtensionField<FA>,
+	FA: BinaryField,
+	P: PackedField<Scalar = F> + PackedExtension<FA>,
+	NTT: AdditiveNTT<FA> + Sync,
+	MerkleProver: MerkleTreeProver<F, Scheme = VCS>,
+	VCS: MerkleTreeScheme<F>,
+{
+	let rs_code = params.rs_code();
+	let log_batch_size = params.log_batch_size();
+	let log_elems = params.log_msg_len();
+	if log_elems < P::LOG_WIDTH {
+		bail!(Error::InvalidArgs("message is shorter than a packed field element".to_string()));
+	}
+
+	let mut encoded = zeroed_vec(1 << (log_elems - P::LOG_WIDTH + rs_code.log_inv_rate()));
+	message_writer(&mut encoded[..1 << (log_elems - P::LOG_WIDTH)]);
+	rs_code.encode_ext_batch_inplace(ntt, &mut encoded, log_batch_size)?;
+
+	// Each leaf of the Merkle tree is a column of the encoded matrix, which is a contiguous chunk
+	// of the interleaved codeword.
+	let log_n_columns = params.log_n_columns();
+	let (commitment, committed) = if log_batch_size > P::LOG_WIDTH {
+		let iterated_big_chunks = to_par_scalar_big_chunks(&encoded, 1 << log_batch_size);
+		merkle_prover
+			.commit_iterated(iterated_big_chunks, log_n_columns)
+			.map_err(|err| Error::VectorCommit(Box::new(err)))?
+	} else {
+		let iterated_small_chunks = to_par_scalar_small_chunks(&encoded, 1 << log_batch_size);
+		merkle_prover
+			.commit_iterated(iterated_small_chunks, log_n_columns)
+			.map_err(|err| Error::VectorCommit(Box::new(err)))?
+	};
+
+	Ok(CommitOutput {
+		commitment: commitment.root,
+		committed,
+		codeword: encoded,
+	})
+}
+
+/// Proves the evaluation of the committed message, as a multilinear, at a point.
+///
+/// The prover sends the combination of the rows of the message matrix by the tensor expansion of
+/// the first `log_batch_size` coordinates of the point, then opens the columns at the indices
+/// sampled by the verifier.
+///
+/// ## Arguments
+///
+/// * `params` - common Ligero protocol parameters.
+/// * `merkle_prover` - the Merkle tree prover used to commit the columns
+/// * `message` - the interleaved message that was committed
+/// * `codeword` - the interleaved codeword returned by [`commit_with`]
+/// * `committed` - the Merkle tree prover state returned by [`commit_with`]
+/// * `point` - the evaluation point, which must be sampled after the commitment is sent
+/// * `transcript` - the prover transcript
+#[instrument(skip_all, name = "ligero::prove_evaluation", level = "debug")]
+pub fn prove_evaluation<F, FA, P, MerkleProver, VCS, Challenger_>(
+	params: &LigeroParams<F, FA>,
+	merkle_prover: &MerkleProver,
+	message: &[P],
+	codeword: &[P],
+	committed: &MerkleProver::Committed,
+	point: &[F],
+	transcript: &mut ProverTranscript<Challenger_>,
+) -> Result<(), Error>
+where
+	F: TowerField + ExtensionField<FA>,
+	FA: BinaryField,
+	P: PackedField<Scalar = F>,
+	MerkleProver: MerkleTreeProver<F, Scheme = VCS>,
+	VCS: MerkleTreeScheme<F, Digest: SerializeBytes>,
+	Challenger_: Challenger,
+{
+	let log_batch_size = params.log_batch_size();
+	if point.len() != params.log_msg_len() {
+		bail!(Error::InvalidArgs("evaluation point length does not match the message".to_string()));
+	}
+	if message.len() << P::LOG_WIDTH != 1 << params.log_msg_len() {
+		bail!(Error::InvalidArgs("message length does not match code parameters".to_string()));
+	}
+
+	let row_query = MultilinearQuery::<F>::expand(&point[..log_batch_size]).into_expansion();
+	let combined_row = if log_batch_size > P::LOG_WIDTH {
+		to_par_scalar_big_chunks(message, 1 << log_batch_size)
+			.map(|column| inner_product(column, &row_query))
+			.collect::<Vec<_>>()
+	} else {
+		to_par_scalar_small_chunks(message, 1 << log_batch_size)
+			.map(|column| inner_product(column, &row_query))
+			.collect::<Vec<_>>()
+	};
+	transcript.message().write_scalar_slice(&combined_row);
+
+	let layer_depth = params.optimal_layer_depth(merkle_prover.scheme());
+	let layer = merkle_prover
+		.layer(committed, layer_depth)
+		.map_err(|err| Error::VectorCommit(Box::new(err)))?;
+	transcript.decommitment().write_slice(layer);
+
+	for _ in 0..params.n_test_queries() {
+		let index = transcript.sample_bits(params.log_n_columns()) as usize;
+
+		let mut advice = transcript.decommitment();
+		let column = iter_packed_slice_with_offset(codeword, index << log_batch_size)
+			.take(1 << log_batch_size);
+		advice.write_scalar_iter(column);
+		merkle_prover
+			.prove_opening(committed, layer_depth, index, &mut advice)
+			.map_err(|err| Error::VectorCommit(Box::new(err)))?;
+	}
+
+	Ok(())
+}
+
+pub(super) fn inner_product<F: BinaryField>(values: impl Iterator<Item = F>, query: &[F]) -> F {
+	values.zip(query).map(|(value, &coeff)| value * coeff).sum()
+}
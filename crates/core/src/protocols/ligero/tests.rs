@@ -0,0 +1,155 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::iter::repeat_with;
+
+use binius_field::{
+	BinaryField, BinaryField16b, BinaryField32b, BinaryField128b, ExtensionField, PackedExtension,
+	PackedField, TowerField,
+	arch::OptimalUnderlier128b,
+	as_packed_field::{PackScalar, PackedType},
+	underlier::UnderlierType,
+};
+use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+use binius_math::{MultilinearExtension, MultilinearQuery, TowerTop};
+use binius_ntt::SingleThreadedNTT;
+use rand::prelude::*;
+
+use crate::{
+	fiat_shamir::{CanSample, HasherChallenger},
+	merkle_tree::BinaryMerkleTreeProver,
+	protocols::{
+		fri::CommitOutput,
+		ligero::{self, Error, LigeroParams, VerificationError},
+	},
+	reed_solomon::reed_solomon::ReedSolomonCode,
+	transcript::ProverTranscript,
+};
+
+type Challenger_ = HasherChallenger<Groestl256>;
+
+fn commit_prove_verify<U, F, FA>(
+	log_dimension: usize,
+	log_inv_rate: usize,
+	log_batch_size: usize,
+	open_other_message: bool,
+) -> Result<(), Error>
+where
+	U: UnderlierType + PackScalar<F>,
+	F: TowerField + ExtensionField<FA> + TowerTop,
+	FA: BinaryField,
+	PackedType<U, F>: PackedExtension<FA>,
+{
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let merkle_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+	let rs_code = ReedSolomonCode::<FA>::new(log_dimension, log_inv_rate).unwrap();
+	let ntt = SingleThreadedNTT::with_subspace(rs_code.subspace()).unwrap();
+	let params = LigeroParams::<F, FA>::new(rs_code, log_batch_size, 8);
+
+	let log_msg_len = params.log_msg_len();
+	let msg = repeat_with(|| <PackedType<U, F>>::random(&mut rng))
+		.take(1 << (log_msg_len - <PackedType<U, F>>::LOG_WIDTH))
+		.collect::<Vec<_>>();
+
+	let CommitOutput {
+		commitment,
+		committed,
+		codeword,
+	} = ligero::commit_with(&params, &ntt, &merkle_prover, |buffer| buffer.copy_from_slice(&msg))
+		.unwrap();
+
+	// A cheating prover may try to open a message other than the committed one.
+	let mut opened_msg = msg.clone();
+	if open_other_message {
+		opened_msg[0] += <PackedType<U, F>>::one();
+	}
+
+	let mut prover_transcript = ProverTranscript::<Challenger_>::new();
+	prover_transcript.message().write(&commitment);
+	let point: Vec<F> = prover_transcript.sample_vec(log_msg_len);
+	ligero::prove_evaluation(
+		&params,
+		&merkle_prover,
+		&opened_msg,
+		&codeword,
+		&committed,
+		&point,
+		&mut prover_transcript,
+	)
+	.unwrap();
+
+	let mut verifier_transcript = prover_transcript.into_verifier();
+	let commitment = verifier_transcript.message().read().unwrap();
+	let point: Vec<F> = verifier_transcript.sample_vec(log_msg_len);
+	let eval = ligero::verify_evaluation(
+		&params,
+		merkle_prover.scheme(),
+		&commitment,
+		&point,
+		&mut verifier_transcript,
+	)?;
+	verifier_transcript.finalize().unwrap();
+
+	let multilin = MultilinearExtension::new(log_msg_len, msg).unwrap();
+	let expected_eval = multilin
+		.evaluate(&MultilinearQuery::<F>::expand(&point))
+		.unwrap();
+	assert_eq!(eval, expected_eval);
+	Ok(())
+}
+
+#[test]
+fn test_commit_prove_verify_success_128b() {
+	commit_prove_verify::<OptimalUnderlier128b, BinaryField128b, BinaryField16b>(6, 1, 4, false)
+		.unwrap();
+	commit_prove_verify::<OptimalUnderlier128b, BinaryField128b, BinaryField32b>(5, 2, 5, false)
+		.unwrap();
+}
+
+#[test]
+fn test_commit_prove_verify_success_no_batch() {
+	commit_prove_verify::<OptimalUnderlier128b, BinaryField128b, BinaryField16b>(8, 1, 0, false)
+		.unwrap();
+}
+
+#[test]
+fn test_commit_prove_verify_success_single_column() {
+	commit_prove_verify::<OptimalUnderlier128b, BinaryField128b, BinaryField32b>(0, 3, 7, false)
+		.unwrap();
+}
+
+#[test]
+fn test_verify_rejects_opening_of_other_message() {
+	let result =
+		commit_prove_verify::<OptimalUnderlier128b, BinaryField128b, BinaryField16b>(6, 1, 4, true);
+	assert!(matches!(
+		result,
+		Err(Error::Verification(VerificationError::IncorrectColumnCombination { .. }))
+	));
+}
+
+#[test]
+fn test_choose_params_minimizes_proof_size() {
+	let merkle_prover =
+		BinaryMerkleTreeProver::<BinaryField128b, Groestl256, _>::new(Groestl256ByteCompression);
+	let ntt = SingleThreadedNTT::<BinaryField32b>::new(32).unwrap();
+	let params = LigeroParams::<BinaryField128b, BinaryField32b>::choose_with_optimal_batch_size(
+		&ntt,
+		merkle_prover.scheme(),
+		20,
+		96,
+		1,
+	)
+	.unwrap();
+	assert_eq!(params.log_msg_len(), 20);
+
+	let proof_size = params.proof_size(merkle_prover.scheme()).unwrap();
+	for log_batch_size in 0..=20 {
+		let rs_code = ReedSolomonCode::with_ntt_subspace(&ntt, 20 - log_batch_size, 1).unwrap();
+		let n_test_queries =
+			ligero::calculate_n_test_queries::<BinaryField128b, _>(96, &rs_code, log_batch_size)
+				.unwrap();
+		let other = LigeroParams::new(rs_code, log_batch_size, n_test_queries);
+		assert!(proof_size <= other.proof_size(merkle_prover.scheme()).unwrap());
+	}
+}
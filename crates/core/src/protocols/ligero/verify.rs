@@ -0,0 +1,98 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::{BinaryField, ExtensionField, TowerField};
+use binius_math::MultilinearQuery;
+use binius_ntt::SingleThreadedNTT;
+use binius_utils::{DeserializeBytes, bail};
+use tracing::instrument;
+
+use super::{
+	common::LigeroParams,
+	error::{Error, VerificationError},
+	prove::inner_product,
+};
+use crate::{
+	fiat_shamir::{CanSampleBits, Challenger},
+	merkle_tree::MerkleTreeScheme,
+	reed_solomon::reed_solomon::ReedSolomonCode,
+	transcript::VerifierTranscript,
+};
+
+/// Verifies the evaluation of a committed message, as a multilinear, at a point.
+///
+/// Returns the evaluation of the committed multilinear at `point`.
+///
+/// ## Arguments
+///
+/// * `params` - common Ligero protocol parameters.
+/// * `merkle_scheme` - the Merkle tree commitment scheme for the columns
+/// * `commitment` - the Merkle root of the committed columns
+/// * `point` - the evaluation point, which must be sampled after the commitment is received
+/// * `transcript` - the verifier transcript
+#[instrument(skip_all, name = "ligero::verify_evaluation", level = "debug")]
+pub fn verify_evaluation<F, FA, VCS, Challenger_>(
+	params: &LigeroParams<F, FA>,
+	merkle_scheme: &VCS,
+	commitment: &VCS::Digest,
+	point: &[F],
+	transcript: &mut VerifierTranscript<Challenger_>,
+) -> Result<F, Error>
+where
+	F: TowerField + ExtensionField<FA>,
+	FA: BinaryField,
+	VCS: MerkleTreeScheme<F, Digest: DeserializeBytes>,
+	Challenger_: Challenger,
+{
+	let rs_code = params.rs_code();
+	let log_batch_size = params.log_batch_size();
+	if point.len() != params.log_msg_len() {
+		bail!(Error::InvalidArgs("evaluation point length does not match the message".to_string()));
+	}
+
+	let combined_row = transcript.message().read_scalar_slice::<F>(rs_code.dim())?;
+
+	// Encode the combined row, which by linearity of the code is the same combination of the
+	// encoded rows. The row is over the extension field, so we encode it with the isomorphic code
+	// over the extension field rather than requiring a packed extension relation.
+	let ext_code = ReedSolomonCode::<F>::with_subspace(
+		rs_code.subspace().isomorphic(),
+		rs_code.log_dim(),
+		rs_code.log_inv_rate(),
+	)?;
+	let ntt = SingleThreadedNTT::with_subspace(ext_code.subspace())?;
+	let mut encoded_row = combined_row.clone();
+	encoded_row.resize(ext_code.len(), F::ZERO);
+	ext_code.encode_batch_inplace(&ntt, &mut encoded_row, 0)?;
+
+	// Verify that the provided layer matches the commitment.
+	let layer_depth = params.optimal_layer_depth(merkle_scheme);
+	let layer = transcript.decommitment().read_vec(1 << layer_depth)?;
+	merkle_scheme
+		.verify_layer(commitment, layer_depth, &layer)
+		.map_err(|err| Error::VectorCommit(Box::new(err)))?;
+
+	let row_query = MultilinearQuery::<F>::expand(&point[..log_batch_size]).into_expansion();
+	for _ in 0..params.n_test_queries() {
+		let index = transcript.sample_bits(params.log_n_columns()) as usize;
+
+		let mut advice = transcript.decommitment();
+		let column = advice.read_scalar_slice::<F>(1 << log_batch_size)?;
+		merkle_scheme
+			.verify_opening(
+				index,
+				&column,
+				layer_depth,
+				params.log_n_columns(),
+				&layer,
+				&mut advice,
+			)
+			.map_err(|err| Error::VectorCommit(Box::new(err)))?;
+
+		if inner_product(column.into_iter(), &row_query) != encoded_row[index] {
+			return Err(VerificationError::IncorrectColumnCombination { index }.into());
+		}
+	}
+
+	let column_query = MultilinearQuery::<F>::expand(&point[log_batch_size..]).into_expansion();
+	Ok(inner_product(combined_row.into_iter(), &column_query))
+}
@@ -34,7 +34,9 @@ pub mod fri;
 pub mod gkr_exp;
 pub mod gkr_gpa;
 pub mod greedy_evalcheck;
+pub mod ligero;
 pub mod prodcheck;
+pub mod stir;
 pub mod sumcheck;
 
 #[allow(dead_code)]
@@ -0,0 +1,623 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::marker::PhantomData;
+
+use binius_field::{BinaryField, ExtensionField, Field};
+use binius_math::{BinarySubspace, MultilinearQuery};
+use binius_ntt::{
+	AdditiveNTT,
+	fri::{fold_chunk, fold_interleaved_chunk},
+};
+use binius_utils::bail;
+use getset::{CopyGetters, Getters};
+
+use crate::{
+	fiat_shamir::CanSampleBits, protocols::stir::Error, reed_solomon::reed_solomon::ReedSolomonCode,
+};
+
+/// Parameters for a STIR interleaved code proximity protocol.
+///
+/// The protocol sends one oracle per fold arity after the initial codeword. The first oracle is
+/// the committed interleaved codeword itself and oracle $i > 0$ is committed after the $i$-th
+/// group of folding challenges. `n_test_queries` has one entry per oracle: the number of shift
+/// queries made to each oracle but the last, followed by the number of queries in the final
+/// check.
+#[derive(Debug, Getters, CopyGetters)]
+pub struct StirParams<F, FA>
+where
+	F: BinaryField,
+	FA: BinaryField,
+{
+	/// The Reed-Solomon code the verifier is testing proximity to.
+	#[getset(get = "pub")]
+	rs_code: ReedSolomonCode<FA>,
+	/// The binary logarithm of the interleaved code batch size.
+	#[getset(get_copy = "pub")]
+	log_batch_size: usize,
+	/// The number of folding challenges between each oracle sent to the verifier.
+	fold_arities: Vec<usize>,
+	/// The number of queries to each oracle.
+	n_test_queries: Vec<usize>,
+	/// The subspace of the NTT domain, which is larger than the code domain so that every oracle
+	/// has room for its shifted evaluation domain.
+	#[getset(get = "pub")]
+	ntt_subspace: BinarySubspace<FA>,
+	_marker: PhantomData<F>,
+}
+
+impl<F, FA> StirParams<F, FA>
+where
+	F: BinaryField + ExtensionField<FA>,
+	FA: BinaryField,
+{
+	pub fn new(
+		rs_code: ReedSolomonCode<FA>,
+		log_batch_size: usize,
+		fold_arities: Vec<usize>,
+		n_test_queries: Vec<usize>,
+	) -> Result<Self, Error> {
+		let n_fold_rounds = rs_code.log_dim() + log_batch_size;
+		if !fold_arities.is_empty() && fold_arities.iter().sum::<usize>() >= n_fold_rounds {
+			bail!(Error::InvalidFoldAritySequence);
+		}
+		// Each fold must shrink the domain faster than the shifted domains shrink, so that the
+		// folded domain is disjoint from the domain of the next oracle.
+		for (index, &arity) in fold_arities.iter().enumerate() {
+			let min = if index == 0 { log_batch_size + 1 } else { 2 };
+			if arity < min {
+				bail!(Error::FoldArityTooSmall { index, arity, min });
+			}
+		}
+		if n_test_queries.len() != fold_arities.len() + 1 {
+			bail!(Error::IncorrectNumberOfQueryCounts {
+				expected: fold_arities.len() + 1,
+				got: n_test_queries.len(),
+			});
+		}
+		if *rs_code.subspace() != BinarySubspace::with_dim(rs_code.log_len())? {
+			bail!(Error::NonCanonicalSubspace);
+		}
+
+		let ntt_log_domain_size = match fold_arities.split_first() {
+			Some((&first_arity, rest)) => {
+				rs_code.log_len()
+					+ (first_arity - log_batch_size)
+					+ rest.iter().map(|arity| arity - 1).sum::<usize>()
+			}
+			None => rs_code.log_len(),
+		};
+		let ntt_subspace = BinarySubspace::with_dim(ntt_log_domain_size)?;
+
+		Ok(Self {
+			rs_code,
+			log_batch_size,
+			fold_arities,
+			n_test_queries,
+			ntt_subspace,
+			_marker: PhantomData,
+		})
+	}
+
+	/// Choose parameters based on protocol parameters, using a constant fold arity.
+	///
+	/// The number of oracles is the largest for which the NTT domain fits in the subfield `FA`.
+	///
+	/// ## Arguments
+	///
+	/// * `log_msg_len` - the binary logarithm of the length of the message to commit.
+	/// * `security_bits` - the target security level in bits.
+	/// * `log_inv_rate` - the binary logarithm of the inverse Reed–Solomon code rate.
+	/// * `arity` - the folding arity, which must be at least 2.
+	pub fn choose_with_constant_fold_arity(
+		log_msg_len: usize,
+		security_bits: usize,
+		log_inv_rate: usize,
+		arity: usize,
+	) -> Result<Self, Error> {
+		if arity < 2 {
+			bail!(Error::FoldArityTooSmall {
+				index: 0,
+				arity,
+				min: 2
+			});
+		}
+
+		let rs_code = ReedSolomonCode::new(log_msg_len, log_inv_rate)?;
+		// The first oracle after the initial codeword needs `arity` more NTT dimensions than the
+		// code domain, and each subsequent oracle needs `arity - 1` more.
+		let max_ntt_oracles = if FA::N_BITS >= rs_code.log_len() + arity {
+			(FA::N_BITS - rs_code.log_len() - 1) / (arity - 1)
+		} else {
+			0
+		};
+		let n_oracles = (log_msg_len.saturating_sub(1) / arity).min(max_ntt_oracles);
+		let fold_arities = vec![arity; n_oracles];
+
+		let n_test_queries =
+			calculate_n_test_queries::<F, _>(security_bits, &rs_code, 0, &fold_arities)?;
+		Self::new(rs_code, 0, fold_arities, n_test_queries)
+	}
+
+	pub const fn n_fold_rounds(&self) -> usize {
+		self.rs_code.log_dim() + self.log_batch_size
+	}
+
+	/// Number of oracles sent during the fold rounds.
+	pub fn n_oracles(&self) -> usize {
+		self.fold_arities.len()
+	}
+
+	/// The number of folding challenges between each oracle sent to the verifier.
+	pub fn fold_arities(&self) -> &[usize] {
+		&self.fold_arities
+	}
+
+	/// The number of queries to each oracle, including the initial codeword.
+	pub fn n_test_queries(&self) -> &[usize] {
+		&self.n_test_queries
+	}
+
+	/// Number of folding challenges the verifier sends after receiving the last oracle.
+	pub fn n_final_challenges(&self) -> usize {
+		self.n_fold_rounds() - self.fold_arities.iter().sum::<usize>()
+	}
+
+	/// The binary logarithm of the length of the initial oracle.
+	pub fn log_len(&self) -> usize {
+		self.rs_code.log_len() + self.log_batch_size
+	}
+
+	/// The binary logarithm of the NTT domain size required by the protocol.
+	pub fn ntt_log_domain_size(&self) -> usize {
+		self.ntt_subspace.dim()
+	}
+
+	/// The number of folding challenges before oracle `oracle` is sent.
+	fn n_challenges_before(&self, oracle: usize) -> usize {
+		self.fold_arities[..oracle].iter().sum()
+	}
+
+	/// The NTT layer of the polynomial committed in an oracle, counted in folds from the initial
+	/// codeword domain.
+	pub(super) fn oracle_layer(&self, oracle: usize) -> usize {
+		match oracle {
+			0 => 0,
+			_ => self.n_challenges_before(oracle) - self.log_batch_size,
+		}
+	}
+
+	/// The binary logarithm of the degree bound of the polynomial committed in an oracle.
+	pub(super) fn oracle_log_dim(&self, oracle: usize) -> usize {
+		self.rs_code.log_dim() + self.log_batch_size - self.n_challenges_before(oracle)
+	}
+
+	/// The binary logarithm of the number of evaluation points of an oracle.
+	///
+	/// Every oracle after the initial codeword has half as many points as the previous one.
+	pub(super) fn oracle_log_len(&self, oracle: usize) -> usize {
+		self.rs_code.log_len() - oracle
+	}
+
+	/// The index of the first evaluation point of an oracle in the domain of its NTT layer.
+	///
+	/// Every oracle after the initial codeword is evaluated on the upper half of a subspace, which
+	/// is disjoint from the folded domain of the previous oracle.
+	pub(super) fn oracle_domain_offset(&self, oracle: usize) -> usize {
+		match oracle {
+			0 => 0,
+			_ => 1 << self.oracle_log_len(oracle),
+		}
+	}
+
+	/// The number of values per evaluation point of an oracle.
+	///
+	/// Oracles after the second hold evaluations of both the folded committed polynomial and the
+	/// folded virtual function. The second oracle only holds the first, because the virtual
+	/// function of the initial codeword is the codeword itself.
+	pub(super) fn oracle_width(&self, oracle: usize) -> usize {
+		if oracle >= 2 { 2 } else { 1 }
+	}
+
+	/// The binary logarithm of the number of folding challenges applied to an oracle.
+	///
+	/// This is also the binary logarithm of the number of points per Merkle leaf, including the
+	/// interleaved batch for the initial codeword.
+	pub(super) fn oracle_log_coset_size(&self, oracle: usize) -> usize {
+		self.fold_arities
+			.get(oracle)
+			.copied()
+			.unwrap_or_else(|| self.n_final_challenges())
+	}
+
+	/// The binary logarithm of the number of evaluation points folded into one.
+	pub(super) fn oracle_fold_arity(&self, oracle: usize) -> usize {
+		match oracle {
+			0 => self.oracle_log_coset_size(0) - self.log_batch_size,
+			_ => self.oracle_log_coset_size(oracle),
+		}
+	}
+
+	/// The binary logarithm of the number of Merkle leaves of an oracle.
+	pub(super) fn oracle_index_bits(&self, oracle: usize) -> usize {
+		self.oracle_log_len(oracle) - self.oracle_fold_arity(oracle)
+	}
+}
+
+/// Calculates the number of queries to each oracle required to achieve a target security level.
+///
+/// The query error budget left after the field errors is split evenly between the oracles.
+///
+/// Throws [`Error::ParameterError`] if the security level is unattainable given the code
+/// parameters.
+pub fn calculate_n_test_queries<F, FEncode>(
+	security_bits: usize,
+	code: &ReedSolomonCode<FEncode>,
+	log_batch_size: usize,
+	fold_arities: &[usize],
+) -> Result<Vec<usize>, Error>
+where
+	F: BinaryField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+{
+	let field_size = 2.0_f64.powi(F::N_BITS as i32);
+	// The log inverse rates and log lengths of the oracles. Each oracle after the first halves the
+	// domain size while the degree bound shrinks by the fold arity.
+	let oracles = (0..=fold_arities.len())
+		.map(|oracle| {
+			let log_len = code.log_len() - oracle;
+			let log_inv_rate = match oracle {
+				0 => code.log_inv_rate(),
+				_ => {
+					let n_challenges = fold_arities[..oracle].iter().sum::<usize>();
+					log_len + n_challenges - code.log_dim() - log_batch_size
+				}
+			};
+			(log_len, log_inv_rate)
+		})
+		.collect::<Vec<_>>();
+
+	let sumcheck_err = (2 * code.log_dim()) as f64 / field_size;
+	// Each oracle contributes a folding error, like in FRI, and the oracles after the first
+	// contribute the errors of the combination and degree correction challenges.
+	let field_err = oracles
+		.iter()
+		.enumerate()
+		.map(|(oracle, &(log_len, _))| {
+			let n_challenges = if oracle == 0 { 1 } else { 3 };
+			(n_challenges << log_len) as f64 / field_size
+		})
+		.sum::<f64>();
+	let allowed_query_err = 2.0_f64.powi(-(security_bits as i32)) - sumcheck_err - field_err;
+	if allowed_query_err <= 0.0 {
+		return Err(Error::ParameterError);
+	}
+
+	// The soundness error is the sum of the query errors of all oracles, so each oracle gets an
+	// equal share of the budget.
+	let allowed_oracle_err = allowed_query_err / oracles.len() as f64;
+	let n_queries = oracles
+		.into_iter()
+		.map(|(_, log_inv_rate)| {
+			let per_query_err = 0.5 * (1f64 + 2.0f64.powi(-(log_inv_rate as i32)));
+			allowed_oracle_err.log(per_query_err).ceil() as usize
+		})
+		.collect();
+	Ok(n_queries)
+}
+
+/// The virtual function that the verifier tests for proximity in place of a committed oracle.
+///
+/// For an oracle holding values $g$ and optionally $h$, the virtual function is
+///
+/// $$
+/// f(X) = \left(\sum_{l=0}^{e} (r X)^l\right) \cdot
+///   \frac{g(X) + \beta h(X) - \textsf{Ans}(X)}{\prod_{j=0}^{e-1} (X - y_j)},
+/// $$
+///
+/// where $y_j$ are the distinct shift query points, $\textsf{Ans}$ interpolates the folded values
+/// at those points, $\beta$ is the combination challenge and $r$ is the degree correction
+/// challenge. See [ACFY24], Section 2.
+///
+/// [ACFY24]: <https://eprint.iacr.org/2024/390>
+#[derive(Debug, Clone)]
+pub(super) struct ShiftQuotient<F, FA: BinaryField> {
+	/// The evaluation domain of the oracle's NTT layer.
+	domain: BinarySubspace<FA>,
+	combination_challenge: Option<F>,
+	points: Vec<F>,
+	/// The answers at each point scaled by the barycentric weight of the point.
+	weighted_answers: Vec<F>,
+	degree_correction_challenge: F,
+}
+
+impl<F, FA> ShiftQuotient<F, FA>
+where
+	F: BinaryField + ExtensionField<FA>,
+	FA: BinaryField,
+{
+	/// Constructs the virtual function from distinct shift query points and the answers there.
+	pub fn new(
+		domain: BinarySubspace<FA>,
+		combination_challenge: Option<F>,
+		points: Vec<F>,
+		answers: Vec<F>,
+		degree_correction_challenge: F,
+	) -> Self {
+		let weighted_answers = points
+			.iter()
+			.zip(answers)
+			.enumerate()
+			.map(|(j, (&y_j, answer))| {
+				let denominator = points
+					.iter()
+					.enumerate()
+					.filter(|&(l, _)| l != j)
+					.map(|(_, &y_l)| y_j - y_l)
+					.product::<F>();
+				answer
+					* denominator
+						.invert()
+						.expect("shift query points are distinct")
+			})
+			.collect();
+		Self {
+			domain,
+			combination_challenge,
+			points,
+			weighted_answers,
+			degree_correction_challenge,
+		}
+	}
+
+	/// Evaluates the virtual function at the point with the given index in the layer domain.
+	///
+	/// `values` holds the oracle values at the point, which are one or two values depending on
+	/// the oracle width.
+	pub fn evaluate(&self, index: usize, values: (F, Option<F>)) -> F {
+		let x = F::from(self.domain.get(index));
+		let value = match (values, self.combination_challenge) {
+			((g, Some(h)), Some(beta)) => g + beta * h,
+			((g, _), _) => g,
+		};
+
+		// Batch invert the differences to the query points along with the degree correction
+		// denominator, using a single field inversion.
+		let rx = self.degree_correction_challenge * x;
+		let mut inverses = self
+			.points
+			.iter()
+			.map(|&y| x - y)
+			.chain(std::iter::once(F::ONE + rx))
+			.collect::<Vec<_>>();
+		let vanishing = batch_invert(&mut inverses);
+		let dc_inverse = inverses
+			.pop()
+			.expect("inverses contains the degree correction term");
+
+		let quotient = value * vanishing
+			- std::iter::zip(&self.weighted_answers, &inverses)
+				.map(|(&answer, &inverse)| answer * inverse)
+				.sum::<F>();
+
+		// The degree correction factor is the geometric sum of (rx)^l for l from 0 to e, which in
+		// characteristic 2 is (1 + (rx)^{e+1}) / (1 + rx), unless rx = 1.
+		let degree_correction = if rx == F::ONE {
+			if self.points.len() % 2 == 0 {
+				F::ONE
+			} else {
+				F::ZERO
+			}
+		} else {
+			(F::ONE + rx.pow([(self.points.len() + 1) as u64])) * dc_inverse
+		};
+		quotient * degree_correction
+	}
+}
+
+/// Inverts every element in place and returns the inverse of the product of all elements except
+/// the last.
+///
+/// All elements must be non-zero, except possibly the last, whose inverse is returned as zero.
+fn batch_invert<F: Field>(values: &mut [F]) -> F {
+	let (last, rest) = values.split_last_mut().expect("values is not empty");
+	let mut prefix_products = Vec::with_capacity(rest.len());
+	let mut product = F::ONE;
+	for &value in rest.iter() {
+		prefix_products.push(product);
+		product *= value;
+	}
+	let product_inverse = product
+		.invert()
+		.expect("evaluation points are disjoint from the shift query points");
+	*last = last.invert().unwrap_or(F::ZERO);
+
+	let mut suffix_inverse = product_inverse;
+	for (value, prefix) in rest.iter_mut().zip(prefix_products).rev() {
+		let inverse = suffix_inverse * prefix;
+		suffix_inverse *= *value;
+		*value = inverse;
+	}
+	product_inverse
+}
+
+/// Folds one Merkle leaf of an oracle with the given folding challenges.
+///
+/// Returns the fold of the committed polynomial values and the fold of the virtual function values
+/// at the point that the leaf folds to. For the initial codeword, the virtual function is the
+/// codeword itself, so the two folds are equal.
+pub(super) fn fold_oracle_leaf<F, FA, NTT>(
+	params: &StirParams<F, FA>,
+	ntt: &NTT,
+	oracle: usize,
+	quotient: Option<&ShiftQuotient<F, FA>>,
+	leaf_index: usize,
+	leaf: &[F],
+	challenges: &[F],
+) -> (F, F)
+where
+	F: BinaryField + ExtensionField<FA>,
+	FA: BinaryField,
+	NTT: AdditiveNTT<FA>,
+{
+	let log_len = ntt.log_domain_size() - params.oracle_layer(oracle);
+	match quotient {
+		None => {
+			let log_batch_size = params.log_batch_size();
+			let (interleave_challenges, fold_challenges) = challenges.split_at(log_batch_size);
+			let tensor = MultilinearQuery::<F>::expand(interleave_challenges).into_expansion();
+			let mut scratch_buffer = vec![F::ZERO; 1 << fold_challenges.len()];
+			let value = fold_interleaved_chunk(
+				ntt,
+				log_len,
+				log_batch_size,
+				leaf_index,
+				leaf,
+				&tensor,
+				fold_challenges,
+				&mut scratch_buffer,
+			);
+			(value, value)
+		}
+		Some(quotient) => {
+			let log_coset_size = params.oracle_log_coset_size(oracle);
+			let (committed_values, extra_values) = leaf.split_at(1 << log_coset_size);
+			let first_index = params.oracle_domain_offset(oracle) + (leaf_index << log_coset_size);
+			let mut virtual_values = (0..1 << log_coset_size)
+				.map(|j| {
+					let extra_value = extra_values.get(j).copied();
+					quotient.evaluate(first_index + j, (committed_values[j], extra_value))
+				})
+				.collect::<Vec<_>>();
+			let chunk_index = first_index >> log_coset_size;
+
+			let mut committed_values = committed_values.to_vec();
+			let committed_fold =
+				fold_chunk(ntt, log_len, chunk_index, &mut committed_values, challenges);
+			let virtual_fold =
+				fold_chunk(ntt, log_len, chunk_index, &mut virtual_values, challenges);
+			(committed_fold, virtual_fold)
+		}
+	}
+}
+
+/// Returns the index, in the domain of the next NTT layer, of the point a leaf folds to.
+pub(super) fn folded_leaf_index<F, FA>(
+	params: &StirParams<F, FA>,
+	oracle: usize,
+	leaf_index: usize,
+) -> usize
+where
+	F: BinaryField + ExtensionField<FA>,
+	FA: BinaryField,
+{
+	(params.oracle_domain_offset(oracle) >> params.oracle_fold_arity(oracle)) + leaf_index
+}
+
+/// Samples query indices of the given bit width and returns the distinct indices in sorted order.
+pub(super) fn sample_distinct_indices(
+	transcript: &mut impl CanSampleBits<u32>,
+	n_queries: usize,
+	index_bits: usize,
+) -> Vec<usize> {
+	let mut indices = (0..n_queries)
+		.map(|_| transcript.sample_bits(index_bits) as usize)
+		.collect::<Vec<_>>();
+	indices.sort_unstable();
+	indices.dedup();
+	indices
+}
+
+/// Returns the evaluation domain of an NTT layer.
+pub(super) fn layer_domain<FA: BinaryField>(
+	ntt: &impl AdditiveNTT<FA>,
+	layer: usize,
+) -> BinarySubspace<FA> {
+	ntt.subspace(ntt.log_domain_size() - layer)
+}
+
+#[cfg(test)]
+mod tests {
+	use assert_matches::assert_matches;
+	use binius_field::{BinaryField16b, BinaryField32b, BinaryField128b};
+
+	use super::*;
+	use crate::protocols::fri;
+
+	#[test]
+	fn test_calculate_n_test_queries() {
+		let security_bits = 96;
+		let rs_code = ReedSolomonCode::new(20, 1).unwrap();
+		let n_test_queries = calculate_n_test_queries::<BinaryField128b, BinaryField32b>(
+			security_bits,
+			&rs_code,
+			0,
+			&[4, 4, 4],
+		)
+		.unwrap();
+		assert_eq!(n_test_queries, vec![237, 108, 100, 99]);
+	}
+
+	#[test]
+	fn test_calculate_n_test_queries_unsatisfiable() {
+		let security_bits = 128;
+		let rs_code = ReedSolomonCode::<BinaryField32b>::new(28, 1).unwrap();
+		assert_matches!(
+			calculate_n_test_queries::<BinaryField128b, _>(security_bits, &rs_code, 0, &[4]),
+			Err(Error::ParameterError)
+		);
+	}
+
+	#[test]
+	fn test_first_oracle_queries_match_fri() {
+		let security_bits = 96;
+		let rs_code = ReedSolomonCode::new(20, 1).unwrap();
+		let fri_queries = fri::calculate_n_test_queries::<BinaryField128b, BinaryField32b>(
+			security_bits,
+			&rs_code,
+		)
+		.unwrap();
+		let stir_queries = calculate_n_test_queries::<BinaryField128b, BinaryField32b>(
+			security_bits,
+			&rs_code,
+			0,
+			&[],
+		)
+		.unwrap();
+		assert_eq!(stir_queries, vec![fri_queries]);
+	}
+
+	#[test]
+	fn test_new_rejects_small_arities() {
+		let rs_code = ReedSolomonCode::<BinaryField16b>::new(8, 2).unwrap();
+		assert_matches!(
+			StirParams::<BinaryField128b, _>::new(rs_code, 1, vec![1, 2], vec![1, 1, 1]),
+			Err(Error::FoldArityTooSmall {
+				index: 0,
+				arity: 1,
+				min: 2
+			})
+		);
+
+		let rs_code = ReedSolomonCode::<BinaryField16b>::new(8, 2).unwrap();
+		assert_matches!(
+			StirParams::<BinaryField128b, _>::new(rs_code, 0, vec![2, 1], vec![1, 1, 1]),
+			Err(Error::FoldArityTooSmall {
+				index: 1,
+				arity: 1,
+				min: 2
+			})
+		);
+	}
+
+	#[test]
+	fn test_choose_with_constant_fold_arity() {
+		let params =
+			StirParams::<BinaryField128b, BinaryField32b>::choose_with_constant_fold_arity(
+				20, 96, 1, 4,
+			)
+			.unwrap();
+		assert_eq!(params.fold_arities(), &[4, 4, 4]);
+		assert_eq!(params.n_final_challenges(), 8);
+		assert!(params.ntt_log_domain_size() <= 32);
+	}
+}
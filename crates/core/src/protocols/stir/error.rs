@@ -0,0 +1,49 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_ntt::Error as NttError;
+
+use crate::{reed_solomon, transcript};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	#[error("cannot calculate parameters satisfying the security target")]
+	ParameterError,
+	#[error("conflicting or incorrect constructor argument: {0}")]
+	InvalidArgs(String),
+	#[error("fold arities total exceeds the number of fold rounds")]
+	InvalidFoldAritySequence,
+	#[error("the fold arity at index {index} is {arity}, but must be at least {min}")]
+	FoldArityTooSmall {
+		index: usize,
+		arity: usize,
+		min: usize,
+	},
+	#[error("expected {expected} query counts, one per oracle, got {got}")]
+	IncorrectNumberOfQueryCounts { expected: usize, got: usize },
+	#[error("STIR requires the code to be defined over the canonical subspace")]
+	NonCanonicalSubspace,
+	#[error("the NTT domain does not match the STIR parameters")]
+	NTTSubspaceMismatch,
+	#[error("attempted to fold more than maximum of {max_folds} times")]
+	TooManyFoldExecutions { max_folds: usize },
+	#[error("attempted to finish prover before executing all fold rounds")]
+	EarlyProverFinish,
+	#[error("binary subspace error: {0}")]
+	MathError(#[from] binius_math::Error),
+	#[error("Reed-Solomon encoding error: {0}")]
+	CodeError(#[from] reed_solomon::Error),
+	#[error("Reed-Solomon encoding error: {0}")]
+	EncodeError(#[from] NttError),
+	#[error("vector commit error: {0}")]
+	VectorCommit(#[source] Box<dyn std::error::Error + Send + Sync>),
+	#[error("verification error: {0}")]
+	Verification(#[from] VerificationError),
+	#[error("transcript error: {0}")]
+	TranscriptError(#[from] transcript::Error),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum VerificationError {
+	#[error("the folded oracle {oracle} is not consistent with the final values at index {index}")]
+	IncorrectFinalFold { oracle: usize, index: usize },
+}
@@ -0,0 +1,34 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Implementation of the STIR IOP of proximity for Reed–Solomon codes over binary fields.
+//!
+//! STIR was introduced in [ACFY24]. Like FRI, the prover folds the purported codeword with random
+//! challenges and sends oracles to the folded codewords. Unlike FRI, each oracle is a codeword of
+//! a code with a lower rate, evaluated on a domain that is disjoint from the folded domain of the
+//! previous oracle. The verifier makes a few shift queries to each oracle and tests the proximity
+//! of a virtual function that is a low-degree quotient of the next oracle by the answers.
+//! Lowering the rate in every round lets the verifier make fewer queries to later oracles than
+//! FRI makes to each.
+//!
+//! This implementation tests an interleaved Reed–Solomon code committed exactly as in the FRI
+//! implementation of [DP24] and folds with the same FRI-Binius folding procedure, so the final
+//! folded value is the evaluation of the committed message at the folding challenges. The domains
+//! of the oracles are subspace cosets of a single additive NTT over a larger domain than the code,
+//! so the verifier can interpolate the shift query answers in the novel polynomial basis of the
+//! folded codewords. The protocol runs in the unique decoding regime and makes no
+//! out-of-domain queries.
+//!
+//! [ACFY24]: <https://eprint.iacr.org/2024/390>
+//! [DP24]: <https://eprint.iacr.org/2024/504>
+
+mod common;
+mod error;
+mod prove;
+#[cfg(test)]
+mod tests;
+mod verify;
+
+pub use common::{StirParams, calculate_n_test_queries};
+pub use error::*;
+pub use prove::*;
+pub use verify::*;
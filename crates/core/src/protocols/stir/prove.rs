@@ -0,0 +1,513 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::{
+	BinaryField, ExtensionField, PackedExtension, PackedField, TowerField,
+	packed::{iter_packed_slice_with_offset, len_packed_slice},
+};
+use binius_maybe_rayon::prelude::*;
+use binius_ntt::{AdditiveNTT, NTTShape};
+use binius_utils::{SerializeBytes, bail};
+use bytemuck::zeroed_vec;
+use bytes::BufMut;
+use tracing::instrument;
+
+use super::{
+	common::{
+		ShiftQuotient, StirParams, fold_oracle_leaf, folded_leaf_index, layer_domain,
+		sample_distinct_indices,
+	},
+	error::Error,
+};
+use crate::{
+	fiat_shamir::{CanSample, Challenger},
+	merkle_tree::{MerkleTreeProver, MerkleTreeScheme},
+	protocols::fri::{CommitOutput, to_par_scalar_big_chunks, to_par_scalar_small_chunks},
+	transcript::{ProverTranscript, TranscriptWriter},
+};
+
+/// Checks that the NTT domain extends the NTT subspace required by the STIR parameters.
+fn check_ntt_subspace<F, FA, NTT>(params: &StirParams<F, FA>, ntt: &NTT) -> Result<(), Error>
+where
+	F: BinaryField + ExtensionField<FA>,
+	FA: BinaryField,
+	NTT: AdditiveNTT<FA>,
+{
+	let log_domain_size = params.ntt_log_domain_size();
+	if ntt.log_domain_size() < log_domain_size
+		|| ntt
+			.subspace(ntt.log_domain_size())
+			.reduce_dim(log_domain_size)?
+			!= *params.ntt_subspace()
+	{
+		bail!(Error::NTTSubspaceMismatch);
+	}
+	Ok(())
+}
+
+/// Encodes and commits the input message.
+///
+/// ## Arguments
+///
+/// * `params` - common STIR protocol parameters.
+/// * `ntt` - the NTT, whose domain must extend [`StirParams::ntt_subspace`].
+/// * `merkle_prover` - the Merkle tree prover to use for committing
+/// * `message` - the interleaved message to encode and commit
+#[instrument(skip_all, level = "debug")]
+pub fn commit_interleaved<F, FA, P, NTT, MerkleProver, VCS>(
+	params: &StirParams<F, FA>,
+	ntt: &NTT,
+	merkle_prover: &MerkleProver,
+	message: &[P],
+) -> Result<CommitOutput<P, VCS::Digest, MerkleProver::Committed>, Error>
+where
+	F: BinaryField + ExtensionField<FA>,
+	FA: BinaryField,
+	P: PackedField<Scalar = F> + PackedExtension<FA>,
+	NTT: AdditiveNTT<FA> + Sync,
+	MerkleProver: MerkleTreeProver<F, Scheme = VCS>,
+	VCS: MerkleTreeScheme<F>,
+{
+	let n_elems = params.rs_code().dim() << params.log_batch_size();
+	if message.len() * P::WIDTH != n_elems {
+		bail!(Error::InvalidArgs(
+			"interleaved message length does not match code parameters".to_string()
+		));
+	}
+
+	commit_interleaved_with(params, ntt, merkle_prover, move |buffer| {
+		buffer.copy_from_slice(message)
+	})
+}
+
+/// Encodes and commits the input message with a closure for writing the message.
+///
+/// The codeword is the same as the one [`crate::protocols::fri::commit_interleaved`] produces for
+/// the same code, so the commitment can be opened with the same evaluation claims.
+///
+/// ## Arguments
+///
+/// * `params` - common STIR protocol parameters.
+/// * `ntt` - the NTT, whose domain must extend [`StirParams::ntt_subspace`].
+/// * `merkle_prover` - the Merkle tree prover to use for committing
+/// * `message_writer` - a closure that writes the interleaved message to encode and commit
+pub fn commit_interleaved_with<F, FA, P, NTT, MerkleProver, VCS>(
+	params: &StirParams<F, FA>,
+	ntt: &NTT,
+	merkle_prover: &MerkleProver,
+	message_writer: impl FnOnce(&mut [P]),
+) -> Result<CommitOutput<P, VCS::Digest, MerkleProver::Committed>, Error>
+where
+	F: BinaryField + ExtensionField<FA>,
+	FA: BinaryField,
+	P: PackedField<Scalar = F> + PackedExtension<FA>,
+	NTT: AdditiveNTT<FA> + Sync,
+	MerkleProver: MerkleTreeProver<F, Scheme = VCS>,
+	VCS: MerkleTreeScheme<F>,
+{
+	check_ntt_subspace(params, ntt)?;
+
+	let rs_code = params.rs_code();
+	let log_batch_size = params.log_batch_size();
+	let log_elems = rs_code.log_dim() + log_batch_size;
+	if log_elems < P::LOG_WIDTH {
+		bail!(Error::InvalidArgs(
+			"interleaved message must fill at least one packed element".to_string()
+		));
+	}
+
+	let mut encoded = zeroed_vec::<P>(1 << (params.log_len() - P::LOG_WIDTH));
+	let (message, rest) = encoded.split_at_mut(1 << (log_elems - P::LOG_WIDTH));
+	message_writer(message);
+	for chunk in rest.chunks_mut(message.len()) {
+		chunk.copy_from_slice(message);
+	}
+
+	// Encode over the first points of the full NTT domain rather than an NTT over the code
+	// subspace alone, so that the later oracles share the layers of the same NTT.
+	let shape = NTTShape {
+		log_x: log_batch_size,
+		log_y: rs_code.log_len(),
+		..Default::default()
+	};
+	ntt.forward_transform_ext(
+		&mut encoded,
+		shape,
+		0,
+		ntt.log_domain_size() - rs_code.log_len(),
+		rs_code.log_inv_rate(),
+	)?;
+
+	let coset_log_len = params.oracle_log_coset_size(0);
+	let log_len = params.oracle_index_bits(0);
+	let (commitment, vcs_committed) = if coset_log_len > P::LOG_WIDTH {
+		let iterated_big_chunks = to_par_scalar_big_chunks(&encoded, 1 << coset_log_len);
+
+		merkle_prover
+			.commit_iterated(iterated_big_chunks, log_len)
+			.map_err(|err| Error::VectorCommit(Box::new(err)))?
+	} else {
+		let iterated_small_chunks = to_par_scalar_small_chunks(&encoded, 1 << coset_log_len);
+
+		merkle_prover
+			.commit_iterated(iterated_small_chunks, log_len)
+			.map_err(|err| Error::VectorCommit(Box::new(err)))?
+	};
+
+	Ok(CommitOutput {
+		commitment: commitment.root,
+		committed: vcs_committed,
+		codeword: encoded,
+	})
+}
+
+/// An oracle sent during the fold rounds, along with the virtual function the verifier tests in
+/// its place.
+struct CommittedOracle<F, FA, Committed>
+where
+	F: BinaryField,
+	FA: BinaryField,
+{
+	/// The oracle values, ordered by Merkle leaf.
+	leaves: Vec<F>,
+	committed: Committed,
+	quotient: ShiftQuotient<F, FA>,
+}
+
+/// The prover for the STIR protocol.
+///
+/// Unlike FRI, the prover writes the oracle commitments and openings to the transcript during the
+/// fold rounds, because the verifier's shift queries determine the functions that are folded in
+/// subsequent rounds.
+pub struct StirFolder<'a, F, FA, P, NTT, MerkleProver, VCS>
+where
+	F: BinaryField,
+	FA: BinaryField,
+	P: PackedField<Scalar = F>,
+	MerkleProver: MerkleTreeProver<F, Scheme = VCS>,
+	VCS: MerkleTreeScheme<F>,
+{
+	params: &'a StirParams<F, FA>,
+	ntt: &'a NTT,
+	merkle_prover: &'a MerkleProver,
+	codeword: &'a [P],
+	codeword_committed: &'a MerkleProver::Committed,
+	oracles: Vec<CommittedOracle<F, FA, MerkleProver::Committed>>,
+	curr_round: usize,
+	unprocessed_challenges: Vec<F>,
+}
+
+impl<'a, F, FA, P, NTT, MerkleProver, VCS> StirFolder<'a, F, FA, P, NTT, MerkleProver, VCS>
+where
+	F: TowerField + ExtensionField<FA> + PackedExtension<FA>,
+	FA: BinaryField,
+	P: PackedField<Scalar = F>,
+	NTT: AdditiveNTT<FA> + Sync,
+	MerkleProver: MerkleTreeProver<F, Scheme = VCS>,
+	VCS: MerkleTreeScheme<F, Digest: SerializeBytes>,
+{
+	/// Constructs a new folder.
+	pub fn new(
+		params: &'a StirParams<F, FA>,
+		ntt: &'a NTT,
+		merkle_prover: &'a MerkleProver,
+		codeword: &'a [P],
+		committed: &'a MerkleProver::Committed,
+	) -> Result<Self, Error> {
+		check_ntt_subspace(params, ntt)?;
+		if len_packed_slice(codeword) < 1 << params.log_len() {
+			bail!(Error::InvalidArgs(
+				"Reed–Solomon code length must match interleaved codeword length".to_string(),
+			));
+		}
+
+		Ok(Self {
+			params,
+			ntt,
+			merkle_prover,
+			codeword,
+			codeword_committed: committed,
+			oracles: Vec::with_capacity(params.n_oracles()),
+			curr_round: 0,
+			unprocessed_challenges: Vec::with_capacity(params.n_fold_rounds()),
+		})
+	}
+
+	/// Number of fold rounds, including the final fold.
+	pub const fn n_rounds(&self) -> usize {
+		self.params.n_fold_rounds()
+	}
+
+	/// Number of times `execute_fold_round` has been called.
+	pub const fn curr_round(&self) -> usize {
+		self.curr_round
+	}
+
+	fn is_commitment_round(&self) -> bool {
+		self.params.fold_arities().get(self.oracles.len())
+			== Some(&self.unprocessed_challenges.len())
+	}
+
+	/// Executes the next fold round.
+	///
+	/// When the round completes a group of folding challenges, the prover commits the next oracle,
+	/// samples the shift queries to the previous oracle and answers them in the transcript.
+	#[instrument(
+		skip_all,
+		name = "stir::StirFolder::execute_fold_round",
+		level = "debug"
+	)]
+	pub fn execute_fold_round<Challenger_>(
+		&mut self,
+		challenge: F,
+		transcript: &mut ProverTranscript<Challenger_>,
+	) -> Result<(), Error>
+	where
+		Challenger_: Challenger,
+	{
+		if self.curr_round == self.n_rounds() {
+			bail!(Error::TooManyFoldExecutions {
+				max_folds: self.n_rounds()
+			});
+		}
+		self.unprocessed_challenges.push(challenge);
+		self.curr_round += 1;
+
+		if !self.is_commitment_round() {
+			return Ok(());
+		}
+
+		let oracle = self.oracles.len();
+		let next_oracle = oracle + 1;
+		let folded = self.fold_oracle(oracle);
+		let leaves = self.extend_folded(next_oracle, &folded)?;
+
+		let leaf_size =
+			self.params.oracle_width(next_oracle) << self.params.oracle_log_coset_size(next_oracle);
+		let (commitment, committed) = self
+			.merkle_prover
+			.commit(&leaves, leaf_size)
+			.map_err(|err| Error::VectorCommit(Box::new(err)))?;
+		transcript.message().write(&commitment.root);
+
+		let combination_challenge =
+			(self.params.oracle_width(next_oracle) == 2).then(|| transcript.sample());
+		let degree_correction_challenge = transcript.sample();
+		let indices = sample_distinct_indices(
+			transcript,
+			self.params.n_test_queries()[oracle],
+			self.params.oracle_index_bits(oracle),
+		);
+		self.prove_openings(oracle, &indices, &mut transcript.decommitment())?;
+
+		let domain = layer_domain(self.ntt, self.params.oracle_layer(next_oracle));
+		let (points, answers) = indices
+			.iter()
+			.map(|&index| {
+				let point = F::from(domain.get(folded_leaf_index(self.params, oracle, index)));
+				let (committed_fold, virtual_fold) = folded[index];
+				let answer = match combination_challenge {
+					Some(beta) => committed_fold + beta * virtual_fold,
+					None => committed_fold,
+				};
+				(point, answer)
+			})
+			.unzip();
+		let quotient = ShiftQuotient::new(
+			domain,
+			combination_challenge,
+			points,
+			answers,
+			degree_correction_challenge,
+		);
+
+		self.oracles.push(CommittedOracle {
+			leaves,
+			committed,
+			quotient,
+		});
+		self.unprocessed_challenges.clear();
+		Ok(())
+	}
+
+	/// Folds every leaf of an oracle with the unprocessed challenges.
+	fn fold_oracle(&self, oracle: usize) -> Vec<(F, F)> {
+		let params = self.params;
+		let ntt = self.ntt;
+		let challenges = &self.unprocessed_challenges[..];
+		let n_leaves = 1 << params.oracle_index_bits(oracle);
+		match oracle.checked_sub(1) {
+			None => {
+				let codeword = self.codeword;
+				let log_coset_size = params.oracle_log_coset_size(0);
+				(0..n_leaves)
+					.into_par_iter()
+					.map(|index| {
+						let leaf = iter_packed_slice_with_offset(codeword, index << log_coset_size)
+							.take(1 << log_coset_size)
+							.collect::<Vec<_>>();
+						fold_oracle_leaf(params, ntt, 0, None, index, &leaf, challenges)
+					})
+					.collect()
+			}
+			Some(prev) => {
+				let leaves = &self.oracles[prev].leaves;
+				let quotient = &self.oracles[prev].quotient;
+				leaves
+					.par_chunks(leaves.len() / n_leaves)
+					.enumerate()
+					.map(|(index, leaf)| {
+						fold_oracle_leaf(
+							params,
+							ntt,
+							oracle,
+							Some(quotient),
+							index,
+							leaf,
+							challenges,
+						)
+					})
+					.collect()
+			}
+		}
+	}
+
+	/// Extends the folded values of the previous oracle to the evaluation domain of the next one
+	/// and arranges them into Merkle leaves.
+	fn extend_folded(&self, next_oracle: usize, folded: &[(F, F)]) -> Result<Vec<F>, Error> {
+		let params = self.params;
+		let ntt = self.ntt;
+		let log_dim = params.oracle_log_dim(next_oracle);
+		let log_len = params.oracle_log_len(next_oracle);
+		let coset_bits = ntt.log_domain_size() - params.oracle_layer(next_oracle) - log_dim;
+		let shape = NTTShape {
+			log_y: log_dim,
+			..Default::default()
+		};
+
+		// The folded values lie on consecutive subspace cosets of the next layer, the first of
+		// which determines the polynomial.
+		let folded_coset = folded_leaf_index(params, next_oracle - 1, 0) >> log_dim;
+		let first_coset = params.oracle_domain_offset(next_oracle) >> log_dim;
+		let evals = (0..params.oracle_width(next_oracle))
+			.map(|chain| {
+				let mut coeffs = folded[..1 << log_dim]
+					.iter()
+					.map(|&(committed_fold, virtual_fold)| {
+						if chain == 0 {
+							committed_fold
+						} else {
+							virtual_fold
+						}
+					})
+					.collect::<Vec<_>>();
+				ntt.inverse_transform_ext(&mut coeffs, shape, folded_coset, coset_bits, 0)?;
+
+				let mut values = vec![F::ZERO; 1 << log_len];
+				values
+					.par_chunks_mut(1 << log_dim)
+					.enumerate()
+					.try_for_each(|(i, chunk)| {
+						chunk.copy_from_slice(&coeffs);
+						ntt.forward_transform_ext(chunk, shape, first_coset + i, coset_bits, 0)
+					})?;
+				Ok(values)
+			})
+			.collect::<Result<Vec<_>, Error>>()?;
+
+		let log_coset_size = params.oracle_log_coset_size(next_oracle);
+		let leaves = (0..1 << (log_len - log_coset_size))
+			.flat_map(|index| {
+				evals.iter().flat_map(move |values| {
+					&values[index << log_coset_size..][..1 << log_coset_size]
+				})
+			})
+			.copied()
+			.collect();
+		Ok(leaves)
+	}
+
+	fn leaf_values(&self, oracle: usize, index: usize) -> Vec<F> {
+		let leaf_size =
+			self.params.oracle_width(oracle) << self.params.oracle_log_coset_size(oracle);
+		match oracle.checked_sub(1) {
+			None => iter_packed_slice_with_offset(self.codeword, index * leaf_size)
+				.take(leaf_size)
+				.collect(),
+			Some(prev) => self.oracles[prev].leaves[index * leaf_size..][..leaf_size].to_vec(),
+		}
+	}
+
+	fn prove_openings<B: BufMut>(
+		&self,
+		oracle: usize,
+		indices: &[usize],
+		advice: &mut TranscriptWriter<B>,
+	) -> Result<(), Error> {
+		let committed = match oracle.checked_sub(1) {
+			None => self.codeword_committed,
+			Some(prev) => &self.oracles[prev].committed,
+		};
+		let layer_depth = self.merkle_prover.scheme().optimal_verify_layer(
+			self.params.n_test_queries()[oracle],
+			self.params.oracle_index_bits(oracle),
+		);
+		let layer = self
+			.merkle_prover
+			.layer(committed, layer_depth)
+			.map_err(|err| Error::VectorCommit(Box::new(err)))?;
+		advice.write_slice(layer);
+
+		for &index in indices {
+			advice.write_scalar_slice(&self.leaf_values(oracle, index));
+			self.merkle_prover
+				.prove_opening(committed, layer_depth, index, advice)
+				.map_err(|err| Error::VectorCommit(Box::new(err)))?;
+		}
+		Ok(())
+	}
+
+	/// Finishes the proof after all fold rounds.
+	///
+	/// The prover sends the values that the last oracle and its virtual function fold to, and
+	/// answers the final queries to the last oracle.
+	#[instrument(skip_all, name = "stir::StirFolder::finish_proof", level = "debug")]
+	pub fn finish_proof<Challenger_>(
+		self,
+		transcript: &mut ProverTranscript<Challenger_>,
+	) -> Result<(), Error>
+	where
+		Challenger_: Challenger,
+	{
+		if self.curr_round != self.n_rounds() {
+			bail!(Error::EarlyProverFinish);
+		}
+
+		// Every leaf of the last oracle folds to the same value, so it suffices to fold the first.
+		let oracle = self.oracles.len();
+		let quotient = oracle
+			.checked_sub(1)
+			.map(|prev| &self.oracles[prev].quotient);
+		let (committed_fold, virtual_fold) = fold_oracle_leaf(
+			self.params,
+			self.ntt,
+			oracle,
+			quotient,
+			0,
+			&self.leaf_values(oracle, 0),
+			&self.unprocessed_challenges,
+		);
+
+		let mut message = transcript.message();
+		message.write_scalar(committed_fold);
+		if oracle > 0 {
+			message.write_scalar(virtual_fold);
+		}
+
+		let indices = sample_distinct_indices(
+			transcript,
+			self.params.n_test_queries()[oracle],
+			self.params.oracle_index_bits(oracle),
+		);
+		self.prove_openings(oracle, &indices, &mut transcript.decommitment())
+	}
+}
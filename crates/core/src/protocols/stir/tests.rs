@@ -0,0 +1,333 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::iter::repeat_with;
+
+use binius_field::{
+	BinaryField, BinaryField16b, BinaryField32b, BinaryField128b, ExtensionField, PackedExtension,
+	PackedField, TowerField,
+	arch::{OptimalUnderlier128b, OptimalUnderlier256b, OptimalUnderlier512b},
+	as_packed_field::{PackScalar, PackedType},
+	underlier::UnderlierType,
+};
+use binius_hal::{ComputationBackendExt, make_portable_backend};
+use binius_hash::{
+	PseudoCompressionFunction,
+	groestl::{Groestl256, Groestl256ByteCompression},
+};
+use binius_math::{MultilinearExtension, TowerTop};
+use binius_ntt::SingleThreadedNTT;
+use digest::Output;
+use rand::prelude::*;
+
+use crate::{
+	fiat_shamir::{CanSample, HasherChallenger},
+	merkle_tree::{KAryMerkleTreeProver, MerkleTreeProver},
+	protocols::{
+		fri::{self, CommitOutput},
+		stir::{self, Error, StirFolder, StirParams, StirVerifier},
+	},
+	reed_solomon::reed_solomon::ReedSolomonCode,
+	transcript::ProverTranscript,
+};
+
+/// Runs the prover and verifier and returns the final value and the multilinear evaluation of the
+/// message at the folding challenges.
+fn run_commit_prove_verify<U, F, FA, const MERKLE_ARITY: usize>(
+	params: &StirParams<F, FA>,
+	ntt: &SingleThreadedNTT<FA>,
+	corrupt_codeword: bool,
+) -> Result<(F, F), Error>
+where
+	U: UnderlierType + PackScalar<F> + PackScalar<FA>,
+	F: TowerField + ExtensionField<FA> + PackedField<Scalar = F> + PackedExtension<FA> + TowerTop,
+	FA: BinaryField,
+	PackedType<U, F>: PackedField,
+	Groestl256ByteCompression: PseudoCompressionFunction<Output<Groestl256>, MERKLE_ARITY>,
+{
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let merkle_prover =
+		KAryMerkleTreeProver::<_, Groestl256, _, MERKLE_ARITY>::new(Groestl256ByteCompression);
+
+	// Generate a random message
+	let msg = repeat_with(|| <PackedType<U, F>>::random(&mut rng))
+		.take(params.rs_code().dim() << params.log_batch_size() >> <PackedType<U, F>>::LOG_WIDTH)
+		.collect::<Vec<_>>();
+
+	// Prover commits the message
+	let CommitOutput {
+		commitment: mut codeword_commitment,
+		mut committed,
+		mut codeword,
+	} = stir::commit_interleaved(params, ntt, &merkle_prover, &msg).unwrap();
+
+	if corrupt_codeword {
+		// Replace the codeword with random values and commit to them instead.
+		codeword = repeat_with(|| <PackedType<U, F>>::random(&mut rng))
+			.take(codeword.len())
+			.collect();
+		let scalars = PackedField::iter_slice(&codeword).collect::<Vec<_>>();
+		let (commitment, tree) = merkle_prover
+			.commit(&scalars, 1 << params.fold_arities().first().unwrap())
+			.unwrap();
+		codeword_commitment = commitment.root;
+		committed = tree;
+	}
+
+	let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	prover_transcript.message().write(&codeword_commitment);
+	let mut folder = StirFolder::new(params, ntt, &merkle_prover, &codeword, &committed).unwrap();
+	for _ in 0..params.n_fold_rounds() {
+		let challenge = prover_transcript.sample();
+		folder
+			.execute_fold_round(challenge, &mut prover_transcript)
+			.unwrap();
+	}
+	folder.finish_proof(&mut prover_transcript).unwrap();
+
+	let mut verifier_transcript = prover_transcript.into_verifier();
+	let codeword_commitment = verifier_transcript.message().read().unwrap();
+	let mut verifier =
+		StirVerifier::new(params, merkle_prover.scheme(), &codeword_commitment).unwrap();
+	let mut challenges = Vec::with_capacity(params.n_fold_rounds());
+	for _ in 0..params.n_fold_rounds() {
+		let challenge = verifier_transcript.sample();
+		challenges.push(challenge);
+		verifier.receive_fold_challenge(challenge, &mut verifier_transcript)?;
+	}
+	let final_value = verifier.finish(&mut verifier_transcript)?;
+	verifier_transcript.finalize().unwrap();
+
+	let backend = make_portable_backend();
+	let eval_query = backend.multilinear_query::<F>(&challenges).unwrap();
+	let multilin = MultilinearExtension::from_values_slice(&msg).unwrap();
+	let computed_eval = multilin.evaluate(&eval_query).unwrap();
+	Ok((final_value, computed_eval))
+}
+
+fn test_commit_prove_verify_success<U, F, FA>(
+	log_dimension: usize,
+	log_inv_rate: usize,
+	log_batch_size: usize,
+	arities: &[usize],
+) where
+	U: UnderlierType + PackScalar<F> + PackScalar<FA>,
+	F: TowerField + ExtensionField<FA> + PackedField<Scalar = F> + PackedExtension<FA> + TowerTop,
+	FA: BinaryField,
+	PackedType<U, F>: PackedField,
+{
+	test_commit_prove_verify_success_with_merkle_arity::<U, F, FA, 2>(
+		log_dimension,
+		log_inv_rate,
+		log_batch_size,
+		arities,
+	);
+}
+
+fn test_commit_prove_verify_success_with_merkle_arity<U, F, FA, const MERKLE_ARITY: usize>(
+	log_dimension: usize,
+	log_inv_rate: usize,
+	log_batch_size: usize,
+	arities: &[usize],
+) where
+	U: UnderlierType + PackScalar<F> + PackScalar<FA>,
+	F: TowerField + ExtensionField<FA> + PackedField<Scalar = F> + PackedExtension<FA> + TowerTop,
+	FA: BinaryField,
+	PackedType<U, F>: PackedField,
+	Groestl256ByteCompression: PseudoCompressionFunction<Output<Groestl256>, MERKLE_ARITY>,
+{
+	let rs_code = ReedSolomonCode::<FA>::new(log_dimension, log_inv_rate).unwrap();
+	let n_test_queries = vec![3; arities.len() + 1];
+	let params =
+		StirParams::new(rs_code, log_batch_size, arities.to_vec(), n_test_queries).unwrap();
+	let ntt = SingleThreadedNTT::with_subspace(params.ntt_subspace()).unwrap();
+
+	let (final_value, computed_eval) =
+		run_commit_prove_verify::<U, F, FA, MERKLE_ARITY>(&params, &ntt, false).unwrap();
+	assert_eq!(final_value, computed_eval);
+}
+
+#[test]
+fn test_commit_prove_verify_success_128b_higher_arity() {
+	test_commit_prove_verify_success::<OptimalUnderlier128b, BinaryField128b, BinaryField16b>(
+		8,
+		2,
+		0,
+		&[3, 2],
+	);
+}
+
+#[test]
+fn test_commit_prove_verify_success_128b_constant_arity() {
+	test_commit_prove_verify_success::<OptimalUnderlier128b, BinaryField128b, BinaryField16b>(
+		8,
+		1,
+		0,
+		&[2, 2, 2],
+	);
+}
+
+#[test]
+fn test_commit_prove_verify_success_128b_single_oracle() {
+	test_commit_prove_verify_success::<OptimalUnderlier128b, BinaryField128b, BinaryField16b>(
+		8,
+		2,
+		0,
+		&[4],
+	);
+}
+
+#[test]
+fn test_commit_prove_verify_success_128b_4ary_merkle() {
+	test_commit_prove_verify_success_with_merkle_arity::<
+		OptimalUnderlier128b,
+		BinaryField128b,
+		BinaryField16b,
+		4,
+	>(8, 2, 0, &[3, 2]);
+}
+
+#[test]
+fn test_commit_prove_verify_success_128b_interleaved() {
+	test_commit_prove_verify_success::<OptimalUnderlier128b, BinaryField128b, BinaryField16b>(
+		6,
+		2,
+		2,
+		&[3, 2],
+	);
+}
+
+#[test]
+fn test_commit_prove_verify_success_128b_interleaved_packed() {
+	test_commit_prove_verify_success::<OptimalUnderlier256b, BinaryField128b, BinaryField16b>(
+		6,
+		2,
+		2,
+		&[3, 2],
+	);
+}
+
+#[test]
+fn test_commit_prove_verify_success_without_folding() {
+	test_commit_prove_verify_success::<OptimalUnderlier128b, BinaryField128b, BinaryField16b>(
+		4,
+		2,
+		2,
+		&[],
+	);
+}
+
+#[test]
+fn test_commit_prove_verify_success_with_larger_ntt() {
+	let rs_code = ReedSolomonCode::<BinaryField16b>::new(8, 2).unwrap();
+	let params = StirParams::<BinaryField128b, _>::new(rs_code, 0, vec![3, 2], vec![3; 3]).unwrap();
+	let ntt = SingleThreadedNTT::new(params.ntt_log_domain_size() + 2).unwrap();
+
+	let (final_value, computed_eval) =
+		run_commit_prove_verify::<OptimalUnderlier128b, _, _, 2>(&params, &ntt, false).unwrap();
+	assert_eq!(final_value, computed_eval);
+}
+
+#[test]
+fn test_verifier_rejects_non_codeword() {
+	let rs_code = ReedSolomonCode::<BinaryField16b>::new(8, 2).unwrap();
+	let params = StirParams::<BinaryField128b, _>::new(rs_code, 0, vec![3, 2], vec![8; 3]).unwrap();
+	let ntt = SingleThreadedNTT::with_subspace(params.ntt_subspace()).unwrap();
+
+	let result = run_commit_prove_verify::<OptimalUnderlier128b, _, _, 2>(&params, &ntt, true);
+	assert!(matches!(result, Err(Error::Verification(_))));
+}
+
+#[test]
+fn test_fewer_queries_than_fri() {
+	let security_bits = 96;
+	let rs_code = ReedSolomonCode::<BinaryField32b>::new(20, 1).unwrap();
+	let params = StirParams::<BinaryField128b, BinaryField32b>::choose_with_constant_fold_arity(
+		20,
+		security_bits,
+		1,
+		4,
+	)
+	.unwrap();
+	let stir_queries = params.n_test_queries().iter().sum::<usize>();
+
+	// FRI opens every oracle at each of its test queries.
+	let fri_queries =
+		fri::calculate_n_test_queries::<BinaryField128b, _>(security_bits, &rs_code).unwrap();
+	assert!(stir_queries < fri_queries * (params.n_oracles() + 1));
+}
+
+#[test]
+fn test_codeword_matches_fri() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let merkle_prover = KAryMerkleTreeProver::<_, Groestl256, _, 2>::new(Groestl256ByteCompression);
+
+	let rs_code = ReedSolomonCode::<BinaryField16b>::new(8, 2).unwrap();
+	let stir_params = StirParams::<BinaryField128b, BinaryField16b>::new(
+		ReedSolomonCode::new(8, 2).unwrap(),
+		1,
+		vec![3, 2],
+		vec![3; 3],
+	)
+	.unwrap();
+	let fri_params =
+		fri::FRIParams::new(ReedSolomonCode::new(8, 2).unwrap(), 1, vec![3, 2], 3).unwrap();
+
+	let msg = repeat_with(|| BinaryField128b::random(&mut rng))
+		.take(rs_code.dim() << 1)
+		.collect::<Vec<_>>();
+
+	let stir_ntt = SingleThreadedNTT::with_subspace(stir_params.ntt_subspace()).unwrap();
+	let stir_output =
+		stir::commit_interleaved(&stir_params, &stir_ntt, &merkle_prover, &msg).unwrap();
+	let fri_ntt = SingleThreadedNTT::with_subspace(rs_code.subspace()).unwrap();
+	let fri_output =
+		fri::commit_interleaved(&rs_code, &fri_params, &fri_ntt, &merkle_prover, &msg).unwrap();
+
+	assert_eq!(stir_output.codeword, fri_output.codeword);
+	assert_eq!(stir_output.commitment, fri_output.commitment);
+}
+
+/// Commits a message with the given packing and returns the commitment and codeword scalars.
+fn commit_packed<U>(
+	params: &StirParams<BinaryField128b, BinaryField16b>,
+	ntt: &SingleThreadedNTT<BinaryField16b>,
+	msg: &[BinaryField128b],
+) -> (Output<Groestl256>, Vec<BinaryField128b>)
+where
+	U: UnderlierType + PackScalar<BinaryField128b> + PackScalar<BinaryField16b>,
+	PackedType<U, BinaryField128b>: PackedExtension<BinaryField16b>,
+{
+	let merkle_prover = KAryMerkleTreeProver::<_, Groestl256, _, 2>::new(Groestl256ByteCompression);
+	let packed_msg = msg
+		.chunks(<PackedType<U, BinaryField128b>>::WIDTH)
+		.map(|chunk| <PackedType<U, BinaryField128b>>::from_scalars(chunk.iter().copied()))
+		.collect::<Vec<_>>();
+	let output = stir::commit_interleaved(params, ntt, &merkle_prover, &packed_msg).unwrap();
+	(output.commitment, PackedField::iter_slice(&output.codeword).collect())
+}
+
+#[test]
+fn test_parallel_iterator_for_commitments() {
+	// With a first fold arity of 1, the cosets committed as Merkle leaves span several packed
+	// elements of width 1 and are split within packed elements of width 2 or 4, so the commitment
+	// takes both parallel iterators over the codeword.
+	let mut rng = StdRng::seed_from_u64(0);
+	let params = StirParams::<BinaryField128b, BinaryField16b>::new(
+		ReedSolomonCode::new(6, 2).unwrap(),
+		0,
+		vec![1, 2],
+		vec![3; 3],
+	)
+	.unwrap();
+	let ntt = SingleThreadedNTT::with_subspace(params.ntt_subspace()).unwrap();
+	let msg = repeat_with(|| BinaryField128b::random(&mut rng))
+		.take(params.rs_code().dim())
+		.collect::<Vec<_>>();
+
+	let big_chunks = commit_packed::<OptimalUnderlier128b>(&params, &ntt, &msg);
+	let small_chunks = commit_packed::<OptimalUnderlier256b>(&params, &ntt, &msg);
+	let smaller_chunks = commit_packed::<OptimalUnderlier512b>(&params, &ntt, &msg);
+	assert_eq!(big_chunks, small_chunks);
+	assert_eq!(big_chunks, smaller_chunks);
+}
@@ -0,0 +1,225 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::{BinaryField, ExtensionField, TowerField};
+use binius_ntt::SingleThreadedNTT;
+use binius_utils::{DeserializeBytes, bail};
+use bytes::Buf;
+use tracing::instrument;
+
+use super::{
+	VerificationError,
+	common::{
+		ShiftQuotient, StirParams, fold_oracle_leaf, folded_leaf_index, layer_domain,
+		sample_distinct_indices,
+	},
+	error::Error,
+};
+use crate::{
+	fiat_shamir::{CanSample, Challenger},
+	merkle_tree::MerkleTreeScheme,
+	transcript::{TranscriptReader, VerifierTranscript},
+};
+
+/// A verifier for the STIR protocol.
+///
+/// The verifier receives the folding challenges one at a time, because the shift queries to each
+/// oracle are checked as soon as the next oracle is received.
+#[derive(Debug)]
+pub struct StirVerifier<'a, F, FA, VCS>
+where
+	F: BinaryField + ExtensionField<FA>,
+	FA: BinaryField,
+	VCS: MerkleTreeScheme<F>,
+{
+	params: &'a StirParams<F, FA>,
+	vcs: &'a VCS,
+	ntt: SingleThreadedNTT<FA>,
+	/// Received commitments to the codeword and the round oracles.
+	commitments: Vec<VCS::Digest>,
+	/// The virtual functions tested in place of the round oracles.
+	quotients: Vec<ShiftQuotient<F, FA>>,
+	curr_round: usize,
+	unprocessed_challenges: Vec<F>,
+}
+
+impl<'a, F, FA, VCS> StirVerifier<'a, F, FA, VCS>
+where
+	F: TowerField + ExtensionField<FA>,
+	FA: BinaryField,
+	VCS: MerkleTreeScheme<F, Digest: DeserializeBytes>,
+{
+	pub fn new(
+		params: &'a StirParams<F, FA>,
+		vcs: &'a VCS,
+		codeword_commitment: &VCS::Digest,
+	) -> Result<Self, Error> {
+		let ntt = SingleThreadedNTT::with_subspace(params.ntt_subspace())?;
+
+		let mut commitments = Vec::with_capacity(params.n_oracles() + 1);
+		commitments.push(codeword_commitment.clone());
+		Ok(Self {
+			params,
+			vcs,
+			ntt,
+			commitments,
+			quotients: Vec::with_capacity(params.n_oracles()),
+			curr_round: 0,
+			unprocessed_challenges: Vec::with_capacity(params.n_fold_rounds()),
+		})
+	}
+
+	/// Number of fold rounds, including the final fold.
+	pub const fn n_rounds(&self) -> usize {
+		self.params.n_fold_rounds()
+	}
+
+	/// Receives the folding challenge for the next round.
+	///
+	/// When the challenge completes a group of folding challenges, the verifier reads the next
+	/// oracle commitment and checks the shift queries to the previous oracle.
+	#[instrument(
+		skip_all,
+		name = "stir::StirVerifier::receive_fold_challenge",
+		level = "debug"
+	)]
+	pub fn receive_fold_challenge<Challenger_>(
+		&mut self,
+		challenge: F,
+		transcript: &mut VerifierTranscript<Challenger_>,
+	) -> Result<(), Error>
+	where
+		Challenger_: Challenger,
+	{
+		if self.curr_round == self.n_rounds() {
+			bail!(Error::TooManyFoldExecutions {
+				max_folds: self.n_rounds()
+			});
+		}
+		self.unprocessed_challenges.push(challenge);
+		self.curr_round += 1;
+
+		let oracle = self.quotients.len();
+		if self.params.fold_arities().get(oracle) != Some(&self.unprocessed_challenges.len()) {
+			return Ok(());
+		}
+
+		let next_oracle = oracle + 1;
+		let commitment = transcript.message().read::<VCS::Digest>()?;
+		let combination_challenge =
+			(self.params.oracle_width(next_oracle) == 2).then(|| transcript.sample());
+		let degree_correction_challenge = transcript.sample();
+		let indices = sample_distinct_indices(
+			transcript,
+			self.params.n_test_queries()[oracle],
+			self.params.oracle_index_bits(oracle),
+		);
+		let folds = self.verify_openings(oracle, &indices, &mut transcript.decommitment())?;
+
+		let domain = layer_domain(&self.ntt, self.params.oracle_layer(next_oracle));
+		let (points, answers) = std::iter::zip(&indices, folds)
+			.map(|(&index, (committed_fold, virtual_fold))| {
+				let point = F::from(domain.get(folded_leaf_index(self.params, oracle, index)));
+				let answer = match combination_challenge {
+					Some(beta) => committed_fold + beta * virtual_fold,
+					None => committed_fold,
+				};
+				(point, answer)
+			})
+			.unzip();
+
+		self.commitments.push(commitment);
+		self.quotients.push(ShiftQuotient::new(
+			domain,
+			combination_challenge,
+			points,
+			answers,
+			degree_correction_challenge,
+		));
+		self.unprocessed_challenges.clear();
+		Ok(())
+	}
+
+	/// Verifies the openings of an oracle at the given leaves and folds them with the unprocessed
+	/// challenges.
+	fn verify_openings<B: Buf>(
+		&self,
+		oracle: usize,
+		indices: &[usize],
+		advice: &mut TranscriptReader<B>,
+	) -> Result<Vec<(F, F)>, Error> {
+		let index_bits = self.params.oracle_index_bits(oracle);
+		let layer_depth = self
+			.vcs
+			.optimal_verify_layer(self.params.n_test_queries()[oracle], index_bits);
+		let layer = advice.read_vec(1 << layer_depth)?;
+		self.vcs
+			.verify_layer(&self.commitments[oracle], layer_depth, &layer)
+			.map_err(|err| Error::VectorCommit(Box::new(err)))?;
+
+		let leaf_size =
+			self.params.oracle_width(oracle) << self.params.oracle_log_coset_size(oracle);
+		let quotient = oracle.checked_sub(1).map(|prev| &self.quotients[prev]);
+		indices
+			.iter()
+			.map(|&index| {
+				let leaf = advice.read_scalar_slice(leaf_size)?;
+				self.vcs
+					.verify_opening(index, &leaf, layer_depth, index_bits, &layer, advice)
+					.map_err(|err| Error::VectorCommit(Box::new(err)))?;
+				Ok(fold_oracle_leaf(
+					self.params,
+					&self.ntt,
+					oracle,
+					quotient,
+					index,
+					&leaf,
+					&self.unprocessed_challenges,
+				))
+			})
+			.collect()
+	}
+
+	/// Checks the final queries after all fold rounds and returns the final folded value.
+	///
+	/// The final value is the evaluation of the committed interleaved message at the folding
+	/// challenges, as in FRI.
+	#[instrument(skip_all, name = "stir::StirVerifier::finish", level = "debug")]
+	pub fn finish<Challenger_>(
+		self,
+		transcript: &mut VerifierTranscript<Challenger_>,
+	) -> Result<F, Error>
+	where
+		Challenger_: Challenger,
+	{
+		if self.curr_round != self.n_rounds() {
+			bail!(Error::InvalidArgs(format!(
+				"received {} folding challenges, expected {}",
+				self.curr_round,
+				self.n_rounds(),
+			)));
+		}
+
+		let oracle = self.quotients.len();
+		let mut message = transcript.message();
+		let committed_fold = message.read_scalar::<F>()?;
+		let virtual_fold = if oracle > 0 {
+			message.read_scalar::<F>()?
+		} else {
+			committed_fold
+		};
+
+		let indices = sample_distinct_indices(
+			transcript,
+			self.params.n_test_queries()[oracle],
+			self.params.oracle_index_bits(oracle),
+		);
+		let folds = self.verify_openings(oracle, &indices, &mut transcript.decommitment())?;
+		for (index, (committed, virtual_)) in std::iter::zip(indices, folds) {
+			if committed != committed_fold || virtual_ != virtual_fold {
+				return Err(VerificationError::IncorrectFinalFold { oracle, index }.into());
+			}
+		}
+
+		Ok(committed_fold)
+	}
+}
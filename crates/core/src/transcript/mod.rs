@@ -11,15 +11,26 @@
 //! used for values that were previously committed to in the transcript tape.** For example, it is
 //! secure to write a Merkle tree root to the transcript tape, sample a random index, then provide
 //! the Merkle leaf opening at that index in the advice tape.
+//!
+//! Both transcripts can record the operations performed on them for debugging, see [`recording`].
 
 mod error;
+pub mod recording;
 
-use std::{fs::File, io::Write, iter::repeat_with, slice};
+use std::{
+	any::type_name,
+	fs::File,
+	io::Write,
+	iter::{self, repeat_with},
+	panic::Location,
+	slice,
+};
 
 use binius_field::{PackedField, TowerField};
 use binius_utils::{DeserializeBytes, SerializationMode, SerializeBytes};
 use bytes::{Buf, BufMut, Bytes, BytesMut, buf::UninitSlice};
 pub use error::Error;
+use recording::{Recorder, Tee, TranscriptOperation, TranscriptRecording};
 use tracing::warn;
 
 use crate::fiat_shamir::{CanSample, CanSampleBits, Challenger};
@@ -33,6 +44,7 @@ use crate::fiat_shamir::{CanSample, CanSampleBits, Challenger};
 pub struct ProverTranscript<Challenger> {
 	combined: FiatShamirBuf<BytesMut, Challenger>,
 	debug_assertions: bool,
+	recording: Option<TranscriptRecording>,
 }
 
 /// Verifier transcript over some Challenger that reads from the internal tape and `CanSample<F:
@@ -43,7 +55,9 @@ pub struct ProverTranscript<Challenger> {
 #[derive(Debug, Clone)]
 pub struct VerifierTranscript<Challenger> {
 	combined: FiatShamirBuf<Bytes, Challenger>,
+	tape_len: usize,
 	debug_assertions: bool,
+	recording: Option<TranscriptRecording>,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -108,6 +122,7 @@ impl<Challenger_: Default + Challenger> ProverTranscript<Challenger_> {
 		Self {
 			combined: Default::default(),
 			debug_assertions: cfg!(debug_assertions),
+			recording: None,
 		}
 	}
 
@@ -165,6 +180,19 @@ impl<Challenger_: Challenger> ProverTranscript<Challenger_> {
 		self.debug_assertions = debug;
 	}
 
+	/// Starts recording the operations on the transcript, discarding any previous recording.
+	///
+	/// Only the operations through the typed methods of [`TranscriptWriter`] and the sampling
+	/// traits are recorded, not the data written directly to [`TranscriptWriter::buffer`].
+	pub fn start_recording(&mut self) {
+		self.recording = Some(TranscriptRecording::default());
+	}
+
+	/// Stops recording and returns the recorded operations.
+	pub const fn take_recording(&mut self) -> Option<TranscriptRecording> {
+		self.recording.take()
+	}
+
 	/// Returns a writeable buffer that only observes the data written, without writing it to the
 	/// proof tape.
 	///
@@ -173,9 +201,13 @@ impl<Challenger_: Challenger> ProverTranscript<Challenger_> {
 	where
 		'a: 'b,
 	{
+		let tape_offset = self.combined.buffer.len();
 		TranscriptWriter {
 			buffer: self.combined.challenger.observer(),
 			debug_assertions: self.debug_assertions,
+			recorder: self.recording.as_mut().map(|recording| {
+				Recorder::new(recording, TranscriptOperation::Observe, tape_offset)
+			}),
 		}
 	}
 
@@ -188,9 +220,13 @@ impl<Challenger_: Challenger> ProverTranscript<Challenger_> {
 	/// be written using [`Self::decommitment`] because they are verified with respect to the
 	/// previously sent Merkle root.
 	pub fn decommitment(&mut self) -> TranscriptWriter<impl BufMut> {
+		let tape_offset = self.combined.buffer.len();
 		TranscriptWriter {
 			buffer: &mut self.combined.buffer,
 			debug_assertions: self.debug_assertions,
+			recorder: self.recording.as_mut().map(|recording| {
+				Recorder::new(recording, TranscriptOperation::Decommitment, tape_offset)
+			}),
 		}
 	}
 
@@ -201,9 +237,13 @@ impl<Challenger_: Challenger> ProverTranscript<Challenger_> {
 	where
 		'a: 'b,
 	{
+		let tape_offset = self.combined.buffer.len();
 		TranscriptWriter {
 			buffer: &mut self.combined,
 			debug_assertions: self.debug_assertions,
+			recorder: self.recording.as_mut().map(|recording| {
+				Recorder::new(recording, TranscriptOperation::Message, tape_offset)
+			}),
 		}
 	}
 }
@@ -211,11 +251,13 @@ impl<Challenger_: Challenger> ProverTranscript<Challenger_> {
 impl<Challenger_: Default + Challenger> VerifierTranscript<Challenger_> {
 	pub fn new(vec: Vec<u8>) -> Self {
 		Self {
+			tape_len: vec.len(),
 			combined: FiatShamirBuf {
 				challenger: Challenger_::default(),
 				buffer: Bytes::from(vec),
 			},
 			debug_assertions: cfg!(debug_assertions),
+			recording: None,
 		}
 	}
 }
@@ -234,6 +276,24 @@ impl<Challenger_: Challenger> VerifierTranscript<Challenger_> {
 		self.debug_assertions = debug;
 	}
 
+	/// Starts recording the operations on the transcript, discarding any previous recording.
+	///
+	/// Only the operations through the typed methods of [`TranscriptReader`] and
+	/// [`TranscriptWriter`] and the sampling traits are recorded, not the data accessed directly
+	/// through their buffers.
+	pub fn start_recording(&mut self) {
+		self.recording = Some(TranscriptRecording::default());
+	}
+
+	/// Stops recording and returns the recorded operations.
+	pub const fn take_recording(&mut self) -> Option<TranscriptRecording> {
+		self.recording.take()
+	}
+
+	fn tape_offset(&self) -> usize {
+		self.tape_len - self.combined.buffer.remaining()
+	}
+
 	/// Returns a writable buffer that only observes the data written, without reading it from the
 	/// proof tape.
 	///
@@ -242,9 +302,13 @@ impl<Challenger_: Challenger> VerifierTranscript<Challenger_> {
 	where
 		'a: 'b,
 	{
+		let tape_offset = self.tape_offset();
 		TranscriptWriter {
 			buffer: self.combined.challenger.observer(),
 			debug_assertions: self.debug_assertions,
+			recorder: self.recording.as_mut().map(|recording| {
+				Recorder::new(recording, TranscriptOperation::Observe, tape_offset)
+			}),
 		}
 	}
 
@@ -254,9 +318,13 @@ impl<Challenger_: Challenger> VerifierTranscript<Challenger_> {
 	/// This method should only be used to read advice that was previously written to the transcript
 	/// as an observed message.
 	pub fn decommitment(&mut self) -> TranscriptReader<impl Buf + '_> {
+		let tape_offset = self.tape_offset();
 		TranscriptReader {
 			buffer: &mut self.combined.buffer,
 			debug_assertions: self.debug_assertions,
+			recorder: self.recording.as_mut().map(|recording| {
+				Recorder::new(recording, TranscriptOperation::Decommitment, tape_offset)
+			}),
 		}
 	}
 
@@ -267,9 +335,13 @@ impl<Challenger_: Challenger> VerifierTranscript<Challenger_> {
 	where
 		'a: 'b,
 	{
+		let tape_offset = self.tape_offset();
 		TranscriptReader {
 			buffer: &mut self.combined,
 			debug_assertions: self.debug_assertions,
+			recorder: self.recording.as_mut().map(|recording| {
+				Recorder::new(recording, TranscriptOperation::Message, tape_offset)
+			}),
 		}
 	}
 }
@@ -289,6 +361,7 @@ impl<Challenger> Drop for VerifierTranscript<Challenger> {
 pub struct TranscriptReader<'a, B: Buf> {
 	buffer: &'a mut B,
 	debug_assertions: bool,
+	recorder: Option<Recorder<'a>>,
 }
 
 impl<B: Buf> TranscriptReader<'_, B> {
@@ -296,53 +369,60 @@ impl<B: Buf> TranscriptReader<'_, B> {
 		self.buffer
 	}
 
+	#[track_caller]
 	pub fn read<T: DeserializeBytes>(&mut self) -> Result<T, Error> {
 		let mode = SerializationMode::CanonicalTower;
-		T::deserialize(self.buffer(), mode).map_err(Into::into)
+		self.record(type_name::<T>(), |buffer| T::deserialize(buffer, mode).map_err(Into::into))
 	}
 
+	#[track_caller]
 	pub fn read_vec<T: DeserializeBytes>(&mut self, n: usize) -> Result<Vec<T>, Error> {
 		let mode = SerializationMode::CanonicalTower;
-		let mut buffer = self.buffer();
-		repeat_with(move || T::deserialize(&mut buffer, mode).map_err(Into::into))
-			.take(n)
-			.collect()
+		self.record(type_name::<[T]>(), |mut buffer| {
+			repeat_with(move || T::deserialize(&mut buffer, mode).map_err(Into::into))
+				.take(n)
+				.collect()
+		})
 	}
 
+	#[track_caller]
 	pub fn read_bytes(&mut self, buf: &mut [u8]) -> Result<(), Error> {
-		let buffer = self.buffer();
-		if buffer.remaining() < buf.len() {
-			return Err(Error::NotEnoughBytes);
-		}
-		buffer.copy_to_slice(buf);
-		Ok(())
+		self.record(type_name::<[u8]>(), |buffer| {
+			if buffer.remaining() < buf.len() {
+				return Err(Error::NotEnoughBytes);
+			}
+			buffer.copy_to_slice(buf);
+			Ok(())
+		})
 	}
 
+	#[track_caller]
 	pub fn read_scalar<F: TowerField>(&mut self) -> Result<F, Error> {
 		let mut out = F::default();
-		self.read_scalar_slice_into(slice::from_mut(&mut out))?;
+		self.read_scalars_into(type_name::<F>(), slice::from_mut(&mut out))?;
 		Ok(out)
 	}
 
+	#[track_caller]
 	pub fn read_scalar_slice_into<F: TowerField>(&mut self, buf: &mut [F]) -> Result<(), Error> {
-		let mut buffer = self.buffer();
-		for elem in buf {
-			let mode = SerializationMode::CanonicalTower;
-			*elem = DeserializeBytes::deserialize(&mut buffer, mode)?;
-		}
-		Ok(())
+		self.read_scalars_into(type_name::<[F]>(), buf)
 	}
 
+	#[track_caller]
 	pub fn read_scalar_slice<F: TowerField>(&mut self, len: usize) -> Result<Vec<F>, Error> {
 		let mut elems = vec![F::default(); len];
 		self.read_scalar_slice_into(&mut elems)?;
 		Ok(elems)
 	}
 
+	#[track_caller]
 	pub fn read_packed<P: PackedField<Scalar: TowerField>>(&mut self) -> Result<P, Error> {
-		P::try_from_fn(|_| self.read_scalar())
+		let mut scalars = vec![P::Scalar::default(); P::WIDTH];
+		self.read_scalars_into(type_name::<P>(), &mut scalars)?;
+		Ok(P::from_scalars(scalars))
 	}
 
+	#[track_caller]
 	pub fn read_packed_slice<P: PackedField<Scalar: TowerField>>(
 		&mut self,
 		len: usize,
@@ -354,6 +434,7 @@ impl<B: Buf> TranscriptReader<'_, B> {
 		Ok(packed)
 	}
 
+	#[track_caller]
 	pub fn read_debug(&mut self, msg: &str) {
 		if self.debug_assertions {
 			let msg_bytes = msg.as_bytes();
@@ -362,11 +443,32 @@ impl<B: Buf> TranscriptReader<'_, B> {
 			assert_eq!(msg_bytes, buffer);
 		}
 	}
+
+	#[track_caller]
+	fn read_scalars_into<F: TowerField>(
+		&mut self,
+		type_name: &'static str,
+		buf: &mut [F],
+	) -> Result<(), Error> {
+		self.record(type_name, |mut buffer| {
+			for elem in buf {
+				let mode = SerializationMode::CanonicalTower;
+				*elem = DeserializeBytes::deserialize(&mut buffer, mode)?;
+			}
+			Ok(())
+		})
+	}
+
+	#[track_caller]
+	fn record<T>(&mut self, type_name: &'static str, f: impl FnOnce(&mut Tee<B>) -> T) -> T {
+		Recorder::record(self.recorder.as_mut(), self.buffer, type_name, Location::caller(), f)
+	}
 }
 
 pub struct TranscriptWriter<'a, B: BufMut> {
 	buffer: &'a mut B,
 	debug_assertions: bool,
+	recorder: Option<Recorder<'a>>,
 }
 
 impl<B: BufMut> TranscriptWriter<'_, B> {
@@ -374,16 +476,18 @@ impl<B: BufMut> TranscriptWriter<'_, B> {
 		self.buffer
 	}
 
+	#[track_caller]
 	pub fn write<T: SerializeBytes>(&mut self, value: &T) {
-		self.proof_size_event_wrapper(|buffer| {
+		self.proof_size_event_wrapper(type_name::<T>(), |buffer| {
 			value
 				.serialize(buffer, SerializationMode::CanonicalTower)
 				.expect("TODO: propagate error");
 		});
 	}
 
+	#[track_caller]
 	pub fn write_slice<T: SerializeBytes>(&mut self, values: &[T]) {
-		self.proof_size_event_wrapper(|buffer| {
+		self.proof_size_event_wrapper(type_name::<[T]>(), |buffer| {
 			for value in values {
 				value
 					.serialize(&mut *buffer, SerializationMode::CanonicalTower)
@@ -392,56 +496,79 @@ impl<B: BufMut> TranscriptWriter<'_, B> {
 		});
 	}
 
+	#[track_caller]
 	pub fn write_bytes(&mut self, data: &[u8]) {
-		self.proof_size_event_wrapper(|buffer| {
+		self.proof_size_event_wrapper(type_name::<[u8]>(), |buffer| {
 			buffer.put_slice(data);
 		});
 	}
 
+	#[track_caller]
 	pub fn write_scalar<F: TowerField>(&mut self, f: F) {
-		self.write_scalar_slice(slice::from_ref(&f));
+		self.write_scalars(type_name::<F>(), iter::once(f));
 	}
 
+	#[track_caller]
 	pub fn write_scalar_iter<F: TowerField>(&mut self, it: impl IntoIterator<Item = F>) {
-		self.proof_size_event_wrapper(move |buffer| {
-			for elem in it {
-				SerializeBytes::serialize(&elem, &mut *buffer, SerializationMode::CanonicalTower)
-					.expect("TODO: propagate error");
-			}
-		});
+		self.write_scalars(type_name::<[F]>(), it);
 	}
 
+	#[track_caller]
 	pub fn write_scalar_slice<F: TowerField>(&mut self, elems: &[F]) {
 		self.write_scalar_iter(elems.iter().copied());
 	}
 
+	#[track_caller]
 	pub fn write_packed<P: PackedField<Scalar: TowerField>>(&mut self, packed: P) {
-		self.write_scalar_iter(packed.into_iter());
+		self.write_scalars(type_name::<P>(), packed.into_iter());
 	}
 
+	#[track_caller]
 	pub fn write_packed_iter<P: PackedField<Scalar: TowerField>>(
 		&mut self,
 		it: impl IntoIterator<Item = P>,
 	) {
-		self.write_scalar_iter(it.into_iter().flat_map(|packed| packed.into_iter()));
+		self.write_scalars(
+			type_name::<[P]>(),
+			it.into_iter().flat_map(|packed| packed.into_iter()),
+		);
 	}
 
+	#[track_caller]
 	pub fn write_packed_slice<P: PackedField<Scalar: TowerField>>(&mut self, packed_slice: &[P]) {
-		self.write_scalar_iter(P::iter_slice(packed_slice));
+		self.write_scalars(type_name::<[P]>(), P::iter_slice(packed_slice));
 	}
 
+	#[track_caller]
 	pub fn write_debug(&mut self, msg: &str) {
 		if self.debug_assertions {
 			self.write_bytes(msg.as_bytes())
 		}
 	}
 
-	fn proof_size_event_wrapper<F: FnOnce(&mut B)>(&mut self, f: F) {
-		let buffer = self.buffer();
-		let start_bytes = buffer.remaining_mut();
-		f(buffer);
-		let end_bytes = buffer.remaining_mut();
-		tracing::event!(name: "incremental_proof_size", tracing::Level::INFO, counter=true, incremental=true, value=start_bytes - end_bytes);
+	#[track_caller]
+	fn write_scalars<F: TowerField>(
+		&mut self,
+		type_name: &'static str,
+		it: impl IntoIterator<Item = F>,
+	) {
+		self.proof_size_event_wrapper(type_name, move |buffer| {
+			for elem in it {
+				SerializeBytes::serialize(&elem, &mut *buffer, SerializationMode::CanonicalTower)
+					.expect("TODO: propagate error");
+			}
+		});
+	}
+
+	#[track_caller]
+	fn proof_size_event_wrapper<F: FnOnce(&mut Tee<B>)>(&mut self, type_name: &'static str, f: F) {
+		let location = Location::caller();
+		Recorder::record(self.recorder.as_mut(), self.buffer, type_name, location, |buffer| {
+			let start_bytes = buffer.remaining_mut();
+			f(buffer);
+			let end_bytes = buffer.remaining_mut();
+			tracing::event!(name: "incremental_proof_size", tracing::Level::INFO, counter=true, incremental=true, value=start_bytes - end_bytes);
+		});
 	}
 }
 
@@ -450,10 +577,10 @@ where
 	F: TowerField,
 	Challenger_: Challenger,
 {
+	#[track_caller]
 	fn sample(&mut self) -> F {
-		let mode = SerializationMode::CanonicalTower;
-		DeserializeBytes::deserialize(self.combined.challenger.sampler(), mode)
-			.expect("challenger has infinite buffer")
+		let tape_offset = self.tape_offset();
+		sample_value(&mut self.combined.challenger, self.recording.as_mut(), tape_offset)
 	}
 }
 
@@ -462,37 +589,55 @@ where
 	F: TowerField,
 	Challenger_: Challenger,
 {
+	#[track_caller]
 	fn sample(&mut self) -> F {
-		let mode = SerializationMode::CanonicalTower;
-		DeserializeBytes::deserialize(self.combined.challenger.sampler(), mode)
-			.expect("challenger has infinite buffer")
+		let tape_offset = self.combined.buffer.len();
+		sample_value(&mut self.combined.challenger, self.recording.as_mut(), tape_offset)
 	}
 }
 
-fn sample_bits_reader<Reader: Buf>(mut reader: Reader, bits: usize) -> u32 {
-	let bits = bits.min(u32::BITS as usize);
-
-	let bytes_to_sample: usize = std::mem::size_of::<u32>();
-
-	let mut bytes = [0u8; std::mem::size_of::<u32>()];
+#[track_caller]
+fn sample_value<T: DeserializeBytes>(
+	challenger: &mut impl Challenger,
+	recording: Option<&mut TranscriptRecording>,
+	tape_offset: usize,
+) -> T {
+	let mut recorder = recording
+		.map(|recording| Recorder::new(recording, TranscriptOperation::Sample, tape_offset));
+	Recorder::record(
+		recorder.as_mut(),
+		challenger.sampler(),
+		type_name::<T>(),
+		Location::caller(),
+		|buffer| {
+			T::deserialize(buffer, SerializationMode::CanonicalTower)
+				.expect("challenger has infinite buffer")
+		},
+	)
+}
 
-	reader.copy_to_slice(&mut bytes[..bytes_to_sample]);
+fn mask_bits(value: u32, bits: usize) -> u32 {
+	let bits = bits.min(u32::BITS as usize);
 
-	let unmasked = u32::from_le_bytes(bytes);
 	let mask = 1u32.checked_shl(bits as u32);
 	let mask = match mask {
 		Some(x) => x - 1,
 		None => u32::MAX,
 	};
-	mask & unmasked
+	mask & value
 }
 
 impl<Challenger_> CanSampleBits<u32> for VerifierTranscript<Challenger_>
 where
 	Challenger_: Challenger,
 {
+	#[track_caller]
 	fn sample_bits(&mut self, bits: usize) -> u32 {
-		sample_bits_reader(self.combined.challenger.sampler(), bits)
+		let tape_offset = self.tape_offset();
+		mask_bits(
+			sample_value(&mut self.combined.challenger, self.recording.as_mut(), tape_offset),
+			bits,
+		)
 	}
 }
 
@@ -500,8 +645,13 @@ impl<Challenger_> CanSampleBits<u32> for ProverTranscript<Challenger_>
 where
 	Challenger_: Challenger,
 {
+	#[track_caller]
 	fn sample_bits(&mut self, bits: usize) -> u32 {
-		sample_bits_reader(self.combined.challenger.sampler(), bits)
+		let tape_offset = self.combined.buffer.len();
+		mask_bits(
+			sample_value(&mut self.combined.challenger, self.recording.as_mut(), tape_offset),
+			bits,
+		)
 	}
 }
 
@@ -524,13 +674,13 @@ mod tests {
 	};
 	use binius_hash::groestl::Groestl256;
 	use rand::RngCore;
+	use recording::find_divergence;
 
 	use super::*;
-	use crate::fiat_shamir::HasherChallenger;
+	use crate::fiat_shamir::{HasherChallenger, VisionChallenger};
 
-	#[test]
-	fn test_transcript_interactions() {
-		let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	fn check_transcript_interactions<Challenger_: Challenger + Default>() {
+		let mut prover_transcript = ProverTranscript::<Challenger_>::new();
 		let mut writable = prover_transcript.message();
 
 		writable.write_scalar(BinaryField8b::new(0x96));
@@ -590,6 +740,16 @@ mod tests {
 		verifier_transcript.finalize().unwrap();
 	}
 
+	#[test]
+	fn test_transcript_interactions() {
+		check_transcript_interactions::<HasherChallenger<Groestl256>>();
+	}
+
+	#[test]
+	fn test_transcript_interactions_vision_challenger() {
+		check_transcript_interactions::<VisionChallenger>();
+	}
+
 	#[test]
 	fn test_advising() {
 		let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
@@ -701,4 +861,104 @@ mod tests {
 			.message()
 			.read_debug("test_transcript_debug_should_fail");
 	}
+
+	fn record_prover() -> (TranscriptRecording, Vec<u8>) {
+		let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		transcript.start_recording();
+
+		transcript.observe().write_scalar(BinaryField32b::new(7));
+		transcript.message().write_scalar_slice(&[
+			BinaryField128b::new(1),
+			BinaryField128b::new(2),
+			BinaryField128b::new(3),
+		]);
+		let _: BinaryField128b = transcript.sample();
+		transcript.decommitment().write_bytes(&[4, 5, 6]);
+		transcript.sample_bits(10);
+
+		let recording = transcript.take_recording().unwrap();
+		(recording, transcript.finalize())
+	}
+
+	#[test]
+	fn test_recording_agrees() {
+		let (prover, proof) = record_prover();
+		assert_eq!(
+			prover
+				.events()
+				.iter()
+				.map(|event| event.operation)
+				.collect::<Vec<_>>(),
+			[
+				TranscriptOperation::Observe,
+				TranscriptOperation::Message,
+				TranscriptOperation::Sample,
+				TranscriptOperation::Decommitment,
+				TranscriptOperation::Sample,
+			]
+		);
+		assert!(
+			prover
+				.events()
+				.iter()
+				.all(|event| event.location.file() == file!())
+		);
+		assert_eq!(prover.events()[1].type_name, type_name::<[BinaryField128b]>());
+		assert_eq!(prover.events()[1].bytes.len(), 48);
+		assert_eq!(prover.events()[3].tape_offset, 48);
+
+		let mut transcript = VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof);
+		transcript.start_recording();
+
+		transcript.observe().write_scalar(BinaryField32b::new(7));
+		let mut reader = transcript.message();
+		for _ in 0..3 {
+			let _: BinaryField128b = reader.read_scalar().unwrap();
+		}
+		let _: BinaryField128b = transcript.sample();
+		let mut bytes = [0; 3];
+		transcript.decommitment().read_bytes(&mut bytes).unwrap();
+		transcript.sample_bits(10);
+
+		let verifier = transcript.take_recording().unwrap();
+		assert_eq!(verifier.events().len(), 7);
+		assert_eq!(verifier.events()[5].tape_offset, 48);
+		assert_eq!(verifier.events()[4].bytes, prover.events()[2].bytes);
+		assert_eq!(find_divergence(&prover, &verifier), None);
+		transcript.finalize().unwrap();
+	}
+
+	#[test]
+	fn test_recording_finds_divergence() {
+		let (prover, proof) = record_prover();
+
+		let mut transcript = VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof);
+		transcript.start_recording();
+
+		transcript.observe().write_scalar(BinaryField32b::new(7));
+		let _: Vec<BinaryField128b> = transcript.message().read_scalar_slice(2).unwrap();
+		let sample_line = line!() + 1;
+		let _: BinaryField128b = transcript.sample();
+
+		let verifier = transcript.take_recording().unwrap();
+		let divergence = find_divergence(&prover, &verifier).unwrap();
+		assert_eq!(divergence.position, 4 + 32);
+
+		let prover_point = divergence.prover.unwrap();
+		assert_eq!(prover_point.event_index, 1);
+		assert_eq!(prover_point.byte_index, 32);
+		assert_eq!(prover_point.event.operation, TranscriptOperation::Message);
+
+		let verifier_point = divergence.verifier.unwrap();
+		assert_eq!(verifier_point.event_index, 2);
+		assert_eq!(verifier_point.event.operation, TranscriptOperation::Sample);
+		assert_eq!(verifier_point.event.location.line(), sample_line);
+		assert!(divergence.to_string().contains("sample of"));
+
+		// A recording that ends early diverges at its end.
+		let truncated = TranscriptRecording::default();
+		let divergence = find_divergence(&prover, &truncated).unwrap();
+		assert_eq!(divergence.position, 0);
+		assert!(divergence.verifier.is_none());
+	}
 }
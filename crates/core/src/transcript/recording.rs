@@ -0,0 +1,307 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Recording of transcript operations, for debugging disagreements between prover and verifier.
+//!
+//! A [`super::ProverTranscript`] or [`super::VerifierTranscript`] records every operation after
+//! a call to `start_recording`. The recording of each side is retrieved with `take_recording`,
+//! and [`find_divergence`] locates the first operation at which the two sides disagree.
+
+use std::{cmp::min, fmt, panic::Location, slice};
+
+use bytes::{Buf, BufMut, buf::UninitSlice};
+
+/// The kind of a recorded transcript operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TranscriptOperation {
+	/// Data observed by the challenger without being written to the proof tape.
+	Observe,
+	/// Data written to or read from the proof tape and observed by the challenger.
+	Message,
+	/// Data written to or read from the proof tape without being observed.
+	Decommitment,
+	/// Verifier randomness sampled from the challenger.
+	Sample,
+}
+
+impl fmt::Display for TranscriptOperation {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let name = match self {
+			Self::Observe => "observe",
+			Self::Message => "message",
+			Self::Decommitment => "decommitment",
+			Self::Sample => "sample",
+		};
+		f.write_str(name)
+	}
+}
+
+/// A single recorded transcript operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranscriptEvent {
+	pub operation: TranscriptOperation,
+	/// The position on the proof tape at which the operation started.
+	pub tape_offset: usize,
+	/// The type of the value that was written, read or sampled.
+	pub type_name: &'static str,
+	/// The source location of the call to the transcript.
+	pub location: &'static Location<'static>,
+	/// The serialized value, which is the challenge value for samples.
+	pub bytes: Vec<u8>,
+}
+
+impl fmt::Display for TranscriptEvent {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		const MAX_BYTES: usize = 32;
+
+		write!(
+			f,
+			"{} of {} at {}, tape offset {}, {} bytes: ",
+			self.operation,
+			self.type_name,
+			self.location,
+			self.tape_offset,
+			self.bytes.len()
+		)?;
+		for byte in &self.bytes[..min(self.bytes.len(), MAX_BYTES)] {
+			write!(f, "{byte:02x}")?;
+		}
+		if self.bytes.len() > MAX_BYTES {
+			f.write_str("...")?;
+		}
+		Ok(())
+	}
+}
+
+/// The sequence of operations performed on a transcript.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TranscriptRecording {
+	events: Vec<TranscriptEvent>,
+}
+
+impl TranscriptRecording {
+	pub fn events(&self) -> &[TranscriptEvent] {
+		&self.events
+	}
+
+	/// Iterates over the recorded bytes along with their positions.
+	fn bytes(&self) -> impl Iterator<Item = (DivergencePoint<'_>, u8)> {
+		self.events
+			.iter()
+			.enumerate()
+			.flat_map(|(event_index, event)| {
+				event
+					.bytes
+					.iter()
+					.enumerate()
+					.map(move |(byte_index, &byte)| {
+						let point = DivergencePoint {
+							event_index,
+							event,
+							byte_index,
+						};
+						(point, byte)
+					})
+			})
+	}
+}
+
+impl fmt::Display for TranscriptRecording {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for (i, event) in self.events.iter().enumerate() {
+			writeln!(f, "{i}: {event}")?;
+		}
+		Ok(())
+	}
+}
+
+/// The position in a recording at which it diverges from another one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DivergencePoint<'a> {
+	pub event_index: usize,
+	pub event: &'a TranscriptEvent,
+	/// The index of the first differing byte within the event.
+	pub byte_index: usize,
+}
+
+/// The first disagreement between a prover and a verifier recording.
+///
+/// A side is `None` if its recording ended before the divergence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Divergence<'a> {
+	/// The number of bytes on which the recordings agree.
+	pub position: usize,
+	pub prover: Option<DivergencePoint<'a>>,
+	pub verifier: Option<DivergencePoint<'a>>,
+}
+
+impl fmt::Display for Divergence<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		writeln!(f, "transcripts diverge after {} bytes", self.position)?;
+		for (side, point) in [("prover", &self.prover), ("verifier", &self.verifier)] {
+			match point {
+				Some(point) => writeln!(
+					f,
+					"  {side}: event {}, byte {}: {}",
+					point.event_index, point.byte_index, point.event
+				)?,
+				None => writeln!(f, "  {side}: end of recording")?,
+			}
+		}
+		Ok(())
+	}
+}
+
+/// Finds the first operation at which the prover and verifier recordings disagree.
+///
+/// The recordings are aligned byte by byte rather than event by event, because the two sides
+/// may transfer the same data in differently sized pieces, for example when the prover writes a
+/// slice of scalars that the verifier reads one at a time. The recordings diverge at the first
+/// byte that differs in value or in the kind of operation that produced it. Returns `None` if
+/// the recordings agree.
+pub fn find_divergence<'a>(
+	prover: &'a TranscriptRecording,
+	verifier: &'a TranscriptRecording,
+) -> Option<Divergence<'a>> {
+	let mut prover_bytes = prover.bytes();
+	let mut verifier_bytes = verifier.bytes();
+	for position in 0.. {
+		let (prover_byte, verifier_byte) = (prover_bytes.next(), verifier_bytes.next());
+		let agree = match (&prover_byte, &verifier_byte) {
+			(None, None) => return None,
+			(Some((prover_point, prover_byte)), Some((verifier_point, verifier_byte))) => {
+				prover_point.event.operation == verifier_point.event.operation
+					&& prover_byte == verifier_byte
+			}
+			_ => false,
+		};
+		if !agree {
+			return Some(Divergence {
+				position,
+				prover: prover_byte.map(|(point, _)| point),
+				verifier: verifier_byte.map(|(point, _)| point),
+			});
+		}
+	}
+	unreachable!("the loop only exits by returning")
+}
+
+/// Appends the events of one transcript operation to a recording.
+#[derive(Debug)]
+pub(super) struct Recorder<'a> {
+	recording: &'a mut TranscriptRecording,
+	operation: TranscriptOperation,
+	tape_offset: usize,
+}
+
+impl<'a> Recorder<'a> {
+	pub(super) const fn new(
+		recording: &'a mut TranscriptRecording,
+		operation: TranscriptOperation,
+		tape_offset: usize,
+	) -> Self {
+		Self {
+			recording,
+			operation,
+			tape_offset,
+		}
+	}
+
+	/// Performs an operation on a buffer, recording the bytes transferred if recording is
+	/// enabled.
+	pub(super) fn record<B, T>(
+		recorder: Option<&mut Self>,
+		buffer: &mut B,
+		type_name: &'static str,
+		location: &'static Location<'static>,
+		f: impl FnOnce(&mut Tee<'_, B>) -> T,
+	) -> T {
+		let mut tee = Tee {
+			inner: buffer,
+			copy: recorder.is_some().then(Vec::new),
+		};
+		let result = f(&mut tee);
+		if let (Some(recorder), Some(bytes)) = (recorder, tee.copy) {
+			recorder.push(type_name, location, bytes);
+		}
+		result
+	}
+
+	fn push(
+		&mut self,
+		type_name: &'static str,
+		location: &'static Location<'static>,
+		bytes: Vec<u8>,
+	) {
+		let tape_offset = self.tape_offset;
+		if matches!(
+			self.operation,
+			TranscriptOperation::Message | TranscriptOperation::Decommitment
+		) {
+			self.tape_offset += bytes.len();
+		}
+		self.recording.events.push(TranscriptEvent {
+			operation: self.operation,
+			tape_offset,
+			type_name,
+			location,
+			bytes,
+		});
+	}
+}
+
+/// A buffer that forwards to an inner buffer and copies the bytes written or read.
+pub(super) struct Tee<'a, B> {
+	inner: &'a mut B,
+	copy: Option<Vec<u8>>,
+}
+
+impl<B: Buf> Buf for Tee<'_, B> {
+	fn remaining(&self) -> usize {
+		self.inner.remaining()
+	}
+
+	fn chunk(&self) -> &[u8] {
+		self.inner.chunk()
+	}
+
+	fn advance(&mut self, mut cnt: usize) {
+		let Some(copy) = &mut self.copy else {
+			return self.inner.advance(cnt);
+		};
+
+		assert!(cnt <= self.inner.remaining());
+		loop {
+			let n = min(cnt, self.inner.chunk().len());
+			copy.extend_from_slice(&self.inner.chunk()[..n]);
+			self.inner.advance(n);
+			cnt -= n;
+			if cnt == 0 {
+				break;
+			}
+		}
+	}
+}
+
+unsafe impl<B: BufMut> BufMut for Tee<'_, B> {
+	fn remaining_mut(&self) -> usize {
+		self.inner.remaining_mut()
+	}
+
+	unsafe fn advance_mut(&mut self, cnt: usize) {
+		if let Some(copy) = &mut self.copy {
+			let written = self.inner.chunk_mut();
+			assert!(cnt <= written.len());
+
+			// NOTE: The caller guarantees that the next cnt bytes are initialized.
+			let written: &[u8] = unsafe { slice::from_raw_parts(written.as_mut_ptr(), cnt) };
+			copy.extend_from_slice(written);
+		}
+		unsafe {
+			self.inner.advance_mut(cnt);
+		}
+	}
+
+	fn chunk_mut(&mut self) -> &mut UninitSlice {
+		self.inner.chunk_mut()
+	}
+}
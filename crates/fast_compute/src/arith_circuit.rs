@@ -5,10 +5,12 @@ use std::{mem::MaybeUninit, sync::Arc};
 use binius_field::{ExtensionField, Field, PackedField, TowerField};
 use binius_math::{ArithCircuit, ArithCircuitStep, CompositionPoly, Error, RowsBatchRef};
 use binius_utils::{
-	DeserializeBytes, SerializationError, SerializationMode, SerializeBytes, bail,
+	DeserializeBytes, SerializationError, SerializationMode, SerializeBytes,
 	mem::{slice_assume_init_mut, slice_assume_init_ref},
 };
 
+use crate::bytecode::CircuitBytecode;
+
 /// Convert the expression to a sequence of arithmetic operations that can be evaluated in sequence.
 fn convert_circuit_steps<F: Field>(
 	expr: &ArithCircuit<F>,
@@ -138,22 +140,6 @@ enum CircuitNode {
 	Slot(usize),
 }
 
-impl CircuitNode {
-	/// Return either the input column or the slice with evaluations at one of the previous steps.
-	/// This method is used for batch evaluation.
-	fn get_sparse_chunk<'a, P: PackedField>(
-		&'a self,
-		inputs: &'a RowsBatchRef<'a, P>,
-		evals: &'a [P],
-		row_len: usize,
-	) -> &'a [P] {
-		match self {
-			Self::Var(index) => inputs.row(*index),
-			Self::Slot(slot) => &evals[slot * row_len..(slot + 1) * row_len],
-		}
-	}
-}
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum CircuitStepArgument<F> {
 	Expr(CircuitNode),
@@ -180,10 +166,14 @@ enum CircuitStep<F: Field> {
 /// The advantage over a hard coded CompositionPoly is that this can be constructed and manipulated
 /// dynamically at runtime and the object representing different polnomials can be stored in a
 /// homogeneous collection.
+///
+/// Batch evaluation runs the circuit compiled to [`CircuitBytecode`].
 #[derive(Debug, Clone)]
 pub struct ArithCircuitPoly<F: Field> {
 	expr: ArithCircuit<F>,
 	steps: Arc<[CircuitStep<F>]>,
+	/// The circuit compiled for batch evaluation.
+	bytecode: Arc<CircuitBytecode<F>>,
 	/// The "top level expression", which depends on circuit expression evaluations
 	retval: CircuitStepArgument<F>,
 	degree: usize,
@@ -232,10 +222,12 @@ impl<F: TowerField> ArithCircuitPoly<F> {
 		let n_vars = expr.n_vars();
 		let tower_level = expr.binary_tower_level();
 		let (exprs, retval) = convert_circuit_steps(&expr);
+		let bytecode = CircuitBytecode::compile(&expr);
 
 		Self {
 			expr,
 			steps: exprs.into(),
+			bytecode: bytecode.into(),
 			retval,
 			degree,
 			n_vars,
@@ -258,10 +250,12 @@ impl<F: TowerField> ArithCircuitPoly<F> {
 			});
 		}
 		let (steps, retval) = convert_circuit_steps(&expr);
+		let bytecode = CircuitBytecode::compile(&expr);
 
 		Ok(Self {
 			expr,
 			steps: steps.into(),
+			bytecode: bytecode.into(),
 			retval,
 			n_vars,
 			degree,
@@ -363,183 +357,11 @@ impl<F: TowerField, P: PackedField<Scalar: ExtensionField<F>>> CompositionPoly<P
 	}
 
 	fn batch_evaluate(&self, batch_query: &RowsBatchRef<P>, evals: &mut [P]) -> Result<(), Error> {
-		let row_len = evals.len();
-		if batch_query.row_len() != row_len {
-			bail!(Error::BatchEvaluateSizeMismatch {
-				expected: row_len,
-				actual: batch_query.row_len(),
-			});
-		}
-
-		alloc_scratch_space::<P, (), _>(self.steps.len() * row_len, |sparse_evals| {
-			for (i, expr) in self.steps.iter().enumerate() {
-				let (before, current) = sparse_evals.split_at_mut(i * row_len);
-
-				// Safety: `before` is guaranteed to be initialized by the previous loop iterations
-				// (if dereferenced).
-				let before = unsafe { slice_assume_init_mut(before) };
-				let current = &mut current[..row_len];
-
-				match expr {
-					CircuitStep::Add(left, right) => {
-						apply_binary_op(
-							left,
-							right,
-							batch_query,
-							before,
-							current,
-							|left, right, out| {
-								out.write(left + right);
-							},
-						);
-					}
-					CircuitStep::Mul(left, right) => {
-						apply_binary_op(
-							left,
-							right,
-							batch_query,
-							before,
-							current,
-							|left, right, out| {
-								out.write(left * right);
-							},
-						);
-					}
-					CircuitStep::Square(arg) => {
-						match arg {
-							CircuitStepArgument::Expr(node) => {
-								let id_chunk = node.get_sparse_chunk(batch_query, before, row_len);
-								for j in 0..row_len {
-									// Safety: `current` and `id_chunk` have length equal to
-									// `row_len`
-									unsafe {
-										current
-											.get_unchecked_mut(j)
-											.write(id_chunk.get_unchecked(j).square());
-									}
-								}
-							}
-							CircuitStepArgument::Const(value) => {
-								let value: P = P::broadcast((*value).into());
-								let result = value.square();
-								for j in 0..row_len {
-									// Safety: `current` has length equal to `row_len`
-									unsafe {
-										current.get_unchecked_mut(j).write(result);
-									}
-								}
-							}
-						}
-					}
-					CircuitStep::AddMul(target, left, right) => {
-						let target = &mut before[row_len * target..(target + 1) * row_len];
-						// Safety: by construction of steps and evaluation order we know
-						// that `target` is not borrowed elsewhere.
-						let target: &mut [MaybeUninit<P>] = unsafe {
-							std::slice::from_raw_parts_mut(
-								target.as_mut_ptr() as *mut MaybeUninit<P>,
-								target.len(),
-							)
-						};
-						apply_binary_op(
-							left,
-							right,
-							batch_query,
-							before,
-							target,
-							// Safety: by construction of steps and evaluation order we know
-							// that `target`/`out` is initialized.
-							|left, right, out| unsafe {
-								let out = out.assume_init_mut();
-								*out += left * right;
-							},
-						);
-					}
-				}
-			}
-
-			match self.retval {
-				CircuitStepArgument::Expr(node) => {
-					// Safety: `sparse_evals` is fully initialized by the previous loop iterations
-					let sparse_evals = unsafe { slice_assume_init_ref(sparse_evals) };
-					evals.copy_from_slice(node.get_sparse_chunk(batch_query, sparse_evals, row_len))
-				}
-				CircuitStepArgument::Const(val) => evals.fill(P::broadcast(val.into())),
-			}
-		});
-
-		Ok(())
-	}
-}
-
-/// Apply a binary operation to two arguments and store the result in `current_evals`.
-/// `op` must be a function that takes two arguments and initialized the result with the third
-/// argument.
-fn apply_binary_op<F: Field, P: PackedField<Scalar: ExtensionField<F>>>(
-	left: &CircuitStepArgument<F>,
-	right: &CircuitStepArgument<F>,
-	batch_query: &RowsBatchRef<P>,
-	evals_before: &[P],
-	current_evals: &mut [MaybeUninit<P>],
-	op: impl Fn(P, P, &mut MaybeUninit<P>),
-) {
-	let row_len = current_evals.len();
-
-	match (left, right) {
-		(CircuitStepArgument::Expr(left), CircuitStepArgument::Expr(right)) => {
-			let left = left.get_sparse_chunk(batch_query, evals_before, row_len);
-			let right = right.get_sparse_chunk(batch_query, evals_before, row_len);
-			for j in 0..row_len {
-				// Safety: `current`, `left` and `right` have length equal to `row_len`
-				unsafe {
-					op(
-						*left.get_unchecked(j),
-						*right.get_unchecked(j),
-						current_evals.get_unchecked_mut(j),
-					)
-				}
-			}
-		}
-		(CircuitStepArgument::Expr(left), CircuitStepArgument::Const(right)) => {
-			let left = left.get_sparse_chunk(batch_query, evals_before, row_len);
-			let right = P::broadcast((*right).into());
-			for j in 0..row_len {
-				// Safety: `current` and `left` have length equal to `row_len`
-				unsafe {
-					op(*left.get_unchecked(j), right, current_evals.get_unchecked_mut(j));
-				}
-			}
-		}
-		(CircuitStepArgument::Const(left), CircuitStepArgument::Expr(right)) => {
-			let left = P::broadcast((*left).into());
-			let right = right.get_sparse_chunk(batch_query, evals_before, row_len);
-			for j in 0..row_len {
-				// Safety: `current` and `right` have length equal to `row_len`
-				unsafe {
-					op(left, *right.get_unchecked(j), current_evals.get_unchecked_mut(j));
-				}
-			}
-		}
-		(CircuitStepArgument::Const(left), CircuitStepArgument::Const(right)) => {
-			let left = P::broadcast((*left).into());
-			let right = P::broadcast((*right).into());
-			let mut result = MaybeUninit::uninit();
-			op(left, right, &mut result);
-			for j in 0..row_len {
-				// Safety:
-				// - `current` has length equal to `row_len`
-				// - `result` is initialized by `op`
-				unsafe {
-					current_evals
-						.get_unchecked_mut(j)
-						.write(result.assume_init());
-				}
-			}
-		}
+		self.bytecode.batch_evaluate(batch_query, evals)
 	}
 }
 
-fn alloc_scratch_space<T, U, F>(size: usize, callback: F) -> U
+pub(crate) fn alloc_scratch_space<T, U, F>(size: usize, callback: F) -> U
 where
 	F: FnOnce(&mut [MaybeUninit<T>]) -> U,
 {
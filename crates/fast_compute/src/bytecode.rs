@@ -0,0 +1,670 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Compilation of arithmetic circuits into register-allocated bytecode.
+//!
+//! [`ArithCircuit`] is a list of steps which may contain duplicate subexpressions, unused steps
+//! and exponentiations. [`CircuitBytecode::compile`] lowers it into a linear sequence of
+//! instructions over a small set of registers:
+//!
+//! 1. The steps are hash-consed into a DAG, which eliminates common subexpressions, folds constants
+//!    and expands exponentiations into square-and-multiply chains.
+//! 2. Additions with an operand that is a product used nowhere else are fused into multiply-add
+//!    instructions.
+//! 3. Registers are assigned by a linear scan over the instructions, releasing a register after the
+//!    last instruction that reads it.
+//!
+//! Batch evaluation processes the rows in chunks of packed elements. Every instruction is decoded
+//! once per chunk and then applied to the whole chunk by a loop that is monomorphized for the
+//! packed field, so the cost of the dispatch is amortized over the chunk.
+
+use std::{collections::HashMap, mem::MaybeUninit, ptr};
+
+use binius_field::{ExtensionField, Field, PackedField};
+use binius_math::{ArithCircuit, ArithCircuitStep, Error, RowsBatchRef};
+use binius_utils::bail;
+
+use crate::arith_circuit::alloc_scratch_space;
+
+/// The number of packed elements of every row which each instruction is applied to at once.
+const CHUNK_LEN: usize = 64;
+
+/// An input of an instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+	/// Input variable.
+	Var(usize),
+	/// Register.
+	Reg(usize),
+}
+
+/// An instruction of the bytecode, which writes its result to the `dst` register.
+///
+/// The destination register may be one of the operands, since the instruction is applied
+/// elementwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+	Add {
+		dst: usize,
+		lhs: Operand,
+		rhs: Operand,
+	},
+	Mul {
+		dst: usize,
+		lhs: Operand,
+		rhs: Operand,
+	},
+	Square {
+		dst: usize,
+		src: Operand,
+	},
+	/// Computes `lhs * rhs + addend`.
+	MulAdd {
+		dst: usize,
+		lhs: Operand,
+		rhs: Operand,
+		addend: Operand,
+	},
+}
+
+/// An arithmetic circuit compiled to register-allocated bytecode.
+#[derive(Debug, Clone)]
+pub struct CircuitBytecode<F: Field> {
+	/// The values of the first registers, which are never written by the instructions.
+	constants: Vec<F>,
+	instructions: Vec<Instruction>,
+	n_registers: usize,
+	n_vars: usize,
+	output: Operand,
+}
+
+impl<F: Field> CircuitBytecode<F> {
+	pub fn compile(circuit: &ArithCircuit<F>) -> Self {
+		let mut dag = Dag::default();
+		let mut step_nodes = Vec::with_capacity(circuit.steps().len());
+		for step in circuit.steps() {
+			let node = match *step {
+				ArithCircuitStep::Var(index) => dag.insert(Node::Var(index)),
+				ArithCircuitStep::Const(value) => dag.insert(Node::Const(value)),
+				ArithCircuitStep::Add(left, right) => dag.add(step_nodes[left], step_nodes[right]),
+				ArithCircuitStep::Mul(left, right) => dag.mul(step_nodes[left], step_nodes[right]),
+				ArithCircuitStep::Pow(base, exp) => dag.pow(step_nodes[base], exp),
+			};
+			step_nodes.push(node);
+		}
+		let output = *step_nodes.last().expect("circuit has at least one step");
+
+		// Count the uses of every node reachable from the output, counting the output itself as
+		// a use so that its value is never released or fused. Operands precede their users, so a
+		// reverse scan visits all users of a node before the node.
+		let mut uses = vec![0; dag.nodes.len()];
+		uses[output] = 1;
+		for id in (0..dag.nodes.len()).rev() {
+			if uses[id] > 0 {
+				for operand in dag.nodes[id].operands() {
+					uses[operand] += 1;
+				}
+			}
+		}
+
+		// Fuse additions with a product which has no other use. The operands of the product
+		// become operands of the multiply-add, so the use counts stay valid.
+		let is_fusable =
+			|id: usize| uses[id] == 1 && matches!(dag.nodes[id], Node::Mul(..) | Node::Square(_));
+		let mut fused = vec![false; dag.nodes.len()];
+		let mut fused_adds = HashMap::new();
+		for (id, node) in dag.nodes.iter().enumerate() {
+			if let Node::Add(left, right) = *node {
+				if uses[id] == 0 {
+					continue;
+				}
+				let (product, addend) = if is_fusable(right) {
+					(right, left)
+				} else if is_fusable(left) {
+					(left, right)
+				} else {
+					continue;
+				};
+				fused[product] = true;
+				fused_adds.insert(id, (product, addend));
+			}
+		}
+
+		let mut locations = vec![None; dag.nodes.len()];
+		let mut constants = Vec::new();
+		for (id, node) in dag.nodes.iter().enumerate() {
+			match *node {
+				Node::Var(index) if uses[id] > 0 => locations[id] = Some(Operand::Var(index)),
+				Node::Const(value) if uses[id] > 0 => {
+					locations[id] = Some(Operand::Reg(constants.len()));
+					constants.push(value);
+				}
+				_ => {}
+			}
+		}
+
+		let mut allocator = RegisterAllocator {
+			n_registers: constants.len(),
+			free: Vec::new(),
+		};
+		let mut remaining_uses = uses.clone();
+		let mut instructions = Vec::new();
+		for (id, node) in dag.nodes.iter().enumerate() {
+			if uses[id] == 0 || fused[id] || matches!(node, Node::Var(_) | Node::Const(_)) {
+				continue;
+			}
+
+			// The DAG operands are the nodes whose uses this instruction consumes, which for a
+			// fused square is its base only once, even though the instruction reads it twice.
+			let (operands, dag_operands) = match fused_adds.get(&id) {
+				Some(&(product, addend)) => {
+					let (lhs, rhs) = match dag.nodes[product] {
+						Node::Mul(lhs, rhs) => (lhs, rhs),
+						Node::Square(src) => (src, src),
+						_ => unreachable!("fusable nodes are products"),
+					};
+					let dag_operands = dag.nodes[product].operands().chain([addend]).collect();
+					(vec![lhs, rhs, addend], dag_operands)
+				}
+				None => (node.operands().collect(), node.operands().collect::<Vec<_>>()),
+			};
+			let location = |id: usize| locations[id].expect("operands precede their users");
+			let operand_locations = operands.iter().map(|&id| location(id)).collect::<Vec<_>>();
+
+			// Release the registers of operands which are not read again before allocating the
+			// destination, which may therefore reuse one of them.
+			for &operand in &dag_operands {
+				remaining_uses[operand] -= 1;
+				let is_computed = !matches!(dag.nodes[operand], Node::Var(_) | Node::Const(_));
+				if remaining_uses[operand] == 0 && is_computed {
+					let Operand::Reg(register) = location(operand) else {
+						unreachable!("computed nodes are held in registers");
+					};
+					allocator.free.push(register);
+				}
+			}
+			let dst = allocator.allocate();
+			locations[id] = Some(Operand::Reg(dst));
+
+			let instruction = match (node, operand_locations.as_slice()) {
+				(Node::Add(..), &[lhs, rhs, addend]) => Instruction::MulAdd {
+					dst,
+					lhs,
+					rhs,
+					addend,
+				},
+				(Node::Add(..), &[lhs, rhs]) => Instruction::Add { dst, lhs, rhs },
+				(Node::Mul(..), &[lhs, rhs]) => Instruction::Mul { dst, lhs, rhs },
+				(Node::Square(_), &[src]) => Instruction::Square { dst, src },
+				_ => unreachable!("operand counts match the node kinds"),
+			};
+			instructions.push(instruction);
+		}
+
+		Self {
+			constants,
+			instructions,
+			n_registers: allocator.n_registers,
+			n_vars: circuit.n_vars(),
+			output: locations[output].expect("output is reachable"),
+		}
+	}
+
+	pub fn instructions(&self) -> &[Instruction] {
+		&self.instructions
+	}
+
+	/// The number of registers, including the registers holding constants.
+	pub const fn n_registers(&self) -> usize {
+		self.n_registers
+	}
+
+	pub const fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	pub fn evaluate<P>(&self, query: &[P]) -> Result<P, Error>
+	where
+		P: PackedField<Scalar: ExtensionField<F>>,
+	{
+		if query.len() < self.n_vars {
+			bail!(Error::IncorrectQuerySize {
+				expected: self.n_vars,
+				actual: query.len(),
+			});
+		}
+
+		alloc_scratch_space::<P, _, _>(self.n_registers, |registers| {
+			let registers = self.init_registers(registers, 1);
+			// Safety: every variable row has one element, and so does every register.
+			unsafe {
+				let var_row = |index| ptr::from_ref(&query[index]);
+				self.execute_chunk(registers, 1, 1, var_row);
+				Ok(*operand_ptr(self.output, registers, 1, var_row))
+			}
+		})
+	}
+
+	pub fn batch_evaluate<P>(
+		&self,
+		batch_query: &RowsBatchRef<P>,
+		evals: &mut [P],
+	) -> Result<(), Error>
+	where
+		P: PackedField<Scalar: ExtensionField<F>>,
+	{
+		let row_len = evals.len();
+		if batch_query.row_len() != row_len {
+			bail!(Error::BatchEvaluateSizeMismatch {
+				expected: row_len,
+				actual: batch_query.row_len(),
+			});
+		}
+		if batch_query.n_rows() < self.n_vars {
+			bail!(Error::IncorrectQuerySize {
+				expected: self.n_vars,
+				actual: batch_query.n_rows(),
+			});
+		}
+
+		let chunk_len = row_len.clamp(1, CHUNK_LEN);
+		alloc_scratch_space::<P, _, _>(self.n_registers * chunk_len, |registers| {
+			let registers = self.init_registers(registers, chunk_len);
+			for (offset, evals) in (0..).step_by(chunk_len).zip(evals.chunks_mut(chunk_len)) {
+				let len = evals.len();
+				// Safety: the rows have `row_len` elements, so at least `len` from `offset` on, and
+				// every register has `chunk_len >= len` elements.
+				unsafe {
+					let var_row = |index| batch_query.row(index).as_ptr().add(offset);
+					self.execute_chunk(registers, chunk_len, len, var_row);
+					let output = operand_ptr(self.output, registers, chunk_len, var_row);
+					for (j, eval) in evals.iter_mut().enumerate() {
+						*eval = *output.add(j);
+					}
+				}
+			}
+		});
+
+		Ok(())
+	}
+
+	/// Writes the constants to their registers and returns a pointer to the register file.
+	///
+	/// The other registers are left uninitialized, as every instruction writes its destination
+	/// register before any instruction reads it.
+	fn init_registers<P>(&self, registers: &mut [MaybeUninit<P>], chunk_len: usize) -> *mut P
+	where
+		P: PackedField<Scalar: ExtensionField<F>>,
+	{
+		for (register, &value) in registers.chunks_exact_mut(chunk_len).zip(&self.constants) {
+			register.fill(MaybeUninit::new(P::broadcast(value.into())));
+		}
+		registers.as_mut_ptr().cast()
+	}
+
+	/// Applies the instructions to the first `len` elements of every row.
+	///
+	/// ## Safety
+	///
+	/// `registers` must point to `self.n_registers` registers of `chunk_len >= len` elements each,
+	/// and `var_row` must return a pointer to at least `len` elements for every variable.
+	unsafe fn execute_chunk<P: PackedField>(
+		&self,
+		registers: *mut P,
+		chunk_len: usize,
+		len: usize,
+		var_row: impl Fn(usize) -> *const P + Copy,
+	) {
+		// Safety: register operands are in bounds by construction of the instructions.
+		let operand = |operand| unsafe { operand_ptr(operand, registers, chunk_len, var_row) };
+		for instruction in &self.instructions {
+			// Safety: the pointers are valid for `len` elements by the contract of the function.
+			// The destination may alias an operand, but every element is read before it is
+			// written.
+			unsafe {
+				match *instruction {
+					Instruction::Add { dst, lhs, rhs } => {
+						let (dst, lhs, rhs) =
+							(registers.add(dst * chunk_len), operand(lhs), operand(rhs));
+						for j in 0..len {
+							*dst.add(j) = *lhs.add(j) + *rhs.add(j);
+						}
+					}
+					Instruction::Mul { dst, lhs, rhs } => {
+						let (dst, lhs, rhs) =
+							(registers.add(dst * chunk_len), operand(lhs), operand(rhs));
+						for j in 0..len {
+							*dst.add(j) = *lhs.add(j) * *rhs.add(j);
+						}
+					}
+					Instruction::Square { dst, src } => {
+						let (dst, src) = (registers.add(dst * chunk_len), operand(src));
+						for j in 0..len {
+							*dst.add(j) = (*src.add(j)).square();
+						}
+					}
+					Instruction::MulAdd {
+						dst,
+						lhs,
+						rhs,
+						addend,
+					} => {
+						let (dst, lhs, rhs, addend) = (
+							registers.add(dst * chunk_len),
+							operand(lhs),
+							operand(rhs),
+							operand(addend),
+						);
+						for j in 0..len {
+							*dst.add(j) = *lhs.add(j) * *rhs.add(j) + *addend.add(j);
+						}
+					}
+				}
+			}
+		}
+	}
+}
+
+/// Returns a pointer to the elements of an operand in the current chunk.
+///
+/// ## Safety
+///
+/// A register operand must be in bounds of the register file.
+unsafe fn operand_ptr<P>(
+	operand: Operand,
+	registers: *mut P,
+	chunk_len: usize,
+	var_row: impl Fn(usize) -> *const P,
+) -> *const P {
+	match operand {
+		Operand::Var(index) => var_row(index),
+		// Safety: guaranteed by the caller.
+		Operand::Reg(register) => unsafe { registers.add(register * chunk_len) },
+	}
+}
+
+/// A node of the DAG of the circuit, whose operands are the indices of previous nodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Node<F> {
+	Var(usize),
+	Const(F),
+	Add(usize, usize),
+	Mul(usize, usize),
+	Square(usize),
+}
+
+impl<F> Node<F> {
+	fn operands(&self) -> impl Iterator<Item = usize> {
+		let (first, second) = match *self {
+			Self::Var(_) | Self::Const(_) => (None, None),
+			Self::Add(left, right) | Self::Mul(left, right) => (Some(left), Some(right)),
+			Self::Square(src) => (Some(src), None),
+		};
+		first.into_iter().chain(second)
+	}
+}
+
+/// A hash-consed DAG, in which structurally equal nodes are shared.
+#[derive(Debug)]
+struct Dag<F> {
+	nodes: Vec<Node<F>>,
+	ids: HashMap<Node<F>, usize>,
+}
+
+impl<F> Default for Dag<F> {
+	fn default() -> Self {
+		Self {
+			nodes: Vec::new(),
+			ids: HashMap::new(),
+		}
+	}
+}
+
+impl<F: Field> Dag<F> {
+	fn insert(&mut self, node: Node<F>) -> usize {
+		*self.ids.entry(node).or_insert_with(|| {
+			self.nodes.push(node);
+			self.nodes.len() - 1
+		})
+	}
+
+	fn add(&mut self, left: usize, right: usize) -> usize {
+		match (self.nodes[left], self.nodes[right]) {
+			(Node::Const(left), Node::Const(right)) => self.insert(Node::Const(left + right)),
+			(Node::Const(value), _) if value == F::ZERO => right,
+			(_, Node::Const(value)) if value == F::ZERO => left,
+			// Operands of commutative operations are sorted so that both orders share a node.
+			_ => self.insert(Node::Add(left.min(right), left.max(right))),
+		}
+	}
+
+	fn mul(&mut self, left: usize, right: usize) -> usize {
+		match (self.nodes[left], self.nodes[right]) {
+			(Node::Const(left), Node::Const(right)) => self.insert(Node::Const(left * right)),
+			(Node::Const(value), _) | (_, Node::Const(value)) if value == F::ZERO => {
+				self.insert(Node::Const(F::ZERO))
+			}
+			(Node::Const(value), _) if value == F::ONE => right,
+			(_, Node::Const(value)) if value == F::ONE => left,
+			_ if left == right => self.square(left),
+			_ => self.insert(Node::Mul(left.min(right), left.max(right))),
+		}
+	}
+
+	fn square(&mut self, src: usize) -> usize {
+		match self.nodes[src] {
+			Node::Const(value) => self.insert(Node::Const(value.square())),
+			_ => self.insert(Node::Square(src)),
+		}
+	}
+
+	fn pow(&mut self, base: usize, exp: u64) -> usize {
+		if exp == 0 {
+			return self.insert(Node::Const(F::ONE));
+		}
+
+		let mut acc = base;
+		for i in (0..exp.ilog2()).rev() {
+			acc = self.square(acc);
+			if (exp >> i) & 1 != 0 {
+				acc = self.mul(acc, base);
+			}
+		}
+		acc
+	}
+}
+
+#[derive(Debug)]
+struct RegisterAllocator {
+	n_registers: usize,
+	free: Vec<usize>,
+}
+
+impl RegisterAllocator {
+	fn allocate(&mut self) -> usize {
+		self.free.pop().unwrap_or_else(|| {
+			self.n_registers += 1;
+			self.n_registers - 1
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField8b, BinaryField128b, PackedBinaryField16x8b};
+	use binius_math::{ArithExpr, RowsBatch};
+	use rand::{Rng, SeedableRng, rngs::StdRng};
+
+	use super::*;
+
+	type F = BinaryField8b;
+	type P = PackedBinaryField16x8b;
+
+	/// Generates a random expression whose subexpressions are drawn from a pool of previously
+	/// generated ones, so that the compiled DAG shares nodes between several users.
+	fn random_expr(rng: &mut StdRng, n_vars: usize, depth: usize) -> ArithExpr<F> {
+		let mut pool = Vec::new();
+		random_shared_expr(rng, n_vars, depth, &mut pool)
+	}
+
+	fn random_shared_expr(
+		rng: &mut StdRng,
+		n_vars: usize,
+		depth: usize,
+		pool: &mut Vec<ArithExpr<F>>,
+	) -> ArithExpr<F> {
+		if !pool.is_empty() && rng.random_bool(0.3) {
+			return pool[rng.random_range(0..pool.len())].clone();
+		}
+		let expr = if depth == 0 {
+			if rng.random_bool(0.8) {
+				ArithExpr::Var(rng.random_range(0..n_vars))
+			} else {
+				ArithExpr::Const(F::new(rng.random()))
+			}
+		} else {
+			let (choice, exp) = (rng.random_range(0..5), rng.random_range(0..6));
+			let mut operand = || random_shared_expr(rng, n_vars, depth - 1, pool);
+			match choice {
+				0 => operand() + operand(),
+				1 => operand() * operand(),
+				2 => operand().pow(exp),
+				// A square with an addend is fused into a multiply-add.
+				3 => operand().pow(2) + operand(),
+				_ => random_shared_expr(rng, n_vars, 0, pool),
+			}
+		};
+		pool.push(expr.clone());
+		expr
+	}
+
+	fn check_against_circuit(circuit: &ArithCircuit<F>, n_vars: usize, row_len: usize) {
+		let bytecode = CircuitBytecode::compile(circuit);
+		let mut rng = StdRng::seed_from_u64(row_len as u64);
+		let rows = (0..n_vars)
+			.map(|_| {
+				(0..row_len)
+					.map(|_| P::random(&mut rng))
+					.collect::<Vec<_>>()
+			})
+			.collect::<Vec<_>>();
+		let batch_query = RowsBatch::new_from_iter(rows.iter().map(Vec::as_slice), row_len);
+
+		let mut evals = vec![P::zero(); row_len];
+		bytecode
+			.batch_evaluate(&batch_query.get_ref(), &mut evals)
+			.unwrap();
+
+		for (i, &eval) in evals.iter().enumerate() {
+			let query = rows.iter().map(|row| row[i]).collect::<Vec<_>>();
+			assert_eq!(bytecode.evaluate(&query).unwrap(), eval);
+			for (j, scalar) in eval.iter().enumerate() {
+				let scalar_query = query.iter().map(|x| x.get(j)).collect::<Vec<_>>();
+				assert_eq!(circuit.evaluate(&scalar_query).unwrap(), scalar);
+			}
+		}
+	}
+
+	#[test]
+	fn test_random_circuits_match_interpreter() {
+		let mut rng = StdRng::seed_from_u64(0);
+		for _ in 0..50 {
+			let circuit = ArithCircuit::from(&random_expr(&mut rng, 4, 5));
+			for row_len in [1, 3, CHUNK_LEN, CHUNK_LEN + 5] {
+				check_against_circuit(&circuit, 4, row_len);
+			}
+		}
+	}
+
+	#[test]
+	fn test_common_subexpressions_are_shared() {
+		let x = ArithExpr::<F>::Var(0);
+		let y = ArithExpr::<F>::Var(1);
+		// Both operands of the outer product are the same sum, built twice in the circuit.
+		let expr = (x.clone() + y.clone()) * (y + x);
+		let bytecode = CircuitBytecode::compile(&ArithCircuit::from(&expr));
+		assert_eq!(
+			bytecode.instructions(),
+			&[
+				Instruction::Add {
+					dst: 0,
+					lhs: Operand::Var(0),
+					rhs: Operand::Var(1),
+				},
+				Instruction::Square {
+					dst: 0,
+					src: Operand::Reg(0),
+				},
+			]
+		);
+	}
+
+	#[test]
+	fn test_products_are_fused_into_additions() {
+		let expr = ArithExpr::Const(F::new(7))
+			+ ArithExpr::Var(0) * ArithExpr::Var(1)
+			+ ArithExpr::Var(2) * ArithExpr::Var(3);
+		let bytecode = CircuitBytecode::compile(&ArithCircuit::from(&expr));
+		assert_eq!(bytecode.n_registers(), 2);
+		assert!(
+			bytecode
+				.instructions()
+				.iter()
+				.all(|instruction| matches!(instruction, Instruction::MulAdd { .. }))
+		);
+		check_against_circuit(&ArithCircuit::from(&expr), 4, 10);
+	}
+
+	#[test]
+	fn test_fused_square_keeps_shared_base_live() {
+		// The square of `x + y` is fused into the addition of `z`, while `x + y` is read again
+		// by the outer product.
+		let sum = ArithExpr::<F>::Var(0) + ArithExpr::Var(1);
+		let expr = (sum.clone().pow(2) + ArithExpr::Var(2)) * sum;
+		let circuit = ArithCircuit::from(&expr);
+		let bytecode = CircuitBytecode::compile(&circuit);
+		assert!(
+			bytecode
+				.instructions()
+				.iter()
+				.any(|instruction| matches!(instruction, Instruction::MulAdd { .. }))
+		);
+
+		let query = [F::new(3), F::new(5), F::new(7)];
+		assert_eq!(bytecode.evaluate(&query).unwrap(), circuit.evaluate(&query).unwrap());
+		check_against_circuit(&circuit, 3, 10);
+	}
+
+	#[test]
+	fn test_registers_are_reused() {
+		// A chain of 100 operations only needs one register at a time.
+		let expr = (0..100).fold(ArithExpr::<F>::Var(0), |acc, i| {
+			acc * ArithExpr::Var(1 + i % 3) + ArithExpr::Var(i % 4)
+		});
+		let bytecode = CircuitBytecode::compile(&ArithCircuit::from(&expr));
+		assert_eq!(bytecode.n_registers(), 1);
+		check_against_circuit(&ArithCircuit::from(&expr), 4, 10);
+	}
+
+	#[test]
+	fn test_trivial_outputs() {
+		let bytecode = CircuitBytecode::compile(&ArithCircuit::<BinaryField128b>::var(2));
+		assert!(bytecode.instructions().is_empty());
+		assert_eq!(
+			bytecode
+				.evaluate(&[
+					BinaryField128b::ZERO,
+					BinaryField128b::ZERO,
+					BinaryField128b::ONE
+				])
+				.unwrap(),
+			BinaryField128b::ONE
+		);
+
+		let circuit = ArithCircuit::<F>::var(0).pow(3) * ArithCircuit::zero();
+		let bytecode = CircuitBytecode::compile(&circuit);
+		assert!(bytecode.instructions().is_empty());
+		assert_eq!(bytecode.evaluate(&[P::one()]).unwrap(), P::zero());
+
+		assert!(matches!(bytecode.evaluate::<P>(&[]), Err(Error::IncorrectQuerySize { .. })));
+	}
+}
@@ -1,5 +1,6 @@
 // Copyright 2025 Irreducible Inc.
 
 pub mod arith_circuit;
+pub mod bytecode;
 pub mod layer;
 pub mod memory;
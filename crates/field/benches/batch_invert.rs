@@ -0,0 +1,64 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::{
+	BinaryField32b, BinaryField128b, PackedBinaryField1x128b, PackedBinaryField4x32b,
+	PackedBinaryField16x8b, PackedField,
+	batch_invert::{batch_invert_or_zero, batch_invert_or_zero_with_scratch},
+};
+use criterion::{
+	BenchmarkGroup, Criterion, criterion_group, criterion_main, measurement::Measurement,
+};
+
+const LOG_SIZES: [usize; 3] = [8, 12, 16];
+
+fn bench_batch_invert<P: PackedField, M: Measurement>(
+	group: &mut BenchmarkGroup<'_, M>,
+	name: &str,
+) {
+	let mut rng = rand::rng();
+	for log_size in LOG_SIZES {
+		let values = (0..1 << log_size)
+			.map(|_| P::random(&mut rng))
+			.collect::<Vec<_>>();
+		group.throughput(criterion::Throughput::Elements(((1 << log_size) * P::WIDTH) as _));
+
+		group.bench_function(format!("{name}/elementwise/{log_size}"), |bench| {
+			let mut output = values.clone();
+			bench.iter(|| {
+				for (output, value) in output.iter_mut().zip(&values) {
+					*output = value.invert_or_zero();
+				}
+			})
+		});
+
+		group.bench_function(format!("{name}/batch_single_threaded/{log_size}"), |bench| {
+			let mut output = values.clone();
+			let mut scratch = Vec::new();
+			bench.iter(|| {
+				output.copy_from_slice(&values);
+				batch_invert_or_zero_with_scratch(&mut output, &mut scratch);
+			})
+		});
+
+		group.bench_function(format!("{name}/batch/{log_size}"), |bench| {
+			let mut output = values.clone();
+			bench.iter(|| {
+				output.copy_from_slice(&values);
+				batch_invert_or_zero(&mut output);
+			})
+		});
+	}
+}
+
+fn batch_invert(c: &mut Criterion) {
+	let mut group = c.benchmark_group("batch_invert");
+	bench_batch_invert::<BinaryField32b, _>(&mut group, "BinaryField32b");
+	bench_batch_invert::<BinaryField128b, _>(&mut group, "BinaryField128b");
+	bench_batch_invert::<PackedBinaryField16x8b, _>(&mut group, "PackedBinaryField16x8b");
+	bench_batch_invert::<PackedBinaryField4x32b, _>(&mut group, "PackedBinaryField4x32b");
+	bench_batch_invert::<PackedBinaryField1x128b, _>(&mut group, "PackedBinaryField1x128b");
+	group.finish();
+}
+
+criterion_group!(benches, batch_invert);
+criterion_main!(benches);
@@ -4,7 +4,7 @@ use std::array;
 
 use binius_field::{
 	BinaryField8b, BinaryField16b, BinaryField32b, BinaryField64b, BinaryField128b,
-	BinaryField128bPolyval, Field,
+	BinaryField128bPolyval, Field, PackedField,
 	aes_field::{
 		AESTowerField8b, AESTowerField16b, AESTowerField32b, AESTowerField64b, AESTowerField128b,
 	},
@@ -105,5 +105,72 @@ fn invert(c: &mut Criterion) {
 	bench_all_fields::<InvertOp>(c);
 }
 
+#[cfg(feature = "nightly_features")]
+fn bench_packed_function<P: PackedField, M: Measurement, R>(
+	c: &mut BenchmarkGroup<'_, M>,
+	id: &str,
+	func: impl Fn(P, P) -> R,
+) {
+	let mut rng = rand::rng();
+	let a: [P; BATCH_SIZE] = array::from_fn(|_| P::random(&mut rng));
+	let b: [P; BATCH_SIZE] = array::from_fn(|_| P::random(&mut rng));
+	c.bench_function(id, |bench| {
+		bench.iter(|| array::from_fn::<_, BATCH_SIZE, _>(|i| func(a[i], b[i])))
+	});
+}
+
+/// Benchmarks the packed fields of the `std::simd` backend against the default backend of the
+/// target architecture.
+#[cfg(feature = "nightly_features")]
+fn packed_portable_simd(c: &mut Criterion) {
+	use binius_field::arch;
+
+	macro_rules! run_packed_bench {
+		($group:ident, $module:ident, $packed:ident, $op:expr) => {
+			bench_packed_function::<arch::$module::$packed, _, _>(
+				&mut $group,
+				concat!("default/", stringify!($packed)),
+				$op,
+			);
+			bench_packed_function::<arch::portable_simd::$module::$packed, _, _>(
+				&mut $group,
+				concat!("portable_simd/", stringify!($packed)),
+				$op,
+			);
+		};
+	}
+
+	macro_rules! run_packed_benches {
+		($group:ident, $op:expr) => {
+			run_packed_bench!($group, packed_128, PackedBinaryField16x8b, $op);
+			run_packed_bench!($group, packed_128, PackedBinaryField1x128b, $op);
+			run_packed_bench!($group, packed_256, PackedBinaryField32x8b, $op);
+			run_packed_bench!($group, packed_256, PackedBinaryField2x128b, $op);
+			run_packed_bench!($group, packed_512, PackedBinaryField64x8b, $op);
+			run_packed_bench!($group, packed_512, PackedBinaryField4x128b, $op);
+			run_packed_bench!($group, packed_aes_256, PackedAESBinaryField32x8b, $op);
+			run_packed_bench!($group, packed_aes_256, PackedAESBinaryField2x128b, $op);
+		};
+	}
+
+	let mut group = c.benchmark_group("packed/multiply");
+	group.throughput(criterion::Throughput::Elements(BATCH_SIZE as _));
+	run_packed_benches!(group, |a, b| a * b);
+	group.finish();
+
+	let mut group = c.benchmark_group("packed/square");
+	group.throughput(criterion::Throughput::Elements(BATCH_SIZE as _));
+	run_packed_benches!(group, |a, _| PackedField::square(a));
+	group.finish();
+
+	let mut group = c.benchmark_group("packed/invert");
+	group.throughput(criterion::Throughput::Elements(BATCH_SIZE as _));
+	run_packed_benches!(group, |a, _| PackedField::invert_or_zero(a));
+	group.finish();
+}
+
+#[cfg(feature = "nightly_features")]
+criterion_group!(binary_arithmetic, multiply, square, invert, packed_portable_simd);
+#[cfg(not(feature = "nightly_features"))]
 criterion_group!(binary_arithmetic, multiply, square, invert,);
 criterion_main!(binary_arithmetic);
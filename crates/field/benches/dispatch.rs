@@ -0,0 +1,82 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::{
+	PackedAESBinaryField1x128b, PackedBinaryField1x128b, PackedBinaryField4x32b,
+	PackedBinaryField16x8b, PackedField,
+	arch::dispatch::{Dispatcher, FeatureLevel},
+};
+use criterion::{
+	BenchmarkGroup, Criterion, criterion_group, criterion_main, measurement::Measurement,
+};
+use itertools::{Itertools, izip};
+
+const LOG_SIZE: usize = 12;
+
+/// Compares the arithmetic of the packed types selected at compile time with the arithmetic the
+/// [`Dispatcher`] selects at runtime, at the baseline and at the best level of the running CPU.
+fn bench_dispatch<P: PackedField, M: Measurement>(group: &mut BenchmarkGroup<'_, M>, name: &str) {
+	let mut rng = rand::rng();
+	let lhs = (0..1 << LOG_SIZE)
+		.map(|_| P::random(&mut rng))
+		.collect::<Vec<_>>();
+	let rhs = (0..1 << LOG_SIZE)
+		.map(|_| P::random(&mut rng))
+		.collect::<Vec<_>>();
+	group.throughput(criterion::Throughput::Elements(((1 << LOG_SIZE) * P::WIDTH) as _));
+
+	group.bench_function(format!("{name}/mul/operator"), |bench| {
+		let mut output = lhs.clone();
+		bench.iter(|| {
+			for (output, &lhs, &rhs) in izip!(&mut output, &lhs, &rhs) {
+				*output = lhs * rhs;
+			}
+		})
+	});
+	group.bench_function(format!("{name}/invert/operator"), |bench| {
+		let mut output = lhs.clone();
+		bench.iter(|| {
+			for (output, &lhs) in output.iter_mut().zip(&lhs) {
+				*output = lhs.invert_or_zero();
+			}
+		})
+	});
+
+	for level in [FeatureLevel::Baseline, FeatureLevel::get()]
+		.into_iter()
+		.dedup()
+	{
+		let dispatcher = Dispatcher::at(level);
+		group.bench_function(format!("{name}/mul/{level:?}"), |bench| {
+			let mut output = lhs.clone();
+			bench.iter(|| {
+				dispatcher.run(|dispatcher| {
+					for (output, &lhs, &rhs) in izip!(&mut output, &lhs, &rhs) {
+						*output = dispatcher.mul(lhs, rhs);
+					}
+				})
+			})
+		});
+		group.bench_function(format!("{name}/invert/{level:?}"), |bench| {
+			let mut output = lhs.clone();
+			bench.iter(|| {
+				dispatcher.run(|dispatcher| {
+					for (output, &lhs) in output.iter_mut().zip(&lhs) {
+						*output = dispatcher.invert_or_zero(lhs);
+					}
+				})
+			})
+		});
+	}
+}
+
+fn dispatch(c: &mut Criterion) {
+	let mut group = c.benchmark_group("dispatch");
+	bench_dispatch::<PackedBinaryField16x8b, _>(&mut group, "PackedBinaryField16x8b");
+	bench_dispatch::<PackedBinaryField4x32b, _>(&mut group, "PackedBinaryField4x32b");
+	bench_dispatch::<PackedBinaryField1x128b, _>(&mut group, "PackedBinaryField1x128b");
+	bench_dispatch::<PackedAESBinaryField1x128b, _>(&mut group, "PackedAESBinaryField1x128b");
+	group.finish();
+}
+
+criterion_group!(benches, dispatch);
+criterion_main!(benches);
@@ -339,6 +339,9 @@ macro_rules! serialize_deserialize_non_canonical {
 				}
 			}
 		}
+
+		#[cfg(feature = "serde")]
+		binius_utils::impl_serde_via_big_endian_bytes!($field);
 	};
 }
 
@@ -0,0 +1,533 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Runtime CPU feature detection and function multiversioning.
+//!
+//! The packed field types, including [`OptimalUnderlier`](super::OptimalUnderlier), are selected
+//! at compile time from the enabled target features. A binary built for a generic target never
+//! uses the instructions of newer CPUs, even when it runs on one. A [`Dispatcher`] selects the
+//! best [`FeatureLevel`] the running CPU supports once per process and then switches the code to
+//! that level in two ways:
+//!
+//! * [`Dispatcher::run`] runs a closure in a function compiled for the level, so the code inlined
+//!   into it is compiled with the wider registers and the additional instructions of the level.
+//! * [`Dispatcher::mul`], [`Dispatcher::square`] and [`Dispatcher::invert_or_zero`] switch the
+//!   arithmetic strategy of the 128-bit packed fields to the GFNI strategies when the level
+//!   supports GFNI, but the binary was compiled without it. Other packed fields use their
+//!   operators.
+//!
+//! The dispatcher is `Copy`, hot loops should obtain it once and run as a whole in
+//! [`Dispatcher::run`], because the arithmetic has to be inlined into the dispatched function to
+//! use the instructions of the level.
+
+use std::{str::FromStr, sync::OnceLock};
+
+use cfg_if::cfg_if;
+
+use crate::{
+	PackedField,
+	linear_transformation::{
+		FieldLinearTransformation, PackedTransformationFactory, Transformation,
+	},
+};
+
+cfg_if! {
+	if #[cfg(all(
+		target_arch = "x86_64",
+		target_feature = "sse2",
+		not(target_feature = "gfni"),
+		feature = "nightly_features"
+	))] {
+		use super::x86_64::gfni::runtime as gfni_runtime;
+
+		/// Whether the GFNI arithmetic has to be selected at runtime.
+		const RUNTIME_GFNI: bool = true;
+	} else {
+		/// Without the runtime GFNI kernels every packed field uses its operators.
+		mod gfni_runtime {
+			use crate::{BinaryField, PackedField, linear_transformation::FieldLinearTransformation};
+
+			pub unsafe fn mul<P: PackedField>(_lhs: P, _rhs: P) -> Option<P> {
+				None
+			}
+
+			pub unsafe fn square<P: PackedField>(_value: P) -> Option<P> {
+				None
+			}
+
+			pub unsafe fn invert_or_zero<P: PackedField>(_value: P) -> Option<P> {
+				None
+			}
+
+			pub unsafe fn transform_slice<P, Data>(
+				_transformation: &FieldLinearTransformation<P::Scalar, Data>,
+				_values: &mut [P],
+			) -> Option<()>
+			where
+				P: PackedField<Scalar: BinaryField>,
+				Data: AsRef<[P::Scalar]> + Sync,
+			{
+				None
+			}
+		}
+
+		/// Whether the GFNI arithmetic has to be selected at runtime.
+		const RUNTIME_GFNI: bool = false;
+	}
+}
+
+/// The environment variable capping the feature level selected by [`FeatureLevel::get`].
+pub const MAX_FEATURE_LEVEL_ENV: &str = "BINIUS_MAX_FEATURE_LEVEL";
+
+/// The CPU features relevant to binary field arithmetic.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CpuFeatures {
+	pub avx2: bool,
+	pub avx512f: bool,
+	pub avx512bw: bool,
+	pub avx512vl: bool,
+	pub gfni: bool,
+	pub pclmulqdq: bool,
+	pub vpclmulqdq: bool,
+	pub neon: bool,
+	pub aes: bool,
+}
+
+impl CpuFeatures {
+	/// Probes the features of the running CPU.
+	pub fn detect() -> Self {
+		#[allow(unused_mut)]
+		let mut features = Self::default();
+		#[cfg(target_arch = "x86_64")]
+		{
+			features.avx2 = std::arch::is_x86_feature_detected!("avx2");
+			features.avx512f = std::arch::is_x86_feature_detected!("avx512f");
+			features.avx512bw = std::arch::is_x86_feature_detected!("avx512bw");
+			features.avx512vl = std::arch::is_x86_feature_detected!("avx512vl");
+			features.gfni = std::arch::is_x86_feature_detected!("gfni");
+			features.pclmulqdq = std::arch::is_x86_feature_detected!("pclmulqdq");
+			features.vpclmulqdq = std::arch::is_x86_feature_detected!("vpclmulqdq");
+		}
+		#[cfg(target_arch = "aarch64")]
+		{
+			features.neon = std::arch::is_aarch64_feature_detected!("neon");
+			features.aes = std::arch::is_aarch64_feature_detected!("aes");
+		}
+		features
+	}
+
+	/// Returns the features of the running CPU, probing them on the first call.
+	pub fn get() -> &'static Self {
+		static FEATURES: OnceLock<CpuFeatures> = OnceLock::new();
+		FEATURES.get_or_init(Self::detect)
+	}
+}
+
+/// A set of target features [`dispatch`] can compile code for, in increasing order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FeatureLevel {
+	/// The target features enabled at compile time.
+	Baseline,
+	/// AVX2 and carry-less multiplication.
+	Avx2,
+	/// AVX2, GFNI and carry-less multiplication.
+	///
+	/// Compiling for GFNI requires the `nightly_features` feature.
+	Avx2Gfni,
+	/// AVX-512, GFNI and carry-less multiplication, including their 512-bit forms.
+	///
+	/// Compiling for AVX-512 requires the `nightly_features` feature.
+	Avx512Gfni,
+}
+
+impl FeatureLevel {
+	pub const ALL: [Self; 4] = [Self::Baseline, Self::Avx2, Self::Avx2Gfni, Self::Avx512Gfni];
+
+	/// Returns whether a CPU with the given features can run code compiled for this level.
+	pub fn is_supported(self, features: &CpuFeatures) -> bool {
+		let avx2 = cfg!(target_arch = "x86_64") && features.avx2 && features.pclmulqdq;
+		let gfni = cfg!(feature = "nightly_features") && avx2 && features.gfni;
+		match self {
+			Self::Baseline => true,
+			Self::Avx2 => avx2,
+			Self::Avx2Gfni => gfni,
+			Self::Avx512Gfni => {
+				gfni && features.avx512f
+					&& features.avx512bw
+					&& features.avx512vl
+					&& features.vpclmulqdq
+			}
+		}
+	}
+
+	/// Returns the highest level a CPU with the given features supports.
+	pub fn best(features: &CpuFeatures) -> Self {
+		Self::ALL
+			.into_iter()
+			.rfind(|level| level.is_supported(features))
+			.unwrap_or(Self::Baseline)
+	}
+
+	/// Returns the level [`dispatch`] uses.
+	///
+	/// This is the highest level the running CPU supports, capped by the level named in the
+	/// [`MAX_FEATURE_LEVEL_ENV`] environment variable if it is set. The level is selected on the
+	/// first call.
+	pub fn get() -> Self {
+		static LEVEL: OnceLock<FeatureLevel> = OnceLock::new();
+		*LEVEL.get_or_init(|| {
+			let best = Self::best(CpuFeatures::get());
+			match std::env::var(MAX_FEATURE_LEVEL_ENV) {
+				Ok(name) => match name.parse::<Self>() {
+					Ok(max) => best.min(max),
+					Err(_) => {
+						tracing::warn!(
+							"ignoring unknown feature level {name:?} in {MAX_FEATURE_LEVEL_ENV}"
+						);
+						best
+					}
+				},
+				Err(_) => best,
+			}
+		})
+	}
+}
+
+impl FromStr for FeatureLevel {
+	type Err = UnknownFeatureLevel;
+
+	fn from_str(name: &str) -> Result<Self, Self::Err> {
+		match name {
+			"baseline" => Ok(Self::Baseline),
+			"avx2" => Ok(Self::Avx2),
+			"avx2-gfni" => Ok(Self::Avx2Gfni),
+			"avx512-gfni" => Ok(Self::Avx512Gfni),
+			_ => Err(UnknownFeatureLevel),
+		}
+	}
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("unknown feature level")]
+pub struct UnknownFeatureLevel;
+
+/// Selects the packed field arithmetic for a [`FeatureLevel`] the running CPU supports.
+///
+/// The level is validated when the dispatcher is created, so copies of the dispatcher can be passed
+/// into hot loops and parallel tasks without probing the CPU features again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dispatcher {
+	level: FeatureLevel,
+}
+
+impl Dispatcher {
+	/// Returns the dispatcher for the level returned by [`FeatureLevel::get`].
+	#[inline]
+	pub fn get() -> Self {
+		// The level is capped by the best level the CPU supports.
+		Self {
+			level: FeatureLevel::get(),
+		}
+	}
+
+	/// Returns the dispatcher for `level`.
+	///
+	/// ## Panics
+	///
+	/// * if the running CPU does not support `level`
+	pub fn at(level: FeatureLevel) -> Self {
+		assert!(
+			level.is_supported(CpuFeatures::get()),
+			"the CPU does not support feature level {level:?}"
+		);
+		Self { level }
+	}
+
+	pub const fn level(self) -> FeatureLevel {
+		self.level
+	}
+
+	/// Runs `f` in a function compiled for the level of the dispatcher.
+	#[inline]
+	pub fn run<R>(self, f: impl FnOnce(Self) -> R) -> R {
+		match self.level {
+			FeatureLevel::Baseline => f(self),
+			// Safety: the CPU supports the target features of the level.
+			#[cfg(target_arch = "x86_64")]
+			FeatureLevel::Avx2 => unsafe { x86_64::run_avx2(|| f(self)) },
+			#[cfg(all(feature = "nightly_features", target_arch = "x86_64"))]
+			FeatureLevel::Avx2Gfni => unsafe { x86_64::run_avx2_gfni(|| f(self)) },
+			#[cfg(all(feature = "nightly_features", target_arch = "x86_64"))]
+			FeatureLevel::Avx512Gfni => unsafe { x86_64::run_avx512_gfni(|| f(self)) },
+			#[allow(unreachable_patterns)]
+			_ => unreachable!("the level is not supported by this build"),
+		}
+	}
+
+	/// Whether the arithmetic is switched to the GFNI strategies at runtime.
+	#[inline(always)]
+	const fn runtime_gfni(self) -> bool {
+		RUNTIME_GFNI && matches!(self.level, FeatureLevel::Avx2Gfni | FeatureLevel::Avx512Gfni)
+	}
+
+	/// Multiplies `lhs` by `rhs` with the arithmetic of the level.
+	#[inline(always)]
+	pub fn mul<P: PackedField>(self, lhs: P, rhs: P) -> P {
+		if self.runtime_gfni() {
+			// Safety: the CPU supports GFNI.
+			if let Some(product) = unsafe { gfni_runtime::mul(lhs, rhs) } {
+				return product;
+			}
+		}
+		lhs * rhs
+	}
+
+	/// Squares `value` with the arithmetic of the level.
+	#[inline(always)]
+	pub fn square<P: PackedField>(self, value: P) -> P {
+		if self.runtime_gfni() {
+			// Safety: the CPU supports GFNI.
+			if let Some(square) = unsafe { gfni_runtime::square(value) } {
+				return square;
+			}
+		}
+		value.square()
+	}
+
+	/// Inverts `value` with the arithmetic of the level, mapping zero to zero.
+	#[inline(always)]
+	pub fn invert_or_zero<P: PackedField>(self, value: P) -> P {
+		if self.runtime_gfni() {
+			// Safety: the CPU supports GFNI.
+			if let Some(inverse) = unsafe { gfni_runtime::invert_or_zero(value) } {
+				return inverse;
+			}
+		}
+		value.invert_or_zero()
+	}
+}
+
+/// Runs `f` in a function compiled for the level returned by [`FeatureLevel::get`].
+#[inline]
+pub fn dispatch<R>(f: impl FnOnce() -> R) -> R {
+	Dispatcher::get().run(|_| f())
+}
+
+/// Runs `f` in a function compiled for `level`.
+///
+/// ## Panics
+///
+/// * if the running CPU does not support `level`
+#[inline]
+pub fn dispatch_at<R>(level: FeatureLevel, f: impl FnOnce() -> R) -> R {
+	Dispatcher::at(level).run(|_| f())
+}
+
+#[cfg(target_arch = "x86_64")]
+mod x86_64 {
+	#[target_feature(enable = "avx2,pclmulqdq")]
+	pub unsafe fn run_avx2<R>(f: impl FnOnce() -> R) -> R {
+		f()
+	}
+
+	#[cfg(feature = "nightly_features")]
+	#[target_feature(enable = "avx2,pclmulqdq,gfni")]
+	pub unsafe fn run_avx2_gfni<R>(f: impl FnOnce() -> R) -> R {
+		f()
+	}
+
+	#[cfg(feature = "nightly_features")]
+	#[target_feature(enable = "avx2,pclmulqdq,gfni,avx512f,avx512bw,avx512vl,vpclmulqdq")]
+	pub unsafe fn run_avx512_gfni<R>(f: impl FnOnce() -> R) -> R {
+		f()
+	}
+}
+
+/// Multiplies `lhs` by `rhs` element-wise, in place.
+///
+/// ## Preconditions
+///
+/// * `lhs` and `rhs` must have the same length
+pub fn mul_assign_slice<P: PackedField>(lhs: &mut [P], rhs: &[P]) {
+	assert_eq!(lhs.len(), rhs.len(), "slices must have the same length");
+	Dispatcher::get().run(|dispatcher| {
+		for (lhs, &rhs) in lhs.iter_mut().zip(rhs) {
+			*lhs = dispatcher.mul(*lhs, rhs);
+		}
+	})
+}
+
+/// Squares every element of `values` in place.
+pub fn square_slice<P: PackedField>(values: &mut [P]) {
+	Dispatcher::get().run(|dispatcher| {
+		for value in values.iter_mut() {
+			*value = dispatcher.square(*value);
+		}
+	})
+}
+
+/// Inverts every element of `values` in place, mapping zero to zero.
+pub fn invert_or_zero_slice<P: PackedField>(values: &mut [P]) {
+	Dispatcher::get().run(|dispatcher| {
+		for value in values.iter_mut() {
+			*value = dispatcher.invert_or_zero(*value);
+		}
+	})
+}
+
+/// Applies a linear transformation to every element of `values` in place.
+pub fn transform_slice<P, Data>(
+	transformation: &FieldLinearTransformation<P::Scalar, Data>,
+	values: &mut [P],
+) where
+	P: PackedTransformationFactory<P>,
+	Data: AsRef<[P::Scalar]> + Sync,
+{
+	Dispatcher::get().run(|dispatcher| {
+		// Safety: the CPU supports GFNI.
+		if dispatcher.runtime_gfni()
+			&& unsafe { gfni_runtime::transform_slice(transformation, values) }.is_some()
+		{
+			return;
+		}
+
+		let transformation =
+			P::make_packed_transformation(FieldLinearTransformation::new(transformation.bases()));
+		for value in values.iter_mut() {
+			*value = transformation.transform(value);
+		}
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use super::*;
+	use crate::{
+		BinaryField128b, Field, PackedAESBinaryField1x128b, PackedAESBinaryField2x64b,
+		PackedAESBinaryField4x32b, PackedAESBinaryField8x16b, PackedAESBinaryField16x8b,
+		PackedBinaryField1x128b, PackedBinaryField2x64b, PackedBinaryField2x128b,
+		PackedBinaryField4x32b, PackedBinaryField8x16b, PackedBinaryField16x8b,
+	};
+
+	fn supported_levels() -> impl Iterator<Item = FeatureLevel> {
+		FeatureLevel::ALL
+			.into_iter()
+			.filter(|level| level.is_supported(CpuFeatures::get()))
+	}
+
+	fn random_values<P: PackedField>(n: usize) -> Vec<P> {
+		let mut rng = StdRng::seed_from_u64(0);
+		(0..n).map(|_| P::random(&mut rng)).collect()
+	}
+
+	#[test]
+	fn test_feature_level_ordering() {
+		let features = CpuFeatures::get();
+		assert!(FeatureLevel::Baseline.is_supported(features));
+		assert!(FeatureLevel::best(features) >= FeatureLevel::get());
+		for level in supported_levels() {
+			assert!(level <= FeatureLevel::best(features));
+		}
+		assert_eq!(FeatureLevel::best(&CpuFeatures::default()), FeatureLevel::Baseline);
+	}
+
+	#[test]
+	fn test_parse_feature_level() {
+		assert_eq!("avx2-gfni".parse::<FeatureLevel>().unwrap(), FeatureLevel::Avx2Gfni);
+		assert!("sse9".parse::<FeatureLevel>().is_err());
+	}
+
+	#[test]
+	fn test_arithmetic_matches_across_levels() {
+		let lhs = random_values::<PackedBinaryField2x128b>(64);
+		let rhs = random_values::<PackedBinaryField2x128b>(64);
+		let product = lhs
+			.iter()
+			.zip(&rhs)
+			.map(|(&a, &b)| a * b)
+			.collect::<Vec<_>>();
+		let square = lhs.iter().map(|a| a.square()).collect::<Vec<_>>();
+		let inverse = lhs.iter().map(|a| a.invert_or_zero()).collect::<Vec<_>>();
+
+		for level in supported_levels() {
+			let mut values = lhs.clone();
+			dispatch_at(level, || {
+				for (value, &rhs) in values.iter_mut().zip(&rhs) {
+					*value *= rhs;
+				}
+			});
+			assert_eq!(values, product, "{level:?}");
+		}
+
+		let mut values = lhs.clone();
+		mul_assign_slice(&mut values, &rhs);
+		assert_eq!(values, product);
+		let mut values = lhs.clone();
+		square_slice(&mut values);
+		assert_eq!(values, square);
+		let lhs_first = lhs[0].get(0);
+		let mut values = lhs;
+		invert_or_zero_slice(&mut values);
+		assert_eq!(values, inverse);
+		assert_eq!(values[0].get(0) * lhs_first, BinaryField128b::ONE);
+	}
+
+	fn check_dispatcher_arithmetic<P: PackedField>() {
+		let lhs = random_values::<P>(16);
+		let rhs = random_values::<P>(16);
+		for level in supported_levels() {
+			Dispatcher::at(level).run(|dispatcher| {
+				for (&lhs, &rhs) in lhs.iter().zip(&rhs) {
+					assert_eq!(dispatcher.mul(lhs, rhs), lhs * rhs, "{level:?}");
+					assert_eq!(dispatcher.square(lhs), lhs.square(), "{level:?}");
+					assert_eq!(dispatcher.invert_or_zero(lhs), lhs.invert_or_zero(), "{level:?}");
+				}
+				assert_eq!(dispatcher.invert_or_zero(P::zero()), P::zero(), "{level:?}");
+			});
+		}
+	}
+
+	#[test]
+	fn test_dispatcher_arithmetic() {
+		check_dispatcher_arithmetic::<PackedBinaryField16x8b>();
+		check_dispatcher_arithmetic::<PackedBinaryField8x16b>();
+		check_dispatcher_arithmetic::<PackedBinaryField4x32b>();
+		check_dispatcher_arithmetic::<PackedBinaryField2x64b>();
+		check_dispatcher_arithmetic::<PackedBinaryField1x128b>();
+		check_dispatcher_arithmetic::<PackedAESBinaryField16x8b>();
+		check_dispatcher_arithmetic::<PackedAESBinaryField8x16b>();
+		check_dispatcher_arithmetic::<PackedAESBinaryField4x32b>();
+		check_dispatcher_arithmetic::<PackedAESBinaryField2x64b>();
+		check_dispatcher_arithmetic::<PackedAESBinaryField1x128b>();
+		check_dispatcher_arithmetic::<PackedBinaryField2x128b>();
+	}
+
+	#[test]
+	fn test_dispatcher_level() {
+		assert_eq!(Dispatcher::get().level(), FeatureLevel::get());
+		assert_eq!(Dispatcher::at(FeatureLevel::Baseline).level(), FeatureLevel::Baseline);
+	}
+
+	fn check_transform_slice<P: PackedTransformationFactory<P>>() {
+		let scalar_transformation =
+			FieldLinearTransformation::<P::Scalar, _>::random(StdRng::seed_from_u64(1));
+		let mut values = random_values::<P>(16);
+		let expected = values
+			.iter()
+			.map(|value| P::from_fn(|i| scalar_transformation.transform(&value.get(i))))
+			.collect::<Vec<_>>();
+		transform_slice(&scalar_transformation, &mut values);
+		assert_eq!(values, expected);
+	}
+
+	#[test]
+	fn test_transform_slice() {
+		check_transform_slice::<PackedBinaryField16x8b>();
+		check_transform_slice::<PackedBinaryField8x16b>();
+		check_transform_slice::<PackedBinaryField1x128b>();
+		check_transform_slice::<PackedAESBinaryField4x32b>();
+		check_transform_slice::<PackedAESBinaryField2x64b>();
+		check_transform_slice::<PackedBinaryField2x128b>();
+	}
+}
@@ -4,6 +4,9 @@ use cfg_if::cfg_if;
 
 mod arch_optimal;
 mod binary_utils;
+pub mod dispatch;
+#[cfg(feature = "nightly_features")]
+pub mod portable_simd;
 mod strategies;
 
 cfg_if! {
@@ -9,6 +9,7 @@ use std::{
 
 use binius_utils::checked_arithmetics::checked_log_2;
 use bytemuck::{Pod, Zeroable};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 
 use super::{invert::invert_or_zero, multiply::mul, square::square};
 use crate::{
@@ -123,6 +124,24 @@ macro_rules! define_byte_sliced_3d {
 			}
 		}
 
+		impl ConstantTimeEq for $name {
+			fn ct_eq(&self, other: &Self) -> Choice {
+				self.data.as_flattened().ct_eq(other.data.as_flattened())
+			}
+		}
+
+		impl ConditionallySelectable for $name {
+			fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+				Self {
+					data: array::from_fn(|i| {
+						array::from_fn(|j| {
+							ConditionallySelectable::conditional_select(&a.data[i][j], &b.data[i][j], choice)
+						})
+					}),
+				}
+			}
+		}
+
 		impl PackedField for $name {
 			type Scalar = $scalar_type;
 
@@ -708,6 +727,22 @@ macro_rules! define_byte_sliced_3d_1b {
 			}
 		}
 
+		impl ConstantTimeEq for $name {
+			fn ct_eq(&self, other: &Self) -> Choice {
+				self.data.ct_eq(&other.data)
+			}
+		}
+
+		impl ConditionallySelectable for $name {
+			fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+				Self {
+					data: array::from_fn(|i| {
+						ConditionallySelectable::conditional_select(&a.data[i], &b.data[i], choice)
+					}),
+				}
+			}
+		}
+
 		impl PackedField for $name {
 			type Scalar = BinaryField1b;
 
@@ -3,7 +3,7 @@
 use binius_utils::checked_arithmetics::checked_log_2;
 use bytemuck::{Pod, Zeroable};
 use rand::RngCore;
-use subtle::{Choice, ConstantTimeEq};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 
 use crate::underlier::{Random, ScaledUnderlier, UnderlierType};
 
@@ -26,6 +26,14 @@ impl<U: ConstantTimeEq, const N: usize> ConstantTimeEq for ByteSlicedUnderlier<U
 	}
 }
 
+impl<U: ConditionallySelectable, const N: usize> ConditionallySelectable
+	for ByteSlicedUnderlier<U, N>
+{
+	fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+		Self(ScaledUnderlier::conditional_select(&a.0, &b.0, choice))
+	}
+}
+
 unsafe impl<U: Zeroable, const N: usize> Zeroable for ByteSlicedUnderlier<U, N> {}
 
 unsafe impl<U: Pod, const N: usize> Pod for ByteSlicedUnderlier<U, N> {}
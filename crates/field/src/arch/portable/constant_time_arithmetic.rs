@@ -0,0 +1,119 @@
+// Copyright 2025 Irreducible Inc.
+
+use super::{
+	packed::PackedPrimitiveType,
+	packed_arithmetic::{
+		PackedTowerField, SubfieldOps, TowerConstants, UnderlierWithBitConstants,
+		invert_or_zero_packed_tower, mul_alpha_packed_tower, mul_packed_tower, square_packed_tower,
+	},
+};
+use crate::{
+	BinaryField1b,
+	arch::ConstantTimeStrategy,
+	arithmetic_traits::{TaggedInvertOrZero, TaggedMul, TaggedMulAlpha, TaggedSquare},
+	underlier::UnderlierWithBitOps,
+};
+
+// The 1-bit field is the base of the recursion: multiplication is a bitwise AND, and the other
+// operations are the identity.
+
+impl<U: UnderlierWithBitOps> TaggedMul<ConstantTimeStrategy>
+	for PackedPrimitiveType<U, BinaryField1b>
+{
+	#[inline]
+	fn mul(self, rhs: Self) -> Self {
+		Self::from_underlier(self.to_underlier() & rhs.to_underlier())
+	}
+}
+
+impl<U: UnderlierWithBitOps> TaggedMulAlpha<ConstantTimeStrategy>
+	for PackedPrimitiveType<U, BinaryField1b>
+{
+	#[inline]
+	fn mul_alpha(self) -> Self {
+		self
+	}
+}
+
+impl<U: UnderlierWithBitOps> TaggedSquare<ConstantTimeStrategy>
+	for PackedPrimitiveType<U, BinaryField1b>
+{
+	#[inline]
+	fn square(self) -> Self {
+		self
+	}
+}
+
+impl<U: UnderlierWithBitOps> TaggedInvertOrZero<ConstantTimeStrategy>
+	for PackedPrimitiveType<U, BinaryField1b>
+{
+	#[inline]
+	fn invert_or_zero(self) -> Self {
+		self
+	}
+}
+
+impl<PT> TaggedMul<ConstantTimeStrategy> for PT
+where
+	PT: PackedTowerField,
+	PT::Underlier: UnderlierWithBitConstants,
+	PT::DirectSubfield: TowerConstants<PT::Underlier>,
+	PT::PackedDirectSubfield: TaggedMul<ConstantTimeStrategy>,
+{
+	#[inline]
+	fn mul(self, rhs: Self) -> Self {
+		mul_packed_tower(self, rhs, TaggedMul::<ConstantTimeStrategy>::mul)
+	}
+}
+
+impl<PT> TaggedMulAlpha<ConstantTimeStrategy> for PT
+where
+	PT: PackedTowerField,
+	PT::Underlier: UnderlierWithBitConstants,
+	PT::PackedDirectSubfield: TaggedMulAlpha<ConstantTimeStrategy>,
+{
+	#[inline]
+	fn mul_alpha(self) -> Self {
+		mul_alpha_packed_tower(self, TaggedMulAlpha::<ConstantTimeStrategy>::mul_alpha)
+	}
+}
+
+impl<PT> TaggedSquare<ConstantTimeStrategy> for PT
+where
+	PT: PackedTowerField,
+	PT::Underlier: UnderlierWithBitConstants,
+	PT::PackedDirectSubfield:
+		TaggedSquare<ConstantTimeStrategy> + TaggedMulAlpha<ConstantTimeStrategy>,
+{
+	#[inline]
+	fn square(self) -> Self {
+		square_packed_tower(
+			self,
+			TaggedSquare::<ConstantTimeStrategy>::square,
+			TaggedMulAlpha::<ConstantTimeStrategy>::mul_alpha,
+		)
+	}
+}
+
+impl<PT> TaggedInvertOrZero<ConstantTimeStrategy> for PT
+where
+	PT: PackedTowerField,
+	PT::Underlier: UnderlierWithBitConstants,
+	PT::PackedDirectSubfield: TaggedMul<ConstantTimeStrategy>
+		+ TaggedSquare<ConstantTimeStrategy>
+		+ TaggedMulAlpha<ConstantTimeStrategy>
+		+ TaggedInvertOrZero<ConstantTimeStrategy>,
+{
+	#[inline]
+	fn invert_or_zero(self) -> Self {
+		invert_or_zero_packed_tower(
+			self,
+			SubfieldOps {
+				mul: TaggedMul::<ConstantTimeStrategy>::mul,
+				square: TaggedSquare::<ConstantTimeStrategy>::square,
+				mul_alpha: TaggedMulAlpha::<ConstantTimeStrategy>::mul_alpha,
+				invert_or_zero: TaggedInvertOrZero::<ConstantTimeStrategy>::invert_or_zero,
+			},
+		)
+	}
+}
@@ -30,6 +30,7 @@ pub mod byte_sliced;
 
 pub(super) mod packed_scaled;
 
+pub(super) mod constant_time_arithmetic;
 pub(super) mod hybrid_recursive_arithmetics;
 pub(super) mod packed_arithmetic;
 pub(super) mod pairwise_arithmetic;
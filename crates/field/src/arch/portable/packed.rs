@@ -16,7 +16,7 @@ use std::{
 use binius_utils::{checked_arithmetics::checked_int_div, iter::IterExtensions};
 use bytemuck::{Pod, TransparentWrapper, Zeroable};
 use rand::RngCore;
-use subtle::{Choice, ConstantTimeEq};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 
 use super::packed_arithmetic::UnderlierWithBitConstants;
 use crate::{
@@ -147,6 +147,16 @@ impl<U: UnderlierType, Scalar: BinaryField> ConstantTimeEq for PackedPrimitiveTy
 	}
 }
 
+impl<U, Scalar> ConditionallySelectable for PackedPrimitiveType<U, Scalar>
+where
+	U: UnderlierType + ConditionallySelectable,
+	Scalar: BinaryField,
+{
+	fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+		U::conditional_select(&a.0, &b.0, choice).into()
+	}
+}
+
 impl<U: UnderlierWithBitOps, Scalar: BinaryField> Add for PackedPrimitiveType<U, Scalar> {
 	type Output = Self;
 
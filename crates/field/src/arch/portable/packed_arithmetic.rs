@@ -113,61 +113,78 @@ where
 	/// Optimized packed field multiplication algorithm
 	#[inline]
 	fn mul(self, b: Self) -> Self {
-		let a = self;
-
-		// a and b can be interpreted as packed subfield elements:
-		// a = <a_lo_0, a_hi_0, a_lo_1, a_hi_1, ...>
-		// b = <b_lo_0, b_hi_0, b_lo_1, b_hi_1, ...>//
-		// ab is the product of a * b as packed subfield elements
-		// ab = <a_lo_0 * b_lo_0, a_hi_0 * b_hi_0, a_lo_1 * b_lo_1, a_hi_1 * b_hi_1, ...>
-		let repacked_a = a.as_packed_subfield();
-		let repacked_b = b.as_packed_subfield();
-		let z0_even_z2_odd = repacked_a * repacked_b;
-
-		// lo = <a_lo_0, b_lo_0, a_lo_1, b_lo_1, ...>
-		// hi = <a_hi_0, b_hi_0, a_hi_1, b_hi_1, ...>
-		let (lo, hi) =
-			interleave::<PT::Underlier, PT::DirectSubfield>(a.to_underlier(), b.to_underlier());
-
-		// <a_lo_0 + a_hi_0, b_lo_0 + b_hi_0, a_lo_1 + a_hi_1, b_lo_1 + b_hi_1, ...>
-		let lo_plus_hi_a_even_b_odd = lo ^ hi;
-
-		let odd_mask = <PT::Underlier as UnderlierWithBitConstants>::INTERLEAVE_ODD_MASK
-			[PT::DirectSubfield::TOWER_LEVEL];
-
-		let alphas = PT::DirectSubfield::ALPHAS_ODD;
-
-		// <α, z2_0, α, z2_1, ...>
-		let alpha_even_z2_odd = alphas ^ (z0_even_z2_odd.to_underlier() & odd_mask);
-
-		// a_lo_plus_hi_even_z2_odd    = <a_lo_0 + a_hi_0, z2_0, a_lo_1 + a_hi_1, z2_1, ...>
-		// b_lo_plus_hi_even_alpha_odd = <b_lo_0 + b_hi_0,    α, a_lo_1 + a_hi_1,   αz, ...>
-		let (a_lo_plus_hi_even_alpha_odd, b_lo_plus_hi_even_z2_odd) =
-			interleave::<PT::Underlier, PT::DirectSubfield>(
-				lo_plus_hi_a_even_b_odd,
-				alpha_even_z2_odd,
-			);
-
-		// <z1_0 + z0_0 + z2_0, z2a_0, z1_1 + z0_1 + z2_1, z2a_1, ...>
-		let z1_plus_z0_plus_z2_even_z2a_odd =
-			PT::PackedDirectSubfield::from_underlier(a_lo_plus_hi_even_alpha_odd)
-				* PT::PackedDirectSubfield::from_underlier(b_lo_plus_hi_even_z2_odd);
-
-		// <0, z1_0 + z2a_0 + z0_0 + z2_0, 0, z1_1 + z2a_1 + z0_1 + z2_1, ...>
-		let zero_even_z1_plus_z2a_plus_z0_plus_z2_odd = (z1_plus_z0_plus_z2_even_z2a_odd
-			.to_underlier()
-			^ (z1_plus_z0_plus_z2_even_z2a_odd.to_underlier() << PT::DirectSubfield::N_BITS))
-			& odd_mask;
-
-		// <z0_0 + z2_0, z0_0 + z2_0, z0_1 + z2_1, z0_1 + z2_1, ...>
-		let z0_plus_z2_dup =
-			xor_adjacent::<PT::Underlier, PT::DirectSubfield>(z0_even_z2_odd.to_underlier());
-
-		// <z0_0 + z2_0, z1_0 + z2a_0, z0_1 + z2_1, z1_1 + z2a_1, ...>
-		Self::from_underlier(z0_plus_z2_dup ^ zero_even_z1_plus_z2a_plus_z0_plus_z2_odd)
+		mul_packed_tower(self, b, |a, b| a * b)
 	}
 }
 
+/// Multiplies packed tower field elements by a Karatsuba step over the direct subfield.
+///
+/// `mul_subfield` multiplies the packed direct subfield elements, which allows the callers to
+/// choose the strategy of the recursion.
+#[inline]
+pub(super) fn mul_packed_tower<PT>(
+	a: PT,
+	b: PT,
+	mul_subfield: impl Fn(
+		PT::PackedDirectSubfield,
+		PT::PackedDirectSubfield,
+	) -> PT::PackedDirectSubfield,
+) -> PT
+where
+	PT: PackedTowerField,
+	PT::Underlier: UnderlierWithBitConstants,
+	PT::DirectSubfield: TowerConstants<PT::Underlier>,
+{
+	// a and b can be interpreted as packed subfield elements:
+	// a = <a_lo_0, a_hi_0, a_lo_1, a_hi_1, ...>
+	// b = <b_lo_0, b_hi_0, b_lo_1, b_hi_1, ...>//
+	// ab is the product of a * b as packed subfield elements
+	// ab = <a_lo_0 * b_lo_0, a_hi_0 * b_hi_0, a_lo_1 * b_lo_1, a_hi_1 * b_hi_1, ...>
+	let repacked_a = a.as_packed_subfield();
+	let repacked_b = b.as_packed_subfield();
+	let z0_even_z2_odd = mul_subfield(repacked_a, repacked_b);
+
+	// lo = <a_lo_0, b_lo_0, a_lo_1, b_lo_1, ...>
+	// hi = <a_hi_0, b_hi_0, a_hi_1, b_hi_1, ...>
+	let (lo, hi) =
+		interleave::<PT::Underlier, PT::DirectSubfield>(a.to_underlier(), b.to_underlier());
+
+	// <a_lo_0 + a_hi_0, b_lo_0 + b_hi_0, a_lo_1 + a_hi_1, b_lo_1 + b_hi_1, ...>
+	let lo_plus_hi_a_even_b_odd = lo ^ hi;
+
+	let odd_mask = <PT::Underlier as UnderlierWithBitConstants>::INTERLEAVE_ODD_MASK
+		[PT::DirectSubfield::TOWER_LEVEL];
+
+	let alphas = PT::DirectSubfield::ALPHAS_ODD;
+
+	// <α, z2_0, α, z2_1, ...>
+	let alpha_even_z2_odd = alphas ^ (z0_even_z2_odd.to_underlier() & odd_mask);
+
+	// a_lo_plus_hi_even_z2_odd    = <a_lo_0 + a_hi_0, z2_0, a_lo_1 + a_hi_1, z2_1, ...>
+	// b_lo_plus_hi_even_alpha_odd = <b_lo_0 + b_hi_0,    α, a_lo_1 + a_hi_1,   αz, ...>
+	let (a_lo_plus_hi_even_alpha_odd, b_lo_plus_hi_even_z2_odd) =
+		interleave::<PT::Underlier, PT::DirectSubfield>(lo_plus_hi_a_even_b_odd, alpha_even_z2_odd);
+
+	// <z1_0 + z0_0 + z2_0, z2a_0, z1_1 + z0_1 + z2_1, z2a_1, ...>
+	let z1_plus_z0_plus_z2_even_z2a_odd = mul_subfield(
+		PT::PackedDirectSubfield::from_underlier(a_lo_plus_hi_even_alpha_odd),
+		PT::PackedDirectSubfield::from_underlier(b_lo_plus_hi_even_z2_odd),
+	);
+
+	// <0, z1_0 + z2a_0 + z0_0 + z2_0, 0, z1_1 + z2a_1 + z0_1 + z2_1, ...>
+	let zero_even_z1_plus_z2a_plus_z0_plus_z2_odd = (z1_plus_z0_plus_z2_even_z2a_odd
+		.to_underlier()
+		^ (z1_plus_z0_plus_z2_even_z2a_odd.to_underlier() << PT::DirectSubfield::N_BITS))
+		& odd_mask;
+
+	// <z0_0 + z2_0, z0_0 + z2_0, z0_1 + z2_1, z0_1 + z2_1, ...>
+	let z0_plus_z2_dup =
+		xor_adjacent::<PT::Underlier, PT::DirectSubfield>(z0_even_z2_odd.to_underlier());
+
+	// <z0_0 + z2_0, z1_0 + z2a_0, z0_1 + z2_1, z1_1 + z2a_1, ...>
+	PT::from_underlier(z0_plus_z2_dup ^ zero_even_z1_plus_z2a_plus_z0_plus_z2_odd)
+}
+
 /// Generate the mask with alphas in the odd packed element positions and zeros in even
 macro_rules! alphas {
 	($underlier:ty, $tower_level:literal) => {{
@@ -251,23 +268,35 @@ where
 {
 	#[inline]
 	fn mul_alpha(self) -> Self {
-		let block_len = PT::DirectSubfield::N_BITS;
-		let even_mask = <PT::Underlier as UnderlierWithBitConstants>::INTERLEAVE_EVEN_MASK
-			[PT::DirectSubfield::TOWER_LEVEL];
-		let odd_mask = <PT::Underlier as UnderlierWithBitConstants>::INTERLEAVE_ODD_MASK
-			[PT::DirectSubfield::TOWER_LEVEL];
-
-		let a = self.to_underlier();
-		let a0 = a & even_mask;
-		let a1 = a & odd_mask;
-		let z1 = PT::PackedDirectSubfield::from_underlier(a1)
-			.mul_alpha()
-			.to_underlier();
-
-		Self::from_underlier((a1 >> block_len) | ((a0 << block_len) ^ z1))
+		mul_alpha_packed_tower(self, MulAlpha::mul_alpha)
 	}
 }
 
+/// Multiplies packed tower field elements by the tower generator, given the multiplication of the
+/// packed direct subfield elements by its generator.
+#[inline]
+pub(super) fn mul_alpha_packed_tower<PT>(
+	value: PT,
+	mul_alpha_subfield: impl Fn(PT::PackedDirectSubfield) -> PT::PackedDirectSubfield,
+) -> PT
+where
+	PT: PackedTowerField,
+	PT::Underlier: UnderlierWithBitConstants,
+{
+	let block_len = PT::DirectSubfield::N_BITS;
+	let even_mask = <PT::Underlier as UnderlierWithBitConstants>::INTERLEAVE_EVEN_MASK
+		[PT::DirectSubfield::TOWER_LEVEL];
+	let odd_mask = <PT::Underlier as UnderlierWithBitConstants>::INTERLEAVE_ODD_MASK
+		[PT::DirectSubfield::TOWER_LEVEL];
+
+	let a = value.to_underlier();
+	let a0 = a & even_mask;
+	let a1 = a & odd_mask;
+	let z1 = mul_alpha_subfield(PT::PackedDirectSubfield::from_underlier(a1)).to_underlier();
+
+	PT::from_underlier((a1 >> block_len) | ((a0 << block_len) ^ z1))
+}
+
 impl<PT> TaggedSquare<PackedStrategy> for PT
 where
 	PT: PackedTowerField,
@@ -276,19 +305,34 @@ where
 {
 	#[inline]
 	fn square(self) -> Self {
-		let block_len = PT::DirectSubfield::N_BITS;
-		let even_mask = <PT::Underlier as UnderlierWithBitConstants>::INTERLEAVE_EVEN_MASK
-			[PT::DirectSubfield::TOWER_LEVEL];
-		let odd_mask = <PT::Underlier as UnderlierWithBitConstants>::INTERLEAVE_ODD_MASK
-			[PT::DirectSubfield::TOWER_LEVEL];
+		square_packed_tower(self, PackedField::square, MulAlpha::mul_alpha)
+	}
+}
 
-		let z_02 = PackedField::square(self.as_packed_subfield());
-		let z_2a = z_02.mul_alpha().to_underlier() & odd_mask;
+/// Squares packed tower field elements, given the squaring and the multiplication by the
+/// generator of the packed direct subfield elements.
+#[inline]
+pub(super) fn square_packed_tower<PT>(
+	value: PT,
+	square_subfield: impl Fn(PT::PackedDirectSubfield) -> PT::PackedDirectSubfield,
+	mul_alpha_subfield: impl Fn(PT::PackedDirectSubfield) -> PT::PackedDirectSubfield,
+) -> PT
+where
+	PT: PackedTowerField,
+	PT::Underlier: UnderlierWithBitConstants,
+{
+	let block_len = PT::DirectSubfield::N_BITS;
+	let even_mask = <PT::Underlier as UnderlierWithBitConstants>::INTERLEAVE_EVEN_MASK
+		[PT::DirectSubfield::TOWER_LEVEL];
+	let odd_mask = <PT::Underlier as UnderlierWithBitConstants>::INTERLEAVE_ODD_MASK
+		[PT::DirectSubfield::TOWER_LEVEL];
 
-		let z_0_xor_z_2 = (z_02.to_underlier() ^ (z_02.to_underlier() >> block_len)) & even_mask;
+	let z_02 = square_subfield(value.as_packed_subfield());
+	let z_2a = mul_alpha_subfield(z_02).to_underlier() & odd_mask;
 
-		Self::from_underlier(z_0_xor_z_2 | z_2a)
-	}
+	let z_0_xor_z_2 = (z_02.to_underlier() ^ (z_02.to_underlier() >> block_len)) & even_mask;
+
+	PT::from_underlier(z_0_xor_z_2 | z_2a)
 }
 
 impl<PT> TaggedInvertOrZero<PackedStrategy> for PT
@@ -299,31 +343,66 @@ where
 {
 	#[inline]
 	fn invert_or_zero(self) -> Self {
-		let block_len = PT::DirectSubfield::N_BITS;
-		let even_mask = <PT::Underlier as UnderlierWithBitConstants>::INTERLEAVE_EVEN_MASK
-			[PT::DirectSubfield::TOWER_LEVEL];
-		let odd_mask = <PT::Underlier as UnderlierWithBitConstants>::INTERLEAVE_ODD_MASK
-			[PT::DirectSubfield::TOWER_LEVEL];
-
-		// has meaningful values in even positions
-		let a_1_even = PT::PackedDirectSubfield::from_underlier(self.to_underlier() >> block_len);
-		let intermediate = self.as_packed_subfield() + a_1_even.mul_alpha();
-		let delta = self.as_packed_subfield() * intermediate
-			+ <PT::PackedDirectSubfield as PackedField>::square(a_1_even);
-		let delta_inv = PackedField::invert_or_zero(delta);
-
-		// set values from even positions to odd as well
-		let mut delta_inv_delta_inv = delta_inv.to_underlier() & even_mask;
-		delta_inv_delta_inv |= delta_inv_delta_inv << block_len;
-
-		let intermediate_a1 =
-			(self.to_underlier() & odd_mask) | (intermediate.to_underlier() & even_mask);
-		let result = PT::PackedDirectSubfield::from_underlier(delta_inv_delta_inv)
-			* PT::PackedDirectSubfield::from_underlier(intermediate_a1);
-		Self::from_underlier(result.to_underlier())
+		invert_or_zero_packed_tower(
+			self,
+			SubfieldOps {
+				mul: |a, b| a * b,
+				square: PackedField::square,
+				mul_alpha: MulAlpha::mul_alpha,
+				invert_or_zero: PackedField::invert_or_zero,
+			},
+		)
 	}
 }
 
+/// The operations on packed direct subfield elements used by the recursive tower algorithms.
+pub(super) struct SubfieldOps<Mul, Square, MulAlpha, Invert> {
+	pub mul: Mul,
+	pub square: Square,
+	pub mul_alpha: MulAlpha,
+	pub invert_or_zero: Invert,
+}
+
+/// Inverts packed tower field elements, mapping zeros to zero, given the operations on the packed
+/// direct subfield elements.
+#[inline]
+pub(super) fn invert_or_zero_packed_tower<PT, Mul, Square, MulAlpha, Invert>(
+	value: PT,
+	ops: SubfieldOps<Mul, Square, MulAlpha, Invert>,
+) -> PT
+where
+	PT: PackedTowerField,
+	PT::Underlier: UnderlierWithBitConstants,
+	Mul: Fn(PT::PackedDirectSubfield, PT::PackedDirectSubfield) -> PT::PackedDirectSubfield,
+	Square: Fn(PT::PackedDirectSubfield) -> PT::PackedDirectSubfield,
+	MulAlpha: Fn(PT::PackedDirectSubfield) -> PT::PackedDirectSubfield,
+	Invert: Fn(PT::PackedDirectSubfield) -> PT::PackedDirectSubfield,
+{
+	let block_len = PT::DirectSubfield::N_BITS;
+	let even_mask = <PT::Underlier as UnderlierWithBitConstants>::INTERLEAVE_EVEN_MASK
+		[PT::DirectSubfield::TOWER_LEVEL];
+	let odd_mask = <PT::Underlier as UnderlierWithBitConstants>::INTERLEAVE_ODD_MASK
+		[PT::DirectSubfield::TOWER_LEVEL];
+
+	// has meaningful values in even positions
+	let a_1_even = PT::PackedDirectSubfield::from_underlier(value.to_underlier() >> block_len);
+	let intermediate = value.as_packed_subfield() + (ops.mul_alpha)(a_1_even);
+	let delta = (ops.mul)(value.as_packed_subfield(), intermediate) + (ops.square)(a_1_even);
+	let delta_inv = (ops.invert_or_zero)(delta);
+
+	// set values from even positions to odd as well
+	let mut delta_inv_delta_inv = delta_inv.to_underlier() & even_mask;
+	delta_inv_delta_inv |= delta_inv_delta_inv << block_len;
+
+	let intermediate_a1 =
+		(value.to_underlier() & odd_mask) | (intermediate.to_underlier() & even_mask);
+	let result = (ops.mul)(
+		PT::PackedDirectSubfield::from_underlier(delta_inv_delta_inv),
+		PT::PackedDirectSubfield::from_underlier(intermediate_a1),
+	);
+	PT::from_underlier(result.to_underlier())
+}
+
 /// Packed transformation implementation.
 /// Stores bases in a form of:
 /// [
@@ -13,11 +13,12 @@ use binius_utils::{
 };
 use bytemuck::{Pod, TransparentWrapper, Zeroable};
 use rand::RngCore;
-use subtle::ConstantTimeEq;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 
 use crate::{
 	Field, PackedField,
-	arithmetic_traits::MulAlpha,
+	arch::ConstantTimeStrategy,
+	arithmetic_traits::{MulAlpha, TaggedInvertOrZero, TaggedMul, TaggedMulAlpha, TaggedSquare},
 	as_packed_field::PackScalar,
 	linear_transformation::{
 		FieldLinearTransformation, PackedTransformationFactory, Transformation,
@@ -156,6 +157,14 @@ impl<PT: ConstantTimeEq, const N: usize> ConstantTimeEq for ScaledPackedField<PT
 	}
 }
 
+impl<PT: ConditionallySelectable, const N: usize> ConditionallySelectable
+	for ScaledPackedField<PT, N>
+{
+	fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+		Self::from_direct_packed_fn(|i| PT::conditional_select(&a.0[i], &b.0[i], choice))
+	}
+}
+
 impl<PT: Copy + Add<Output = PT>, const N: usize> Add for ScaledPackedField<PT, N>
 where
 	Self: Default,
@@ -402,6 +411,48 @@ where
 	}
 }
 
+impl<PT: Copy + TaggedMul<ConstantTimeStrategy>, const N: usize> TaggedMul<ConstantTimeStrategy>
+	for ScaledPackedField<PT, N>
+{
+	#[inline]
+	fn mul(self, rhs: Self) -> Self {
+		Self::from_direct_packed_fn(|i| TaggedMul::<ConstantTimeStrategy>::mul(self.0[i], rhs.0[i]))
+	}
+}
+
+impl<PT: TaggedMulAlpha<ConstantTimeStrategy>, const N: usize> TaggedMulAlpha<ConstantTimeStrategy>
+	for ScaledPackedField<PT, N>
+{
+	#[inline]
+	fn mul_alpha(self) -> Self {
+		Self(
+			self.0
+				.map(TaggedMulAlpha::<ConstantTimeStrategy>::mul_alpha),
+		)
+	}
+}
+
+impl<PT: TaggedSquare<ConstantTimeStrategy>, const N: usize> TaggedSquare<ConstantTimeStrategy>
+	for ScaledPackedField<PT, N>
+{
+	#[inline]
+	fn square(self) -> Self {
+		Self(self.0.map(TaggedSquare::<ConstantTimeStrategy>::square))
+	}
+}
+
+impl<PT: TaggedInvertOrZero<ConstantTimeStrategy>, const N: usize>
+	TaggedInvertOrZero<ConstantTimeStrategy> for ScaledPackedField<PT, N>
+{
+	#[inline]
+	fn invert_or_zero(self) -> Self {
+		Self(
+			self.0
+				.map(TaggedInvertOrZero::<ConstantTimeStrategy>::invert_or_zero),
+		)
+	}
+}
+
 /// Per-element transformation as a scaled packed field.
 pub struct ScaledTransformation<I> {
 	inner: I,
@@ -0,0 +1,222 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Packed binary fields over [`std::simd`] vectors.
+//!
+//! The underliers [`SimdU128`], [`SimdU256`] and [`SimdU512`] wrap vectors of `u64` lanes, and
+//! the tower arithmetic is implemented with the same portable strategies as the `u128` backend.
+//! The backend relies on the compiler to lower the lane operations to the vector instructions of
+//! the target, so it works on any architecture supported by `core::simd`, at the cost of being
+//! slower than the hand-written `x86_64` and `aarch64` backends where those are available.
+//!
+//! The backend is not used by [`crate::arch::OptimalUnderlier`]. Packed fields can be used
+//! directly through the type aliases of the submodules or through
+//! [`crate::as_packed_field::PackedType`]. POLYVAL packed fields are not provided.
+
+mod packed_macros;
+mod underlier;
+
+pub mod packed_128;
+pub mod packed_256;
+pub mod packed_512;
+pub mod packed_aes_128;
+pub mod packed_aes_256;
+pub mod packed_aes_512;
+
+pub use underlier::{SimdU128, SimdU256, SimdU512};
+
+#[cfg(test)]
+mod tests {
+	use binius_utils::{DeserializeBytes, SerializationMode, SerializeBytes, bytes::BytesMut};
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use crate::{
+		PackedField,
+		linear_transformation::{
+			FieldLinearTransformation, PackedTransformationFactory, Transformation,
+		},
+		packed::PackedBinaryField,
+	};
+
+	/// Checks that the arithmetic of `P` matches the one of the portable packed field `R` of the
+	/// same width.
+	fn check_equivalent<P, R>()
+	where
+		P: PackedBinaryField + PackedTransformationFactory<P>,
+		R: PackedField<Scalar = P::Scalar>,
+	{
+		assert_eq!(P::LOG_WIDTH, R::LOG_WIDTH);
+
+		let convert = |value: P| R::from_scalars(value.iter());
+		let mut rng = StdRng::seed_from_u64(0);
+		for _ in 0..16 {
+			let (a, b) = (P::random(&mut rng), P::random(&mut rng));
+			let (a_ref, b_ref) = (convert(a), convert(b));
+
+			assert_eq!(convert(a * b), a_ref * b_ref);
+			assert_eq!(convert(a.square()), a_ref.square());
+			assert_eq!(convert(a.invert_or_zero()), a_ref.invert_or_zero());
+			assert_eq!(convert(a + b), a_ref + b_ref);
+			assert_eq!(convert(P::broadcast(b.get(0))), R::broadcast(b.get(0)));
+
+			for log_block_len in 0..P::LOG_WIDTH {
+				let (c, d) = a.interleave(b, log_block_len);
+				let (c_ref, d_ref) = a_ref.interleave(b_ref, log_block_len);
+				assert_eq!((convert(c), convert(d)), (c_ref, d_ref));
+
+				let (c, d) = a.unzip(b, log_block_len);
+				let (c_ref, d_ref) = a_ref.unzip(b_ref, log_block_len);
+				assert_eq!((convert(c), convert(d)), (c_ref, d_ref));
+			}
+		}
+
+		let scalar_transformation = FieldLinearTransformation::<P::Scalar, _>::random(&mut rng);
+		let transformation = P::make_packed_transformation(scalar_transformation.clone());
+		let value = P::random(&mut rng);
+		assert_eq!(
+			transformation.transform(&value),
+			P::from_fn(|i| scalar_transformation.transform(&value.get(i)))
+		);
+	}
+
+	/// Checks that `P` serializes to the same bytes as the portable packed field `R`.
+	///
+	/// Only packed fields of canonical tower fields support serialization.
+	fn check_serialization_equivalent<P, R>()
+	where
+		P: PackedField + SerializeBytes + DeserializeBytes,
+		R: PackedField<Scalar = P::Scalar> + SerializeBytes,
+	{
+		let mut rng = StdRng::seed_from_u64(0);
+		let value = P::random(&mut rng);
+		let (mut buffer, mut buffer_ref) = (BytesMut::new(), BytesMut::new());
+		value
+			.serialize(&mut buffer, SerializationMode::Native)
+			.unwrap();
+		R::from_scalars(value.iter())
+			.serialize(&mut buffer_ref, SerializationMode::Native)
+			.unwrap();
+		assert_eq!(buffer, buffer_ref);
+		assert_eq!(P::deserialize(buffer.freeze(), SerializationMode::Native).unwrap(), value);
+	}
+
+	macro_rules! define_equivalence_tests {
+		($module:ident, $reference:ident, [$($name:ident),* $(,)?]) => {
+			mod $module {
+				use super::*;
+
+				define_equivalence_tests!(@tests $module, $reference, check_equivalent, [$($name),*]);
+			}
+		};
+		($module:ident, $reference:ident, serialization, [$($name:ident),* $(,)?]) => {
+			mod $module {
+				use super::*;
+
+				define_equivalence_tests!(@tests $module, $reference, check_equivalent, [$($name),*]);
+
+				mod serialization {
+					use super::*;
+
+					define_equivalence_tests!(
+						@tests $module, $reference, check_serialization_equivalent, [$($name),*]
+					);
+				}
+			}
+		};
+		(@tests $module:ident, $reference:ident, $check:ident, [$($name:ident),*]) => {
+			$(
+				#[test]
+				#[allow(non_snake_case)]
+				fn $name() {
+					$check::<
+						crate::arch::portable_simd::$module::$name,
+						crate::arch::portable::$reference::$name,
+					>();
+				}
+			)*
+		};
+	}
+
+	define_equivalence_tests!(
+		packed_128,
+		packed_128,
+		serialization,
+		[
+			PackedBinaryField128x1b,
+			PackedBinaryField64x2b,
+			PackedBinaryField32x4b,
+			PackedBinaryField16x8b,
+			PackedBinaryField8x16b,
+			PackedBinaryField4x32b,
+			PackedBinaryField2x64b,
+			PackedBinaryField1x128b,
+		]
+	);
+
+	define_equivalence_tests!(
+		packed_256,
+		packed_256,
+		serialization,
+		[
+			PackedBinaryField256x1b,
+			PackedBinaryField128x2b,
+			PackedBinaryField64x4b,
+			PackedBinaryField32x8b,
+			PackedBinaryField16x16b,
+			PackedBinaryField8x32b,
+			PackedBinaryField4x64b,
+			PackedBinaryField2x128b,
+		]
+	);
+
+	define_equivalence_tests!(
+		packed_512,
+		packed_512,
+		serialization,
+		[
+			PackedBinaryField512x1b,
+			PackedBinaryField256x2b,
+			PackedBinaryField128x4b,
+			PackedBinaryField64x8b,
+			PackedBinaryField32x16b,
+			PackedBinaryField16x32b,
+			PackedBinaryField8x64b,
+			PackedBinaryField4x128b,
+		]
+	);
+
+	define_equivalence_tests!(
+		packed_aes_128,
+		packed_aes_128,
+		[
+			PackedAESBinaryField16x8b,
+			PackedAESBinaryField8x16b,
+			PackedAESBinaryField4x32b,
+			PackedAESBinaryField2x64b,
+			PackedAESBinaryField1x128b,
+		]
+	);
+
+	define_equivalence_tests!(
+		packed_aes_256,
+		packed_aes_256,
+		[
+			PackedAESBinaryField32x8b,
+			PackedAESBinaryField16x16b,
+			PackedAESBinaryField8x32b,
+			PackedAESBinaryField4x64b,
+			PackedAESBinaryField2x128b,
+		]
+	);
+
+	define_equivalence_tests!(
+		packed_aes_512,
+		packed_aes_512,
+		[
+			PackedAESBinaryField64x8b,
+			PackedAESBinaryField32x16b,
+			PackedAESBinaryField16x32b,
+			PackedAESBinaryField8x64b,
+			PackedAESBinaryField4x128b,
+		]
+	);
+}
@@ -0,0 +1,615 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{
+	array,
+	ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, Shr},
+	simd::Simd,
+};
+
+use binius_utils::{
+	DeserializeBytes, SerializationError, SerializationMode, SerializeBytes,
+	bytes::{Buf, BufMut},
+	serialization::{assert_enough_data_for, assert_enough_space_for},
+};
+use bytemuck::{Pod, Zeroable};
+use rand::{Rng, RngCore};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+use crate::{
+	BinaryField,
+	arch::{
+		binary_utils::{as_array_mut, as_array_ref},
+		portable::{
+			packed::{PackedPrimitiveType, impl_pack_scalar},
+			packed_arithmetic::{
+				UnderlierWithBitConstants, interleave_mask_even, interleave_mask_odd,
+			},
+		},
+	},
+	arithmetic_traits::Broadcast,
+	underlier::{
+		NumCast, Random, SmallU, U1, U2, U4, UnderlierType, UnderlierWithBitOps, WithUnderlier,
+		impl_divisible, impl_iteration,
+	},
+};
+
+/// Shifts the lanes of `value` towards the higher indices by `shift` bits, as if it was a single
+/// little-endian integer.
+#[inline(always)]
+fn shl_lanes<const N: usize>(value: [u64; N], shift: usize) -> [u64; N] {
+	let (lane_shift, bit_shift) = (shift / 64, shift % 64);
+	array::from_fn(|i| {
+		let lane = |j: usize| {
+			j.checked_sub(lane_shift)
+				.filter(|&j| j < N)
+				.map_or(0, |j| value[j])
+		};
+		match bit_shift {
+			0 => lane(i),
+			_ => lane(i) << bit_shift | i.checked_sub(1).map_or(0, |i| lane(i) >> (64 - bit_shift)),
+		}
+	})
+}
+
+/// Shifts the lanes of `value` towards the lower indices by `shift` bits, as if it was a single
+/// little-endian integer.
+#[inline(always)]
+fn shr_lanes<const N: usize>(value: [u64; N], shift: usize) -> [u64; N] {
+	let (lane_shift, bit_shift) = (shift / 64, shift % 64);
+	let lane = |j: usize| value.get(j + lane_shift).copied().unwrap_or(0);
+	array::from_fn(|i| match bit_shift {
+		0 => lane(i),
+		_ => lane(i) >> bit_shift | lane(i + 1) << (64 - bit_shift),
+	})
+}
+
+/// Interleaves blocks of `1 << log_block_lanes` lanes, see
+/// [`UnderlierWithBitConstants::interleave`].
+#[inline(always)]
+fn interleave_lanes<const N: usize>(
+	a: [u64; N],
+	b: [u64; N],
+	log_block_lanes: usize,
+) -> ([u64; N], [u64; N]) {
+	let block_lanes = 1 << log_block_lanes;
+	let c = array::from_fn(|i| {
+		if i & block_lanes == 0 {
+			a[i]
+		} else {
+			b[i - block_lanes]
+		}
+	});
+	let d = array::from_fn(|i| {
+		if i & block_lanes == 0 {
+			a[i + block_lanes]
+		} else {
+			b[i]
+		}
+	});
+	(c, d)
+}
+
+macro_rules! define_simd_underlier {
+	($name:ident, $lanes:literal, $log_bits:literal, $bits:literal) => {
+		#[doc = concat!(
+			"A ", stringify!($bits), "-bit value backed by a portable SIMD vector of ",
+			stringify!($lanes), " `u64` lanes, in little-endian lane order."
+		)]
+		#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+		#[repr(transparent)]
+		pub struct $name(pub Simd<u64, $lanes>);
+
+		impl $name {
+			/// Creates a value with every 128-bit lane set to `val`.
+			pub const fn from_equal_u128s(val: u128) -> Self {
+				let mut lanes = [0u64; $lanes];
+				let mut i = 0;
+				while i < $lanes {
+					lanes[i] = if i % 2 == 0 { val as u64 } else { (val >> 64) as u64 };
+					i += 1;
+				}
+				Self(Simd::from_array(lanes))
+			}
+
+			#[inline(always)]
+			pub const fn to_array(self) -> [u64; $lanes] {
+				self.0.to_array()
+			}
+
+			#[inline(always)]
+			fn map_lanes(self, f: impl Fn([u64; $lanes]) -> [u64; $lanes]) -> Self {
+				Self(Simd::from_array(f(self.to_array())))
+			}
+		}
+
+		unsafe impl Zeroable for $name {}
+
+		unsafe impl Pod for $name {}
+
+		impl From<[u64; $lanes]> for $name {
+			#[inline(always)]
+			fn from(value: [u64; $lanes]) -> Self {
+				Self(Simd::from_array(value))
+			}
+		}
+
+		impl From<$name> for [u64; $lanes] {
+			#[inline(always)]
+			fn from(value: $name) -> Self {
+				value.to_array()
+			}
+		}
+
+		impl From<u128> for $name {
+			#[inline(always)]
+			fn from(value: u128) -> Self {
+				let mut lanes = [0u64; $lanes];
+				lanes[0] = value as u64;
+				lanes[1] = (value >> 64) as u64;
+				lanes.into()
+			}
+		}
+
+		impl From<u64> for $name {
+			#[inline(always)]
+			fn from(value: u64) -> Self {
+				let mut lanes = [0u64; $lanes];
+				lanes[0] = value;
+				lanes.into()
+			}
+		}
+
+		impl From<u32> for $name {
+			#[inline(always)]
+			fn from(value: u32) -> Self {
+				Self::from(value as u64)
+			}
+		}
+
+		impl From<u16> for $name {
+			#[inline(always)]
+			fn from(value: u16) -> Self {
+				Self::from(value as u64)
+			}
+		}
+
+		impl From<u8> for $name {
+			#[inline(always)]
+			fn from(value: u8) -> Self {
+				Self::from(value as u64)
+			}
+		}
+
+		impl<const N: usize> From<SmallU<N>> for $name {
+			#[inline(always)]
+			fn from(value: SmallU<N>) -> Self {
+				Self::from(value.val())
+			}
+		}
+
+		impl<U: NumCast<u128>> NumCast<$name> for U {
+			#[inline(always)]
+			fn num_cast_from(val: $name) -> Self {
+				let lanes = val.to_array();
+				Self::num_cast_from(lanes[0] as u128 | (lanes[1] as u128) << 64)
+			}
+		}
+
+		impl SerializeBytes for $name {
+			fn serialize(
+				&self,
+				mut write_buf: impl BufMut,
+				_mode: SerializationMode,
+			) -> Result<(), SerializationError> {
+				assert_enough_space_for(&write_buf, size_of::<Self>())?;
+
+				for lane in self.to_array() {
+					write_buf.put_u64_le(lane);
+				}
+
+				Ok(())
+			}
+		}
+
+		impl DeserializeBytes for $name {
+			fn deserialize(
+				mut read_buf: impl Buf,
+				_mode: SerializationMode,
+			) -> Result<Self, SerializationError>
+			where
+				Self: Sized,
+			{
+				assert_enough_data_for(&read_buf, size_of::<Self>())?;
+
+				Ok(array::from_fn::<_, $lanes, _>(|_| read_buf.get_u64_le()).into())
+			}
+		}
+
+		impl_divisible!(@pairs $name, u128, u64, u32, u16, u8);
+		impl_pack_scalar!($name);
+
+		impl BitAnd for $name {
+			type Output = Self;
+
+			#[inline(always)]
+			fn bitand(self, rhs: Self) -> Self::Output {
+				Self(self.0 & rhs.0)
+			}
+		}
+
+		impl BitAndAssign for $name {
+			#[inline(always)]
+			fn bitand_assign(&mut self, rhs: Self) {
+				self.0 &= rhs.0;
+			}
+		}
+
+		impl BitOr for $name {
+			type Output = Self;
+
+			#[inline(always)]
+			fn bitor(self, rhs: Self) -> Self::Output {
+				Self(self.0 | rhs.0)
+			}
+		}
+
+		impl BitOrAssign for $name {
+			#[inline(always)]
+			fn bitor_assign(&mut self, rhs: Self) {
+				self.0 |= rhs.0;
+			}
+		}
+
+		impl BitXor for $name {
+			type Output = Self;
+
+			#[inline(always)]
+			fn bitxor(self, rhs: Self) -> Self::Output {
+				Self(self.0 ^ rhs.0)
+			}
+		}
+
+		impl BitXorAssign for $name {
+			#[inline(always)]
+			fn bitxor_assign(&mut self, rhs: Self) {
+				self.0 ^= rhs.0;
+			}
+		}
+
+		impl Not for $name {
+			type Output = Self;
+
+			#[inline(always)]
+			fn not(self) -> Self::Output {
+				Self(!self.0)
+			}
+		}
+
+		impl Shr<usize> for $name {
+			type Output = Self;
+
+			#[inline(always)]
+			fn shr(self, rhs: usize) -> Self::Output {
+				if rhs >= $bits {
+					return Self::ZERO;
+				}
+				self.map_lanes(|lanes| shr_lanes(lanes, rhs))
+			}
+		}
+
+		impl Shl<usize> for $name {
+			type Output = Self;
+
+			#[inline(always)]
+			fn shl(self, rhs: usize) -> Self::Output {
+				if rhs >= $bits {
+					return Self::ZERO;
+				}
+				self.map_lanes(|lanes| shl_lanes(lanes, rhs))
+			}
+		}
+
+		impl ConstantTimeEq for $name {
+			#[inline(always)]
+			fn ct_eq(&self, other: &Self) -> Choice {
+				self.to_array()
+					.iter()
+					.zip(other.to_array().iter())
+					.fold(Choice::from(1), |acc, (a, b)| acc & a.ct_eq(b))
+			}
+		}
+
+		impl ConditionallySelectable for $name {
+			fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+				let (a, b) = (a.to_array(), b.to_array());
+				array::from_fn::<_, $lanes, _>(|i| u64::conditional_select(&a[i], &b[i], choice))
+					.into()
+			}
+		}
+
+		impl Random for $name {
+			fn random(mut rng: impl RngCore) -> Self {
+				rng.random::<[u64; $lanes]>().into()
+			}
+		}
+
+		impl std::fmt::Display for $name {
+			fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				write!(f, "{:016X?}", self.to_array())
+			}
+		}
+
+		impl std::fmt::Debug for $name {
+			fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				write!(f, "{}({self})", stringify!($name))
+			}
+		}
+
+		impl UnderlierType for $name {
+			const LOG_BITS: usize = $log_bits;
+		}
+
+		impl UnderlierWithBitOps for $name {
+			const ZERO: Self = Self(Simd::from_array([0; $lanes]));
+			const ONE: Self = {
+				let mut lanes = [0; $lanes];
+				lanes[0] = 1;
+				Self(Simd::from_array(lanes))
+			};
+			const ONES: Self = Self(Simd::from_array([u64::MAX; $lanes]));
+
+			#[inline(always)]
+			fn fill_with_bit(val: u8) -> Self {
+				Self(Simd::splat((val as u64).wrapping_neg()))
+			}
+
+			#[inline(always)]
+			unsafe fn get_subvalue<T>(&self, i: usize) -> T
+			where
+				T: UnderlierType + NumCast<Self>,
+			{
+				match T::BITS {
+					1 | 2 | 4 => {
+						let elements_in_8 = 8 / T::BITS;
+						let value = as_array_ref::<_, u8, { $lanes * 8 }, _>(self, |array| {
+							array[i / elements_in_8] >> ((i % elements_in_8) * T::BITS)
+						});
+						T::num_cast_from(Self::from(value))
+					}
+					8 => T::num_cast_from(Self::from(as_array_ref::<_, u8, { $lanes * 8 }, _>(
+						self,
+						|array| array[i],
+					))),
+					16 => T::num_cast_from(Self::from(as_array_ref::<_, u16, { $lanes * 4 }, _>(
+						self,
+						|array| array[i],
+					))),
+					32 => T::num_cast_from(Self::from(as_array_ref::<_, u32, { $lanes * 2 }, _>(
+						self,
+						|array| array[i],
+					))),
+					64 => T::num_cast_from(Self::from(self.to_array()[i])),
+					128 => T::num_cast_from(Self::from(as_array_ref::<_, u128, { $lanes / 2 }, _>(
+						self,
+						|array| array[i],
+					))),
+					_ => panic!("unsupported bit count"),
+				}
+			}
+
+			#[inline(always)]
+			unsafe fn set_subvalue<T>(&mut self, i: usize, val: T)
+			where
+				T: UnderlierWithBitOps,
+				Self: From<T>,
+			{
+				match T::BITS {
+					1 | 2 | 4 => {
+						let elements_in_8 = 8 / T::BITS;
+						let shift = (i % elements_in_8) * T::BITS;
+						let mask = ((1u8 << T::BITS) - 1) << shift;
+						let val = u8::num_cast_from(Self::from(val)) << shift;
+
+						as_array_mut::<_, u8, { $lanes * 8 }>(self, |array| {
+							let element = &mut array[i / elements_in_8];
+							*element &= !mask;
+							*element |= val;
+						});
+					}
+					8 => as_array_mut::<_, u8, { $lanes * 8 }>(self, |array| {
+						array[i] = u8::num_cast_from(Self::from(val));
+					}),
+					16 => as_array_mut::<_, u16, { $lanes * 4 }>(self, |array| {
+						array[i] = u16::num_cast_from(Self::from(val));
+					}),
+					32 => as_array_mut::<_, u32, { $lanes * 2 }>(self, |array| {
+						array[i] = u32::num_cast_from(Self::from(val));
+					}),
+					64 => as_array_mut::<_, u64, $lanes>(self, |array| {
+						array[i] = u64::num_cast_from(Self::from(val));
+					}),
+					128 => as_array_mut::<_, u128, { $lanes / 2 }>(self, |array| {
+						array[i] = u128::num_cast_from(Self::from(val));
+					}),
+					_ => panic!("unsupported bit count"),
+				}
+			}
+
+			#[inline(always)]
+			fn shl_128b_lanes(self, shift: usize) -> Self {
+				self.map_lanes(|lanes| {
+					let mut result = [0; $lanes];
+					for (result, lanes) in result.chunks_exact_mut(2).zip(lanes.chunks_exact(2)) {
+						let shifted = if shift >= 128 {
+							[0, 0]
+						} else {
+							shl_lanes([lanes[0], lanes[1]], shift)
+						};
+						result.copy_from_slice(&shifted);
+					}
+					result
+				})
+			}
+
+			#[inline(always)]
+			fn shr_128b_lanes(self, shift: usize) -> Self {
+				self.map_lanes(|lanes| {
+					let mut result = [0; $lanes];
+					for (result, lanes) in result.chunks_exact_mut(2).zip(lanes.chunks_exact(2)) {
+						let shifted = if shift >= 128 {
+							[0, 0]
+						} else {
+							shr_lanes([lanes[0], lanes[1]], shift)
+						};
+						result.copy_from_slice(&shifted);
+					}
+					result
+				})
+			}
+		}
+
+		impl UnderlierWithBitConstants for $name {
+			const INTERLEAVE_EVEN_MASK: &'static [Self] = &[
+				Self::from_equal_u128s(interleave_mask_even!(u128, 0)),
+				Self::from_equal_u128s(interleave_mask_even!(u128, 1)),
+				Self::from_equal_u128s(interleave_mask_even!(u128, 2)),
+				Self::from_equal_u128s(interleave_mask_even!(u128, 3)),
+				Self::from_equal_u128s(interleave_mask_even!(u128, 4)),
+				Self::from_equal_u128s(interleave_mask_even!(u128, 5)),
+				Self::from_equal_u128s(interleave_mask_even!(u128, 6)),
+			];
+
+			const INTERLEAVE_ODD_MASK: &'static [Self] = &[
+				Self::from_equal_u128s(interleave_mask_odd!(u128, 0)),
+				Self::from_equal_u128s(interleave_mask_odd!(u128, 1)),
+				Self::from_equal_u128s(interleave_mask_odd!(u128, 2)),
+				Self::from_equal_u128s(interleave_mask_odd!(u128, 3)),
+				Self::from_equal_u128s(interleave_mask_odd!(u128, 4)),
+				Self::from_equal_u128s(interleave_mask_odd!(u128, 5)),
+				Self::from_equal_u128s(interleave_mask_odd!(u128, 6)),
+			];
+
+			#[inline(always)]
+			fn interleave(self, other: Self, log_block_len: usize) -> (Self, Self) {
+				assert!(log_block_len < $log_bits);
+
+				if log_block_len < 6 {
+					// Pairs of blocks do not cross the 64-bit lanes, so the shifts can be done
+					// lane-wise.
+					let block_len = Simd::splat(1 << log_block_len);
+					let mask = Self::INTERLEAVE_EVEN_MASK[log_block_len].0;
+					let t = ((self.0 >> block_len) ^ other.0) & mask;
+					(Self(self.0 ^ (t << block_len)), Self(other.0 ^ t))
+				} else {
+					let (c, d) =
+						interleave_lanes(self.to_array(), other.to_array(), log_block_len - 6);
+					(c.into(), d.into())
+				}
+			}
+
+			#[inline(always)]
+			fn transpose(mut self, mut other: Self, log_block_len: usize) -> (Self, Self) {
+				assert!(log_block_len < $log_bits);
+
+				for log_block_len in (log_block_len..$log_bits).rev() {
+					(self, other) = self.interleave(other, log_block_len);
+				}
+
+				(self, other)
+			}
+		}
+
+		impl<Scalar: BinaryField> Broadcast<Scalar> for PackedPrimitiveType<$name, Scalar>
+		where
+			u128: From<Scalar::Underlier>,
+		{
+			#[inline(always)]
+			fn broadcast(scalar: Scalar) -> Self {
+				let tower_level = Scalar::N_BITS.ilog2() as usize;
+				let mut value = u128::from(scalar.to_underlier());
+				for n in tower_level..6 {
+					value |= value << (1 << n);
+				}
+
+				let underlier = match tower_level {
+					0..=6 => $name(Simd::splat(value as u64)),
+					7 => $name::from_equal_u128s(value),
+					_ => unreachable!(),
+				};
+				underlier.into()
+			}
+		}
+
+		impl_iteration!($name,
+			@strategy BitIterationStrategy, U1,
+			@strategy FallbackStrategy, U2, U4,
+			@strategy DivisibleStrategy, u8, u16, u32, u64, u128, $name,
+		);
+	};
+}
+
+define_simd_underlier!(SimdU128, 2, 7, 128);
+define_simd_underlier!(SimdU256, 4, 8, 256);
+define_simd_underlier!(SimdU512, 8, 9, 512);
+
+#[cfg(test)]
+mod tests {
+	use proptest::{arbitrary::any, proptest};
+
+	use super::*;
+
+	fn to_u128s<const N: usize>(lanes: [u64; N]) -> Vec<u128> {
+		lanes
+			.chunks_exact(2)
+			.map(|pair| pair[0] as u128 | (pair[1] as u128) << 64)
+			.collect()
+	}
+
+	proptest! {
+		#[test]
+		fn test_shifts_match_u128(a in any::<u128>(), shift in 0usize..128) {
+			let value = SimdU128::from(a);
+			assert_eq!(to_u128s((value << shift).to_array()), vec![a << shift]);
+			assert_eq!(to_u128s((value >> shift).to_array()), vec![a >> shift]);
+		}
+
+		#[test]
+		fn test_shifts_across_128b_lanes(a in any::<[u64; 4]>(), shift in 0usize..256) {
+			let value = SimdU256::from(a);
+			let [lo, hi] = <[u128; 2]>::try_from(to_u128s(a)).unwrap();
+			let shl = |x: u128, s: usize| x.checked_shl(s as u32).unwrap_or(0);
+			let shr = |x: u128, s: usize| x.checked_shr(s as u32).unwrap_or(0);
+			let expected_shl = if shift < 128 {
+				vec![shl(lo, shift), shl(hi, shift) | shr(lo, 128 - shift)]
+			} else {
+				vec![0, shl(lo, shift - 128)]
+			};
+			assert_eq!(to_u128s((value << shift).to_array()), expected_shl);
+
+			let lanes = value.shl_128b_lanes(shift % 128);
+			assert_eq!(to_u128s(lanes.to_array()), vec![lo << (shift % 128), hi << (shift % 128)]);
+		}
+
+		#[test]
+		fn test_interleave_matches_u128(a in any::<u128>(), b in any::<u128>(), log_block_len in 0usize..7) {
+			let (c, d) = SimdU128::from(a).interleave(SimdU128::from(b), log_block_len);
+			let (expected_c, expected_d) = a.interleave(b, log_block_len);
+			assert_eq!(to_u128s(c.to_array()), vec![expected_c]);
+			assert_eq!(to_u128s(d.to_array()), vec![expected_d]);
+		}
+
+		#[test]
+		fn test_interleave_128b_blocks(a in any::<[u64; 4]>(), b in any::<[u64; 4]>()) {
+			let (c, d) = SimdU256::from(a).interleave(SimdU256::from(b), 7);
+			assert_eq!(c.to_array(), [a[0], a[1], b[0], b[1]]);
+			assert_eq!(d.to_array(), [a[2], a[3], b[2], b[3]]);
+		}
+	}
+
+	#[test]
+	fn test_constants() {
+		assert_eq!(SimdU256::ONE.to_array(), [1, 0, 0, 0]);
+		assert_eq!(SimdU512::ONES, !SimdU512::ZERO);
+		assert_eq!(SimdU128::fill_with_bit(1), SimdU128::ONES);
+	}
+}
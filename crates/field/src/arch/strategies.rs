@@ -19,6 +19,11 @@ pub struct SimdStrategy;
 /// Reuse multiplication operation for that.
 pub struct ReuseMultiplyStrategy;
 
+/// Constant-time strategy. Applies the recursive algorithms of [`PackedStrategy`] at every level
+/// of the tower down to the 1-bit elements, so the operations consist of bitwise operations and
+/// shifts by public amounts only, without table lookups or branches on the values.
+pub struct ConstantTimeStrategy;
+
 /// Use operations with GFNI instructions
 pub struct GfniStrategy;
 /// Specialized versions of the above to resolve conflicting implementations
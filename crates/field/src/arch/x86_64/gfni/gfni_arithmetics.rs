@@ -314,6 +314,7 @@ where
 }
 
 /// Implement packed transformation factory with GFNI instructions for scalars bigger than 8 bits
+#[cfg(target_feature = "gfni")]
 macro_rules! impl_transformation_with_gfni_nxn {
 	($name:ty, $blocks:literal) => {
 		impl<OP> $crate::linear_transformation::PackedTransformationFactory<OP> for $name
@@ -350,4 +351,5 @@ macro_rules! impl_transformation_with_gfni_nxn {
 	};
 }
 
+#[cfg(target_feature = "gfni")]
 pub(crate) use impl_transformation_with_gfni_nxn;
@@ -9,3 +9,5 @@ mod m128;
 mod m256;
 #[cfg(target_feature = "avx512f")]
 mod m512;
+#[cfg(all(target_feature = "sse2", not(target_feature = "gfni")))]
+pub(in crate::arch) mod runtime;
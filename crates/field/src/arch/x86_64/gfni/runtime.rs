@@ -0,0 +1,311 @@
+// Copyright 2025 Irreducible Inc.
+
+//! GFNI arithmetic of the 128-bit packed fields for binaries compiled without the `gfni` target
+//! feature.
+//!
+//! The packed field types of such binaries fall back to the lookup table and SIMD strategies. The
+//! functions of this module implement the operations with the strategies the types use when GFNI
+//! is enabled at compile time, so that [`Dispatcher`](crate::arch::dispatch::Dispatcher) can
+//! switch to them when the running CPU supports GFNI. They only apply to the packed types of
+//! [`M128`] and return `None` for other types.
+
+use std::{any::TypeId, slice};
+
+use super::gfni_arithmetics::{
+	AES_TO_TOWER_MAP, GfniTransformation, GfniTransformationNxN, TOWER_TO_AES_MAP, linear_transform,
+};
+use crate::{
+	BinaryField, PackedField,
+	arch::{
+		GfniStrategy,
+		portable::reuse_multiply_arithmetic::Alpha,
+		x86_64::{
+			m128::M128,
+			packed_128::*,
+			packed_aes_128::*,
+			simd::simd_arithmetic::{
+				tower_invert_or_zero, tower_mul, tower_mul_alpha, tower_square,
+			},
+		},
+	},
+	arithmetic_traits::{TaggedInvertOrZero, TaggedMul},
+	linear_transformation::{FieldLinearTransformation, Transformation},
+	underlier::WithUnderlier,
+};
+
+/// Packed field arithmetic with GFNI instructions.
+trait GfniArithmetic: PackedField + WithUnderlier<Underlier = M128> {
+	fn mul(self, rhs: Self) -> Self;
+	fn square(self) -> Self;
+	fn invert_or_zero(self) -> Self;
+}
+
+/// Multiplication by the tower generator with GFNI instructions, used by the tower recursion of
+/// the AES fields.
+trait GfniMulAlpha: GfniArithmetic {
+	fn mul_alpha(self) -> Self;
+}
+
+impl GfniArithmetic for PackedAESBinaryField16x8b {
+	#[inline(always)]
+	fn mul(self, rhs: Self) -> Self {
+		TaggedMul::<GfniStrategy>::mul(self, rhs)
+	}
+
+	#[inline(always)]
+	fn square(self) -> Self {
+		TaggedMul::<GfniStrategy>::mul(self, self)
+	}
+
+	#[inline(always)]
+	fn invert_or_zero(self) -> Self {
+		TaggedInvertOrZero::<GfniStrategy>::invert_or_zero(self)
+	}
+}
+
+impl GfniMulAlpha for PackedAESBinaryField16x8b {
+	#[inline(always)]
+	fn mul_alpha(self) -> Self {
+		TaggedMul::<GfniStrategy>::mul(self, Self::alpha())
+	}
+}
+
+macro_rules! impl_gfni_arithmetic_for_aes_tower {
+	($($name:ty),*) => {
+		$(
+			impl GfniArithmetic for $name {
+				#[inline(always)]
+				fn mul(self, rhs: Self) -> Self {
+					tower_mul(self, rhs, GfniArithmetic::mul)
+				}
+
+				#[inline(always)]
+				fn square(self) -> Self {
+					tower_square(self, GfniArithmetic::square, GfniMulAlpha::mul_alpha)
+				}
+
+				#[inline(always)]
+				fn invert_or_zero(self) -> Self {
+					tower_invert_or_zero(
+						self,
+						GfniArithmetic::mul,
+						GfniArithmetic::square,
+						GfniMulAlpha::mul_alpha,
+						GfniArithmetic::invert_or_zero,
+					)
+				}
+			}
+
+			impl GfniMulAlpha for $name {
+				#[inline(always)]
+				fn mul_alpha(self) -> Self {
+					tower_mul_alpha(self, GfniMulAlpha::mul_alpha)
+				}
+			}
+		)*
+	};
+}
+
+impl_gfni_arithmetic_for_aes_tower!(
+	PackedAESBinaryField8x16b,
+	PackedAESBinaryField4x32b,
+	PackedAESBinaryField2x64b,
+	PackedAESBinaryField1x128b
+);
+
+/// Implements the arithmetic of the canonical tower fields by mapping the bytes of the elements to
+/// the isomorphic AES fields, as [`AESIsomorphicStrategy`](crate::arch::AESIsomorphicStrategy)
+/// does.
+macro_rules! impl_gfni_arithmetic_via_aes_isomorphism {
+	($($name:ty => $aes_name:ty),*) => {
+		$(
+			impl GfniArithmetic for $name {
+				#[inline(always)]
+				fn mul(self, rhs: Self) -> Self {
+					from_aes(GfniArithmetic::mul(to_aes::<_, $aes_name>(self), to_aes(rhs)))
+				}
+
+				#[inline(always)]
+				fn square(self) -> Self {
+					from_aes(GfniArithmetic::square(to_aes::<_, $aes_name>(self)))
+				}
+
+				#[inline(always)]
+				fn invert_or_zero(self) -> Self {
+					from_aes(GfniArithmetic::invert_or_zero(to_aes::<_, $aes_name>(self)))
+				}
+			}
+		)*
+	};
+}
+
+impl_gfni_arithmetic_via_aes_isomorphism!(
+	PackedBinaryField8x16b => PackedAESBinaryField8x16b,
+	PackedBinaryField4x32b => PackedAESBinaryField4x32b,
+	PackedBinaryField2x64b => PackedAESBinaryField2x64b,
+	PackedBinaryField1x128b => PackedAESBinaryField1x128b
+);
+
+impl GfniArithmetic for PackedBinaryField16x8b {
+	#[inline(always)]
+	fn mul(self, rhs: Self) -> Self {
+		from_aes(GfniArithmetic::mul(to_aes::<_, PackedAESBinaryField16x8b>(self), to_aes(rhs)))
+	}
+
+	#[inline(always)]
+	fn square(self) -> Self {
+		from_aes(GfniArithmetic::square(to_aes::<_, PackedAESBinaryField16x8b>(self)))
+	}
+
+	#[inline(always)]
+	fn invert_or_zero(self) -> Self {
+		// The inversion and the transformation back to the canonical field take a single
+		// instruction.
+		TaggedInvertOrZero::<GfniStrategy>::invert_or_zero(self)
+	}
+}
+
+#[inline(always)]
+fn to_aes<P, AES>(value: P) -> AES
+where
+	P: WithUnderlier<Underlier = M128>,
+	AES: WithUnderlier<Underlier = M128>,
+{
+	AES::from_underlier(linear_transform(value.to_underlier(), TOWER_TO_AES_MAP))
+}
+
+#[inline(always)]
+fn from_aes<AES, P>(value: AES) -> P
+where
+	AES: WithUnderlier<Underlier = M128>,
+	P: WithUnderlier<Underlier = M128>,
+{
+	P::from_underlier(linear_transform(value.to_underlier(), AES_TO_TOWER_MAP))
+}
+
+/// Reinterprets `value` as a `T`.
+///
+/// ## Safety
+///
+/// `P` and `T` must be the same type.
+#[inline(always)]
+unsafe fn cast<P: Copy + 'static, T: Copy + 'static>(value: P) -> T {
+	debug_assert_eq!(TypeId::of::<P>(), TypeId::of::<T>());
+	unsafe { std::mem::transmute_copy(&value) }
+}
+
+/// Evaluates `$body` with `$T` bound to the packed type `$P` is equal to, returning
+/// `Some($body)` from the enclosing function, or `None` if `$P` is not one of the types in the
+/// list.
+macro_rules! with_gfni_type {
+	($P:ty, |$T:ident| $body:expr, [$($name:ty),*]) => {{
+		$(
+			if TypeId::of::<$P>() == TypeId::of::<$name>() {
+				type $T = $name;
+				return Some($body);
+			}
+		)*
+		None
+	}};
+	($P:ty, |$T:ident| $body:expr) => {
+		with_gfni_type!($P, |$T| $body, [
+			PackedBinaryField16x8b,
+			PackedBinaryField8x16b,
+			PackedBinaryField4x32b,
+			PackedBinaryField2x64b,
+			PackedBinaryField1x128b,
+			PackedAESBinaryField16x8b,
+			PackedAESBinaryField8x16b,
+			PackedAESBinaryField4x32b,
+			PackedAESBinaryField2x64b,
+			PackedAESBinaryField1x128b
+		])
+	};
+}
+
+/// Multiplies `lhs` by `rhs` with GFNI instructions, if `P` is supported.
+///
+/// ## Safety
+///
+/// The running CPU must support GFNI.
+#[inline(always)]
+pub(crate) unsafe fn mul<P: PackedField>(lhs: P, rhs: P) -> Option<P> {
+	with_gfni_type!(P, |T| unsafe {
+		cast::<T, P>(GfniArithmetic::mul(cast::<P, T>(lhs), cast::<P, T>(rhs)))
+	})
+}
+
+/// Squares `value` with GFNI instructions, if `P` is supported.
+///
+/// ## Safety
+///
+/// The running CPU must support GFNI.
+#[inline(always)]
+pub(crate) unsafe fn square<P: PackedField>(value: P) -> Option<P> {
+	with_gfni_type!(P, |T| unsafe { cast::<T, P>(GfniArithmetic::square(cast::<P, T>(value))) })
+}
+
+/// Inverts `value` with GFNI instructions, mapping zero to zero, if `P` is supported.
+///
+/// ## Safety
+///
+/// The running CPU must support GFNI.
+#[inline(always)]
+pub(crate) unsafe fn invert_or_zero<P: PackedField>(value: P) -> Option<P> {
+	with_gfni_type!(P, |T| unsafe {
+		cast::<T, P>(GfniArithmetic::invert_or_zero(cast::<P, T>(value)))
+	})
+}
+
+/// Applies `transformation` to every element of `values` in place with GFNI instructions, if `P` is
+/// supported.
+///
+/// ## Safety
+///
+/// The running CPU must support GFNI.
+#[inline(always)]
+pub(crate) unsafe fn transform_slice<P, Data>(
+	transformation: &FieldLinearTransformation<P::Scalar, Data>,
+	values: &mut [P],
+) -> Option<()>
+where
+	P: PackedField<Scalar: BinaryField>,
+	Data: AsRef<[P::Scalar]> + Sync,
+{
+	macro_rules! transform_with {
+		($transformation:ident $(<$blocks:literal, $matrices:literal>)?, $name:ty) => {
+			if TypeId::of::<P>() == TypeId::of::<$name>() {
+				// Safety: `P` is `$name`, so the scalars of both types are also the same.
+				let (bases, values) = unsafe {
+					let bases = transformation.bases();
+					(
+						slice::from_raw_parts(
+							bases.as_ptr().cast::<<$name as PackedField>::Scalar>(),
+							bases.len(),
+						),
+						slice::from_raw_parts_mut(values.as_mut_ptr().cast::<$name>(), values.len()),
+					)
+				};
+				let transformation = $transformation::<$name $(, $blocks, $matrices)?>::new(
+					FieldLinearTransformation::new(bases),
+				);
+				for value in values.iter_mut() {
+					*value = Transformation::<$name, $name>::transform(&transformation, value);
+				}
+				return Some(());
+			}
+		};
+	}
+
+	transform_with!(GfniTransformation, PackedBinaryField16x8b);
+	transform_with!(GfniTransformation, PackedAESBinaryField16x8b);
+	transform_with!(GfniTransformationNxN<2, 1>, PackedBinaryField8x16b);
+	transform_with!(GfniTransformationNxN<2, 1>, PackedAESBinaryField8x16b);
+	transform_with!(GfniTransformationNxN<4, 2>, PackedBinaryField4x32b);
+	transform_with!(GfniTransformationNxN<4, 2>, PackedAESBinaryField4x32b);
+	transform_with!(GfniTransformationNxN<8, 4>, PackedBinaryField2x64b);
+	transform_with!(GfniTransformationNxN<8, 4>, PackedAESBinaryField2x64b);
+	transform_with!(GfniTransformationNxN<16, 8>, PackedBinaryField1x128b);
+	transform_with!(GfniTransformationNxN<16, 8>, PackedAESBinaryField1x128b);
+	None
+}
@@ -2,8 +2,10 @@
 
 use cfg_if::cfg_if;
 
-#[cfg(target_feature = "gfni")]
-mod gfni;
+// Without the `gfni` target feature, the GFNI arithmetic is only used by the kernels selected at
+// runtime, see `crate::arch::dispatch`.
+#[cfg(any(target_feature = "gfni", feature = "nightly_features"))]
+pub(super) mod gfni;
 
 #[cfg(target_feature = "pclmulqdq")]
 mod pclmul;
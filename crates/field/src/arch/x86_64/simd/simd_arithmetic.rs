@@ -187,33 +187,7 @@ where
 			return self * rhs;
 		}
 
-		let a = self.as_packed_subfield();
-		let b = rhs.as_packed_subfield();
-
-		// [a0_lo * b0_lo, a0_hi * b0_hi, a1_lo * b1_lo, a1_h1 * b1_hi, ...]
-		let z0_even_z2_odd = a * b;
-
-		// [a0_lo, b0_lo, a1_lo, b1_lo, ...]
-		// [a0_hi, b0_hi, a1_hi, b1_hi, ...]
-		let (lo, hi) = a.interleave(b, 0);
-		// [a0_lo + a0_hi, b0_lo + b0_hi, a1_lo + a1_hi, b1lo + b1_hi, ...]
-		let lo_plus_hi_a_even_b_odd = lo + hi;
-
-		let alpha_even_z2_odd = <Self as PackedTowerField>::PackedDirectSubfield::from_underlier(
-			z0_even_z2_odd
-				.to_underlier()
-				.set_alpha_even::<<Self as PackedTowerField>::DirectSubfield>(),
-		);
-		let (lhs, rhs) = lo_plus_hi_a_even_b_odd.interleave(alpha_even_z2_odd, 0);
-		let z1_xor_z0z2_even_z2a_odd = lhs * rhs;
-
-		let z1_xor_z0z2 = duplicate_odd(z1_xor_z0z2_even_z2a_odd);
-		let zero_even_z1_xor_z2a_xor_z0z2_odd = xor(z1_xor_z0z2_even_z2a_odd, z1_xor_z0z2);
-
-		let z2_even_z0_odd = flip_even_odd(z0_even_z2_odd);
-		let z0z2 = xor(z0_even_z2_odd, z2_even_z0_odd);
-
-		Self::from_packed_subfield(xor(zero_even_z1_xor_z2a_xor_z0z2_odd, z0z2))
+		tower_mul(self, rhs, |a, b| a * b)
 	}
 }
 
@@ -230,13 +204,7 @@ where
 			return MulAlpha::mul_alpha(self);
 		}
 
-		let a_0_a_1 = self.as_packed_subfield();
-		let a_0_mul_alpha_a_1_mul_alpha = a_0_a_1.mul_alpha();
-
-		let a_1_a_0 = flip_even_odd(self.as_packed_subfield());
-		let a0_plus_a1_alpha = xor(a_0_mul_alpha_a_1_mul_alpha, a_1_a_0);
-
-		Self::from_packed_subfield(blend_odd_even(a0_plus_a1_alpha, a_1_a_0))
+		tower_mul_alpha(self, MulAlpha::mul_alpha)
 	}
 }
 
@@ -252,13 +220,7 @@ where
 			return PackedField::square(self);
 		}
 
-		let a_0_a_1 = self.as_packed_subfield();
-		let a_0_sq_a_1_sq = PackedField::square(a_0_a_1);
-		let a_1_sq_a_0_sq = flip_even_odd(a_0_sq_a_1_sq);
-		let a_0_sq_plus_a_1_sq = a_0_sq_a_1_sq + a_1_sq_a_0_sq;
-		let a_1_mul_alpha = a_0_sq_a_1_sq.mul_alpha();
-
-		Self::from_packed_subfield(blend_odd_even(a_1_mul_alpha, a_0_sq_plus_a_1_sq))
+		tower_square(self, PackedField::square, MulAlpha::mul_alpha)
 	}
 }
 
@@ -274,20 +236,121 @@ where
 			return PackedField::invert_or_zero(self);
 		}
 
-		let a_0_a_1 = self.as_packed_subfield();
-		let a_1_a_0 = flip_even_odd(a_0_a_1);
-		let a_1_mul_alpha = a_1_a_0.mul_alpha();
-		let a_0_plus_a1_mul_alpha = xor(a_0_a_1, a_1_mul_alpha);
-		let a_1_sq_a_0_sq = PackedField::square(a_1_a_0);
-		let delta = xor(a_1_sq_a_0_sq, a_0_plus_a1_mul_alpha * a_0_a_1);
-		let delta_inv = PackedField::invert_or_zero(delta);
-		let delta_inv_delta_inv = duplicate_odd(delta_inv);
-		let delta_multiplier = blend_odd_even(a_0_a_1, a_0_plus_a1_mul_alpha);
-
-		Self::from_packed_subfield(delta_inv_delta_inv * delta_multiplier)
+		tower_invert_or_zero(
+			self,
+			|a, b| a * b,
+			PackedField::square,
+			MulAlpha::mul_alpha,
+			PackedField::invert_or_zero,
+		)
 	}
 }
 
+/// Multiplies packed tower field elements with the Karatsuba formula for their halves.
+///
+/// The tower operations are generic over the arithmetic of the direct subfield, so that
+/// [`SimdStrategy`] can build on the strategies of the packed subfield types and on the
+/// arithmetic selected at runtime.
+#[inline(always)]
+pub(in crate::arch::x86_64) fn tower_mul<PT>(
+	lhs: PT,
+	rhs: PT,
+	mul: impl Fn(PT::PackedDirectSubfield, PT::PackedDirectSubfield) -> PT::PackedDirectSubfield,
+) -> PT
+where
+	PT: PackedTowerField<Underlier: TowerSimdType>,
+{
+	let a = lhs.as_packed_subfield();
+	let b = rhs.as_packed_subfield();
+
+	// [a0_lo * b0_lo, a0_hi * b0_hi, a1_lo * b1_lo, a1_h1 * b1_hi, ...]
+	let z0_even_z2_odd = mul(a, b);
+
+	// [a0_lo, b0_lo, a1_lo, b1_lo, ...]
+	// [a0_hi, b0_hi, a1_hi, b1_hi, ...]
+	let (lo, hi) = a.interleave(b, 0);
+	// [a0_lo + a0_hi, b0_lo + b0_hi, a1_lo + a1_hi, b1lo + b1_hi, ...]
+	let lo_plus_hi_a_even_b_odd = lo + hi;
+
+	let alpha_even_z2_odd = PT::PackedDirectSubfield::from_underlier(
+		z0_even_z2_odd
+			.to_underlier()
+			.set_alpha_even::<PT::DirectSubfield>(),
+	);
+	let (lhs, rhs) = lo_plus_hi_a_even_b_odd.interleave(alpha_even_z2_odd, 0);
+	let z1_xor_z0z2_even_z2a_odd = mul(lhs, rhs);
+
+	let z1_xor_z0z2 = duplicate_odd(z1_xor_z0z2_even_z2a_odd);
+	let zero_even_z1_xor_z2a_xor_z0z2_odd = xor(z1_xor_z0z2_even_z2a_odd, z1_xor_z0z2);
+
+	let z2_even_z0_odd = flip_even_odd(z0_even_z2_odd);
+	let z0z2 = xor(z0_even_z2_odd, z2_even_z0_odd);
+
+	PT::from_packed_subfield(xor(zero_even_z1_xor_z2a_xor_z0z2_odd, z0z2))
+}
+
+/// Multiplies packed tower field elements by the tower generator, see [`tower_mul`].
+#[inline(always)]
+pub(in crate::arch::x86_64) fn tower_mul_alpha<PT>(
+	value: PT,
+	mul_alpha: impl Fn(PT::PackedDirectSubfield) -> PT::PackedDirectSubfield,
+) -> PT
+where
+	PT: PackedTowerField<Underlier: TowerSimdType>,
+{
+	let a_0_a_1 = value.as_packed_subfield();
+	let a_0_mul_alpha_a_1_mul_alpha = mul_alpha(a_0_a_1);
+
+	let a_1_a_0 = flip_even_odd(value.as_packed_subfield());
+	let a0_plus_a1_alpha = xor(a_0_mul_alpha_a_1_mul_alpha, a_1_a_0);
+
+	PT::from_packed_subfield(blend_odd_even(a0_plus_a1_alpha, a_1_a_0))
+}
+
+/// Squares packed tower field elements, see [`tower_mul`].
+#[inline(always)]
+pub(in crate::arch::x86_64) fn tower_square<PT>(
+	value: PT,
+	square: impl Fn(PT::PackedDirectSubfield) -> PT::PackedDirectSubfield,
+	mul_alpha: impl Fn(PT::PackedDirectSubfield) -> PT::PackedDirectSubfield,
+) -> PT
+where
+	PT: PackedTowerField<Underlier: TowerSimdType>,
+{
+	let a_0_a_1 = value.as_packed_subfield();
+	let a_0_sq_a_1_sq = square(a_0_a_1);
+	let a_1_sq_a_0_sq = flip_even_odd(a_0_sq_a_1_sq);
+	let a_0_sq_plus_a_1_sq = a_0_sq_a_1_sq + a_1_sq_a_0_sq;
+	let a_1_mul_alpha = mul_alpha(a_0_sq_a_1_sq);
+
+	PT::from_packed_subfield(blend_odd_even(a_1_mul_alpha, a_0_sq_plus_a_1_sq))
+}
+
+/// Inverts packed tower field elements, mapping zero to zero, see [`tower_mul`].
+#[inline(always)]
+pub(in crate::arch::x86_64) fn tower_invert_or_zero<PT>(
+	value: PT,
+	mul: impl Fn(PT::PackedDirectSubfield, PT::PackedDirectSubfield) -> PT::PackedDirectSubfield,
+	square: impl Fn(PT::PackedDirectSubfield) -> PT::PackedDirectSubfield,
+	mul_alpha: impl Fn(PT::PackedDirectSubfield) -> PT::PackedDirectSubfield,
+	invert_or_zero: impl Fn(PT::PackedDirectSubfield) -> PT::PackedDirectSubfield,
+) -> PT
+where
+	PT: PackedTowerField<Underlier: TowerSimdType>,
+{
+	let a_0_a_1 = value.as_packed_subfield();
+	let a_1_a_0 = flip_even_odd(a_0_a_1);
+	let a_1_mul_alpha = mul_alpha(a_1_a_0);
+	let a_0_plus_a1_mul_alpha = xor(a_0_a_1, a_1_mul_alpha);
+	let a_1_sq_a_0_sq = square(a_1_a_0);
+	let delta = xor(a_1_sq_a_0_sq, mul(a_0_plus_a1_mul_alpha, a_0_a_1));
+	let delta_inv = invert_or_zero(delta);
+	let delta_inv_delta_inv = duplicate_odd(delta_inv);
+	let delta_multiplier = blend_odd_even(a_0_a_1, a_0_plus_a1_mul_alpha);
+
+	PT::from_packed_subfield(mul(delta_inv_delta_inv, delta_multiplier))
+}
+
 /// SIMD packed field transformation.
 /// The idea is similar to `PackedTransformation` but we use SIMD instructions
 /// to multiply a component with zeros/ones by a basis vector.
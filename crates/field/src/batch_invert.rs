@@ -0,0 +1,196 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Batch inversion of field elements with Montgomery's trick.
+//!
+//! Inverting $n$ elements one by one costs $n$ inversions. Montgomery's trick computes the prefix
+//! products of the elements, inverts the last one, and walks the prefix products backwards to
+//! recover every inverse, for a cost of a single inversion and $3(n - 1)$ multiplications.
+//!
+//! The functions follow the semantics of [`PackedField::invert_or_zero`]: zero elements, or zero
+//! lanes of packed elements, are left as zero and do not affect the inverses of the other
+//! elements. Every scalar field is also a [`PackedField`] of width 1, so the functions apply to
+//! slices of scalars as well as slices of packed elements.
+//!
+//! The zero handling branches on the values of the elements, so the functions are not
+//! constant-time.
+
+use binius_maybe_rayon::prelude::*;
+
+use crate::{Field, PackedField};
+
+/// The number of packed elements that are inverted with a single field inversion.
+///
+/// Chunks are inverted in parallel, so the size is a trade-off between the number of inversions
+/// and the available parallelism.
+const CHUNK_SIZE: usize = 1 << 10;
+
+/// Inverts every element of `values` in place, mapping zeros to zero.
+///
+/// This is equivalent to calling [`PackedField::invert_or_zero`] on every element. The slice is
+/// split into chunks that are inverted in parallel, with one field inversion per chunk.
+pub fn batch_invert_or_zero<P: PackedField>(values: &mut [P]) {
+	values
+		.par_chunks_mut(CHUNK_SIZE)
+		.for_each_init(Vec::new, |scratch, chunk| {
+			batch_invert_or_zero_with_scratch(chunk, scratch)
+		});
+}
+
+/// Inverts every element of `values` in place, mapping zeros to zero, on the current thread.
+///
+/// `scratch` holds the prefix products. Its contents are overwritten, it is passed in so that
+/// repeated calls can reuse the allocation.
+pub fn batch_invert_or_zero_with_scratch<P: PackedField>(values: &mut [P], scratch: &mut Vec<P>) {
+	scratch.clear();
+	scratch.reserve(values.len());
+
+	let mut product = P::one();
+	for &value in values.iter() {
+		scratch.push(product);
+		product *= value;
+	}
+
+	// A zero lane of any element zeroes that lane of the product, so checking the lanes of the
+	// product once detects whether the zero handling is needed at all.
+	if product.iter().any(|lane| lane == P::Scalar::ZERO) {
+		return batch_invert_or_zero_with_zeros(values, scratch);
+	}
+
+	let mut inverse = product.invert_or_zero();
+	for (value, &prefix) in values.iter_mut().zip(scratch.iter()).rev() {
+		let value_inverse = inverse * prefix;
+		inverse *= *value;
+		*value = value_inverse;
+	}
+}
+
+/// Inverts every element of `values` in place when some of their lanes are zero.
+fn batch_invert_or_zero_with_zeros<P: PackedField>(values: &mut [P], scratch: &mut Vec<P>) {
+	scratch.clear();
+
+	// Zero lanes are replaced with ones, so that they do not zero the running product. Their
+	// inverses are ones, which are replaced back with zeros.
+	let mut product = P::one();
+	for &value in values.iter() {
+		scratch.push(product);
+		product *= value + zero_lanes(value);
+	}
+
+	let mut inverse = product.invert_or_zero();
+	for (value, &prefix) in values.iter_mut().zip(scratch.iter()).rev() {
+		let zeros = zero_lanes(*value);
+		let value_inverse = inverse * prefix;
+		inverse *= *value + zeros;
+		*value = value_inverse - zeros;
+	}
+}
+
+/// Returns a packed element with ones in the lanes where `value` is zero and zeros elsewhere.
+#[inline]
+fn zero_lanes<P: PackedField>(value: P) -> P {
+	P::from_fn(|i| {
+		if value.get(i) == P::Scalar::ZERO {
+			P::Scalar::ONE
+		} else {
+			P::Scalar::ZERO
+		}
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use proptest::{collection::vec, prelude::*};
+
+	use super::*;
+	use crate::{
+		AESTowerField8b, BinaryField8b, BinaryField32b, BinaryField128b, PackedAESBinaryField16x8b,
+		PackedBinaryField4x32b, PackedBinaryField16x8b, underlier::WithUnderlier,
+	};
+
+	fn check_batch_invert<P: PackedField>(values: Vec<P>) {
+		let expected = values
+			.iter()
+			.map(|value| value.invert_or_zero())
+			.collect::<Vec<_>>();
+
+		let mut inverted = values.clone();
+		batch_invert_or_zero(&mut inverted);
+		assert_eq!(inverted, expected);
+
+		let mut inverted = values;
+		batch_invert_or_zero_with_scratch(&mut inverted, &mut Vec::new());
+		assert_eq!(inverted, expected);
+	}
+
+	/// Values with a high proportion of zeros and ones, which are the special cases of the zero
+	/// handling.
+	fn sparse_u32() -> impl Strategy<Value = u32> {
+		prop_oneof![Just(0), Just(1), any::<u32>()]
+	}
+
+	proptest! {
+		#[test]
+		fn test_batch_invert_scalars_128b(values in vec(prop_oneof![Just(0), any::<u128>()], 0..3000)) {
+			check_batch_invert(values.into_iter().map(BinaryField128b::from_underlier).collect());
+		}
+
+		#[test]
+		fn test_batch_invert_scalars_32b(values in vec(sparse_u32(), 0..100)) {
+			check_batch_invert(values.into_iter().map(BinaryField32b::from_underlier).collect());
+		}
+
+		#[test]
+		fn test_batch_invert_packed_8b(values in vec(any::<[u8; 16]>(), 0..100)) {
+			check_batch_invert(
+				values
+					.into_iter()
+					.map(|bytes| {
+						PackedBinaryField16x8b::from_fn(|i| BinaryField8b::from_underlier(bytes[i]))
+					})
+					.collect(),
+			);
+		}
+
+		#[test]
+		fn test_batch_invert_packed_8b_nonzero(values in vec(any::<[u8; 16]>(), 0..100)) {
+			// Without zero lanes the inversion skips the zero handling.
+			check_batch_invert(
+				values
+					.into_iter()
+					.map(|bytes| {
+						PackedBinaryField16x8b::from_fn(|i| BinaryField8b::from_underlier(bytes[i].max(1)))
+					})
+					.collect(),
+			);
+		}
+
+		#[test]
+		fn test_batch_invert_packed_aes_8b(values in vec(any::<[u8; 16]>(), 0..100)) {
+			check_batch_invert(
+				values
+					.into_iter()
+					.map(|bytes| {
+						PackedAESBinaryField16x8b::from_fn(|i| AESTowerField8b::from_underlier(bytes[i]))
+					})
+					.collect(),
+			);
+		}
+
+		#[test]
+		fn test_batch_invert_packed_32b(values in vec(any::<[u32; 4]>(), 0..100)) {
+			check_batch_invert(
+				values
+					.into_iter()
+					.map(|words| PackedBinaryField4x32b::from_fn(|i| BinaryField32b::from_underlier(words[i] % 4)))
+					.collect(),
+			);
+		}
+	}
+
+	#[test]
+	fn test_batch_invert_all_zeros() {
+		let mut values = vec![BinaryField128b::ZERO; CHUNK_SIZE + 1];
+		batch_invert_or_zero(&mut values);
+		assert!(values.iter().all(|&value| value == BinaryField128b::ZERO));
+	}
+}
@@ -788,6 +788,9 @@ macro_rules! serialize_deserialize {
 				Ok(Self(DeserializeBytes::deserialize(read_buf, mode)?))
 			}
 		}
+
+		#[cfg(feature = "serde")]
+		binius_utils::impl_serde_via_big_endian_bytes!($bin_type);
 	};
 }
 
@@ -1298,6 +1301,27 @@ pub(crate) mod tests {
 		assert_eq!(BinaryField128b::deserialize(&mut read_buffer, mode).unwrap(), b128);
 	}
 
+	#[cfg(feature = "serde")]
+	#[test]
+	fn test_serde() {
+		let b32 = BinaryField32b::new(0x789ABCDE);
+		let json = serde_json::to_string(&b32).unwrap();
+		assert_eq!(json, r#""789abcde""#);
+		assert_eq!(serde_json::from_str::<BinaryField32b>(&json).unwrap(), b32);
+
+		let aes = crate::AESTowerField32b::from(b32);
+		assert_eq!(serde_json::to_string(&aes).unwrap(), json);
+		assert_eq!(serde_json::from_str::<crate::AESTowerField32b>(&json).unwrap(), aes);
+
+		let b128 = BinaryField128b::new(0x147AD0369CF258BE8899AABBCCDDEEFF);
+		let values = serde_json::to_string(&[b128, BinaryField128b::ZERO]).unwrap();
+		assert_eq!(
+			values,
+			r#"["147ad0369cf258be8899aabbccddeeff","00000000000000000000000000000000"]"#
+		);
+		assert_eq!(serde_json::from_str::<[BinaryField128b; 2]>(&values).unwrap()[0], b128);
+	}
+
 	#[test]
 	fn test_gf2_new_unchecked() {
 		for i in 0..2 {
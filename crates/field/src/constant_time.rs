@@ -0,0 +1,173 @@
+// Copyright 2025 Irreducible Inc.
+
+//! The constant-time subset of the field API.
+//!
+//! The default arithmetic of the field types is chosen for speed, and some of the strategies it
+//! uses are not constant-time. For example, the [`crate::arch::PairwiseTableStrategy`]
+//! multiplication and inversion index lookup tables with the values of the elements, and the
+//! byte-sliced fields are built on top of it on targets without GFNI. Code that handles secret
+//! values, such as witness generation for a zero-knowledge proof, should restrict itself to the
+//! operations listed here.
+//!
+//! The following operations execute in time independent of the values of their operands:
+//!
+//! - addition and subtraction of scalars and packed elements of the binary fields, which are XORs
+//!   of the underlying bits;
+//! - [`ConstantTimeEq`] and [`ConditionallySelectable`] on the binary tower, AES tower and POLYVAL
+//!   scalars, and on the packed and byte-sliced fields whose underliers implement them;
+//! - the [`ConstantTimeArithmetic`] operations on the canonical tower scalars and on the packed
+//!   canonical tower fields.
+//!
+//! [`ConstantTimeArithmetic`] uses [`ConstantTimeStrategy`], which applies the recursive tower
+//! algorithms of [`crate::arch::PackedStrategy`] down to the 1-bit field, so it is built from
+//! bitwise operations and shifts by public amounts only. It is considerably slower than the
+//! default arithmetic. The AES tower and POLYVAL fields do not provide constant-time
+//! multiplication.
+//!
+//! Operations that take public indices, such as [`PackedField::get`], are constant-time with
+//! respect to the values but not to the index. Everything else, including
+//! [`crate::batch_invert`] and [`crate::Field::invert`], should be assumed to leak the values
+//! through timing.
+//!
+//! These are properties of the source code; the compiler does not guarantee to preserve them.
+//! The `constant_time` integration test contains a dudect-style timing harness that checks the
+//! compiled code.
+//!
+//! [`ConstantTimeEq`]: subtle::ConstantTimeEq
+//! [`ConditionallySelectable`]: subtle::ConditionallySelectable
+
+use crate::{
+	PackedField,
+	arch::ConstantTimeStrategy,
+	arithmetic_traits::{TaggedInvertOrZero, TaggedMul, TaggedMulAlpha, TaggedSquare},
+};
+
+/// Field arithmetic that executes in time independent of the values of the operands.
+pub trait ConstantTimeArithmetic: PackedField {
+	/// Multiplies two elements.
+	fn ct_mul(self, rhs: Self) -> Self;
+
+	/// Squares an element.
+	fn ct_square(self) -> Self;
+
+	/// Multiplies an element by the generator of the tower level over its direct subfield.
+	fn ct_mul_alpha(self) -> Self;
+
+	/// Inverts an element, mapping zero to zero.
+	fn ct_invert_or_zero(self) -> Self;
+}
+
+impl<P> ConstantTimeArithmetic for P
+where
+	P: PackedField
+		+ TaggedMul<ConstantTimeStrategy>
+		+ TaggedSquare<ConstantTimeStrategy>
+		+ TaggedMulAlpha<ConstantTimeStrategy>
+		+ TaggedInvertOrZero<ConstantTimeStrategy>,
+{
+	#[inline]
+	fn ct_mul(self, rhs: Self) -> Self {
+		TaggedMul::<ConstantTimeStrategy>::mul(self, rhs)
+	}
+
+	#[inline]
+	fn ct_square(self) -> Self {
+		TaggedSquare::<ConstantTimeStrategy>::square(self)
+	}
+
+	#[inline]
+	fn ct_mul_alpha(self) -> Self {
+		TaggedMulAlpha::<ConstantTimeStrategy>::mul_alpha(self)
+	}
+
+	#[inline]
+	fn ct_invert_or_zero(self) -> Self {
+		TaggedInvertOrZero::<ConstantTimeStrategy>::invert_or_zero(self)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use proptest::prelude::*;
+	use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+	use super::*;
+	use crate::{
+		BinaryField8b, BinaryField16b, BinaryField32b, BinaryField64b, BinaryField128b,
+		ByteSlicedAES32x128b, PackedBinaryField1x128b, PackedBinaryField2x64b,
+		PackedBinaryField4x128b, PackedBinaryField16x8b, PackedBinaryField32x4b,
+		PackedBinaryField128x1b, arithmetic_traits::MulAlpha,
+	};
+
+	fn check_arithmetic<P: ConstantTimeArithmetic + MulAlpha>(a: P, b: P) {
+		assert_eq!(a.ct_mul(b), a * b);
+		assert_eq!(a.ct_square(), a.square());
+		assert_eq!(a.ct_mul_alpha(), a.mul_alpha());
+		assert_eq!(a.ct_invert_or_zero(), a.invert_or_zero());
+		assert_eq!(P::zero().ct_invert_or_zero(), P::zero());
+	}
+
+	fn check_select<P: PackedField + ConditionallySelectable + ConstantTimeEq>(a: P, b: P) {
+		assert_eq!(P::conditional_select(&a, &b, Choice::from(0)), a);
+		assert_eq!(P::conditional_select(&a, &b, Choice::from(1)), b);
+		assert!(bool::from(a.ct_eq(&a)));
+		assert_eq!(bool::from(a.ct_eq(&b)), a == b);
+	}
+
+	proptest! {
+		#[test]
+		fn test_scalar_arithmetic(a in any::<u128>(), b in any::<u128>()) {
+			check_arithmetic(BinaryField8b::new(a as u8), BinaryField8b::new(b as u8));
+			check_arithmetic(BinaryField16b::new(a as u16), BinaryField16b::new(b as u16));
+			check_arithmetic(BinaryField32b::new(a as u32), BinaryField32b::new(b as u32));
+			check_arithmetic(BinaryField64b::new(a as u64), BinaryField64b::new(b as u64));
+			check_arithmetic(BinaryField128b::new(a), BinaryField128b::new(b));
+		}
+
+		#[test]
+		fn test_packed_arithmetic(a in any::<[u128; 4]>(), b in any::<[u128; 4]>()) {
+			let packed = |values: [u128; 4]| PackedBinaryField4x128b::from_fn(|i| BinaryField128b::new(values[i]));
+			check_arithmetic(packed(a), packed(b));
+
+			let (a, b) = (a[0].into(), b[0].into());
+			check_arithmetic(
+				PackedBinaryField16x8b::from_underlier(a),
+				PackedBinaryField16x8b::from_underlier(b),
+			);
+			check_arithmetic(
+				PackedBinaryField32x4b::from_underlier(a),
+				PackedBinaryField32x4b::from_underlier(b),
+			);
+			check_arithmetic(
+				PackedBinaryField2x64b::from_underlier(a),
+				PackedBinaryField2x64b::from_underlier(b),
+			);
+			check_arithmetic(
+				PackedBinaryField1x128b::from_underlier(a),
+				PackedBinaryField1x128b::from_underlier(b),
+			);
+			check_arithmetic(
+				PackedBinaryField128x1b::from_underlier(a),
+				PackedBinaryField128x1b::from_underlier(b),
+			);
+		}
+	}
+
+	#[test]
+	fn test_conditional_select() {
+		let mut rng = rand::rng();
+		check_select(BinaryField32b::random(&mut rng), BinaryField32b::random(&mut rng));
+		check_select(
+			PackedBinaryField16x8b::random(&mut rng),
+			PackedBinaryField16x8b::random(&mut rng),
+		);
+		check_select(
+			PackedBinaryField4x128b::random(&mut rng),
+			PackedBinaryField4x128b::random(&mut rng),
+		);
+		check_select(
+			ByteSlicedAES32x128b::random(&mut rng),
+			ByteSlicedAES32x128b::random(&mut rng),
+		);
+	}
+}
@@ -11,16 +11,19 @@
 
 #![cfg_attr(
 	all(feature = "nightly_features", target_arch = "x86_64"),
-	feature(stdarch_x86_avx512)
+	feature(avx512_target_feature, stdarch_x86_avx512)
 )]
+#![cfg_attr(feature = "nightly_features", feature(portable_simd))]
 
 pub mod aes_field;
 pub mod arch;
 pub mod arithmetic_traits;
 pub mod as_packed_field;
+pub mod batch_invert;
 pub mod binary_field;
 mod binary_field_arithmetic;
 pub mod byte_iteration;
+pub mod constant_time;
 pub mod error;
 pub mod extension;
 pub mod field;
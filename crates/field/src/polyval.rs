@@ -492,6 +492,9 @@ impl DeserializeBytes for BinaryField128bPolyval {
 	}
 }
 
+#[cfg(feature = "serde")]
+binius_utils::impl_serde_via_big_endian_bytes!(BinaryField128bPolyval);
+
 impl BinaryField for BinaryField128bPolyval {
 	const MULTIPLICATIVE_GENERATOR: Self = Self(0x72bdf2504ce49c03105433c1c25a4a7);
 }
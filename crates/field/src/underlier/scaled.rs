@@ -8,7 +8,7 @@ use std::{
 use binius_utils::checked_arithmetics::checked_log_2;
 use bytemuck::{NoUninit, Pod, Zeroable, must_cast_mut, must_cast_ref};
 use rand::RngCore;
-use subtle::{Choice, ConstantTimeEq};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 
 use super::{Divisible, NumCast, Random, UnderlierType, UnderlierWithBitOps};
 use crate::tower_levels::TowerLevel;
@@ -55,6 +55,12 @@ impl<U: ConstantTimeEq, const N: usize> ConstantTimeEq for ScaledUnderlier<U, N>
 	}
 }
 
+impl<U: ConditionallySelectable, const N: usize> ConditionallySelectable for ScaledUnderlier<U, N> {
+	fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+		Self(array::from_fn(|i| U::conditional_select(&a.0[i], &b.0[i], choice)))
+	}
+}
+
 unsafe impl<U: Zeroable, const N: usize> Zeroable for ScaledUnderlier<U, N> {}
 
 unsafe impl<U: Pod, const N: usize> Pod for ScaledUnderlier<U, N> {}
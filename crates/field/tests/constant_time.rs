@@ -0,0 +1,157 @@
+// Copyright 2025 Irreducible Inc.
+
+//! A dudect-style timing harness for [`binius_field::constant_time`].
+//!
+//! Each test times an operation on two classes of inputs, a fixed value and uniformly random
+//! values, with the measurements of the two classes interleaved at random. Welch's t-test is
+//! applied to the two samples, and the test fails if the statistic exceeds the threshold used by
+//! dudect, which indicates that the running time depends on the input.
+//!
+//! The measurements are only meaningful for optimized builds on a quiet machine, so the tests are
+//! ignored by default. Run them with
+//!
+//! ```text
+//! cargo test --release -p binius_field --test constant_time -- --ignored --test-threads=1
+//! ```
+
+use std::{hint::black_box, time::Instant};
+
+use binius_field::{
+	BinaryField32b, BinaryField128b, Field, PackedBinaryField4x32b, PackedBinaryField16x8b,
+	PackedField, constant_time::ConstantTimeArithmetic,
+};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use subtle::{Choice, ConditionallySelectable};
+
+/// The number of measurements per test.
+const MEASUREMENTS: usize = 1 << 20;
+
+/// The number of operations timed by a single measurement, to get above the timer resolution.
+const BATCH: usize = 64;
+
+/// The threshold on the t statistic above which dudect reports a leak.
+const T_THRESHOLD: f64 = 4.5;
+
+/// Running mean and variance with Welford's algorithm.
+#[derive(Default)]
+struct Stats {
+	count: f64,
+	mean: f64,
+	m2: f64,
+}
+
+impl Stats {
+	fn push(&mut self, x: f64) {
+		self.count += 1.0;
+		let delta = x - self.mean;
+		self.mean += delta / self.count;
+		self.m2 += delta * (x - self.mean);
+	}
+
+	fn variance(&self) -> f64 {
+		self.m2 / (self.count - 1.0)
+	}
+}
+
+/// Welch's t statistic of two samples.
+fn welch_t(a: &Stats, b: &Stats) -> f64 {
+	(a.mean - b.mean) / (a.variance() / a.count + b.variance() / b.count).sqrt()
+}
+
+/// Times `op` on a fixed input and on random inputs, and returns the t statistic.
+fn measure<T: Copy>(
+	fixed: T,
+	mut random: impl FnMut(&mut StdRng) -> T,
+	op: impl Fn(T) -> T,
+) -> f64 {
+	let mut rng = StdRng::seed_from_u64(0);
+	let mut samples = [Stats::default(), Stats::default()];
+	let mut timings = Vec::with_capacity(MEASUREMENTS);
+
+	for _ in 0..MEASUREMENTS {
+		let class = rng.random_bool(0.5) as usize;
+		let inputs: [T; BATCH] =
+			std::array::from_fn(|_| if class == 0 { fixed } else { random(&mut rng) });
+
+		let start = Instant::now();
+		for input in inputs {
+			black_box(op(black_box(input)));
+		}
+		timings.push((class, start.elapsed().as_nanos() as f64));
+	}
+
+	// Crop the outliers caused by interrupts and preemption, as dudect does.
+	let mut sorted = timings.iter().map(|&(_, t)| t).collect::<Vec<_>>();
+	sorted.sort_by(f64::total_cmp);
+	let cutoff = sorted[sorted.len() * 9 / 10];
+	for (class, t) in timings {
+		if t <= cutoff {
+			samples[class].push(t);
+		}
+	}
+
+	welch_t(&samples[0], &samples[1])
+}
+
+fn assert_constant_time<T: Copy>(
+	fixed: T,
+	random: impl FnMut(&mut StdRng) -> T,
+	op: impl Fn(T) -> T,
+) {
+	let t = measure(fixed, random, op);
+	assert!(t.abs() < T_THRESHOLD, "timing leak detected: |t| = {:.2}", t.abs());
+}
+
+#[test]
+#[ignore = "timing measurement, run with --release"]
+fn test_ct_mul_128b() {
+	let rhs = BinaryField128b::new(0x1234_5678_9abc_def0_0fed_cba9_8765_4321);
+	assert_constant_time(
+		BinaryField128b::ZERO,
+		|rng| BinaryField128b::new(rng.random()),
+		|x| x.ct_mul(rhs),
+	);
+}
+
+#[test]
+#[ignore = "timing measurement, run with --release"]
+fn test_ct_invert_32b() {
+	assert_constant_time(
+		BinaryField32b::ZERO,
+		|rng| BinaryField32b::new(rng.random()),
+		|x| x.ct_invert_or_zero(),
+	);
+}
+
+#[test]
+#[ignore = "timing measurement, run with --release"]
+fn test_ct_invert_packed_8b() {
+	assert_constant_time(
+		PackedBinaryField16x8b::zero(),
+		|rng| PackedBinaryField16x8b::random(rng),
+		|x| x.ct_invert_or_zero(),
+	);
+}
+
+#[test]
+#[ignore = "timing measurement, run with --release"]
+fn test_ct_square_packed_32b() {
+	assert_constant_time(
+		PackedBinaryField4x32b::one(),
+		|rng| PackedBinaryField4x32b::random(rng),
+		|x| x.ct_square(),
+	);
+}
+
+#[test]
+#[ignore = "timing measurement, run with --release"]
+fn test_conditional_select_packed_32b() {
+	let other = PackedBinaryField4x32b::one();
+	assert_constant_time(
+		(PackedBinaryField4x32b::zero(), 0u8),
+		|rng| (PackedBinaryField4x32b::random(&mut *rng), rng.random::<u8>() & 1),
+		|(x, choice)| {
+			(PackedBinaryField4x32b::conditional_select(&x, &other, Choice::from(choice)), choice)
+		},
+	);
+}
@@ -7,11 +7,12 @@
 use std::iter;
 
 use binius_field::{
-	Field, PackedExtension, PackedField, PackedSubfield, packed::get_packed_slice_checked,
+	Field, PackedExtension, PackedField, PackedSubfield, arch::dispatch::Dispatcher,
+	packed::get_packed_slice_checked,
 };
 use binius_math::{
 	CompositionPoly, EvaluationOrder, MultilinearPoly, MultilinearQuery, MultilinearQueryRef,
-	RowsBatchRef, extrapolate_lines,
+	RowsBatchRef,
 };
 use binius_maybe_rayon::prelude::*;
 use binius_utils::bail;
@@ -190,114 +191,127 @@ where
 	}
 
 	let index_vars = n_vars - 1 - subcube_vars;
+	let dispatcher = Dispatcher::get();
 	let packed_accumulators = (0..1 << index_vars)
 		.into_par_iter()
 		.try_fold(
 			|| ParFoldStates::new(&access, n_multilinears, n_round_evals.clone(), subcube_vars),
 			|mut par_fold_states, subcube_index| {
-				let ParFoldStates {
-					multilinear_evals,
-					scratch_space,
-					round_evals,
-				} = &mut par_fold_states;
-
-				for (multilinear, evals, &subcube_count) in
-					izip!(multilinears, multilinear_evals.iter_mut(), &subcube_count_by_multilinear)
-				{
-					if subcube_index < subcube_count {
-						access.subcube_evaluations(
-							multilinear,
-							subcube_vars,
-							subcube_index,
-							index_vars,
-							tensor_query,
-							scratch_space.as_deref_mut(),
-							&mut evals.evals_0,
-							&mut evals.evals_1,
-						)?;
+				dispatcher.run(|dispatcher| {
+					let ParFoldStates {
+						multilinear_evals,
+						scratch_space,
+						round_evals,
+					} = &mut par_fold_states;
+
+					for (multilinear, evals, &subcube_count) in izip!(
+						multilinears,
+						multilinear_evals.iter_mut(),
+						&subcube_count_by_multilinear
+					) {
+						if subcube_index < subcube_count {
+							access.subcube_evaluations(
+								multilinear,
+								subcube_vars,
+								subcube_index,
+								index_vars,
+								tensor_query,
+								scratch_space.as_deref_mut(),
+								&mut evals.evals_0,
+								&mut evals.evals_1,
+							)?;
+						}
 					}
-				}
 
-				// Proceed by evaluation point first to share interpolation work between evaluators.
-				for eval_point_index in eval_point_indices.clone() {
-					// Infinity point requires special evaluation rules
-					let is_infinity_point = eval_point_index == 2;
-
-					// Multilinears are evaluated at a point t via linear interpolation:
-					//   f(z, xs) = f(0, xs) + z * (f(1, xs) - f(0, xs))
-					// The first three points are treated specially:
-					//   index 0 - z = 0   => f(z, xs) = f(0, xs)
-					//   index 1 - z = 1   => f(z, xs) = f(1, xs)
-					//   index 2 = z = inf => f(inf, xs) = high (f(0, xs) + z * (f(1, xs) - f(0,
-					// xs))) =                                   = f(1, xs) - f(0, xs)
-					//   index 3 and above - remaining finite evaluation points
-					let evals_z_iter =
-						izip!(multilinear_evals.iter_mut(), &subcube_count_by_multilinear).map(
-							|(evals, &subcube_count)| match eval_point_index {
-								// This multilinear is not accessed, return arbitrary slice
-								_ if subcube_index >= subcube_count => evals.evals_0.as_slice(),
-								0 => evals.evals_0.as_slice(),
-								1 => evals.evals_1.as_slice(),
-								2 => {
-									// infinity point
-									izip!(&mut evals.evals_z, &evals.evals_0, &evals.evals_1)
-										.for_each(|(eval_z, &eval_0, &eval_1)| {
-											*eval_z = eval_1 - eval_0;
-										});
-
-									evals.evals_z.as_slice()
-								}
-								3.. => {
-									// Account for the gap occupied by the 0, 1 & infinity point
-									let eval_point =
-										nontrivial_evaluation_points[eval_point_index - 3];
-									let eval_point_broadcast =
-										<PackedSubfield<P, FDomain>>::broadcast(eval_point);
-
-									izip!(&mut evals.evals_z, &evals.evals_0, &evals.evals_1)
-										.for_each(|(eval_z, &eval_0, &eval_1)| {
-											// This is logically the same as calling
-											// `binius_math::univariate::extrapolate_line`, except
-											// that we do not repeat the broadcast of the
-											// subfield element to a packed subfield.
-											*eval_z = P::cast_ext(extrapolate_lines(
-												P::cast_base(eval_0),
-												P::cast_base(eval_1),
-												eval_point_broadcast,
-											));
-										});
-
-									evals.evals_z.as_slice()
-								}
-							},
-						);
+					// Proceed by evaluation point first to share interpolation work between
+					// evaluators.
+					for eval_point_index in eval_point_indices.clone() {
+						// Infinity point requires special evaluation rules
+						let is_infinity_point = eval_point_index == 2;
+
+						// Multilinears are evaluated at a point t via linear interpolation:
+						//   f(z, xs) = f(0, xs) + z * (f(1, xs) - f(0, xs))
+						// The first three points are treated specially:
+						//   index 0 - z = 0   => f(z, xs) = f(0, xs)
+						//   index 1 - z = 1   => f(z, xs) = f(1, xs)
+						//   index 2 = z = inf => f(inf, xs) = high (f(0, xs) + z * (f(1, xs) - f(0,
+						// xs))) =                                   = f(1, xs) - f(0, xs)
+						//   index 3 and above - remaining finite evaluation points
+						let evals_z_iter = izip!(
+							multilinear_evals.iter_mut(),
+							&subcube_count_by_multilinear
+						)
+						.map(|(evals, &subcube_count)| match eval_point_index {
+							// This multilinear is not accessed, return arbitrary slice
+							_ if subcube_index >= subcube_count => evals.evals_0.as_slice(),
+							0 => evals.evals_0.as_slice(),
+							1 => evals.evals_1.as_slice(),
+							2 => {
+								// infinity point
+								izip!(&mut evals.evals_z, &evals.evals_0, &evals.evals_1).for_each(
+									|(eval_z, &eval_0, &eval_1)| {
+										*eval_z = eval_1 - eval_0;
+									},
+								);
 
-					let row_len = 1 << subcube_vars.saturating_sub(P::LOG_WIDTH);
-					stackalloc_with_iter(n_multilinears, evals_z_iter, |evals_z| {
-						let evals_z = RowsBatchRef::new(evals_z, row_len);
-
-						for (evaluator, round_evals, &subcube_count) in
-							izip!(evaluators, round_evals.iter_mut(), &subcube_count_by_evaluator)
-						{
-							let eval_point_indices = evaluator.eval_point_indices();
-							if !eval_point_indices.contains(&eval_point_index)
-								|| subcube_index >= subcube_count
-							{
-								continue;
+								evals.evals_z.as_slice()
 							}
-
-							round_evals[eval_point_index - eval_point_indices.start] += evaluator
-								.process_subcube_at_eval_point(
-									subcube_vars,
-									subcube_index,
-									is_infinity_point,
-									&evals_z,
+							3.. => {
+								// Account for the gap occupied by the 0, 1 & infinity point
+								let eval_point = nontrivial_evaluation_points[eval_point_index - 3];
+								let eval_point_broadcast =
+									<PackedSubfield<P, FDomain>>::broadcast(eval_point);
+
+								izip!(&mut evals.evals_z, &evals.evals_0, &evals.evals_1).for_each(
+									|(eval_z, &eval_0, &eval_1)| {
+										// This is logically the same as calling
+										// `binius_math::univariate::extrapolate_line`,
+										// except that we do not repeat the
+										// broadcast of the subfield element to
+										// a packed subfield.
+										let eval_0 = P::cast_base(eval_0);
+										let eval_1 = P::cast_base(eval_1);
+										*eval_z = P::cast_ext(
+											eval_0
+												+ dispatcher
+													.mul(eval_1 - eval_0, eval_point_broadcast),
+										);
+									},
 								);
-						}
-					});
-				}
 
-				Ok(par_fold_states)
+								evals.evals_z.as_slice()
+							}
+						});
+
+						let row_len = 1 << subcube_vars.saturating_sub(P::LOG_WIDTH);
+						stackalloc_with_iter(n_multilinears, evals_z_iter, |evals_z| {
+							let evals_z = RowsBatchRef::new(evals_z, row_len);
+
+							for (evaluator, round_evals, &subcube_count) in izip!(
+								evaluators,
+								round_evals.iter_mut(),
+								&subcube_count_by_evaluator
+							) {
+								let eval_point_indices = evaluator.eval_point_indices();
+								if !eval_point_indices.contains(&eval_point_index)
+									|| subcube_index >= subcube_count
+								{
+									continue;
+								}
+
+								round_evals[eval_point_index - eval_point_indices.start] +=
+									evaluator.process_subcube_at_eval_point(
+										subcube_vars,
+										subcube_index,
+										is_infinity_point,
+										&evals_z,
+									);
+							}
+						});
+					}
+
+					Ok(par_fold_states)
+				})
 			},
 		)
 		.map(|states: Result<ParFoldStates<P>, Error>| -> Result<_, Error> {
@@ -6,7 +6,9 @@ use binius_field::{BinaryField8b, Field};
 use binius_hash::{
 	VisionHasherDigest, VisionHasherDigestByteSliced,
 	groestl::{Groestl256, Groestl256Parallel},
+	keccak::{Keccak256, Keccak256Parallel},
 	multi_digest::{MultiDigest, ParallelDigest},
+	sha2::Sha256Parallel,
 };
 use binius_maybe_rayon::{iter::IntoParallelRefIterator, prelude::ParallelIterator};
 use criterion::{Criterion, Throughput, criterion_group, criterion_main};
@@ -58,6 +60,58 @@ fn bench_groestl(c: &mut Criterion) {
 	group.finish()
 }
 
+fn bench_sha256(c: &mut Criterion) {
+	let mut group = c.benchmark_group("SHA-256");
+
+	let mut rng = rand::rng();
+
+	const N: usize = 1 << 16;
+	let mut data = vec![0u8; N];
+	rng.fill_bytes(&mut data);
+	group.throughput(Throughput::Bytes(N as u64));
+	group.bench_function("Sha256", |bench| bench.iter(|| <sha2::Sha256 as Digest>::digest(&data)));
+
+	const PARALLEL_INSTANCES: usize = 64;
+	let mut multi_digest = [MaybeUninit::<GenericArray<u8, U32>>::uninit(); PARALLEL_INSTANCES];
+	let hasher = <Sha256Parallel as ParallelDigest>::new();
+	let data = vec![vec![BinaryField8b::ZERO; N / PARALLEL_INSTANCES]; PARALLEL_INSTANCES];
+
+	group.bench_function("Sha256Parallel", |bench| {
+		bench.iter(|| {
+			let parallel_borrowed_slices = data.par_iter().map(|x| x.as_slice().iter().copied());
+			hasher.digest(parallel_borrowed_slices, &mut multi_digest)
+		})
+	});
+
+	group.finish()
+}
+
+fn bench_keccak256(c: &mut Criterion) {
+	let mut group = c.benchmark_group("Keccak-256");
+
+	let mut rng = rand::rng();
+
+	const N: usize = 1 << 16;
+	let mut data = vec![0u8; N];
+	rng.fill_bytes(&mut data);
+	group.throughput(Throughput::Bytes(N as u64));
+	group.bench_function("Keccak256", |bench| bench.iter(|| <Keccak256 as Digest>::digest(&data)));
+
+	const PARALLEL_INSTANCES: usize = 64;
+	let mut multi_digest = [MaybeUninit::<GenericArray<u8, U32>>::uninit(); PARALLEL_INSTANCES];
+	let hasher = <Keccak256Parallel as ParallelDigest>::new();
+	let data = vec![vec![BinaryField8b::ZERO; N / PARALLEL_INSTANCES]; PARALLEL_INSTANCES];
+
+	group.bench_function("Keccak256Parallel", |bench| {
+		bench.iter(|| {
+			let parallel_borrowed_slices = data.par_iter().map(|x| x.as_slice().iter().copied());
+			hasher.digest(parallel_borrowed_slices, &mut multi_digest)
+		})
+	});
+
+	group.finish()
+}
+
 fn bench_vision32(c: &mut Criterion) {
 	let mut group = c.benchmark_group("Vision Mark-32");
 
@@ -87,5 +141,5 @@ fn bench_vision32(c: &mut Criterion) {
 	group.finish()
 }
 
-criterion_group!(hash, bench_groestl, bench_vision32);
+criterion_group!(hash, bench_groestl, bench_sha256, bench_keccak256, bench_vision32);
 criterion_main!(hash);
@@ -34,3 +34,36 @@ impl PseudoCompressionFunction<Output<Groestl256>, 2> for Groestl256ByteCompress
 		*<Output<Groestl256>>::from_slice(&state_bytes[32..])
 	}
 }
+
+/// One-way compression function that compresses four 32-byte strings into a single 32-byte
+/// string.
+///
+/// The first two strings form the 64-byte chaining value and the last two the 64-byte message
+/// block of one application of the Grøstl-256 compression function $f$, described in section 3.2
+/// of the [Grøstl] specification, followed by the output transformation. In the ideal permutation
+/// model, $f$ is collision resistant up to about $2^{128}$ queries for arbitrary chaining values,
+/// so a single application compresses four Merkle tree nodes at once.
+///
+/// [Grøstl]: <https://www.groestl.info/Groestl.pdf>
+impl PseudoCompressionFunction<Output<Groestl256>, 4> for Groestl256ByteCompression {
+	fn compress(&self, input: [Output<Groestl256>; 4]) -> Output<Groestl256> {
+		let mut chaining_bytes = [0u8; 64];
+		let mut message_bytes = [0u8; 64];
+		for (chunk, digest) in chaining_bytes
+			.chunks_exact_mut(32)
+			.chain(message_bytes.chunks_exact_mut(32))
+			.zip(&input)
+		{
+			chunk.copy_from_slice(digest);
+		}
+
+		let mut state = GroestlShortImpl::state_from_bytes(&chaining_bytes);
+		GroestlShortImpl::compress(&mut state, &message_bytes);
+
+		let input = state;
+		GroestlShortImpl::p_perm(&mut state);
+		GroestlShortImpl::xor_state(&mut state, &input);
+		let state_bytes = GroestlShortImpl::state_to_bytes(&state);
+		*<Output<Groestl256>>::from_slice(&state_bytes[32..])
+	}
+}
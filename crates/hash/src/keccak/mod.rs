@@ -0,0 +1,35 @@
+// Copyright 2025 Irreducible Inc.
+
+//! The Keccak-256 hash function, as used by Ethereum, and compression functions based on it.
+
+use digest::{Digest, Output};
+pub use sha3::Keccak256;
+
+use crate::{CompressionFunction, PseudoCompressionFunction};
+
+mod multi_digest;
+#[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+mod simd;
+
+pub use multi_digest::{Keccak256Multi, Keccak256Parallel, Keccak256x4, Keccak256x8};
+
+/// Compression function that hashes the concatenation of 32-byte Keccak-256 digests.
+///
+/// The 2-to-1 compression is `keccak256(left || right)`, which is the node hash of the Merkle
+/// trees commonly verified on Ethereum, so commitments made with it can be checked by existing
+/// Solidity verifiers. Up to four digests fit into a single block of the sponge and cost one
+/// Keccak-f permutation.
+#[derive(Debug, Default, Clone)]
+pub struct Keccak256Compression;
+
+impl<const N: usize> PseudoCompressionFunction<Output<Keccak256>, N> for Keccak256Compression {
+	fn compress(&self, input: [Output<Keccak256>; N]) -> Output<Keccak256> {
+		let mut hasher = Keccak256::new();
+		for digest in &input {
+			hasher.update(digest);
+		}
+		hasher.finalize()
+	}
+}
+
+impl<const N: usize> CompressionFunction<Output<Keccak256>, N> for Keccak256Compression {}
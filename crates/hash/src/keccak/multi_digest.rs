@@ -0,0 +1,223 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{array, cmp::min, mem::MaybeUninit};
+
+use cfg_if::cfg_if;
+use digest::Output;
+
+use super::Keccak256;
+use crate::multi_digest::MultiDigest;
+
+/// The number of state words in Keccak-f[1600].
+pub(super) const STATE_WORDS: usize = 25;
+/// The rate of Keccak-256 in bytes.
+const RATE: usize = 136;
+
+/// `N` instances of Keccak-256 computed in parallel.
+///
+/// On x86_64 the Keccak-f permutations of all lanes are computed in groups of 8 or 4 with
+/// AVX-512 or AVX2 instructions respectively, depending on the target features enabled at
+/// compile time and on `N`. Otherwise every lane is permuted with the portable implementation
+/// of the `keccak` crate.
+#[derive(Debug, Clone)]
+pub struct Keccak256Multi<const N: usize> {
+	states: [[u64; STATE_WORDS]; N],
+	unfinished_blocks: [[u8; RATE]; N],
+	num_unfinished_bytes: usize,
+}
+
+pub type Keccak256x4 = Keccak256Multi<4>;
+pub type Keccak256x8 = Keccak256Multi<8>;
+
+cfg_if! {
+	if #[cfg(all(feature = "nightly_features", target_arch = "x86_64", target_feature = "avx512f"))] {
+		use crate::multi_digest::ParallelMultidigestImpl;
+		pub type Keccak256Parallel = ParallelMultidigestImpl<Keccak256x8, 8>;
+	} else if #[cfg(all(target_arch = "x86_64", target_feature = "avx2"))] {
+		use crate::multi_digest::ParallelMultidigestImpl;
+		pub type Keccak256Parallel = ParallelMultidigestImpl<Keccak256x4, 4>;
+	} else {
+		pub type Keccak256Parallel = Keccak256;
+	}
+}
+
+impl<const N: usize> Default for Keccak256Multi<N> {
+	fn default() -> Self {
+		Self {
+			states: [[0; STATE_WORDS]; N],
+			unfinished_blocks: [[0; RATE]; N],
+			num_unfinished_bytes: 0,
+		}
+	}
+}
+
+impl<const N: usize> Keccak256Multi<N> {
+	fn finalize(&mut self, out: &mut [MaybeUninit<Output<Keccak256>>; N]) {
+		// Keccak pads with a one bit, zeros and a final one bit, without the domain separation
+		// bits that SHA-3 adds.
+		for block in &mut self.unfinished_blocks {
+			block[self.num_unfinished_bytes..].fill(0);
+			block[self.num_unfinished_bytes] |= 0x01;
+			block[RATE - 1] |= 0x80;
+		}
+		absorb_blocks(&mut self.states, array::from_fn(|i| &self.unfinished_blocks[i]));
+
+		for (out, state) in out.iter_mut().zip(&self.states) {
+			let mut digest = Output::<Keccak256>::default();
+			for (bytes, word) in digest.chunks_exact_mut(8).zip(state) {
+				bytes.copy_from_slice(&word.to_le_bytes());
+			}
+			out.write(digest);
+		}
+	}
+}
+
+impl<const N: usize> MultiDigest<N> for Keccak256Multi<N> {
+	type Digest = Keccak256;
+
+	fn new() -> Self {
+		Self::default()
+	}
+
+	// Empty lanes are hashed as if they contained zeros.
+	fn update(&mut self, data: [&[u8]; N]) {
+		let len = data[0].len();
+		assert!(
+			data.iter().all(|lane| lane.len() == len || lane.is_empty()),
+			"all lanes must have the same length"
+		);
+
+		let mut offset = 0;
+		if self.num_unfinished_bytes != 0 {
+			let n = min(RATE - self.num_unfinished_bytes, len);
+			for (block, lane) in self.unfinished_blocks.iter_mut().zip(data) {
+				if !lane.is_empty() {
+					block[self.num_unfinished_bytes..self.num_unfinished_bytes + n]
+						.copy_from_slice(&lane[..n]);
+				}
+			}
+			self.num_unfinished_bytes += n;
+			offset = n;
+
+			if self.num_unfinished_bytes < RATE {
+				return;
+			}
+			absorb_blocks(&mut self.states, array::from_fn(|i| &self.unfinished_blocks[i]));
+			self.num_unfinished_bytes = 0;
+		}
+
+		const ZERO_BLOCK: [u8; RATE] = [0; RATE];
+		while offset + RATE <= len {
+			absorb_blocks(
+				&mut self.states,
+				array::from_fn(|i| match data[i] {
+					[] => &ZERO_BLOCK,
+					lane => lane[offset..offset + RATE]
+						.try_into()
+						.expect("slice has block size"),
+				}),
+			);
+			offset += RATE;
+		}
+
+		let n = len - offset;
+		for (block, lane) in self.unfinished_blocks.iter_mut().zip(data) {
+			if !lane.is_empty() {
+				block[..n].copy_from_slice(&lane[offset..]);
+			}
+		}
+		self.num_unfinished_bytes = n;
+	}
+
+	fn finalize_into(mut self, out: &mut [MaybeUninit<Output<Keccak256>>; N]) {
+		self.finalize(out);
+	}
+
+	fn finalize_into_reset(&mut self, out: &mut [MaybeUninit<Output<Keccak256>>; N]) {
+		self.finalize(out);
+		self.reset();
+	}
+
+	fn reset(&mut self) {
+		*self = Self::default();
+	}
+
+	fn digest(data: [&[u8]; N], out: &mut [MaybeUninit<Output<Keccak256>>; N]) {
+		let mut hasher = Self::default();
+		hasher.update(data);
+		hasher.finalize(out);
+	}
+}
+
+/// Absorbs one block into the state of every lane.
+fn absorb_blocks<const N: usize>(states: &mut [[u64; STATE_WORDS]; N], blocks: [&[u8; RATE]; N]) {
+	for (state, block) in states.iter_mut().zip(blocks) {
+		for (word, bytes) in state.iter_mut().zip(block.chunks_exact(8)) {
+			*word ^= u64::from_le_bytes(bytes.try_into().expect("chunk is 8 bytes"));
+		}
+	}
+
+	#[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+	if super::simd::permute(states) {
+		return;
+	}
+
+	for state in states {
+		keccak::f1600(state);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use digest::Digest;
+	use proptest::prelude::*;
+
+	use super::*;
+
+	fn check_against_reference<const N: usize>(lanes: &[Vec<u8>], split: usize) {
+		let len = lanes[0].len();
+		let split = min(split, len);
+		let mut hasher = Keccak256Multi::<N>::new();
+		hasher.update(array::from_fn(|i| &lanes[i][..split]));
+		hasher.update(array::from_fn(|i| &lanes[i][split..]));
+
+		let mut out = [MaybeUninit::uninit(); N];
+		hasher.finalize_into(&mut out);
+		for (lane, out) in lanes.iter().zip(out) {
+			assert_eq!(unsafe { out.assume_init() }, Keccak256::digest(lane));
+		}
+	}
+
+	fn lanes(max_len: usize, num_lanes: usize) -> impl Strategy<Value = Vec<Vec<u8>>> {
+		(0..=max_len).prop_flat_map(move |len| {
+			prop::collection::vec(prop::collection::vec(any::<u8>(), len), num_lanes)
+		})
+	}
+
+	proptest! {
+		#[test]
+		fn test_keccak256x4_vs_reference(lanes in lanes(500, 4), split in 0..500usize) {
+			check_against_reference::<4>(&lanes, split);
+		}
+
+		#[test]
+		fn test_keccak256x8_vs_reference(lanes in lanes(500, 8), split in 0..500usize) {
+			check_against_reference::<8>(&lanes, split);
+		}
+
+		#[test]
+		fn test_keccak256x3_vs_reference(lanes in lanes(500, 3), split in 0..500usize) {
+			check_against_reference::<3>(&lanes, split);
+		}
+	}
+
+	#[test]
+	fn test_empty_lanes_are_ignored() {
+		let data = [0xab; 300];
+		let mut out = [MaybeUninit::uninit(); 8];
+		Keccak256x8::digest(array::from_fn(|i| if i % 3 == 0 { &data[..] } else { &[] }), &mut out);
+		for out in out.iter().step_by(3) {
+			assert_eq!(unsafe { out.assume_init() }, Keccak256::digest(data));
+		}
+	}
+}
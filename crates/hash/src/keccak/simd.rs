@@ -0,0 +1,233 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Keccak-f[1600] permutation of several independent states at once, with one state per 64-bit
+//! lane of a SIMD register.
+
+use std::{arch::x86_64::*, array};
+
+use super::multi_digest::STATE_WORDS;
+
+/// The round constants of Keccak-f[1600].
+const RC: [u64; 24] = [
+	0x0000000000000001,
+	0x0000000000008082,
+	0x800000000000808a,
+	0x8000000080008000,
+	0x000000000000808b,
+	0x0000000080000001,
+	0x8000000080008081,
+	0x8000000000008009,
+	0x000000000000008a,
+	0x0000000000000088,
+	0x0000000080008009,
+	0x000000008000000a,
+	0x000000008000808b,
+	0x800000000000008b,
+	0x8000000000008089,
+	0x8000000000008003,
+	0x8000000000008002,
+	0x8000000000000080,
+	0x000000000000800a,
+	0x800000008000000a,
+	0x8000000080008081,
+	0x8000000000008080,
+	0x0000000080000001,
+	0x8000000080008008,
+];
+
+/// The rotation offsets of the ρ step, indexed by `x + 5 * y`.
+const RHO: [u32; STATE_WORDS] = [
+	0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14,
+];
+
+/// Permutes every state with the widest SIMD instructions that divide the number of states.
+///
+/// Returns `false` without doing anything if the number of states is not a multiple of any
+/// supported SIMD width.
+pub(super) fn permute<const N: usize>(states: &mut [[u64; STATE_WORDS]; N]) -> bool {
+	#[cfg(all(feature = "nightly_features", target_feature = "avx512f"))]
+	if N % 8 == 0 {
+		permute_groups::<__m512i, 8>(states);
+		return true;
+	}
+
+	if N % 4 == 0 {
+		permute_groups::<__m256i, 4>(states);
+		return true;
+	}
+
+	false
+}
+
+fn permute_groups<V: Lanes<W>, const W: usize>(states: &mut [[u64; STATE_WORDS]]) {
+	for states in states.chunks_exact_mut(W) {
+		let mut a: [V; STATE_WORDS] =
+			array::from_fn(|i| V::from_lanes(array::from_fn(|lane| states[lane][i])));
+		keccak_f(&mut a);
+		for (i, word) in a.into_iter().enumerate() {
+			for (state, word) in states.iter_mut().zip(word.to_lanes()) {
+				state[i] = word;
+			}
+		}
+	}
+}
+
+/// The Keccak-f[1600] permutation, applied independently in every lane.
+#[inline(always)]
+fn keccak_f<V: Lanes<W>, const W: usize>(a: &mut [V; STATE_WORDS]) {
+	for rc in RC {
+		// θ
+		let c: [V; 5] = array::from_fn(|x| {
+			a[x].xor(a[x + 5])
+				.xor(a[x + 10])
+				.xor(a[x + 15])
+				.xor(a[x + 20])
+		});
+		for x in 0..5 {
+			let d = c[(x + 4) % 5].xor(c[(x + 1) % 5].rotl(1));
+			for y in 0..5 {
+				a[x + 5 * y] = a[x + 5 * y].xor(d);
+			}
+		}
+
+		// ρ and π
+		let mut b = *a;
+		for x in 0..5 {
+			for y in 0..5 {
+				b[y + 5 * ((2 * x + 3 * y) % 5)] = a[x + 5 * y].rotl(RHO[x + 5 * y]);
+			}
+		}
+
+		// χ
+		for y in 0..5 {
+			for x in 0..5 {
+				a[x + 5 * y] =
+					b[x + 5 * y].xor(b[(x + 1) % 5 + 5 * y].andnot(b[(x + 2) % 5 + 5 * y]));
+			}
+		}
+
+		// ι
+		a[0] = a[0].xor(V::splat(rc));
+	}
+}
+
+/// A SIMD register holding `W` 64-bit lanes.
+trait Lanes<const W: usize>: Copy {
+	fn from_lanes(values: [u64; W]) -> Self;
+
+	fn to_lanes(self) -> [u64; W];
+
+	fn splat(value: u64) -> Self;
+
+	fn xor(self, rhs: Self) -> Self;
+
+	/// Computes `!self & rhs`.
+	fn andnot(self, rhs: Self) -> Self;
+
+	fn rotl(self, n: u32) -> Self;
+}
+
+impl Lanes<4> for __m256i {
+	#[inline(always)]
+	fn from_lanes(values: [u64; 4]) -> Self {
+		unsafe { _mm256_loadu_si256(values.as_ptr().cast()) }
+	}
+
+	#[inline(always)]
+	fn to_lanes(self) -> [u64; 4] {
+		let mut values = [0; 4];
+		unsafe { _mm256_storeu_si256(values.as_mut_ptr().cast(), self) };
+		values
+	}
+
+	#[inline(always)]
+	fn splat(value: u64) -> Self {
+		unsafe { _mm256_set1_epi64x(value as i64) }
+	}
+
+	#[inline(always)]
+	fn xor(self, rhs: Self) -> Self {
+		unsafe { _mm256_xor_si256(self, rhs) }
+	}
+
+	#[inline(always)]
+	fn andnot(self, rhs: Self) -> Self {
+		unsafe { _mm256_andnot_si256(self, rhs) }
+	}
+
+	#[inline(always)]
+	fn rotl(self, n: u32) -> Self {
+		unsafe {
+			let left = _mm256_sll_epi64(self, _mm_cvtsi32_si128(n as i32));
+			let right = _mm256_srl_epi64(self, _mm_cvtsi32_si128(64 - n as i32));
+			_mm256_or_si256(left, right)
+		}
+	}
+}
+
+#[cfg(all(feature = "nightly_features", target_feature = "avx512f"))]
+impl Lanes<8> for __m512i {
+	#[inline(always)]
+	fn from_lanes(values: [u64; 8]) -> Self {
+		unsafe { _mm512_loadu_si512(values.as_ptr().cast()) }
+	}
+
+	#[inline(always)]
+	fn to_lanes(self) -> [u64; 8] {
+		let mut values = [0; 8];
+		unsafe { _mm512_storeu_si512(values.as_mut_ptr().cast(), self) };
+		values
+	}
+
+	#[inline(always)]
+	fn splat(value: u64) -> Self {
+		unsafe { _mm512_set1_epi64(value as i64) }
+	}
+
+	#[inline(always)]
+	fn xor(self, rhs: Self) -> Self {
+		unsafe { _mm512_xor_si512(self, rhs) }
+	}
+
+	#[inline(always)]
+	fn andnot(self, rhs: Self) -> Self {
+		unsafe { _mm512_andnot_si512(self, rhs) }
+	}
+
+	#[inline(always)]
+	fn rotl(self, n: u32) -> Self {
+		unsafe { _mm512_rolv_epi64(self, _mm512_set1_epi64(n as i64)) }
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rand::{RngCore, SeedableRng, rngs::StdRng};
+
+	use super::*;
+
+	fn check_against_reference<V: Lanes<W>, const W: usize>() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let mut states: [[u64; STATE_WORDS]; W] =
+			array::from_fn(|_| array::from_fn(|_| rng.next_u64()));
+
+		let mut expected = states;
+		for state in &mut expected {
+			keccak::f1600(state);
+		}
+
+		permute_groups::<V, W>(&mut states);
+		assert_eq!(states, expected);
+	}
+
+	#[test]
+	fn test_avx2_vs_reference() {
+		check_against_reference::<__m256i, 4>();
+	}
+
+	#[cfg(all(feature = "nightly_features", target_feature = "avx512f"))]
+	#[test]
+	fn test_avx512_vs_reference() {
+		check_against_reference::<__m512i, 8>();
+	}
+}
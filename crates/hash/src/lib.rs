@@ -20,6 +20,7 @@
 
 pub mod compression;
 pub mod groestl;
+pub mod keccak;
 pub mod multi_digest;
 pub mod permutation;
 mod serialization;
@@ -6,6 +6,12 @@ use sha2::{Sha256, compress256, digest::Output};
 
 use crate::{CompressionFunction, PseudoCompressionFunction};
 
+mod multi_digest;
+#[cfg(target_arch = "x86_64")]
+mod simd;
+
+pub use multi_digest::{Sha256Multi, Sha256Parallel, Sha256x4, Sha256x8, Sha256x16};
+
 /// A two-to-one compression function for SHA-256 digests.
 #[derive(Debug, Clone)]
 pub struct Sha256Compression {
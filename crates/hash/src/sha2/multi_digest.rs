@@ -0,0 +1,229 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{array, cmp::min, mem::MaybeUninit};
+
+use cfg_if::cfg_if;
+use digest::{Output, core_api::Block};
+use sha2::{Sha256, compress256};
+
+use crate::multi_digest::{MultiDigest, ParallelMultidigestImpl};
+
+const BLOCK_SIZE: usize = 64;
+
+/// The SHA-256 initial hash value.
+const INITIAL_STATE: [u32; 8] = [
+	0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// `N` instances of SHA-256 computed in parallel.
+///
+/// On x86_64 the lanes are compressed in groups of 16, 8 or 4 with AVX-512, AVX2 or SSE2
+/// instructions respectively, depending on the target features enabled at compile time and on
+/// `N`. When the CPU has the SHA extensions, groups that would only be processed with SSE2 are
+/// instead compressed one lane at a time with SHA-NI, which is faster. On other architectures
+/// every lane is compressed with the portable implementation of the `sha2` crate.
+#[derive(Debug, Clone)]
+pub struct Sha256Multi<const N: usize> {
+	states: [[u32; 8]; N],
+	unfinished_blocks: [[u8; BLOCK_SIZE]; N],
+	num_unfinished_bytes: usize,
+	/// The number of bytes processed in each lane.
+	len: u64,
+}
+
+pub type Sha256x4 = Sha256Multi<4>;
+pub type Sha256x8 = Sha256Multi<8>;
+pub type Sha256x16 = Sha256Multi<16>;
+
+cfg_if! {
+	if #[cfg(all(feature = "nightly_features", target_arch = "x86_64", target_feature = "avx512f"))] {
+		pub type Sha256Parallel = ParallelMultidigestImpl<Sha256x16, 16>;
+	} else if #[cfg(all(target_arch = "x86_64", target_feature = "avx2"))] {
+		pub type Sha256Parallel = ParallelMultidigestImpl<Sha256x8, 8>;
+	} else {
+		pub type Sha256Parallel = ParallelMultidigestImpl<Sha256x4, 4>;
+	}
+}
+
+impl<const N: usize> Default for Sha256Multi<N> {
+	fn default() -> Self {
+		Self {
+			states: [INITIAL_STATE; N],
+			unfinished_blocks: [[0; BLOCK_SIZE]; N],
+			num_unfinished_bytes: 0,
+			len: 0,
+		}
+	}
+}
+
+impl<const N: usize> Sha256Multi<N> {
+	fn finalize(&mut self, out: &mut [MaybeUninit<Output<Sha256>>; N]) {
+		// Padding is a one bit, zeros up to 8 bytes before the end of a block and the message
+		// length in bits as a big-endian 64-bit integer.
+		let bit_len = self.len * 8;
+		let num_zeros = (2 * BLOCK_SIZE - 9 - self.num_unfinished_bytes) % BLOCK_SIZE;
+		let mut padding = [0u8; BLOCK_SIZE + 8];
+		padding[0] = 0x80;
+		padding[1 + num_zeros..9 + num_zeros].copy_from_slice(&bit_len.to_be_bytes());
+		self.update([&padding[..9 + num_zeros]; N]);
+		debug_assert_eq!(self.num_unfinished_bytes, 0);
+
+		for (out, state) in out.iter_mut().zip(&self.states) {
+			let mut digest = Output::<Sha256>::default();
+			for (bytes, word) in digest.chunks_exact_mut(4).zip(state) {
+				bytes.copy_from_slice(&word.to_be_bytes());
+			}
+			out.write(digest);
+		}
+	}
+}
+
+impl<const N: usize> MultiDigest<N> for Sha256Multi<N> {
+	type Digest = Sha256;
+
+	fn new() -> Self {
+		Self::default()
+	}
+
+	// Empty lanes are hashed as if they contained zeros.
+	fn update(&mut self, data: [&[u8]; N]) {
+		let len = data[0].len();
+		assert!(
+			data.iter().all(|lane| lane.len() == len || lane.is_empty()),
+			"all lanes must have the same length"
+		);
+		self.len += len as u64;
+
+		let mut offset = 0;
+		if self.num_unfinished_bytes != 0 {
+			let n = min(BLOCK_SIZE - self.num_unfinished_bytes, len);
+			for (block, lane) in self.unfinished_blocks.iter_mut().zip(data) {
+				if !lane.is_empty() {
+					block[self.num_unfinished_bytes..self.num_unfinished_bytes + n]
+						.copy_from_slice(&lane[..n]);
+				}
+			}
+			self.num_unfinished_bytes += n;
+			offset = n;
+
+			if self.num_unfinished_bytes < BLOCK_SIZE {
+				return;
+			}
+			compress_blocks(&mut self.states, array::from_fn(|i| &self.unfinished_blocks[i]));
+			self.num_unfinished_bytes = 0;
+		}
+
+		const ZERO_BLOCK: [u8; BLOCK_SIZE] = [0; BLOCK_SIZE];
+		while offset + BLOCK_SIZE <= len {
+			compress_blocks(
+				&mut self.states,
+				array::from_fn(|i| match data[i] {
+					[] => &ZERO_BLOCK,
+					lane => lane[offset..offset + BLOCK_SIZE]
+						.try_into()
+						.expect("slice has block size"),
+				}),
+			);
+			offset += BLOCK_SIZE;
+		}
+
+		let n = len - offset;
+		for (block, lane) in self.unfinished_blocks.iter_mut().zip(data) {
+			if !lane.is_empty() {
+				block[..n].copy_from_slice(&lane[offset..]);
+			}
+		}
+		self.num_unfinished_bytes = n;
+	}
+
+	fn finalize_into(mut self, out: &mut [MaybeUninit<Output<Sha256>>; N]) {
+		self.finalize(out);
+	}
+
+	fn finalize_into_reset(&mut self, out: &mut [MaybeUninit<Output<Sha256>>; N]) {
+		self.finalize(out);
+		self.reset();
+	}
+
+	fn reset(&mut self) {
+		*self = Self::default();
+	}
+
+	fn digest(data: [&[u8]; N], out: &mut [MaybeUninit<Output<Sha256>>; N]) {
+		let mut hasher = Self::default();
+		hasher.update(data);
+		hasher.finalize(out);
+	}
+}
+
+/// Applies the SHA-256 compression function to one block in every lane.
+fn compress_blocks<const N: usize>(states: &mut [[u32; 8]; N], blocks: [&[u8; BLOCK_SIZE]; N]) {
+	#[cfg(target_arch = "x86_64")]
+	if super::simd::compress_blocks(states, &blocks) {
+		return;
+	}
+
+	for (state, block) in states.iter_mut().zip(blocks) {
+		compress256(state, &[Block::<Sha256>::from(*block)]);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use digest::Digest;
+	use proptest::prelude::*;
+
+	use super::*;
+
+	fn check_against_reference<const N: usize>(lanes: &[Vec<u8>], split: usize) {
+		let len = lanes[0].len();
+		let split = min(split, len);
+		let mut hasher = Sha256Multi::<N>::new();
+		hasher.update(array::from_fn(|i| &lanes[i][..split]));
+		hasher.update(array::from_fn(|i| &lanes[i][split..]));
+
+		let mut out = [MaybeUninit::uninit(); N];
+		hasher.finalize_into(&mut out);
+		for (lane, out) in lanes.iter().zip(out) {
+			assert_eq!(unsafe { out.assume_init() }, Sha256::digest(lane));
+		}
+	}
+
+	fn lanes(max_len: usize, num_lanes: usize) -> impl Strategy<Value = Vec<Vec<u8>>> {
+		(0..=max_len).prop_flat_map(move |len| {
+			prop::collection::vec(prop::collection::vec(any::<u8>(), len), num_lanes)
+		})
+	}
+
+	proptest! {
+		#[test]
+		fn test_sha256x4_vs_reference(lanes in lanes(300, 4), split in 0..300usize) {
+			check_against_reference::<4>(&lanes, split);
+		}
+
+		#[test]
+		fn test_sha256x8_vs_reference(lanes in lanes(300, 8), split in 0..300usize) {
+			check_against_reference::<8>(&lanes, split);
+		}
+
+		#[test]
+		fn test_sha256x16_vs_reference(lanes in lanes(300, 16), split in 0..300usize) {
+			check_against_reference::<16>(&lanes, split);
+		}
+
+		#[test]
+		fn test_sha256x3_vs_reference(lanes in lanes(300, 3), split in 0..300usize) {
+			check_against_reference::<3>(&lanes, split);
+		}
+	}
+
+	#[test]
+	fn test_empty_lanes_are_ignored() {
+		let data = [0xab; 200];
+		let mut out = [MaybeUninit::uninit(); 8];
+		Sha256x8::digest(array::from_fn(|i| if i % 3 == 0 { &data[..] } else { &[] }), &mut out);
+		for out in out.iter().step_by(3) {
+			assert_eq!(unsafe { out.assume_init() }, Sha256::digest(data));
+		}
+	}
+}
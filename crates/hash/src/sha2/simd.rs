@@ -0,0 +1,348 @@
+// Copyright 2025 Irreducible Inc.
+
+//! SHA-256 compression of several independent messages at once, with one message per 32-bit
+//! lane of a SIMD register.
+
+use std::{arch::x86_64::*, array};
+
+/// The SHA-256 round constants.
+const K: [u32; 64] = [
+	0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+	0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+	0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+	0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+	0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+	0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+	0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+	0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+/// Compresses one block per lane with the widest SIMD instructions that divide the number of
+/// lanes.
+///
+/// Returns `false` without doing anything if the lanes should rather be compressed one at a
+/// time, which is the case when only SSE2 is available but the CPU supports SHA-NI.
+pub(super) fn compress_blocks<const N: usize>(
+	states: &mut [[u32; 8]; N],
+	blocks: &[&[u8; 64]; N],
+) -> bool {
+	#[cfg(all(feature = "nightly_features", target_feature = "avx512f"))]
+	if N % 16 == 0 {
+		compress_groups::<__m512i, 16>(states, blocks);
+		return true;
+	}
+
+	#[cfg(target_feature = "avx2")]
+	if N % 8 == 0 {
+		compress_groups::<__m256i, 8>(states, blocks);
+		return true;
+	}
+
+	if N % 4 == 0 && !is_x86_feature_detected!("sha") {
+		compress_groups::<__m128i, 4>(states, blocks);
+		return true;
+	}
+
+	false
+}
+
+fn compress_groups<V: Lanes<W>, const W: usize>(states: &mut [[u32; 8]], blocks: &[&[u8; 64]]) {
+	for (states, blocks) in states.chunks_exact_mut(W).zip(blocks.chunks_exact(W)) {
+		compress::<V, W>(states, blocks);
+	}
+}
+
+/// The SHA-256 compression function, applied independently in every lane.
+#[inline(always)]
+fn compress<V: Lanes<W>, const W: usize>(states: &mut [[u32; 8]], blocks: &[&[u8; 64]]) {
+	// The message schedule is kept in a rolling window of the last 16 words.
+	let mut w: [V; 16] = array::from_fn(|t| {
+		V::from_lanes(array::from_fn(|lane| {
+			u32::from_be_bytes(blocks[lane][4 * t..4 * t + 4].try_into().expect("4 bytes"))
+		}))
+	});
+	let initial: [V; 8] = array::from_fn(|j| V::from_lanes(array::from_fn(|lane| states[lane][j])));
+
+	let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = initial;
+	for (t, &k) in K.iter().enumerate() {
+		if t >= 16 {
+			let s0 = w[(t + 1) % 16]
+				.rotr(7)
+				.xor(w[(t + 1) % 16].rotr(18))
+				.xor(w[(t + 1) % 16].shr(3));
+			let s1 = w[(t + 14) % 16]
+				.rotr(17)
+				.xor(w[(t + 14) % 16].rotr(19))
+				.xor(w[(t + 14) % 16].shr(10));
+			w[t % 16] = w[t % 16].add(s0).add(w[(t + 9) % 16]).add(s1);
+		}
+
+		let big_s1 = e.rotr(6).xor(e.rotr(11)).xor(e.rotr(25));
+		let ch = e.and(f).xor(e.andnot(g));
+		let t1 = h.add(big_s1).add(ch).add(V::splat(k)).add(w[t % 16]);
+		let big_s0 = a.rotr(2).xor(a.rotr(13)).xor(a.rotr(22));
+		let maj = a.and(b).or(c.and(a.or(b)));
+		let t2 = big_s0.add(maj);
+
+		h = g;
+		g = f;
+		f = e;
+		e = d.add(t1);
+		d = c;
+		c = b;
+		b = a;
+		a = t1.add(t2);
+	}
+
+	for (j, (initial, word)) in initial
+		.into_iter()
+		.zip([a, b, c, d, e, f, g, h])
+		.enumerate()
+	{
+		for (state, word) in states.iter_mut().zip(initial.add(word).to_lanes()) {
+			state[j] = word;
+		}
+	}
+}
+
+/// A SIMD register holding `W` 32-bit lanes.
+trait Lanes<const W: usize>: Copy {
+	fn from_lanes(values: [u32; W]) -> Self;
+
+	fn to_lanes(self) -> [u32; W];
+
+	fn splat(value: u32) -> Self;
+
+	fn add(self, rhs: Self) -> Self;
+
+	fn xor(self, rhs: Self) -> Self;
+
+	fn and(self, rhs: Self) -> Self;
+
+	fn or(self, rhs: Self) -> Self;
+
+	/// Computes `!self & rhs`.
+	fn andnot(self, rhs: Self) -> Self;
+
+	fn shr(self, n: u32) -> Self;
+
+	fn shl(self, n: u32) -> Self;
+
+	#[inline(always)]
+	fn rotr(self, n: u32) -> Self {
+		self.shr(n).or(self.shl(32 - n))
+	}
+}
+
+impl Lanes<4> for __m128i {
+	#[inline(always)]
+	fn from_lanes(values: [u32; 4]) -> Self {
+		unsafe { _mm_loadu_si128(values.as_ptr().cast()) }
+	}
+
+	#[inline(always)]
+	fn to_lanes(self) -> [u32; 4] {
+		let mut values = [0; 4];
+		unsafe { _mm_storeu_si128(values.as_mut_ptr().cast(), self) };
+		values
+	}
+
+	#[inline(always)]
+	fn splat(value: u32) -> Self {
+		unsafe { _mm_set1_epi32(value as i32) }
+	}
+
+	#[inline(always)]
+	fn add(self, rhs: Self) -> Self {
+		unsafe { _mm_add_epi32(self, rhs) }
+	}
+
+	#[inline(always)]
+	fn xor(self, rhs: Self) -> Self {
+		unsafe { _mm_xor_si128(self, rhs) }
+	}
+
+	#[inline(always)]
+	fn and(self, rhs: Self) -> Self {
+		unsafe { _mm_and_si128(self, rhs) }
+	}
+
+	#[inline(always)]
+	fn or(self, rhs: Self) -> Self {
+		unsafe { _mm_or_si128(self, rhs) }
+	}
+
+	#[inline(always)]
+	fn andnot(self, rhs: Self) -> Self {
+		unsafe { _mm_andnot_si128(self, rhs) }
+	}
+
+	#[inline(always)]
+	fn shr(self, n: u32) -> Self {
+		unsafe { _mm_srl_epi32(self, _mm_cvtsi32_si128(n as i32)) }
+	}
+
+	#[inline(always)]
+	fn shl(self, n: u32) -> Self {
+		unsafe { _mm_sll_epi32(self, _mm_cvtsi32_si128(n as i32)) }
+	}
+}
+
+#[cfg(target_feature = "avx2")]
+impl Lanes<8> for __m256i {
+	#[inline(always)]
+	fn from_lanes(values: [u32; 8]) -> Self {
+		unsafe { _mm256_loadu_si256(values.as_ptr().cast()) }
+	}
+
+	#[inline(always)]
+	fn to_lanes(self) -> [u32; 8] {
+		let mut values = [0; 8];
+		unsafe { _mm256_storeu_si256(values.as_mut_ptr().cast(), self) };
+		values
+	}
+
+	#[inline(always)]
+	fn splat(value: u32) -> Self {
+		unsafe { _mm256_set1_epi32(value as i32) }
+	}
+
+	#[inline(always)]
+	fn add(self, rhs: Self) -> Self {
+		unsafe { _mm256_add_epi32(self, rhs) }
+	}
+
+	#[inline(always)]
+	fn xor(self, rhs: Self) -> Self {
+		unsafe { _mm256_xor_si256(self, rhs) }
+	}
+
+	#[inline(always)]
+	fn and(self, rhs: Self) -> Self {
+		unsafe { _mm256_and_si256(self, rhs) }
+	}
+
+	#[inline(always)]
+	fn or(self, rhs: Self) -> Self {
+		unsafe { _mm256_or_si256(self, rhs) }
+	}
+
+	#[inline(always)]
+	fn andnot(self, rhs: Self) -> Self {
+		unsafe { _mm256_andnot_si256(self, rhs) }
+	}
+
+	#[inline(always)]
+	fn shr(self, n: u32) -> Self {
+		unsafe { _mm256_srl_epi32(self, _mm_cvtsi32_si128(n as i32)) }
+	}
+
+	#[inline(always)]
+	fn shl(self, n: u32) -> Self {
+		unsafe { _mm256_sll_epi32(self, _mm_cvtsi32_si128(n as i32)) }
+	}
+}
+
+#[cfg(all(feature = "nightly_features", target_feature = "avx512f"))]
+impl Lanes<16> for __m512i {
+	#[inline(always)]
+	fn from_lanes(values: [u32; 16]) -> Self {
+		unsafe { _mm512_loadu_si512(values.as_ptr().cast()) }
+	}
+
+	#[inline(always)]
+	fn to_lanes(self) -> [u32; 16] {
+		let mut values = [0; 16];
+		unsafe { _mm512_storeu_si512(values.as_mut_ptr().cast(), self) };
+		values
+	}
+
+	#[inline(always)]
+	fn splat(value: u32) -> Self {
+		unsafe { _mm512_set1_epi32(value as i32) }
+	}
+
+	#[inline(always)]
+	fn add(self, rhs: Self) -> Self {
+		unsafe { _mm512_add_epi32(self, rhs) }
+	}
+
+	#[inline(always)]
+	fn xor(self, rhs: Self) -> Self {
+		unsafe { _mm512_xor_si512(self, rhs) }
+	}
+
+	#[inline(always)]
+	fn and(self, rhs: Self) -> Self {
+		unsafe { _mm512_and_si512(self, rhs) }
+	}
+
+	#[inline(always)]
+	fn or(self, rhs: Self) -> Self {
+		unsafe { _mm512_or_si512(self, rhs) }
+	}
+
+	#[inline(always)]
+	fn andnot(self, rhs: Self) -> Self {
+		unsafe { _mm512_andnot_si512(self, rhs) }
+	}
+
+	#[inline(always)]
+	fn shr(self, n: u32) -> Self {
+		unsafe { _mm512_srl_epi32(self, _mm_cvtsi32_si128(n as i32)) }
+	}
+
+	#[inline(always)]
+	fn shl(self, n: u32) -> Self {
+		unsafe { _mm512_sll_epi32(self, _mm_cvtsi32_si128(n as i32)) }
+	}
+
+	#[inline(always)]
+	fn rotr(self, n: u32) -> Self {
+		unsafe { _mm512_rorv_epi32(self, _mm512_set1_epi32(n as i32)) }
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use digest::core_api::Block;
+	use rand::{RngCore, SeedableRng, rngs::StdRng};
+	use sha2::{Sha256, compress256};
+
+	use super::*;
+
+	fn check_against_reference<V: Lanes<W>, const W: usize>() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let mut states: [[u32; 8]; W] = array::from_fn(|_| array::from_fn(|_| rng.next_u32()));
+		let blocks: [[u8; 64]; W] = array::from_fn(|_| {
+			let mut block = [0; 64];
+			rng.fill_bytes(&mut block);
+			block
+		});
+
+		let mut expected = states;
+		for (state, block) in expected.iter_mut().zip(&blocks) {
+			compress256(state, &[Block::<Sha256>::from(*block)]);
+		}
+
+		compress_groups::<V, W>(&mut states, &array::from_fn::<_, W, _>(|i| &blocks[i]));
+		assert_eq!(states, expected);
+	}
+
+	#[test]
+	fn test_sse2_vs_reference() {
+		check_against_reference::<__m128i, 4>();
+	}
+
+	#[cfg(target_feature = "avx2")]
+	#[test]
+	fn test_avx2_vs_reference() {
+		check_against_reference::<__m256i, 8>();
+	}
+
+	#[cfg(all(feature = "nightly_features", target_feature = "avx512f"))]
+	#[test]
+	fn test_avx512_vs_reference() {
+		check_against_reference::<__m512i, 16>();
+	}
+}
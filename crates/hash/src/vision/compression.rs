@@ -5,15 +5,20 @@ use digest::{Digest, Output};
 use super::digest::VisionHasherDigest;
 use crate::PseudoCompressionFunction;
 
-/// One-way compression function that compresses two 32-byte strings into a single 32-byte string.
+/// One-way compression function that compresses `N` 32-byte strings into a single 32-byte string.
+///
+/// The strings are concatenated and hashed with [`VisionHasherDigest`]. The 2-to-1 and 4-to-1
+/// instances are used for binary and 4-ary Merkle trees.
 #[derive(Debug, Default, Clone)]
 pub struct Vision32Compression;
 
-impl PseudoCompressionFunction<Output<VisionHasherDigest>, 2> for Vision32Compression {
-	fn compress(&self, input: [Output<VisionHasherDigest>; 2]) -> Output<VisionHasherDigest> {
-		VisionHasherDigest::new()
-			.chain_update(input[0].as_slice())
-			.chain_update(input[1].as_slice())
+impl<const N: usize> PseudoCompressionFunction<Output<VisionHasherDigest>, N>
+	for Vision32Compression
+{
+	fn compress(&self, input: [Output<VisionHasherDigest>; N]) -> Output<VisionHasherDigest> {
+		input
+			.iter()
+			.fold(VisionHasherDigest::new(), |hasher, digest| hasher.chain_update(digest))
 			.finalize()
 	}
 }
@@ -2,18 +2,24 @@
 
 //! Utilities for testing M3 constraint systems and gadgets.
 use anyhow::Result;
-use binius_compute::ComputeHolder;
-use binius_core::{constraint_system::channel::Boundary, fiat_shamir::HasherChallenger};
+use binius_compute::{ComputeHolder, cpu::alloc::CpuComputeAllocator};
+use binius_core::{
+	constraint_system::{Proof, channel::Boundary, error::Error},
+	fiat_shamir::HasherChallenger,
+	merkle_tree::{BinaryMerkleTreeProver, BinaryMerkleTreeScheme},
+	piop::{FRIPCS, FRIPCSProver, MultilinearPCS, MultilinearPCSProver},
+};
 use binius_fast_compute::layer::FastCpuLayerHolder;
 use binius_field::{
 	BinaryField128bPolyval, PackedField, PackedFieldIndexable, TowerField,
 	as_packed_field::{PackScalar, PackedType},
 	linear_transformation::PackedTransformationFactory,
-	tower::CanonicalTowerFamily,
+	tower::{CanonicalTowerFamily, TowerUnderlier},
 	underlier::UnderlierType,
 };
 use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
 use binius_utils::env::boolean_env_flag_set;
+use digest::Output;
 
 use super::{
 	B1, B8, B16, B32, B64,
@@ -122,43 +128,192 @@ pub fn validate_system_witness_with_prove_verify<U>(
 	.unwrap();
 
 	if prove_verify {
-		const LOG_INV_RATE: usize = 1;
-		const SECURITY_BITS: usize = 100;
-
-		let mut compute_holder =
-			FastCpuLayerHolder::<CanonicalTowerFamily, PackedType<U, B128>>::new(1 << 16, 1 << 24);
-
 		let ccs_digest = ccs.digest::<Groestl256>();
-		let proof = binius_core::constraint_system::prove::<
+		let proof = binius_core::constraint_system::prove_with_pcs::<
 			_,
 			U,
 			CanonicalTowerFamily,
 			Groestl256,
-			Groestl256ByteCompression,
+			_,
 			HasherChallenger<Groestl256>,
 			_,
 			_,
 			_,
 		>(
-			&mut compute_holder.to_data(),
+			&mut compute_holder::<U>().to_data(),
 			&ccs,
-			LOG_INV_RATE,
-			SECURITY_BITS,
+			&fri_pcs_prover(),
 			&ccs_digest,
 			&boundaries,
 			&table_sizes,
 			witness,
 			&binius_hal::make_portable_backend(),
+			None,
 		)
 		.unwrap();
 
-		binius_core::constraint_system::verify::<
+		binius_core::constraint_system::verify_with_pcs::<
 			U,
 			CanonicalTowerFamily,
 			Groestl256,
-			Groestl256ByteCompression,
+			_,
 			HasherChallenger<Groestl256>,
-		>(&ccs, LOG_INV_RATE, SECURITY_BITS, &ccs_digest, &boundaries, proof)
+		>(&ccs, &fri_pcs(), &ccs_digest, &boundaries, proof, None)
 		.unwrap();
 	}
 }
+
+/// The log inverse rate of the codes of the commitment schemes used by the test utilities.
+pub const LOG_INV_RATE: usize = 1;
+
+/// The target security level of the commitment schemes used by the test utilities.
+pub const SECURITY_BITS: usize = 100;
+
+/// The Merkle tree scheme of the commitment schemes used by the test utilities.
+pub type MerkleScheme = BinaryMerkleTreeScheme<B128, Groestl256, Groestl256ByteCompression>;
+
+/// The Merkle tree prover of the commitment schemes used by the test utilities.
+pub type MerkleProver = BinaryMerkleTreeProver<B128, Groestl256, Groestl256ByteCompression>;
+
+/// Returns the FRI commitment scheme prover that [`binius_core::constraint_system::prove`] uses
+/// with the test parameters.
+pub fn fri_pcs_prover() -> FRIPCSProver<B128, B32, MerkleProver> {
+	FRIPCSProver::new(
+		BinaryMerkleTreeProver::new(Groestl256ByteCompression),
+		LOG_INV_RATE,
+		SECURITY_BITS,
+	)
+}
+
+/// Returns the FRI commitment scheme that [`binius_core::constraint_system::verify`] uses with the
+/// test parameters.
+pub fn fri_pcs() -> FRIPCS<B128, B32, MerkleScheme> {
+	FRIPCS::new(MerkleScheme::new(Groestl256ByteCompression), LOG_INV_RATE, SECURITY_BITS)
+}
+
+/// Returns a CPU compute holder with enough memory for the proofs of tests.
+pub fn compute_holder<U>() -> FastCpuLayerHolder<CanonicalTowerFamily, PackedType<U, B128>>
+where
+	U: UnderlierType
+		+ PackScalar<B1>
+		+ PackScalar<B8>
+		+ PackScalar<B16>
+		+ PackScalar<B32>
+		+ PackScalar<B64>
+		+ PackScalar<B128>,
+{
+	FastCpuLayerHolder::new(1 << 16, 1 << 24)
+}
+
+/// Fills a witness with `fill` and proves it with the given commitment scheme prover.
+///
+/// The proof is bound to `constraint_system_digest`, which is usually the digest of the compiled
+/// constraint system. The transcript uses Grøstl-256.
+pub fn prove_with_pcs<U, PCSProver>(
+	cs: &ConstraintSystem<B128>,
+	pcs_prover: &PCSProver,
+	constraint_system_digest: &Output<Groestl256>,
+	boundaries: &[Boundary<B128>],
+	min_security_bits: Option<usize>,
+	fill: impl FnOnce(&mut WitnessIndex<PackedType<U, B128>>) -> Result<()>,
+) -> Result<Proof, Error>
+where
+	U: UnderlierType
+		+ PackScalar<B1>
+		+ PackScalar<B8>
+		+ PackScalar<B16>
+		+ PackScalar<B32>
+		+ PackScalar<B64>
+		+ PackScalar<B128>
+		+ PackScalar<BinaryField128bPolyval>,
+	PackedType<U, B128>:
+		PackedFieldIndexable + PackedTransformationFactory<PackedType<U, BinaryField128bPolyval>>,
+	PackedType<U, BinaryField128bPolyval>: PackedTransformationFactory<PackedType<U, B128>>,
+	PCSProver: MultilinearPCSProver<PackedType<U, B128>>,
+{
+	let mut allocator = CpuComputeAllocator::new(1 << 16);
+	let allocator = allocator.into_bump_allocator();
+	let mut witness = WitnessIndex::new(cs, &allocator);
+	fill(&mut witness).unwrap();
+
+	let ccs = cs.compile().unwrap();
+	let table_sizes = witness.table_sizes();
+	binius_core::constraint_system::prove_with_pcs::<
+		_,
+		U,
+		CanonicalTowerFamily,
+		Groestl256,
+		_,
+		HasherChallenger<Groestl256>,
+		_,
+		_,
+		_,
+	>(
+		&mut compute_holder::<U>().to_data(),
+		&ccs,
+		pcs_prover,
+		constraint_system_digest,
+		boundaries,
+		&table_sizes,
+		witness.into_multilinear_extension_index(),
+		&binius_hal::make_portable_backend(),
+		min_security_bits,
+	)
+}
+
+/// Verifies a proof of [`prove_with_pcs`] with the given commitment scheme.
+pub fn verify_with_pcs<U, PCS>(
+	cs: &ConstraintSystem<B128>,
+	pcs: &PCS,
+	constraint_system_digest: &Output<Groestl256>,
+	boundaries: &[Boundary<B128>],
+	proof: Proof,
+	min_security_bits: Option<usize>,
+) -> Result<(), Error>
+where
+	U: TowerUnderlier<CanonicalTowerFamily>,
+	PCS: MultilinearPCS<B128>,
+{
+	let ccs = cs.compile().unwrap();
+	binius_core::constraint_system::verify_with_pcs::<
+		U,
+		CanonicalTowerFamily,
+		Groestl256,
+		_,
+		HasherChallenger<Groestl256>,
+	>(&ccs, pcs, constraint_system_digest, boundaries, proof, min_security_bits)
+}
+
+/// Fills a witness with `fill`, proves it with the given commitment scheme prover and verifies
+/// the proof with `pcs`, binding the proof to the digest of the constraint system.
+///
+/// The prover and the verifier both require `min_security_bits`, if it is given.
+pub fn prove_verify_with_pcs<U, PCSProver, PCS>(
+	cs: &ConstraintSystem<B128>,
+	pcs_prover: &PCSProver,
+	pcs: &PCS,
+	boundaries: &[Boundary<B128>],
+	min_security_bits: Option<usize>,
+	fill: impl FnOnce(&mut WitnessIndex<PackedType<U, B128>>) -> Result<()>,
+) -> Result<(), Error>
+where
+	U: UnderlierType
+		+ TowerUnderlier<CanonicalTowerFamily>
+		+ PackScalar<B1>
+		+ PackScalar<B8>
+		+ PackScalar<B16>
+		+ PackScalar<B32>
+		+ PackScalar<B64>
+		+ PackScalar<B128>
+		+ PackScalar<BinaryField128bPolyval>,
+	PackedType<U, B128>:
+		PackedFieldIndexable + PackedTransformationFactory<PackedType<U, BinaryField128bPolyval>>,
+	PackedType<U, BinaryField128bPolyval>: PackedTransformationFactory<PackedType<U, B128>>,
+	PCSProver: MultilinearPCSProver<PackedType<U, B128>, PCS = PCS>,
+	PCS: MultilinearPCS<B128>,
+{
+	let ccs_digest = cs.compile().unwrap().digest::<Groestl256>();
+	let proof =
+		prove_with_pcs::<U, _>(cs, pcs_prover, &ccs_digest, boundaries, min_security_bits, fill)?;
+	verify_with_pcs::<U, _>(cs, pcs, &ccs_digest, boundaries, proof, min_security_bits)
+}
@@ -1,5 +1,7 @@
 // Copyright 2025 Irreducible Inc.
 
+pub mod rv32im;
+
 use std::{collections::BTreeMap, fmt::Debug};
 
 /// A channel used to validate a high-level M3 trace.
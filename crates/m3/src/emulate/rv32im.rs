@@ -0,0 +1,970 @@
+// Copyright 2025 Irreducible Inc.
+
+//! A reference emulator for the RV32IM instruction set.
+//!
+//! Programs are given as raw little-endian instruction words loaded at a fixed base address,
+//! without any ELF parsing. The machine has a Harvard architecture: instructions are fetched from
+//! a read-only program memory, while loads and stores access a separate, word-addressed RAM
+//! region. Execution halts on `ECALL`, with the value of register `a0` as the exit code.
+//!
+//! Besides the final machine state, [`execute`] records one [`CpuEvent`] per executed instruction,
+//! including the timestamps of all register and memory accesses. This is the trace consumed by
+//! the RV32IM arithmetization in [`crate::gadgets::rv32im`], which checks register and memory
+//! consistency with offline memory checking: every access at time `t` consumes the tuple
+//! `(address, value, t_prev)` written by the previous access and produces `(address, value', t)`,
+//! with `t_prev < t`.
+
+use std::collections::BTreeMap;
+
+/// The register that writes to `x0` are redirected to.
+///
+/// Reads of `x0` read the hardwired zero register, which is never written. Redirecting writes
+/// to an extra register keeps every instruction class uniform, whether or not its destination is
+/// `x0`.
+pub const SCRATCH_REGISTER: u8 = 32;
+
+/// The number of architectural registers, plus the scratch register.
+pub const N_REGISTERS: usize = 33;
+
+/// The register holding the exit code when the machine halts.
+pub const EXIT_CODE_REGISTER: u8 = 10;
+
+/// The timestamp offset of the first source register read of an instruction.
+pub const RS1_TIMESTAMP_OFFSET: u32 = 1;
+/// The timestamp offset of the second source register read of an instruction.
+pub const RS2_TIMESTAMP_OFFSET: u32 = 2;
+/// The timestamp offset of the destination register write of an instruction.
+pub const RD_TIMESTAMP_OFFSET: u32 = 3;
+/// The timestamp offset of the RAM access of an instruction.
+pub const MEM_TIMESTAMP_OFFSET: u32 = 3;
+
+/// Returns the timestamp of an access with the given offset by the instruction at cycle `clk`.
+///
+/// Timestamp zero is reserved for the initial register and memory contents.
+pub fn timestamp(clk: u32, offset: u32) -> u32 {
+	4 * clk + offset
+}
+
+/// The flag distinguishing register-immediate ALU opcodes from their register-register variants.
+pub const IMM_FLAG: u32 = 0x100;
+
+/// The RV32IM operations.
+///
+/// The discriminants are the opcode identifiers used in the program ROM. Register-immediate ALU
+/// operations have the identifier of the corresponding register-register operation with
+/// [`IMM_FLAG`] set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u32)]
+pub enum Opcode {
+	Add = 0x00,
+	Sub = 0x01,
+	Sll = 0x02,
+	Slt = 0x03,
+	Sltu = 0x04,
+	Xor = 0x05,
+	Srl = 0x06,
+	Sra = 0x07,
+	Or = 0x08,
+	And = 0x09,
+	Mul = 0x0a,
+	Mulh = 0x0b,
+	Mulhsu = 0x0c,
+	Mulhu = 0x0d,
+	Div = 0x0e,
+	Divu = 0x0f,
+	Rem = 0x10,
+	Remu = 0x11,
+	Addi = 0x100,
+	Slli = 0x102,
+	Slti = 0x103,
+	Sltiu = 0x104,
+	Xori = 0x105,
+	Srli = 0x106,
+	Srai = 0x107,
+	Ori = 0x108,
+	Andi = 0x109,
+	Lui = 0x200,
+	Auipc = 0x201,
+	Jal = 0x202,
+	Jalr = 0x203,
+	Beq = 0x300,
+	Bne = 0x301,
+	Blt = 0x302,
+	Bge = 0x303,
+	Bltu = 0x304,
+	Bgeu = 0x305,
+	Lb = 0x400,
+	Lh = 0x401,
+	Lw = 0x402,
+	Lbu = 0x403,
+	Lhu = 0x404,
+	Sb = 0x500,
+	Sh = 0x501,
+	Sw = 0x502,
+	Ecall = 0x600,
+}
+
+/// The instruction classes, which determine the operands an instruction accesses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum InstructionClass {
+	/// `rd = rs1 op rs2`
+	AluReg,
+	/// `rd = rs1 op imm`
+	AluImm,
+	/// `rd = imm`
+	Lui,
+	/// `rd = pc + imm`
+	Auipc,
+	/// `rd = pc + 4; pc = pc + imm`
+	Jal,
+	/// `rd = pc + 4; pc = (rs1 + imm) & !1`
+	Jalr,
+	/// `if rs1 cmp rs2 { pc = pc + imm }`
+	Branch,
+	/// `rd = mem[rs1 + imm]`
+	Load,
+	/// `mem[rs1 + imm] = rs2`
+	Store,
+	/// Halts with exit code `a0`.
+	Halt,
+}
+
+impl Opcode {
+	pub const fn id(self) -> u32 {
+		self as u32
+	}
+
+	pub fn class(self) -> InstructionClass {
+		match self.id() >> 8 {
+			0 => InstructionClass::AluReg,
+			1 => InstructionClass::AluImm,
+			2 => match self {
+				Self::Lui => InstructionClass::Lui,
+				Self::Auipc => InstructionClass::Auipc,
+				Self::Jal => InstructionClass::Jal,
+				_ => InstructionClass::Jalr,
+			},
+			3 => InstructionClass::Branch,
+			4 => InstructionClass::Load,
+			5 => InstructionClass::Store,
+			_ => InstructionClass::Halt,
+		}
+	}
+
+	/// The register-register ALU operation computed by an ALU opcode.
+	///
+	/// Returns `None` for opcodes outside of the [`InstructionClass::AluReg`] and
+	/// [`InstructionClass::AluImm`] classes.
+	pub fn alu_op(self) -> Option<Self> {
+		match self.class() {
+			InstructionClass::AluReg => Some(self),
+			InstructionClass::AluImm => Some(match self {
+				Self::Addi => Self::Add,
+				Self::Slli => Self::Sll,
+				Self::Slti => Self::Slt,
+				Self::Sltiu => Self::Sltu,
+				Self::Xori => Self::Xor,
+				Self::Srli => Self::Srl,
+				Self::Srai => Self::Sra,
+				Self::Ori => Self::Or,
+				_ => Self::And,
+			}),
+			_ => None,
+		}
+	}
+
+	/// The number of bytes accessed by a load or store opcode.
+	///
+	/// Returns `None` for opcodes outside of the [`InstructionClass::Load`] and
+	/// [`InstructionClass::Store`] classes.
+	pub fn access_width(self) -> Option<u32> {
+		match self {
+			Self::Lb | Self::Lbu | Self::Sb => Some(1),
+			Self::Lh | Self::Lhu | Self::Sh => Some(2),
+			Self::Lw | Self::Sw => Some(4),
+			_ => None,
+		}
+	}
+}
+
+/// Computes the result of a register-register ALU operation.
+///
+/// Division by zero and signed overflow follow the RISC-V specification, which defines results
+/// instead of trapping.
+///
+/// ## Panics
+///
+/// Panics if `op` is not a register-register ALU opcode.
+pub fn alu(op: Opcode, a: u32, b: u32) -> u32 {
+	let (sa, sb) = (a as i32, b as i32);
+	match op {
+		Opcode::Add => a.wrapping_add(b),
+		Opcode::Sub => a.wrapping_sub(b),
+		Opcode::Sll => a << (b & 31),
+		Opcode::Slt => (sa < sb) as u32,
+		Opcode::Sltu => (a < b) as u32,
+		Opcode::Xor => a ^ b,
+		Opcode::Srl => a >> (b & 31),
+		Opcode::Sra => (sa >> (b & 31)) as u32,
+		Opcode::Or => a | b,
+		Opcode::And => a & b,
+		Opcode::Mul => a.wrapping_mul(b),
+		Opcode::Mulh => ((sa as i64 * sb as i64) >> 32) as u32,
+		Opcode::Mulhsu => ((sa as i64 * b as i64) >> 32) as u32,
+		Opcode::Mulhu => ((a as u64 * b as u64) >> 32) as u32,
+		Opcode::Div => match b {
+			0 => u32::MAX,
+			_ => sa.wrapping_div(sb) as u32,
+		},
+		Opcode::Divu => a.checked_div(b).unwrap_or(u32::MAX),
+		Opcode::Rem => match b {
+			0 => a,
+			_ => sa.wrapping_rem(sb) as u32,
+		},
+		Opcode::Remu => a.checked_rem(b).unwrap_or(a),
+		_ => panic!("{op:?} is not a register-register ALU operation"),
+	}
+}
+
+/// A decoded instruction.
+///
+/// Unused register fields are zero. The immediate is sign-extended, and for `LUI` and `AUIPC` it
+/// is already shifted into the upper 20 bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+	pub opcode: Opcode,
+	pub rd: u8,
+	pub rs1: u8,
+	pub rs2: u8,
+	pub imm: u32,
+}
+
+impl Instruction {
+	/// Decodes a raw instruction word.
+	pub fn decode(word: u32) -> Option<Self> {
+		let rd = ((word >> 7) & 0x1f) as u8;
+		let rs1 = ((word >> 15) & 0x1f) as u8;
+		let rs2 = ((word >> 20) & 0x1f) as u8;
+		let funct3 = (word >> 12) & 0x7;
+		let funct7 = word >> 25;
+
+		let imm_i = ((word as i32) >> 20) as u32;
+		let imm_s = ((((word as i32) >> 25) << 5) as u32) | ((word >> 7) & 0x1f);
+		let imm_b = ((((word as i32) >> 31) << 12) as u32)
+			| ((word << 4) & 0x800)
+			| ((word >> 20) & 0x7e0)
+			| ((word >> 7) & 0x1e);
+		let imm_u = word & 0xffff_f000;
+		let imm_j = ((((word as i32) >> 31) << 20) as u32)
+			| (word & 0xff000)
+			| ((word >> 9) & 0x800)
+			| ((word >> 20) & 0x7fe);
+
+		let instr = |opcode, rd, rs1, rs2, imm| Self {
+			opcode,
+			rd,
+			rs1,
+			rs2,
+			imm,
+		};
+
+		let decoded = match word & 0x7f {
+			0x37 => instr(Opcode::Lui, rd, 0, 0, imm_u),
+			0x17 => instr(Opcode::Auipc, rd, 0, 0, imm_u),
+			0x6f => instr(Opcode::Jal, rd, 0, 0, imm_j),
+			0x67 if funct3 == 0 => instr(Opcode::Jalr, rd, rs1, 0, imm_i),
+			0x63 => {
+				let opcode = match funct3 {
+					0 => Opcode::Beq,
+					1 => Opcode::Bne,
+					4 => Opcode::Blt,
+					5 => Opcode::Bge,
+					6 => Opcode::Bltu,
+					7 => Opcode::Bgeu,
+					_ => return None,
+				};
+				instr(opcode, 0, rs1, rs2, imm_b)
+			}
+			0x03 => {
+				let opcode = match funct3 {
+					0 => Opcode::Lb,
+					1 => Opcode::Lh,
+					2 => Opcode::Lw,
+					4 => Opcode::Lbu,
+					5 => Opcode::Lhu,
+					_ => return None,
+				};
+				instr(opcode, rd, rs1, 0, imm_i)
+			}
+			0x23 => {
+				let opcode = match funct3 {
+					0 => Opcode::Sb,
+					1 => Opcode::Sh,
+					2 => Opcode::Sw,
+					_ => return None,
+				};
+				instr(opcode, 0, rs1, rs2, imm_s)
+			}
+			0x13 => {
+				let (opcode, imm) = match (funct3, funct7) {
+					(0, _) => (Opcode::Addi, imm_i),
+					(2, _) => (Opcode::Slti, imm_i),
+					(3, _) => (Opcode::Sltiu, imm_i),
+					(4, _) => (Opcode::Xori, imm_i),
+					(6, _) => (Opcode::Ori, imm_i),
+					(7, _) => (Opcode::Andi, imm_i),
+					(1, 0x00) => (Opcode::Slli, rs2 as u32),
+					(5, 0x00) => (Opcode::Srli, rs2 as u32),
+					(5, 0x20) => (Opcode::Srai, rs2 as u32),
+					_ => return None,
+				};
+				instr(opcode, rd, rs1, 0, imm)
+			}
+			0x33 => {
+				let opcode = match (funct7, funct3) {
+					(0x00, 0) => Opcode::Add,
+					(0x20, 0) => Opcode::Sub,
+					(0x00, 1) => Opcode::Sll,
+					(0x00, 2) => Opcode::Slt,
+					(0x00, 3) => Opcode::Sltu,
+					(0x00, 4) => Opcode::Xor,
+					(0x00, 5) => Opcode::Srl,
+					(0x20, 5) => Opcode::Sra,
+					(0x00, 6) => Opcode::Or,
+					(0x00, 7) => Opcode::And,
+					(0x01, 0) => Opcode::Mul,
+					(0x01, 1) => Opcode::Mulh,
+					(0x01, 2) => Opcode::Mulhsu,
+					(0x01, 3) => Opcode::Mulhu,
+					(0x01, 4) => Opcode::Div,
+					(0x01, 5) => Opcode::Divu,
+					(0x01, 6) => Opcode::Rem,
+					(0x01, 7) => Opcode::Remu,
+					_ => return None,
+				};
+				instr(opcode, rd, rs1, rs2, 0)
+			}
+			0x73 if word == 0x73 => instr(Opcode::Ecall, 0, EXIT_CODE_REGISTER, 0, 0),
+			_ => return None,
+		};
+		Some(decoded)
+	}
+
+	/// Encodes the instruction as a raw instruction word.
+	///
+	/// This is the inverse of [`Self::decode`] and is mostly useful for writing test programs.
+	pub fn encode(&self) -> u32 {
+		let Self {
+			opcode,
+			rd,
+			rs1,
+			rs2,
+			imm,
+		} = *self;
+		let (rd, rs1, rs2) = (rd as u32, rs1 as u32, rs2 as u32);
+		let r_type = |funct7: u32, funct3: u32, opcode: u32| {
+			(funct7 << 25) | (rs2 << 20) | (rs1 << 15) | (funct3 << 12) | (rd << 7) | opcode
+		};
+		let i_type = |funct3: u32, opcode: u32| {
+			(imm << 20) | (rs1 << 15) | (funct3 << 12) | (rd << 7) | opcode
+		};
+		let s_type = |funct3: u32| {
+			((imm >> 5) << 25)
+				| (rs2 << 20)
+				| (rs1 << 15)
+				| (funct3 << 12)
+				| ((imm & 0x1f) << 7)
+				| 0x23
+		};
+		let b_type = |funct3: u32| {
+			(((imm >> 12) & 1) << 31)
+				| (((imm >> 5) & 0x3f) << 25)
+				| (rs2 << 20)
+				| (rs1 << 15)
+				| (funct3 << 12)
+				| (((imm >> 1) & 0xf) << 8)
+				| (((imm >> 11) & 1) << 7)
+				| 0x63
+		};
+
+		match opcode {
+			Opcode::Lui => (imm & 0xffff_f000) | (rd << 7) | 0x37,
+			Opcode::Auipc => (imm & 0xffff_f000) | (rd << 7) | 0x17,
+			Opcode::Jal => {
+				(((imm >> 20) & 1) << 31)
+					| (((imm >> 1) & 0x3ff) << 21)
+					| (((imm >> 11) & 1) << 20)
+					| (imm & 0xff000)
+					| (rd << 7) | 0x6f
+			}
+			Opcode::Jalr => i_type(0, 0x67),
+			Opcode::Beq => b_type(0),
+			Opcode::Bne => b_type(1),
+			Opcode::Blt => b_type(4),
+			Opcode::Bge => b_type(5),
+			Opcode::Bltu => b_type(6),
+			Opcode::Bgeu => b_type(7),
+			Opcode::Lb => i_type(0, 0x03),
+			Opcode::Lh => i_type(1, 0x03),
+			Opcode::Lw => i_type(2, 0x03),
+			Opcode::Lbu => i_type(4, 0x03),
+			Opcode::Lhu => i_type(5, 0x03),
+			Opcode::Sb => s_type(0),
+			Opcode::Sh => s_type(1),
+			Opcode::Sw => s_type(2),
+			Opcode::Addi => i_type(0, 0x13),
+			Opcode::Slti => i_type(2, 0x13),
+			Opcode::Sltiu => i_type(3, 0x13),
+			Opcode::Xori => i_type(4, 0x13),
+			Opcode::Ori => i_type(6, 0x13),
+			Opcode::Andi => i_type(7, 0x13),
+			Opcode::Slli => ((imm & 0x1f) << 20) | (rs1 << 15) | (1 << 12) | (rd << 7) | 0x13,
+			Opcode::Srli => ((imm & 0x1f) << 20) | (rs1 << 15) | (5 << 12) | (rd << 7) | 0x13,
+			Opcode::Srai => {
+				(0x20 << 25) | ((imm & 0x1f) << 20) | (rs1 << 15) | (5 << 12) | (rd << 7) | 0x13
+			}
+			Opcode::Add => r_type(0x00, 0, 0x33),
+			Opcode::Sub => r_type(0x20, 0, 0x33),
+			Opcode::Sll => r_type(0x00, 1, 0x33),
+			Opcode::Slt => r_type(0x00, 2, 0x33),
+			Opcode::Sltu => r_type(0x00, 3, 0x33),
+			Opcode::Xor => r_type(0x00, 4, 0x33),
+			Opcode::Srl => r_type(0x00, 5, 0x33),
+			Opcode::Sra => r_type(0x20, 5, 0x33),
+			Opcode::Or => r_type(0x00, 6, 0x33),
+			Opcode::And => r_type(0x00, 7, 0x33),
+			Opcode::Mul => r_type(0x01, 0, 0x33),
+			Opcode::Mulh => r_type(0x01, 1, 0x33),
+			Opcode::Mulhsu => r_type(0x01, 2, 0x33),
+			Opcode::Mulhu => r_type(0x01, 3, 0x33),
+			Opcode::Div => r_type(0x01, 4, 0x33),
+			Opcode::Divu => r_type(0x01, 5, 0x33),
+			Opcode::Rem => r_type(0x01, 6, 0x33),
+			Opcode::Remu => r_type(0x01, 7, 0x33),
+			Opcode::Ecall => 0x73,
+		}
+	}
+}
+
+/// An entry of the program ROM: a decoded instruction at its address.
+///
+/// The destination register is redirected to [`SCRATCH_REGISTER`] when it is `x0`, so that ROM
+/// entries directly describe the register accesses of the instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RomEntry {
+	pub pc: u32,
+	pub opcode: Opcode,
+	pub rd: u8,
+	pub rs1: u8,
+	pub rs2: u8,
+	pub imm: u32,
+}
+
+impl RomEntry {
+	pub fn new(pc: u32, instruction: Instruction) -> Self {
+		let writes_rd = !matches!(
+			instruction.opcode.class(),
+			InstructionClass::Branch | InstructionClass::Store | InstructionClass::Halt
+		);
+		let rd = if writes_rd && instruction.rd == 0 {
+			SCRATCH_REGISTER
+		} else {
+			instruction.rd
+		};
+		Self {
+			pc,
+			opcode: instruction.opcode,
+			rd,
+			rs1: instruction.rs1,
+			rs2: instruction.rs2,
+			imm: instruction.imm,
+		}
+	}
+}
+
+/// A program made of raw instruction words and the initial RAM contents.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Program {
+	/// The address of the first instruction, which is also the entry point.
+	pub code_base: u32,
+	pub code: Vec<u32>,
+	/// The byte address of the first RAM word.
+	pub ram_base: u32,
+	/// The initial RAM contents, which also determine the RAM size.
+	pub ram: Vec<u32>,
+}
+
+impl Program {
+	/// Creates a program with the instruction words `code` loaded at `code_base` and no RAM.
+	pub fn new(code_base: u32, code: Vec<u32>) -> Self {
+		Self {
+			code_base,
+			code,
+			ram_base: 0,
+			ram: Vec::new(),
+		}
+	}
+
+	/// Creates a program from decoded instructions.
+	pub fn from_instructions(
+		code_base: u32,
+		instructions: impl IntoIterator<Item = Instruction>,
+	) -> Self {
+		Self::new(
+			code_base,
+			instructions
+				.into_iter()
+				.map(|instr| instr.encode())
+				.collect(),
+		)
+	}
+
+	/// Sets the RAM region to start at byte address `ram_base` with the initial contents `ram`.
+	pub fn with_ram(mut self, ram_base: u32, ram: Vec<u32>) -> Self {
+		self.ram_base = ram_base;
+		self.ram = ram;
+		self
+	}
+
+	pub fn entry_point(&self) -> u32 {
+		self.code_base
+	}
+
+	/// Decodes the program into ROM entries.
+	pub fn rom(&self) -> Result<Vec<RomEntry>, Error> {
+		self.code
+			.iter()
+			.enumerate()
+			.map(|(i, &word)| {
+				let pc = self.code_base.wrapping_add(4 * i as u32);
+				Instruction::decode(word)
+					.map(|instruction| RomEntry::new(pc, instruction))
+					.ok_or(Error::IllegalInstruction { pc, word })
+			})
+			.collect()
+	}
+
+	/// The byte address of the RAM word with the given index.
+	pub fn ram_address(&self, index: usize) -> u32 {
+		self.ram_base.wrapping_add(4 * index as u32)
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+	#[error("illegal instruction {word:#010x} at pc {pc:#010x}")]
+	IllegalInstruction { pc: u32, word: u32 },
+	#[error("pc {pc:#010x} is outside of the program")]
+	PcOutOfBounds { pc: u32 },
+	#[error("instruction at pc {pc:#010x} accesses address {addr:#010x} outside of the RAM")]
+	MemoryOutOfBounds { pc: u32, addr: u32 },
+	#[error("instruction at pc {pc:#010x} accesses misaligned address {addr:#010x}")]
+	MisalignedAccess { pc: u32, addr: u32 },
+	#[error("the machine did not halt within {max_steps} steps")]
+	StepLimitExceeded { max_steps: usize },
+}
+
+/// A register or RAM access, with the value and timestamp before and after it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Access {
+	/// The register index or the RAM byte address.
+	pub addr: u32,
+	pub prev_value: u32,
+	pub prev_timestamp: u32,
+	pub value: u32,
+	pub timestamp: u32,
+}
+
+/// The execution of a single instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CpuEvent {
+	pub clk: u32,
+	pub rom: RomEntry,
+	pub next_pc: u32,
+	pub rs1: Option<Access>,
+	pub rs2: Option<Access>,
+	pub rd: Option<Access>,
+	pub mem: Option<Access>,
+}
+
+impl CpuEvent {
+	pub fn pc(&self) -> u32 {
+		self.rom.pc
+	}
+}
+
+/// The trace of a halted execution.
+#[derive(Debug, Clone)]
+pub struct Trace {
+	/// The executed instructions, in order.
+	pub events: Vec<CpuEvent>,
+	/// The final `(value, timestamp)` of every register, including the scratch register.
+	pub registers: Vec<(u32, u32)>,
+	/// The final `(value, timestamp)` of every RAM word.
+	pub ram: Vec<(u32, u32)>,
+	pub exit_code: u32,
+}
+
+/// A memory with access timestamps.
+#[derive(Debug)]
+struct TimestampedMemory {
+	cells: Vec<(u32, u32)>,
+}
+
+impl TimestampedMemory {
+	fn new(values: impl IntoIterator<Item = u32>) -> Self {
+		Self {
+			cells: values.into_iter().map(|value| (value, 0)).collect(),
+		}
+	}
+
+	fn access(&mut self, index: usize, addr: u32, value: Option<u32>, timestamp: u32) -> Access {
+		let (prev_value, prev_timestamp) = self.cells[index];
+		let value = value.unwrap_or(prev_value);
+		self.cells[index] = (value, timestamp);
+		Access {
+			addr,
+			prev_value,
+			prev_timestamp,
+			value,
+			timestamp,
+		}
+	}
+}
+
+/// Executes `program` until it halts, for at most `max_steps` instructions.
+pub fn execute(program: &Program, max_steps: usize) -> Result<Trace, Error> {
+	let rom = program.rom()?;
+	let mut registers = TimestampedMemory::new(vec![0; N_REGISTERS]);
+	let mut ram = TimestampedMemory::new(program.ram.iter().copied());
+	let ram_index = |pc: u32, addr: u32, align: u32| {
+		if addr % align != 0 {
+			return Err(Error::MisalignedAccess { pc, addr });
+		}
+		let offset = addr.wrapping_sub(program.ram_base) as usize / 4;
+		if addr < program.ram_base || offset >= program.ram.len() {
+			return Err(Error::MemoryOutOfBounds { pc, addr });
+		}
+		Ok(offset)
+	};
+
+	let mut events = Vec::new();
+	let mut pc = program.entry_point();
+	for clk in 0..max_steps as u32 {
+		let offset = pc.wrapping_sub(program.code_base);
+		let entry = match rom.get(offset as usize / 4) {
+			Some(entry) if offset % 4 == 0 => *entry,
+			_ => return Err(Error::PcOutOfBounds { pc }),
+		};
+
+		let class = entry.opcode.class();
+		let reads_rs1 = !matches!(
+			class,
+			InstructionClass::Lui | InstructionClass::Auipc | InstructionClass::Jal
+		);
+		let reads_rs2 = matches!(
+			class,
+			InstructionClass::AluReg | InstructionClass::Branch | InstructionClass::Store
+		);
+
+		let rs1 = reads_rs1.then(|| {
+			registers.access(
+				entry.rs1 as usize,
+				entry.rs1 as u32,
+				None,
+				timestamp(clk, RS1_TIMESTAMP_OFFSET),
+			)
+		});
+		let rs2 = reads_rs2.then(|| {
+			registers.access(
+				entry.rs2 as usize,
+				entry.rs2 as u32,
+				None,
+				timestamp(clk, RS2_TIMESTAMP_OFFSET),
+			)
+		});
+		let v1 = rs1.map_or(0, |access| access.value);
+		let v2 = rs2.map_or(0, |access| access.value);
+		let imm = entry.imm;
+
+		let mut next_pc = pc.wrapping_add(4);
+		let mut mem = None;
+		let rd_value = match class {
+			InstructionClass::AluReg => Some(alu(entry.opcode, v1, v2)),
+			InstructionClass::AluImm => {
+				let op = entry.opcode.alu_op().expect("opcode is an ALU opcode");
+				Some(alu(op, v1, imm))
+			}
+			InstructionClass::Lui => Some(imm),
+			InstructionClass::Auipc => Some(pc.wrapping_add(imm)),
+			InstructionClass::Jal => {
+				next_pc = pc.wrapping_add(imm);
+				Some(pc.wrapping_add(4))
+			}
+			InstructionClass::Jalr => {
+				next_pc = v1.wrapping_add(imm) & !1;
+				Some(pc.wrapping_add(4))
+			}
+			InstructionClass::Branch => {
+				let taken = match entry.opcode {
+					Opcode::Beq => v1 == v2,
+					Opcode::Bne => v1 != v2,
+					Opcode::Blt => (v1 as i32) < (v2 as i32),
+					Opcode::Bge => (v1 as i32) >= (v2 as i32),
+					Opcode::Bltu => v1 < v2,
+					_ => v1 >= v2,
+				};
+				if taken {
+					next_pc = pc.wrapping_add(imm);
+				}
+				None
+			}
+			InstructionClass::Load => {
+				let addr = v1.wrapping_add(imm);
+				let width = entry.opcode.access_width().expect("opcode is a load");
+				let index = ram_index(pc, addr, width)?;
+				let access =
+					ram.access(index, addr & !3, None, timestamp(clk, MEM_TIMESTAMP_OFFSET));
+				mem = Some(access);
+
+				let shifted = access.value >> (8 * (addr & 3));
+				Some(match entry.opcode {
+					Opcode::Lb => shifted as i8 as u32,
+					Opcode::Lbu => shifted as u8 as u32,
+					Opcode::Lh => shifted as i16 as u32,
+					Opcode::Lhu => shifted as u16 as u32,
+					_ => shifted,
+				})
+			}
+			InstructionClass::Store => {
+				let addr = v1.wrapping_add(imm);
+				let width = entry.opcode.access_width().expect("opcode is a store");
+				let mask = u32::MAX >> (32 - 8 * width);
+				let index = ram_index(pc, addr, width)?;
+				let shift = 8 * (addr & 3);
+				let (prev_value, _) = ram.cells[index];
+				let value = (prev_value & !(mask << shift)) | ((v2 & mask) << shift);
+				mem = Some(ram.access(
+					index,
+					addr & !3,
+					Some(value),
+					timestamp(clk, MEM_TIMESTAMP_OFFSET),
+				));
+				None
+			}
+			InstructionClass::Halt => {
+				events.push(CpuEvent {
+					clk,
+					rom: entry,
+					next_pc: pc,
+					rs1,
+					rs2,
+					rd: None,
+					mem,
+				});
+				return Ok(Trace {
+					events,
+					registers: registers.cells,
+					ram: ram.cells,
+					exit_code: v1,
+				});
+			}
+		};
+
+		let rd = rd_value.map(|value| {
+			registers.access(
+				entry.rd as usize,
+				entry.rd as u32,
+				Some(value),
+				timestamp(clk, RD_TIMESTAMP_OFFSET),
+			)
+		});
+
+		events.push(CpuEvent {
+			clk,
+			rom: entry,
+			next_pc,
+			rs1,
+			rs2,
+			rd,
+			mem,
+		});
+		pc = next_pc;
+	}
+
+	Err(Error::StepLimitExceeded { max_steps })
+}
+
+/// Runs `program` and returns only the exit code and final RAM contents.
+///
+/// This is a convenience wrapper around [`execute`] for checking program behavior.
+pub fn run(program: &Program, max_steps: usize) -> Result<(u32, BTreeMap<u32, u32>), Error> {
+	let trace = execute(program, max_steps)?;
+	let ram = trace
+		.ram
+		.iter()
+		.enumerate()
+		.map(|(i, &(value, _))| (program.ram_address(i), value))
+		.collect();
+	Ok((trace.exit_code, ram))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn i(opcode: Opcode, rd: u8, rs1: u8, rs2: u8, imm: i32) -> Instruction {
+		Instruction {
+			opcode,
+			rd,
+			rs1,
+			rs2,
+			imm: imm as u32,
+		}
+	}
+
+	#[test]
+	fn test_decode_known_words() {
+		// addi a0, zero, -1
+		assert_eq!(Instruction::decode(0xfff00513), Some(i(Opcode::Addi, 10, 0, 0, -1)));
+		// lui a1, 0x12345
+		assert_eq!(Instruction::decode(0x123455b7), Some(i(Opcode::Lui, 11, 0, 0, 0x12345000)));
+		// jal ra, -8
+		assert_eq!(Instruction::decode(0xff9ff0ef), Some(i(Opcode::Jal, 1, 0, 0, -8)));
+		// bne a0, a1, 16
+		assert_eq!(Instruction::decode(0x00b51863), Some(i(Opcode::Bne, 0, 10, 11, 16)));
+		// sw a1, -4(sp)
+		assert_eq!(Instruction::decode(0xfeb12e23), Some(i(Opcode::Sw, 0, 2, 11, -4)));
+		// mulhsu t0, t1, t2
+		assert_eq!(Instruction::decode(0x027322b3), Some(i(Opcode::Mulhsu, 5, 6, 7, 0)));
+		// srai a0, a0, 3
+		assert_eq!(Instruction::decode(0x40355513), Some(i(Opcode::Srai, 10, 10, 0, 3)));
+		assert_eq!(Instruction::decode(0x00000073), Some(i(Opcode::Ecall, 0, 10, 0, 0)));
+		assert_eq!(Instruction::decode(0x00100073), None);
+		assert_eq!(Instruction::decode(0), None);
+	}
+
+	#[test]
+	fn test_encode_decode_roundtrip() {
+		let instructions = [
+			i(Opcode::Add, 1, 2, 3, 0),
+			i(Opcode::Remu, 31, 30, 29, 0),
+			i(Opcode::Andi, 4, 5, 0, -2048),
+			i(Opcode::Slli, 4, 5, 0, 31),
+			i(Opcode::Srai, 4, 5, 0, 1),
+			i(Opcode::Auipc, 7, 0, 0, 0x7ffff000),
+			i(Opcode::Jal, 0, 0, 0, -1048576),
+			i(Opcode::Jalr, 1, 6, 0, 2047),
+			i(Opcode::Bgeu, 0, 8, 9, -4096),
+			i(Opcode::Blt, 0, 8, 9, 4094),
+			i(Opcode::Lhu, 10, 2, 0, -1),
+			i(Opcode::Sb, 0, 2, 10, 2047),
+			i(Opcode::Ecall, 0, 10, 0, 0),
+		];
+		for instr in instructions {
+			assert_eq!(Instruction::decode(instr.encode()), Some(instr), "{instr:?}");
+		}
+	}
+
+	#[test]
+	fn test_alu_edge_cases() {
+		assert_eq!(alu(Opcode::Div, 7, 0), u32::MAX);
+		assert_eq!(alu(Opcode::Rem, 7, 0), 7);
+		assert_eq!(alu(Opcode::Divu, 7, 0), u32::MAX);
+		assert_eq!(alu(Opcode::Remu, 7, 0), 7);
+		assert_eq!(alu(Opcode::Div, i32::MIN as u32, u32::MAX), i32::MIN as u32);
+		assert_eq!(alu(Opcode::Rem, i32::MIN as u32, u32::MAX), 0);
+		assert_eq!(alu(Opcode::Mulh, u32::MAX, u32::MAX), 0);
+		assert_eq!(alu(Opcode::Mulhsu, u32::MAX, u32::MAX), u32::MAX);
+		assert_eq!(alu(Opcode::Mulhu, u32::MAX, u32::MAX), u32::MAX - 1);
+		assert_eq!(alu(Opcode::Sra, 0x8000_0000, 35), 0xf000_0000);
+	}
+
+	#[test]
+	fn test_execute_loop_and_memory() {
+		// Sums 1..=10 into a0, storing the partial sums to RAM.
+		let program = Program::from_instructions(
+			0x1000,
+			[
+				i(Opcode::Addi, 10, 0, 0, 0),
+				i(Opcode::Addi, 5, 0, 0, 1),
+				i(Opcode::Addi, 6, 0, 0, 11),
+				i(Opcode::Lui, 7, 0, 0, 0x8000),
+				i(Opcode::Add, 10, 10, 5, 0),
+				i(Opcode::Sw, 0, 7, 10, 0),
+				i(Opcode::Addi, 7, 7, 0, 4),
+				i(Opcode::Addi, 5, 5, 0, 1),
+				i(Opcode::Bne, 0, 5, 6, -16),
+				i(Opcode::Addi, 0, 0, 0, 5),
+				i(Opcode::Ecall, 0, 10, 0, 0),
+			],
+		)
+		.with_ram(0x8000, vec![0; 16]);
+
+		let trace = execute(&program, 1000).unwrap();
+		assert_eq!(trace.exit_code, 55);
+		assert_eq!(trace.events.len(), 4 + 10 * 5 + 2);
+		assert_eq!(trace.registers[0].0, 0);
+		assert_eq!(trace.registers[SCRATCH_REGISTER as usize].0, 5);
+		let sums = (1..=10).scan(0, |acc, x| {
+			*acc += x;
+			Some(*acc)
+		});
+		for (index, sum) in sums.enumerate() {
+			assert_eq!(trace.ram[index].0, sum);
+		}
+
+		// Every access consumes the latest value and timestamp of its cell.
+		for event in &trace.events {
+			for access in [event.rs1, event.rs2, event.rd, event.mem]
+				.into_iter()
+				.flatten()
+			{
+				assert!(access.prev_timestamp < access.timestamp);
+			}
+		}
+	}
+
+	#[test]
+	fn test_execute_subword_memory() {
+		let program = Program::from_instructions(
+			0,
+			[
+				i(Opcode::Lui, 1, 0, 0, 0x1000),
+				i(Opcode::Addi, 2, 0, 0, -2),
+				i(Opcode::Sb, 0, 1, 2, 1),
+				i(Opcode::Lh, 3, 1, 0, 0),
+				i(Opcode::Lbu, 4, 1, 0, 1),
+				i(Opcode::Add, 10, 3, 4, 0),
+				i(Opcode::Ecall, 0, 10, 0, 0),
+			],
+		)
+		.with_ram(0x1000, vec![0x1234_5678]);
+
+		let (exit_code, ram) = run(&program, 100).unwrap();
+		assert_eq!(ram[&0x1000], 0x1234_fe78);
+		assert_eq!(exit_code, (0xfe78u32 as i16 as u32).wrapping_add(0xfe));
+	}
+
+	#[test]
+	fn test_execute_errors() {
+		let program = Program::new(0, vec![0xffff_ffff]);
+		assert_eq!(
+			execute(&program, 10).unwrap_err(),
+			Error::IllegalInstruction {
+				pc: 0,
+				word: 0xffff_ffff
+			}
+		);
+
+		let program = Program::from_instructions(0, [i(Opcode::Jal, 0, 0, 0, 0)]);
+		assert_eq!(execute(&program, 10).unwrap_err(), Error::StepLimitExceeded { max_steps: 10 });
+
+		let program =
+			Program::from_instructions(0, [i(Opcode::Lw, 1, 0, 0, 2)]).with_ram(0, vec![0]);
+		assert_eq!(execute(&program, 10).unwrap_err(), Error::MisalignedAccess { pc: 0, addr: 2 });
+
+		let program = Program::from_instructions(0, [i(Opcode::Addi, 1, 0, 0, 8)]);
+		assert_eq!(execute(&program, 10).unwrap_err(), Error::PcOutOfBounds { pc: 4 });
+	}
+}
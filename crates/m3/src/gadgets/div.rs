@@ -193,7 +193,7 @@ impl DivUU32 {
 
 /// Gadget for signed division of two i32s.
 ///
-/// `p = q*a + r` where p and r have the same sign bit.
+/// `p = q*a + r` where r is zero or has the sign of p.
 #[derive(Debug)]
 pub struct DivSS32 {
 	mul_inner: MulSS32,
@@ -229,9 +229,6 @@ impl DivSS32 {
 		let out_div_bits = table.add_committed_multiple("out_div_bits");
 		let out_rem_bits = table.add_committed_multiple("out_rem_bits");
 
-		// Check sign(p) == sign(r)
-		table.assert_zero("sign_dividend_eq_sign_rem", p_in_bits[31] - out_rem_bits[31]);
-
 		let sign_extend_q: [_; 64] =
 			array::from_fn(|i| if i < 32 { q_in_bits[i] } else { q_in_bits[31] });
 		let sign_extend_rem = array::from_fn(|i| {
@@ -272,11 +269,14 @@ impl DivSS32 {
 
 		// Add constraint to make sure that |r| < |q| by computing s = |r| - |q| in a larger bit
 		// length. There maybe a better way to do it with channels and simpler comparator logic.
-		let r_is_negative = out_rem_bits[31];
+		// The remainder is negated when p is negative, so that |r| is non-negative exactly when r
+		// is zero or has the sign of p.
+		let p_is_negative = p_in_bits[31];
 		let mut inner_abs_rem_table = table.with_namespace("rem_abs_value");
 		let abs_r_value =
-			SignConverter::new(&mut inner_abs_rem_table, sign_extend_rem, r_is_negative.into());
+			SignConverter::new(&mut inner_abs_rem_table, sign_extend_rem, p_is_negative.into());
 		let abs_r_bits = abs_r_value.converted_bits;
+		table.assert_zero("sign_rem_eq_sign_dividend", abs_r_bits[63].into());
 
 		let q_is_positive = q_in_bits[31] + B1::ONE;
 		let mut inner_neg_abs_q_table = table.with_namespace("neg_abs_q");
@@ -6,7 +6,7 @@
 
 use std::{array, iter};
 
-use anyhow::Result;
+use anyhow::{Result, ensure};
 use array_util::ArrayExt;
 use binius_core::oracle::ShiftVariant;
 use binius_field::{
@@ -17,8 +17,9 @@ use binius_field::{
 	},
 	packed::{get_packed_slice, len_packed_slice, set_packed_slice},
 };
+use binius_hash::groestl::{GroestlShortImpl, GroestlShortInternal};
 
-use crate::builder::{B1, B8, B128, Col, Expr, TableBuilder, TableWitnessSegment, upcast_col};
+use crate::builder::{B1, B8, B32, B128, Col, Expr, TableBuilder, TableWitnessSegment, upcast_col};
 
 /// The first row of the circulant matrix defining the MixBytes step in Grøstl.
 const MIX_BYTES_VEC: [u8; 8] = [0x02, 0x02, 0x03, 0x04, 0x05, 0x03, 0x05, 0x07];
@@ -174,6 +175,305 @@ fn round_consts(round: usize) -> [B8; 8] {
 	})
 }
 
+/// The number of bytes of a Grøstl-256 message block.
+const GROESTL256_BLOCK_LEN: usize = 64;
+
+/// Returns the [`B8`] element representing a byte, in the isomorphic representation of the
+/// Grøstl gadgets.
+pub fn isomorphic_byte(byte: u8) -> B8 {
+	B8::from(AESTowerField8b::new(byte))
+}
+
+/// The initial chaining value of Grøstl-256, which encodes the output length in bits.
+fn groestl256_iv() -> [u8; 64] {
+	let mut iv = [0u8; 64];
+	iv[56..].copy_from_slice(&256u64.to_be_bytes());
+	iv
+}
+
+/// Returns the bytes appended to a message of `message_len` bytes by the Grøstl-256 padding: a
+/// one bit, zeros, and the big-endian number of blocks of the padded message.
+fn groestl256_padding(message_len: usize) -> Vec<u8> {
+	let n_blocks = (message_len + 9).div_ceil(GROESTL256_BLOCK_LEN);
+	let mut padding = vec![0u8; n_blocks * GROESTL256_BLOCK_LEN - message_len];
+	padding[0] = 0x80;
+	let len = padding.len();
+	padding[len - 8..].copy_from_slice(&(n_blocks as u64).to_be_bytes());
+	padding
+}
+
+/// Populates state columns with states in the standard byte representation.
+fn populate_state<P>(
+	index: &mut TableWitnessSegment<P>,
+	cols: [Col<B8, 8>; 8],
+	states: &[[u8; 64]],
+) -> Result<()>
+where
+	P: PackedFieldIndexable<Scalar = B128> + PackedExtension<B8>,
+{
+	for (i, col) in cols.into_iter().enumerate() {
+		let mut col = index.get_mut_as::<u8, B8, 8>(col)?;
+		for (k, state) in states.iter().enumerate() {
+			for j in 0..8 {
+				col[k * 8 + j] = isomorphic_byte(state[j * 8 + i]).val();
+			}
+		}
+	}
+	Ok(())
+}
+
+/// A gadget for the Grøstl-256 hash of messages of a fixed length.
+///
+/// The gadget pads the message, checks the compression function
+/// `f(h, m) = P(h ⊕ m) ⊕ Q(m) ⊕ h` on each block, and the output transformation `P(h) ⊕ h`, whose
+/// last 32 bytes are the digest. The bytes are in the isomorphic representation of [`Permutation`].
+/// The message bytes are committed and left to the caller to constrain, through
+/// [`Self::message_byte`]; the padding bytes are constrained by the gadget.
+#[derive(Debug, Clone)]
+pub struct Groestl256Hash {
+	message_len: usize,
+	/// The padded message blocks, as input states of the Q permutations.
+	blocks: Vec<[Col<B8, 8>; 8]>,
+	/// The bytes of the blocks, where `block_bytes[b][i][j]` is row `i` and column `j` of block
+	/// `b`.
+	block_bytes: Vec<[[Col<B8>; 8]; 8]>,
+	compressions: Vec<Compression>,
+	output: Permutation,
+	output_state: [Col<B8, 8>; 8],
+	/// The bytes of the digest, where `digest_bytes[i][j]` is row `i` and column `4 + j` of the
+	/// output state.
+	digest_bytes: [[Col<B8>; 4]; 8],
+	digest: [Col<B32>; 8],
+}
+
+impl Groestl256Hash {
+	pub fn new(table: &mut TableBuilder, message_len: usize) -> Self {
+		let padding = groestl256_padding(message_len);
+		let n_blocks = (message_len + padding.len()) / GROESTL256_BLOCK_LEN;
+
+		let blocks = (0..n_blocks)
+			.map(|b| table.add_committed_multiple(format!("blocks[{b}]")))
+			.collect::<Vec<[Col<B8, 8>; 8]>>();
+		let block_bytes = blocks
+			.iter()
+			.enumerate()
+			.map(|(b, block)| {
+				array::from_fn(|i| {
+					array::from_fn(|j| {
+						table.add_selected(format!("block_bytes[{b}][{i}][{j}]"), block[i], j)
+					})
+				})
+			})
+			.collect::<Vec<[[Col<B8>; 8]; 8]>>();
+		for (offset, &byte) in padding.iter().enumerate() {
+			let position = message_len + offset;
+			let col = Self::byte_col(&block_bytes, position);
+			table.assert_zero(format!("padding[{offset}]"), col - isomorphic_byte(byte));
+		}
+
+		let iv_bytes = groestl256_iv();
+		let mut state: [Col<B8, 8>; 8] = array::from_fn(|i| {
+			table.add_constant(
+				format!("iv[{i}]"),
+				array::from_fn(|j| isomorphic_byte(iv_bytes[j * 8 + i])),
+			)
+		});
+		let compressions = blocks
+			.iter()
+			.enumerate()
+			.map(|(b, &block)| {
+				let compression = Compression::new(
+					&mut table.with_namespace(format!("compression[{b}]")),
+					state,
+					block,
+				);
+				state = compression.state_out;
+				compression
+			})
+			.collect::<Vec<_>>();
+
+		let output =
+			Permutation::new(&mut table.with_namespace("output"), PermutationVariant::P, state);
+		let output_state = array::from_fn(|i| {
+			table.add_computed(format!("output_state[{i}]"), output.state_out()[i] + state[i])
+		});
+		let digest_bytes: [[Col<B8>; 4]; 8] = array::from_fn(|i| {
+			array::from_fn(|j| {
+				table.add_selected(format!("digest_bytes[{i}][{j}]"), output_state[i], 4 + j)
+			})
+		});
+		let digest = array::from_fn(|i| {
+			let packed = (0..4)
+				.map(|j| upcast_col(digest_bytes[i][j]) * ext_basis::<B32, B8>(j))
+				.reduce(|a, b| a + b)
+				.expect("digest rows have 4 bytes");
+			table.add_computed(format!("digest[{i}]"), packed)
+		});
+
+		Self {
+			message_len,
+			blocks,
+			block_bytes,
+			compressions,
+			output,
+			output_state,
+			digest_bytes,
+			digest,
+		}
+	}
+
+	fn byte_col(block_bytes: &[[[Col<B8>; 8]; 8]], position: usize) -> Col<B8> {
+		let offset = position % GROESTL256_BLOCK_LEN;
+		block_bytes[position / GROESTL256_BLOCK_LEN][offset % 8][offset / 8]
+	}
+
+	/// The length of the messages in bytes.
+	pub fn message_len(&self) -> usize {
+		self.message_len
+	}
+
+	/// Returns the column of the message byte at `index`.
+	///
+	/// ## Preconditions
+	///
+	/// * `index` must be less than the message length
+	pub fn message_byte(&self, index: usize) -> Col<B8> {
+		assert!(index < self.message_len, "index must be within the message");
+		Self::byte_col(&self.block_bytes, index)
+	}
+
+	/// Returns the column of the digest byte at `index`.
+	///
+	/// ## Preconditions
+	///
+	/// * `index` must be less than 32
+	pub fn digest_byte(&self, index: usize) -> Col<B8> {
+		self.digest_bytes[index % 8][index / 8]
+	}
+
+	/// Returns the digest, as 8 rows of 4 bytes each packed into a [`B32`].
+	///
+	/// Row `i` packs the bytes `i`, `8 + i`, `16 + i` and `24 + i` of the digest, which is the
+	/// layout of the digests in the [`crate::gadgets::merkle_tree`] channels.
+	pub fn digest(&self) -> [Col<B32>; 8] {
+		self.digest
+	}
+
+	/// Populates the witness with the hashes of the messages, in the standard byte
+	/// representation.
+	pub fn populate<P>(
+		&self,
+		index: &mut TableWitnessSegment<P>,
+		messages: impl IntoIterator<Item = impl AsRef<[u8]>>,
+	) -> Result<()>
+	where
+		P: PackedFieldIndexable<Scalar = B128>
+			+ PackedExtension<B1>
+			+ PackedExtension<B8>
+			+ PackedExtension<B32>,
+		PackedSubfield<P, B8>: PackedTransformationFactory<PackedSubfield<P, B8>>,
+	{
+		let n_blocks = self.compressions.len();
+		let padding = groestl256_padding(self.message_len);
+		let mut blocks = vec![Vec::new(); n_blocks];
+		let mut p_ins = vec![Vec::new(); n_blocks];
+		let mut chain = vec![Vec::new(); n_blocks];
+		let mut outputs = Vec::new();
+		for message in messages {
+			let message = message.as_ref();
+			ensure!(
+				message.len() == self.message_len,
+				"message has {} bytes, expected {}",
+				message.len(),
+				self.message_len
+			);
+
+			let padded = [message, &padding].concat();
+			let mut h = GroestlShortImpl::state_from_bytes(&groestl256_iv());
+			for (b, block) in padded.chunks_exact(GROESTL256_BLOCK_LEN).enumerate() {
+				let block: [u8; 64] = block.try_into().expect("chunks have the block length");
+				let mut q = GroestlShortImpl::state_from_bytes(&block);
+				let mut p = h;
+				GroestlShortImpl::xor_state(&mut p, &q);
+				p_ins[b].push(GroestlShortImpl::state_to_bytes(&p));
+				GroestlShortImpl::p_perm(&mut p);
+				GroestlShortImpl::q_perm(&mut q);
+				GroestlShortImpl::xor_state(&mut h, &p);
+				GroestlShortImpl::xor_state(&mut h, &q);
+				blocks[b].push(block);
+				chain[b].push(GroestlShortImpl::state_to_bytes(&h));
+			}
+			let mut output = h;
+			GroestlShortImpl::p_perm(&mut output);
+			GroestlShortImpl::xor_state(&mut output, &h);
+			outputs.push(GroestlShortImpl::state_to_bytes(&output));
+		}
+
+		for (b, compression) in self.compressions.iter().enumerate() {
+			populate_state(index, self.blocks[b], &blocks[b])?;
+			for (i, row) in self.block_bytes[b].iter().enumerate() {
+				for (j, &col) in row.iter().enumerate() {
+					let mut col = index.get_mut_as::<u8, B8, 1>(col)?;
+					for (k, block) in blocks[b].iter().enumerate() {
+						col[k] = isomorphic_byte(block[j * 8 + i]).val();
+					}
+				}
+			}
+			populate_state(index, compression.p_in, &p_ins[b])?;
+			compression.p.populate(index)?;
+			compression.q.populate(index)?;
+			populate_state(index, compression.state_out, &chain[b])?;
+		}
+
+		self.output.populate(index)?;
+		populate_state(index, self.output_state, &outputs)?;
+		for (i, (row, &digest)) in iter::zip(&self.digest_bytes, &self.digest).enumerate() {
+			let mut digest = index.get_mut_as::<u32, B32, 1>(digest)?;
+			for (k, output) in outputs.iter().enumerate() {
+				let bytes = array::from_fn(|j| isomorphic_byte(output[32 + j * 8 + i]).val());
+				digest[k] = u32::from_le_bytes(bytes);
+			}
+			for (j, &col) in row.iter().enumerate() {
+				let mut col = index.get_mut_as::<u8, B8, 1>(col)?;
+				for (k, output) in outputs.iter().enumerate() {
+					col[k] = isomorphic_byte(output[32 + j * 8 + i]).val();
+				}
+			}
+		}
+		Ok(())
+	}
+}
+
+/// The Grøstl compression function `f(h, m) = P(h ⊕ m) ⊕ Q(m) ⊕ h` of one block.
+#[derive(Debug, Clone)]
+struct Compression {
+	p_in: [Col<B8, 8>; 8],
+	p: Permutation,
+	q: Permutation,
+	state_out: [Col<B8, 8>; 8],
+}
+
+impl Compression {
+	fn new(table: &mut TableBuilder, state_in: [Col<B8, 8>; 8], block: [Col<B8, 8>; 8]) -> Self {
+		let p_in =
+			array::from_fn(|i| table.add_computed(format!("p_in[{i}]"), state_in[i] + block[i]));
+		let p = Permutation::new(&mut table.with_namespace("P"), PermutationVariant::P, p_in);
+		let q = Permutation::new(&mut table.with_namespace("Q"), PermutationVariant::Q, block);
+		let state_out = array::from_fn(|i| {
+			table.add_computed(
+				format!("state_out[{i}]"),
+				p.state_out()[i] + q.state_out()[i] + state_in[i],
+			)
+		});
+		Self {
+			p_in,
+			p,
+			q,
+			state_out,
+		}
+	}
+}
+
 /// A single round of a Grøstl permutation.
 #[derive(Debug, Clone)]
 struct PermutationRound {
@@ -403,8 +703,9 @@ mod tests {
 	use binius_field::{
 		arch::OptimalUnderlier128b, arithmetic_traits::InvertOrZero, as_packed_field::PackedType,
 	};
-	use binius_hash::groestl::{GroestlShortImpl, GroestlShortInternal};
-	use rand::{SeedableRng, prelude::StdRng};
+	use binius_hash::groestl::Groestl256;
+	use digest::Digest;
+	use rand::{Rng, SeedableRng, prelude::StdRng};
 
 	use super::*;
 	use crate::builder::{ConstraintSystem, WitnessIndex};
@@ -562,6 +863,64 @@ mod tests {
 		.unwrap();
 	}
 
+	fn check_groestl256_hash(message_len: usize) {
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("Grøstl-256 test");
+		let hash = Groestl256Hash::new(&mut table, message_len);
+		let table_id = table.id();
+
+		let mut allocator = CpuComputeAllocator::new(1 << 18);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		let table_witness = witness.init_table(table_id, 1 << 4).unwrap();
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let messages = repeat_with(|| (0..message_len).map(|_| rng.random()).collect::<Vec<u8>>())
+			.take(1 << 4)
+			.collect::<Vec<_>>();
+		let mut segment = table_witness.full_segment();
+		hash.populate(&mut segment, &messages).unwrap();
+
+		let digest = hash
+			.digest()
+			.map(|row| segment.get_as::<u32, B32, 1>(row).unwrap().to_vec());
+		for (k, message) in messages.iter().enumerate() {
+			let expected = Groestl256::digest(message);
+			for (i, row) in digest.iter().enumerate() {
+				let bytes = row[k].to_le_bytes();
+				for (j, &byte) in bytes.iter().enumerate() {
+					assert_eq!(byte, isomorphic_byte(expected[j * 8 + i]).val());
+				}
+			}
+		}
+		drop(segment);
+		witness.fill_constant_cols().unwrap();
+
+		let ccs = cs.compile().unwrap();
+		let table_sizes = witness.table_sizes();
+		let witness = witness.into_multilinear_extension_index();
+
+		binius_core::constraint_system::validate::validate_witness(
+			&ccs,
+			&[],
+			&table_sizes,
+			&witness,
+		)
+		.unwrap();
+	}
+
+	#[test]
+	fn test_groestl256_hash_one_block() {
+		check_groestl256_hash(32);
+	}
+
+	#[test]
+	fn test_groestl256_hash_two_blocks() {
+		check_groestl256_hash(56);
+		check_groestl256_hash(104);
+	}
+
 	#[test]
 	fn test_isomorphic_sbox() {
 		#[rustfmt::skip]
@@ -129,10 +129,7 @@ impl MerkleTreeCS {
 		witness.fill_table_parallel(&self.merkle_path_table_both, &both_events)?;
 
 		// Fill the roots table.
-		witness.fill_table_parallel(
-			&self.root_table,
-			&trace.root.clone().into_iter().collect::<Vec<_>>(),
-		)?;
+		witness.fill_table_parallel(&self.root_table, &trace.root)?;
 
 		let lookup_counts = tally(cs, witness, &[], self.lookup_channel, &IncrIndexedLookup)?;
 
@@ -218,7 +215,7 @@ pub enum MerklePathPullChild {
 pub struct NodesTable {
 	id: TableId,
 	// The root id field is used to identify the root the node is associated with.
-	root_id: Col<B8>,
+	root_id: Col<B32>,
 	// Concatenated bytes of the left and right digests of the Merkle tree node. Organised
 	// in a packed row of the Groestl-256 permutation state.
 	state_out_shifted: [Col<B8, 8>; 8],
@@ -347,39 +344,25 @@ impl NodesTable {
 		let parent_index_upcasted = upcast_col(parent_index_packed);
 		let parent_depth_upcasted = upcast_col(parent_depth);
 		let child_depth_upcasted = upcast_col(child_depth);
-		let root_id_upcasted = upcast_col(root_id);
 
 		let mut nodes_channel = NodesChannel::new(&mut table, nodes_channel_id);
 
-		nodes_channel.push(
-			root_id_upcasted,
-			parent_packed,
-			parent_depth_upcasted,
-			parent_index_upcasted,
-		);
+		nodes_channel.push(root_id, parent_packed, parent_depth_upcasted, parent_index_upcasted);
 
 		match pull_child {
-			MerklePathPullChild::Left => nodes_channel.pull(
-				root_id_upcasted,
-				left_packed,
-				child_depth_upcasted,
-				left_index_upcasted,
-			),
+			MerklePathPullChild::Left => {
+				nodes_channel.pull(root_id, left_packed, child_depth_upcasted, left_index_upcasted)
+			}
 			MerklePathPullChild::Right => nodes_channel.pull(
-				root_id_upcasted,
+				root_id,
 				right_packed,
 				child_depth_upcasted,
 				right_index_upcasted,
 			),
 			MerklePathPullChild::Both => {
+				nodes_channel.pull(root_id, left_packed, child_depth_upcasted, left_index_upcasted);
 				nodes_channel.pull(
-					root_id_upcasted,
-					left_packed,
-					child_depth_upcasted,
-					left_index_upcasted,
-				);
-				nodes_channel.pull(
-					root_id_upcasted,
+					root_id,
 					right_packed,
 					child_depth_upcasted,
 					right_index_upcasted,
@@ -461,7 +444,7 @@ fn to_root_flush(root_id: Col<B32>, digest: [Col<B32>; 8]) -> [Col<B32>; 9] {
 
 pub struct RootTable {
 	pub id: TableId,
-	pub root_id: Col<B8>,
+	pub root_id: Col<B32>,
 	pub digest: [Col<B32>; 8],
 }
 
@@ -477,10 +460,9 @@ impl RootTable {
 		let digest = table.add_committed_multiple("digest");
 
 		let zero = table.add_constant("zero", [B32::ZERO]);
-		let root_id_upcasted = upcast_col(root_id);
-		table.pull(roots_channel_id, to_root_flush(root_id_upcasted, digest));
+		table.pull(roots_channel_id, to_root_flush(root_id, digest));
 		let mut nodes_channel = NodesChannel::new(&mut table, nodes_channel_id);
-		nodes_channel.pull(root_id_upcasted, digest, zero, zero);
+		nodes_channel.pull(root_id, digest, zero, zero);
 		Self {
 			id,
 			root_id,
@@ -525,7 +507,7 @@ where
 		self.permutation.populate_state_in(witness, &state_ins)?;
 		self.permutation.populate(witness)?;
 
-		let mut witness_root_id: RefMut<'_, [u8]> = witness.get_mut_as(self.root_id)?;
+		let mut witness_root_id: RefMut<'_, [u32]> = witness.get_mut_as(self.root_id)?;
 		let mut witness_parent_depth: RefMut<'_, [u8]> = witness.get_mut_as(self.parent_depth)?;
 		let mut witness_child_depth: RefMut<'_, [u8]> = witness.get_mut_as(self.child_depth)?;
 		let mut witness_parent_index: RefMut<'_, [u32]> = witness.get_mut_as(self.parent_index)?;
@@ -636,7 +618,7 @@ where
 		rows: &[Self::Event],
 		witness: &mut TableWitnessSegment<P>,
 	) -> anyhow::Result<()> {
-		let mut witness_root_id = witness.get_mut_as(self.root_id)?;
+		let mut witness_root_id: RefMut<'_, [u32]> = witness.get_mut_as(self.root_id)?;
 		let mut witness_root_digest: Vec<RefMut<'_, [PackedBinaryField4x8b]>> = (0..8)
 			.map(|i| witness.get_mut_as(self.digest[i]))
 			.collect::<Result<Vec<_>, _>>()?;
@@ -756,7 +738,7 @@ mod tests {
 		let mut witness = WitnessIndex::<PackedType<OptimalUnderlier, B128>>::new(&cs, &allocator);
 
 		witness
-			.fill_table_sequential(&root_table, &trace.root.into_iter().collect::<Vec<_>>())
+			.fill_table_sequential(&root_table, &trace.root)
 			.unwrap();
 	}
 
@@ -814,7 +796,7 @@ mod tests {
 			.iter()
 			.enumerate()
 			.map(|(i, tree)| MerklePath {
-				root_id: i as u8,
+				root_id: i as u32,
 				index,
 				leaf: leaves[i][index],
 				nodes: tree.merkle_path(index),
@@ -15,7 +15,7 @@ use crate::{builder::B8, emulate::Channel};
 /// Signature of the Nodes channel: (Root ID, Data, Depth, Index)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct NodeFlushToken {
-	pub root_id: u8,
+	pub root_id: u32,
 	pub data: [u8; 32],
 	pub depth: usize,
 	pub index: usize,
@@ -24,7 +24,7 @@ pub struct NodeFlushToken {
 /// Signature of the Roots channel: (Root ID, Root digest)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct RootFlushToken {
-	pub root_id: u8,
+	pub root_id: u32,
 	pub data: [u8; 32],
 }
 
@@ -33,7 +33,7 @@ pub struct RootFlushToken {
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct MerklePath {
-	pub root_id: u8,
+	pub root_id: u32,
 	pub index: usize,
 	pub leaf: [u8; 32],
 	pub nodes: Vec<[u8; 32]>,
@@ -72,7 +72,7 @@ impl MerkleTreeChannels {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct MerklePathEvent {
-	pub root_id: u8,
+	pub root_id: u32,
 	pub left: [u8; 32],
 	pub right: [u8; 32],
 	pub parent: [u8; 32],
@@ -86,12 +86,12 @@ pub struct MerklePathEvent {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct MerkleRootEvent {
-	pub root_id: u8,
+	pub root_id: u32,
 	pub digest: [u8; 32],
 }
 
 impl MerkleRootEvent {
-	pub fn new(root_id: u8, digest: [u8; 32]) -> Self {
+	pub fn new(root_id: u32, digest: [u8; 32]) -> Self {
 		Self { root_id, digest }
 	}
 }
@@ -279,7 +279,7 @@ impl MerkleBoundaries {
 pub struct MerkleTreeTrace {
 	pub boundaries: MerkleBoundaries,
 	pub nodes: Vec<MerklePathEvent>,
-	pub root: HashSet<MerkleRootEvent>,
+	pub root: Vec<MerkleRootEvent>,
 }
 impl MerkleTreeTrace {
 	/// Method to generate the trace given the witness values. The function assumes that the
@@ -373,7 +373,51 @@ impl MerkleTreeTrace {
 		Self {
 			boundaries,
 			nodes: path_nodes,
-			root: root_nodes,
+			root: root_nodes.into_iter().collect(),
+		}
+	}
+
+	/// Generates the trace of each path on its own, without merging the nodes that paths share.
+	///
+	/// The root of each path is computed from its leaf and siblings, so a path that does not open
+	/// into the root its `root_id` refers to pulls a digest that the root's pusher does not push.
+	/// Every path pushes its leaf to the nodes channel and pulls its root from the roots channel
+	/// once. This suits callers that flush the leaves and the roots from their own tables, with
+	/// the multiplicity of the openings, so the returned trace has no boundaries.
+	pub fn generate_unmerged(paths: &[MerklePath]) -> Self {
+		let mut nodes = Vec::new();
+		let mut root = Vec::with_capacity(paths.len());
+		for path in paths {
+			let depth = path.nodes.len();
+			let mut current_child = path.leaf;
+			for (i, &node) in path.nodes.iter().enumerate() {
+				let mut parent = [0u8; 32];
+				let is_left = (path.index >> i) & 1 == 0;
+				let (left, right) = if is_left {
+					(current_child, node)
+				} else {
+					(node, current_child)
+				};
+				compress(&left, &right, &mut parent);
+				nodes.push(MerklePathEvent {
+					root_id: path.root_id,
+					left,
+					right,
+					parent,
+					parent_depth: depth - i - 1,
+					parent_index: path.index >> (i + 1),
+					flush_left: is_left,
+					flush_right: !is_left,
+				});
+				current_child = parent;
+			}
+			root.push(MerkleRootEvent::new(path.root_id, current_child));
+		}
+
+		Self {
+			boundaries: MerkleBoundaries::new(),
+			nodes,
+			root,
 		}
 	}
 
@@ -463,7 +507,7 @@ mod tests {
 			.map(|_| {
 				let path_index = rng.random_range(0..1 << 4);
 				MerklePath {
-					root_id: 0u8,
+					root_id: 0u32,
 					index: path_index,
 					leaf: leaves[path_index],
 					nodes: tree.merkle_path(path_index),
@@ -474,6 +518,50 @@ mod tests {
 		merkle_tree_trace.validate();
 	}
 
+	#[test]
+	fn test_high_level_model_unmerged_paths() {
+		let mut rng = StdRng::from_seed([0; 32]);
+		let leaves = (0..1 << 4)
+			.map(|_| rng.random::<[u8; 32]>())
+			.collect::<Vec<_>>();
+		let tree = MerkleTree::new(&leaves);
+
+		// The same index is opened twice, so its nodes are flushed twice.
+		let paths = [3, 3, 12]
+			.into_iter()
+			.map(|index| MerklePath {
+				root_id: 7,
+				index,
+				leaf: leaves[index],
+				nodes: tree.merkle_path(index),
+			})
+			.collect::<Vec<_>>();
+		let trace = MerkleTreeTrace::generate_unmerged(&paths);
+		assert_eq!(trace.nodes.len(), 3 * 4);
+
+		let mut channels = MerkleTreeChannels::new();
+		for path in &paths {
+			channels.nodes.push(NodeFlushToken {
+				root_id: path.root_id,
+				data: path.leaf,
+				depth: path.nodes.len(),
+				index: path.index,
+			});
+			channels.roots.push(RootFlushToken {
+				root_id: path.root_id,
+				data: tree.root(),
+			});
+		}
+		for root in &trace.root {
+			root.fire(&mut channels.nodes, &mut channels.roots);
+		}
+		for node in &trace.nodes {
+			node.fire(&mut channels.nodes);
+		}
+		channels.nodes.assert_balanced();
+		channels.roots.assert_balanced();
+	}
+
 	#[test]
 	fn test_high_level_model_inclusion_multiple_roots() {
 		let mut rng = StdRng::from_seed([0; 32]);
@@ -494,7 +582,7 @@ mod tests {
 			.iter()
 			.enumerate()
 			.map(|(i, tree)| MerklePath {
-				root_id: i as u8,
+				root_id: i as u32,
 				index: path_index,
 				leaf: leaves[i][path_index],
 				nodes: tree.merkle_path(path_index),
@@ -8,6 +8,10 @@ pub mod indexed_lookup;
 pub mod lookup;
 pub mod merkle_tree;
 pub mod mul;
+pub mod recursion;
+pub mod regex;
+pub mod rv32im;
+pub mod sorted;
 pub mod structured;
 pub mod sub;
 mod util;
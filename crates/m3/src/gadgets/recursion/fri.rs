@@ -0,0 +1,463 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::array;
+
+use binius_core::{constraint_system::channel::ChannelId, oracle::ShiftVariant};
+use binius_field::{
+	Field, PackedExtension, PackedFieldIndexable, PackedSubfield,
+	linear_transformation::PackedTransformationFactory, packed::set_packed_slice,
+};
+
+use super::transcript::StandardBytes;
+use crate::{
+	builder::{
+		B1, B8, B32, B128, Col, TableBuilder, TableFiller, TableId, TableWitnessSegment, upcast_col,
+	},
+	gadgets::{hash::groestl::Groestl256Hash, util::position},
+};
+
+/// A single FRI folding step of a query, folding a pair of codeword values with one challenge.
+#[derive(Debug, Clone, Copy)]
+pub struct FriFold {
+	/// The identifier of the FRI instance, which namespaces the queries and the folding
+	/// challenges.
+	pub instance: u32,
+	/// The index of the coset of the pair in the codeword of the round.
+	pub query: u32,
+	/// The codeword values at the two positions of the coset.
+	pub values: (B128, B128),
+	pub challenge: B128,
+}
+
+/// Folds a pair of codeword values with an inverse additive NTT butterfly followed by a line
+/// extrapolation, as the FRI verifier does for a fold of arity 1.
+pub fn fold_pair((u, v): (B128, B128), twiddle: B32, challenge: B128) -> B128 {
+	let v = v + u;
+	let u = u + v * twiddle;
+	u + (v - u) * challenge
+}
+
+/// A table checking the FRI folding steps of arity 1 of a single round.
+///
+/// Each row pulls `(instance, query, round_tag, u, v)` from the pair channel and
+/// `(instance, round_tag, challenge)` from the challenge channel, and pushes
+/// `(instance, query, next_round_tag, fold)` to the fold channel, where `fold` is given by
+/// [`fold_pair`]. The round tags are the [`position`]s of the rounds. Every query pulls the
+/// challenge of each round it is folded in.
+///
+/// The twiddle of the butterfly is derived from the bits of the query. The twiddles of the
+/// additive NTT are linear in the index of the coset, so the twiddle is the sum of the elements
+/// of the twiddle basis of the round selected by the bits of the query. The query must index a
+/// coset of the round, its bits above the basis are constrained to zero.
+///
+/// The caller provides the pairs, for example from the Merkle openings of the committed
+/// codewords, and consumes the folded values, either as a value of the next round's pair or as
+/// a value of the final codeword. A FRI verifier of a fixed shape uses one table per round.
+pub struct FriFoldTable {
+	table_id: TableId,
+	twiddle_basis: Vec<B32>,
+	instance: Col<B32>,
+	query_bits: Col<B1, 32>,
+	query_bit_cols: [Col<B1>; 32],
+	u: Col<B128>,
+	v: Col<B128>,
+	twiddle: Col<B32>,
+	challenge: Col<B128>,
+	fold: Col<B128>,
+}
+
+impl FriFoldTable {
+	/// Creates the table of the given round.
+	///
+	/// `twiddle_basis[j]` is the twiddle of the coset with index `2^j` in the codeword of the
+	/// round, for example `ntt.get_subspace_eval(log_len, 1 << j)` for a codeword of length
+	/// `2^log_len`. The length of the basis is the log number of cosets of the round.
+	///
+	/// ## Preconditions
+	///
+	/// * `twiddle_basis` must have at most 32 elements
+	pub fn new(
+		table: &mut TableBuilder,
+		pair_channel: ChannelId,
+		challenge_channel: ChannelId,
+		fold_channel: ChannelId,
+		round: usize,
+		twiddle_basis: &[B32],
+	) -> Self {
+		assert!(twiddle_basis.len() <= 32, "the query must fit in 32 bits");
+
+		let instance = table.add_committed("instance");
+		let round_tag = table.add_constant("round_tag", [position(round)]);
+		let next_round_tag = table.add_constant("next_round_tag", [position(round + 1)]);
+		let query_bits = table.add_committed::<B1, 32>("query_bits");
+		let query: Col<B32> = table.add_packed("query", query_bits);
+		let query_bit_cols: [Col<B1>; 32] =
+			array::from_fn(|j| table.add_selected(format!("query_bits[{j}]"), query_bits, j));
+		for (j, &bit) in query_bit_cols.iter().enumerate().skip(twiddle_basis.len()) {
+			table.assert_zero(format!("query_bits[{j}] = 0"), bit.into());
+		}
+		// The twiddle of a round with a single coset is zero.
+		let twiddle_expr = (1..twiddle_basis.len()).fold(
+			upcast_col(query_bit_cols[0]) * twiddle_basis.first().copied().unwrap_or(B32::ZERO),
+			|expr, j| expr + upcast_col(query_bit_cols[j]) * twiddle_basis[j],
+		);
+		let twiddle = table.add_computed("twiddle", twiddle_expr);
+		let u = table.add_committed("u");
+		let v = table.add_committed("v");
+		let challenge = table.add_committed("challenge");
+
+		let butterfly_v = v + u;
+		let butterfly_u = butterfly_v.clone() * upcast_col(twiddle) + u;
+		let fold = table
+			.add_computed("fold", butterfly_u.clone() + (butterfly_v - butterfly_u) * challenge);
+
+		let (instance_upcast, query_upcast) = (upcast_col(instance), upcast_col(query));
+		let round_tag_upcast = upcast_col(round_tag);
+		table.pull(pair_channel, [instance_upcast, query_upcast, round_tag_upcast, u, v]);
+		table.pull(challenge_channel, [instance_upcast, round_tag_upcast, challenge]);
+		table.push(
+			fold_channel,
+			[
+				instance_upcast,
+				query_upcast,
+				upcast_col(next_round_tag),
+				fold,
+			],
+		);
+
+		Self {
+			table_id: table.id(),
+			twiddle_basis: twiddle_basis.to_vec(),
+			instance,
+			query_bits,
+			query_bit_cols,
+			u,
+			v,
+			twiddle,
+			challenge,
+			fold,
+		}
+	}
+
+	/// Returns the twiddle of the coset with index `query`.
+	pub fn twiddle(&self, query: u32) -> B32 {
+		self.twiddle_basis
+			.iter()
+			.enumerate()
+			.filter(|&(j, _)| (query >> j) & 1 == 1)
+			.map(|(_, &basis)| basis)
+			.sum()
+	}
+
+	/// Returns the folded value of a folding step.
+	pub fn fold(&self, fold: &FriFold) -> B128 {
+		fold_pair(fold.values, self.twiddle(fold.query), fold.challenge)
+	}
+}
+
+impl<P> TableFiller<P> for FriFoldTable
+where
+	P: PackedFieldIndexable<Scalar = B128>
+		+ PackedExtension<B1>
+		+ PackedExtension<B32>
+		+ PackedExtension<B128>,
+	PackedSubfield<P, B32>: PackedFieldIndexable,
+{
+	type Event = FriFold;
+
+	fn id(&self) -> TableId {
+		self.table_id
+	}
+
+	fn fill(
+		&self,
+		rows: &[Self::Event],
+		witness: &mut TableWitnessSegment<P>,
+	) -> anyhow::Result<()> {
+		let mut instance = witness.get_scalars_mut(self.instance)?;
+		let mut query_bits = witness.get_mut_as::<u32, _, 32>(self.query_bits)?;
+		let mut query_bit_cols: [_; 32] =
+			array_util::try_from_fn(|j| witness.get_mut(self.query_bit_cols[j]))?;
+		let mut u = witness.get_mut_as::<B128, B128, 1>(self.u)?;
+		let mut v = witness.get_mut_as::<B128, B128, 1>(self.v)?;
+		let mut twiddle = witness.get_scalars_mut(self.twiddle)?;
+		let mut challenge = witness.get_mut_as::<B128, B128, 1>(self.challenge)?;
+		let mut fold = witness.get_mut_as::<B128, B128, 1>(self.fold)?;
+		for (i, row) in rows.iter().enumerate() {
+			instance[i] = B32::new(row.instance);
+			query_bits[i] = row.query;
+			for (j, bits) in query_bit_cols.iter_mut().enumerate() {
+				set_packed_slice(bits, i, B1::from((row.query >> j) & 1 == 1));
+			}
+			(u[i], v[i]) = row.values;
+			twiddle[i] = self.twiddle(row.query);
+			challenge[i] = row.challenge;
+			fold[i] = self.fold(row);
+		}
+		Ok(())
+	}
+}
+
+/// The opening of a FRI query in a round: the values of the coset the query folds.
+#[derive(Debug, Clone, Copy)]
+pub struct FriOpening {
+	pub instance: u32,
+	/// In the first round, the index of the coset, which is the sampled query. In later rounds,
+	/// the index in the codeword of the round of the value folded by the previous round, whose
+	/// coset has index `index >> 1`.
+	pub index: u32,
+	/// The codeword values at the two positions of the coset.
+	pub values: (B128, B128),
+}
+
+impl FriOpening {
+	/// The index of the opened coset in the codeword of the round.
+	pub fn coset(&self, round: usize) -> u32 {
+		if round == 0 {
+			self.index
+		} else {
+			self.index >> 1
+		}
+	}
+
+	/// The value of the coset at `index`, which the previous round folded.
+	pub fn folded_value(&self) -> B128 {
+		let (u, v) = self.values;
+		if self.index & 1 == 0 { u } else { v }
+	}
+}
+
+/// A table connecting the opened values of a FRI round to the queries and to the Merkle tree of
+/// the committed codeword.
+///
+/// In the first round, each row pulls `(instance, query)` from the query channel, and the query is
+/// the index of the opened coset. In later rounds, each row pulls `(instance, index, round_tag,
+/// value)` from the fold channel, where `index` is the position of the value folded by the
+/// previous round in the codeword of the round, the opened coset is `index >> 1`, and `value` is
+/// the value of the coset at `index`. The row pushes `(instance, coset, round_tag, u, v)` to the
+/// pair channel, hashes the Merkle leaf of the coset, which is the Grøstl-256 digest of the
+/// serialized `u` and `v`, and pushes `(root_id, leaf, depth, coset)` to the nodes channel of a
+/// [`crate::gadgets::merkle_tree::MerkleTreeCS`], where `depth` is the depth of the tree and the
+/// root id is the round tag, as pushed by the
+/// [`TranscriptStepTable`](super::transcript::TranscriptStepTable) that absorbs the commitment.
+pub struct FriOpeningTable {
+	table_id: TableId,
+	round: usize,
+	instance: Col<B32>,
+	index_bits: Col<B1, 32>,
+	coset_bits: Option<Col<B1, 32>>,
+	parity: Option<Col<B1>>,
+	value: Option<Col<B128>>,
+	hash: Groestl256Hash,
+	u: StandardBytes,
+	v: StandardBytes,
+}
+
+impl FriOpeningTable {
+	/// Creates the table of the given round, whose codeword is committed in a Merkle tree of
+	/// depth `tree_depth`.
+	#[allow(clippy::too_many_arguments)]
+	pub fn new(
+		table: &mut TableBuilder,
+		query_channel: ChannelId,
+		fold_channel: ChannelId,
+		pair_channel: ChannelId,
+		nodes_channel: ChannelId,
+		round: usize,
+		tree_depth: usize,
+	) -> Self {
+		let instance = table.add_committed("instance");
+		let round_tag = table.add_constant("round_tag", [position(round)]);
+		let depth = table.add_constant("depth", [B32::new(tree_depth as u32)]);
+		let index_bits = table.add_committed::<B1, 32>("index_bits");
+		let index: Col<B32> = table.add_packed("index", index_bits);
+
+		let hash = Groestl256Hash::new(&mut table.with_namespace("leaf"), 2 * 16);
+		let u = StandardBytes::new(
+			&mut table.with_namespace("u"),
+			array::from_fn(|m| hash.message_byte(m)),
+		);
+		let v = StandardBytes::new(
+			&mut table.with_namespace("v"),
+			array::from_fn(|m| hash.message_byte(16 + m)),
+		);
+
+		let instance_upcast = upcast_col(instance);
+		let round_tag_upcast = upcast_col(round_tag);
+		let (coset, coset_bits, parity, value) = if round == 0 {
+			table.pull(query_channel, [instance_upcast, upcast_col(index)]);
+			(index, None, None, None)
+		} else {
+			let coset_bits =
+				table.add_shifted("coset_bits", index_bits, 5, 1, ShiftVariant::LogicalRight);
+			let coset = table.add_packed("coset", coset_bits);
+			let parity = table.add_selected("parity", index_bits, 0);
+			let value =
+				table.add_computed("value", u.value + (v.value - u.value) * upcast_col(parity));
+			table.pull(fold_channel, [instance_upcast, upcast_col(index), round_tag_upcast, value]);
+			(coset, Some(coset_bits), Some(parity), Some(value))
+		};
+		table.push(
+			pair_channel,
+			[
+				instance_upcast,
+				upcast_col(coset),
+				round_tag_upcast,
+				u.value,
+				v.value,
+			],
+		);
+		let mut leaf = vec![round_tag];
+		leaf.extend(hash.digest());
+		leaf.extend([depth, coset]);
+		table.push(nodes_channel, leaf);
+
+		Self {
+			table_id: table.id(),
+			round,
+			instance,
+			index_bits,
+			coset_bits,
+			parity,
+			value,
+			hash,
+			u,
+			v,
+		}
+	}
+}
+
+impl<P> TableFiller<P> for FriOpeningTable
+where
+	P: PackedFieldIndexable<Scalar = B128>
+		+ PackedExtension<B1>
+		+ PackedExtension<B8>
+		+ PackedExtension<B32>
+		+ PackedExtension<B128>,
+	PackedSubfield<P, B8>: PackedTransformationFactory<PackedSubfield<P, B8>>,
+	PackedSubfield<P, B32>: PackedFieldIndexable,
+{
+	type Event = FriOpening;
+
+	fn id(&self) -> TableId {
+		self.table_id
+	}
+
+	fn fill(
+		&self,
+		rows: &[Self::Event],
+		witness: &mut TableWitnessSegment<P>,
+	) -> anyhow::Result<()> {
+		let leaves = rows.iter().map(|row| {
+			let (u, v) = row.values;
+			[u.val().to_le_bytes(), v.val().to_le_bytes()].concat()
+		});
+		self.hash.populate(witness, leaves)?;
+		self.u
+			.populate(witness, rows.iter().map(|row| row.values.0.val()))?;
+		self.v
+			.populate(witness, rows.iter().map(|row| row.values.1.val()))?;
+
+		let mut instance = witness.get_scalars_mut(self.instance)?;
+		let mut index_bits = witness.get_mut_as::<u32, _, 32>(self.index_bits)?;
+		for (i, row) in rows.iter().enumerate() {
+			instance[i] = B32::new(row.instance);
+			index_bits[i] = row.index;
+		}
+		if let Some(coset_bits) = self.coset_bits {
+			let mut coset_bits = witness.get_mut_as::<u32, _, 32>(coset_bits)?;
+			for (i, row) in rows.iter().enumerate() {
+				coset_bits[i] = row.coset(self.round);
+			}
+		}
+		if let Some(parity) = self.parity {
+			let mut parity = witness.get_mut(parity)?;
+			for (i, row) in rows.iter().enumerate() {
+				set_packed_slice(&mut parity, i, B1::from(row.index & 1 == 1));
+			}
+		}
+		if let Some(value) = self.value {
+			let mut value = witness.get_mut_as::<B128, B128, 1>(value)?;
+			for (i, row) in rows.iter().enumerate() {
+				value[i] = row.folded_value();
+			}
+		}
+		Ok(())
+	}
+}
+
+/// The final value of a FRI query after the last folding round.
+#[derive(Debug, Clone, Copy)]
+pub struct FriFinal {
+	pub instance: u32,
+	/// The index of the value in the final codeword.
+	pub query: u32,
+	pub value: B128,
+}
+
+/// A table checking that the queries fold into the final codeword, which is a constant the
+/// prover sends.
+///
+/// Each row pulls `(instance, query, final_tag, value)` from the fold channel and the value of
+/// the final codeword as `(instance, final_tag, 0, value)` from the message channel, where the
+/// final tag is the [`position`] of the round after the last folding round.
+pub struct FriFinalTable {
+	table_id: TableId,
+	instance: Col<B32>,
+	query: Col<B32>,
+	value: Col<B128>,
+}
+
+impl FriFinalTable {
+	pub fn new(
+		table: &mut TableBuilder,
+		fold_channel: ChannelId,
+		message_channel: ChannelId,
+		n_rounds: usize,
+	) -> Self {
+		let instance = table.add_committed("instance");
+		let query = table.add_committed("query");
+		let value = table.add_committed("value");
+		let final_tag = upcast_col(table.add_constant("final_tag", [position(n_rounds)]));
+		let slot = upcast_col(table.add_constant("slot", [B32::ZERO]));
+
+		let instance_upcast = upcast_col(instance);
+		table.pull(fold_channel, [instance_upcast, upcast_col(query), final_tag, value]);
+		table.pull(message_channel, [instance_upcast, final_tag, slot, value]);
+
+		Self {
+			table_id: table.id(),
+			instance,
+			query,
+			value,
+		}
+	}
+}
+
+impl<P> TableFiller<P> for FriFinalTable
+where
+	P: PackedFieldIndexable<Scalar = B128> + PackedExtension<B32> + PackedExtension<B128>,
+	PackedSubfield<P, B32>: PackedFieldIndexable,
+{
+	type Event = FriFinal;
+
+	fn id(&self) -> TableId {
+		self.table_id
+	}
+
+	fn fill(
+		&self,
+		rows: &[Self::Event],
+		witness: &mut TableWitnessSegment<P>,
+	) -> anyhow::Result<()> {
+		let mut instance = witness.get_scalars_mut(self.instance)?;
+		let mut query = witness.get_scalars_mut(self.query)?;
+		let mut value = witness.get_mut_as::<B128, B128, 1>(self.value)?;
+		for (i, row) in rows.iter().enumerate() {
+			instance[i] = B32::new(row.instance);
+			query[i] = B32::new(row.query);
+			value[i] = row.value;
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,286 @@
+// Copyright 2025 Irreducible Inc.
+
+//! A Binius verifier expressed as M3 tables, for recursive proof composition.
+//!
+//! The tables check the parts of a verification:
+//!
+//! - [`transcript::TranscriptStepTable`] replays the Fiat–Shamir transcript in-circuit. It absorbs
+//!   the messages of a round with the Grøstl-256 gadget, in the way
+//!   [`HasherChallenger`](binius_core::fiat_shamir::HasherChallenger) does, and pushes the sampled
+//!   challenges and queries to the channels the other tables pull them from.
+//! - [`sumcheck::SumcheckRoundTable`] checks that sumcheck round polynomials are consistent with
+//!   the claimed sums, and chains the round claims.
+//! - [`fri::FriFoldTable`] checks the FRI folding steps of the queries, and
+//!   [`fri::FriOpeningTable`] hashes the opened cosets into leaves of the Merkle trees checked by
+//!   the [`crate::gadgets::merkle_tree`] tables, whose roots are the commitments absorbed by the
+//!   transcript.
+//!
+//! [`verifier::EvalProofVerifier`] combines the tables into the verifier of a FRI-Binius
+//! evaluation proof, and fills them from a proof by replaying its transcript.
+//!
+//! # Scope
+//!
+//! The tables target fixed verifier shapes: the number of rounds and queries, the degree of the
+//! round polynomials and the twiddles of the FRI rounds are fixed when the constraint system is
+//! built. The verifier of the recursive proof only pushes the claim and the initial challenger
+//! state as boundaries.
+
+pub mod fri;
+pub mod sumcheck;
+pub mod transcript;
+pub mod verifier;
+
+#[cfg(test)]
+mod tests {
+	use binius_compute::cpu::alloc::CpuComputeAllocator;
+	use binius_core::{
+		constraint_system::channel::{Boundary, FlushDirection},
+		fiat_shamir::{CanSample, HasherChallenger},
+		transcript::ProverTranscript,
+	};
+	use binius_field::{
+		Field, arch::OptimalUnderlier128b, as_packed_field::PackedType,
+		util::inner_product_unchecked,
+	};
+	use binius_hash::groestl::Groestl256;
+	use binius_ntt::{AdditiveNTT, SingleThreadedNTT, fri::fold_chunk};
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use super::{
+		fri::{FriFold, FriFoldTable},
+		sumcheck::{SumcheckRoundTable, replay_sumcheck},
+	};
+	use crate::{
+		builder::{B32, B128, ConstraintSystem, WitnessIndex},
+		gadgets::util::position,
+	};
+
+	/// Proves the sum of the product of two multilinears, binding the low variables first.
+	fn prove_product_sumcheck(
+		mut f: Vec<B128>,
+		mut g: Vec<B128>,
+		transcript: &mut ProverTranscript<HasherChallenger<Groestl256>>,
+	) {
+		let batch_coeff: B128 = transcript.sample();
+		while f.len() > 1 {
+			let half = f.len() / 2;
+			let mut coeffs = [B128::ZERO; 3];
+			for i in 0..half {
+				let (f0, f1) = (f[2 * i], f[2 * i + 1]);
+				let (g0, g1) = (g[2 * i], g[2 * i + 1]);
+				coeffs[0] += f0 * g0;
+				coeffs[1] += f0 * (g1 - g0) + g0 * (f1 - f0);
+				coeffs[2] += (f1 - f0) * (g1 - g0);
+			}
+			let coeffs = coeffs.map(|coeff| batch_coeff * coeff);
+			transcript.message().write_scalar_slice(&coeffs[..2]);
+
+			let challenge: B128 = transcript.sample();
+			let fold = |values: &[B128]| {
+				(0..half)
+					.map(|i| values[2 * i] + (values[2 * i + 1] - values[2 * i]) * challenge)
+					.collect::<Vec<_>>()
+			};
+			f = fold(&f);
+			g = fold(&g);
+		}
+		transcript.message().write_scalar_slice(&[f[0], g[0]]);
+	}
+
+	fn sumcheck_witness(corrupt_round: Option<usize>) -> anyhow::Result<()> {
+		const N_VARS: usize = 5;
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let f = (0..1 << N_VARS)
+			.map(|_| B128::random(&mut rng))
+			.collect::<Vec<_>>();
+		let g = (0..1 << N_VARS)
+			.map(|_| B128::random(&mut rng))
+			.collect::<Vec<_>>();
+		let sum = inner_product_unchecked(f.iter().copied(), g.iter().copied());
+
+		let mut prover_transcript = ProverTranscript::new();
+		prove_product_sumcheck(f, g, &mut prover_transcript);
+		let mut transcript = prover_transcript.into_verifier();
+		let mut replay = replay_sumcheck(3, sum, N_VARS, 2, &mut transcript)?;
+		let evals = transcript.message().read_scalar_slice::<B128>(2)?;
+		transcript.finalize()?;
+		assert_eq!(replay.eval, replay.batch_coeff * evals[0] * evals[1]);
+
+		if let Some(round) = corrupt_round {
+			replay.rounds[round].coeffs[1] += B128::ONE;
+		}
+
+		let mut cs = ConstraintSystem::new();
+		let claim_channel = cs.add_channel("sumcheck_claims");
+		let challenge_channel = cs.add_channel("challenges");
+		let message_channel = cs.add_channel("messages");
+		let table = SumcheckRoundTable::new(
+			&mut cs.add_table("sumcheck_rounds"),
+			claim_channel,
+			challenge_channel,
+			message_channel,
+			2,
+		);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		witness.fill_table_sequential(&table, &replay.rounds)?;
+
+		witness.fill_constant_cols()?;
+
+		let boundaries = replay.boundaries(claim_channel, challenge_channel, message_channel);
+		let ccs = cs.compile()?;
+		let table_sizes = witness.table_sizes();
+		let witness = witness.into_multilinear_extension_index();
+		binius_core::constraint_system::validate::validate_witness(
+			&ccs,
+			&boundaries,
+			&table_sizes,
+			&witness,
+		)?;
+		Ok(())
+	}
+
+	#[test]
+	fn test_sumcheck_rounds() {
+		sumcheck_witness(None).unwrap();
+	}
+
+	#[test]
+	fn test_sumcheck_rounds_reject_corrupt_round() {
+		assert!(sumcheck_witness(Some(2)).is_err());
+	}
+
+	const FRI_LOG_LEN: usize = 6;
+	const FRI_INSTANCE: u32 = 1;
+
+	/// Folds a random codeword with two rounds of arity 1 and checks the folds with one table per
+	/// round. The pairs are pushed with the instance `pair_instance`.
+	fn fri_fold_witness(pair_instance: u32) -> anyhow::Result<()> {
+		let mut rng = StdRng::seed_from_u64(0);
+		let ntt = SingleThreadedNTT::<B32>::new(FRI_LOG_LEN).unwrap();
+		let codeword = (0..1 << FRI_LOG_LEN)
+			.map(|_| B128::random(&mut rng))
+			.collect::<Vec<_>>();
+		let challenges = [B128::random(&mut rng), B128::random(&mut rng)];
+
+		let mut cs = ConstraintSystem::new();
+		let pair_channel = cs.add_channel("fri_pairs");
+		let challenge_channel = cs.add_channel("challenges");
+		let fold_channel = cs.add_channel("fri_folds");
+		let tables = (0..challenges.len())
+			.map(|round| {
+				let log_len = FRI_LOG_LEN - round;
+				let twiddle_basis = (0..log_len - 1)
+					.map(|j| ntt.get_subspace_eval(log_len, 1 << j))
+					.collect::<Vec<_>>();
+				FriFoldTable::new(
+					&mut cs.add_table(format!("fri_folds[{round}]")),
+					pair_channel,
+					challenge_channel,
+					fold_channel,
+					round,
+					&twiddle_basis,
+				)
+			})
+			.collect::<Vec<_>>();
+
+		// Two rounds of arity 1 folding match a single fold of arity 2.
+		let mut folds = Vec::new();
+		let mut values = codeword.clone();
+		for (round, (&challenge, table)) in challenges.iter().zip(&tables).enumerate() {
+			let log_len = FRI_LOG_LEN - round;
+			let round_folds = values
+				.chunks(2)
+				.enumerate()
+				.map(|(index, pair)| {
+					assert_eq!(table.twiddle(index as u32), ntt.get_subspace_eval(log_len, index));
+					FriFold {
+						instance: FRI_INSTANCE,
+						query: index as u32,
+						values: (pair[0], pair[1]),
+						challenge,
+					}
+				})
+				.collect::<Vec<_>>();
+			values = round_folds.iter().map(|fold| table.fold(fold)).collect();
+			folds.push(round_folds);
+		}
+		for (index, chunk) in codeword.chunks(4).enumerate() {
+			let expected = fold_chunk(&ntt, FRI_LOG_LEN, index, &mut chunk.to_vec(), &challenges);
+			assert_eq!(values[index], expected);
+		}
+
+		let mut allocator = CpuComputeAllocator::new(1 << 12);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		for (table, round_folds) in tables.iter().zip(&folds) {
+			witness.fill_table_sequential(table, round_folds)?;
+		}
+		witness.fill_constant_cols()?;
+
+		let boundary = |channel_id, direction, values: Vec<B128>, multiplicity| Boundary {
+			values,
+			channel_id,
+			direction,
+			multiplicity,
+		};
+		// Every fold pulls the challenge of its round.
+		let instance = B128::from(B32::new(FRI_INSTANCE));
+		let mut boundaries = challenges
+			.iter()
+			.enumerate()
+			.map(|(round, &challenge)| {
+				let values = vec![instance, position(round).into(), challenge];
+				let n_folds = 1 << (FRI_LOG_LEN - round - 1);
+				boundary(challenge_channel, FlushDirection::Push, values, n_folds)
+			})
+			.collect::<Vec<_>>();
+		for (round, (table, round_folds)) in tables.iter().zip(&folds).enumerate() {
+			for fold in round_folds {
+				let query = B128::from(B32::new(fold.query));
+				let (u, v) = fold.values;
+				let pair = vec![
+					B32::new(pair_instance).into(),
+					query,
+					position(round).into(),
+					u,
+					v,
+				];
+				boundaries.push(boundary(pair_channel, FlushDirection::Push, pair, 1));
+				let folded = vec![
+					instance,
+					query,
+					position(round + 1).into(),
+					table.fold(fold),
+				];
+				boundaries.push(boundary(fold_channel, FlushDirection::Pull, folded, 1));
+			}
+		}
+
+		let ccs = cs.compile()?;
+		let table_sizes = witness.table_sizes();
+		let witness = witness.into_multilinear_extension_index();
+		binius_core::constraint_system::validate::validate_witness(
+			&ccs,
+			&boundaries,
+			&table_sizes,
+			&witness,
+		)?;
+		Ok(())
+	}
+
+	#[test]
+	fn test_fri_fold() {
+		fri_fold_witness(FRI_INSTANCE).unwrap();
+	}
+
+	#[test]
+	fn test_fri_fold_rejects_pairs_of_other_instance() {
+		assert!(fri_fold_witness(FRI_INSTANCE + 1).is_err());
+	}
+}
@@ -0,0 +1,388 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_core::{
+	constraint_system::channel::{Boundary, ChannelId, FlushDirection},
+	fiat_shamir::{CanSample, Challenger},
+	protocols::sumcheck::{RoundCoeffs, RoundProof},
+	transcript::{self, VerifierTranscript},
+};
+use binius_field::{BinaryField, Field, PackedExtension, PackedFieldIndexable, PackedSubfield};
+use binius_math::evaluate_univariate;
+
+use crate::{
+	builder::{
+		B32, B128, Col, Expr, TableBuilder, TableFiller, TableId, TableWitnessSegment, upcast_col,
+	},
+	gadgets::util::position,
+};
+
+/// A round of a sumcheck verification.
+#[derive(Debug, Clone)]
+pub struct SumcheckRound {
+	/// The identifier of the sumcheck instance.
+	pub instance: u32,
+	pub round: usize,
+	/// The claimed sum of the round.
+	pub claim: B128,
+	/// All coefficients of the round polynomial, in ascending order of degree.
+	pub coeffs: Vec<B128>,
+	pub challenge: B128,
+}
+
+impl SumcheckRound {
+	/// The claimed sum of the next round, which is the round polynomial evaluated at the challenge.
+	pub fn next_claim(&self) -> B128 {
+		evaluate_univariate(&self.coeffs, self.challenge)
+	}
+}
+
+/// A table checking sumcheck rounds with round polynomials of a fixed degree.
+///
+/// Each row checks one round. It pulls `(instance, round_tag, claim)` from the claim channel,
+/// `(instance, round_tag, challenge)` from the challenge channel, and the coefficients the prover
+/// sends as `(instance, round_tag, slot, coeff)` from the message channel, where the slot of a
+/// coefficient is its degree. It checks that the round polynomial `p` satisfies
+/// `p(0) + p(1) = claim`, and pushes `(instance, next_round_tag, p(challenge))` to the claim
+/// channel. The round tags are the [`position`]s of the rounds.
+///
+/// The prover sends all coefficients but the one of highest degree, which the verifier recovers
+/// from the claim, as [`RoundProof::recover`] does.
+pub struct SumcheckRoundTable {
+	table_id: TableId,
+	instance: Col<B32>,
+	round_tag: Col<B32>,
+	next_round_tag: Col<B32>,
+	claim: Col<B128>,
+	coeffs: Vec<Col<B128>>,
+	challenge: Col<B128>,
+	next_claim: Col<B128>,
+}
+
+impl SumcheckRoundTable {
+	pub fn new(
+		table: &mut TableBuilder,
+		claim_channel: ChannelId,
+		challenge_channel: ChannelId,
+		message_channel: ChannelId,
+		degree: usize,
+	) -> Self {
+		assert!(degree > 0, "round polynomials must have positive degree");
+
+		let instance = table.add_committed("instance");
+		let round_tag = table.add_committed("round_tag");
+		let next_round_tag =
+			table.add_computed("next_round_tag", round_tag * B32::MULTIPLICATIVE_GENERATOR);
+		let claim = table.add_committed("claim");
+		let coeffs = (0..=degree)
+			.map(|i| table.add_committed(format!("coeffs[{i}]")))
+			.collect::<Vec<Col<B128>>>();
+		let challenge = table.add_committed("challenge");
+
+		// In characteristic 2, p(0) + p(1) is the sum of all coefficients but the constant one.
+		let sum = coeffs[1..]
+			.iter()
+			.fold(Expr::from(claim), |sum, &coeff| sum - coeff);
+		table.assert_zero("round_sum", sum);
+
+		let evaluation = coeffs[..degree]
+			.iter()
+			.rev()
+			.fold(Expr::from(coeffs[degree]), |acc, &coeff| acc * challenge + coeff);
+		let next_claim = table.add_computed("next_claim", evaluation);
+
+		let (instance_upcast, round_tag_upcast) = (upcast_col(instance), upcast_col(round_tag));
+		table.pull(claim_channel, [instance_upcast, round_tag_upcast, claim]);
+		table.pull(challenge_channel, [instance_upcast, round_tag_upcast, challenge]);
+		for (j, &coeff) in coeffs[..degree].iter().enumerate() {
+			let slot = table.add_constant(format!("slot[{j}]"), [B32::new(j as u32)]);
+			table.pull(
+				message_channel,
+				[instance_upcast, round_tag_upcast, upcast_col(slot), coeff],
+			);
+		}
+		table.push(claim_channel, [instance_upcast, upcast_col(next_round_tag), next_claim]);
+
+		Self {
+			table_id: table.id(),
+			instance,
+			round_tag,
+			next_round_tag,
+			claim,
+			coeffs,
+			challenge,
+			next_claim,
+		}
+	}
+
+	/// The degree of the round polynomials.
+	pub fn degree(&self) -> usize {
+		self.coeffs.len() - 1
+	}
+}
+
+impl<P> TableFiller<P> for SumcheckRoundTable
+where
+	P: PackedFieldIndexable<Scalar = B128> + PackedExtension<B32> + PackedExtension<B128>,
+	PackedSubfield<P, B32>: PackedFieldIndexable,
+{
+	type Event = SumcheckRound;
+
+	fn id(&self) -> TableId {
+		self.table_id
+	}
+
+	fn fill(
+		&self,
+		rows: &[Self::Event],
+		witness: &mut TableWitnessSegment<P>,
+	) -> anyhow::Result<()> {
+		let mut instance = witness.get_scalars_mut(self.instance)?;
+		let mut round_tag = witness.get_scalars_mut(self.round_tag)?;
+		let mut next_round_tag = witness.get_scalars_mut(self.next_round_tag)?;
+		let mut claim = witness.get_mut_as::<B128, B128, 1>(self.claim)?;
+		let mut coeffs = self
+			.coeffs
+			.iter()
+			.map(|&coeff| witness.get_mut_as::<B128, B128, 1>(coeff))
+			.collect::<Result<Vec<_>, _>>()?;
+		let mut challenge = witness.get_mut_as::<B128, B128, 1>(self.challenge)?;
+		let mut next_claim = witness.get_mut_as::<B128, B128, 1>(self.next_claim)?;
+		for (i, row) in rows.iter().enumerate() {
+			anyhow::ensure!(
+				row.coeffs.len() == coeffs.len(),
+				"round polynomial has degree {}, expected {}",
+				row.coeffs.len().saturating_sub(1),
+				self.degree()
+			);
+			instance[i] = B32::new(row.instance);
+			round_tag[i] = position(row.round);
+			next_round_tag[i] = position(row.round + 1);
+			claim[i] = row.claim;
+			for (col, &coeff) in coeffs.iter_mut().zip(&row.coeffs) {
+				col[i] = coeff;
+			}
+			challenge[i] = row.challenge;
+			next_claim[i] = row.next_claim();
+		}
+		Ok(())
+	}
+}
+
+/// A factor of the equality indicator evaluated at the sumcheck challenges.
+#[derive(Debug, Clone, Copy)]
+pub struct EqIndRound {
+	pub instance: u32,
+	pub round: usize,
+	/// The product of the factors of the previous rounds.
+	pub acc: B128,
+	/// The coordinate of the evaluation point bound in the round.
+	pub point: B128,
+	pub challenge: B128,
+}
+
+impl EqIndRound {
+	/// The product of the factors up to and including this round.
+	pub fn next_acc(&self) -> B128 {
+		self.acc * (B128::ONE + self.point + self.challenge)
+	}
+}
+
+/// A table evaluating the multilinear equality indicator `eq(z, r)` at the sumcheck challenges
+/// `r`, one variable per row.
+///
+/// Each row pulls `(instance, round_tag, acc)` from the eq channel, `(instance, round_tag, z)`
+/// from the point channel and `(instance, round_tag, r)` from the challenge channel, and pushes
+/// `(instance, next_round_tag, acc · (1 + z + r))` to the eq channel. In characteristic 2,
+/// `1 + z + r = z·r + (1 - z)·(1 - r)` is the factor of the equality indicator for one variable.
+/// The verifier of the recursive proof pushes the coordinates of `z` and the initial product
+/// `1`, and the product after the last round is consumed by the table that checks the final
+/// sumcheck claim.
+pub struct EqIndTable {
+	table_id: TableId,
+	instance: Col<B32>,
+	round_tag: Col<B32>,
+	next_round_tag: Col<B32>,
+	acc: Col<B128>,
+	point: Col<B128>,
+	challenge: Col<B128>,
+	next_acc: Col<B128>,
+}
+
+impl EqIndTable {
+	pub fn new(
+		table: &mut TableBuilder,
+		eq_channel: ChannelId,
+		point_channel: ChannelId,
+		challenge_channel: ChannelId,
+	) -> Self {
+		let instance = table.add_committed("instance");
+		let round_tag = table.add_committed("round_tag");
+		let next_round_tag =
+			table.add_computed("next_round_tag", round_tag * B32::MULTIPLICATIVE_GENERATOR);
+		let acc = table.add_committed("acc");
+		let point = table.add_committed("point");
+		let challenge = table.add_committed("challenge");
+		let next_acc = table.add_computed("next_acc", acc * (point + challenge + B128::ONE));
+
+		let (instance_upcast, round_tag_upcast) = (upcast_col(instance), upcast_col(round_tag));
+		table.pull(eq_channel, [instance_upcast, round_tag_upcast, acc]);
+		table.pull(point_channel, [instance_upcast, round_tag_upcast, point]);
+		table.pull(challenge_channel, [instance_upcast, round_tag_upcast, challenge]);
+		table.push(eq_channel, [instance_upcast, upcast_col(next_round_tag), next_acc]);
+
+		Self {
+			table_id: table.id(),
+			instance,
+			round_tag,
+			next_round_tag,
+			acc,
+			point,
+			challenge,
+			next_acc,
+		}
+	}
+}
+
+impl<P> TableFiller<P> for EqIndTable
+where
+	P: PackedFieldIndexable<Scalar = B128> + PackedExtension<B32> + PackedExtension<B128>,
+	PackedSubfield<P, B32>: PackedFieldIndexable,
+{
+	type Event = EqIndRound;
+
+	fn id(&self) -> TableId {
+		self.table_id
+	}
+
+	fn fill(
+		&self,
+		rows: &[Self::Event],
+		witness: &mut TableWitnessSegment<P>,
+	) -> anyhow::Result<()> {
+		let mut instance = witness.get_scalars_mut(self.instance)?;
+		let mut round_tag = witness.get_scalars_mut(self.round_tag)?;
+		let mut next_round_tag = witness.get_scalars_mut(self.next_round_tag)?;
+		let mut acc = witness.get_mut_as::<B128, B128, 1>(self.acc)?;
+		let mut point = witness.get_mut_as::<B128, B128, 1>(self.point)?;
+		let mut challenge = witness.get_mut_as::<B128, B128, 1>(self.challenge)?;
+		let mut next_acc = witness.get_mut_as::<B128, B128, 1>(self.next_acc)?;
+		for (i, row) in rows.iter().enumerate() {
+			instance[i] = B32::new(row.instance);
+			round_tag[i] = position(row.round);
+			next_round_tag[i] = position(row.round + 1);
+			acc[i] = row.acc;
+			point[i] = row.point;
+			challenge[i] = row.challenge;
+			next_acc[i] = row.next_acc();
+		}
+		Ok(())
+	}
+}
+
+/// The verification of a sumcheck proof for a single claim, replayed from a transcript.
+#[derive(Debug, Clone)]
+pub struct SumcheckReplay {
+	pub instance: u32,
+	/// The coefficient the claimed sum is batched with.
+	pub batch_coeff: B128,
+	pub rounds: Vec<SumcheckRound>,
+	/// The claimed evaluation of the composite polynomial at the challenge point, scaled by the
+	/// batching coefficient.
+	pub eval: B128,
+}
+
+impl SumcheckReplay {
+	/// The claimed sum of the first round, which is the batched claimed sum.
+	pub fn initial_claim(&self) -> B128 {
+		self.rounds.first().map_or(self.eval, |round| round.claim)
+	}
+
+	pub fn challenges(&self) -> impl Iterator<Item = B128> + '_ {
+		self.rounds.iter().map(|round| round.challenge)
+	}
+
+	/// Returns the boundaries supplying the claims, challenges and round messages to a
+	/// [`SumcheckRoundTable`].
+	///
+	/// The boundaries push the initial claim, every challenge and the coefficients sent by the
+	/// prover, and pull the final claim [`Self::eval`], which the verifier must check against the
+	/// evaluation of the composite polynomial. This suits a verifier of the recursive proof that
+	/// replays the transcript itself; a [`super::transcript::TranscriptStepTable`] derives the
+	/// challenges and pushes the messages in-circuit instead.
+	pub fn boundaries(
+		&self,
+		claim_channel: ChannelId,
+		challenge_channel: ChannelId,
+		message_channel: ChannelId,
+	) -> Vec<Boundary<B128>> {
+		let instance = B128::from(B32::new(self.instance));
+		let boundary = |channel_id, direction, round: usize, value: B128| Boundary {
+			values: vec![instance, position(round).into(), value],
+			channel_id,
+			direction,
+			multiplicity: 1,
+		};
+
+		let mut boundaries = vec![
+			boundary(claim_channel, FlushDirection::Push, 0, self.initial_claim()),
+			boundary(claim_channel, FlushDirection::Pull, self.rounds.len(), self.eval),
+		];
+		boundaries.extend(self.rounds.iter().map(|round| {
+			boundary(challenge_channel, FlushDirection::Push, round.round, round.challenge)
+		}));
+		for round in &self.rounds {
+			let sent = &round.coeffs[..round.coeffs.len() - 1];
+			boundaries.extend(sent.iter().enumerate().map(|(slot, &coeff)| Boundary {
+				values: vec![
+					instance,
+					position(round.round).into(),
+					B32::new(slot as u32).into(),
+					coeff,
+				],
+				channel_id: message_channel,
+				direction: FlushDirection::Push,
+				multiplicity: 1,
+			}));
+		}
+		boundaries
+	}
+}
+
+/// Replays the verification of a sumcheck proof for a single claim.
+///
+/// Samples the batching coefficient, reads the round polynomials and samples the round challenges
+/// in the same order as [`binius_core::protocols::sumcheck::batch_verify`] does for a batch of one
+/// claim over `n_vars` variables with a single composite of individual degree `degree`. The
+/// transcript is left positioned at the multilinear evaluations that follow the rounds.
+pub fn replay_sumcheck<Challenger_: Challenger>(
+	instance: u32,
+	sum: B128,
+	n_vars: usize,
+	degree: usize,
+	transcript: &mut VerifierTranscript<Challenger_>,
+) -> Result<SumcheckReplay, transcript::Error> {
+	let batch_coeff: B128 = transcript.sample();
+	let mut claim = batch_coeff * sum;
+	let mut rounds = Vec::with_capacity(n_vars);
+	for round in 0..n_vars {
+		let coeffs = transcript.message().read_scalar_slice(degree)?;
+		let RoundCoeffs(coeffs) = RoundProof(RoundCoeffs(coeffs)).recover(claim);
+		let challenge = transcript.sample();
+		let sumcheck_round = SumcheckRound {
+			instance,
+			round,
+			claim,
+			coeffs,
+			challenge,
+		};
+		claim = sumcheck_round.next_claim();
+		rounds.push(sumcheck_round);
+	}
+
+	Ok(SumcheckReplay {
+		instance,
+		batch_coeff,
+		rounds,
+		eval: claim,
+	})
+}
@@ -0,0 +1,519 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{array, iter};
+
+use binius_core::constraint_system::channel::{Boundary, ChannelId, FlushDirection};
+use binius_field::{
+	BinaryField, PackedExtension, PackedFieldIndexable, PackedSubfield, ext_basis,
+	linear_transformation::PackedTransformationFactory, packed::set_packed_slice,
+};
+use binius_hash::groestl::Groestl256;
+use digest::Digest;
+
+use crate::{
+	builder::{
+		B1, B8, B32, B128, Col, ConstraintSystem, FlushOpts, TableBuilder, TableFiller, TableId,
+		TableWitnessSegment, upcast_col,
+	},
+	gadgets::{
+		hash::groestl::{Groestl256Hash, isomorphic_byte},
+		util::position,
+	},
+};
+
+/// The number of bytes of a Grøstl-256 digest.
+const DIGEST_LEN: usize = 32;
+
+/// The number of bytes of a serialized [`B128`].
+const SCALAR_LEN: usize = 16;
+
+/// The number of bytes a step hashes before the messages: the digest of the previous step and
+/// the little-endian 64-bit number of its bytes that were sampled.
+const PREFIX_LEN: usize = DIGEST_LEN + 8;
+
+/// Returns the rows of a digest in the layout of the [`crate::gadgets::merkle_tree`] channels.
+///
+/// Row `i` packs the bytes `i`, `8 + i`, `16 + i` and `24 + i` of the digest, in the isomorphic
+/// representation of the Grøstl gadgets.
+pub fn digest_rows(digest: &[u8; DIGEST_LEN]) -> [B32; 8] {
+	array::from_fn(|i| {
+		B32::new(u32::from_le_bytes(array::from_fn(|j| isomorphic_byte(digest[j * 8 + i]).val())))
+	})
+}
+
+/// Sixteen bytes of a Grøstl state, decomposed into the bits of their standard representation.
+///
+/// The bits pack into a [`B128`] whose little-endian serialization is the bytes, which is how the
+/// transcripts serialize field elements. Converting bytes between the standard and the isomorphic
+/// representation is linear over GF(2) only, so the conversion goes through the bits.
+#[derive(Debug, Clone)]
+pub(super) struct StandardBytes {
+	bits: Col<B1, 128>,
+	bit_cols: [Col<B1>; 128],
+	/// The bits packed into a field element.
+	pub value: Col<B128>,
+}
+
+impl StandardBytes {
+	pub fn new(table: &mut TableBuilder, bytes: [Col<B8>; SCALAR_LEN]) -> Self {
+		let bits = table.add_committed::<B1, 128>("bits");
+		let bit_cols: [Col<B1>; 128] =
+			array::from_fn(|t| table.add_selected(format!("bits[{t}]"), bits, t));
+		for (m, byte) in bytes.into_iter().enumerate() {
+			let isomorphic = (0..8)
+				.map(|t| upcast_col(bit_cols[8 * m + t]) * isomorphic_byte(1 << t))
+				.reduce(|a, b| a + b)
+				.expect("bytes have 8 bits");
+			table.assert_zero(format!("bytes[{m}]"), isomorphic - byte);
+		}
+		let value = table.add_packed("value", bits);
+		Self {
+			bits,
+			bit_cols,
+			value,
+		}
+	}
+
+	/// Returns the column of bit `t`, which is bit `t % 8` of byte `t / 8`.
+	pub fn bit(&self, t: usize) -> Col<B1> {
+		self.bit_cols[t]
+	}
+
+	pub fn populate<P>(
+		&self,
+		index: &mut TableWitnessSegment<P>,
+		values: impl IntoIterator<Item = u128>,
+	) -> anyhow::Result<()>
+	where
+		P: PackedFieldIndexable<Scalar = B128> + PackedExtension<B1>,
+	{
+		let mut bits = index.get_mut_as::<u128, B1, 128>(self.bits)?;
+		let mut bit_cols: [_; 128] = array_util::try_from_fn(|t| index.get_mut(self.bit_cols[t]))?;
+		for (k, value) in values.into_iter().enumerate() {
+			bits[k] = value;
+			for (t, bit_col) in bit_cols.iter_mut().enumerate() {
+				set_packed_slice(bit_col, k, B1::from((value >> t) & 1 == 1));
+			}
+		}
+		Ok(())
+	}
+}
+
+/// The values a transcript step samples from its digest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Squeeze {
+	/// A [`B128`] challenge, pushed `multiplicity` times to the challenge channel.
+	Challenge { multiplicity: u32 },
+	/// Query indices, each sampled as a little-endian `u32` masked to its `n_bits` low bits, and
+	/// pushed once to the query channel.
+	Queries { n_queries: usize, n_bits: usize },
+}
+
+impl Squeeze {
+	/// The number of digest bytes the step samples.
+	pub fn n_bytes(&self) -> usize {
+		match *self {
+			Self::Challenge { .. } => SCALAR_LEN,
+			Self::Queries { n_queries, .. } => 4 * n_queries,
+		}
+	}
+}
+
+/// The messages a transcript step absorbs, and the values it samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TranscriptStepShape {
+	/// The number of digests the step absorbs, which are commitments pushed to the root channel.
+	pub n_digests: usize,
+	/// The number of times each digest is pushed, which is the number of Merkle paths opened
+	/// against it.
+	pub digest_multiplicity: u32,
+	/// The number of [`B128`] scalars the step absorbs after the digests.
+	pub n_scalars: usize,
+	/// The number of times each scalar is pushed to the message channel.
+	pub scalar_multiplicity: u32,
+	pub squeeze: Squeeze,
+	/// Whether the step is the last of the transcript, in which case the challenger state is not
+	/// pushed for a next step.
+	pub is_last: bool,
+}
+
+impl TranscriptStepShape {
+	/// The number of bytes the step hashes.
+	pub fn preimage_len(&self) -> usize {
+		PREFIX_LEN + DIGEST_LEN * self.n_digests + SCALAR_LEN * self.n_scalars
+	}
+}
+
+/// The channels connecting the transcript steps to the tables that use the messages and the
+/// challenges.
+#[derive(Debug, Clone, Copy)]
+pub struct TranscriptChannels {
+	/// The challenger states between the steps, flushed as `(step_tag, digest, sampled)`, where
+	/// `digest` is in the layout of [`digest_rows`] and `sampled` is the isomorphic byte of the
+	/// number of digest bytes sampled.
+	pub state: ChannelId,
+	/// The scalars, flushed as `(instance, round_tag, slot, value)`, where `slot` is the position
+	/// of the scalar among the scalars of the step.
+	pub message: ChannelId,
+	/// The commitments, flushed as `(root_id, digest)`, for example the roots channel of
+	/// [`crate::gadgets::merkle_tree::MerkleTreeCS`].
+	pub root: ChannelId,
+	/// The challenges, flushed as `(instance, round_tag, challenge)`.
+	pub challenge: ChannelId,
+	/// The query indices, flushed as `(instance, query)`.
+	pub query: ChannelId,
+}
+
+impl TranscriptChannels {
+	pub fn new(cs: &mut ConstraintSystem, root: ChannelId, challenge: ChannelId) -> Self {
+		Self {
+			state: cs.add_channel("transcript_states"),
+			message: cs.add_channel("transcript_messages"),
+			root,
+			challenge,
+			query: cs.add_channel("transcript_queries"),
+		}
+	}
+
+	/// Returns the boundary pushing the state of a fresh
+	/// [`HasherChallenger<Groestl256>`](binius_core::fiat_shamir::HasherChallenger), which samples
+	/// from the digest of the empty string, for the first step.
+	pub fn initial_state_boundary(&self) -> Boundary<B128> {
+		let digest: [u8; DIGEST_LEN] = Groestl256::digest(b"").into();
+		let values = iter::once(position(0))
+			.chain(digest_rows(&digest))
+			.map(B128::from)
+			.chain(iter::once(B128::from(isomorphic_byte(0))))
+			.collect();
+		Boundary {
+			values,
+			channel_id: self.state,
+			direction: FlushDirection::Push,
+			multiplicity: 1,
+		}
+	}
+}
+
+/// A step of a Fiat–Shamir transcript hashed with
+/// [`HasherChallenger<Groestl256>`](binius_core::fiat_shamir::HasherChallenger): the messages of a
+/// round, followed by the values sampled after them.
+#[derive(Debug, Clone)]
+pub struct TranscriptStep {
+	/// The round the step closes. The step absorbs the messages of the round and samples its
+	/// challenges.
+	pub round: usize,
+	/// The digest the challenger sampled from before the step.
+	pub state: [u8; DIGEST_LEN],
+	/// The number of bytes of `state` that were sampled.
+	pub sampled: usize,
+	pub digests: Vec<[u8; DIGEST_LEN]>,
+	pub scalars: Vec<B128>,
+}
+
+impl TranscriptStep {
+	/// Returns the bytes the step hashes.
+	///
+	/// When the challenger switches from sampling to observing, it hashes the number of bytes
+	/// sampled from its digest after the digest, and then the observed messages.
+	pub fn preimage(&self) -> Vec<u8> {
+		let mut preimage = self.state.to_vec();
+		preimage.extend_from_slice(&(self.sampled as u64).to_le_bytes());
+		for digest in &self.digests {
+			preimage.extend_from_slice(digest);
+		}
+		for scalar in &self.scalars {
+			preimage.extend_from_slice(&scalar.val().to_le_bytes());
+		}
+		preimage
+	}
+
+	/// Returns the digest the values of the step are sampled from.
+	pub fn digest(&self) -> [u8; DIGEST_LEN] {
+		Groestl256::digest(self.preimage()).into()
+	}
+
+	/// Returns the challenge sampled by the step.
+	pub fn challenge(&self) -> B128 {
+		let digest = self.digest();
+		B128::new(u128::from_le_bytes(array::from_fn(|i| digest[i])))
+	}
+
+	/// Returns the query indices sampled by the step.
+	pub fn queries(&self, n_queries: usize, n_bits: usize) -> Vec<u32> {
+		let digest = self.digest();
+		digest[..4 * n_queries]
+			.chunks_exact(4)
+			.map(|bytes| {
+				let query = u32::from_le_bytes(bytes.try_into().expect("chunks have 4 bytes"));
+				query & mask(n_bits)
+			})
+			.collect()
+	}
+}
+
+fn mask(n_bits: usize) -> u32 {
+	1u32.checked_shl(n_bits as u32)
+		.map_or(u32::MAX, |bit| bit - 1)
+}
+
+/// A table hashing steps of a Fiat–Shamir transcript with Grøstl-256, in the way
+/// [`HasherChallenger<Groestl256>`](binius_core::fiat_shamir::HasherChallenger) does.
+///
+/// Each row checks one step of a fixed shape. It pulls `(step_tag, state, sampled)` from the
+/// state channel, hashes the state digest, the number of sampled bytes and the messages of the
+/// step, and samples the challenge or the queries of the step from the resulting digest. The
+/// digests it absorbs are pushed to the root channel with the step tag as root id, the scalars to
+/// the message channel, the challenge to the challenge channel, and the queries to the query
+/// channel. Unless the step is the last, the row pushes `(next_step_tag, digest, sampled)` for
+/// the next step. The step tags are the [`position`]s of the rounds, so the messages and the
+/// challenges are tagged with the round they belong to.
+///
+/// A transcript of a fixed shape uses one table per step shape, and its first step pulls the
+/// [`TranscriptChannels::initial_state_boundary`].
+pub struct TranscriptStepTable {
+	table_id: TableId,
+	shape: TranscriptStepShape,
+	step_tag: Col<B32>,
+	next_step_tag: Option<Col<B32>>,
+	hash: Groestl256Hash,
+	state: [Col<B32>; 8],
+	digests: Vec<[Col<B32>; 8]>,
+	scalars: Vec<StandardBytes>,
+	squeezed: Vec<StandardBytes>,
+	queries: Vec<Col<B32>>,
+}
+
+impl TranscriptStepTable {
+	/// Creates a table of transcript steps of the given shape, whose scalars, challenges and
+	/// queries are tagged with `instance`.
+	///
+	/// ## Preconditions
+	///
+	/// * the step must sample at most the 32 bytes of a digest
+	/// * the queries must have between 1 and 32 bits
+	pub fn new(
+		table: &mut TableBuilder,
+		channels: &TranscriptChannels,
+		instance: u32,
+		shape: TranscriptStepShape,
+	) -> Self {
+		assert!(shape.squeeze.n_bytes() <= DIGEST_LEN, "a step samples from a single digest");
+		if let Squeeze::Queries { n_bits, .. } = shape.squeeze {
+			assert!((1..=32).contains(&n_bits), "queries are sampled from 32 bits");
+		}
+
+		let instance = upcast_col(table.add_constant("instance", [B32::new(instance)]));
+		let step_tag = table.add_committed("step_tag");
+		let hash = Groestl256Hash::new(&mut table.with_namespace("hash"), shape.preimage_len());
+
+		// Packs message bytes `offset..offset + 32` into digest rows.
+		let pack_digest = |table: &mut TableBuilder, name: String, offset: usize| {
+			array::from_fn(|i| {
+				let packed = (0..4)
+					.map(|j| {
+						upcast_col(hash.message_byte(offset + j * 8 + i)) * ext_basis::<B32, B8>(j)
+					})
+					.reduce(|a, b| a + b)
+					.expect("digest rows have 4 bytes");
+				table.add_computed(format!("{name}[{i}]"), packed)
+			})
+		};
+
+		let state = pack_digest(table, "state".to_string(), 0);
+		let sampled = hash.message_byte(DIGEST_LEN);
+		for offset in DIGEST_LEN + 1..PREFIX_LEN {
+			table.assert_zero(format!("sampled[{offset}] = 0"), hash.message_byte(offset).into());
+		}
+		let mut pulled = vec![step_tag];
+		pulled.extend(state);
+		pulled.push(upcast_col(sampled));
+		table.pull(channels.state, pulled);
+
+		let digests = (0..shape.n_digests)
+			.map(|d| {
+				let digest =
+					pack_digest(table, format!("digests[{d}]"), PREFIX_LEN + DIGEST_LEN * d);
+				table.push_with_opts(
+					channels.root,
+					iter::once(step_tag).chain(digest),
+					FlushOpts {
+						multiplicity: shape.digest_multiplicity,
+						selectors: vec![],
+					},
+				);
+				digest
+			})
+			.collect::<Vec<_>>();
+
+		let scalars_offset = PREFIX_LEN + DIGEST_LEN * shape.n_digests;
+		let scalars = (0..shape.n_scalars)
+			.map(|s| {
+				let offset = scalars_offset + SCALAR_LEN * s;
+				let bytes = array::from_fn(|m| hash.message_byte(offset + m));
+				let scalar =
+					StandardBytes::new(&mut table.with_namespace(format!("scalars[{s}]")), bytes);
+				let slot = table.add_constant(format!("slot[{s}]"), [B32::new(s as u32)]);
+				table.push_with_opts(
+					channels.message,
+					[
+						instance,
+						upcast_col(step_tag),
+						upcast_col(slot),
+						scalar.value,
+					],
+					FlushOpts {
+						multiplicity: shape.scalar_multiplicity,
+						selectors: vec![],
+					},
+				);
+				scalar
+			})
+			.collect::<Vec<_>>();
+
+		let squeezed = (0..shape.squeeze.n_bytes().div_ceil(SCALAR_LEN))
+			.map(|c| {
+				let bytes = array::from_fn(|m| hash.digest_byte(SCALAR_LEN * c + m));
+				StandardBytes::new(&mut table.with_namespace(format!("squeezed[{c}]")), bytes)
+			})
+			.collect::<Vec<_>>();
+		let queries = match shape.squeeze {
+			Squeeze::Challenge { multiplicity } => {
+				table.push_with_opts(
+					channels.challenge,
+					[instance, upcast_col(step_tag), squeezed[0].value],
+					FlushOpts {
+						multiplicity,
+						selectors: vec![],
+					},
+				);
+				Vec::new()
+			}
+			Squeeze::Queries { n_queries, n_bits } => (0..n_queries)
+				.map(|q| {
+					let (chunk, offset) = (&squeezed[q / 4], 32 * (q % 4));
+					let packed = (0..n_bits)
+						.map(|t| upcast_col(chunk.bit(offset + t)) * B32::new(1 << t))
+						.reduce(|a, b| a + b)
+						.expect("queries have at least one bit");
+					let query = table.add_computed(format!("queries[{q}]"), packed);
+					table.push(channels.query, [instance, upcast_col(query)]);
+					query
+				})
+				.collect(),
+		};
+
+		let next_step_tag = (!shape.is_last).then(|| {
+			let next_step_tag =
+				table.add_computed("next_step_tag", step_tag * B32::MULTIPLICATIVE_GENERATOR);
+			let sampled = table
+				.add_constant("next_sampled", [isomorphic_byte(shape.squeeze.n_bytes() as u8)]);
+			let mut pushed = vec![next_step_tag];
+			pushed.extend(hash.digest());
+			pushed.push(upcast_col(sampled));
+			table.push(channels.state, pushed);
+			next_step_tag
+		});
+
+		Self {
+			table_id: table.id(),
+			shape,
+			step_tag,
+			next_step_tag,
+			hash,
+			state,
+			digests,
+			scalars,
+			squeezed,
+			queries,
+		}
+	}
+
+	pub fn shape(&self) -> &TranscriptStepShape {
+		&self.shape
+	}
+}
+
+impl<P> TableFiller<P> for TranscriptStepTable
+where
+	P: PackedFieldIndexable<Scalar = B128>
+		+ PackedExtension<B1>
+		+ PackedExtension<B8>
+		+ PackedExtension<B32>,
+	PackedSubfield<P, B8>: PackedTransformationFactory<PackedSubfield<P, B8>>,
+	PackedSubfield<P, B32>: PackedFieldIndexable,
+{
+	type Event = TranscriptStep;
+
+	fn id(&self) -> TableId {
+		self.table_id
+	}
+
+	fn fill(
+		&self,
+		rows: &[Self::Event],
+		witness: &mut TableWitnessSegment<P>,
+	) -> anyhow::Result<()> {
+		for row in rows {
+			anyhow::ensure!(
+				row.digests.len() == self.shape.n_digests
+					&& row.scalars.len() == self.shape.n_scalars,
+				"transcript step of round {} does not match the shape of the table",
+				row.round
+			);
+			anyhow::ensure!(row.sampled <= DIGEST_LEN, "at most a digest can be sampled");
+		}
+		let digests = rows.iter().map(TranscriptStep::digest).collect::<Vec<_>>();
+
+		self.hash
+			.populate(witness, rows.iter().map(TranscriptStep::preimage))?;
+		{
+			let mut step_tag = witness.get_scalars_mut(self.step_tag)?;
+			for (i, row) in rows.iter().enumerate() {
+				step_tag[i] = position(row.round);
+			}
+		}
+		if let Some(next_step_tag) = self.next_step_tag {
+			let mut next_step_tag = witness.get_scalars_mut(next_step_tag)?;
+			for (i, row) in rows.iter().enumerate() {
+				next_step_tag[i] = position(row.round + 1);
+			}
+		}
+
+		let fill_digest = |cols: [Col<B32>; 8], digests: Vec<[u8; DIGEST_LEN]>| {
+			let mut cols: [_; 8] = array_util::try_from_fn(|i| witness.get_scalars_mut(cols[i]))?;
+			for (k, digest) in digests.iter().enumerate() {
+				for (col, row) in iter::zip(&mut cols, digest_rows(digest)) {
+					col[k] = row;
+				}
+			}
+			anyhow::Ok(())
+		};
+		fill_digest(self.state, rows.iter().map(|row| row.state).collect())?;
+		for (d, &cols) in self.digests.iter().enumerate() {
+			fill_digest(cols, rows.iter().map(|row| row.digests[d]).collect())?;
+		}
+
+		for (s, scalar) in self.scalars.iter().enumerate() {
+			scalar.populate(witness, rows.iter().map(|row| row.scalars[s].val()))?;
+		}
+		for (c, squeezed) in self.squeezed.iter().enumerate() {
+			let values = digests
+				.iter()
+				.map(|digest| u128::from_le_bytes(array::from_fn(|m| digest[SCALAR_LEN * c + m])));
+			squeezed.populate(witness, values)?;
+		}
+		if let Squeeze::Queries { n_queries, n_bits } = self.shape.squeeze {
+			let mut queries: Vec<_> = self
+				.queries
+				.iter()
+				.map(|&query| witness.get_scalars_mut(query))
+				.collect::<Result<_, _>>()?;
+			for (k, row) in rows.iter().enumerate() {
+				for (col, query) in iter::zip(&mut queries, row.queries(n_queries, n_bits)) {
+					col[k] = B32::new(query);
+				}
+			}
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,767 @@
+// Copyright 2025 Irreducible Inc.
+
+//! A recursive verifier of FRI-Binius evaluation proofs of a fixed shape.
+//!
+//! An evaluation proof proves the claim `f(z) = y` about a multilinear `f` of `n` variables,
+//! whose evaluations over the hypercube are encoded with the additive NTT at rate `2^-k` and
+//! folded by FRI, one variable per round:
+//!
+//! - In each round `i < n`, the prover sends the Merkle root of the codeword of the round, whose
+//!   leaves are the cosets of two adjacent values, and the round polynomial of the sumcheck of `f ·
+//!   eq(z, ·)` without its coefficient of highest degree. The verifier samples the challenge `r_i`,
+//!   which is both the sumcheck challenge and the FRI folding challenge.
+//! - The prover sends the constant `c` of the final codeword, which is `f(r)`, and the verifier
+//!   samples the queries, each the index of a coset of the first codeword.
+//! - For each query and round, the decommitment opens the two values of the coset the query folds,
+//!   with the Merkle branch of the coset.
+//!
+//! The verifier checks that the final sumcheck claim is `c · eq(z, r)`, that the Merkle branches
+//! open into the roots, and that the opened values of every query fold into `c`.
+
+use std::{iter, slice};
+
+use binius_core::{
+	constraint_system::{
+		Proof,
+		channel::{Boundary, ChannelId, FlushDirection},
+	},
+	fiat_shamir::{CanSample, CanSampleBits, HasherChallenger},
+	protocols::sumcheck::{RoundCoeffs, RoundProof},
+	transcript::{self, VerifierTranscript},
+};
+use binius_field::{Field, PackedExtension, PackedFieldIndexable, PackedSubfield};
+use binius_hash::groestl::Groestl256;
+use binius_ntt::{AdditiveNTT, SingleThreadedNTT};
+use digest::Digest;
+
+use super::{
+	fri::{FriFinal, FriFinalTable, FriFold, FriFoldTable, FriOpening, FriOpeningTable},
+	sumcheck::{EqIndRound, EqIndTable, SumcheckRound, SumcheckRoundTable},
+	transcript::{
+		Squeeze, TranscriptChannels, TranscriptStep, TranscriptStepShape, TranscriptStepTable,
+	},
+};
+use crate::{
+	builder::{
+		B32, B128, Col, ConstraintSystem, TableBuilder, TableFiller, TableId, TableWitnessSegment,
+		WitnessIndex, upcast_col,
+	},
+	gadgets::{
+		hash::groestl::isomorphic_byte,
+		merkle_tree::{
+			MerkleTreeCS,
+			trace::{MerklePath, MerkleTreeTrace},
+		},
+		util::position,
+	},
+};
+
+/// The identifier of the sumcheck and FRI instance of the verifier.
+const INSTANCE: u32 = 0;
+
+/// The shape of an evaluation proof, which fixes the tables of its verifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EvalProofShape {
+	/// The number of variables of the committed multilinear.
+	pub n_vars: usize,
+	/// The binary logarithm of the inverse rate of the code.
+	pub log_inv_rate: usize,
+	pub n_queries: usize,
+}
+
+impl EvalProofShape {
+	/// The binary logarithm of the length of the first codeword.
+	pub fn log_len(&self) -> usize {
+		self.n_vars + self.log_inv_rate
+	}
+
+	/// The depth of the Merkle tree committing the codeword of a round.
+	pub fn tree_depth(&self, round: usize) -> usize {
+		self.log_len() - round - 1
+	}
+}
+
+/// The claim that the committed multilinear evaluates to `value` at `point`.
+#[derive(Debug, Clone)]
+pub struct EvalClaim {
+	pub point: Vec<B128>,
+	pub value: B128,
+}
+
+/// The final claim of the sumcheck, checked against the value of the final codeword.
+#[derive(Debug, Clone, Copy)]
+pub struct FinalClaim {
+	pub instance: u32,
+	pub claim: B128,
+	/// The equality indicator `eq(z, r)`.
+	pub eq: B128,
+	/// The value of the final codeword, which is `f(r)`.
+	pub value: B128,
+}
+
+/// A table checking the final claim of the sumcheck of `f · eq(z, ·)`.
+///
+/// The row pulls `(instance, final_tag, claim)` from the claim channel, `(instance, final_tag,
+/// eq)` from the eq channel and `(instance, final_tag, 0, value)` from the message channel, and
+/// checks that `claim = value · eq`.
+pub struct FinalClaimTable {
+	table_id: TableId,
+	instance: Col<B32>,
+	claim: Col<B128>,
+	eq: Col<B128>,
+	value: Col<B128>,
+}
+
+impl FinalClaimTable {
+	pub fn new(
+		table: &mut TableBuilder,
+		claim_channel: ChannelId,
+		eq_channel: ChannelId,
+		message_channel: ChannelId,
+		n_rounds: usize,
+	) -> Self {
+		let instance = table.add_committed("instance");
+		let claim = table.add_committed("claim");
+		let eq = table.add_committed("eq");
+		let value = table.add_committed("value");
+		let final_tag = upcast_col(table.add_constant("final_tag", [position(n_rounds)]));
+		let slot = upcast_col(table.add_constant("slot", [B32::ZERO]));
+
+		table.assert_zero("final_claim", claim - value * eq);
+
+		let instance_upcast = upcast_col(instance);
+		table.pull(claim_channel, [instance_upcast, final_tag, claim]);
+		table.pull(eq_channel, [instance_upcast, final_tag, eq]);
+		table.pull(message_channel, [instance_upcast, final_tag, slot, value]);
+
+		Self {
+			table_id: table.id(),
+			instance,
+			claim,
+			eq,
+			value,
+		}
+	}
+}
+
+impl<P> TableFiller<P> for FinalClaimTable
+where
+	P: PackedFieldIndexable<Scalar = B128> + PackedExtension<B32> + PackedExtension<B128>,
+	PackedSubfield<P, B32>: PackedFieldIndexable,
+{
+	type Event = FinalClaim;
+
+	fn id(&self) -> TableId {
+		self.table_id
+	}
+
+	fn fill(
+		&self,
+		rows: &[Self::Event],
+		witness: &mut TableWitnessSegment<P>,
+	) -> anyhow::Result<()> {
+		let mut instance = witness.get_scalars_mut(self.instance)?;
+		let mut claim = witness.get_mut_as::<B128, B128, 1>(self.claim)?;
+		let mut eq = witness.get_mut_as::<B128, B128, 1>(self.eq)?;
+		let mut value = witness.get_mut_as::<B128, B128, 1>(self.value)?;
+		for (i, row) in rows.iter().enumerate() {
+			instance[i] = B32::new(row.instance);
+			claim[i] = row.claim;
+			eq[i] = row.eq;
+			value[i] = row.value;
+		}
+		Ok(())
+	}
+}
+
+/// The events of the tables of an [`EvalProofVerifier`], replayed from a proof.
+#[derive(Debug, Clone)]
+pub struct EvalProofTrace {
+	/// The transcript steps of the folding rounds.
+	pub round_steps: Vec<TranscriptStep>,
+	/// The transcript step absorbing the final value and sampling the queries.
+	pub final_step: TranscriptStep,
+	pub sumcheck_rounds: Vec<SumcheckRound>,
+	pub eq_rounds: Vec<EqIndRound>,
+	pub final_claim: FinalClaim,
+	/// The openings of the queries, per round.
+	pub openings: Vec<Vec<FriOpening>>,
+	/// The folds of the queries, per round.
+	pub folds: Vec<Vec<FriFold>>,
+	pub finals: Vec<FriFinal>,
+	/// The Merkle paths of the opened cosets, whose root ids are the round tags.
+	pub paths: Vec<MerklePath>,
+}
+
+/// The tables verifying an evaluation proof of a fixed shape.
+///
+/// Every challenge and query is derived in-circuit by the [`TranscriptStepTable`]s, which absorb
+/// the Merkle roots and the scalars of the proof, and every opened coset is checked against the
+/// root of its round by the tables of a [`MerkleTreeCS`]. The verifier of the recursive proof
+/// only pushes the claim as [`EvalProofVerifier::boundaries`].
+pub struct EvalProofVerifier {
+	shape: EvalProofShape,
+	claim_channel: ChannelId,
+	eq_channel: ChannelId,
+	point_channel: ChannelId,
+	transcript: TranscriptChannels,
+	round_steps: TranscriptStepTable,
+	final_step: TranscriptStepTable,
+	sumcheck: SumcheckRoundTable,
+	eq_ind: EqIndTable,
+	final_claim: FinalClaimTable,
+	openings: Vec<FriOpeningTable>,
+	folds: Vec<FriFoldTable>,
+	fri_final: FriFinalTable,
+	merkle: MerkleTreeCS,
+}
+
+impl EvalProofVerifier {
+	/// Creates the tables of the verifier of evaluation proofs of the given shape.
+	///
+	/// ## Preconditions
+	///
+	/// * the multilinear must have at least one variable
+	/// * there must be between 1 and 8 queries
+	/// * the first codeword must have at most `2^33` values
+	pub fn new(cs: &mut ConstraintSystem, shape: EvalProofShape) -> Self {
+		assert!(shape.n_vars > 0, "the multilinear must have at least one variable");
+		assert!((1..=8).contains(&shape.n_queries), "the queries are sampled from one digest");
+
+		let merkle = MerkleTreeCS::new(cs);
+		let challenge_channel = cs.add_channel("challenges");
+		let transcript = TranscriptChannels::new(cs, merkle.roots_channel, challenge_channel);
+		let claim_channel = cs.add_channel("sumcheck_claims");
+		let eq_channel = cs.add_channel("eq_ind");
+		let point_channel = cs.add_channel("eval_point");
+		let pair_channel = cs.add_channel("fri_pairs");
+		let fold_channel = cs.add_channel("fri_folds");
+
+		// Each root is opened once per query, and each challenge is pulled by the sumcheck round,
+		// the equality indicator and the fold of every query.
+		let n_queries = shape.n_queries as u32;
+		let round_steps = TranscriptStepTable::new(
+			&mut cs.add_table("transcript_rounds"),
+			&transcript,
+			INSTANCE,
+			TranscriptStepShape {
+				n_digests: 1,
+				digest_multiplicity: n_queries,
+				n_scalars: 2,
+				scalar_multiplicity: 1,
+				squeeze: Squeeze::Challenge {
+					multiplicity: n_queries + 2,
+				},
+				is_last: false,
+			},
+		);
+		// The final value is pulled by the final claim and by the final fold of every query.
+		let final_step = TranscriptStepTable::new(
+			&mut cs.add_table("transcript_final"),
+			&transcript,
+			INSTANCE,
+			TranscriptStepShape {
+				n_digests: 0,
+				digest_multiplicity: 0,
+				n_scalars: 1,
+				scalar_multiplicity: n_queries + 1,
+				squeeze: Squeeze::Queries {
+					n_queries: shape.n_queries,
+					n_bits: shape.tree_depth(0),
+				},
+				is_last: true,
+			},
+		);
+
+		let sumcheck = SumcheckRoundTable::new(
+			&mut cs.add_table("sumcheck_rounds"),
+			claim_channel,
+			challenge_channel,
+			transcript.message,
+			2,
+		);
+		let eq_ind = EqIndTable::new(
+			&mut cs.add_table("eq_ind"),
+			eq_channel,
+			point_channel,
+			challenge_channel,
+		);
+		let final_claim = FinalClaimTable::new(
+			&mut cs.add_table("final_claim"),
+			claim_channel,
+			eq_channel,
+			transcript.message,
+			shape.n_vars,
+		);
+
+		let ntt = SingleThreadedNTT::<B32>::new(shape.log_len())
+			.expect("the codeword length is supported by the NTT");
+		let (openings, folds) = (0..shape.n_vars)
+			.map(|round| {
+				let opening = FriOpeningTable::new(
+					&mut cs.add_table(format!("fri_openings[{round}]")),
+					transcript.query,
+					fold_channel,
+					pair_channel,
+					merkle.nodes_channel,
+					round,
+					shape.tree_depth(round),
+				);
+				let log_len = shape.log_len() - round;
+				let twiddle_basis = (0..log_len - 1)
+					.map(|j| ntt.get_subspace_eval(log_len, 1 << j))
+					.collect::<Vec<_>>();
+				let fold = FriFoldTable::new(
+					&mut cs.add_table(format!("fri_folds[{round}]")),
+					pair_channel,
+					challenge_channel,
+					fold_channel,
+					round,
+					&twiddle_basis,
+				);
+				(opening, fold)
+			})
+			.unzip();
+		let fri_final = FriFinalTable::new(
+			&mut cs.add_table("fri_final"),
+			fold_channel,
+			transcript.message,
+			shape.n_vars,
+		);
+
+		Self {
+			shape,
+			claim_channel,
+			eq_channel,
+			point_channel,
+			transcript,
+			round_steps,
+			final_step,
+			sumcheck,
+			eq_ind,
+			final_claim,
+			openings,
+			folds,
+			fri_final,
+			merkle,
+		}
+	}
+
+	pub fn shape(&self) -> &EvalProofShape {
+		&self.shape
+	}
+
+	/// Replays the transcript of `proof` for `claim`, returning the events of the tables.
+	///
+	/// The replay only reads the proof. It does not check the claims and the openings, which the
+	/// tables do.
+	pub fn replay(
+		&self,
+		proof: &Proof,
+		claim: &EvalClaim,
+	) -> Result<EvalProofTrace, transcript::Error> {
+		let shape = self.shape;
+		assert_eq!(
+			claim.point.len(),
+			shape.n_vars,
+			"the claim must have one coordinate per variable"
+		);
+
+		let mut transcript =
+			VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof.transcript.clone());
+		let mut state: [u8; 32] = Groestl256::digest(b"").into();
+		let mut sampled = 0;
+
+		let mut round_steps = Vec::with_capacity(shape.n_vars);
+		let mut sumcheck_rounds = Vec::with_capacity(shape.n_vars);
+		let mut eq_rounds = Vec::with_capacity(shape.n_vars);
+		let mut sumcheck_claim = claim.value;
+		let mut eq = B128::ONE;
+		for (round, &point) in claim.point.iter().enumerate() {
+			let mut root = [0u8; 32];
+			transcript.message().read_bytes(&mut root)?;
+			let sent = transcript.message().read_scalar_slice::<B128>(2)?;
+			let challenge: B128 = transcript.sample();
+
+			let step = TranscriptStep {
+				round,
+				state,
+				sampled,
+				digests: vec![root],
+				scalars: sent.clone(),
+			};
+			debug_assert_eq!(step.challenge(), challenge);
+			state = step.digest();
+			sampled = 16;
+			round_steps.push(step);
+
+			let RoundCoeffs(coeffs) = RoundProof(RoundCoeffs(sent)).recover(sumcheck_claim);
+			let sumcheck_round = SumcheckRound {
+				instance: INSTANCE,
+				round,
+				claim: sumcheck_claim,
+				coeffs,
+				challenge,
+			};
+			sumcheck_claim = sumcheck_round.next_claim();
+			sumcheck_rounds.push(sumcheck_round);
+
+			let eq_round = EqIndRound {
+				instance: INSTANCE,
+				round,
+				acc: eq,
+				point,
+				challenge,
+			};
+			eq = eq_round.next_acc();
+			eq_rounds.push(eq_round);
+		}
+
+		let final_value: B128 = transcript.message().read_scalar()?;
+		let queries: Vec<u32> = (0..shape.n_queries)
+			.map(|_| transcript.sample_bits(shape.tree_depth(0)))
+			.collect();
+		let final_step = TranscriptStep {
+			round: shape.n_vars,
+			state,
+			sampled,
+			digests: Vec::new(),
+			scalars: vec![final_value],
+		};
+		debug_assert_eq!(final_step.queries(shape.n_queries, shape.tree_depth(0)), queries);
+		let final_claim = FinalClaim {
+			instance: INSTANCE,
+			claim: sumcheck_claim,
+			eq,
+			value: final_value,
+		};
+
+		let mut openings = vec![Vec::with_capacity(shape.n_queries); shape.n_vars];
+		let mut folds = vec![Vec::with_capacity(shape.n_queries); shape.n_vars];
+		let mut finals = Vec::with_capacity(shape.n_queries);
+		let mut paths = Vec::with_capacity(shape.n_queries * shape.n_vars);
+		for query in queries {
+			let mut index = query;
+			let mut value = final_value;
+			for (round, table) in self.folds.iter().enumerate() {
+				let values = transcript.decommitment().read_scalar_slice::<B128>(2)?;
+				let mut branch = vec![[0u8; 32]; shape.tree_depth(round)];
+				for node in &mut branch {
+					transcript.decommitment().read_bytes(node)?;
+				}
+
+				let opening = FriOpening {
+					instance: INSTANCE,
+					index,
+					values: (values[0], values[1]),
+				};
+				let coset = opening.coset(round);
+				let leaf: [u8; 32] = Groestl256::digest(
+					values
+						.iter()
+						.flat_map(|value| value.val().to_le_bytes())
+						.collect::<Vec<_>>(),
+				)
+				.into();
+				paths.push(MerklePath {
+					root_id: position(round).val(),
+					index: coset as usize,
+					leaf: isomorphic_digest(&leaf),
+					nodes: branch.iter().map(isomorphic_digest).collect(),
+				});
+
+				let fold = FriFold {
+					instance: INSTANCE,
+					query: coset,
+					values: opening.values,
+					challenge: sumcheck_rounds[round].challenge,
+				};
+				value = table.fold(&fold);
+				index = coset;
+				openings[round].push(opening);
+				folds[round].push(fold);
+			}
+			finals.push(FriFinal {
+				instance: INSTANCE,
+				query: index,
+				value,
+			});
+		}
+		transcript.finalize()?;
+
+		Ok(EvalProofTrace {
+			round_steps,
+			final_step,
+			sumcheck_rounds,
+			eq_rounds,
+			final_claim,
+			openings,
+			folds,
+			finals,
+			paths,
+		})
+	}
+
+	/// Fills the witness of every table from the events of a replayed proof.
+	pub fn fill(
+		&self,
+		cs: &ConstraintSystem,
+		trace: &EvalProofTrace,
+		witness: &mut WitnessIndex,
+	) -> anyhow::Result<()> {
+		witness.fill_table_sequential(&self.round_steps, &trace.round_steps)?;
+		witness.fill_table_sequential(&self.final_step, slice::from_ref(&trace.final_step))?;
+		witness.fill_table_sequential(&self.sumcheck, &trace.sumcheck_rounds)?;
+		witness.fill_table_sequential(&self.eq_ind, &trace.eq_rounds)?;
+		witness.fill_table_sequential(&self.final_claim, slice::from_ref(&trace.final_claim))?;
+		for (table, openings) in self.openings.iter().zip(&trace.openings) {
+			witness.fill_table_sequential(table, openings)?;
+		}
+		for (table, folds) in self.folds.iter().zip(&trace.folds) {
+			witness.fill_table_sequential(table, folds)?;
+		}
+		witness.fill_table_sequential(&self.fri_final, &trace.finals)?;
+
+		// Fills the Merkle tables and the constant columns of every table.
+		let merkle_trace = MerkleTreeTrace::generate_unmerged(&trace.paths);
+		self.merkle.fill_tables(&merkle_trace, cs, witness)
+	}
+
+	/// Returns the boundaries pushing `claim` and the initial challenger state.
+	pub fn boundaries(&self, claim: &EvalClaim) -> Vec<Boundary<B128>> {
+		let instance = B128::from(B32::new(INSTANCE));
+		let push = |channel_id, values| Boundary {
+			values,
+			channel_id,
+			direction: FlushDirection::Push,
+			multiplicity: 1,
+		};
+		iter::once(self.transcript.initial_state_boundary())
+			.chain(iter::once(push(
+				self.claim_channel,
+				vec![instance, position(0).into(), claim.value],
+			)))
+			.chain(iter::once(push(self.eq_channel, vec![instance, position(0).into(), B128::ONE])))
+			.chain(claim.point.iter().enumerate().map(|(round, &coord)| {
+				push(self.point_channel, vec![instance, position(round).into(), coord])
+			}))
+			.collect()
+	}
+
+	/// Replays `proof` for `claim` and fills the witness of every table, returning the
+	/// boundaries of the verification.
+	pub fn fill_witness(
+		&self,
+		cs: &ConstraintSystem,
+		proof: &Proof,
+		claim: &EvalClaim,
+		witness: &mut WitnessIndex,
+	) -> anyhow::Result<Vec<Boundary<B128>>> {
+		let trace = self.replay(proof, claim)?;
+		self.fill(cs, &trace, witness)?;
+		Ok(self.boundaries(claim))
+	}
+}
+
+/// Converts the bytes of a digest to the isomorphic representation of the Grøstl gadgets.
+fn isomorphic_digest(digest: &[u8; 32]) -> [u8; 32] {
+	digest.map(|byte| isomorphic_byte(byte).val())
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_compute::cpu::alloc::CpuComputeAllocator;
+	use binius_core::{
+		merkle_tree::{BinaryMerkleTreeProver, MerkleTreeProver},
+		transcript::ProverTranscript,
+	};
+	use binius_field::util::inner_product_unchecked;
+	use binius_hash::groestl::Groestl256ByteCompression;
+	use binius_ntt::NTTShape;
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use super::{super::fri::fold_pair, *};
+
+	const SHAPE: EvalProofShape = EvalProofShape {
+		n_vars: 3,
+		log_inv_rate: 1,
+		n_queries: 2,
+	};
+
+	/// Returns the evaluations of the equality indicator `eq(point, ·)` over the hypercube.
+	fn eq_ind(point: &[B128]) -> Vec<B128> {
+		point.iter().fold(vec![B128::ONE], |evals, &coord| {
+			let lo = evals.iter().map(|&eval| eval * (B128::ONE - coord));
+			let hi = evals.iter().map(|&eval| eval * coord);
+			lo.chain(hi).collect()
+		})
+	}
+
+	/// Proves the evaluation of `f` at `point` with the protocol of the module.
+	fn prove(f: &[B128], point: &[B128]) -> Proof {
+		let log_len = SHAPE.log_len();
+		let ntt = SingleThreadedNTT::<B32>::new(log_len).unwrap();
+		let merkle_prover =
+			BinaryMerkleTreeProver::<B128, Groestl256, _>::new(Groestl256ByteCompression);
+		let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+
+		let mut codeword = f.to_vec();
+		codeword.resize(1 << log_len, B128::ZERO);
+		let ntt_shape = NTTShape {
+			log_y: log_len,
+			..Default::default()
+		};
+		ntt.forward_transform_ext(&mut codeword, ntt_shape, 0, 0, 0)
+			.unwrap();
+
+		let mut f = f.to_vec();
+		let mut eq = eq_ind(point);
+		let mut codewords = Vec::with_capacity(SHAPE.n_vars);
+		for round in 0..SHAPE.n_vars {
+			let (commitment, committed) = merkle_prover.commit(&codeword, 2).unwrap();
+			transcript.message().write_bytes(&commitment.root);
+
+			let half = f.len() / 2;
+			let mut coeffs = [B128::ZERO; 3];
+			for i in 0..half {
+				let (f0, f1) = (f[2 * i], f[2 * i + 1]);
+				let (e0, e1) = (eq[2 * i], eq[2 * i + 1]);
+				coeffs[0] += f0 * e0;
+				coeffs[1] += f0 * (e1 - e0) + e0 * (f1 - f0);
+				coeffs[2] += (f1 - f0) * (e1 - e0);
+			}
+			transcript.message().write_scalar_slice(&coeffs[..2]);
+
+			let challenge: B128 = transcript.sample();
+			let fold = |values: &[B128]| {
+				(0..half)
+					.map(|i| values[2 * i] + (values[2 * i + 1] - values[2 * i]) * challenge)
+					.collect::<Vec<_>>()
+			};
+			f = fold(&f);
+			eq = fold(&eq);
+			let folded = codeword
+				.chunks_exact(2)
+				.enumerate()
+				.map(|(index, pair)| {
+					let twiddle = ntt.get_subspace_eval(log_len - round, index);
+					fold_pair((pair[0], pair[1]), twiddle, challenge)
+				})
+				.collect();
+			codewords.push((codeword, committed));
+			codeword = folded;
+		}
+		assert!(codeword.iter().all(|&value| value == f[0]));
+		transcript.message().write_scalar(f[0]);
+
+		let queries: Vec<u32> = (0..SHAPE.n_queries)
+			.map(|_| transcript.sample_bits(SHAPE.tree_depth(0)))
+			.collect();
+		for query in queries {
+			let mut coset = query as usize;
+			for (round, (codeword, committed)) in codewords.iter().enumerate() {
+				if round > 0 {
+					coset >>= 1;
+				}
+				transcript
+					.decommitment()
+					.write_scalar_slice(&codeword[2 * coset..2 * coset + 2]);
+				merkle_prover
+					.prove_opening(committed, 0, coset, &mut transcript.decommitment())
+					.unwrap();
+			}
+		}
+		Proof {
+			transcript: transcript.finalize(),
+		}
+	}
+
+	fn random_claim(rng: &mut StdRng) -> (Proof, EvalClaim) {
+		let f = (0..1 << SHAPE.n_vars)
+			.map(|_| B128::random(&mut *rng))
+			.collect::<Vec<_>>();
+		let point = (0..SHAPE.n_vars)
+			.map(|_| B128::random(&mut *rng))
+			.collect::<Vec<_>>();
+		let value = inner_product_unchecked(f.iter().copied(), eq_ind(&point));
+		(prove(&f, &point), EvalClaim { point, value })
+	}
+
+	/// Replays a proof, lets `tamper` modify the events, and validates the witness of the tables.
+	fn verify_witness(tamper: impl FnOnce(&mut EvalProofTrace)) -> anyhow::Result<()> {
+		let mut rng = StdRng::seed_from_u64(0);
+		let (proof, claim) = random_claim(&mut rng);
+
+		let mut cs = ConstraintSystem::new();
+		let verifier = EvalProofVerifier::new(&mut cs, SHAPE);
+		let mut trace = verifier.replay(&proof, &claim)?;
+		tamper(&mut trace);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 22);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness = WitnessIndex::new(&cs, &allocator);
+		verifier.fill(&cs, &trace, &mut witness)?;
+
+		let boundaries = verifier.boundaries(&claim);
+		let ccs = cs.compile()?;
+		let table_sizes = witness.table_sizes();
+		let witness = witness.into_multilinear_extension_index();
+		binius_core::constraint_system::validate::validate_witness(
+			&ccs,
+			&boundaries,
+			&table_sizes,
+			&witness,
+		)?;
+		Ok(())
+	}
+
+	#[test]
+	fn test_eval_proof_verifier() {
+		verify_witness(|_| {}).unwrap();
+	}
+
+	#[test]
+	fn test_eval_proof_verifier_fill_witness() {
+		let mut rng = StdRng::seed_from_u64(1);
+		let (proof, claim) = random_claim(&mut rng);
+
+		let mut cs = ConstraintSystem::new();
+		let verifier = EvalProofVerifier::new(&mut cs, SHAPE);
+		let mut allocator = CpuComputeAllocator::new(1 << 22);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness = WitnessIndex::new(&cs, &allocator);
+		let boundaries = verifier
+			.fill_witness(&cs, &proof, &claim, &mut witness)
+			.unwrap();
+
+		let ccs = cs.compile().unwrap();
+		let table_sizes = witness.table_sizes();
+		let witness = witness.into_multilinear_extension_index();
+		binius_core::constraint_system::validate::validate_witness(
+			&ccs,
+			&boundaries,
+			&table_sizes,
+			&witness,
+		)
+		.unwrap();
+	}
+
+	#[test]
+	fn test_eval_proof_verifier_rejects_tampered_challenge() {
+		let result = verify_witness(|trace| {
+			let round = &mut trace.sumcheck_rounds[1];
+			round.challenge += B128::ONE;
+			trace.eq_rounds[1].challenge = round.challenge;
+			for fold in &mut trace.folds[1] {
+				fold.challenge = round.challenge;
+			}
+		});
+		assert!(result.is_err());
+	}
+
+	#[test]
+	fn test_eval_proof_verifier_rejects_tampered_merkle_path() {
+		let result = verify_witness(|trace| trace.paths[1].nodes[0][0] ^= 1);
+		assert!(result.is_err());
+	}
+}
@@ -0,0 +1,738 @@
+// Copyright 2025 Irreducible Inc.
+
+//! A small regular-expression to DFA compiler.
+//!
+//! The supported syntax is a byte-oriented subset of the usual regular expression languages:
+//!
+//! * literal bytes and escapes (`\.`, `\\`, `\n`, `\t`, `\r`, `\xHH`),
+//! * the wildcard `.`, which matches any byte,
+//! * character classes such as `[a-z0-9_]` and negated classes such as `[^@]`,
+//! * the shorthand classes `\d`, `\w`, `\s` and their negations `\D`, `\W`, `\S`,
+//! * grouping with `(...)` and alternation with `|`,
+//! * the repetition operators `*`, `+`, `?`, `{n}`, `{n,}` and `{n,m}`.
+//!
+//! Patterns always match the entire input, as if they were surrounded by `^` and `$`. The
+//! compiler builds a Thompson NFA, determinizes it with the subset construction, removes states
+//! that cannot reach an accepting state, and finally minimizes the result.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// The maximum number of states a compiled DFA may have.
+///
+/// States are identified by bytes in the arithmetization.
+pub const MAX_DFA_STATES: usize = 1 << 8;
+
+/// The maximum number of states of the subset construction.
+///
+/// Minimization can shrink the DFA by far, so the unminimized DFA may exceed [`MAX_DFA_STATES`].
+/// Some patterns blow up exponentially in the subset construction though, so it is stopped once
+/// the DFA is too large to plausibly minimize to the maximum.
+const MAX_SUBSET_STATES: usize = 16 * MAX_DFA_STATES;
+
+/// The maximum number of repetitions allowed in a bounded repetition operator.
+const MAX_REPETITIONS: usize = 1 << 10;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum RegexError {
+	#[error("unexpected end of pattern")]
+	UnexpectedEnd,
+	#[error("unexpected character {0:?} at offset {1}")]
+	UnexpectedChar(char, usize),
+	#[error("invalid escape sequence at offset {0}")]
+	InvalidEscape(usize),
+	#[error("invalid character class range at offset {0}")]
+	InvalidRange(usize),
+	#[error("invalid repetition bounds at offset {0}")]
+	InvalidRepetition(usize),
+	#[error("the pattern does not match any input")]
+	EmptyLanguage,
+	#[error("the DFA has {0} states, which exceeds the maximum of {MAX_DFA_STATES}")]
+	TooManyStates(usize),
+}
+
+/// A set of bytes, represented as a 256-bit mask.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ByteSet([u64; 4]);
+
+impl ByteSet {
+	const EMPTY: Self = Self([0; 4]);
+	const FULL: Self = Self([u64::MAX; 4]);
+
+	fn single(byte: u8) -> Self {
+		let mut set = Self::EMPTY;
+		set.insert(byte);
+		set
+	}
+
+	fn range(lo: u8, hi: u8) -> Self {
+		let mut set = Self::EMPTY;
+		for byte in lo..=hi {
+			set.insert(byte);
+		}
+		set
+	}
+
+	fn insert(&mut self, byte: u8) {
+		self.0[(byte >> 6) as usize] |= 1 << (byte & 63);
+	}
+
+	fn contains(&self, byte: u8) -> bool {
+		(self.0[(byte >> 6) as usize] >> (byte & 63)) & 1 == 1
+	}
+
+	fn union(self, other: Self) -> Self {
+		Self(std::array::from_fn(|i| self.0[i] | other.0[i]))
+	}
+
+	fn complement(self) -> Self {
+		Self(self.0.map(|word| !word))
+	}
+}
+
+/// Abstract syntax tree of a parsed pattern.
+#[derive(Debug, Clone)]
+enum Ast {
+	Empty,
+	Class(ByteSet),
+	Concat(Vec<Ast>),
+	Alt(Vec<Ast>),
+	Repeat {
+		inner: Box<Ast>,
+		min: usize,
+		max: Option<usize>,
+	},
+}
+
+struct Parser<'a> {
+	pattern: &'a [u8],
+	pos: usize,
+}
+
+impl<'a> Parser<'a> {
+	fn new(pattern: &'a str) -> Self {
+		Self {
+			pattern: pattern.as_bytes(),
+			pos: 0,
+		}
+	}
+
+	fn peek(&self) -> Option<u8> {
+		self.pattern.get(self.pos).copied()
+	}
+
+	fn next(&mut self) -> Result<u8, RegexError> {
+		let byte = self.peek().ok_or(RegexError::UnexpectedEnd)?;
+		self.pos += 1;
+		Ok(byte)
+	}
+
+	fn unexpected(&self) -> RegexError {
+		match self.peek() {
+			Some(byte) => RegexError::UnexpectedChar(byte as char, self.pos),
+			None => RegexError::UnexpectedEnd,
+		}
+	}
+
+	fn parse(mut self) -> Result<Ast, RegexError> {
+		let ast = self.parse_alt()?;
+		if self.peek().is_some() {
+			return Err(self.unexpected());
+		}
+		Ok(ast)
+	}
+
+	fn parse_alt(&mut self) -> Result<Ast, RegexError> {
+		let mut branches = vec![self.parse_concat()?];
+		while self.peek() == Some(b'|') {
+			self.pos += 1;
+			branches.push(self.parse_concat()?);
+		}
+		Ok(if branches.len() == 1 {
+			branches.pop().expect("branches has length 1")
+		} else {
+			Ast::Alt(branches)
+		})
+	}
+
+	fn parse_concat(&mut self) -> Result<Ast, RegexError> {
+		let mut items = Vec::new();
+		while let Some(byte) = self.peek() {
+			if byte == b'|' || byte == b')' {
+				break;
+			}
+			items.push(self.parse_repeat()?);
+		}
+		Ok(match items.len() {
+			0 => Ast::Empty,
+			1 => items.pop().expect("items has length 1"),
+			_ => Ast::Concat(items),
+		})
+	}
+
+	fn parse_repeat(&mut self) -> Result<Ast, RegexError> {
+		let mut ast = self.parse_atom()?;
+		loop {
+			let (min, max) = match self.peek() {
+				Some(b'*') => {
+					self.pos += 1;
+					(0, None)
+				}
+				Some(b'+') => {
+					self.pos += 1;
+					(1, None)
+				}
+				Some(b'?') => {
+					self.pos += 1;
+					(0, Some(1))
+				}
+				Some(b'{') => self.parse_bounds()?,
+				_ => break,
+			};
+			ast = Ast::Repeat {
+				inner: Box::new(ast),
+				min,
+				max,
+			};
+		}
+		Ok(ast)
+	}
+
+	fn parse_bounds(&mut self) -> Result<(usize, Option<usize>), RegexError> {
+		let start = self.pos;
+		self.pos += 1;
+		let min = self
+			.parse_number()?
+			.ok_or(RegexError::InvalidRepetition(start))?;
+		let max = match self.next()? {
+			b'}' => Some(min),
+			b',' => {
+				let max = self.parse_number()?;
+				if self.next()? != b'}' {
+					return Err(RegexError::InvalidRepetition(start));
+				}
+				max
+			}
+			_ => return Err(RegexError::InvalidRepetition(start)),
+		};
+		if max.is_some_and(|max| max < min) || max.unwrap_or(min) > MAX_REPETITIONS {
+			return Err(RegexError::InvalidRepetition(start));
+		}
+		Ok((min, max))
+	}
+
+	fn parse_number(&mut self) -> Result<Option<usize>, RegexError> {
+		let start = self.pos;
+		let mut value = None;
+		while let Some(digit @ b'0'..=b'9') = self.peek() {
+			self.pos += 1;
+			value = Some(
+				value
+					.unwrap_or(0usize)
+					.checked_mul(10)
+					.and_then(|value| value.checked_add((digit - b'0') as usize))
+					.ok_or(RegexError::InvalidRepetition(start))?,
+			);
+		}
+		Ok(value)
+	}
+
+	fn parse_atom(&mut self) -> Result<Ast, RegexError> {
+		let pos = self.pos;
+		match self.next()? {
+			b'(' => {
+				let inner = self.parse_alt()?;
+				if self.next()? != b')' {
+					return Err(RegexError::UnexpectedChar(
+						self.pattern[self.pos - 1] as char,
+						self.pos - 1,
+					));
+				}
+				Ok(inner)
+			}
+			b'[' => self.parse_class().map(Ast::Class),
+			b'.' => Ok(Ast::Class(ByteSet::FULL)),
+			b'\\' => self.parse_escape().map(Ast::Class),
+			byte @ (b'*' | b'+' | b'?' | b'{' | b')' | b'|') => {
+				Err(RegexError::UnexpectedChar(byte as char, pos))
+			}
+			byte => Ok(Ast::Class(ByteSet::single(byte))),
+		}
+	}
+
+	fn parse_escape(&mut self) -> Result<ByteSet, RegexError> {
+		let start = self.pos - 1;
+		let digits = ByteSet::range(b'0', b'9');
+		let word = digits
+			.union(ByteSet::range(b'a', b'z'))
+			.union(ByteSet::range(b'A', b'Z'))
+			.union(ByteSet::single(b'_'));
+		let space = [b' ', b'\t', b'\n', b'\r', 0x0b, 0x0c]
+			.into_iter()
+			.map(ByteSet::single)
+			.fold(ByteSet::EMPTY, ByteSet::union);
+
+		let set = match self.next()? {
+			b'd' => digits,
+			b'D' => digits.complement(),
+			b'w' => word,
+			b'W' => word.complement(),
+			b's' => space,
+			b'S' => space.complement(),
+			b'n' => ByteSet::single(b'\n'),
+			b't' => ByteSet::single(b'\t'),
+			b'r' => ByteSet::single(b'\r'),
+			b'x' => {
+				let hi = self.next()?;
+				let lo = self.next()?;
+				let hex = std::str::from_utf8(&[hi, lo])
+					.ok()
+					.and_then(|hex| u8::from_str_radix(hex, 16).ok())
+					.ok_or(RegexError::InvalidEscape(start))?;
+				ByteSet::single(hex)
+			}
+			byte if byte.is_ascii_punctuation() => ByteSet::single(byte),
+			_ => return Err(RegexError::InvalidEscape(start)),
+		};
+		Ok(set)
+	}
+
+	fn parse_class(&mut self) -> Result<ByteSet, RegexError> {
+		let negated = self.peek() == Some(b'^');
+		if negated {
+			self.pos += 1;
+		}
+
+		let mut set = ByteSet::EMPTY;
+		let mut first = true;
+		loop {
+			let start = self.pos;
+			let lo = match self.next()? {
+				b']' if !first => break,
+				b'\\' => {
+					let escaped = self.parse_escape()?;
+					// Multi-byte escapes such as `\d` cannot be the start of a range.
+					if escaped.0.iter().map(|word| word.count_ones()).sum::<u32>() != 1 {
+						set = set.union(escaped);
+						first = false;
+						continue;
+					}
+					(0..=255u8)
+						.find(|&byte| escaped.contains(byte))
+						.expect("escaped set has exactly one element")
+				}
+				byte => byte,
+			};
+			first = false;
+
+			if self.peek() == Some(b'-') && self.pattern.get(self.pos + 1) != Some(&b']') {
+				self.pos += 1;
+				let hi = match self.next()? {
+					b'\\' => {
+						let escaped = self.parse_escape()?;
+						(0..=255u8)
+							.find(|&byte| escaped.contains(byte))
+							.ok_or(RegexError::InvalidRange(start))?
+					}
+					byte => byte,
+				};
+				if hi < lo {
+					return Err(RegexError::InvalidRange(start));
+				}
+				set = set.union(ByteSet::range(lo, hi));
+			} else {
+				set.insert(lo);
+			}
+		}
+
+		Ok(if negated { set.complement() } else { set })
+	}
+}
+
+/// A Thompson NFA with epsilon transitions.
+#[derive(Debug, Default)]
+struct Nfa {
+	epsilon: Vec<Vec<usize>>,
+	edges: Vec<Vec<(ByteSet, usize)>>,
+}
+
+impl Nfa {
+	fn add_state(&mut self) -> usize {
+		self.epsilon.push(Vec::new());
+		self.edges.push(Vec::new());
+		self.epsilon.len() - 1
+	}
+
+	/// Adds the fragment for `ast` and returns its (entry, exit) states.
+	fn build(&mut self, ast: &Ast) -> (usize, usize) {
+		match ast {
+			Ast::Empty => {
+				let state = self.add_state();
+				(state, state)
+			}
+			Ast::Class(set) => {
+				let entry = self.add_state();
+				let exit = self.add_state();
+				self.edges[entry].push((*set, exit));
+				(entry, exit)
+			}
+			Ast::Concat(items) => {
+				let entry = self.add_state();
+				let mut exit = entry;
+				for item in items {
+					let (item_entry, item_exit) = self.build(item);
+					self.epsilon[exit].push(item_entry);
+					exit = item_exit;
+				}
+				(entry, exit)
+			}
+			Ast::Alt(branches) => {
+				let entry = self.add_state();
+				let exit = self.add_state();
+				for branch in branches {
+					let (branch_entry, branch_exit) = self.build(branch);
+					self.epsilon[entry].push(branch_entry);
+					self.epsilon[branch_exit].push(exit);
+				}
+				(entry, exit)
+			}
+			Ast::Repeat { inner, min, max } => {
+				let entry = self.add_state();
+				let mut exit = entry;
+				for _ in 0..*min {
+					let (item_entry, item_exit) = self.build(inner);
+					self.epsilon[exit].push(item_entry);
+					exit = item_exit;
+				}
+				match max {
+					Some(max) => {
+						let end = self.add_state();
+						for _ in *min..*max {
+							let (item_entry, item_exit) = self.build(inner);
+							self.epsilon[exit].push(item_entry);
+							self.epsilon[exit].push(end);
+							exit = item_exit;
+						}
+						self.epsilon[exit].push(end);
+						exit = end;
+					}
+					None => {
+						let (item_entry, item_exit) = self.build(inner);
+						let end = self.add_state();
+						self.epsilon[exit].push(item_entry);
+						self.epsilon[exit].push(end);
+						self.epsilon[item_exit].push(item_entry);
+						self.epsilon[item_exit].push(end);
+						exit = end;
+					}
+				}
+				(entry, exit)
+			}
+		}
+	}
+
+	fn epsilon_closure(&self, states: impl IntoIterator<Item = usize>) -> BTreeSet<usize> {
+		let mut closure = BTreeSet::new();
+		let mut stack = states.into_iter().collect::<Vec<_>>();
+		while let Some(state) = stack.pop() {
+			if closure.insert(state) {
+				stack.extend(self.epsilon[state].iter().copied());
+			}
+		}
+		closure
+	}
+}
+
+/// A deterministic finite automaton over bytes.
+///
+/// Only live states are represented, i.e. states from which an accepting state is reachable.
+/// Consequently, the transition function is partial: a missing transition means that the input is
+/// rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dfa {
+	start: u8,
+	accepting: Vec<bool>,
+	transitions: Vec<[Option<u8>; 256]>,
+}
+
+impl Dfa {
+	/// Compiles a regular expression into a minimal DFA matching the whole input.
+	pub fn from_regex(pattern: &str) -> Result<Self, RegexError> {
+		let ast = Parser::new(pattern).parse()?;
+
+		let mut nfa = Nfa::default();
+		let (nfa_start, nfa_accept) = nfa.build(&ast);
+
+		// Subset construction.
+		let start_set = nfa.epsilon_closure([nfa_start]);
+		let mut set_ids = HashMap::from([(start_set.clone(), 0usize)]);
+		let mut sets = vec![start_set];
+		let mut transitions = Vec::<[Option<usize>; 256]>::new();
+		let mut i = 0;
+		while i < sets.len() {
+			let mut row = [None; 256];
+			for byte in 0..=255u8 {
+				let targets = sets[i].iter().flat_map(|&state| {
+					nfa.edges[state]
+						.iter()
+						.filter(move |(set, _)| set.contains(byte))
+						.map(|&(_, target)| target)
+				});
+				let target_set = nfa.epsilon_closure(targets);
+				if target_set.is_empty() {
+					continue;
+				}
+				let id = *set_ids.entry(target_set.clone()).or_insert_with(|| {
+					sets.push(target_set);
+					sets.len() - 1
+				});
+				row[byte as usize] = Some(id);
+			}
+			if sets.len() > MAX_SUBSET_STATES {
+				return Err(RegexError::TooManyStates(sets.len()));
+			}
+			transitions.push(row);
+			i += 1;
+		}
+		let accepting = sets
+			.iter()
+			.map(|set| set.contains(&nfa_accept))
+			.collect::<Vec<_>>();
+
+		let (start, accepting, transitions) = minimize(0, &accepting, &transitions)?;
+		if accepting.len() > MAX_DFA_STATES {
+			return Err(RegexError::TooManyStates(accepting.len()));
+		}
+
+		let transitions = transitions
+			.into_iter()
+			.map(|row| row.map(|next| next.map(|next| next as u8)))
+			.collect();
+		Ok(Self {
+			start: start as u8,
+			accepting,
+			transitions,
+		})
+	}
+
+	/// The number of DFA states.
+	pub fn n_states(&self) -> usize {
+		self.accepting.len()
+	}
+
+	/// The initial state.
+	pub fn start_state(&self) -> u8 {
+		self.start
+	}
+
+	/// Whether `state` is an accepting state.
+	pub fn is_accepting(&self, state: u8) -> bool {
+		self.accepting[state as usize]
+	}
+
+	/// The state reached from `state` after reading `byte`, or `None` if the input is rejected.
+	pub fn next_state(&self, state: u8, byte: u8) -> Option<u8> {
+		self.transitions[state as usize][byte as usize]
+	}
+
+	/// Runs the DFA on `input` and returns the sequence of visited states, including the initial
+	/// state, or `None` if the input is rejected.
+	pub fn run(&self, input: &[u8]) -> Option<Vec<u8>> {
+		let mut states = Vec::with_capacity(input.len() + 1);
+		let mut state = self.start;
+		states.push(state);
+		for &byte in input {
+			state = self.next_state(state, byte)?;
+			states.push(state);
+		}
+		self.is_accepting(state).then_some(states)
+	}
+
+	/// Whether the whole `input` matches the pattern.
+	pub fn accepts(&self, input: &[u8]) -> bool {
+		self.run(input).is_some()
+	}
+
+	/// Iterates over all defined transitions as `(state, byte, next_state)` tuples.
+	pub fn transitions(&self) -> impl Iterator<Item = (u8, u8, u8)> + '_ {
+		self.transitions
+			.iter()
+			.enumerate()
+			.flat_map(|(state, row)| {
+				row.iter().enumerate().filter_map(move |(byte, next)| {
+					next.map(|next| (state as u8, byte as u8, next))
+				})
+			})
+	}
+
+	/// Iterates over the accepting states.
+	pub fn accepting_states(&self) -> impl Iterator<Item = u8> + '_ {
+		self.accepting
+			.iter()
+			.enumerate()
+			.filter_map(|(state, &accepting)| accepting.then_some(state as u8))
+	}
+}
+
+/// Removes dead states and merges equivalent states with Moore's partition refinement.
+///
+/// Returns the new start state, accepting flags, and transition table.
+#[allow(clippy::type_complexity)]
+fn minimize(
+	start: usize,
+	accepting: &[bool],
+	transitions: &[[Option<usize>; 256]],
+) -> Result<(usize, Vec<bool>, Vec<[Option<usize>; 256]>), RegexError> {
+	let n_states = accepting.len();
+
+	// Find the live states by backward reachability from the accepting states.
+	let mut predecessors = vec![Vec::new(); n_states];
+	for (state, row) in transitions.iter().enumerate() {
+		for next in row.iter().flatten() {
+			predecessors[*next].push(state);
+		}
+	}
+	let mut live = accepting.to_vec();
+	let mut stack = (0..n_states).filter(|&s| accepting[s]).collect::<Vec<_>>();
+	while let Some(state) = stack.pop() {
+		for &pred in &predecessors[state] {
+			if !live[pred] {
+				live[pred] = true;
+				stack.push(pred);
+			}
+		}
+	}
+	if !live[start] {
+		return Err(RegexError::EmptyLanguage);
+	}
+	let live_next = |next: Option<usize>| next.filter(|&next| live[next]);
+
+	// Partition refinement. Classes are numbered by first occurrence, so the start state always
+	// ends up in class 0 of the final partition once we renumber below.
+	let mut class = (0..n_states)
+		.map(|s| accepting[s] as usize)
+		.collect::<Vec<_>>();
+	let mut n_classes = 0;
+	loop {
+		let mut signatures = BTreeMap::new();
+		let new_class = (0..n_states)
+			.map(|s| {
+				let signature =
+					(class[s], transitions[s].map(|next| live_next(next).map(|next| class[next])));
+				let n_signatures = signatures.len();
+				*signatures.entry(signature).or_insert(n_signatures)
+			})
+			.collect::<Vec<_>>();
+		let new_n_classes = signatures.len();
+		class = new_class;
+		if new_n_classes == n_classes {
+			break;
+		}
+		n_classes = new_n_classes;
+	}
+
+	// Renumber the live classes in breadth-first order from the start state.
+	let mut renumber = HashMap::from([(class[start], 0usize)]);
+	let mut representatives = vec![start];
+	let mut i = 0;
+	while i < representatives.len() {
+		let state = representatives[i];
+		for next in transitions[state]
+			.iter()
+			.filter_map(|&next| live_next(next))
+		{
+			renumber.entry(class[next]).or_insert_with(|| {
+				representatives.push(next);
+				representatives.len() - 1
+			});
+		}
+		i += 1;
+	}
+
+	let new_accepting = representatives.iter().map(|&s| accepting[s]).collect();
+	let new_transitions = representatives
+		.iter()
+		.map(|&s| transitions[s].map(|next| live_next(next).map(|next| renumber[&class[next]])))
+		.collect();
+	Ok((0, new_accepting, new_transitions))
+}
+
+#[cfg(test)]
+mod tests {
+	use assert_matches::assert_matches;
+
+	use super::*;
+
+	#[test]
+	fn test_literal() {
+		let dfa = Dfa::from_regex("abc").unwrap();
+		assert!(dfa.accepts(b"abc"));
+		assert!(!dfa.accepts(b"ab"));
+		assert!(!dfa.accepts(b"abcd"));
+		assert!(!dfa.accepts(b""));
+		assert_eq!(dfa.n_states(), 4);
+	}
+
+	#[test]
+	fn test_operators() {
+		let dfa = Dfa::from_regex("a(b|c)*d+e?").unwrap();
+		assert!(dfa.accepts(b"ad"));
+		assert!(dfa.accepts(b"abcbcddde"));
+		assert!(!dfa.accepts(b"abe"));
+		assert!(!dfa.accepts(b"adee"));
+	}
+
+	#[test]
+	fn test_bounded_repetition() {
+		let dfa = Dfa::from_regex("x{2,3}y{2}z{1,}").unwrap();
+		assert!(dfa.accepts(b"xxyyz"));
+		assert!(dfa.accepts(b"xxxyyzzz"));
+		assert!(!dfa.accepts(b"xyyz"));
+		assert!(!dfa.accepts(b"xxxxyyz"));
+		assert!(!dfa.accepts(b"xxyz"));
+	}
+
+	#[test]
+	fn test_classes() {
+		let dfa = Dfa::from_regex(r"[a-c\d_]+[^x-z]\.\x41").unwrap();
+		assert!(dfa.accepts(b"a1_bw.A"));
+		assert!(!dfa.accepts(b"a1_by.A"));
+		assert!(!dfa.accepts(b"d1_bw.A"));
+		assert!(!dfa.accepts(b"a1_bw.B"));
+	}
+
+	#[test]
+	fn test_email() {
+		let dfa = Dfa::from_regex(r"[\w.+-]+@[a-zA-Z\d-]+(\.[a-zA-Z\d-]+)*\.[a-z]{2,6}").unwrap();
+		assert!(dfa.accepts(b"alice+test@mail.example.com"));
+		assert!(dfa.accepts(b"bob@irreducible.xyz"));
+		assert!(!dfa.accepts(b"bob@localhost"));
+		assert!(!dfa.accepts(b"@example.com"));
+		assert!(!dfa.accepts(b"bob@@example.com"));
+	}
+
+	#[test]
+	fn test_minimization() {
+		// Both alternatives are equivalent, so the DFA should be as small as for "a+".
+		let dfa = Dfa::from_regex("a+|aa*").unwrap();
+		assert_eq!(dfa.n_states(), 2);
+		assert_eq!(dfa.start_state(), 0);
+		assert_eq!(dfa.run(b"aaa"), Some(vec![0, 1, 1, 1]));
+	}
+
+	#[test]
+	fn test_parse_errors() {
+		assert_eq!(Dfa::from_regex("(ab"), Err(RegexError::UnexpectedEnd));
+		assert_eq!(Dfa::from_regex("ab)"), Err(RegexError::UnexpectedChar(')', 2)));
+		assert_eq!(Dfa::from_regex("*a"), Err(RegexError::UnexpectedChar('*', 0)));
+		assert_eq!(Dfa::from_regex("[z-a]"), Err(RegexError::InvalidRange(1)));
+		assert_eq!(Dfa::from_regex("a{3,2}"), Err(RegexError::InvalidRepetition(1)));
+		assert_eq!(Dfa::from_regex(r"\q"), Err(RegexError::InvalidEscape(0)));
+		assert_eq!(Dfa::from_regex("[^\\x00-\\xff]"), Err(RegexError::EmptyLanguage));
+	}
+
+	#[test]
+	fn test_subset_construction_blowup() {
+		// The DFA has to remember the last 21 characters, which takes 2^21 states.
+		assert_matches!(Dfa::from_regex("(a|b)*a(a|b){20}"), Err(RegexError::TooManyStates(_)));
+	}
+}
@@ -0,0 +1,471 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Gadgets for proving that a committed byte string matches a regular expression.
+//!
+//! A regular expression is compiled into a [`Dfa`], whose transition function becomes a lookup
+//! table of `(state, byte, next_state)` entries. The input is walked one byte per row by
+//! [`DfaStep`], which pulls `(position, state)` from a state channel, reads the transition from
+//! the lookup table, and pushes `(position * g, next_state)` back, where `g` is the multiplicative
+//! generator of `B32`. Positions are thus the powers of `g`, which are distinct for all inputs
+//! shorter than `2^32 - 1` bytes.
+//!
+//! The verifier fixes the automaton and the input length with boundary values:
+//!
+//! * it pushes every lookup table entry exactly once to the DFA table channel, which forces the
+//!   committed [`DfaLookup`] table to be a permutation of the automaton's transitions,
+//! * it pushes `(g^0, start_state)` to the state channel,
+//! * it pulls `g^n` from the accepted channel, which is only pushed by a [`DfaAccept`] row after
+//!   checking that the final state is accepting.
+//!
+//! See [`Dfa::boundaries`] for the complete list.
+
+mod compiler;
+
+use std::collections::HashMap;
+
+use anyhow::ensure;
+use binius_core::constraint_system::channel::{Boundary, ChannelId, FlushDirection};
+use binius_field::{BinaryField, PackedExtension, PackedFieldIndexable, PackedSubfield, ext_basis};
+pub use compiler::{Dfa, MAX_DFA_STATES, RegexError};
+
+pub use crate::gadgets::util::position;
+use crate::{
+	builder::{
+		B1, B8, B32, B128, Col, ConstraintSystem, TableBuilder, TableFiller, TableId,
+		TableWitnessSegment, upcast_col,
+	},
+	gadgets::lookup::LookupProducer,
+};
+
+/// Flag set in the encoding of lookup table entries asserting that a state is accepting.
+const ACCEPT_FLAG: u32 = 1 << 24;
+
+/// Encodes a transition as a lookup table entry.
+pub fn transition_entry(state: u8, byte: u8, next_state: u8) -> u32 {
+	((next_state as u32) << 16) | ((byte as u32) << 8) | state as u32
+}
+
+/// Encodes the assertion that `state` is accepting as a lookup table entry.
+pub fn accept_entry(state: u8) -> u32 {
+	ACCEPT_FLAG | state as u32
+}
+
+/// A single transition of the DFA while reading the byte at position `index`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DfaStepEvent {
+	pub index: usize,
+	pub state: u8,
+	pub byte: u8,
+	pub next_state: u8,
+}
+
+/// The final acceptance check of a DFA run over an input of length `index`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DfaAcceptEvent {
+	pub index: usize,
+	pub state: u8,
+}
+
+impl Dfa {
+	/// Runs the DFA on `input` and returns the step events and the acceptance event, or `None`
+	/// if the input is rejected.
+	pub fn trace(&self, input: &[u8]) -> Option<(Vec<DfaStepEvent>, DfaAcceptEvent)> {
+		let states = self.run(input)?;
+		let steps = input
+			.iter()
+			.enumerate()
+			.map(|(index, &byte)| DfaStepEvent {
+				index,
+				state: states[index],
+				byte,
+				next_state: states[index + 1],
+			})
+			.collect();
+		let accept = DfaAcceptEvent {
+			index: input.len(),
+			state: states[input.len()],
+		};
+		Some((steps, accept))
+	}
+
+	/// The lookup table entries, transitions first, in a canonical order.
+	pub fn lookup_entries(&self) -> Vec<u32> {
+		self.transitions()
+			.map(|(state, byte, next_state)| transition_entry(state, byte, next_state))
+			.chain(self.accepting_states().map(accept_entry))
+			.collect()
+	}
+
+	/// Counts the lookup table reads of the given events.
+	///
+	/// Returns `(entry, count)` pairs for every lookup table entry, sorted by descending count,
+	/// which is the event format expected by [`DfaLookup`].
+	pub fn tally<'a>(
+		&self,
+		steps: impl IntoIterator<Item = &'a DfaStepEvent>,
+		accepts: impl IntoIterator<Item = &'a DfaAcceptEvent>,
+	) -> Vec<(u32, u32)> {
+		let mut counts = self
+			.lookup_entries()
+			.into_iter()
+			.map(|entry| (entry, 0u32))
+			.collect::<HashMap<_, _>>();
+		for step in steps {
+			let entry = transition_entry(step.state, step.byte, step.next_state);
+			*counts
+				.get_mut(&entry)
+				.expect("step events must be DFA transitions") += 1;
+		}
+		for accept in accepts {
+			let entry = accept_entry(accept.state);
+			*counts
+				.get_mut(&entry)
+				.expect("accept events must be accepting states") += 1;
+		}
+
+		let mut counts = counts.into_iter().collect::<Vec<_>>();
+		counts.sort_unstable_by(|(entry_a, count_a), (entry_b, count_b)| {
+			count_b.cmp(count_a).then(entry_a.cmp(entry_b))
+		});
+		counts
+	}
+
+	/// The boundary values a verifier uses to check that an input of length `input_len` matches.
+	pub fn boundaries(&self, channels: &DfaChannels, input_len: usize) -> Vec<Boundary<B128>> {
+		let table_entries = self.lookup_entries().into_iter().map(|entry| Boundary {
+			values: vec![B32::new(entry).into()],
+			channel_id: channels.table,
+			direction: FlushDirection::Push,
+			multiplicity: 1,
+		});
+		[
+			Boundary {
+				values: vec![position(0).into(), B8::new(self.start_state()).into()],
+				channel_id: channels.state,
+				direction: FlushDirection::Push,
+				multiplicity: 1,
+			},
+			Boundary {
+				values: vec![position(input_len).into()],
+				channel_id: channels.accepted,
+				direction: FlushDirection::Pull,
+				multiplicity: 1,
+			},
+		]
+		.into_iter()
+		.chain(table_entries)
+		.collect()
+	}
+}
+
+/// The channels shared by the DFA gadgets.
+#[derive(Debug, Clone, Copy)]
+pub struct DfaChannels {
+	/// Carries `(position, state)` tuples between consecutive steps.
+	pub state: ChannelId,
+	/// The lookup channel for transition and acceptance entries.
+	pub lookup: ChannelId,
+	/// Binds the committed lookup table to the verifier's boundary values.
+	pub table: ChannelId,
+	/// Carries the final position of an accepted run.
+	pub accepted: ChannelId,
+}
+
+impl DfaChannels {
+	pub fn new(cs: &mut ConstraintSystem) -> Self {
+		Self {
+			state: cs.add_channel("dfa_state"),
+			lookup: cs.add_channel("dfa_lookup"),
+			table: cs.add_channel("dfa_table"),
+			accepted: cs.add_channel("dfa_accepted"),
+		}
+	}
+}
+
+/// A gadget reading one input byte per row and advancing the DFA state.
+#[derive(Debug)]
+pub struct DfaStep {
+	/// The input byte, provided by the caller.
+	pub byte: Col<B8>,
+	/// The position of the byte in the input, as a power of the multiplicative generator.
+	pub position: Col<B32>,
+	pub next_position: Col<B32>,
+	pub state: Col<B8>,
+	pub next_state: Col<B8>,
+	merged: Col<B32>,
+}
+
+impl DfaStep {
+	/// Constructs a new DFA step gadget over the input column `byte`.
+	pub fn new(table: &mut TableBuilder, channels: &DfaChannels, byte: Col<B8>) -> Self {
+		let position = table.add_committed::<B32, 1>("position");
+		let state = table.add_committed::<B8, 1>("state");
+		let next_state = table.add_committed::<B8, 1>("next_state");
+		let next_position =
+			table.add_computed("next_position", position * B32::MULTIPLICATIVE_GENERATOR);
+		let merged = table.add_computed(
+			"merged",
+			upcast_col(state)
+				+ upcast_col(byte) * ext_basis::<B32, B8>(1)
+				+ upcast_col(next_state) * ext_basis::<B32, B8>(2),
+		);
+
+		table.pull(channels.state, [position, upcast_col(state)]);
+		table.push(channels.state, [next_position, upcast_col(next_state)]);
+		table.pull(channels.lookup, [merged]);
+
+		Self {
+			byte,
+			position,
+			next_position,
+			state,
+			next_state,
+			merged,
+		}
+	}
+
+	/// Populates the witness columns, except the input byte column, which must already be filled.
+	pub fn populate<'a, P>(
+		&self,
+		witness: &mut TableWitnessSegment<P>,
+		events: impl IntoIterator<Item = &'a DfaStepEvent>,
+	) -> anyhow::Result<()>
+	where
+		P: PackedFieldIndexable<Scalar = B128> + PackedExtension<B8> + PackedExtension<B32>,
+		PackedSubfield<P, B32>: PackedFieldIndexable,
+	{
+		let byte = witness.get_as::<u8, _, 1>(self.byte)?;
+		let mut position = witness.get_scalars_mut(self.position)?;
+		let mut next_position = witness.get_scalars_mut(self.next_position)?;
+		let mut state = witness.get_mut_as::<u8, _, 1>(self.state)?;
+		let mut next_state = witness.get_mut_as::<u8, _, 1>(self.next_state)?;
+		let mut merged = witness.get_mut_as::<u32, _, 1>(self.merged)?;
+
+		for (i, event) in events.into_iter().enumerate() {
+			ensure!(byte[i] == event.byte, "input byte at row {i} does not match the step event");
+			position[i] = self::position(event.index);
+			next_position[i] = position[i] * B32::MULTIPLICATIVE_GENERATOR;
+			state[i] = event.state;
+			next_state[i] = event.next_state;
+			merged[i] = transition_entry(event.state, event.byte, event.next_state);
+		}
+
+		Ok(())
+	}
+}
+
+/// A gadget checking that the final state of a DFA run is accepting.
+///
+/// Each row consumes the last `(position, state)` tuple of a run and pushes the position to the
+/// accepted channel, where the verifier pulls it.
+#[derive(Debug)]
+pub struct DfaAccept {
+	pub position: Col<B32>,
+	pub state: Col<B8>,
+	merged: Col<B32>,
+}
+
+impl DfaAccept {
+	pub fn new(table: &mut TableBuilder, channels: &DfaChannels) -> Self {
+		let position = table.add_committed::<B32, 1>("position");
+		let state = table.add_committed::<B8, 1>("state");
+		let merged = table.add_computed("merged", upcast_col(state) + B32::new(ACCEPT_FLAG));
+
+		table.pull(channels.state, [position, upcast_col(state)]);
+		table.pull(channels.lookup, [merged]);
+		table.push(channels.accepted, [position]);
+
+		Self {
+			position,
+			state,
+			merged,
+		}
+	}
+
+	pub fn populate<'a, P>(
+		&self,
+		witness: &mut TableWitnessSegment<P>,
+		events: impl IntoIterator<Item = &'a DfaAcceptEvent>,
+	) -> anyhow::Result<()>
+	where
+		P: PackedFieldIndexable<Scalar = B128> + PackedExtension<B8> + PackedExtension<B32>,
+		PackedSubfield<P, B32>: PackedFieldIndexable,
+	{
+		let mut position = witness.get_scalars_mut(self.position)?;
+		let mut state = witness.get_mut_as::<u8, _, 1>(self.state)?;
+		let mut merged = witness.get_mut_as::<u32, _, 1>(self.merged)?;
+
+		for (i, event) in events.into_iter().enumerate() {
+			position[i] = self::position(event.index);
+			state[i] = event.state;
+			merged[i] = accept_entry(event.state);
+		}
+
+		Ok(())
+	}
+}
+
+/// The lookup table of DFA transitions and accepting states.
+///
+/// The table is committed, and its entries are pulled from the DFA table channel once each. The
+/// verifier pushes the entries of the automaton as boundary values, see [`Dfa::boundaries`].
+pub struct DfaLookup {
+	table_id: TableId,
+	entries: Col<B32>,
+	lookup_producer: LookupProducer,
+}
+
+impl DfaLookup {
+	pub fn new(
+		table: &mut TableBuilder,
+		channels: &DfaChannels,
+		n_multiplicity_bits: usize,
+	) -> Self {
+		let entries = table.add_committed::<B32, 1>("entries");
+		table.pull(channels.table, [entries]);
+		let lookup_producer =
+			LookupProducer::new(table, channels.lookup, &[entries], n_multiplicity_bits);
+
+		Self {
+			table_id: table.id(),
+			entries,
+			lookup_producer,
+		}
+	}
+}
+
+impl<P> TableFiller<P> for DfaLookup
+where
+	P: PackedFieldIndexable<Scalar = B128> + PackedExtension<B1> + PackedExtension<B32>,
+	PackedSubfield<P, B32>: PackedFieldIndexable,
+{
+	// Tuple of entry and count
+	type Event = (u32, u32);
+
+	fn id(&self) -> TableId {
+		self.table_id
+	}
+
+	fn fill(
+		&self,
+		rows: &[Self::Event],
+		witness: &mut TableWitnessSegment<P>,
+	) -> anyhow::Result<()> {
+		{
+			let mut entries = witness.get_mut_as::<u32, _, 1>(self.entries)?;
+			for (dst, &(entry, _)) in entries.iter_mut().zip(rows) {
+				*dst = entry;
+			}
+		}
+		self.lookup_producer
+			.populate(witness, rows.iter().map(|&(_, count)| count))?;
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_compute::cpu::alloc::CpuComputeAllocator;
+	use binius_field::{arch::OptimalUnderlier128b, as_packed_field::PackedType};
+
+	use super::*;
+	use crate::builder::{
+		WitnessIndex,
+		test_utils::{ClosureFiller, validate_system_witness},
+	};
+
+	fn test_regex_match(pattern: &str, input: &[u8]) {
+		let dfa = Dfa::from_regex(pattern).unwrap();
+		let (steps, accept) = dfa.trace(input).expect("input matches");
+
+		let mut cs = ConstraintSystem::new();
+		let channels = DfaChannels::new(&mut cs);
+		let input_chan = cs.add_channel("input");
+
+		let mut step_table = cs.add_table("dfa_step");
+		let step_table_id = step_table.id();
+		let byte = step_table.add_committed::<B8, 1>("byte");
+		let step = DfaStep::new(&mut step_table, &channels, byte);
+		// Bind the input bytes to their positions, so the verifier can check a public input.
+		step_table.pull(input_chan, [step.position, upcast_col(byte)]);
+
+		let mut accept_table = cs.add_table("dfa_accept");
+		let accept_table_id = accept_table.id();
+		let dfa_accept = DfaAccept::new(&mut accept_table, &channels);
+
+		let mut lookup_table = cs.add_table("dfa_lookup");
+		let dfa_lookup = DfaLookup::new(&mut lookup_table, &channels, 8);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 16);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+
+		witness
+			.fill_table_sequential(
+				&ClosureFiller::new(step_table_id, |events: &[DfaStepEvent], segment| {
+					{
+						let mut byte = segment.get_mut_as::<u8, _, 1>(byte)?;
+						for (dst, event) in byte.iter_mut().zip(events) {
+							*dst = event.byte;
+						}
+					}
+					step.populate(segment, events)
+				}),
+				&steps,
+			)
+			.unwrap();
+		witness
+			.fill_table_sequential(
+				&ClosureFiller::new(accept_table_id, |events: &[DfaAcceptEvent], segment| {
+					dfa_accept.populate(segment, events)
+				}),
+				&[accept],
+			)
+			.unwrap();
+		witness
+			.fill_table_sequential(&dfa_lookup, &dfa.tally(&steps, &[accept]))
+			.unwrap();
+
+		let mut boundaries = dfa.boundaries(&channels, input.len());
+		boundaries.extend(input.iter().enumerate().map(|(i, &byte)| Boundary {
+			values: vec![position(i).into(), B8::new(byte).into()],
+			channel_id: input_chan,
+			direction: FlushDirection::Push,
+			multiplicity: 1,
+		}));
+
+		validate_system_witness::<OptimalUnderlier128b>(&cs, witness, boundaries);
+	}
+
+	#[test]
+	fn test_email_match() {
+		test_regex_match(
+			r"[\w.+-]+@[a-zA-Z\d-]+(\.[a-zA-Z\d-]+)*\.[a-z]{2,6}",
+			b"alice.smith+binius@mail.example.org",
+		);
+	}
+
+	#[test]
+	fn test_repeated_transitions() {
+		test_regex_match("(ab|ba)*c?", b"abababbababaabbaabc");
+	}
+
+	#[test]
+	fn test_trace_rejects() {
+		let dfa = Dfa::from_regex("a+b").unwrap();
+		assert!(dfa.trace(b"aab").is_some());
+		assert!(dfa.trace(b"aa").is_none());
+		assert!(dfa.trace(b"aabb").is_none());
+	}
+
+	#[test]
+	fn test_tally() {
+		let dfa = Dfa::from_regex("a+b").unwrap();
+		let (steps, accept) = dfa.trace(b"aaab").unwrap();
+		let counts = dfa.tally(&steps, &[accept]);
+		assert_eq!(counts.len(), dfa.lookup_entries().len());
+		assert_eq!(counts[0], (transition_entry(1, b'a', 1), 2));
+		assert_eq!(counts.iter().map(|(_, count)| count).sum::<u32>(), 5);
+	}
+}
@@ -0,0 +1,541 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_core::oracle::ShiftVariant;
+use binius_field::{
+	Field, PackedExtension, PackedField, PackedFieldIndexable, PackedSubfield,
+	packed::set_packed_slice,
+};
+
+use super::{Rv32imChannels, fill_constant};
+use crate::{
+	builder::{
+		B1, B32, B64, B128, Col, Expr, TableBuilder, TableFiller, TableId, TableWitnessSegment,
+		upcast_col,
+	},
+	emulate::rv32im::{Opcode, alu},
+	gadgets::{
+		add::{U32Add, U32AddFlags},
+		barrel_shifter::BarrelShifter,
+		div::{DivSS32, DivUU32},
+		mul::{MulSS32, MulSU32, MulUU32},
+		sub::{U32Sub, U32SubFlags},
+	},
+};
+
+/// The sign bit of a 32-bit word.
+const SIGN_BIT: u32 = 1 << 31;
+
+/// The circuit computing an ALU operation.
+#[allow(clippy::large_enum_variant)]
+enum AluCircuit {
+	Add(U32Add),
+	Sub(U32Sub),
+	/// `XOR`, `OR` or `AND` of the operand bits.
+	Bitwise {
+		a: Col<B1, 32>,
+		b: Col<B1, 32>,
+		c: Col<B1, 32>,
+	},
+	/// Unsigned comparison of the operands, with their sign bits flipped for signed comparisons.
+	Compare {
+		sub: U32Sub,
+		signed: bool,
+	},
+	Shift {
+		a: Col<B1, 32>,
+		b: Col<B1, 32>,
+		amount: Col<B1, 16>,
+		/// The sign handling of arithmetic shifts.
+		sign_extension: Option<SignExtension>,
+		shifter: BarrelShifter,
+	},
+	MulUU(MulUU32),
+	MulSS(MulSS32),
+	MulSU(MulSU32),
+	DivUU {
+		div: DivUU32,
+		divisor: DivisorSubstitution,
+	},
+	DivSS {
+		div: DivSS32,
+		divisor: DivisorSubstitution,
+	},
+}
+
+/// The columns handling the divisors that the division gadgets reject.
+///
+/// The gadget divides by 1 instead of `b` when `b` is zero or, for signed division, when the
+/// quotient of `INT_MIN` by -1 overflows. The gadget then yields the quotient `INT_MIN` and the
+/// remainder zero required on overflow, and for a zero divisor the result is replaced with all
+/// ones for quotients and with `a` for remainders.
+struct DivisorSubstitution {
+	/// The requested divisor, which the gadget input is derived from.
+	b: Col<B32>,
+	b_zero: ZeroFlag,
+	overflow: Option<DivOverflow>,
+	c: Col<B32>,
+}
+
+/// The columns detecting a signed division of `INT_MIN` by -1.
+struct DivOverflow {
+	a_min: ZeroFlag,
+	b_minus_one: ZeroFlag,
+	overflow: Col<B1>,
+}
+
+/// A bit flagging whether a value is zero, with the inverse of the value proving it is not.
+struct ZeroFlag {
+	inverse: Col<B32>,
+	flag: Col<B1>,
+}
+
+impl ZeroFlag {
+	fn new(table: &mut TableBuilder, name: &str, value: Expr<B32, 1>) -> Self {
+		let inverse = table.add_committed(format!("{name}_inv"));
+		let flag = table.add_committed(format!("{name}_zero"));
+		table.assert_zero(
+			format!("{name}_zero_if_not_invertible"),
+			value.clone() * inverse + upcast_col(flag) - B32::ONE,
+		);
+		table.assert_zero(format!("{name}_nonzero_if_flagged"), value * upcast_col(flag));
+		Self { inverse, flag }
+	}
+
+	fn populate<P>(
+		&self,
+		witness: &mut TableWitnessSegment<P>,
+		values: impl IntoIterator<Item = u32>,
+	) -> anyhow::Result<()>
+	where
+		P: PackedFieldIndexable<Scalar = B128> + PackedExtension<B1> + PackedExtension<B32>,
+		PackedSubfield<P, B32>: PackedFieldIndexable,
+	{
+		let mut inverse = witness.get_scalars_mut(self.inverse)?;
+		let mut flag = witness.get_mut(self.flag)?;
+		for (i, value) in values.into_iter().enumerate() {
+			inverse[i] = B32::new(value).invert_or_zero();
+			set_packed_slice(&mut flag, i, B1::from(value == 0));
+		}
+		Ok(())
+	}
+}
+
+impl DivisorSubstitution {
+	/// Creates the columns selecting the gadget divisor `q_in` and the result from the gadget
+	/// output `out`.
+	fn new(
+		table: &mut TableBuilder,
+		op: Opcode,
+		a: Col<B32>,
+		q_in: Col<B32>,
+		out: Col<B32>,
+	) -> Self {
+		let b = table.add_committed("b");
+		let b_zero = ZeroFlag::new(table, "b", b.into());
+		let overflow = matches!(op, Opcode::Div | Opcode::Rem).then(|| {
+			let a_min = ZeroFlag::new(table, "a_min", a + B32::new(SIGN_BIT));
+			let b_minus_one = ZeroFlag::new(table, "b_minus_one", b + B32::new(u32::MAX));
+			let overflow = table.add_computed("overflow", a_min.flag * b_minus_one.flag);
+			DivOverflow {
+				a_min,
+				b_minus_one,
+				overflow,
+			}
+		});
+
+		// The zero and overflow flags are exclusive, so their sum selects the divisor 1.
+		let mut substituted = upcast_col(b_zero.flag) * (b + B32::ONE);
+		if let Some(overflow) = &overflow {
+			substituted = substituted + upcast_col(overflow.overflow) * (b + B32::ONE);
+		}
+		table.assert_zero("divisor", q_in - (substituted + b));
+
+		let c = match op {
+			Opcode::Div | Opcode::Divu => {
+				table.add_computed("c", out + upcast_col(b_zero.flag) * (out + B32::new(u32::MAX)))
+			}
+			_ => table.add_computed("c", out + upcast_col(b_zero.flag) * (out + a)),
+		};
+		Self {
+			b,
+			b_zero,
+			overflow,
+			c,
+		}
+	}
+
+	/// Fills the columns and returns the divisors passed to the gadget.
+	fn populate<P>(
+		&self,
+		witness: &mut TableWitnessSegment<P>,
+		op: Opcode,
+		rows: &[(u32, u32)],
+	) -> anyhow::Result<Vec<B32>>
+	where
+		P: PackedFieldIndexable<Scalar = B128> + PackedExtension<B1> + PackedExtension<B32>,
+		PackedSubfield<P, B32>: PackedFieldIndexable,
+	{
+		self.b_zero
+			.populate(witness, rows.iter().map(|&(_, b)| b))?;
+		if let Some(overflow) = &self.overflow {
+			overflow
+				.a_min
+				.populate(witness, rows.iter().map(|&(a, _)| a ^ SIGN_BIT))?;
+			overflow
+				.b_minus_one
+				.populate(witness, rows.iter().map(|&(_, b)| b ^ u32::MAX))?;
+			let mut flag = witness.get_mut(overflow.overflow)?;
+			for (i, &(a, b)) in rows.iter().enumerate() {
+				set_packed_slice(&mut flag, i, B1::from(a == SIGN_BIT && b == u32::MAX));
+			}
+		}
+
+		let mut b_col = witness.get_mut_as::<u32, _, 1>(self.b)?;
+		let mut c = witness.get_mut_as::<u32, _, 1>(self.c)?;
+		let overflows = self.overflow.is_some();
+		Ok(rows
+			.iter()
+			.enumerate()
+			.map(|(i, &(a, b))| {
+				b_col[i] = b;
+				c[i] = alu(op, a, b);
+				if b == 0 || (overflows && a == SIGN_BIT && b == u32::MAX) {
+					B32::ONE
+				} else {
+					B32::new(b)
+				}
+			})
+			.collect())
+	}
+}
+
+/// The columns turning a logical right shift into an arithmetic one.
+///
+/// The shifter input is `a ^ mask` and the result is `shifted ^ mask`, where every bit of the mask
+/// equals the sign bit of `a`.
+struct SignExtension {
+	mask: Col<B1, 32>,
+	mask_rotated: Col<B1, 32>,
+	mask_bit: Col<B1>,
+	sign_bit: Col<B1>,
+	input: Col<B1, 32>,
+	output: Col<B1, 32>,
+}
+
+/// A table proving the results of one ALU operation.
+///
+/// Each row pulls `(op, a, b, op(a, b))` from the ALU channel, where the CPU tables push their
+/// requests.
+pub struct AluTable {
+	table_id: TableId,
+	op: Opcode,
+	op_col: Col<B32>,
+	a: Col<B32>,
+	b: Col<B32>,
+	circuit: AluCircuit,
+}
+
+impl AluTable {
+	/// Creates the table for the register-register ALU operation `op`.
+	pub fn new(table: &mut TableBuilder, channels: &Rv32imChannels, op: Opcode) -> Self {
+		let op_col = table.add_constant("op", [B32::new(op.id())]);
+		let (a, b, c, circuit) = match op {
+			Opcode::Add => {
+				let a = table.add_committed("a");
+				let b = table.add_committed("b");
+				let add = U32Add::new(table, a, b, U32AddFlags::default());
+				let c = table.add_packed("c", add.zout);
+				(table.add_packed("a", a), table.add_packed("b", b), c, AluCircuit::Add(add))
+			}
+			Opcode::Sub => {
+				let a = table.add_committed("a");
+				let b = table.add_committed("b");
+				let sub = U32Sub::new(table, a, b, U32SubFlags::default());
+				let c = table.add_packed("c", sub.zout);
+				(table.add_packed("a", a), table.add_packed("b", b), c, AluCircuit::Sub(sub))
+			}
+			Opcode::Xor | Opcode::Or | Opcode::And => {
+				let a = table.add_committed::<B1, 32>("a");
+				let b = table.add_committed::<B1, 32>("b");
+				let c = match op {
+					Opcode::Xor => table.add_computed("c", a + b),
+					Opcode::Or => table.add_computed("c", a + b + a * b),
+					_ => table.add_computed("c", a * b),
+				};
+				(
+					table.add_packed("a", a),
+					table.add_packed("b", b),
+					table.add_packed("c", c),
+					AluCircuit::Bitwise { a, b, c },
+				)
+			}
+			Opcode::Slt | Opcode::Sltu => {
+				let signed = op == Opcode::Slt;
+				let x = table.add_committed("x");
+				let y = table.add_committed("y");
+				let sub = U32Sub::new(
+					table,
+					x,
+					y,
+					U32SubFlags {
+						expose_final_borrow: true,
+						..U32SubFlags::default()
+					},
+				);
+				let x_packed = table.add_packed::<_, 32, B32, 1>("x_packed", x);
+				let y_packed = table.add_packed::<_, 32, B32, 1>("y_packed", y);
+				let (a, b) = if signed {
+					(
+						table.add_computed("a", x_packed + B32::new(SIGN_BIT)),
+						table.add_computed("b", y_packed + B32::new(SIGN_BIT)),
+					)
+				} else {
+					(x_packed, y_packed)
+				};
+				let c = upcast_col(sub.final_borrow.expect("expose_final_borrow is set"));
+				(a, b, c, AluCircuit::Compare { sub, signed })
+			}
+			Opcode::Sll | Opcode::Srl | Opcode::Sra => {
+				let a = table.add_committed::<B1, 32>("a");
+				let b = table.add_committed::<B1, 32>("b");
+				let amount = table.add_selected_block("amount", b, 0);
+				let (shifter, sign_extension, c) = match op {
+					Opcode::Sra => {
+						// An arithmetic shift is a logical shift of `a ^ mask`, masked again, where
+						// the mask has all bits equal to the sign bit of `a`.
+						let mask = table.add_committed::<B1, 32>("mask");
+						let mask_rotated = table.add_shifted(
+							"mask_rotated",
+							mask,
+							5,
+							1,
+							ShiftVariant::CircularLeft,
+						);
+						table.assert_zero("mask_uniform", mask_rotated - mask);
+						let mask_bit = table.add_selected("mask_bit", mask, 0);
+						let sign_bit = table.add_selected("sign_bit", a, 31);
+						table.assert_zero("mask_is_sign", mask_bit - sign_bit);
+
+						let input = table.add_computed("input", a + mask);
+						let shifter =
+							BarrelShifter::new(table, input, amount, ShiftVariant::LogicalRight);
+						let c = table.add_computed("c", shifter.output + mask);
+						let sign_extension = SignExtension {
+							mask,
+							mask_rotated,
+							mask_bit,
+							sign_bit,
+							input,
+							output: c,
+						};
+						(shifter, Some(sign_extension), c)
+					}
+					_ => {
+						let variant = match op {
+							Opcode::Sll => ShiftVariant::LogicalLeft,
+							_ => ShiftVariant::LogicalRight,
+						};
+						let shifter = BarrelShifter::new(table, a, amount, variant);
+						let c = shifter.output;
+						(shifter, None, c)
+					}
+				};
+				(
+					table.add_packed("a", a),
+					table.add_packed("b", b),
+					table.add_packed("c", c),
+					AluCircuit::Shift {
+						a,
+						b,
+						amount,
+						sign_extension,
+						shifter,
+					},
+				)
+			}
+			Opcode::Mul | Opcode::Mulhu => {
+				let mul = MulUU32::new(table);
+				let c = match op {
+					Opcode::Mul => mul.out_low,
+					_ => mul.out_high,
+				};
+				(mul.xin, mul.yin, c, AluCircuit::MulUU(mul))
+			}
+			Opcode::Mulh => {
+				let mul = MulSS32::new(table);
+				(mul.xin, mul.yin, mul.out_high, AluCircuit::MulSS(mul))
+			}
+			Opcode::Mulhsu => {
+				let mul = MulSU32::new(table);
+				(mul.xin, mul.yin, mul.out_high, AluCircuit::MulSU(mul))
+			}
+			Opcode::Divu | Opcode::Remu => {
+				let div = DivUU32::new(table);
+				let out = match op {
+					Opcode::Divu => div.out_div,
+					_ => div.out_rem,
+				};
+				let divisor = DivisorSubstitution::new(table, op, div.p_in, div.q_in, out);
+				(div.p_in, divisor.b, divisor.c, AluCircuit::DivUU { div, divisor })
+			}
+			Opcode::Div | Opcode::Rem => {
+				let div = DivSS32::new(table);
+				let out = match op {
+					Opcode::Div => div.out_div,
+					_ => div.out_rem,
+				};
+				let divisor = DivisorSubstitution::new(table, op, div.p_in, div.q_in, out);
+				(div.p_in, divisor.b, divisor.c, AluCircuit::DivSS { div, divisor })
+			}
+			_ => panic!("{op:?} is not a register-register ALU operation"),
+		};
+		table.pull(channels.alu, [op_col, a, b, c]);
+
+		Self {
+			table_id: table.id(),
+			op,
+			op_col,
+			a,
+			b,
+			circuit,
+		}
+	}
+
+	pub fn op(&self) -> Opcode {
+		self.op
+	}
+}
+
+impl<P> TableFiller<P> for AluTable
+where
+	P: PackedFieldIndexable<Scalar = B128>
+		+ PackedExtension<B1>
+		+ PackedExtension<B32>
+		+ PackedExtension<B64>,
+	PackedSubfield<P, B32>: PackedFieldIndexable,
+{
+	/// Tuple of the operands.
+	type Event = (u32, u32);
+
+	fn id(&self) -> TableId {
+		self.table_id
+	}
+
+	fn fill(
+		&self,
+		rows: &[Self::Event],
+		witness: &mut TableWitnessSegment<P>,
+	) -> anyhow::Result<()> {
+		fill_constant(witness, self.op_col, self.op.id())?;
+
+		let a_vals = || rows.iter().map(|&(a, _)| B32::new(a));
+		let b_vals = || rows.iter().map(|&(_, b)| B32::new(b));
+		match &self.circuit {
+			AluCircuit::Add(add) => {
+				fill_operands(witness, add.xin, add.yin, rows.iter().copied())?;
+				add.populate(witness)
+			}
+			AluCircuit::Sub(sub) => {
+				fill_operands(witness, sub.xin, sub.yin, rows.iter().copied())?;
+				sub.populate(witness)
+			}
+			AluCircuit::Bitwise { a, b, c } => {
+				fill_operands(witness, *a, *b, rows.iter().copied())?;
+				let mut c = witness.get_mut_as::<u32, _, 32>(*c)?;
+				for (i, &(a, b)) in rows.iter().enumerate() {
+					c[i] = alu(self.op, a, b);
+				}
+				Ok(())
+			}
+			AluCircuit::Compare { sub, signed } => {
+				if *signed {
+					// The packed operands are computed from the flipped bits.
+					fill_operands(
+						witness,
+						sub.xin,
+						sub.yin,
+						rows.iter().map(|&(a, b)| (a ^ SIGN_BIT, b ^ SIGN_BIT)),
+					)?;
+					let mut a = witness.get_mut_as::<u32, _, 1>(self.a)?;
+					let mut b = witness.get_mut_as::<u32, _, 1>(self.b)?;
+					for (i, &(a_val, b_val)) in rows.iter().enumerate() {
+						a[i] = a_val;
+						b[i] = b_val;
+					}
+				} else {
+					fill_operands(witness, sub.xin, sub.yin, rows.iter().copied())?;
+				}
+				sub.populate(witness)
+			}
+			AluCircuit::Shift {
+				a,
+				b,
+				amount,
+				sign_extension,
+				shifter,
+			} => {
+				fill_operands(witness, *a, *b, rows.iter().copied())?;
+				{
+					let mut amount = witness.get_mut_as::<u16, _, 16>(*amount)?;
+					for (i, &(_, b)) in rows.iter().enumerate() {
+						amount[i] = b as u16;
+					}
+				}
+				if let Some(sign_extension) = sign_extension {
+					let mut mask = witness.get_mut_as::<u32, _, 32>(sign_extension.mask)?;
+					let mut mask_rotated =
+						witness.get_mut_as::<u32, _, 32>(sign_extension.mask_rotated)?;
+					let mut mask_bit = witness.get_mut(sign_extension.mask_bit)?;
+					let mut sign_bit = witness.get_mut(sign_extension.sign_bit)?;
+					let mut input = witness.get_mut_as::<u32, _, 32>(sign_extension.input)?;
+					for (i, &(a, _)) in rows.iter().enumerate() {
+						let sign = a >> 31 == 1;
+						mask[i] = if sign { u32::MAX } else { 0 };
+						mask_rotated[i] = mask[i];
+						set_packed_slice(&mut mask_bit, i, B1::from(sign));
+						set_packed_slice(&mut sign_bit, i, B1::from(sign));
+						input[i] = a ^ mask[i];
+					}
+				}
+				shifter.populate(witness)?;
+				if let Some(sign_extension) = sign_extension {
+					let mut c = witness.get_mut_as::<u32, _, 32>(sign_extension.output)?;
+					for (i, &(a, b)) in rows.iter().enumerate() {
+						c[i] = alu(self.op, a, b);
+					}
+				}
+				Ok(())
+			}
+			AluCircuit::MulUU(mul) => mul.populate_with_inputs(witness, a_vals(), b_vals()),
+			AluCircuit::MulSS(mul) => mul.populate_with_inputs(witness, a_vals(), b_vals()),
+			AluCircuit::MulSU(mul) => mul.populate_with_inputs(witness, a_vals(), b_vals()),
+			AluCircuit::DivUU { div, divisor } => {
+				let q_vals = divisor.populate(witness, self.op, rows)?;
+				div.populate_with_inputs(witness, a_vals(), q_vals)
+			}
+			AluCircuit::DivSS { div, divisor } => {
+				let q_vals = divisor.populate(witness, self.op, rows)?;
+				div.populate_with_inputs(witness, a_vals(), q_vals)
+			}
+		}
+	}
+}
+
+/// Fills the operand bit columns.
+fn fill_operands<P>(
+	witness: &mut TableWitnessSegment<P>,
+	a: Col<B1, 32>,
+	b: Col<B1, 32>,
+	rows: impl IntoIterator<Item = (u32, u32)>,
+) -> anyhow::Result<()>
+where
+	P: PackedFieldIndexable<Scalar = B128> + PackedExtension<B1>,
+{
+	let mut a = witness.get_mut_as::<u32, _, 32>(a)?;
+	let mut b = witness.get_mut_as::<u32, _, 32>(b)?;
+	for (i, (a_val, b_val)) in rows.into_iter().enumerate() {
+		a[i] = a_val;
+		b[i] = b_val;
+	}
+	Ok(())
+}
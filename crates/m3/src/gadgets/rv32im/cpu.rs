@@ -0,0 +1,959 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::array;
+
+use anyhow::{anyhow, ensure};
+use binius_core::{constraint_system::channel::ChannelId, oracle::ShiftVariant};
+use binius_field::{
+	Field, PackedExtension, PackedField, PackedFieldIndexable, PackedSubfield,
+	packed::set_packed_slice,
+};
+
+use super::{Rv32imChannels, fill_constant, memory::Access};
+use crate::{
+	builder::{
+		B1, B32, B128, Col, TableBuilder, TableFiller, TableId, TableWitnessSegment, upcast_col,
+	},
+	emulate::rv32im::{
+		CpuEvent, IMM_FLAG, InstructionClass, MEM_TIMESTAMP_OFFSET, Opcode, RD_TIMESTAMP_OFFSET,
+		RS1_TIMESTAMP_OFFSET, RS2_TIMESTAMP_OFFSET,
+	},
+	gadgets::add::{U32Add, U32AddFlags},
+};
+
+/// The columns shared by all CPU tables.
+///
+/// Every row fetches the ROM entry at `pc`, pulls `(pc, clk)` from the state channel, and, unless
+/// the machine halts, pushes `(next_pc, clk + 1)`. The timestamp of an access with offset `k` in
+/// `1..=3` is `4 * clk + k`, which is computed from the bits of `clk` with a shift and constant
+/// low bits.
+#[derive(Debug)]
+pub struct Cpu {
+	pub pc: Col<B32>,
+	/// The program counter of the next instruction, to be constrained by the instruction table.
+	pub next_pc: Col<B32>,
+	pub opcode: Col<B32>,
+	pub rd: Col<B32>,
+	pub rs1: Col<B32>,
+	pub rs2: Col<B32>,
+	pub imm: Col<B32>,
+	clk_bits: Col<B1, 32>,
+	one: Col<B1, 32>,
+	next_clk: U32Add,
+	clk_x4: Col<B1, 32>,
+	/// The timestamps with offsets `1..=3`.
+	timestamps: [Col<B1, 32>; 3],
+	/// Constant columns holding the low bits of the timestamps.
+	offsets: [Col<B1, 32>; 3],
+	/// `B32` constant columns and their values.
+	constants: Vec<(Col<B32>, u32)>,
+}
+
+impl Cpu {
+	/// Creates the CPU columns, constraining the opcode to `opcode` if given.
+	pub fn new(
+		table: &mut TableBuilder,
+		channels: &Rv32imChannels,
+		opcode: Option<Opcode>,
+	) -> Self {
+		let pc = table.add_committed("pc");
+		let next_pc = table.add_committed("next_pc");
+		let [opcode_col, rd, rs1, rs2, imm] =
+			["opcode", "rd", "rs1", "rs2", "imm"].map(|name| table.add_committed(name));
+		if let Some(opcode) = opcode {
+			table.assert_zero("opcode", opcode_col - B32::new(opcode.id()));
+		}
+		table.pull(channels.rom, [pc, opcode_col, rd, rs1, rs2, imm]);
+
+		let clk_bits = table.add_committed("clk_bits");
+		let clk = table.add_packed("clk", clk_bits);
+		table.pull(channels.state, [pc, clk]);
+
+		let one = table.add_constant("one", array::from_fn(|i| B1::from(i == 0)));
+		let next_clk = U32Add::new(
+			&mut table.with_namespace("next_clk"),
+			clk_bits,
+			one,
+			U32AddFlags::default(),
+		);
+
+		let clk_x4 = table.add_shifted("clk_x4", clk_bits, 5, 2, ShiftVariant::LogicalLeft);
+		let offsets = [1, 2, 3].map(|offset: u32| {
+			table.add_constant(
+				format!("timestamp_offset_{offset}"),
+				array::from_fn(|i| B1::from((offset >> i) & 1 == 1)),
+			)
+		});
+		let timestamps = array::from_fn(|i| {
+			table.add_computed(format!("timestamp_{}", i + 1), clk_x4 + offsets[i])
+		});
+
+		Self {
+			pc,
+			next_pc,
+			opcode: opcode_col,
+			rd,
+			rs1,
+			rs2,
+			imm,
+			clk_bits,
+			one,
+			next_clk,
+			clk_x4,
+			timestamps,
+			offsets,
+			constants: Vec::new(),
+		}
+	}
+
+	/// Pushes the state of the next cycle.
+	pub fn transition(&self, table: &mut TableBuilder, channels: &Rv32imChannels) {
+		let next_clk = table.add_packed("next_clk", self.next_clk.zout);
+		table.push(channels.state, [self.next_pc, next_clk]);
+	}
+
+	/// Adds a `B32` constant column, which is populated by [`Self::populate`].
+	///
+	/// Constants with the same value share a column.
+	pub fn constant(
+		&mut self,
+		table: &mut TableBuilder,
+		name: impl ToString,
+		value: u32,
+	) -> Col<B32> {
+		if let Some(&(col, _)) = self.constants.iter().find(|&&(_, v)| v == value) {
+			return col;
+		}
+		let col = table.add_constant(name, [B32::new(value)]);
+		self.constants.push((col, value));
+		col
+	}
+
+	/// Requests `c = op(a, b)` from the ALU tables.
+	pub fn alu(
+		&mut self,
+		table: &mut TableBuilder,
+		channels: &Rv32imChannels,
+		op: Opcode,
+		[a, b, c]: [Col<B32>; 3],
+	) {
+		let op = self.constant(table, format!("{op:?}"), op.id());
+		table.push(channels.alu, [op, a, b, c]);
+	}
+
+	/// Constrains `next_pc = pc + 4`.
+	pub fn sequential(&mut self, table: &mut TableBuilder, channels: &Rv32imChannels) {
+		let four = self.constant(table, "four", 4);
+		self.alu(table, channels, Opcode::Add, [self.pc, four, self.next_pc]);
+	}
+
+	/// Adds an access to a register or RAM cell at the timestamp with the given offset.
+	pub fn access(
+		&self,
+		table: &mut TableBuilder,
+		channel: ChannelId,
+		addr: Col<B32>,
+		value: Option<Col<B32>>,
+		offset: u32,
+	) -> Access {
+		let offset = offset as usize;
+		assert!((1..=3).contains(&offset));
+		let timestamp_minus_one = match offset {
+			1 => self.clk_x4,
+			_ => self.timestamps[offset - 2],
+		};
+		Access::new(table, channel, addr, value, self.timestamps[offset - 1], timestamp_minus_one)
+	}
+
+	pub fn populate<'a, P>(
+		&self,
+		witness: &mut TableWitnessSegment<P>,
+		events: impl IntoIterator<Item = &'a CpuEvent>,
+	) -> anyhow::Result<()>
+	where
+		P: PackedFieldIndexable<Scalar = B128> + PackedExtension<B1> + PackedExtension<B32>,
+	{
+		{
+			let mut pc = witness.get_mut_as::<u32, _, 1>(self.pc)?;
+			let mut next_pc = witness.get_mut_as::<u32, _, 1>(self.next_pc)?;
+			let mut fields =
+				array_util::try_map([self.opcode, self.rd, self.rs1, self.rs2, self.imm], |col| {
+					witness.get_mut_as::<u32, _, 1>(col)
+				})?;
+			let mut clk = witness.get_mut_as::<u32, _, 32>(self.clk_bits)?;
+			let mut clk_x4 = witness.get_mut_as::<u32, _, 32>(self.clk_x4)?;
+			let mut timestamps =
+				array_util::try_map(self.timestamps, |col| witness.get_mut_as::<u32, _, 32>(col))?;
+
+			for (i, event) in events.into_iter().enumerate() {
+				ensure!(event.clk < 1 << 30, "cycle {} exceeds the timestamp range", event.clk);
+				pc[i] = event.rom.pc;
+				next_pc[i] = event.next_pc;
+				let rom = super::memory::rom_fields(&event.rom);
+				for (field, value) in fields.iter_mut().zip(&rom[1..]) {
+					field[i] = *value;
+				}
+				clk[i] = event.clk;
+				clk_x4[i] = event.clk << 2;
+				for (offset, timestamp) in timestamps.iter_mut().enumerate() {
+					timestamp[i] = (event.clk << 2) | (offset as u32 + 1);
+				}
+			}
+		}
+
+		fill_constant(witness, self.one, 1)?;
+		for (offset, &col) in self.offsets.iter().enumerate() {
+			fill_constant(witness, col, offset as u32 + 1)?;
+		}
+		for &(col, value) in &self.constants {
+			fill_constant(witness, col, value)?;
+		}
+		self.next_clk.populate(witness)
+	}
+}
+
+fn rd_values<'a>(events: &'a [CpuEvent]) -> impl Iterator<Item = anyhow::Result<u32>> + 'a {
+	events.iter().enumerate().map(|(i, event)| {
+		event
+			.rd
+			.map(|access| access.value)
+			.ok_or_else(|| anyhow!("missing destination register write in row {i}"))
+	})
+}
+
+fn fill_col<P>(
+	witness: &mut TableWitnessSegment<P>,
+	col: Col<B32>,
+	values: impl IntoIterator<Item = anyhow::Result<u32>>,
+) -> anyhow::Result<()>
+where
+	P: PackedFieldIndexable<Scalar = B128> + PackedExtension<B32>,
+{
+	let mut col = witness.get_mut_as::<u32, _, 1>(col)?;
+	for (dst, value) in col.iter_mut().zip(values) {
+		*dst = value?;
+	}
+	Ok(())
+}
+
+macro_rules! impl_cpu_table_filler {
+	($table:ty) => {
+		impl<P> TableFiller<P> for $table
+		where
+			P: PackedFieldIndexable<Scalar = B128> + PackedExtension<B1> + PackedExtension<B32>,
+			PackedSubfield<P, B32>: PackedFieldIndexable,
+		{
+			type Event = CpuEvent;
+
+			fn id(&self) -> TableId {
+				self.table_id
+			}
+
+			fn fill(
+				&self,
+				rows: &[Self::Event],
+				witness: &mut TableWitnessSegment<P>,
+			) -> anyhow::Result<()> {
+				self.cpu.populate(witness, rows)?;
+				self.populate(rows, witness)
+			}
+		}
+	};
+}
+
+/// The table of register-register or register-immediate ALU instructions.
+///
+/// The operation is forwarded to the ALU tables with the opcode of the ROM entry, with the
+/// [`IMM_FLAG`] cleared for register-immediate instructions. Only ALU tables pull from the ALU
+/// channel, so the ROM opcode must be an ALU opcode of the right kind.
+#[derive(Debug)]
+pub struct AluInstrTable {
+	table_id: TableId,
+	cpu: Cpu,
+	rs1: Access,
+	rs2: Option<Access>,
+	rd: Access,
+	alu_op: Col<B32>,
+}
+
+impl AluInstrTable {
+	pub fn new(table: &mut TableBuilder, channels: &Rv32imChannels, immediate: bool) -> Self {
+		let mut cpu = Cpu::new(table, channels, None);
+		let rs1 = cpu.access(table, channels.registers, cpu.rs1, None, RS1_TIMESTAMP_OFFSET);
+		let rs2 = (!immediate)
+			.then(|| cpu.access(table, channels.registers, cpu.rs2, None, RS2_TIMESTAMP_OFFSET));
+		let rd_value = table.add_committed("rd_value");
+		let rd = cpu.access(table, channels.registers, cpu.rd, Some(rd_value), RD_TIMESTAMP_OFFSET);
+
+		let (alu_op, operand) = match &rs2 {
+			Some(rs2) => (cpu.opcode, rs2.value),
+			None => (table.add_computed("alu_op", cpu.opcode + B32::new(IMM_FLAG)), cpu.imm),
+		};
+		table.push(channels.alu, [alu_op, rs1.value, operand, rd_value]);
+		cpu.sequential(table, channels);
+		cpu.transition(table, channels);
+
+		Self {
+			table_id: table.id(),
+			cpu,
+			rs1,
+			rs2,
+			rd,
+			alu_op,
+		}
+	}
+
+	fn populate<P>(
+		&self,
+		events: &[CpuEvent],
+		witness: &mut TableWitnessSegment<P>,
+	) -> anyhow::Result<()>
+	where
+		P: PackedFieldIndexable<Scalar = B128> + PackedExtension<B1> + PackedExtension<B32>,
+	{
+		if self.rs2.is_none() {
+			fill_col(
+				witness,
+				self.alu_op,
+				events
+					.iter()
+					.map(|event| Ok(event.rom.opcode.id() ^ IMM_FLAG)),
+			)?;
+		}
+		self.rs1
+			.populate(witness, events.iter().map(|event| event.rs1.as_ref()))?;
+		if let Some(rs2) = &self.rs2 {
+			rs2.populate(witness, events.iter().map(|event| event.rs2.as_ref()))?;
+		}
+		fill_col(witness, self.rd.value, rd_values(events))?;
+		self.rd
+			.populate(witness, events.iter().map(|event| event.rd.as_ref()))
+	}
+}
+
+impl_cpu_table_filler!(AluInstrTable);
+
+/// The table of `LUI` or `AUIPC` instructions.
+#[derive(Debug)]
+pub struct UpperImmTable {
+	table_id: TableId,
+	cpu: Cpu,
+	opcode: Opcode,
+	rd: Access,
+}
+
+impl UpperImmTable {
+	pub fn new(table: &mut TableBuilder, channels: &Rv32imChannels, opcode: Opcode) -> Self {
+		assert!(matches!(opcode, Opcode::Lui | Opcode::Auipc));
+		let mut cpu = Cpu::new(table, channels, Some(opcode));
+		let rd_value = match opcode {
+			Opcode::Lui => cpu.imm,
+			_ => {
+				let rd_value = table.add_committed("rd_value");
+				cpu.alu(table, channels, Opcode::Add, [cpu.pc, cpu.imm, rd_value]);
+				rd_value
+			}
+		};
+		let rd = cpu.access(table, channels.registers, cpu.rd, Some(rd_value), RD_TIMESTAMP_OFFSET);
+		cpu.sequential(table, channels);
+		cpu.transition(table, channels);
+
+		Self {
+			table_id: table.id(),
+			cpu,
+			opcode,
+			rd,
+		}
+	}
+
+	fn populate<P>(
+		&self,
+		events: &[CpuEvent],
+		witness: &mut TableWitnessSegment<P>,
+	) -> anyhow::Result<()>
+	where
+		P: PackedFieldIndexable<Scalar = B128> + PackedExtension<B1> + PackedExtension<B32>,
+	{
+		if self.opcode == Opcode::Auipc {
+			fill_col(witness, self.rd.value, rd_values(events))?;
+		}
+		self.rd
+			.populate(witness, events.iter().map(|event| event.rd.as_ref()))
+	}
+}
+
+impl_cpu_table_filler!(UpperImmTable);
+
+/// The table of `JAL` or `JALR` instructions.
+#[derive(Debug)]
+pub struct JumpTable {
+	table_id: TableId,
+	cpu: Cpu,
+	rs1: Option<Access>,
+	/// The unmasked jump target of `JALR`.
+	target: Option<Col<B32>>,
+	rd: Access,
+}
+
+impl JumpTable {
+	pub fn new(table: &mut TableBuilder, channels: &Rv32imChannels, opcode: Opcode) -> Self {
+		let mut cpu = Cpu::new(table, channels, Some(opcode));
+		let (rs1, target) = match opcode {
+			Opcode::Jal => {
+				cpu.alu(table, channels, Opcode::Add, [cpu.pc, cpu.imm, cpu.next_pc]);
+				(None, None)
+			}
+			Opcode::Jalr => {
+				let rs1 =
+					cpu.access(table, channels.registers, cpu.rs1, None, RS1_TIMESTAMP_OFFSET);
+				let target = table.add_committed("target");
+				let mask = cpu.constant(table, "clear_low_bit", !1);
+				cpu.alu(table, channels, Opcode::Add, [rs1.value, cpu.imm, target]);
+				cpu.alu(table, channels, Opcode::And, [target, mask, cpu.next_pc]);
+				(Some(rs1), Some(target))
+			}
+			_ => panic!("{opcode:?} is not a jump"),
+		};
+
+		let link = table.add_committed("link");
+		let four = cpu.constant(table, "four", 4);
+		cpu.alu(table, channels, Opcode::Add, [cpu.pc, four, link]);
+		let rd = cpu.access(table, channels.registers, cpu.rd, Some(link), RD_TIMESTAMP_OFFSET);
+		cpu.transition(table, channels);
+
+		Self {
+			table_id: table.id(),
+			cpu,
+			rs1,
+			target,
+			rd,
+		}
+	}
+
+	fn populate<P>(
+		&self,
+		events: &[CpuEvent],
+		witness: &mut TableWitnessSegment<P>,
+	) -> anyhow::Result<()>
+	where
+		P: PackedFieldIndexable<Scalar = B128> + PackedExtension<B1> + PackedExtension<B32>,
+	{
+		if let Some(rs1) = &self.rs1 {
+			rs1.populate(witness, events.iter().map(|event| event.rs1.as_ref()))?;
+		}
+		if let Some(target) = self.target {
+			fill_col(
+				witness,
+				target,
+				events.iter().map(|event| {
+					let rs1 = event.rs1.ok_or_else(|| anyhow!("missing rs1 read"))?;
+					Ok(rs1.value.wrapping_add(event.rom.imm))
+				}),
+			)?;
+		}
+		fill_col(witness, self.rd.value, rd_values(events))?;
+		self.rd
+			.populate(witness, events.iter().map(|event| event.rd.as_ref()))
+	}
+}
+
+impl_cpu_table_filler!(JumpTable);
+
+/// The columns deciding whether a branch is taken.
+#[derive(Debug)]
+enum BranchCondition {
+	/// `BEQ` and `BNE`, using an inverse to test `rs1 - rs2` for zero.
+	Equal {
+		diff: Col<B32>,
+		diff_inv: Col<B32>,
+		equal: Col<B1>,
+	},
+	/// `BLT`, `BGE`, `BLTU` and `BGEU`, using an ALU comparison.
+	LessThan { less_than: Col<B1> },
+}
+
+/// The table of conditional branches with a single opcode.
+#[derive(Debug)]
+pub struct BranchTable {
+	table_id: TableId,
+	cpu: Cpu,
+	opcode: Opcode,
+	rs1: Access,
+	rs2: Access,
+	condition: BranchCondition,
+	/// The negated condition bit, for `BNE`, `BGE` and `BGEU`.
+	not_condition: Option<Col<B1>>,
+	fallthrough: Col<B32>,
+	target: Col<B32>,
+}
+
+impl BranchTable {
+	pub fn new(table: &mut TableBuilder, channels: &Rv32imChannels, opcode: Opcode) -> Self {
+		let mut cpu = Cpu::new(table, channels, Some(opcode));
+		let rs1 = cpu.access(table, channels.registers, cpu.rs1, None, RS1_TIMESTAMP_OFFSET);
+		let rs2 = cpu.access(table, channels.registers, cpu.rs2, None, RS2_TIMESTAMP_OFFSET);
+
+		let (condition, condition_bit) = match opcode {
+			Opcode::Beq | Opcode::Bne => {
+				let diff = table.add_computed("diff", rs1.value + rs2.value);
+				let diff_inv = table.add_committed("diff_inv");
+				let equal = table.add_committed("equal");
+				table.assert_zero("equal_if_zero", diff * diff_inv + upcast_col(equal) - B32::ONE);
+				table.assert_zero("zero_if_equal", diff * upcast_col(equal));
+				(
+					BranchCondition::Equal {
+						diff,
+						diff_inv,
+						equal,
+					},
+					equal,
+				)
+			}
+			Opcode::Blt | Opcode::Bge | Opcode::Bltu | Opcode::Bgeu => {
+				let less_than = table.add_committed("less_than");
+				let compare = match opcode {
+					Opcode::Blt | Opcode::Bge => Opcode::Slt,
+					_ => Opcode::Sltu,
+				};
+				cpu.alu(table, channels, compare, [rs1.value, rs2.value, upcast_col(less_than)]);
+				(BranchCondition::LessThan { less_than }, less_than)
+			}
+			_ => panic!("{opcode:?} is not a branch"),
+		};
+		let not_condition = matches!(opcode, Opcode::Bne | Opcode::Bge | Opcode::Bgeu)
+			.then(|| table.add_computed("not_condition", condition_bit + B1::ONE));
+		let taken = not_condition.unwrap_or(condition_bit);
+
+		let fallthrough = table.add_committed("fallthrough");
+		let four = cpu.constant(table, "four", 4);
+		cpu.alu(table, channels, Opcode::Add, [cpu.pc, four, fallthrough]);
+		let target = table.add_committed("target");
+		cpu.alu(table, channels, Opcode::Add, [cpu.pc, cpu.imm, target]);
+		table.assert_zero(
+			"next_pc",
+			cpu.next_pc - (fallthrough + upcast_col(taken) * (target + fallthrough)),
+		);
+		cpu.transition(table, channels);
+
+		Self {
+			table_id: table.id(),
+			cpu,
+			opcode,
+			rs1,
+			rs2,
+			condition,
+			not_condition,
+			fallthrough,
+			target,
+		}
+	}
+
+	fn populate<P>(
+		&self,
+		events: &[CpuEvent],
+		witness: &mut TableWitnessSegment<P>,
+	) -> anyhow::Result<()>
+	where
+		P: PackedFieldIndexable<Scalar = B128> + PackedExtension<B1> + PackedExtension<B32>,
+		PackedSubfield<P, B32>: PackedFieldIndexable,
+	{
+		self.rs1
+			.populate(witness, events.iter().map(|event| event.rs1.as_ref()))?;
+		self.rs2
+			.populate(witness, events.iter().map(|event| event.rs2.as_ref()))?;
+		fill_col(
+			witness,
+			self.fallthrough,
+			events.iter().map(|event| Ok(event.pc().wrapping_add(4))),
+		)?;
+		fill_col(
+			witness,
+			self.target,
+			events
+				.iter()
+				.map(|event| Ok(event.pc().wrapping_add(event.rom.imm))),
+		)?;
+
+		let operands = events
+			.iter()
+			.map(|event| match (event.rs1, event.rs2) {
+				(Some(rs1), Some(rs2)) => Ok((rs1.value, rs2.value)),
+				_ => Err(anyhow!("missing source register reads")),
+			})
+			.collect::<anyhow::Result<Vec<_>>>()?;
+
+		let condition_bits = match self.condition {
+			BranchCondition::Equal {
+				diff,
+				diff_inv,
+				equal,
+			} => {
+				let mut diff = witness.get_scalars_mut(diff)?;
+				let mut diff_inv = witness.get_scalars_mut(diff_inv)?;
+				let mut equal = witness.get_mut(equal)?;
+				for (i, &(a, b)) in operands.iter().enumerate() {
+					diff[i] = B32::new(a ^ b);
+					diff_inv[i] = diff[i].invert_or_zero();
+					set_packed_slice(&mut equal, i, B1::from(a == b));
+				}
+				operands.iter().map(|&(a, b)| a == b).collect::<Vec<_>>()
+			}
+			BranchCondition::LessThan { less_than } => {
+				let signed = matches!(self.opcode, Opcode::Blt | Opcode::Bge);
+				let bits = operands
+					.iter()
+					.map(|&(a, b)| {
+						if signed {
+							(a as i32) < (b as i32)
+						} else {
+							a < b
+						}
+					})
+					.collect::<Vec<_>>();
+				let mut less_than = witness.get_mut(less_than)?;
+				for (i, &bit) in bits.iter().enumerate() {
+					set_packed_slice(&mut less_than, i, B1::from(bit));
+				}
+				bits
+			}
+		};
+		if let Some(not_condition) = self.not_condition {
+			let mut not_condition = witness.get_mut(not_condition)?;
+			for (i, &bit) in condition_bits.iter().enumerate() {
+				set_packed_slice(&mut not_condition, i, B1::from(!bit));
+			}
+		}
+		Ok(())
+	}
+}
+
+impl_cpu_table_filler!(BranchTable);
+
+/// The table of loads or stores with one opcode.
+///
+/// Word accesses (`LW`, `SW`) access the RAM word at `addr = rs1 + imm`, which is checked to be
+/// word aligned by requesting `addr & 3 = 0` from the ALU.
+///
+/// Sub-word accesses access the RAM word at `addr = eff & !3`, where `eff = rs1 + imm`, and the
+/// byte lanes starting at `offset = eff ^ addr` within it. Halfword accesses check that the
+/// offset is even by requesting `eff & 1 = 0`. The lanes are moved with shifts by
+/// `8 * offset` requested from the ALU, see [`SubWordLanes`].
+#[derive(Debug)]
+pub struct MemoryInstrTable {
+	table_id: TableId,
+	opcode: Opcode,
+	cpu: Cpu,
+	rs1: Access,
+	/// The stored register, for stores.
+	rs2: Option<Access>,
+	addr: Col<B32>,
+	mem: Access,
+	/// The destination register, for loads.
+	rd: Option<Access>,
+	lanes: Option<SubWordLanes>,
+}
+
+/// The columns moving the byte lanes of a sub-word load or store.
+///
+/// A load shifts the word right by `shift = 8 * offset` and keeps the low lanes, with `AND` for
+/// unsigned loads and with a left shift followed by an arithmetic right shift for signed loads.
+/// A store replaces the lanes `lane_mask = mask << shift` of the previous word with
+/// `(rs2 & mask) << shift`, where `mask` covers the low lanes.
+#[derive(Debug)]
+struct SubWordLanes {
+	eff: Col<B32>,
+	offset: Col<B32>,
+	shift: Col<B32>,
+	/// The accessed word shifted right, for loads.
+	shifted: Option<Col<B32>>,
+	/// The shifted word with the accessed lanes moved to the top, for signed loads.
+	high: Option<Col<B32>>,
+	/// The loaded value, for loads.
+	value: Option<Col<B32>>,
+	/// `lane_mask`, its complement, `rs2 & mask`, that value shifted, the lanes kept from the
+	/// previous word, and the new word, for stores.
+	store: Option<[Col<B32>; 6]>,
+}
+
+impl MemoryInstrTable {
+	pub fn new(table: &mut TableBuilder, channels: &Rv32imChannels, opcode: Opcode) -> Self {
+		let width = opcode.access_width().expect("opcode is a load or store");
+		let is_load = opcode.class() == InstructionClass::Load;
+		let mut cpu = Cpu::new(table, channels, Some(opcode));
+		let rs1 = cpu.access(table, channels.registers, cpu.rs1, None, RS1_TIMESTAMP_OFFSET);
+		let addr = table.add_committed("addr");
+		let zero = cpu.constant(table, "zero", 0);
+		let rs2 = (!is_load)
+			.then(|| cpu.access(table, channels.registers, cpu.rs2, None, RS2_TIMESTAMP_OFFSET));
+
+		if width == 4 {
+			cpu.alu(table, channels, Opcode::Add, [rs1.value, cpu.imm, addr]);
+			let low_bits_mask = cpu.constant(table, "low_bits_mask", 3);
+			cpu.alu(table, channels, Opcode::And, [addr, low_bits_mask, zero]);
+
+			let (mem, rd) = match &rs2 {
+				None => {
+					let mem = cpu.access(table, channels.memory, addr, None, MEM_TIMESTAMP_OFFSET);
+					let rd = cpu.access(
+						table,
+						channels.registers,
+						cpu.rd,
+						Some(mem.value),
+						RD_TIMESTAMP_OFFSET,
+					);
+					(mem, Some(rd))
+				}
+				Some(rs2) => {
+					let mem = cpu.access(
+						table,
+						channels.memory,
+						addr,
+						Some(rs2.value),
+						MEM_TIMESTAMP_OFFSET,
+					);
+					(mem, None)
+				}
+			};
+			cpu.sequential(table, channels);
+			cpu.transition(table, channels);
+
+			return Self {
+				table_id: table.id(),
+				opcode,
+				cpu,
+				rs1,
+				rs2,
+				addr,
+				mem,
+				rd,
+				lanes: None,
+			};
+		}
+
+		let eff = table.add_committed("eff");
+		cpu.alu(table, channels, Opcode::Add, [rs1.value, cpu.imm, eff]);
+		let word_mask = cpu.constant(table, "word_mask", !3);
+		cpu.alu(table, channels, Opcode::And, [eff, word_mask, addr]);
+		let offset = table.add_computed("offset", eff + addr);
+		if width == 2 {
+			let one = cpu.constant(table, "one", 1);
+			cpu.alu(table, channels, Opcode::And, [eff, one, zero]);
+		}
+		let shift = table.add_committed("shift");
+		let three = cpu.constant(table, "three", 3);
+		cpu.alu(table, channels, Opcode::Sll, [offset, three, shift]);
+
+		let lane_bits = 8 * width;
+		let mask = cpu.constant(table, "lane_bits_mask", u32::MAX >> (32 - lane_bits));
+		let (mem, rd, lanes) = match &rs2 {
+			None => {
+				let mem = cpu.access(table, channels.memory, addr, None, MEM_TIMESTAMP_OFFSET);
+				let shifted = table.add_committed("shifted");
+				cpu.alu(table, channels, Opcode::Srl, [mem.value, shift, shifted]);
+				let value = table.add_committed("value");
+				let high = match opcode {
+					Opcode::Lb | Opcode::Lh => {
+						let high = table.add_committed("high");
+						let extension = cpu.constant(table, "extension_bits", 32 - lane_bits);
+						cpu.alu(table, channels, Opcode::Sll, [shifted, extension, high]);
+						cpu.alu(table, channels, Opcode::Sra, [high, extension, value]);
+						Some(high)
+					}
+					_ => {
+						cpu.alu(table, channels, Opcode::And, [shifted, mask, value]);
+						None
+					}
+				};
+				let rd =
+					cpu.access(table, channels.registers, cpu.rd, Some(value), RD_TIMESTAMP_OFFSET);
+				let lanes = SubWordLanes {
+					eff,
+					offset,
+					shift,
+					shifted: Some(shifted),
+					high,
+					value: Some(value),
+					store: None,
+				};
+				(mem, Some(rd), lanes)
+			}
+			Some(rs2) => {
+				let word = table.add_committed("word");
+				let mem =
+					cpu.access(table, channels.memory, addr, Some(word), MEM_TIMESTAMP_OFFSET);
+				let lane_mask = table.add_committed("lane_mask");
+				cpu.alu(table, channels, Opcode::Sll, [mask, shift, lane_mask]);
+				let kept_mask = table.add_computed("kept_mask", lane_mask + B32::new(u32::MAX));
+				let lanes = table.add_committed("lanes");
+				cpu.alu(table, channels, Opcode::And, [rs2.value, mask, lanes]);
+				let lanes_shifted = table.add_committed("lanes_shifted");
+				cpu.alu(table, channels, Opcode::Sll, [lanes, shift, lanes_shifted]);
+				let kept = table.add_committed("kept");
+				cpu.alu(table, channels, Opcode::And, [mem.prev_value, kept_mask, kept]);
+				// The kept and stored lanes are disjoint, so their sum in the binary field is
+				// their bitwise OR.
+				table.assert_zero("word", word - (kept + lanes_shifted));
+				let lanes = SubWordLanes {
+					eff,
+					offset,
+					shift,
+					shifted: None,
+					high: None,
+					value: None,
+					store: Some([lane_mask, kept_mask, lanes, lanes_shifted, kept, word]),
+				};
+				(mem, None, lanes)
+			}
+		};
+		cpu.sequential(table, channels);
+		cpu.transition(table, channels);
+
+		Self {
+			table_id: table.id(),
+			opcode,
+			cpu,
+			rs1,
+			rs2,
+			addr,
+			mem,
+			rd,
+			lanes: Some(lanes),
+		}
+	}
+
+	fn populate<P>(
+		&self,
+		events: &[CpuEvent],
+		witness: &mut TableWitnessSegment<P>,
+	) -> anyhow::Result<()>
+	where
+		P: PackedFieldIndexable<Scalar = B128> + PackedExtension<B1> + PackedExtension<B32>,
+	{
+		self.rs1
+			.populate(witness, events.iter().map(|event| event.rs1.as_ref()))?;
+		if let Some(rs2) = &self.rs2 {
+			rs2.populate(witness, events.iter().map(|event| event.rs2.as_ref()))?;
+		}
+		let mem_accesses = events
+			.iter()
+			.enumerate()
+			.map(|(i, event)| {
+				event
+					.mem
+					.ok_or_else(|| anyhow!("missing memory access in row {i}"))
+			})
+			.collect::<anyhow::Result<Vec<_>>>()?;
+		fill_col(witness, self.addr, mem_accesses.iter().map(|access| Ok(access.addr)))?;
+		self.mem
+			.populate(witness, events.iter().map(|event| event.mem.as_ref()))?;
+		if let Some(rd) = &self.rd {
+			rd.populate(witness, events.iter().map(|event| event.rd.as_ref()))?;
+		}
+
+		if let Some(lanes) = &self.lanes {
+			let width = self
+				.opcode
+				.access_width()
+				.expect("opcode is a load or store");
+			let lane_bits = 8 * width;
+			let mask = u32::MAX >> (32 - lane_bits);
+			let effs = events
+				.iter()
+				.map(|event| {
+					let rs1 = event.rs1.map_or(0, |access| access.value);
+					rs1.wrapping_add(event.rom.imm)
+				})
+				.collect::<Vec<_>>();
+			let shifts = effs.iter().map(|&eff| 8 * (eff & 3)).collect::<Vec<_>>();
+			fill_col(witness, lanes.eff, effs.iter().map(|&eff| Ok(eff)))?;
+			fill_col(witness, lanes.offset, effs.iter().map(|&eff| Ok(eff & 3)))?;
+			fill_col(witness, lanes.shift, shifts.iter().map(|&shift| Ok(shift)))?;
+
+			let shifted = || {
+				mem_accesses
+					.iter()
+					.zip(&shifts)
+					.map(|(access, &shift)| access.value >> shift)
+			};
+			if let Some(col) = lanes.shifted {
+				fill_col(witness, col, shifted().map(Ok))?;
+			}
+			if let Some(col) = lanes.high {
+				fill_col(witness, col, shifted().map(|value| Ok(value << (32 - lane_bits))))?;
+			}
+			if let Some(col) = lanes.value {
+				fill_col(witness, col, rd_values(events))?;
+			}
+			if let Some([lane_mask, kept_mask, lanes_col, lanes_shifted, kept, word]) = lanes.store
+			{
+				let rs2_values = events
+					.iter()
+					.map(|event| event.rs2.map_or(0, |access| access.value))
+					.collect::<Vec<_>>();
+				let lane_masks = || shifts.iter().map(|&shift| mask << shift);
+				fill_col(witness, lane_mask, lane_masks().map(Ok))?;
+				fill_col(witness, kept_mask, lane_masks().map(|lane_mask| Ok(!lane_mask)))?;
+				fill_col(witness, lanes_col, rs2_values.iter().map(|&rs2| Ok(rs2 & mask)))?;
+				fill_col(
+					witness,
+					lanes_shifted,
+					rs2_values
+						.iter()
+						.zip(&shifts)
+						.map(|(&rs2, &shift)| Ok((rs2 & mask) << shift)),
+				)?;
+				fill_col(
+					witness,
+					kept,
+					mem_accesses
+						.iter()
+						.zip(lane_masks())
+						.map(|(access, lane_mask)| Ok(access.prev_value & !lane_mask)),
+				)?;
+				fill_col(witness, word, mem_accesses.iter().map(|access| Ok(access.value)))?;
+			}
+		}
+		Ok(())
+	}
+}
+
+impl_cpu_table_filler!(MemoryInstrTable);
+
+/// The table of `ECALL` instructions, which halt the machine.
+///
+/// The row reads the exit code register and pushes its value to the halt channel instead of
+/// pushing a next state.
+#[derive(Debug)]
+pub struct HaltTable {
+	table_id: TableId,
+	cpu: Cpu,
+	exit_code: Access,
+}
+
+impl HaltTable {
+	pub fn new(table: &mut TableBuilder, channels: &Rv32imChannels) -> Self {
+		let cpu = Cpu::new(table, channels, Some(Opcode::Ecall));
+		let exit_code = cpu.access(table, channels.registers, cpu.rs1, None, RS1_TIMESTAMP_OFFSET);
+		table.push(channels.halt, [exit_code.value]);
+
+		Self {
+			table_id: table.id(),
+			cpu,
+			exit_code,
+		}
+	}
+
+	fn populate<P>(
+		&self,
+		events: &[CpuEvent],
+		witness: &mut TableWitnessSegment<P>,
+	) -> anyhow::Result<()>
+	where
+		P: PackedFieldIndexable<Scalar = B128> + PackedExtension<B1> + PackedExtension<B32>,
+	{
+		self.exit_code
+			.populate(witness, events.iter().map(|event| event.rs1.as_ref()))
+	}
+}
+
+impl_cpu_table_filler!(HaltTable);
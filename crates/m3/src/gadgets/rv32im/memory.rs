@@ -0,0 +1,237 @@
+// Copyright 2025 Irreducible Inc.
+
+use anyhow::anyhow;
+use binius_core::constraint_system::channel::ChannelId;
+use binius_field::{PackedExtension, PackedFieldIndexable, PackedSubfield};
+
+use super::Rv32imChannels;
+use crate::{
+	builder::{B1, B32, B128, Col, TableBuilder, TableFiller, TableId, TableWitnessSegment},
+	emulate::rv32im::{self, RomEntry},
+	gadgets::{
+		lookup::LookupProducer,
+		sub::{U32Sub, U32SubFlags},
+	},
+};
+
+/// A timestamped access to a register or RAM cell, checked with offline memory checking.
+///
+/// The gadget pulls `(addr, prev_value, prev_timestamp)` from the memory channel and pushes
+/// `(addr, value, timestamp)`, and checks that `prev_timestamp < timestamp`. Reads push back the
+/// value they pulled.
+#[derive(Debug)]
+pub struct Access {
+	/// The register index or RAM address, provided by the caller.
+	pub addr: Col<B32>,
+	pub prev_value: Col<B32>,
+	/// The value after the access, provided by the caller for writes and equal to `prev_value`
+	/// for reads.
+	pub value: Col<B32>,
+	is_write: bool,
+	prev_timestamp_bits: Col<B1, 32>,
+	/// Checks `timestamp - 1 - prev_timestamp >= 0`.
+	check: U32Sub,
+}
+
+impl Access {
+	/// Creates a new access gadget.
+	///
+	/// `timestamp_minus_one` must hold `timestamp - 1`. The caller is responsible for populating
+	/// the address, the timestamps, and the new value of a write.
+	pub fn new(
+		table: &mut TableBuilder,
+		channel: ChannelId,
+		addr: Col<B32>,
+		value: Option<Col<B32>>,
+		timestamp: Col<B1, 32>,
+		timestamp_minus_one: Col<B1, 32>,
+	) -> Self {
+		let prev_value = table.add_committed("prev_value");
+		let prev_timestamp_bits = table.add_committed("prev_timestamp_bits");
+		let prev_timestamp = table.add_packed("prev_timestamp", prev_timestamp_bits);
+		let timestamp = table.add_packed("timestamp", timestamp);
+
+		let check = U32Sub::new(
+			table,
+			timestamp_minus_one,
+			prev_timestamp_bits,
+			U32SubFlags {
+				expose_final_borrow: true,
+				..U32SubFlags::default()
+			},
+		);
+		let final_borrow = check.final_borrow.expect("expose_final_borrow is set");
+		table.assert_zero("timestamp_increases", final_borrow.into());
+
+		table.pull(channel, [addr, prev_value, prev_timestamp]);
+		let is_write = value.is_some();
+		let value = value.unwrap_or(prev_value);
+		table.push(channel, [addr, value, timestamp]);
+
+		Self {
+			addr,
+			prev_value,
+			value,
+			is_write,
+			prev_timestamp_bits,
+			check,
+		}
+	}
+
+	/// Populates the previous value and timestamp, and the value of a read.
+	pub fn populate<'a, P>(
+		&self,
+		witness: &mut TableWitnessSegment<P>,
+		accesses: impl IntoIterator<Item = Option<&'a rv32im::Access>>,
+	) -> anyhow::Result<()>
+	where
+		P: PackedFieldIndexable<Scalar = B128> + PackedExtension<B1> + PackedExtension<B32>,
+	{
+		{
+			let mut prev_value = witness.get_mut_as::<u32, _, 1>(self.prev_value)?;
+			let mut prev_timestamp = witness.get_mut_as::<u32, _, 32>(self.prev_timestamp_bits)?;
+			for (i, access) in accesses.into_iter().enumerate() {
+				let access = access.ok_or_else(|| anyhow!("missing access in row {i}"))?;
+				anyhow::ensure!(
+					self.is_write || access.prev_value == access.value,
+					"read in row {i} modifies the accessed value"
+				);
+				prev_value[i] = access.prev_value;
+				prev_timestamp[i] = access.prev_timestamp;
+			}
+		}
+		self.check.populate(witness)
+	}
+}
+
+/// A table consuming the final state of every register or RAM cell.
+///
+/// Each row pulls the last `(addr, value, timestamp)` tuple of a cell from the memory channel, and
+/// pulls `addr` from the finalization channel. The verifier pushes every address once to the
+/// finalization channel, so the table has exactly one row per cell.
+#[derive(Debug)]
+pub struct FinalizeTable {
+	table_id: TableId,
+	addr: Col<B32>,
+	value: Col<B32>,
+	timestamp: Col<B32>,
+}
+
+impl FinalizeTable {
+	pub fn new(table: &mut TableBuilder, channel: ChannelId, final_channel: ChannelId) -> Self {
+		let addr = table.add_committed("addr");
+		let value = table.add_committed("value");
+		let timestamp = table.add_committed("timestamp");
+		table.pull(channel, [addr, value, timestamp]);
+		table.pull(final_channel, [addr]);
+
+		Self {
+			table_id: table.id(),
+			addr,
+			value,
+			timestamp,
+		}
+	}
+}
+
+impl<P> TableFiller<P> for FinalizeTable
+where
+	P: PackedFieldIndexable<Scalar = B128> + PackedExtension<B32>,
+{
+	/// Tuple of address, final value and final timestamp.
+	type Event = (u32, u32, u32);
+
+	fn id(&self) -> TableId {
+		self.table_id
+	}
+
+	fn fill(
+		&self,
+		rows: &[Self::Event],
+		witness: &mut TableWitnessSegment<P>,
+	) -> anyhow::Result<()> {
+		let mut addr = witness.get_mut_as::<u32, _, 1>(self.addr)?;
+		let mut value = witness.get_mut_as::<u32, _, 1>(self.value)?;
+		let mut timestamp = witness.get_mut_as::<u32, _, 1>(self.timestamp)?;
+		for (i, &(row_addr, row_value, row_timestamp)) in rows.iter().enumerate() {
+			addr[i] = row_addr;
+			value[i] = row_value;
+			timestamp[i] = row_timestamp;
+		}
+		Ok(())
+	}
+}
+
+/// The program ROM, a lookup table of `(pc, opcode, rd, rs1, rs2, imm)` entries.
+///
+/// The entries are committed and pulled once each from the ROM table channel, where the verifier
+/// pushes the decoded program. The CPU tables read the entries through the ROM lookup channel.
+pub struct RomTable {
+	table_id: TableId,
+	fields: [Col<B32>; 6],
+	lookup_producer: LookupProducer,
+}
+
+impl RomTable {
+	pub fn new(
+		table: &mut TableBuilder,
+		channels: &Rv32imChannels,
+		n_multiplicity_bits: usize,
+	) -> Self {
+		let fields =
+			["pc", "opcode", "rd", "rs1", "rs2", "imm"].map(|name| table.add_committed(name));
+		table.pull(channels.rom_table, fields);
+		let lookup_producer =
+			LookupProducer::new(table, channels.rom, &fields, n_multiplicity_bits);
+
+		Self {
+			table_id: table.id(),
+			fields,
+			lookup_producer,
+		}
+	}
+}
+
+/// The fields of a ROM entry, in channel order.
+pub fn rom_fields(entry: &RomEntry) -> [u32; 6] {
+	[
+		entry.pc,
+		entry.opcode.id(),
+		entry.rd as u32,
+		entry.rs1 as u32,
+		entry.rs2 as u32,
+		entry.imm,
+	]
+}
+
+impl<P> TableFiller<P> for RomTable
+where
+	P: PackedFieldIndexable<Scalar = B128> + PackedExtension<B1> + PackedExtension<B32>,
+	PackedSubfield<P, B32>: PackedFieldIndexable,
+{
+	/// Tuple of entry and lookup count.
+	type Event = (RomEntry, u32);
+
+	fn id(&self) -> TableId {
+		self.table_id
+	}
+
+	fn fill(
+		&self,
+		rows: &[Self::Event],
+		witness: &mut TableWitnessSegment<P>,
+	) -> anyhow::Result<()> {
+		{
+			let mut fields =
+				array_util::try_map(self.fields, |col| witness.get_mut_as::<u32, _, 1>(col))?;
+			for (i, (entry, _)) in rows.iter().enumerate() {
+				for (field, value) in fields.iter_mut().zip(rom_fields(entry)) {
+					field[i] = value;
+				}
+			}
+		}
+		self.lookup_producer
+			.populate(witness, rows.iter().map(|&(_, count)| count))?;
+		Ok(())
+	}
+}
@@ -0,0 +1,697 @@
+// Copyright 2025 Irreducible Inc.
+
+//! An arithmetization of the RV32IM virtual machine.
+//!
+//! The trace of a program is produced by the reference emulator in
+//! [`crate::emulate::rv32im`], and proven with the following tables:
+//!
+//! * One CPU table per instruction class, or per opcode for classes with opcode-specific
+//!   constraints. Every row pulls `(pc, clk)` from the state channel, reads its ROM entry from the
+//!   ROM lookup channel, and pushes `(next_pc, clk + 1)`. The single `ECALL` row ends the chain and
+//!   pushes the exit code to the halt channel instead.
+//! * One ALU table per register-register ALU operation, built from the add, sub, mul, div and
+//!   barrel shifter gadgets. The CPU tables push `(op, a, b, c)` requests to the ALU channel,
+//!   including those for computing `pc + 4`, branch targets, memory addresses and the byte lanes of
+//!   sub-word loads and stores.
+//! * The ROM table, holding the decoded program as a lookup table.
+//! * Two finalization tables, consuming the last value of every register and RAM word.
+//!
+//! Registers and RAM are checked with offline memory checking, see [`Access`]. The verifier fixes
+//! the program, the initial state and the exit code with boundary values, see
+//! [`Rv32im::boundaries`].
+//!
+//! Writes to `x0` are redirected to [`SCRATCH_REGISTER`] when decoding the ROM, so that `x0`
+//! always reads as zero.
+
+mod alu;
+mod cpu;
+mod memory;
+
+use std::collections::HashMap;
+
+pub use alu::AluTable;
+use anyhow::anyhow;
+use binius_core::constraint_system::channel::{Boundary, ChannelId, FlushDirection};
+use binius_field::{
+	ExtensionField, PackedExtension, PackedFieldIndexable, PackedSubfield, TowerField,
+};
+pub use cpu::{
+	AluInstrTable, BranchTable, Cpu, HaltTable, JumpTable, MemoryInstrTable, UpperImmTable,
+};
+pub use memory::{Access, FinalizeTable, RomTable, rom_fields};
+
+use crate::{
+	builder::{B1, B32, B64, B128, Col, ConstraintSystem, TableWitnessSegment, WitnessIndex},
+	emulate::rv32im::{
+		CpuEvent, InstructionClass, N_REGISTERS, Opcode, Program, SCRATCH_REGISTER, Trace, {self},
+	},
+};
+
+/// The register-register ALU operations, each proven by its own [`AluTable`].
+pub const ALU_OPS: [Opcode; 18] = [
+	Opcode::Add,
+	Opcode::Sub,
+	Opcode::Sll,
+	Opcode::Slt,
+	Opcode::Sltu,
+	Opcode::Xor,
+	Opcode::Srl,
+	Opcode::Sra,
+	Opcode::Or,
+	Opcode::And,
+	Opcode::Mul,
+	Opcode::Mulh,
+	Opcode::Mulhsu,
+	Opcode::Mulhu,
+	Opcode::Div,
+	Opcode::Divu,
+	Opcode::Rem,
+	Opcode::Remu,
+];
+
+/// The opcodes with a dedicated [`BranchTable`].
+pub const BRANCH_OPS: [Opcode; 6] = [
+	Opcode::Beq,
+	Opcode::Bne,
+	Opcode::Blt,
+	Opcode::Bge,
+	Opcode::Bltu,
+	Opcode::Bgeu,
+];
+
+/// The load and store opcodes, each proven by its own [`MemoryInstrTable`].
+pub const MEMORY_OPS: [Opcode; 8] = [
+	Opcode::Lb,
+	Opcode::Lh,
+	Opcode::Lw,
+	Opcode::Lbu,
+	Opcode::Lhu,
+	Opcode::Sb,
+	Opcode::Sh,
+	Opcode::Sw,
+];
+
+/// The channels of the RV32IM arithmetization.
+#[derive(Debug, Clone, Copy)]
+pub struct Rv32imChannels {
+	/// Carries `(pc, clk)` between consecutive instructions.
+	pub state: ChannelId,
+	/// The lookup channel for `(pc, opcode, rd, rs1, rs2, imm)` ROM entries.
+	pub rom: ChannelId,
+	/// Binds the committed ROM table to the verifier's boundary values.
+	pub rom_table: ChannelId,
+	/// Carries `(op, a, b, c)` requests from the CPU tables to the ALU tables.
+	pub alu: ChannelId,
+	/// Carries `(register, value, timestamp)` tuples.
+	pub registers: ChannelId,
+	/// Carries `(address, value, timestamp)` tuples of RAM words.
+	pub memory: ChannelId,
+	/// Carries the index of every register to its finalization row.
+	pub register_final: ChannelId,
+	/// Carries the address of every RAM word to its finalization row.
+	pub memory_final: ChannelId,
+	/// Carries the exit code of the halted machine.
+	pub halt: ChannelId,
+}
+
+impl Rv32imChannels {
+	pub fn new(cs: &mut ConstraintSystem) -> Self {
+		Self {
+			state: cs.add_channel("rv32im_state"),
+			rom: cs.add_channel("rv32im_rom"),
+			rom_table: cs.add_channel("rv32im_rom_table"),
+			alu: cs.add_channel("rv32im_alu"),
+			registers: cs.add_channel("rv32im_registers"),
+			memory: cs.add_channel("rv32im_memory"),
+			register_final: cs.add_channel("rv32im_register_final"),
+			memory_final: cs.add_channel("rv32im_memory_final"),
+			halt: cs.add_channel("rv32im_halt"),
+		}
+	}
+}
+
+/// Fills a 32-bit constant column with `value`.
+fn fill_constant<P, FSub, const V: usize>(
+	witness: &mut TableWitnessSegment<P>,
+	col: Col<FSub, V>,
+	value: u32,
+) -> anyhow::Result<()>
+where
+	P: PackedFieldIndexable<Scalar = B128> + PackedExtension<FSub>,
+	FSub: TowerField,
+	B128: ExtensionField<FSub>,
+{
+	witness.get_mut_as::<u32, _, V>(col)?.fill(value);
+	Ok(())
+}
+
+/// The ALU requests made by the CPU table row of `event`, as `(op, a, b)` tuples.
+fn alu_requests(event: &CpuEvent) -> anyhow::Result<Vec<(Opcode, u32, u32)>> {
+	let pc = event.pc();
+	let imm = event.rom.imm;
+	let value = |access: Option<rv32im::Access>| {
+		access
+			.map(|access| access.value)
+			.ok_or_else(|| anyhow!("missing register access at pc {pc:#010x}"))
+	};
+	let sequential = (Opcode::Add, pc, 4);
+
+	let requests = match event.rom.opcode.class() {
+		InstructionClass::AluReg => {
+			vec![
+				(event.rom.opcode, value(event.rs1)?, value(event.rs2)?),
+				sequential,
+			]
+		}
+		InstructionClass::AluImm => {
+			let op = event.rom.opcode.alu_op().expect("opcode is an ALU opcode");
+			vec![(op, value(event.rs1)?, imm), sequential]
+		}
+		InstructionClass::Lui => vec![sequential],
+		InstructionClass::Auipc => vec![(Opcode::Add, pc, imm), sequential],
+		InstructionClass::Jal => vec![(Opcode::Add, pc, imm), sequential],
+		InstructionClass::Jalr => {
+			let target = value(event.rs1)?.wrapping_add(imm);
+			vec![
+				(Opcode::Add, value(event.rs1)?, imm),
+				(Opcode::And, target, !1),
+				sequential,
+			]
+		}
+		InstructionClass::Branch => {
+			let mut requests = vec![sequential, (Opcode::Add, pc, imm)];
+			match event.rom.opcode {
+				Opcode::Blt | Opcode::Bge => {
+					requests.push((Opcode::Slt, value(event.rs1)?, value(event.rs2)?))
+				}
+				Opcode::Bltu | Opcode::Bgeu => {
+					requests.push((Opcode::Sltu, value(event.rs1)?, value(event.rs2)?))
+				}
+				_ => {}
+			}
+			requests
+		}
+		InstructionClass::Load | InstructionClass::Store => {
+			let rs1 = value(event.rs1)?;
+			let eff = rs1.wrapping_add(imm);
+			let width = event
+				.rom
+				.opcode
+				.access_width()
+				.expect("opcode is a load or store");
+			if width == 4 {
+				return Ok(vec![(Opcode::Add, rs1, imm), (Opcode::And, eff, 3), sequential]);
+			}
+
+			let mem = event
+				.mem
+				.ok_or_else(|| anyhow!("missing memory access at pc {pc:#010x}"))?;
+			let shift = 8 * (eff & 3);
+			let lane_bits = 8 * width;
+			let mask = u32::MAX >> (32 - lane_bits);
+			let mut requests = vec![
+				(Opcode::Add, rs1, imm),
+				(Opcode::And, eff, !3),
+				(Opcode::Sll, eff & 3, 3),
+			];
+			if width == 2 {
+				requests.push((Opcode::And, eff, 1));
+			}
+			match event.rom.opcode {
+				Opcode::Lb | Opcode::Lh => {
+					let shifted = mem.value >> shift;
+					requests.extend([
+						(Opcode::Srl, mem.value, shift),
+						(Opcode::Sll, shifted, 32 - lane_bits),
+						(Opcode::Sra, shifted << (32 - lane_bits), 32 - lane_bits),
+					]);
+				}
+				Opcode::Lbu | Opcode::Lhu => {
+					requests.extend([
+						(Opcode::Srl, mem.value, shift),
+						(Opcode::And, mem.value >> shift, mask),
+					]);
+				}
+				_ => {
+					let rs2 = value(event.rs2)?;
+					requests.extend([
+						(Opcode::Sll, mask, shift),
+						(Opcode::And, rs2, mask),
+						(Opcode::Sll, rs2 & mask, shift),
+						(Opcode::And, mem.prev_value, !(mask << shift)),
+					]);
+				}
+			}
+			requests.push(sequential);
+			requests
+		}
+		InstructionClass::Halt => vec![],
+	};
+	Ok(requests)
+}
+
+/// The tables of the RV32IM arithmetization.
+pub struct Rv32im {
+	pub channels: Rv32imChannels,
+	rom: RomTable,
+	alu_reg: AluInstrTable,
+	alu_imm: AluInstrTable,
+	lui: UpperImmTable,
+	auipc: UpperImmTable,
+	jal: JumpTable,
+	jalr: JumpTable,
+	branches: Vec<(Opcode, BranchTable)>,
+	memory: Vec<(Opcode, MemoryInstrTable)>,
+	halt: HaltTable,
+	alu: Vec<AluTable>,
+	register_final: FinalizeTable,
+	memory_final: FinalizeTable,
+}
+
+impl Rv32im {
+	/// Adds the RV32IM tables and channels to the constraint system.
+	///
+	/// `rom_multiplicity_bits` bounds the number of times a single instruction can be executed to
+	/// `2^rom_multiplicity_bits - 1`.
+	pub fn new(cs: &mut ConstraintSystem, rom_multiplicity_bits: usize) -> Self {
+		let channels = Rv32imChannels::new(cs);
+
+		let rom = RomTable::new(&mut cs.add_table("rom"), &channels, rom_multiplicity_bits);
+		let alu_reg = AluInstrTable::new(&mut cs.add_table("alu_reg"), &channels, false);
+		let alu_imm = AluInstrTable::new(&mut cs.add_table("alu_imm"), &channels, true);
+		let lui = UpperImmTable::new(&mut cs.add_table("lui"), &channels, Opcode::Lui);
+		let auipc = UpperImmTable::new(&mut cs.add_table("auipc"), &channels, Opcode::Auipc);
+		let jal = JumpTable::new(&mut cs.add_table("jal"), &channels, Opcode::Jal);
+		let jalr = JumpTable::new(&mut cs.add_table("jalr"), &channels, Opcode::Jalr);
+		let branches = BRANCH_OPS
+			.iter()
+			.map(|&op| {
+				let mut table = cs.add_table(format!("{op:?}").to_lowercase());
+				(op, BranchTable::new(&mut table, &channels, op))
+			})
+			.collect();
+		let memory = MEMORY_OPS
+			.iter()
+			.map(|&op| {
+				let mut table = cs.add_table(format!("{op:?}").to_lowercase());
+				(op, MemoryInstrTable::new(&mut table, &channels, op))
+			})
+			.collect();
+		let halt = HaltTable::new(&mut cs.add_table("halt"), &channels);
+		let alu = ALU_OPS
+			.iter()
+			.map(|&op| {
+				let mut table = cs.add_table(format!("alu_{op:?}").to_lowercase());
+				AluTable::new(&mut table, &channels, op)
+			})
+			.collect();
+		let register_final = FinalizeTable::new(
+			&mut cs.add_table("register_final"),
+			channels.registers,
+			channels.register_final,
+		);
+		let memory_final = FinalizeTable::new(
+			&mut cs.add_table("memory_final"),
+			channels.memory,
+			channels.memory_final,
+		);
+
+		Self {
+			channels,
+			rom,
+			alu_reg,
+			alu_imm,
+			lui,
+			auipc,
+			jal,
+			jalr,
+			branches,
+			memory,
+			halt,
+			alu,
+			register_final,
+			memory_final,
+		}
+	}
+
+	/// The boundary values for a run of `program` that halts with `exit_code`.
+	pub fn boundaries(
+		&self,
+		program: &Program,
+		exit_code: u32,
+	) -> Result<Vec<Boundary<B128>>, rv32im::Error> {
+		let channels = &self.channels;
+		let boundary = |values: &[u32], channel_id, direction| Boundary {
+			values: values.iter().map(|&value| B32::new(value).into()).collect(),
+			channel_id,
+			direction,
+			multiplicity: 1,
+		};
+
+		let mut boundaries = vec![
+			boundary(&[program.entry_point(), 0], channels.state, FlushDirection::Push),
+			boundary(&[exit_code], channels.halt, FlushDirection::Pull),
+		];
+		for entry in program.rom()? {
+			boundaries.push(boundary(
+				&rom_fields(&entry),
+				channels.rom_table,
+				FlushDirection::Push,
+			));
+		}
+		for reg in 0..N_REGISTERS as u32 {
+			boundaries.push(boundary(&[reg, 0, 0], channels.registers, FlushDirection::Push));
+			boundaries.push(boundary(&[reg], channels.register_final, FlushDirection::Push));
+		}
+		for (index, &value) in program.ram.iter().enumerate() {
+			let addr = program.ram_address(index);
+			boundaries.push(boundary(&[addr, value, 0], channels.memory, FlushDirection::Push));
+			boundaries.push(boundary(&[addr], channels.memory_final, FlushDirection::Push));
+		}
+		Ok(boundaries)
+	}
+
+	/// Fills the witness of all tables with the trace of `program`.
+	pub fn fill<P>(
+		&self,
+		witness: &mut WitnessIndex<P>,
+		program: &Program,
+		trace: &Trace,
+	) -> anyhow::Result<()>
+	where
+		P: PackedFieldIndexable<Scalar = B128>
+			+ PackedExtension<B1>
+			+ PackedExtension<B32>
+			+ PackedExtension<B64>,
+		PackedSubfield<P, B32>: PackedFieldIndexable,
+	{
+		let mut alu_reg = Vec::new();
+		let mut alu_imm = Vec::new();
+		let mut lui = Vec::new();
+		let mut auipc = Vec::new();
+		let mut jal = Vec::new();
+		let mut jalr = Vec::new();
+		let mut branches = HashMap::<_, Vec<_>>::new();
+		let mut memory = HashMap::<_, Vec<_>>::new();
+		let mut halt = Vec::new();
+		let mut alu = HashMap::<_, Vec<_>>::new();
+		let mut rom_counts = HashMap::<_, u32>::new();
+
+		for event in &trace.events {
+			let events = match event.rom.opcode.class() {
+				InstructionClass::AluReg => &mut alu_reg,
+				InstructionClass::AluImm => &mut alu_imm,
+				InstructionClass::Lui => &mut lui,
+				InstructionClass::Auipc => &mut auipc,
+				InstructionClass::Jal => &mut jal,
+				InstructionClass::Jalr => &mut jalr,
+				InstructionClass::Branch => branches.entry(event.rom.opcode).or_default(),
+				InstructionClass::Load | InstructionClass::Store => {
+					memory.entry(event.rom.opcode).or_default()
+				}
+				InstructionClass::Halt => &mut halt,
+			};
+			events.push(*event);
+
+			for (op, a, b) in alu_requests(event)? {
+				alu.entry(op).or_default().push((a, b));
+			}
+			*rom_counts.entry(event.pc()).or_default() += 1;
+		}
+
+		let mut rom = program
+			.rom()?
+			.into_iter()
+			.map(|entry| (entry, rom_counts.get(&entry.pc).copied().unwrap_or(0)))
+			.collect::<Vec<_>>();
+		rom.sort_by_key(|&(entry, count)| (std::cmp::Reverse(count), entry.pc));
+
+		witness.fill_table_sequential(&self.rom, &rom)?;
+		witness.fill_table_sequential(&self.alu_reg, &alu_reg)?;
+		witness.fill_table_sequential(&self.alu_imm, &alu_imm)?;
+		witness.fill_table_sequential(&self.lui, &lui)?;
+		witness.fill_table_sequential(&self.auipc, &auipc)?;
+		witness.fill_table_sequential(&self.jal, &jal)?;
+		witness.fill_table_sequential(&self.jalr, &jalr)?;
+		for (op, table) in &self.branches {
+			if let Some(events) = branches.get(op) {
+				witness.fill_table_sequential(table, events)?;
+			}
+		}
+		for (op, table) in &self.memory {
+			if let Some(events) = memory.get(op) {
+				witness.fill_table_sequential(table, events)?;
+			}
+		}
+		witness.fill_table_sequential(&self.halt, &halt)?;
+		for table in &self.alu {
+			if let Some(rows) = alu.get(&table.op()) {
+				witness.fill_table_sequential(table, rows)?;
+			}
+		}
+
+		let registers = trace
+			.registers
+			.iter()
+			.enumerate()
+			.map(|(reg, &(value, timestamp))| (reg as u32, value, timestamp))
+			.collect::<Vec<_>>();
+		witness.fill_table_sequential(&self.register_final, &registers)?;
+		let ram = trace
+			.ram
+			.iter()
+			.enumerate()
+			.map(|(index, &(value, timestamp))| (program.ram_address(index), value, timestamp))
+			.collect::<Vec<_>>();
+		witness.fill_table_sequential(&self.memory_final, &ram)?;
+
+		debug_assert_eq!(registers.len(), SCRATCH_REGISTER as usize + 1);
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_compute::cpu::alloc::CpuComputeAllocator;
+	use binius_field::{arch::OptimalUnderlier128b, as_packed_field::PackedType};
+
+	use super::*;
+	use crate::{
+		builder::test_utils::validate_system_witness,
+		emulate::rv32im::{Instruction, execute},
+	};
+
+	fn i(opcode: Opcode, rd: u8, rs1: u8, rs2: u8, imm: i32) -> Instruction {
+		Instruction {
+			opcode,
+			rd,
+			rs1,
+			rs2,
+			imm: imm as u32,
+		}
+	}
+
+	fn prove_program(program: &Program) -> anyhow::Result<()> {
+		let trace = execute(program, 10_000)?;
+
+		let mut cs = ConstraintSystem::new();
+		let rv32im = Rv32im::new(&mut cs, 16);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 22);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		rv32im.fill(&mut witness, program, &trace)?;
+
+		let boundaries = rv32im.boundaries(program, trace.exit_code)?;
+		validate_system_witness::<OptimalUnderlier128b>(&cs, witness, boundaries);
+		Ok(())
+	}
+
+	#[test]
+	fn test_all_alu_ops() {
+		let mut code = vec![
+			i(Opcode::Lui, 5, 0, 0, 0x8765_4000u32 as i32),
+			i(Opcode::Addi, 5, 5, 0, 0x321),
+			i(Opcode::Addi, 6, 0, 0, -7),
+		];
+		for op in ALU_OPS {
+			code.push(i(op, 7, 5, 6, 0));
+			code.push(i(op, 8, 6, 5, 0));
+			code.push(i(Opcode::Xor, 10, 10, 7, 0));
+			code.push(i(Opcode::Add, 10, 10, 8, 0));
+		}
+		for op in [
+			Opcode::Addi,
+			Opcode::Slti,
+			Opcode::Sltiu,
+			Opcode::Xori,
+			Opcode::Ori,
+			Opcode::Andi,
+		] {
+			code.push(i(op, 7, 5, 0, -100));
+			code.push(i(Opcode::Add, 10, 10, 7, 0));
+		}
+		for op in [Opcode::Slli, Opcode::Srli, Opcode::Srai] {
+			code.push(i(op, 7, 5, 0, 13));
+			code.push(i(Opcode::Add, 10, 10, 7, 0));
+		}
+		code.push(i(Opcode::Auipc, 7, 0, 0, 0x1000));
+		code.push(i(Opcode::Add, 0, 10, 7, 0));
+		code.push(i(Opcode::Ecall, 0, 10, 0, 0));
+
+		prove_program(&Program::from_instructions(0x100, code)).unwrap();
+	}
+
+	#[test]
+	fn test_control_flow_and_memory() {
+		// Stores the first 10 Fibonacci numbers to RAM, with a function call per number.
+		let code = [
+			// 0x00: setup
+			i(Opcode::Lui, 2, 0, 0, 0x2000),
+			i(Opcode::Addi, 5, 0, 0, 0),
+			i(Opcode::Addi, 6, 0, 0, 1),
+			i(Opcode::Addi, 9, 0, 0, 10),
+			// 0x10: loop
+			i(Opcode::Jal, 1, 0, 0, 0x30),
+			i(Opcode::Sw, 0, 2, 5, 0),
+			i(Opcode::Addi, 2, 2, 0, 4),
+			i(Opcode::Addi, 9, 9, 0, -1),
+			i(Opcode::Blt, 0, 0, 9, -16),
+			// 0x24: load the last stored number
+			i(Opcode::Lui, 2, 0, 0, 0x2000),
+			i(Opcode::Lw, 10, 2, 0, 36),
+			i(Opcode::Ecall, 0, 10, 0, 0),
+			// 0x30: unused
+			i(Opcode::Ecall, 0, 10, 0, 0),
+			i(Opcode::Ecall, 0, 10, 0, 0),
+			i(Opcode::Ecall, 0, 10, 0, 0),
+			i(Opcode::Ecall, 0, 10, 0, 0),
+			// 0x40: step (a, b) -> (b, a + b), exercise both outcomes of the branches, and return
+			i(Opcode::Add, 7, 5, 6, 0),
+			i(Opcode::Add, 5, 6, 0, 0),
+			i(Opcode::Add, 6, 7, 0, 0),
+			i(Opcode::Bne, 0, 5, 6, 8),
+			i(Opcode::Addi, 0, 0, 0, 0),
+			i(Opcode::Bgeu, 0, 6, 5, 8),
+			i(Opcode::Addi, 0, 0, 0, 0),
+			i(Opcode::Bge, 0, 6, 5, 8),
+			i(Opcode::Addi, 0, 0, 0, 0),
+			i(Opcode::Bltu, 0, 6, 5, 8),
+			i(Opcode::Addi, 0, 0, 0, 0),
+			i(Opcode::Beq, 0, 5, 6, 8),
+			i(Opcode::Addi, 0, 0, 0, 0),
+			i(Opcode::Jalr, 0, 1, 0, 0),
+		];
+		let program = Program::from_instructions(0, code).with_ram(0x2000, vec![0; 10]);
+		let trace = execute(&program, 10_000).unwrap();
+		assert_eq!(trace.exit_code, 55);
+		prove_program(&program).unwrap();
+	}
+
+	#[test]
+	fn test_subword_memory() {
+		let code = [
+			i(Opcode::Lui, 2, 0, 0, 0x2000),
+			// Sign- and zero-extending loads from every lane.
+			i(Opcode::Lb, 5, 2, 0, 0),
+			i(Opcode::Lb, 6, 2, 0, 3),
+			i(Opcode::Lbu, 7, 2, 0, 1),
+			i(Opcode::Lh, 8, 2, 0, 2),
+			i(Opcode::Lhu, 9, 2, 0, 0),
+			i(Opcode::Lh, 11, 2, 0, 0),
+			// Stores keep the other lanes and ignore the high bits of the stored register.
+			i(Opcode::Sb, 0, 2, 5, 4),
+			i(Opcode::Sb, 0, 2, 7, 6),
+			i(Opcode::Sb, 0, 2, 6, 5),
+			i(Opcode::Sh, 0, 2, 8, 10),
+			i(Opcode::Sh, 0, 2, 9, 8),
+			i(Opcode::Add, 10, 5, 6, 0),
+			i(Opcode::Add, 10, 10, 7, 0),
+			i(Opcode::Add, 10, 10, 8, 0),
+			i(Opcode::Add, 10, 10, 9, 0),
+			i(Opcode::Add, 10, 10, 11, 0),
+			i(Opcode::Ecall, 0, 10, 0, 0),
+		];
+		let program = Program::from_instructions(0, code)
+			.with_ram(0x2000, vec![0x8765_43a1, 0x1111_1111, 0x2222_2222]);
+		let trace = execute(&program, 100).unwrap();
+		let loads = [
+			0xffff_ffa1u32,
+			0xffff_ff87,
+			0x43,
+			0xffff_8765,
+			0x43a1,
+			0x43a1,
+		];
+		assert_eq!(trace.exit_code, loads.into_iter().fold(0, u32::wrapping_add));
+		assert_eq!(trace.ram[1].0, 0x1143_87a1);
+		assert_eq!(trace.ram[2].0, 0x8765_43a1);
+		prove_program(&program).unwrap();
+	}
+
+	#[test]
+	fn test_division_edge_cases() {
+		let mut code = vec![
+			i(Opcode::Addi, 5, 0, 0, -7),
+			i(Opcode::Addi, 6, 0, 0, 2),
+			i(Opcode::Lui, 7, 0, 0, i32::MIN),
+			i(Opcode::Addi, 8, 0, 0, -1),
+			i(Opcode::Addi, 9, 0, 0, -6),
+			i(Opcode::Addi, 11, 0, 0, 3),
+		];
+		// Division by zero, signed overflow, exact division of a negative dividend and each
+		// combination of operand signs.
+		let operands = [
+			(5, 0),
+			(7, 8),
+			(9, 11),
+			(5, 6),
+			(6, 5),
+			(5, 8),
+			(7, 6),
+			(11, 9),
+		];
+		for op in [Opcode::Div, Opcode::Divu, Opcode::Rem, Opcode::Remu] {
+			for (rs1, rs2) in operands {
+				code.push(i(op, 12, rs1, rs2, 0));
+				code.push(i(Opcode::Xor, 10, 10, 12, 0));
+				code.push(i(Opcode::Add, 10, 10, 12, 0));
+			}
+		}
+		code.push(i(Opcode::Ecall, 0, 10, 0, 0));
+
+		prove_program(&Program::from_instructions(0x100, code)).unwrap();
+	}
+
+	#[test]
+	fn test_wrong_exit_code_rejected() {
+		let program = Program::from_instructions(
+			0,
+			[i(Opcode::Addi, 10, 0, 0, 42), i(Opcode::Ecall, 0, 10, 0, 0)],
+		);
+		let trace = execute(&program, 10).unwrap();
+
+		let mut cs = ConstraintSystem::new();
+		let rv32im = Rv32im::new(&mut cs, 8);
+		let mut allocator = CpuComputeAllocator::new(1 << 20);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+		rv32im.fill(&mut witness, &program, &trace).unwrap();
+
+		let boundaries = rv32im.boundaries(&program, 43).unwrap();
+		let ccs = cs.compile().unwrap();
+		let table_sizes = witness.table_sizes();
+		let witness = witness.into_multilinear_extension_index();
+		assert!(
+			binius_core::constraint_system::validate::validate_witness(
+				&ccs,
+				&boundaries,
+				&table_sizes,
+				&witness,
+			)
+			.is_err()
+		);
+	}
+}
@@ -0,0 +1,431 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Gadgets for proving that one table holds the sorted order of the keys in another.
+//!
+//! Channels prove multiset equality, which makes it easy to show that a table of keys is a
+//! permutation of another, but they say nothing about order. The gadgets here add the ordering.
+//!
+//! Keys are tuples of `N` 32-bit limbs, compared lexicographically with the most significant limb
+//! first. This covers `u32` keys (`N = 1`), `u64` keys (`N = 2`, see [`u64_to_limbs`]) and
+//! lexicographically ordered tuples of `u32` values.
+//!
+//! * The unsorted table pushes its keys to the permutation channel with
+//!   [`SortedPermutation::push_unsorted`].
+//! * The sorted table uses [`SortedKeys`], which pulls each key from the permutation channel. Every
+//!   row also holds the key of the previous row in sorted order, and a chain of subtractions checks
+//!   that `prev <= key`. The rows are linked by a chain channel of `(position, key)` tuples, where
+//!   the positions are successive powers of the `B32` multiplicative generator. Each row pulls
+//!   `(position, prev)` and pushes `(position * g, key)`.
+//! * A single [`SortedEnd`] row consumes the last link of the chain and pushes its position to the
+//!   length channel.
+//!
+//! The verifier seeds the chain with the all-zero key at position `g^0` and pulls `g^n` from the
+//! length channel, see [`SortedPermutation::boundaries`].
+
+use std::array;
+
+use binius_core::constraint_system::channel::{Boundary, ChannelId, FlushDirection};
+use binius_field::{BinaryField, Field, PackedExtension, PackedFieldIndexable, PackedSubfield};
+
+use crate::{
+	builder::{B1, B32, B128, Col, ConstraintSystem, TableBuilder, TableWitnessSegment},
+	gadgets::{
+		sub::{U32Sub, U32SubFlags},
+		util::position,
+	},
+};
+
+/// Splits a `u64` key into its big-endian 32-bit limbs.
+pub fn u64_to_limbs(key: u64) -> [u32; 2] {
+	[(key >> 32) as u32, key as u32]
+}
+
+/// The channels shared by the sorted permutation gadgets.
+#[derive(Debug, Clone, Copy)]
+pub struct SortedPermutation {
+	/// Carries the keys from the unsorted table to the sorted table.
+	pub permutation: ChannelId,
+	/// Carries `(position, key)` tuples between consecutive sorted rows.
+	pub chain: ChannelId,
+	/// Carries the final position of the chain.
+	pub length: ChannelId,
+}
+
+impl SortedPermutation {
+	pub fn new(cs: &mut ConstraintSystem) -> Self {
+		Self {
+			permutation: cs.add_channel("sorted_permutation"),
+			chain: cs.add_channel("sorted_chain"),
+			length: cs.add_channel("sorted_length"),
+		}
+	}
+
+	/// Pushes the keys of the unsorted table to the permutation channel.
+	pub fn push_unsorted<const N: usize>(&self, table: &mut TableBuilder, key: [Col<B32>; N]) {
+		table.push(self.permutation, key);
+	}
+
+	/// The boundary values a verifier uses to check a sorted table with `n_keys` rows.
+	pub fn boundaries<const N: usize>(&self, n_keys: usize) -> Vec<Boundary<B128>> {
+		vec![
+			Boundary {
+				values: [position(0).into()]
+					.into_iter()
+					.chain([B128::ZERO; N])
+					.collect(),
+				channel_id: self.chain,
+				direction: FlushDirection::Push,
+				multiplicity: 1,
+			},
+			Boundary {
+				values: vec![position(n_keys).into()],
+				channel_id: self.length,
+				direction: FlushDirection::Pull,
+				multiplicity: 1,
+			},
+		]
+	}
+}
+
+/// A row of a sorted table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SortedEvent<const N: usize> {
+	pub index: usize,
+	pub prev: [u32; N],
+	pub key: [u32; N],
+}
+
+/// The end of a sorted chain of length `index`, whose last key is `key`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SortedEndEvent<const N: usize> {
+	pub index: usize,
+	pub key: [u32; N],
+}
+
+/// Sorts `keys` and returns the sorted table rows and the end of the chain.
+pub fn sort_keys<const N: usize>(keys: &[[u32; N]]) -> (Vec<SortedEvent<N>>, SortedEndEvent<N>) {
+	let mut sorted = keys.to_vec();
+	sorted.sort_unstable();
+
+	let mut prev = [0; N];
+	let events = sorted
+		.iter()
+		.enumerate()
+		.map(|(index, &key)| {
+			let event = SortedEvent { index, prev, key };
+			prev = key;
+			event
+		})
+		.collect();
+	let end = SortedEndEvent {
+		index: sorted.len(),
+		key: prev,
+	};
+	(events, end)
+}
+
+/// A gadget constraining the rows of a table to be sorted by an `N`-limb key.
+#[derive(Debug)]
+pub struct SortedKeys<const N: usize> {
+	pub position: Col<B32>,
+	pub next_position: Col<B32>,
+	/// The key limbs, most significant first.
+	pub key_bits: [Col<B1, 32>; N],
+	/// The key limbs of the previous row in sorted order.
+	pub prev_bits: [Col<B1, 32>; N],
+	pub key: [Col<B32>; N],
+	pub prev: [Col<B32>; N],
+	/// Borrow-in bits for all limbs but the least significant one.
+	borrow_in: Vec<Col<B1, 32>>,
+	/// Subtractions `key - prev`, one per limb.
+	diff: Vec<U32Sub>,
+}
+
+impl<const N: usize> SortedKeys<N> {
+	pub fn new(table: &mut TableBuilder, channels: &SortedPermutation) -> Self {
+		assert!(N > 0, "keys must have at least one limb");
+
+		let position = table.add_committed::<B32, 1>("position");
+		let next_position =
+			table.add_computed("next_position", position * B32::MULTIPLICATIVE_GENERATOR);
+		let key_bits = array::from_fn(|i| table.add_committed(format!("key_bits[{i}]")));
+		let prev_bits = array::from_fn(|i| table.add_committed(format!("prev_bits[{i}]")));
+		let key = array::from_fn(|i| table.add_packed(format!("key[{i}]"), key_bits[i]));
+		let prev = array::from_fn(|i| table.add_packed(format!("prev[{i}]"), prev_bits[i]));
+
+		// Compute key - prev limb by limb, starting with the least significant limb, and propagate
+		// the borrows. The key is not smaller than prev if and only if the final borrow is zero.
+		let mut borrow_in = Vec::with_capacity(N - 1);
+		let mut diff = Vec::<U32Sub>::with_capacity(N);
+		for i in (0..N).rev() {
+			let borrow_in_bit = diff.last().map(|lower: &U32Sub| {
+				let final_borrow = lower.final_borrow.expect("expose_final_borrow is set");
+				let bit = table.add_zero_pad(format!("borrow_in[{i}]"), final_borrow, 0);
+				borrow_in.push(bit);
+				bit
+			});
+			diff.push(U32Sub::new(
+				&mut table.with_namespace(format!("diff[{i}]")),
+				key_bits[i],
+				prev_bits[i],
+				U32SubFlags {
+					borrow_in_bit,
+					expose_final_borrow: true,
+					commit_zout: false,
+				},
+			));
+		}
+		let final_borrow = diff
+			.last()
+			.and_then(|diff| diff.final_borrow)
+			.expect("expose_final_borrow is set");
+		table.assert_zero("sorted", final_borrow.into());
+
+		table.pull(channels.permutation, key);
+		table.pull(channels.chain, [position].into_iter().chain(prev));
+		table.push(channels.chain, [next_position].into_iter().chain(key));
+
+		Self {
+			position,
+			next_position,
+			key_bits,
+			prev_bits,
+			key,
+			prev,
+			borrow_in,
+			diff,
+		}
+	}
+
+	pub fn populate<'a, P>(
+		&self,
+		witness: &mut TableWitnessSegment<P>,
+		events: impl IntoIterator<Item = &'a SortedEvent<N>>,
+	) -> anyhow::Result<()>
+	where
+		P: PackedFieldIndexable<Scalar = B128> + PackedExtension<B1> + PackedExtension<B32>,
+		PackedSubfield<P, B32>: PackedFieldIndexable,
+	{
+		{
+			let mut position = witness.get_scalars_mut(self.position)?;
+			let mut next_position = witness.get_scalars_mut(self.next_position)?;
+			let mut key_bits = array_util::try_map(self.key_bits, |col| witness.get_mut_as(col))?;
+			let mut prev_bits = array_util::try_map(self.prev_bits, |col| witness.get_mut_as(col))?;
+			let mut borrow_in = self
+				.borrow_in
+				.iter()
+				.map(|&col| witness.get_mut_as::<u32, _, 32>(col))
+				.collect::<Result<Vec<_>, _>>()?;
+
+			for (i, event) in events.into_iter().enumerate() {
+				position[i] = self::position(event.index);
+				next_position[i] = position[i] * B32::MULTIPLICATIVE_GENERATOR;
+
+				let mut borrow = false;
+				for (j, limb) in (0..N).rev().enumerate() {
+					key_bits[limb][i] = event.key[limb];
+					prev_bits[limb][i] = event.prev[limb];
+					if j > 0 {
+						borrow_in[j - 1][i] = borrow as u32;
+					}
+					let (diff, borrow1) = event.key[limb].overflowing_sub(event.prev[limb]);
+					let (_, borrow2) = diff.overflowing_sub(borrow as u32);
+					borrow = borrow1 | borrow2;
+				}
+			}
+		}
+
+		for diff in &self.diff {
+			diff.populate(witness)?;
+		}
+		Ok(())
+	}
+}
+
+/// A gadget consuming the last link of a sorted chain.
+///
+/// The table using it must have exactly one row per sorted table.
+#[derive(Debug)]
+pub struct SortedEnd<const N: usize> {
+	pub position: Col<B32>,
+	pub key: [Col<B32>; N],
+}
+
+impl<const N: usize> SortedEnd<N> {
+	pub fn new(table: &mut TableBuilder, channels: &SortedPermutation) -> Self {
+		let position = table.add_committed::<B32, 1>("position");
+		let key = array::from_fn(|i| table.add_committed::<B32, 1>(format!("key[{i}]")));
+
+		table.pull(channels.chain, [position].into_iter().chain(key));
+		table.push(channels.length, [position]);
+
+		Self { position, key }
+	}
+
+	pub fn populate<'a, P>(
+		&self,
+		witness: &mut TableWitnessSegment<P>,
+		events: impl IntoIterator<Item = &'a SortedEndEvent<N>>,
+	) -> anyhow::Result<()>
+	where
+		P: PackedFieldIndexable<Scalar = B128> + PackedExtension<B32>,
+		PackedSubfield<P, B32>: PackedFieldIndexable,
+	{
+		let mut position = witness.get_scalars_mut(self.position)?;
+		let mut key = array_util::try_map(self.key, |col| witness.get_mut_as::<u32, _, 1>(col))?;
+
+		for (i, event) in events.into_iter().enumerate() {
+			position[i] = self::position(event.index);
+			for (key, &limb) in key.iter_mut().zip(&event.key) {
+				key[i] = limb;
+			}
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_compute::cpu::alloc::CpuComputeAllocator;
+	use binius_field::{arch::OptimalUnderlier128b, as_packed_field::PackedType};
+	use rand::{Rng, SeedableRng, rngs::StdRng};
+
+	use super::*;
+	use crate::builder::{
+		WitnessIndex,
+		test_utils::{ClosureFiller, validate_system_witness},
+	};
+
+	fn with_sorted_test_instance<const N: usize>(
+		keys: &[[u32; N]],
+		sorted: &[SortedEvent<N>],
+		end: SortedEndEvent<N>,
+		f: impl FnOnce(
+			&ConstraintSystem<B128>,
+			WitnessIndex<PackedType<OptimalUnderlier128b, B128>>,
+			Vec<Boundary<B128>>,
+		),
+	) {
+		let mut cs = ConstraintSystem::new();
+		let channels = SortedPermutation::new(&mut cs);
+
+		let mut unsorted_table = cs.add_table("unsorted");
+		let unsorted_table_id = unsorted_table.id();
+		let unsorted_key: [Col<B32>; N] =
+			array::from_fn(|i| unsorted_table.add_committed(format!("key[{i}]")));
+		channels.push_unsorted(&mut unsorted_table, unsorted_key);
+
+		let mut sorted_table = cs.add_table("sorted");
+		let sorted_table_id = sorted_table.id();
+		let sorted_keys = SortedKeys::<N>::new(&mut sorted_table, &channels);
+
+		let mut end_table = cs.add_table("sorted_end");
+		let end_table_id = end_table.id();
+		let sorted_end = SortedEnd::<N>::new(&mut end_table, &channels);
+
+		let mut allocator = CpuComputeAllocator::new(1 << 16);
+		let allocator = allocator.into_bump_allocator();
+		let mut witness =
+			WitnessIndex::<PackedType<OptimalUnderlier128b, B128>>::new(&cs, &allocator);
+
+		witness
+			.fill_table_sequential(
+				&ClosureFiller::new(unsorted_table_id, |keys: &[[u32; N]], segment| {
+					let mut cols = array_util::try_map(unsorted_key, |col| {
+						segment.get_mut_as::<u32, _, 1>(col)
+					})?;
+					for (i, key) in keys.iter().enumerate() {
+						for (col, &limb) in cols.iter_mut().zip(key) {
+							col[i] = limb;
+						}
+					}
+					Ok(())
+				}),
+				keys,
+			)
+			.unwrap();
+		witness
+			.fill_table_sequential(
+				&ClosureFiller::new(sorted_table_id, |events, segment| {
+					sorted_keys.populate(segment, events)
+				}),
+				sorted,
+			)
+			.unwrap();
+		witness
+			.fill_table_sequential(
+				&ClosureFiller::new(end_table_id, |events, segment| {
+					sorted_end.populate(segment, events)
+				}),
+				&[end],
+			)
+			.unwrap();
+
+		let boundaries = channels.boundaries::<N>(sorted.len());
+		f(&cs, witness, boundaries)
+	}
+
+	fn test_sorted_permutation<const N: usize>(keys: &[[u32; N]]) {
+		let (sorted, end) = sort_keys(keys);
+		with_sorted_test_instance(keys, &sorted, end, |cs, witness, boundaries| {
+			validate_system_witness::<OptimalUnderlier128b>(cs, witness, boundaries)
+		});
+	}
+
+	#[test]
+	fn test_sorted_u32() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let keys = repeat_with(|| [rng.random::<u32>() % 100])
+			.take(67)
+			.collect::<Vec<_>>();
+		test_sorted_permutation(&keys);
+	}
+
+	#[test]
+	fn test_sorted_u64() {
+		let mut rng = StdRng::seed_from_u64(0);
+		// Share the high limbs between keys so that the borrow propagation is exercised.
+		let keys = repeat_with(|| {
+			u64_to_limbs(rng.random_range(0..4u64) << 32 | rng.random::<u32>() as u64)
+		})
+		.take(50)
+		.collect::<Vec<_>>();
+		test_sorted_permutation(&keys);
+	}
+
+	#[test]
+	fn test_sorted_tuples() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let keys = repeat_with(|| [rng.random_range(0..3), rng.random_range(0..3), rng.random()])
+			.take(40)
+			.collect::<Vec<_>>();
+		test_sorted_permutation(&keys);
+	}
+
+	#[test]
+	fn test_unsorted_rejected() {
+		let keys = [[7, 1], [3, 9], [7, 0]];
+		let (mut sorted, end) = sort_keys(&keys);
+
+		// Swap the last two keys, keeping the chain of previous keys consistent.
+		sorted[1].key = [7, 1];
+		sorted[2].prev = [7, 1];
+		sorted[2].key = [7, 0];
+		let end = SortedEndEvent { key: [7, 0], ..end };
+
+		with_sorted_test_instance(&keys, &sorted, end, |cs, witness, boundaries| {
+			let table_sizes = witness.table_sizes();
+			let ccs = cs.compile().unwrap();
+			let witness = witness.into_multilinear_extension_index();
+			let result = binius_core::constraint_system::validate::validate_witness(
+				&ccs,
+				&boundaries,
+				&table_sizes,
+				&witness,
+			);
+			assert!(result.is_err());
+		});
+	}
+}
@@ -2,9 +2,9 @@
 
 use std::array;
 
-use binius_field::{TowerField, ext_basis};
+use binius_field::{BinaryField, PackedField, TowerField, ext_basis};
 
-use crate::builder::{B1, Col, Expr, upcast_col};
+use crate::builder::{B1, B32, Col, Expr, upcast_col};
 
 /// Used to pack an array of `Col<B1>` into `Col<FP>` assuming `BIT_LENGTH` is the bit length of
 /// field `FP`
@@ -19,3 +19,13 @@ pub fn pack_fp<FP: TowerField, const BIT_LENGTH: usize>(
 		.reduce(|a, b| a + b)
 		.expect("bit has length checked above")
 }
+
+/// The position of the `index`-th element of a sequence, `g^index`, where `g` is the
+/// multiplicative generator of `B32`.
+///
+/// Gadgets that chain rows through a channel use positions to enforce a linear order, since
+/// the successor of a position is a linear function of it. The positions are distinct for all
+/// indices less than `2^32 - 1`.
+pub fn position(index: usize) -> B32 {
+	B32::MULTIPLICATIVE_GENERATOR.pow(index as u64)
+}
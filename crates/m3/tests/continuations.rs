@@ -0,0 +1,248 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Proves a computation as a chain of segments, linked by their start and end states.
+
+use assert_matches::assert_matches;
+use binius_compute::{ComputeHolder, alloc::HostBumpAllocator, cpu::alloc::CpuComputeAllocator};
+use binius_core::{
+	constraint_system::{
+		Proof,
+		channel::{Boundary, ChannelId, FlushDirection},
+		continuation::{
+			Segment, SegmentProof, SegmentWitness, prove_segments, segment_sizes, verify_segments,
+		},
+		error::{Error, VerificationError},
+	},
+	fiat_shamir::HasherChallenger,
+};
+use binius_field::{
+	arch::OptimalUnderlier128b, as_packed_field::PackedType, tower::CanonicalTowerFamily,
+};
+use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+use binius_m3::builder::{
+	B32, B128, Col, ConstraintSystem, WitnessIndex,
+	test_utils::{
+		ClosureFiller, LOG_INV_RATE, SECURITY_BITS, compute_holder, fri_pcs_prover, prove_with_pcs,
+	},
+};
+
+type P = PackedType<OptimalUnderlier128b, B128>;
+
+/// Each row of the table steps the state `x` to `3 * x`.
+struct Stepper {
+	cs: ConstraintSystem,
+	state: ChannelId,
+	x: Col<B32>,
+	y: Col<B32>,
+}
+
+impl Stepper {
+	fn new() -> Self {
+		Self::build(false)
+	}
+
+	/// Creates the stepper with a table that is required to have a power-of-two size.
+	fn new_power_of_two() -> Self {
+		Self::build(true)
+	}
+
+	fn build(power_of_two: bool) -> Self {
+		let mut cs = ConstraintSystem::new();
+		let state = cs.add_channel("state");
+		let mut table = cs.add_table("step");
+		if power_of_two {
+			table.require_power_of_two_size();
+		}
+		let x = table.add_committed("x");
+		let y = table.add_computed("y", x * B32::new(3));
+		table.pull(state, [x]);
+		table.push(state, [y]);
+		Self { cs, state, x, y }
+	}
+
+	fn state(&self, value: B32) -> Vec<Boundary<B128>> {
+		vec![Boundary {
+			values: vec![value.into()],
+			channel_id: self.state,
+			direction: FlushDirection::Push,
+			multiplicity: 1,
+		}]
+	}
+
+	/// Returns the states visited by `n_steps` steps from `start`, including both ends.
+	fn steps(start: B32, n_steps: usize) -> Vec<B32> {
+		std::iter::successors(Some(start), |&x| Some(x * B32::new(3)))
+			.take(n_steps + 1)
+			.collect()
+	}
+
+	/// Fills the witness of a segment taking the steps from each of `steps` but the last.
+	fn fill(&self, witness: &mut WitnessIndex<P>, steps: &[B32]) -> anyhow::Result<()> {
+		let (x_col, y_col) = (self.x, self.y);
+		witness.fill_table_sequential(
+			&ClosureFiller::new(self.x.table_id, move |events: &[B32], index| {
+				let mut x = index.get_scalars_mut(x_col)?;
+				let mut y = index.get_scalars_mut(y_col)?;
+				for (i, &event) in events.iter().enumerate() {
+					x[i] = event;
+					y[i] = event * B32::new(3);
+				}
+				Ok(())
+			}),
+			&steps[..steps.len() - 1],
+		)?;
+		Ok(())
+	}
+
+	fn segment_witness<'a>(
+		&self,
+		allocator: &'a HostBumpAllocator<'a, P>,
+		steps: &[B32],
+	) -> SegmentWitness<'a, P, B128> {
+		let mut witness = WitnessIndex::<P>::new(&self.cs, allocator);
+		self.fill(&mut witness, steps).unwrap();
+		SegmentWitness {
+			end: self.state(steps[steps.len() - 1]),
+			table_sizes: witness.table_sizes(),
+			witness: witness.into_multilinear_extension_index(),
+		}
+	}
+
+	fn prove_segment(&self, index: usize, start: B32, n_steps: usize) -> (B32, Proof) {
+		let steps = Self::steps(start, n_steps);
+		let end = steps[n_steps];
+
+		let ccs = self.cs.compile().unwrap();
+		let segment = Segment::new(index, self.state(start), self.state(end)).unwrap();
+		let proof = prove_with_pcs::<OptimalUnderlier128b, _>(
+			&self.cs,
+			&fri_pcs_prover(),
+			&segment.digest::<Groestl256>(&ccs.digest::<Groestl256>()),
+			&segment.boundaries(&[]),
+			None,
+			|witness| self.fill(witness, &steps),
+		)
+		.unwrap();
+		(end, proof)
+	}
+
+	fn prove_chain(&self, start: B32, segment_steps: &[usize]) -> (B32, Vec<SegmentProof<B128>>) {
+		let steps = Self::steps(start, segment_steps.iter().sum());
+		let offsets = std::iter::once(0)
+			.chain(segment_steps.iter().scan(0, |offset, &n_steps| {
+				*offset += n_steps;
+				Some(*offset)
+			}))
+			.collect::<Vec<_>>();
+
+		let ccs = self.cs.compile().unwrap();
+		let mut allocator = CpuComputeAllocator::new(1 << 16);
+		let segments = prove_segments::<
+			_,
+			OptimalUnderlier128b,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+			_,
+			_,
+			_,
+			_,
+			Error,
+		>(
+			&mut compute_holder::<OptimalUnderlier128b>().to_data(),
+			&ccs,
+			LOG_INV_RATE,
+			SECURITY_BITS,
+			&ccs.digest::<Groestl256>(),
+			&[],
+			&self.state(start),
+			segment_steps.len(),
+			&mut allocator,
+			|index, _start, allocator| {
+				Ok(self.segment_witness(allocator, &steps[offsets[index]..=offsets[index + 1]]))
+			},
+			&binius_hal::make_portable_backend(),
+			None,
+		)
+		.unwrap();
+		(*steps.last().unwrap(), segments)
+	}
+
+	fn verify_chain(
+		&self,
+		start: B32,
+		end: B32,
+		segments: Vec<SegmentProof<B128>>,
+	) -> Result<(), Error> {
+		let ccs = self.cs.compile().unwrap();
+		verify_segments::<
+			OptimalUnderlier128b,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(
+			&ccs,
+			LOG_INV_RATE,
+			SECURITY_BITS,
+			&ccs.digest::<Groestl256>(),
+			&[],
+			&self.state(start),
+			&self.state(end),
+			segments,
+			None,
+		)
+	}
+}
+
+#[test]
+fn test_segment_chain() {
+	let stepper = Stepper::new();
+	let start = B32::new(7);
+	let (end, segments) = stepper.prove_chain(start, &[500, 800, 300]);
+	stepper.verify_chain(start, end, segments).unwrap();
+}
+
+#[test]
+fn test_segment_chain_wrong_final_state() {
+	let stepper = Stepper::new();
+	let start = B32::new(7);
+	let (end, segments) = stepper.prove_chain(start, &[500, 300]);
+	assert_matches!(
+		stepper.verify_chain(start, end * B32::new(3), segments),
+		Err(Error::Verification(VerificationError::SegmentFinalStateMismatch))
+	);
+}
+
+#[test]
+fn test_segment_proof_bound_to_index() {
+	let stepper = Stepper::new();
+	let start = B32::new(7);
+	let (end, proof) = stepper.prove_segment(1, start, 400);
+	let segments = vec![SegmentProof {
+		end: stepper.state(end),
+		proof,
+	}];
+	assert!(stepper.verify_chain(start, end, segments).is_err());
+}
+
+#[test]
+fn test_empty_segment_chain() {
+	let stepper = Stepper::new();
+	let start = B32::new(7);
+	assert_matches!(stepper.verify_chain(start, start, Vec::new()), Err(Error::EmptySegmentChain));
+}
+
+#[test]
+fn test_segment_chain_sized_by_table_spec() {
+	let stepper = Stepper::new_power_of_two();
+	let ccs = stepper.cs.compile().unwrap();
+	let spec = ccs.table_size_specs[stepper.x.table_id];
+	let segment_steps = segment_sizes(spec, 1792, 9).unwrap();
+	assert_eq!(segment_steps, [512, 512, 512, 256]);
+
+	let start = B32::new(7);
+	let (end, segments) = stepper.prove_chain(start, &segment_steps);
+	stepper.verify_chain(start, end, segments).unwrap();
+}
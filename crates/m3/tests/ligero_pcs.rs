@@ -0,0 +1,80 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Proves a constraint system with the Ligero commitment scheme in place of FRI.
+
+use binius_core::piop::{LigeroPCS, LigeroPCSProver};
+use binius_field::{arch::OptimalUnderlier128b, as_packed_field::PackedType};
+use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+use binius_m3::builder::{
+	B32, B128, Col, ConstraintSystem, WitnessIndex,
+	test_utils::{
+		ClosureFiller, LOG_INV_RATE, MerkleProver, MerkleScheme, SECURITY_BITS, fri_pcs,
+		prove_verify_with_pcs, prove_with_pcs, verify_with_pcs,
+	},
+};
+
+const N_ROWS: usize = 1 << 10;
+
+type U = OptimalUnderlier128b;
+
+/// Builds a table whose rows multiply `x` by a constant and assert the product.
+fn mul_table() -> (ConstraintSystem, Col<B32>, Col<B32>) {
+	let mut cs = ConstraintSystem::new();
+	let mut table = cs.add_table("mul");
+	let x = table.add_committed("x");
+	let y = table.add_committed("y");
+	table.assert_zero("y = 3 * x", y - x * B32::new(3));
+	(cs, x, y)
+}
+
+fn fill_mul_table(
+	witness: &mut WitnessIndex<PackedType<U, B128>>,
+	x_col: Col<B32>,
+	y_col: Col<B32>,
+) -> anyhow::Result<()> {
+	let events = (0..N_ROWS as u32).map(B32::new).collect::<Vec<_>>();
+	witness.fill_table_sequential(
+		&ClosureFiller::new(x_col.table_id, move |events: &[B32], index| {
+			let mut x = index.get_scalars_mut(x_col)?;
+			let mut y = index.get_scalars_mut(y_col)?;
+			for (i, &event) in events.iter().enumerate() {
+				x[i] = event;
+				y[i] = event * B32::new(3);
+			}
+			Ok(())
+		}),
+		&events,
+	)?;
+	Ok(())
+}
+
+fn ligero_pcs_prover() -> LigeroPCSProver<B128, B32, MerkleProver> {
+	LigeroPCSProver::new(MerkleProver::new(Groestl256ByteCompression), LOG_INV_RATE, SECURITY_BITS)
+}
+
+#[test]
+fn test_prove_verify_with_ligero() {
+	let (cs, x, y) = mul_table();
+	let pcs = LigeroPCS::<_, B32, _>::new(
+		MerkleScheme::new(Groestl256ByteCompression),
+		LOG_INV_RATE,
+		SECURITY_BITS,
+	);
+	prove_verify_with_pcs::<U, _, _>(&cs, &ligero_pcs_prover(), &pcs, &[], None, |witness| {
+		fill_mul_table(witness, x, y)
+	})
+	.unwrap();
+}
+
+#[test]
+fn test_fri_verifier_rejects_ligero_proof() {
+	let (cs, x, y) = mul_table();
+	let ccs_digest = cs.compile().unwrap().digest::<Groestl256>();
+	let proof =
+		prove_with_pcs::<U, _>(&cs, &ligero_pcs_prover(), &ccs_digest, &[], None, |witness| {
+			fill_mul_table(witness, x, y)
+		})
+		.unwrap();
+	let result = verify_with_pcs::<U, _>(&cs, &fri_pcs(), &ccs_digest, &[], proof, None);
+	assert!(result.is_err());
+}
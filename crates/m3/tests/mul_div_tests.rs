@@ -225,10 +225,22 @@ impl MulDivTestSuiteHelper for MulDiv32TestTable {
 					.collect()
 			}
 			MulDivEnum::DivSS32(_) => {
-				repeat_with(|| (B32::new(rng.random::<u32>()), B32::new(rng.random::<u32>())))
-					.filter(|(_, y)| y.val() != 0)
-					.take(table_size)
-					.collect()
+				// Exact division of a negative dividend, and each combination of operand signs.
+				const EXPLICIT_TESTS: [(B32, B32); 5] = [
+					(B32::new(-6i32 as u32), B32::new(3)),
+					(B32::new(-7i32 as u32), B32::new(2)),
+					(B32::new(7), B32::new(-2i32 as u32)),
+					(B32::new(-7i32 as u32), B32::new(-2i32 as u32)),
+					(B32::new(i32::MIN_VALUE as u32), B32::new(-2i32 as u32)),
+				];
+
+				chain!(
+					EXPLICIT_TESTS.into_iter(),
+					repeat_with(|| (B32::new(rng.random::<u32>()), B32::new(rng.random::<u32>())))
+						.filter(|(_, y)| y.val() != 0)
+				)
+				.take(table_size)
+				.collect()
 			}
 		}
 	}
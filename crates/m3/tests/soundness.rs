@@ -0,0 +1,163 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Checks the concrete soundness analysis and the security threshold of the prover and verifier.
+
+use assert_matches::assert_matches;
+use binius_core::{
+	constraint_system::{
+		self, Proof,
+		error::Error,
+		soundness::{self, Subprotocol},
+	},
+	fiat_shamir::HasherChallenger,
+	piop::LigeroPCS,
+};
+use binius_field::{
+	arch::OptimalUnderlier128b, as_packed_field::PackedType, tower::CanonicalTowerFamily,
+};
+use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+use binius_m3::builder::{
+	B32, B128, Col, ConstraintSystem, WitnessIndex,
+	test_utils::{
+		ClosureFiller, LOG_INV_RATE, MerkleScheme, SECURITY_BITS, fri_pcs, fri_pcs_prover,
+		prove_with_pcs, verify_with_pcs,
+	},
+};
+
+const N_ROWS: usize = 1 << 10;
+
+type U = OptimalUnderlier128b;
+
+/// Each row of the table squares `x` and asserts the product.
+struct SquareTable {
+	cs: ConstraintSystem,
+	x: Col<B32>,
+	y: Col<B32>,
+}
+
+impl SquareTable {
+	fn new() -> Self {
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("square");
+		let x = table.add_committed("x");
+		let y = table.add_committed("y");
+		table.assert_zero("y = x * x", y - x * x);
+		Self { cs, x, y }
+	}
+
+	fn fill(&self, witness: &mut WitnessIndex<PackedType<U, B128>>) -> anyhow::Result<()> {
+		let (x_col, y_col) = (self.x, self.y);
+		let events = (0..N_ROWS as u32).map(B32::new).collect::<Vec<_>>();
+		witness.fill_table_sequential(
+			&ClosureFiller::new(self.x.table_id, move |events: &[B32], index| {
+				let mut x = index.get_scalars_mut(x_col)?;
+				let mut y = index.get_scalars_mut(y_col)?;
+				for (i, &event) in events.iter().enumerate() {
+					x[i] = event;
+					y[i] = event * event;
+				}
+				Ok(())
+			}),
+			&events,
+		)?;
+		Ok(())
+	}
+
+	fn prove(&self, min_security_bits: Option<usize>) -> Result<Proof, Error> {
+		let ccs = self.cs.compile().unwrap();
+		prove_with_pcs::<U, _>(
+			&self.cs,
+			&fri_pcs_prover(),
+			&ccs.digest::<Groestl256>(),
+			&[],
+			min_security_bits,
+			|witness| self.fill(witness),
+		)
+	}
+}
+
+#[test]
+fn test_soundness_report() {
+	let table = SquareTable::new();
+	let ccs = table.cs.compile().unwrap();
+
+	let report =
+		soundness::analyze::<CanonicalTowerFamily, Groestl256, _>(&ccs, &[N_ROWS], &fri_pcs())
+			.unwrap();
+
+	// The FRI queries are chosen for the target security level, which dominates the other errors
+	// of this small constraint system.
+	let pcs_error = report.error(Subprotocol::PCS);
+	assert!(pcs_error.proven_bits() >= SECURITY_BITS as f64 - 1.0);
+	assert!(pcs_error.conjectured_bits() > pcs_error.proven_bits());
+	assert!(report.error(Subprotocol::Zerocheck).proven_bits() > 110.0);
+	assert_eq!(report.error(Subprotocol::Exponentiation).proven, 0.0);
+
+	assert!(report.proven_security_bits() <= pcs_error.proven_bits());
+	assert!(report.proven_security_bits() > SECURITY_BITS as f64 - 2.0);
+	assert!(report.conjectured_security_bits() >= report.proven_security_bits());
+	assert_matches!(report.check(128), Err(Error::InsufficientSecurity { required: 128, .. }));
+
+	let ligero_pcs = LigeroPCS::<_, B32, _>::new(
+		MerkleScheme::new(Groestl256ByteCompression),
+		LOG_INV_RATE,
+		SECURITY_BITS,
+	);
+	let report =
+		soundness::analyze::<CanonicalTowerFamily, Groestl256, _>(&ccs, &[N_ROWS], &ligero_pcs)
+			.unwrap();
+	assert!(report.error(Subprotocol::PCS).proven_bits() >= SECURITY_BITS as f64 - 1.0);
+}
+
+#[test]
+fn test_prover_refuses_insufficient_security() {
+	let table = SquareTable::new();
+	let result = table.prove(Some(SECURITY_BITS + 20));
+	assert_matches!(result, Err(Error::InsufficientSecurity { .. }));
+}
+
+#[test]
+fn test_verifier_security_threshold() {
+	let table = SquareTable::new();
+	let ccs = table.cs.compile().unwrap();
+	let min_security_bits = SECURITY_BITS - 2;
+
+	let verify = |proof, min_security_bits| {
+		verify_with_pcs::<U, _>(
+			&table.cs,
+			&fri_pcs(),
+			&ccs.digest::<Groestl256>(),
+			&[],
+			proof,
+			Some(min_security_bits),
+		)
+	};
+
+	let proof = table.prove(Some(min_security_bits)).unwrap();
+	verify(proof.clone(), min_security_bits).unwrap();
+	assert_matches!(verify(proof, SECURITY_BITS + 20), Err(Error::InsufficientSecurity { .. }));
+}
+
+#[test]
+fn test_default_verifier_security_threshold() {
+	let table = SquareTable::new();
+	let ccs = table.cs.compile().unwrap();
+	let proof = table.prove(None).unwrap();
+
+	let result = constraint_system::verify::<
+		U,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+	>(
+		&ccs,
+		LOG_INV_RATE,
+		SECURITY_BITS,
+		&ccs.digest::<Groestl256>(),
+		&[],
+		proof,
+		Some(SECURITY_BITS + 20),
+	);
+	assert_matches!(result, Err(Error::InsufficientSecurity { .. }));
+}
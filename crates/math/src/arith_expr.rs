@@ -197,6 +197,7 @@ impl<F: Field> Product for ArithExpr<F> {
 }
 
 #[derive(Clone, Copy, Debug, SerializeBytes, DeserializeBytes, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ArithCircuitStep<F: Field> {
 	Add(usize, usize),
 	Mul(usize, usize),
@@ -221,6 +222,7 @@ impl<F: Field> Default for ArithCircuitStep<F> {
 /// This implementation isn't optimized for performance, but rather for simplicity
 /// to allow easy conversion and preservation of the common subexpressions
 #[derive(Clone, Debug, SerializeBytes, DeserializeBytes, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ArithCircuit<F: Field> {
 	steps: Vec<ArithCircuitStep<F>>,
 }
@@ -1378,6 +1380,18 @@ mod tests {
 		check_serialize_bytes_roundtrip(expr);
 	}
 
+	#[cfg(feature = "serde")]
+	#[test]
+	fn test_serde_roundtrip() {
+		type F = BinaryField128b;
+		let expr = ArithCircuit::var(0) * ArithCircuit::constant(F::MULTIPLICATIVE_GENERATOR)
+			+ ArithCircuit::var(1).pow(3);
+
+		let json = serde_json::to_string(&expr).unwrap();
+		assert!(json.starts_with(r#"{"steps":[{"Var":0},{"Const":""#));
+		assert_eq!(serde_json::from_str::<ArithCircuit<F>>(&json).unwrap(), expr);
+	}
+
 	#[test]
 	fn test_binary_tower_level() {
 		type F = BinaryField128b;
@@ -6,7 +6,10 @@ use std::{any::TypeId, cmp::min, mem::MaybeUninit};
 use binius_field::{
 	AESTowerField128b, BinaryField1b, BinaryField128b, BinaryField128bPolyval, ExtensionField,
 	Field, PackedField,
-	arch::{ArchOptimal, OptimalUnderlier},
+	arch::{
+		ArchOptimal, OptimalUnderlier,
+		dispatch::{Dispatcher, dispatch},
+	},
 	byte_iteration::{
 		ByteIteratorCallback, can_iterate_bytes, create_partial_sums_lookup_tables,
 		is_sequential_bytes, iterate_bytes,
@@ -98,25 +101,27 @@ where
 {
 	check_fold_arguments(evals, log_evals_size, query, log_query_size, out)?;
 
-	// Try execute the optimized version for 1-bit values if possible
-	if TypeId::of::<P::Scalar>() == TypeId::of::<BinaryField1b>()
-		&& fold_right_1bit_evals(evals, log_evals_size, query, log_query_size, out)
-	{
-		return Ok(());
-	}
+	dispatch(|| {
+		// Try execute the optimized version for 1-bit values if possible
+		if TypeId::of::<P::Scalar>() == TypeId::of::<BinaryField1b>()
+			&& fold_right_1bit_evals(evals, log_evals_size, query, log_query_size, out)
+		{
+			return Ok(());
+		}
 
-	// Use linear interpolation for single variable multilinear queries.
-	let is_lerp = log_query_size == 1
-		&& get_packed_slice(query, 0) + get_packed_slice(query, 1) == PE::Scalar::ONE;
+		// Use linear interpolation for single variable multilinear queries.
+		let is_lerp = log_query_size == 1
+			&& get_packed_slice(query, 0) + get_packed_slice(query, 1) == PE::Scalar::ONE;
 
-	if is_lerp {
-		let lerp_query = get_packed_slice(query, 1);
-		fold_right_lerp(evals, 1 << log_evals_size, lerp_query, P::Scalar::ZERO, out)?;
-	} else {
-		fold_right_fallback(evals, log_evals_size, query, log_query_size, out);
-	}
+		if is_lerp {
+			let lerp_query = get_packed_slice(query, 1);
+			fold_right_lerp(evals, 1 << log_evals_size, lerp_query, P::Scalar::ZERO, out)?;
+		} else {
+			fold_right_fallback(evals, log_evals_size, query, log_query_size, out);
+		}
 
-	Ok(())
+		Ok(())
+	})
 }
 
 /// Execute the left fold operation.
@@ -142,40 +147,43 @@ where
 {
 	check_fold_arguments(evals, log_evals_size, query, log_query_size, out)?;
 
-	if TypeId::of::<P::Scalar>() == TypeId::of::<BinaryField1b>()
-		&& fold_left_1b_128b(evals, log_evals_size, query, log_query_size, out)
-	{
-		return Ok(());
-	}
+	dispatch(|| {
+		if TypeId::of::<P::Scalar>() == TypeId::of::<BinaryField1b>()
+			&& fold_left_1b_128b(evals, log_evals_size, query, log_query_size, out)
+		{
+			return Ok(());
+		}
 
-	// Use linear interpolation for single variable multilinear queries.
-	// Unlike right folds, left folds are often used with packed fields which are not indexable
-	// and have quite slow scalar indexing methods. For now, we specialize the practically important
-	// case of single variable fold when PE == P.
-	let is_lerp = log_query_size == 1
-		&& get_packed_slice(query, 0) + get_packed_slice(query, 1) == PE::Scalar::ONE
-		&& TypeId::of::<P>() == TypeId::of::<PE>();
-
-	if is_lerp {
-		let lerp_query = get_packed_slice(query, 1);
-		// Safety: P == PE checked above.
-		let out_p =
-			unsafe { std::mem::transmute::<&mut [MaybeUninit<PE>], &mut [MaybeUninit<P>]>(out) };
-
-		let lerp_query_p = lerp_query.try_into().ok().expect("P == PE");
-		fold_left_lerp(
-			evals,
-			1 << log_evals_size,
-			P::Scalar::ZERO,
-			log_evals_size,
-			lerp_query_p,
-			out_p,
-		)?;
-	} else {
-		fold_left_fallback(evals, log_evals_size, query, log_query_size, out);
-	}
+		// Use linear interpolation for single variable multilinear queries.
+		// Unlike right folds, left folds are often used with packed fields which are not indexable
+		// and have quite slow scalar indexing methods. For now, we specialize the practically
+		// important case of single variable fold when PE == P.
+		let is_lerp = log_query_size == 1
+			&& get_packed_slice(query, 0) + get_packed_slice(query, 1) == PE::Scalar::ONE
+			&& TypeId::of::<P>() == TypeId::of::<PE>();
+
+		if is_lerp {
+			let lerp_query = get_packed_slice(query, 1);
+			// Safety: P == PE checked above.
+			let out_p = unsafe {
+				std::mem::transmute::<&mut [MaybeUninit<PE>], &mut [MaybeUninit<P>]>(out)
+			};
+
+			let lerp_query_p = lerp_query.try_into().ok().expect("P == PE");
+			fold_left_lerp(
+				evals,
+				1 << log_evals_size,
+				P::Scalar::ZERO,
+				log_evals_size,
+				lerp_query_p,
+				out_p,
+			)?;
+		} else {
+			fold_left_fallback(evals, log_evals_size, query, log_query_size, out);
+		}
 
-	Ok(())
+		Ok(())
+	})
 }
 
 /// Execute the middle fold operation.
@@ -538,41 +546,43 @@ where
 {
 	check_right_lerp_fold_arguments::<_, PE, _>(evals, evals_size, out)?;
 
-	let folded_evals_size = evals_size >> 1;
-	out[..folded_evals_size.div_ceil(PE::WIDTH)]
-		.iter_mut()
-		.enumerate()
-		.for_each(|(i, packed_result_eval)| {
-			for j in 0..min(PE::WIDTH, folded_evals_size - (i << PE::LOG_WIDTH)) {
-				let index = (i << PE::LOG_WIDTH) | j;
-
-				let (eval0, eval1) = unsafe {
-					(
-						get_packed_slice_unchecked(evals, index << 1),
-						get_packed_slice_unchecked(evals, (index << 1) | 1),
-					)
-				};
-
-				let result_eval =
-					PE::Scalar::from(eval1 - eval0) * lerp_query + PE::Scalar::from(eval0);
-
-				// Safety: `j` < `PE::WIDTH`
-				unsafe {
-					packed_result_eval.set_unchecked(j, result_eval);
+	dispatch(|| {
+		let folded_evals_size = evals_size >> 1;
+		out[..folded_evals_size.div_ceil(PE::WIDTH)]
+			.iter_mut()
+			.enumerate()
+			.for_each(|(i, packed_result_eval)| {
+				for j in 0..min(PE::WIDTH, folded_evals_size - (i << PE::LOG_WIDTH)) {
+					let index = (i << PE::LOG_WIDTH) | j;
+
+					let (eval0, eval1) = unsafe {
+						(
+							get_packed_slice_unchecked(evals, index << 1),
+							get_packed_slice_unchecked(evals, (index << 1) | 1),
+						)
+					};
+
+					let result_eval =
+						PE::Scalar::from(eval1 - eval0) * lerp_query + PE::Scalar::from(eval0);
+
+					// Safety: `j` < `PE::WIDTH`
+					unsafe {
+						packed_result_eval.set_unchecked(j, result_eval);
+					}
 				}
-			}
-		});
+			});
 
-	if evals_size % 2 == 1 {
-		let eval0 = get_packed_slice(evals, folded_evals_size << 1);
-		set_packed_slice(
-			out,
-			folded_evals_size,
-			PE::Scalar::from(suffix_eval - eval0) * lerp_query + PE::Scalar::from(eval0),
-		);
-	}
+		if evals_size % 2 == 1 {
+			let eval0 = get_packed_slice(evals, folded_evals_size << 1);
+			set_packed_slice(
+				out,
+				folded_evals_size,
+				PE::Scalar::from(suffix_eval - eval0) * lerp_query + PE::Scalar::from(eval0),
+			);
+		}
 
-	Ok(())
+		Ok(())
+	})
 }
 
 /// Left linear interpolation (lerp, single variable) fold
@@ -600,43 +610,48 @@ where
 {
 	check_left_lerp_fold_arguments::<_, P, _>(evals, non_const_prefix, log_evals_size, out)?;
 
-	if log_evals_size > P::LOG_WIDTH {
-		let pivot = non_const_prefix
-			.saturating_sub(1 << (log_evals_size - 1))
-			.div_ceil(P::WIDTH);
+	Dispatcher::get().run(|dispatcher| {
+		if log_evals_size > P::LOG_WIDTH {
+			let broadcast_lerp_query = P::broadcast(lerp_query);
+			let pivot = non_const_prefix
+				.saturating_sub(1 << (log_evals_size - 1))
+				.div_ceil(P::WIDTH);
 
-		let packed_len = 1 << (log_evals_size - 1 - P::LOG_WIDTH);
-		let upper_bound = non_const_prefix.div_ceil(P::WIDTH).min(packed_len);
+			let packed_len = 1 << (log_evals_size - 1 - P::LOG_WIDTH);
+			let upper_bound = non_const_prefix.div_ceil(P::WIDTH).min(packed_len);
 
-		if pivot > 0 {
-			let (evals_0, evals_1) = evals.split_at(packed_len);
-			for (out, eval_0, eval_1) in izip!(&mut out[..pivot], evals_0, evals_1) {
-				out.write(*eval_0 + (*eval_1 - *eval_0) * lerp_query);
+			if pivot > 0 {
+				let (evals_0, evals_1) = evals.split_at(packed_len);
+				for (out, eval_0, eval_1) in izip!(&mut out[..pivot], evals_0, evals_1) {
+					out.write(*eval_0 + dispatcher.mul(*eval_1 - *eval_0, broadcast_lerp_query));
+				}
 			}
-		}
 
-		let broadcast_suffix_eval = P::broadcast(suffix_eval);
-		for (out, eval) in izip!(&mut out[pivot..upper_bound], &evals[pivot..]) {
-			out.write(*eval + (broadcast_suffix_eval - *eval) * lerp_query);
-		}
+			let broadcast_suffix_eval = P::broadcast(suffix_eval);
+			for (out, eval) in izip!(&mut out[pivot..upper_bound], &evals[pivot..]) {
+				out.write(
+					*eval + dispatcher.mul(broadcast_suffix_eval - *eval, broadcast_lerp_query),
+				);
+			}
 
-		for out in &mut out[upper_bound..] {
-			out.write(P::zero());
-		}
-	} else if non_const_prefix > 0 {
-		let only_packed = *evals.first().expect("log_evals_size > 0");
-		let mut folded = P::zero();
-
-		for i in 0..1 << (log_evals_size - 1) {
-			let eval_0 = only_packed.get(i);
-			let eval_1 = only_packed.get(i | 1 << (log_evals_size - 1));
-			folded.set(i, eval_0 + lerp_query * (eval_1 - eval_0));
-		}
+			for out in &mut out[upper_bound..] {
+				out.write(P::zero());
+			}
+		} else if non_const_prefix > 0 {
+			let only_packed = *evals.first().expect("log_evals_size > 0");
+			let mut folded = P::zero();
+
+			for i in 0..1 << (log_evals_size - 1) {
+				let eval_0 = only_packed.get(i);
+				let eval_1 = only_packed.get(i | 1 << (log_evals_size - 1));
+				folded.set(i, eval_0 + lerp_query * (eval_1 - eval_0));
+			}
 
-		out.first_mut().expect("log_evals_size > 0").write(folded);
-	}
+			out.first_mut().expect("log_evals_size > 0").write(folded);
+		}
 
-	Ok(())
+		Ok(())
+	})
 }
 
 /// Inplace left linear interpolation (lerp, single variable) fold
@@ -657,42 +672,45 @@ where
 {
 	check_left_lerp_fold_arguments::<_, P, _>(evals, non_const_prefix, log_evals_size, evals)?;
 
-	if log_evals_size > P::LOG_WIDTH {
-		let pivot = non_const_prefix
-			.saturating_sub(1 << (log_evals_size - 1))
-			.div_ceil(P::WIDTH);
+	Dispatcher::get().run(|dispatcher| {
+		if log_evals_size > P::LOG_WIDTH {
+			let broadcast_lerp_query = P::broadcast(lerp_query);
+			let pivot = non_const_prefix
+				.saturating_sub(1 << (log_evals_size - 1))
+				.div_ceil(P::WIDTH);
 
-		let packed_len = 1 << (log_evals_size - 1 - P::LOG_WIDTH);
-		let upper_bound = non_const_prefix.div_ceil(P::WIDTH).min(packed_len);
+			let packed_len = 1 << (log_evals_size - 1 - P::LOG_WIDTH);
+			let upper_bound = non_const_prefix.div_ceil(P::WIDTH).min(packed_len);
 
-		if pivot > 0 {
-			let (evals_0, evals_1) = evals.split_at_mut(packed_len);
-			for (eval_0, eval_1) in izip!(&mut evals_0[..pivot], evals_1) {
-				*eval_0 += (*eval_1 - *eval_0) * lerp_query;
+			if pivot > 0 {
+				let (evals_0, evals_1) = evals.split_at_mut(packed_len);
+				for (eval_0, eval_1) in izip!(&mut evals_0[..pivot], evals_1) {
+					*eval_0 += dispatcher.mul(*eval_1 - *eval_0, broadcast_lerp_query);
+				}
 			}
-		}
 
-		let broadcast_suffix_eval = P::broadcast(suffix_eval);
-		for eval in &mut evals[pivot..upper_bound] {
-			*eval += (broadcast_suffix_eval - *eval) * lerp_query;
-		}
+			let broadcast_suffix_eval = P::broadcast(suffix_eval);
+			for eval in &mut evals[pivot..upper_bound] {
+				*eval += dispatcher.mul(broadcast_suffix_eval - *eval, broadcast_lerp_query);
+			}
 
-		evals.truncate(upper_bound);
-	} else if non_const_prefix > 0 {
-		let only_packed = evals.first_mut().expect("log_evals_size > 0");
-		let mut folded = P::zero();
-		let half_size = 1 << (log_evals_size - 1);
+			evals.truncate(upper_bound);
+		} else if non_const_prefix > 0 {
+			let only_packed = evals.first_mut().expect("log_evals_size > 0");
+			let mut folded = P::zero();
+			let half_size = 1 << (log_evals_size - 1);
 
-		for i in 0..half_size {
-			let eval_0 = only_packed.get(i);
-			let eval_1 = only_packed.get(i | half_size);
-			folded.set(i, eval_0 + lerp_query * (eval_1 - eval_0));
-		}
+			for i in 0..half_size {
+				let eval_0 = only_packed.get(i);
+				let eval_1 = only_packed.get(i | half_size);
+				folded.set(i, eval_0 + lerp_query * (eval_1 - eval_0));
+			}
 
-		*only_packed = folded;
-	}
+			*only_packed = folded;
+		}
 
-	Ok(())
+		Ok(())
+	})
 }
 
 /// Fallback implementation for fold that can be executed for any field types and sizes.
@@ -4,11 +4,12 @@ use std::iter::repeat_with;
 
 use binius_field::{
 	AESTowerField32b, AESTowerField128b, BinaryField32b, BinaryField128b, BinaryField128bPolyval,
-	PackedExtension, TowerField,
+	PackedExtension, PackedField, TowerField,
 	arch::{OptimalUnderlier, OptimalUnderlierByteSliced},
 	as_packed_field::PackedType,
 };
-use binius_ntt::{AdditiveNTT, NTTShape, SingleThreadedNTT};
+use binius_ntt::{AdditiveNTT, FourStepNTT, NTTShape, SingleThreadedNTT};
+use binius_utils::rayon::get_log_max_threads;
 use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
 
 fn bench_large_transform<F: TowerField, PE: PackedExtension<F>>(c: &mut Criterion, field: &str) {
@@ -73,9 +74,61 @@ fn bench_packed128b(c: &mut Criterion) {
 	>(c, "field=BinaryField128bPolyval");
 }
 
+/// Compares the four-step NTT with the layer-by-layer implementations on growing domains, to
+/// find the size from which the cache-blocked algorithm pays off.
+fn bench_four_step_crossover(c: &mut Criterion) {
+	type F = BinaryField32b;
+	type PE = PackedType<OptimalUnderlier, BinaryField128b>;
+
+	let mut group = c.benchmark_group("NTT crossover");
+	for log_dim in [14, 16, 18, 20, 22, 24] {
+		let data_len = 1 << (log_dim - PE::LOG_WIDTH);
+		let mut rng = rand::rng();
+		let mut data = repeat_with(|| PE::random(&mut rng))
+			.take(data_len)
+			.collect::<Vec<_>>();
+
+		group.throughput(Throughput::Bytes((data_len * size_of::<PE>()) as u64));
+
+		let shape = NTTShape {
+			log_y: log_dim,
+			..Default::default()
+		};
+
+		let ntt = SingleThreadedNTT::<F>::new(log_dim)
+			.unwrap()
+			.precompute_twiddles();
+		group.bench_function(BenchmarkId::new("single-thread/precompute", log_dim), |b| {
+			b.iter(|| ntt.forward_transform_ext(&mut data, shape, 0, 0, 0));
+		});
+
+		let ntt = ntt.four_step(FourStepNTT::<F>::DEFAULT_LOG_BLOCK_BYTES);
+		group.bench_function(BenchmarkId::new("four-step/precompute", log_dim), |b| {
+			b.iter(|| ntt.forward_transform_ext(&mut data, shape, 0, 0, 0));
+		});
+
+		let ntt = SingleThreadedNTT::<F>::new(log_dim)
+			.unwrap()
+			.precompute_twiddles()
+			.multithreaded();
+		group.bench_function(BenchmarkId::new("multithread/precompute", log_dim), |b| {
+			b.iter(|| ntt.forward_transform_ext(&mut data, shape, 0, 0, 0));
+		});
+
+		let ntt = SingleThreadedNTT::<F>::new(log_dim)
+			.unwrap()
+			.precompute_twiddles()
+			.four_step(FourStepNTT::<F>::DEFAULT_LOG_BLOCK_BYTES)
+			.with_max_threads(get_log_max_threads());
+		group.bench_function(BenchmarkId::new("four-step-multithread/precompute", log_dim), |b| {
+			b.iter(|| ntt.forward_transform_ext(&mut data, shape, 0, 0, 0));
+		});
+	}
+}
+
 criterion_group! {
 	name = large_transform;
 	config = Criterion::default().sample_size(10);
-	targets = bench_packed128b, bench_byte_sliced
+	targets = bench_packed128b, bench_byte_sliced, bench_four_step_crossover
 }
 criterion_main!(large_transform);
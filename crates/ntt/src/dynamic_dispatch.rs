@@ -7,6 +7,7 @@ use binius_utils::rayon::get_log_max_threads;
 use super::{
 	additive_ntt::{AdditiveNTT, NTTShape},
 	error::Error,
+	four_step::FourStepNTT,
 	multithreaded::MultithreadedNTT,
 	single_threaded::SingleThreadedNTT,
 	twiddle::PrecomputedTwiddleAccess,
@@ -48,6 +49,12 @@ impl ThreadingSettings {
 pub struct NTTOptions {
 	pub precompute_twiddles: bool,
 	pub thread_settings: ThreadingSettings,
+	/// Use the cache-blocked [`FourStepNTT`] with sub-transforms of at most
+	/// `1 << log_block_bytes` bytes instead of the layer-by-layer algorithms.
+	///
+	/// This pays off for transforms much larger than the cache, see the `large_transform`
+	/// benchmark for the crossover point on a given machine.
+	pub four_step_log_block_bytes: Option<usize>,
 }
 
 /// An enum that can be used to switch between different NTT implementations without passing
@@ -58,12 +65,28 @@ pub enum DynamicDispatchNTT<F: BinaryField> {
 	SingleThreadedPrecompute(SingleThreadedNTT<F, PrecomputedTwiddleAccess<F>>),
 	MultiThreaded(MultithreadedNTT<F>),
 	MultiThreadedPrecompute(MultithreadedNTT<F, PrecomputedTwiddleAccess<F>>),
+	FourStep(FourStepNTT<F>),
+	FourStepPrecompute(FourStepNTT<F, PrecomputedTwiddleAccess<F>>),
 }
 
 impl<F: BinaryField> DynamicDispatchNTT<F> {
 	/// Create a new AdditiveNTT based on the given settings.
 	pub fn new(log_domain_size: usize, options: &NTTOptions) -> Result<Self, Error> {
 		let log_threads = options.thread_settings.log_threads_count();
+		if let Some(log_block_bytes) = options.four_step_log_block_bytes {
+			let ntt = SingleThreadedNTT::new(log_domain_size)?;
+			let result = if options.precompute_twiddles {
+				Self::FourStepPrecompute(
+					ntt.precompute_twiddles()
+						.four_step(log_block_bytes)
+						.with_max_threads(log_threads),
+				)
+			} else {
+				Self::FourStep(ntt.four_step(log_block_bytes).with_max_threads(log_threads))
+			};
+			return Ok(result);
+		}
+
 		let result = match (options.precompute_twiddles, log_threads) {
 			(false, 0) => Self::SingleThreaded(SingleThreadedNTT::new(log_domain_size)?),
 			(true, 0) => Self::SingleThreadedPrecompute(
@@ -91,6 +114,8 @@ impl<F: BinaryField> AdditiveNTT<F> for DynamicDispatchNTT<F> {
 			Self::SingleThreadedPrecompute(ntt) => ntt.log_domain_size(),
 			Self::MultiThreaded(ntt) => ntt.log_domain_size(),
 			Self::MultiThreadedPrecompute(ntt) => ntt.log_domain_size(),
+			Self::FourStep(ntt) => ntt.log_domain_size(),
+			Self::FourStepPrecompute(ntt) => ntt.log_domain_size(),
 		}
 	}
 
@@ -100,6 +125,8 @@ impl<F: BinaryField> AdditiveNTT<F> for DynamicDispatchNTT<F> {
 			Self::SingleThreadedPrecompute(ntt) => ntt.subspace(i),
 			Self::MultiThreaded(ntt) => ntt.subspace(i),
 			Self::MultiThreadedPrecompute(ntt) => ntt.subspace(i),
+			Self::FourStep(ntt) => ntt.subspace(i),
+			Self::FourStepPrecompute(ntt) => ntt.subspace(i),
 		}
 	}
 
@@ -109,6 +136,8 @@ impl<F: BinaryField> AdditiveNTT<F> for DynamicDispatchNTT<F> {
 			Self::SingleThreadedPrecompute(ntt) => ntt.get_subspace_eval(i, j),
 			Self::MultiThreaded(ntt) => ntt.get_subspace_eval(i, j),
 			Self::MultiThreadedPrecompute(ntt) => ntt.get_subspace_eval(i, j),
+			Self::FourStep(ntt) => ntt.get_subspace_eval(i, j),
+			Self::FourStepPrecompute(ntt) => ntt.get_subspace_eval(i, j),
 		}
 	}
 
@@ -133,6 +162,12 @@ impl<F: BinaryField> AdditiveNTT<F> for DynamicDispatchNTT<F> {
 			Self::MultiThreadedPrecompute(ntt) => {
 				ntt.forward_transform(data, shape, coset, coset_bits, skip_rounds)
 			}
+			Self::FourStep(ntt) => {
+				ntt.forward_transform(data, shape, coset, coset_bits, skip_rounds)
+			}
+			Self::FourStepPrecompute(ntt) => {
+				ntt.forward_transform(data, shape, coset, coset_bits, skip_rounds)
+			}
 		}
 	}
 
@@ -157,6 +192,12 @@ impl<F: BinaryField> AdditiveNTT<F> for DynamicDispatchNTT<F> {
 			Self::MultiThreadedPrecompute(ntt) => {
 				ntt.inverse_transform(data, shape, coset, coset_bits, skip_rounds)
 			}
+			Self::FourStep(ntt) => {
+				ntt.inverse_transform(data, shape, coset, coset_bits, skip_rounds)
+			}
+			Self::FourStepPrecompute(ntt) => {
+				ntt.inverse_transform(data, shape, coset, coset_bits, skip_rounds)
+			}
 		}
 	}
 }
@@ -176,12 +217,14 @@ mod tests {
 		let ntt = make_ntt(&NTTOptions {
 			precompute_twiddles: false,
 			thread_settings: ThreadingSettings::SingleThreaded,
+			..Default::default()
 		});
 		assert!(matches!(ntt, DynamicDispatchNTT::SingleThreaded(_)));
 
 		let ntt = make_ntt(&NTTOptions {
 			precompute_twiddles: true,
 			thread_settings: ThreadingSettings::SingleThreaded,
+			..Default::default()
 		});
 		assert!(matches!(ntt, DynamicDispatchNTT::SingleThreadedPrecompute(_)));
 
@@ -189,6 +232,7 @@ mod tests {
 		let ntt = make_ntt(&NTTOptions {
 			precompute_twiddles: false,
 			thread_settings: ThreadingSettings::MultithreadedDefault,
+			..Default::default()
 		});
 		if multithreaded {
 			assert!(matches!(ntt, DynamicDispatchNTT::MultiThreaded(_)));
@@ -199,6 +243,7 @@ mod tests {
 		let ntt = make_ntt(&NTTOptions {
 			precompute_twiddles: true,
 			thread_settings: ThreadingSettings::MultithreadedDefault,
+			..Default::default()
 		});
 		if multithreaded {
 			assert!(matches!(ntt, DynamicDispatchNTT::MultiThreadedPrecompute(_)));
@@ -209,19 +254,36 @@ mod tests {
 		let ntt = make_ntt(&NTTOptions {
 			precompute_twiddles: false,
 			thread_settings: ThreadingSettings::ExplicitThreadsCount { log_threads: 2 },
+			..Default::default()
 		});
 		assert!(matches!(ntt, DynamicDispatchNTT::MultiThreaded(_)));
 
 		let ntt = make_ntt(&NTTOptions {
 			precompute_twiddles: true,
 			thread_settings: ThreadingSettings::ExplicitThreadsCount { log_threads: 0 },
+			..Default::default()
 		});
 		assert!(matches!(ntt, DynamicDispatchNTT::SingleThreadedPrecompute(_)));
 
 		let ntt = make_ntt(&NTTOptions {
 			precompute_twiddles: false,
 			thread_settings: ThreadingSettings::ExplicitThreadsCount { log_threads: 0 },
+			..Default::default()
 		});
 		assert!(matches!(ntt, DynamicDispatchNTT::SingleThreaded(_)));
+
+		let ntt = make_ntt(&NTTOptions {
+			precompute_twiddles: false,
+			four_step_log_block_bytes: Some(10),
+			..Default::default()
+		});
+		assert!(matches!(ntt, DynamicDispatchNTT::FourStep(_)));
+
+		let ntt = make_ntt(&NTTOptions {
+			precompute_twiddles: true,
+			thread_settings: ThreadingSettings::MultithreadedDefault,
+			four_step_log_block_bytes: Some(10),
+		});
+		assert!(matches!(ntt, DynamicDispatchNTT::FourStepPrecompute(_)));
 	}
 }
@@ -24,6 +24,8 @@ pub enum Error {
 	CosetIndexOutOfBounds { coset: usize, coset_bits: usize },
 	#[error("odd interpolation length mismatch, expected to be exactly {expected_len}")]
 	OddInterpolateIncorrectLength { expected_len: usize },
+	#[error("division by the zero polynomial")]
+	DivisionByZero,
 	#[error("math error: {0}")]
 	MathError(#[from] binius_math::Error),
 }
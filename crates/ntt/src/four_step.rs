@@ -0,0 +1,331 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::mem;
+
+use binius_field::{BinaryField, PackedField};
+use binius_math::BinarySubspace;
+use binius_maybe_rayon::prelude::*;
+use binius_utils::strided_array::StridedArray2DViewMut;
+
+use super::{
+	additive_ntt::{AdditiveNTT, NTTShape},
+	error::Error,
+	single_threaded::{SingleThreadedNTT, check_batch_transform_inputs_and_params},
+	twiddle::TwiddleAccess,
+};
+use crate::twiddle::OnTheFlyTwiddleAccess;
+
+/// Implementation of `AdditiveNTT` that splits large transforms into sub-transforms which fit in
+/// the CPU cache.
+///
+/// The layer-by-layer algorithms of [`SingleThreadedNTT`] and [`crate::MultithreadedNTT`] make a
+/// pass over the whole buffer for every layer of the butterfly network, which is bound by memory
+/// bandwidth once the buffer is much larger than the cache. This implementation uses the blocked
+/// "four-step" decomposition instead. The NTT axis of length $2^{\ell}$ is viewed as a matrix
+/// with $2^{\ell - h}$ rows of $2^h$ elements, where $h$ is chosen so that a row fits in a cache
+/// block.
+///
+/// 1. Blocks of columns are transposed into a scratch buffer, transformed by the top $\ell - h$
+///    layers of the network and transposed back. A column block is sized to fit in a cache block,
+///    and the column transforms are themselves split recursively if a column is too long.
+/// 2. Every row is transformed by the bottom $h$ layers of the network in place.
+///
+/// In the additive NTT the twiddle factors of the bottom layers depend on the row index only
+/// through the coset of the subspace, so the twiddle step of the classic four-step algorithm
+/// amounts to transforming row $i$ on coset $i$ of the row-sized subspace. The inverse transform
+/// performs the two phases in the opposite order.
+#[derive(Debug)]
+pub struct FourStepNTT<F: BinaryField, TA: TwiddleAccess<F> = OnTheFlyTwiddleAccess<F, Vec<F>>> {
+	single_threaded: SingleThreadedNTT<F, TA>,
+	log_block_bytes: usize,
+	log_max_threads: usize,
+}
+
+impl<F: BinaryField, TA: TwiddleAccess<F> + Sync> SingleThreadedNTT<F, TA> {
+	/// Returns the four-step NTT implementation with sub-transforms of at most
+	/// `1 << log_block_bytes` bytes, running on a single thread.
+	pub const fn four_step(self, log_block_bytes: usize) -> FourStepNTT<F, TA> {
+		FourStepNTT {
+			single_threaded: self,
+			log_block_bytes,
+			log_max_threads: 0,
+		}
+	}
+}
+
+impl<F: BinaryField, TA: TwiddleAccess<F>> FourStepNTT<F, TA> {
+	/// The default base-2 logarithm of the sub-transform size in bytes, which fits in the L2
+	/// cache of most CPUs.
+	pub const DEFAULT_LOG_BLOCK_BYTES: usize = 18;
+
+	/// Returns the implementation that processes independent sub-transforms on
+	/// `1 << log_max_threads` threads.
+	pub fn with_max_threads(self, log_max_threads: usize) -> Self {
+		Self {
+			log_max_threads,
+			..self
+		}
+	}
+
+	pub const fn log_block_bytes(&self) -> usize {
+		self.log_block_bytes
+	}
+}
+
+/// The decomposition of a transform into column and row sub-transforms.
+#[derive(Debug, Clone, Copy)]
+struct Split {
+	/// Base-2 logarithm of the row length, which is the number of bottom layers of the network
+	/// performed by the row transforms.
+	log_row_len: usize,
+	/// Base-2 logarithm of the number of packed elements in a column block.
+	log_stride_len: usize,
+}
+
+impl<F: BinaryField, TA: TwiddleAccess<F> + Sync> FourStepNTT<F, TA> {
+	/// Decides how to split a transform, or returns `None` if it should be performed by the
+	/// layer-by-layer algorithm.
+	fn split<P: PackedField>(&self, shape: NTTShape) -> Option<Split> {
+		let NTTShape { log_x, log_y, .. } = shape;
+		let log_w = P::LOG_WIDTH;
+
+		// The number of scalars in a packed element of the sub-transforms which fit in a block.
+		let log_block_len = self
+			.log_block_bytes
+			.saturating_sub(mem::size_of::<P>().ilog2() as usize)
+			+ log_w;
+		if log_x + log_y <= log_block_len {
+			return None;
+		}
+
+		// Rows must span at least two packed elements so that the row transforms do not need the
+		// special handling of single packed elements.
+		let log_row_len = log_block_len
+			.saturating_sub(log_x)
+			.max((log_w + 1).saturating_sub(log_x))
+			.max(1);
+		if log_row_len >= log_y {
+			return None;
+		}
+
+		let log_height = log_y - log_row_len;
+		let log_stride_len = (log_block_len - log_w)
+			.saturating_sub(log_height)
+			.min(log_x + log_row_len - log_w);
+		Some(Split {
+			log_row_len,
+			log_stride_len,
+		})
+	}
+
+	/// Applies `f` to every column block of every Z slice of the data.
+	fn for_each_column_block<P: PackedField>(
+		&self,
+		data: &mut [P],
+		shape: NTTShape,
+		split: Split,
+		f: impl Fn(StridedArray2DViewMut<P>) -> Result<(), Error> + Send + Sync,
+	) -> Result<(), Error> {
+		let NTTShape { log_x, log_y, .. } = shape;
+		let log_width = log_x + split.log_row_len - P::LOG_WIDTH;
+		let log_height = log_y - split.log_row_len;
+		let matrix = |slice| {
+			StridedArray2DViewMut::without_stride(slice, 1 << log_height, 1 << log_width)
+				.expect("dimensions are correct")
+		};
+
+		let slice_len = 1 << (log_width + log_height);
+		if self.log_max_threads == 0 {
+			data.chunks_mut(slice_len)
+				.flat_map(|slice| matrix(slice).into_strides(1 << split.log_stride_len))
+				.try_for_each(f)
+		} else {
+			data.par_chunks_mut(slice_len)
+				.flat_map(|slice| matrix(slice).into_par_strides(1 << split.log_stride_len))
+				.try_for_each(f)
+		}
+	}
+
+	/// Applies `f` to every row of the data along with its index within the Z slice.
+	fn for_each_row<P: PackedField>(
+		&self,
+		data: &mut [P],
+		shape: NTTShape,
+		split: Split,
+		f: impl Fn(usize, &mut [P]) -> Result<(), Error> + Send + Sync,
+	) -> Result<(), Error> {
+		let NTTShape { log_x, log_y, .. } = shape;
+		let log_row_packed_len = log_x + split.log_row_len - P::LOG_WIDTH;
+		let row_mask = (1 << (log_y - split.log_row_len)) - 1;
+
+		if self.log_max_threads == 0 {
+			data.chunks_mut(1 << log_row_packed_len)
+				.enumerate()
+				.try_for_each(|(i, row)| f(i & row_mask, row))
+		} else {
+			data.par_chunks_mut(1 << log_row_packed_len)
+				.enumerate()
+				.try_for_each(|(i, row)| f(i & row_mask, row))
+		}
+	}
+
+	/// Transforms a column block by gathering it into a contiguous buffer.
+	fn transform_column_block<P: PackedField<Scalar = F>>(
+		&self,
+		mut block: StridedArray2DViewMut<P>,
+		log_height: usize,
+		transform: impl FnOnce(&mut [P], NTTShape) -> Result<(), Error>,
+	) -> Result<(), Error> {
+		let width = block.width();
+		let mut buffer = Vec::with_capacity(block.height() * width);
+		for i in 0..block.height() {
+			buffer.extend((0..width).map(|j| block[(i, j)]));
+		}
+
+		let shape = NTTShape {
+			log_x: width.ilog2() as usize + P::LOG_WIDTH,
+			log_y: log_height,
+			log_z: 0,
+		};
+		transform(&mut buffer, shape)?;
+
+		for (i, row) in buffer.chunks_exact(width).enumerate() {
+			for (j, &value) in row.iter().enumerate() {
+				block[(i, j)] = value;
+			}
+		}
+		Ok(())
+	}
+}
+
+impl<F, TA> AdditiveNTT<F> for FourStepNTT<F, TA>
+where
+	F: BinaryField,
+	TA: TwiddleAccess<F> + Sync,
+{
+	fn log_domain_size(&self) -> usize {
+		self.single_threaded.log_domain_size()
+	}
+
+	fn subspace(&self, i: usize) -> BinarySubspace<F> {
+		self.single_threaded.subspace(i)
+	}
+
+	fn get_subspace_eval(&self, i: usize, j: usize) -> F {
+		self.single_threaded.get_subspace_eval(i, j)
+	}
+
+	fn forward_transform<P: PackedField<Scalar = F>>(
+		&self,
+		data: &mut [P],
+		shape: NTTShape,
+		coset: usize,
+		coset_bits: usize,
+		skip_rounds: usize,
+	) -> Result<(), Error> {
+		check_batch_transform_inputs_and_params(
+			self.log_domain_size(),
+			data,
+			shape,
+			coset,
+			coset_bits,
+			skip_rounds,
+		)?;
+
+		let Some(split) = self.split::<P>(shape) else {
+			return self.single_threaded.forward_transform(
+				data,
+				shape,
+				coset,
+				coset_bits,
+				skip_rounds,
+			);
+		};
+		let log_height = shape.log_y - split.log_row_len;
+
+		// The top layers of the network transform the columns.
+		self.for_each_column_block(data, shape, split, |block| {
+			self.transform_column_block(block, log_height, |buffer, shape| {
+				self.forward_transform(
+					buffer,
+					shape,
+					coset,
+					coset_bits,
+					skip_rounds.min(log_height),
+				)
+			})
+		})?;
+
+		// The bottom layers of the network transform each row on its own coset.
+		self.for_each_row(data, shape, split, |i, row| {
+			self.single_threaded.forward_transform(
+				row,
+				NTTShape {
+					log_x: shape.log_x,
+					log_y: split.log_row_len,
+					log_z: 0,
+				},
+				coset << log_height | i,
+				coset_bits + log_height,
+				skip_rounds.saturating_sub(log_height),
+			)
+		})
+	}
+
+	fn inverse_transform<P: PackedField<Scalar = F>>(
+		&self,
+		data: &mut [P],
+		shape: NTTShape,
+		coset: usize,
+		coset_bits: usize,
+		skip_rounds: usize,
+	) -> Result<(), Error> {
+		check_batch_transform_inputs_and_params(
+			self.log_domain_size(),
+			data,
+			shape,
+			coset,
+			coset_bits,
+			skip_rounds,
+		)?;
+
+		let Some(split) = self.split::<P>(shape) else {
+			return self.single_threaded.inverse_transform(
+				data,
+				shape,
+				coset,
+				coset_bits,
+				skip_rounds,
+			);
+		};
+		let log_height = shape.log_y - split.log_row_len;
+
+		// The bottom layers of the network transform each row on its own coset.
+		self.for_each_row(data, shape, split, |i, row| {
+			self.single_threaded.inverse_transform(
+				row,
+				NTTShape {
+					log_x: shape.log_x,
+					log_y: split.log_row_len,
+					log_z: 0,
+				},
+				coset << log_height | i,
+				coset_bits + log_height,
+				skip_rounds.saturating_sub(log_height),
+			)
+		})?;
+
+		// The top layers of the network transform the columns.
+		self.for_each_column_block(data, shape, split, |block| {
+			self.transform_column_block(block, log_height, |buffer, shape| {
+				self.inverse_transform(
+					buffer,
+					shape,
+					coset,
+					coset_bits,
+					skip_rounds.min(log_height),
+				)
+			})
+		})
+	}
+}
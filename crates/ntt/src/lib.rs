@@ -10,6 +10,7 @@
 mod additive_ntt;
 mod dynamic_dispatch;
 mod error;
+mod four_step;
 pub mod fri;
 mod multithreaded;
 mod odd_interpolate;
@@ -17,10 +18,13 @@ mod single_threaded;
 #[cfg(test)]
 mod tests;
 pub mod twiddle;
+mod univariate;
 
 pub use additive_ntt::{AdditiveNTT, NTTShape};
 pub use dynamic_dispatch::{DynamicDispatchNTT, NTTOptions, ThreadingSettings};
 pub use error::Error;
+pub use four_step::FourStepNTT;
 pub use multithreaded::MultithreadedNTT;
 pub use odd_interpolate::OddInterpolate;
 pub use single_threaded::SingleThreadedNTT;
+pub use univariate::UnivariateArithmetic;
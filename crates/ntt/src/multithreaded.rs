@@ -170,37 +170,40 @@ where
 		let log_domain_size = subntt.log_domain_size();
 		let s_evals = &subntt.twiddles()[log_domain_size - (par_rounds + coset_bits)..];
 
+		let dispatcher = subntt.dispatcher();
 		matrix
 			.into_par_strides(1 << log_stride_len)
 			.for_each(|mut stride| {
-				// i indexes the layer of the NTT network, also the binary subspace.
-				for i in (0..par_rounds.saturating_sub(skip_rounds)).rev() {
-					let s_evals_par_i = &s_evals[i];
-					let coset_offset = coset << (par_rounds - 1 - i);
-
-					// j indexes the outer Z tensor axis.
-					for j in 0..1 << log_z {
-						// k indexes the block within the layer. Each block performs butterfly
-						// operations with the same twiddle factor.
-						for k in 0..1 << (par_rounds - 1 - i) {
-							let twiddle = P::broadcast(s_evals_par_i.get(coset_offset | k));
-							// l indexes parallel stride columns
-							for l in 0..1 << i {
-								for m in 0..1 << log_stride_len {
-									let idx0 = j << par_rounds | k << (i + 1) | l;
-									let idx1 = idx0 | 1 << i;
-
-									let mut u = stride[(idx0, m)];
-									let mut v = stride[(idx1, m)];
-									u += v * twiddle;
-									v += u;
-									stride[(idx0, m)] = u;
-									stride[(idx1, m)] = v;
+				dispatcher.run(|dispatcher| {
+					// i indexes the layer of the NTT network, also the binary subspace.
+					for i in (0..par_rounds.saturating_sub(skip_rounds)).rev() {
+						let s_evals_par_i = &s_evals[i];
+						let coset_offset = coset << (par_rounds - 1 - i);
+
+						// j indexes the outer Z tensor axis.
+						for j in 0..1 << log_z {
+							// k indexes the block within the layer. Each block performs butterfly
+							// operations with the same twiddle factor.
+							for k in 0..1 << (par_rounds - 1 - i) {
+								let twiddle = P::broadcast(s_evals_par_i.get(coset_offset | k));
+								// l indexes parallel stride columns
+								for l in 0..1 << i {
+									for m in 0..1 << log_stride_len {
+										let idx0 = j << par_rounds | k << (i + 1) | l;
+										let idx1 = idx0 | 1 << i;
+
+										let mut u = stride[(idx0, m)];
+										let mut v = stride[(idx1, m)];
+										u += dispatcher.mul(v, twiddle);
+										v += u;
+										stride[(idx0, m)] = u;
+										stride[(idx1, m)] = v;
+									}
 								}
 							}
 						}
 					}
-				}
+				})
 			});
 	}
 
@@ -318,38 +321,41 @@ where
 	let log_domain_size = subntt.log_domain_size();
 	let s_evals = &subntt.twiddles()[log_domain_size - (par_rounds + coset_bits)..];
 
+	let dispatcher = subntt.dispatcher();
 	matrix
 		.into_par_strides(1 << log_stride_len)
 		.for_each(|mut stride| {
-			// i indexes the layer of the NTT network, also the binary subspace.
-			#[allow(clippy::needless_range_loop)]
-			for i in 0..par_rounds.saturating_sub(skip_rounds) {
-				let s_evals_par_i = &s_evals[i];
-				let coset_offset = coset << (par_rounds - 1 - i);
-
-				// j indexes the outer Z tensor axis.
-				for j in 0..1 << log_z {
-					// k indexes the block within the layer. Each block performs butterfly
-					// operations with the same twiddle factor.
-					for k in 0..1 << (par_rounds - 1 - i) {
-						let twiddle = P::broadcast(s_evals_par_i.get(coset_offset | k));
-						// l indexes parallel stride columns
-						for l in 0..1 << i {
-							for m in 0..1 << log_stride_len {
-								let idx0 = j << par_rounds | k << (i + 1) | l;
-								let idx1 = idx0 | 1 << i;
-
-								let mut u = stride[(idx0, m)];
-								let mut v = stride[(idx1, m)];
-								v += u;
-								u += v * twiddle;
-								stride[(idx0, m)] = u;
-								stride[(idx1, m)] = v;
+			dispatcher.run(|dispatcher| {
+				// i indexes the layer of the NTT network, also the binary subspace.
+				#[allow(clippy::needless_range_loop)]
+				for i in 0..par_rounds.saturating_sub(skip_rounds) {
+					let s_evals_par_i = &s_evals[i];
+					let coset_offset = coset << (par_rounds - 1 - i);
+
+					// j indexes the outer Z tensor axis.
+					for j in 0..1 << log_z {
+						// k indexes the block within the layer. Each block performs butterfly
+						// operations with the same twiddle factor.
+						for k in 0..1 << (par_rounds - 1 - i) {
+							let twiddle = P::broadcast(s_evals_par_i.get(coset_offset | k));
+							// l indexes parallel stride columns
+							for l in 0..1 << i {
+								for m in 0..1 << log_stride_len {
+									let idx0 = j << par_rounds | k << (i + 1) | l;
+									let idx1 = idx0 | 1 << i;
+
+									let mut u = stride[(idx0, m)];
+									let mut v = stride[(idx1, m)];
+									v += u;
+									u += dispatcher.mul(v, twiddle);
+									stride[(idx0, m)] = u;
+									stride[(idx1, m)] = v;
+								}
 							}
 						}
 					}
 				}
-			}
+			})
 		});
 
 	Ok(())
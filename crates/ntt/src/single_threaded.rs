@@ -2,7 +2,7 @@
 
 use std::{cmp, marker::PhantomData};
 
-use binius_field::{BinaryField, PackedField, TowerField};
+use binius_field::{BinaryField, PackedField, TowerField, arch::dispatch::Dispatcher};
 use binius_math::BinarySubspace;
 use binius_utils::bail;
 
@@ -18,6 +18,7 @@ use crate::twiddle::{OnTheFlyTwiddleAccess, PrecomputedTwiddleAccess, expand_sub
 pub struct SingleThreadedNTT<F: BinaryField, TA: TwiddleAccess<F> = OnTheFlyTwiddleAccess<F>> {
 	// TODO: Figure out how to make this private, it should not be `pub(super)`.
 	pub(super) s_evals: Vec<TA>,
+	dispatcher: Dispatcher,
 	_marker: PhantomData<F>,
 }
 
@@ -59,9 +60,10 @@ impl<F: TowerField> SingleThreadedNTT<F> {
 }
 
 impl<F: BinaryField, TA: TwiddleAccess<F>> SingleThreadedNTT<F, TA> {
-	const fn with_twiddle_access(twiddle_access: Vec<TA>) -> Self {
+	fn with_twiddle_access(twiddle_access: Vec<TA>) -> Self {
 		Self {
 			s_evals: twiddle_access,
+			dispatcher: Dispatcher::get(),
 			_marker: PhantomData,
 		}
 	}
@@ -71,6 +73,11 @@ impl<F: BinaryField, TA: TwiddleAccess<F>> SingleThreadedNTT<F, TA> {
 	pub fn twiddles(&self) -> &[TA] {
 		&self.s_evals
 	}
+
+	/// The dispatcher selecting the packed field arithmetic of the transforms.
+	pub const fn dispatcher(&self) -> Dispatcher {
+		self.dispatcher
+	}
 }
 
 impl<F, TA> AdditiveNTT<F> for SingleThreadedNTT<F, TA>
@@ -100,15 +107,18 @@ where
 		coset_bits: usize,
 		skip_rounds: usize,
 	) -> Result<(), Error> {
-		forward_transform(
-			self.log_domain_size(),
-			&self.s_evals,
-			data,
-			shape,
-			coset,
-			coset_bits,
-			skip_rounds,
-		)
+		self.dispatcher.run(|dispatcher| {
+			forward_transform(
+				dispatcher,
+				self.log_domain_size(),
+				&self.s_evals,
+				data,
+				shape,
+				coset,
+				coset_bits,
+				skip_rounds,
+			)
+		})
 	}
 
 	fn inverse_transform<P: PackedField<Scalar = F>>(
@@ -119,19 +129,24 @@ where
 		coset_bits: usize,
 		skip_rounds: usize,
 	) -> Result<(), Error> {
-		inverse_transform(
-			self.log_domain_size(),
-			&self.s_evals,
-			data,
-			shape,
-			coset,
-			coset_bits,
-			skip_rounds,
-		)
+		self.dispatcher.run(|dispatcher| {
+			inverse_transform(
+				dispatcher,
+				self.log_domain_size(),
+				&self.s_evals,
+				data,
+				shape,
+				coset,
+				coset_bits,
+				skip_rounds,
+			)
+		})
 	}
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn forward_transform<F: BinaryField, P: PackedField<Scalar = F>>(
+	dispatcher: Dispatcher,
 	log_domain_size: usize,
 	s_evals: &[impl TwiddleAccess<F>],
 	data: &mut [P],
@@ -163,6 +178,7 @@ pub fn forward_transform<F: BinaryField, P: PackedField<Scalar = F>>(
 					// data with dummy data and extracting the transformed result.
 					let mut buffer = [data[0], P::zero()];
 					forward_transform(
+						dispatcher,
 						log_domain_size,
 						s_evals,
 						&mut buffer,
@@ -205,11 +221,11 @@ pub fn forward_transform<F: BinaryField, P: PackedField<Scalar = F>>(
 			// k indexes the block within the layer. Each block performs butterfly operations with
 			// the same twiddle factor.
 			for k in 0..1 << (log_y - 1 - i) {
-				let twiddle = s_evals_i.get(coset_offset | k);
+				let twiddle = P::broadcast(s_evals_i.get(coset_offset | k));
 				for l in 0..1 << (i + log_x - log_w) {
 					let idx0 = j << (log_x + log_y - log_w) | k << (log_x + i + 1 - log_w) | l;
 					let idx1 = idx0 | 1 << (log_x + i - log_w);
-					data[idx0] += data[idx1] * twiddle;
+					data[idx0] += dispatcher.mul(data[idx1], twiddle);
 					data[idx1] += data[idx0];
 				}
 			}
@@ -235,7 +251,7 @@ pub fn forward_transform<F: BinaryField, P: PackedField<Scalar = F>>(
 					P::broadcast(s_evals_i.get(coset_offset | k << (cutoff - i))) + block_twiddle;
 				let index = k << 1 | j << (log_packed_count + 1);
 				let (mut u, mut v) = data[index].interleave(data[index | 1], log_block_len);
-				u += v * twiddle;
+				u += dispatcher.mul(v, twiddle);
 				v += u;
 				(data[index], data[index | 1]) = u.interleave(v, log_block_len);
 			}
@@ -245,7 +261,9 @@ pub fn forward_transform<F: BinaryField, P: PackedField<Scalar = F>>(
 	Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn inverse_transform<F: BinaryField, P: PackedField<Scalar = F>>(
+	dispatcher: Dispatcher,
 	log_domain_size: usize,
 	s_evals: &[impl TwiddleAccess<F>],
 	data: &mut [P],
@@ -277,6 +295,7 @@ pub fn inverse_transform<F: BinaryField, P: PackedField<Scalar = F>>(
 					// data with dummy data and extracting the transformed result.
 					let mut buffer = [data[0], P::zero()];
 					inverse_transform(
+						dispatcher,
 						log_domain_size,
 						s_evals,
 						&mut buffer,
@@ -330,7 +349,7 @@ pub fn inverse_transform<F: BinaryField, P: PackedField<Scalar = F>>(
 				let index = k << 1 | j << (log_packed_count + 1);
 				let (mut u, mut v) = data[index].interleave(data[index | 1], log_block_len);
 				v += u;
-				u += v * twiddle;
+				u += dispatcher.mul(v, twiddle);
 				(data[index], data[index | 1]) = u.interleave(v, log_block_len);
 			}
 		}
@@ -347,12 +366,12 @@ pub fn inverse_transform<F: BinaryField, P: PackedField<Scalar = F>>(
 			// k indexes the block within the layer. Each block performs butterfly operations with
 			// the same twiddle factor.
 			for k in 0..1 << (log_y - 1 - i) {
-				let twiddle = s_evals_i.get(coset_offset | k);
+				let twiddle = P::broadcast(s_evals_i.get(coset_offset | k));
 				for l in 0..1 << (i + log_x - log_w) {
 					let idx0 = j << (log_x + log_y - log_w) | k << (log_x + i + 1 - log_w) | l;
 					let idx1 = idx0 | 1 << (log_x + i - log_w);
 					data[idx1] += data[idx0];
-					data[idx0] += data[idx1] * twiddle;
+					data[idx0] += dispatcher.mul(data[idx1], twiddle);
 				}
 			}
 		}
@@ -597,7 +616,16 @@ mod tests {
 			log_y: 2,
 			log_z: 0,
 		};
-		let _ = forward_transform(s.log_domain_size(), &s.s_evals, &mut packed, shape, 3, 2, 0);
+		let _ = forward_transform(
+			s.dispatcher(),
+			s.log_domain_size(),
+			&s.s_evals,
+			&mut packed,
+			shape,
+			3,
+			2,
+			0,
+		);
 		let _ = s.forward_transform(unpacked, shape, 3, 2, 0);
 
 		for (i, unpacked_item) in unpacked.iter().enumerate().take(4) {
@@ -619,7 +647,16 @@ mod tests {
 			log_y: 2,
 			log_z: 0,
 		};
-		let _ = inverse_transform(s.log_domain_size(), &s.s_evals, &mut packed, shape, 3, 2, 0);
+		let _ = inverse_transform(
+			s.dispatcher(),
+			s.log_domain_size(),
+			&s.s_evals,
+			&mut packed,
+			shape,
+			3,
+			2,
+			0,
+		);
 		let _ = s.inverse_transform(unpacked, shape, 3, 2, 0);
 
 		for (i, unpacked_item) in unpacked.iter().enumerate().take(4) {
@@ -100,6 +100,15 @@ fn check_roundtrip_all_ntts<P>(
 	let dynamic_dispatch_ntt = DynamicDispatchNTT::SingleThreaded(
 		SingleThreadedNTT::<P::Scalar>::new(log_domain_size).unwrap(),
 	);
+	// Tiny blocks make the four-step NTT split even the small transforms of the tests.
+	let four_step_ntt = SingleThreadedNTT::<P::Scalar>::new(log_domain_size)
+		.unwrap()
+		.four_step(0);
+	let four_step_precompute_ntt_4 = SingleThreadedNTT::<P::Scalar>::new(log_domain_size)
+		.unwrap()
+		.precompute_twiddles()
+		.four_step(size_of::<P>().ilog2() as usize + 2)
+		.with_max_threads(2);
 
 	let mut rng = StdRng::seed_from_u64(0);
 	let data = (0..1u128 << log_data_size)
@@ -179,6 +188,24 @@ fn check_roundtrip_all_ntts<P>(
 						max_log_coset,
 						skip_rounds,
 					);
+					check_roundtrip_with_reference(
+						&simple_ntt,
+						&four_step_ntt,
+						&data,
+						shape,
+						cosets.clone(),
+						max_log_coset,
+						skip_rounds,
+					);
+					check_roundtrip_with_reference(
+						&simple_ntt,
+						&four_step_precompute_ntt_4,
+						&data,
+						shape,
+						cosets.clone(),
+						max_log_coset,
+						skip_rounds,
+					);
 				}
 			}
 		}
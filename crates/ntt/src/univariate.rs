@@ -0,0 +1,675 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{iter, mem};
+
+use binius_field::BinaryField;
+use binius_math::evaluate_univariate;
+use binius_utils::{bail, checked_arithmetics::log2_ceil_usize};
+
+use super::{
+	additive_ntt::{AdditiveNTT, NTTShape},
+	error::Error,
+};
+
+/// Operands with at most this many coefficients are multiplied and divided with the quadratic
+/// schoolbook algorithms.
+const SCHOOLBOOK_THRESHOLD: usize = 32;
+
+/// Subproduct trees are not split further below this many points.
+const SUBPRODUCT_LEAF_SIZE: usize = 16;
+
+/// Fast arithmetic on univariate polynomials over a binary field.
+///
+/// Polynomials are slices of coefficients in the monomial basis, starting with the constant term.
+/// Results never have trailing zero coefficients, so the zero polynomial is the empty vector.
+///
+/// Multiplication converts the operands into the novel polynomial basis of [LCH14], evaluates
+/// them on a subspace with the additive NTT, multiplies pointwise and interpolates back. The basis
+/// conversions take $O(n \log^2 n)$ field operations, which dominates the cost $M(n)$ of a
+/// multiplication. Division uses Newton iteration to invert the reversed divisor, and multipoint
+/// evaluation and interpolation use subproduct trees, so they take $O(M(n))$ and
+/// $O(M(n) \log n)$ operations respectively. The GCD is computed with the Euclidean algorithm in
+/// $O(n^2)$ operations.
+///
+/// Products of degree at least the size of the NTT domain are computed by splitting the operands
+/// into chunks whose products fit in the domain.
+///
+/// [LCH14]: <https://arxiv.org/abs/1404.3458>
+#[derive(Debug)]
+pub struct UnivariateArithmetic<F: BinaryField, NTT: AdditiveNTT<F>> {
+	ntt: NTT,
+	/// The subspace polynomials $W_i(X)$ for the basis $\beta_0, \ldots, \beta_{\ell-1}$ of the
+	/// NTT domain.
+	subspace_polys: Vec<SubspacePoly<F>>,
+}
+
+/// The unnormalized subspace polynomial $W_i(X) = \prod_{u \in U_i} (X - u)$, where $U_i$ is the
+/// span of $\beta_0, \ldots, \beta_{i-1}$.
+///
+/// $W_i$ is monic and $\mathbb{F}_2$-linear, so it is determined by its coefficients on the
+/// monomials $X^{2^j}$ for $j < i$.
+#[derive(Debug)]
+struct SubspacePoly<F> {
+	linear_coeffs: Vec<F>,
+	/// $W_i(\beta_i)$, the normalization constant of $\hat{W}_i$.
+	norm: F,
+	norm_inv: F,
+}
+
+impl<F: BinaryField, NTT: AdditiveNTT<F>> UnivariateArithmetic<F, NTT> {
+	pub fn new(ntt: NTT) -> Self {
+		let subspace = ntt.subspace(ntt.log_domain_size());
+
+		// $W_0(X) = X$ and $W_{i+1}(X) = W_i(X)^2 + W_i(\beta_i) W_i(X)$.
+		let mut linear_coeffs = vec![F::ONE];
+		let subspace_polys = subspace
+			.basis()
+			.iter()
+			.map(|&beta| {
+				let norm = iter::successors(Some(beta), |&x| Some(x.square()))
+					.zip(&linear_coeffs)
+					.map(|(x, &coeff)| x * coeff)
+					.sum::<F>();
+				let next_coeffs = iter::once(F::ZERO)
+					.chain(linear_coeffs.iter().map(|coeff| coeff.square()))
+					.zip(linear_coeffs.iter().chain(iter::once(&F::ZERO)))
+					.map(|(square, &coeff)| square + norm * coeff)
+					.collect();
+				let mut coeffs = mem::replace(&mut linear_coeffs, next_coeffs);
+				// Drop the leading coefficient, which is one.
+				coeffs.pop();
+				SubspacePoly {
+					linear_coeffs: coeffs,
+					norm,
+					norm_inv: norm
+						.invert()
+						.expect("basis elements are linearly independent"),
+				}
+			})
+			.collect();
+
+		Self {
+			ntt,
+			subspace_polys,
+		}
+	}
+
+	pub const fn ntt(&self) -> &NTT {
+		&self.ntt
+	}
+
+	/// Multiplies two polynomials.
+	pub fn mul(&self, a: &[F], b: &[F]) -> Vec<F> {
+		let (a, b) = (trim(a), trim(b));
+		if a.is_empty() || b.is_empty() {
+			return Vec::new();
+		}
+		if a.len().min(b.len()) <= SCHOOLBOOK_THRESHOLD || self.subspace_polys.is_empty() {
+			return mul_schoolbook(a, b);
+		}
+		self.mul_ntt(a, b)
+	}
+
+	/// Divides `a` by `b`, returning the quotient and the remainder.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::DivisionByZero`] if `b` is the zero polynomial.
+	pub fn div_rem(&self, a: &[F], b: &[F]) -> Result<(Vec<F>, Vec<F>), Error> {
+		let (a, b) = (trim(a), trim(b));
+		let Some(&lead) = b.last() else {
+			bail!(Error::DivisionByZero);
+		};
+		if a.len() < b.len() {
+			return Ok((Vec::new(), a.to_vec()));
+		}
+
+		let quotient_len = a.len() - b.len() + 1;
+		if quotient_len.min(b.len()) <= SCHOOLBOOK_THRESHOLD {
+			return Ok(div_rem_schoolbook(a, b));
+		}
+
+		// The reversal of the quotient is the reversal of `a` divided by the reversal of `b`, as
+		// power series truncated to the length of the quotient.
+		let b_rev = b.iter().rev().copied().collect::<Vec<_>>();
+		let a_rev = a
+			.iter()
+			.rev()
+			.take(quotient_len)
+			.copied()
+			.collect::<Vec<_>>();
+		let b_rev_inv = self.inverse_series(&b_rev, lead, quotient_len);
+		let mut quotient = self.mul(&a_rev, &b_rev_inv);
+		quotient.resize(quotient_len, F::ZERO);
+		quotient.reverse();
+
+		let mut remainder = a[..b.len() - 1].to_vec();
+		for (coeff, product_coeff) in remainder.iter_mut().zip(self.mul(&quotient, b)) {
+			*coeff -= product_coeff;
+		}
+		Ok((quotient, trim_vec(remainder)))
+	}
+
+	/// Evaluates a polynomial at every point.
+	pub fn evaluate_many(&self, coeffs: &[F], points: &[F]) -> Vec<F> {
+		if points.len() <= SUBPRODUCT_LEAF_SIZE {
+			return points
+				.iter()
+				.map(|&x| evaluate_univariate(coeffs, x))
+				.collect();
+		}
+
+		let tree = SubproductTree::new(self, points);
+		let mut evals = Vec::with_capacity(points.len());
+		self.evaluate_subtree(&tree, coeffs, &mut evals);
+		evals
+	}
+
+	/// Returns the polynomial of degree less than the number of points which takes the given
+	/// values at the given points.
+	///
+	/// ## Throws
+	///
+	/// * [`binius_math::Error::IncorrectArgumentLength`] if the number of values does not match the
+	///   number of points.
+	/// * [`binius_math::Error::DuplicateDomainPoint`] if the points are not distinct.
+	pub fn interpolate(&self, points: &[F], values: &[F]) -> Result<Vec<F>, Error> {
+		if values.len() != points.len() {
+			bail!(binius_math::Error::IncorrectArgumentLength {
+				arg: "values".into(),
+				expected: points.len(),
+			});
+		}
+		if points.is_empty() {
+			return Ok(Vec::new());
+		}
+
+		// The Lagrange polynomial of the point $x_i$ is $M(X) / ((X - x_i) M'(x_i))$, where $M$
+		// is the product of $X - x_j$ over all points.
+		let tree = SubproductTree::new(self, points);
+		let mut weights = Vec::with_capacity(points.len());
+		self.evaluate_subtree(&tree, &formal_derivative(&tree.product), &mut weights);
+		for (weight, &value) in iter::zip(&mut weights, values) {
+			*weight = value
+				* weight
+					.invert()
+					.ok_or(binius_math::Error::DuplicateDomainPoint)?;
+		}
+
+		Ok(trim_vec(self.combine_subtree(&tree, &weights)))
+	}
+
+	/// Returns the monic greatest common divisor of two polynomials, or the zero polynomial if
+	/// both are zero.
+	pub fn gcd(&self, a: &[F], b: &[F]) -> Vec<F> {
+		let (mut a, mut b) = (trim(a).to_vec(), trim(b).to_vec());
+		while !b.is_empty() {
+			let (_, remainder) = self.div_rem(&a, &b).expect("divisor is nonzero");
+			a = mem::replace(&mut b, remainder);
+		}
+
+		if let Some(lead) = a.last() {
+			let lead_inv = lead.invert().expect("leading coefficient is nonzero");
+			for coeff in &mut a {
+				*coeff *= lead_inv;
+			}
+		}
+		a
+	}
+
+	fn mul_ntt(&self, a: &[F], b: &[F]) -> Vec<F> {
+		let len = a.len() + b.len() - 1;
+		let log_domain_size = self.subspace_polys.len();
+
+		// If the product does not fit in the NTT domain, the operands are split into chunks whose
+		// products do.
+		let log_len = log2_ceil_usize(len).min(log_domain_size);
+		let chunk_len = if len <= 1 << log_len {
+			a.len().max(b.len())
+		} else {
+			1 << (log_len - 1)
+		};
+
+		let [a_evals, b_evals] = [a, b].map(|coeffs| {
+			coeffs
+				.chunks(chunk_len)
+				.map(|chunk| {
+					let mut evals = chunk.to_vec();
+					evals.resize(1 << log_len, F::ZERO);
+					self.forward_transform(&mut evals);
+					evals
+				})
+				.collect::<Vec<_>>()
+		});
+
+		let mut product = vec![F::ZERO; len];
+		for k in 0..a_evals.len() + b_evals.len() - 1 {
+			let mut evals = vec![F::ZERO; 1 << log_len];
+			for i in k.saturating_sub(b_evals.len() - 1)..=k.min(a_evals.len() - 1) {
+				for ((eval, &a_eval), &b_eval) in
+					iter::zip(&mut evals, &a_evals[i]).zip(&b_evals[k - i])
+				{
+					*eval += a_eval * b_eval;
+				}
+			}
+			self.inverse_transform(&mut evals);
+
+			for (coeff, chunk_coeff) in iter::zip(&mut product[k * chunk_len..], evals) {
+				*coeff += chunk_coeff;
+			}
+		}
+		product
+	}
+
+	/// Converts monomial coefficients into evaluations on the span of $\beta_0, \ldots,
+	/// \beta_{k-1}$, where the length of the data is $2^k$.
+	fn forward_transform(&self, data: &mut [F]) {
+		let log_len = data.len().ilog2() as usize;
+		self.monomial_to_novel_basis(data);
+		self.ntt
+			.forward_transform(data, self.transform_shape(log_len), 0, self.coset_bits(log_len), 0)
+			.expect("the data length is within the domain size");
+	}
+
+	/// The inverse of [`Self::forward_transform`].
+	fn inverse_transform(&self, data: &mut [F]) {
+		let log_len = data.len().ilog2() as usize;
+		self.ntt
+			.inverse_transform(data, self.transform_shape(log_len), 0, self.coset_bits(log_len), 0)
+			.expect("the data length is within the domain size");
+		self.novel_basis_to_monomial(data);
+	}
+
+	const fn transform_shape(&self, log_len: usize) -> NTTShape {
+		NTTShape {
+			log_x: 0,
+			log_y: log_len,
+			log_z: 0,
+		}
+	}
+
+	/// The coset bits which select the span of the first basis elements of the domain as the
+	/// evaluation subspace, so that all transform sizes share the same novel polynomial basis.
+	fn coset_bits(&self, log_len: usize) -> usize {
+		self.subspace_polys.len() - log_len
+	}
+
+	/// Converts the coefficients of a polynomial of degree less than $2^k$ from the monomial basis
+	/// to the novel polynomial basis in place, where the length of the data is $2^k$.
+	///
+	/// The novel basis polynomial $X_j$ is the product of $\hat{W}_i(X)$ over the set bits $i$ of
+	/// $j$. Hence dividing a polynomial of degree less than $2^{i+1}$ by $\hat{W}_i$ separates the
+	/// coordinates with bit $i$ set, which are those of the quotient, from the rest, which are
+	/// those of the remainder. The division is done in place for every block of length $2^{i+1}$,
+	/// from the highest bit down, leaving the quotient in the upper half of the block.
+	fn monomial_to_novel_basis(&self, data: &mut [F]) {
+		let log_len = data.len().ilog2() as usize;
+		for (i, subspace_poly) in self.subspace_polys[..log_len].iter().enumerate().rev() {
+			for block in data.chunks_exact_mut(1 << (i + 1)) {
+				for k in (1 << i..1 << (i + 1)).rev() {
+					let coeff = block[k];
+					if coeff == F::ZERO {
+						continue;
+					}
+					// The leading coefficient of $\hat{W}_i$ is the inverse of the norm.
+					block[k] = coeff * subspace_poly.norm;
+					for (j, &linear_coeff) in subspace_poly.linear_coeffs.iter().enumerate() {
+						block[k - (1 << i) + (1 << j)] -= coeff * linear_coeff;
+					}
+				}
+			}
+		}
+	}
+
+	/// The inverse of [`Self::monomial_to_novel_basis`].
+	fn novel_basis_to_monomial(&self, data: &mut [F]) {
+		let log_len = data.len().ilog2() as usize;
+		for (i, subspace_poly) in self.subspace_polys[..log_len].iter().enumerate() {
+			for block in data.chunks_exact_mut(1 << (i + 1)) {
+				for k in 1 << i..1 << (i + 1) {
+					if block[k] == F::ZERO {
+						continue;
+					}
+					let coeff = block[k] * subspace_poly.norm_inv;
+					block[k] = coeff;
+					for (j, &linear_coeff) in subspace_poly.linear_coeffs.iter().enumerate() {
+						block[k - (1 << i) + (1 << j)] += coeff * linear_coeff;
+					}
+				}
+			}
+		}
+	}
+
+	/// Returns the inverse of a power series with nonzero constant term, truncated to `len`
+	/// coefficients.
+	fn inverse_series(&self, series: &[F], constant: F, len: usize) -> Vec<F> {
+		let mut inverse = vec![constant.invert().expect("constant term is nonzero")];
+		while inverse.len() < len {
+			// Newton iteration $g \mapsto 2g - f g^2$, which is $f g^2$ in characteristic 2,
+			// doubles the number of correct coefficients.
+			let next_len = (2 * inverse.len()).min(len);
+			let square = self.mul(&inverse, &inverse);
+			inverse = self.mul(&series[..next_len.min(series.len())], &square);
+			inverse.resize(next_len, F::ZERO);
+		}
+		inverse
+	}
+
+	/// Appends the evaluations at the points of the tree of a polynomial reduced modulo the
+	/// product of the parent node.
+	fn evaluate_subtree(&self, node: &SubproductTree<F>, coeffs: &[F], evals: &mut Vec<F>) {
+		let (_, remainder) = self
+			.div_rem(coeffs, &node.product)
+			.expect("subproduct is nonzero");
+		match &node.children {
+			Some(children) => {
+				for child in children.iter() {
+					self.evaluate_subtree(child, &remainder, evals);
+				}
+			}
+			None => evals.extend(
+				node.points
+					.iter()
+					.map(|&x| evaluate_univariate(&remainder, x)),
+			),
+		}
+	}
+
+	/// Returns the sum over the points $x_i$ of the tree of the weight $w_i$ times the product of
+	/// $X - x_j$ over the other points of the tree.
+	fn combine_subtree(&self, node: &SubproductTree<F>, weights: &[F]) -> Vec<F> {
+		match &node.children {
+			Some(children) => {
+				let [left, right] = &**children;
+				let (left_weights, right_weights) = weights.split_at(left.points.len());
+				let mut result =
+					self.mul(&self.combine_subtree(left, left_weights), &right.product);
+				let right_result =
+					self.mul(&self.combine_subtree(right, right_weights), &left.product);
+				result.resize(result.len().max(right_result.len()), F::ZERO);
+				for (coeff, right_coeff) in iter::zip(&mut result, right_result) {
+					*coeff += right_coeff;
+				}
+				result
+			}
+			None => {
+				let mut result = vec![F::ZERO; node.points.len()];
+				for (&x, &weight) in iter::zip(node.points, weights) {
+					for (coeff, quotient_coeff) in
+						iter::zip(&mut result, divide_by_linear(&node.product, x))
+					{
+						*coeff += weight * quotient_coeff;
+					}
+				}
+				result
+			}
+		}
+	}
+}
+
+/// A binary tree over a sequence of points in which every node holds the product of $X - x_i$
+/// over its points.
+struct SubproductTree<'a, F> {
+	points: &'a [F],
+	product: Vec<F>,
+	children: Option<Box<[Self; 2]>>,
+}
+
+impl<'a, F: BinaryField> SubproductTree<'a, F> {
+	fn new<NTT: AdditiveNTT<F>>(
+		arithmetic: &UnivariateArithmetic<F, NTT>,
+		points: &'a [F],
+	) -> Self {
+		if points.len() <= SUBPRODUCT_LEAF_SIZE {
+			let product = points
+				.iter()
+				.fold(vec![F::ONE], |product, &x| mul_by_linear(&product, x));
+			return Self {
+				points,
+				product,
+				children: None,
+			};
+		}
+
+		let (left, right) = points.split_at(points.len() / 2);
+		let children = Box::new([Self::new(arithmetic, left), Self::new(arithmetic, right)]);
+		let product = arithmetic.mul(&children[0].product, &children[1].product);
+		Self {
+			points,
+			product,
+			children: Some(children),
+		}
+	}
+}
+
+fn trim<F: BinaryField>(coeffs: &[F]) -> &[F] {
+	let len = coeffs
+		.iter()
+		.rposition(|&coeff| coeff != F::ZERO)
+		.map_or(0, |i| i + 1);
+	&coeffs[..len]
+}
+
+fn trim_vec<F: BinaryField>(mut coeffs: Vec<F>) -> Vec<F> {
+	let len = trim(&coeffs).len();
+	coeffs.truncate(len);
+	coeffs
+}
+
+fn mul_schoolbook<F: BinaryField>(a: &[F], b: &[F]) -> Vec<F> {
+	let mut product = vec![F::ZERO; a.len() + b.len() - 1];
+	for (i, &a_coeff) in a.iter().enumerate() {
+		for (coeff, &b_coeff) in iter::zip(&mut product[i..], b) {
+			*coeff += a_coeff * b_coeff;
+		}
+	}
+	product
+}
+
+/// Divides `a` by a nonzero `b` with at most as many coefficients.
+fn div_rem_schoolbook<F: BinaryField>(a: &[F], b: &[F]) -> (Vec<F>, Vec<F>) {
+	let lead_inv = b
+		.last()
+		.and_then(|lead| lead.invert())
+		.expect("divisor is nonzero");
+	let mut remainder = a.to_vec();
+	let mut quotient = vec![F::ZERO; a.len() - b.len() + 1];
+	for i in (0..quotient.len()).rev() {
+		let quotient_coeff = remainder[i + b.len() - 1] * lead_inv;
+		quotient[i] = quotient_coeff;
+		for (coeff, &b_coeff) in iter::zip(&mut remainder[i..], b) {
+			*coeff -= quotient_coeff * b_coeff;
+		}
+	}
+	remainder.truncate(b.len() - 1);
+	(quotient, trim_vec(remainder))
+}
+
+/// Multiplies a polynomial by $X - x$.
+fn mul_by_linear<F: BinaryField>(coeffs: &[F], x: F) -> Vec<F> {
+	iter::once(F::ZERO)
+		.chain(coeffs.iter().copied())
+		.zip(coeffs.iter().chain(iter::once(&F::ZERO)))
+		.map(|(shifted, &coeff)| shifted - x * coeff)
+		.collect()
+}
+
+/// Divides a polynomial by $X - x$, assuming that $x$ is a root.
+fn divide_by_linear<F: BinaryField>(coeffs: &[F], x: F) -> Vec<F> {
+	let mut quotient = vec![F::ZERO; coeffs.len() - 1];
+	let mut carry = F::ZERO;
+	for (quotient_coeff, &coeff) in iter::zip(&mut quotient, &coeffs[1..]).rev() {
+		carry = coeff + x * carry;
+		*quotient_coeff = carry;
+	}
+	quotient
+}
+
+/// Returns the formal derivative of a polynomial, in which the monomials of even degree vanish
+/// since the field has characteristic 2.
+fn formal_derivative<F: BinaryField>(coeffs: &[F]) -> Vec<F> {
+	let derivative = coeffs
+		.iter()
+		.enumerate()
+		.skip(1)
+		.map(|(i, &coeff)| if i % 2 == 1 { coeff } else { F::ZERO })
+		.collect();
+	trim_vec(derivative)
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField8b, BinaryField32b, BinaryField128b, Field};
+	use proptest::prelude::*;
+	use rand::{SeedableRng, rngs::StdRng};
+
+	use super::*;
+	use crate::SingleThreadedNTT;
+
+	type F = BinaryField32b;
+
+	fn arithmetic(log_domain_size: usize) -> UnivariateArithmetic<F, SingleThreadedNTT<F>> {
+		UnivariateArithmetic::new(SingleThreadedNTT::new(log_domain_size).unwrap())
+	}
+
+	fn random_poly<F: Field>(rng: &mut StdRng, len: usize) -> Vec<F> {
+		let mut coeffs = (0..len).map(|_| F::random(&mut *rng)).collect::<Vec<_>>();
+		if let Some(lead) = coeffs.last_mut() {
+			*lead = F::ONE;
+		}
+		coeffs
+	}
+
+	#[test]
+	fn test_forward_transform_evaluates_on_subspace() {
+		let arithmetic = arithmetic(8);
+		let subspace = arithmetic.ntt().subspace(8);
+		let mut rng = StdRng::seed_from_u64(0);
+		for log_len in 0..=8 {
+			let coeffs = random_poly::<F>(&mut rng, 1 << log_len);
+			let mut evals = coeffs.clone();
+			arithmetic.forward_transform(&mut evals);
+			for (i, &eval) in evals.iter().enumerate() {
+				assert_eq!(eval, evaluate_univariate(&coeffs, subspace.get(i)));
+			}
+
+			arithmetic.inverse_transform(&mut evals);
+			assert_eq!(evals, coeffs);
+		}
+	}
+
+	#[test]
+	fn test_mul_matches_schoolbook_across_chunking() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let a = random_poly::<F>(&mut rng, 300);
+		let b = random_poly::<F>(&mut rng, 170);
+		let expected = mul_schoolbook(&a, &b);
+		// The product fits in the domain of dimension 9 but is chunked with smaller domains.
+		for log_domain_size in [7, 8, 9, 10] {
+			assert_eq!(arithmetic(log_domain_size).mul(&a, &b), expected);
+		}
+	}
+
+	#[test]
+	fn test_mul_in_small_field_exceeding_domain() {
+		// The domain of an 8-bit field has at most 2^8 points, so larger products are chunked.
+		let ntt = SingleThreadedNTT::<BinaryField8b>::new(8).unwrap();
+		let arithmetic = UnivariateArithmetic::new(ntt);
+		let mut rng = StdRng::seed_from_u64(0);
+		let a = random_poly::<BinaryField8b>(&mut rng, 400);
+		let b = random_poly::<BinaryField8b>(&mut rng, 500);
+		assert_eq!(arithmetic.mul(&a, &b), mul_schoolbook(&a, &b));
+	}
+
+	#[test]
+	fn test_div_rem_by_zero() {
+		let result = arithmetic(4).div_rem(&[F::ONE], &[F::ZERO]);
+		assert!(matches!(result, Err(Error::DivisionByZero)));
+	}
+
+	#[test]
+	fn test_evaluate_and_interpolate_roundtrip() {
+		let arithmetic = arithmetic(10);
+		let mut rng = StdRng::seed_from_u64(0);
+		let points = (0..100).map(|_| F::random(&mut rng)).collect::<Vec<_>>();
+		let coeffs = random_poly::<F>(&mut rng, 100);
+
+		let evals = arithmetic.evaluate_many(&coeffs, &points);
+		for (&x, &eval) in iter::zip(&points, &evals) {
+			assert_eq!(eval, evaluate_univariate(&coeffs, x));
+		}
+		assert_eq!(arithmetic.interpolate(&points, &evals).unwrap(), coeffs);
+	}
+
+	#[test]
+	fn test_interpolate_duplicate_points() {
+		let arithmetic = arithmetic(8);
+		let mut rng = StdRng::seed_from_u64(0);
+		let mut points = (0..40).map(|_| F::random(&mut rng)).collect::<Vec<_>>();
+		points[30] = points[3];
+		let values = vec![F::ONE; points.len()];
+		assert!(matches!(
+			arithmetic.interpolate(&points, &values),
+			Err(Error::MathError(binius_math::Error::DuplicateDomainPoint))
+		));
+		assert!(matches!(
+			arithmetic.interpolate(&points, &values[1..]),
+			Err(Error::MathError(binius_math::Error::IncorrectArgumentLength { .. }))
+		));
+	}
+
+	#[test]
+	fn test_gcd_recovers_common_factor() {
+		let ntt = SingleThreadedNTT::<BinaryField128b>::new(10).unwrap();
+		let arithmetic = UnivariateArithmetic::new(ntt);
+		let mut rng = StdRng::seed_from_u64(0);
+		let common = random_poly::<BinaryField128b>(&mut rng, 60);
+		let a = arithmetic.mul(&common, &random_poly(&mut rng, 80));
+		let b = arithmetic.mul(&common, &random_poly(&mut rng, 50));
+
+		// Random factors over a large field are coprime with overwhelming probability.
+		assert_eq!(arithmetic.gcd(&a, &b), common);
+		assert_eq!(arithmetic.gcd(&a, &[]), trim_vec(a.clone()));
+		assert!(arithmetic.gcd(&[], &[]).is_empty());
+	}
+
+	proptest! {
+		#[test]
+		fn test_mul_vs_schoolbook(
+			seed: u64,
+			a_len in 0..200usize,
+			b_len in 0..200usize,
+		) {
+			let mut rng = StdRng::seed_from_u64(seed);
+			let a = random_poly::<F>(&mut rng, a_len);
+			let b = random_poly::<F>(&mut rng, b_len);
+			let expected = if a.is_empty() || b.is_empty() {
+				Vec::new()
+			} else {
+				mul_schoolbook(&a, &b)
+			};
+			prop_assert_eq!(arithmetic(9).mul(&a, &b), expected);
+		}
+
+		#[test]
+		fn test_div_rem_identity(
+			seed: u64,
+			a_len in 0..300usize,
+			b_len in 1..150usize,
+		) {
+			let arithmetic = arithmetic(10);
+			let mut rng = StdRng::seed_from_u64(seed);
+			let a = random_poly::<F>(&mut rng, a_len);
+			let b = random_poly::<F>(&mut rng, b_len);
+
+			let (quotient, remainder) = arithmetic.div_rem(&a, &b).unwrap();
+			prop_assert!(remainder.len() < b.len());
+			let mut reconstructed = arithmetic.mul(&quotient, &b);
+			reconstructed.resize(reconstructed.len().max(remainder.len()), F::ZERO);
+			for (coeff, &remainder_coeff) in iter::zip(&mut reconstructed, &remainder) {
+				*coeff += remainder_coeff;
+			}
+			prop_assert_eq!(trim_vec(reconstructed), a);
+		}
+	}
+}
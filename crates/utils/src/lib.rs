@@ -13,6 +13,8 @@ pub mod iter;
 pub mod mem;
 pub mod random_access_sequence;
 pub mod rayon;
+#[cfg(feature = "serde")]
+pub mod serde_encoding;
 pub mod serialization;
 pub mod sorting;
 pub mod sparse_index;
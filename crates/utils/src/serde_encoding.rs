@@ -0,0 +1,302 @@
+// Copyright 2025 Irreducible Inc.
+
+//! [`serde`] support for leaf values that implement [`SerializeBytes`] and [`DeserializeBytes`].
+//!
+//! Composite types derive their [`Serialize`] and [`Deserialize`] implementations, so that they
+//! are encoded as structured maps and sequences. Only leaf values, such as field elements, byte
+//! strings and type-erased polynomials, are encoded as bytes.
+//!
+//! A leaf is encoded as the bytes produced by [`SerializeBytes`] in
+//! [`SerializationMode::CanonicalTower`], so that the encoding does not depend on the field
+//! representations of the host. Values that represent integers, such as field elements, are
+//! encoded with the bytes in big-endian order instead, so that the encoding reads as the integer
+//! value. Human-readable formats, such as JSON, receive the bytes as a lowercase hex string.
+//! Binary formats, such as CBOR, receive them as a byte string.
+//!
+//! The functions can be used with `#[serde(with = "binius_utils::serde_encoding")]` on fields,
+//! and [`impl_serde_via_bytes`](crate::impl_serde_via_bytes) and
+//! [`impl_serde_via_big_endian_bytes`](crate::impl_serde_via_big_endian_bytes) implement
+//! [`Serialize`] and [`Deserialize`] for a type in terms of them.
+
+use std::fmt;
+
+pub use serde;
+#[cfg(doc)]
+use serde::{Deserialize, Serialize};
+use serde::{
+	Deserializer, Serializer,
+	de::{self, Visitor},
+};
+
+use crate::{DeserializeBytes, SerializationMode, SerializeBytes};
+
+/// Serializes `value` through its [`SerializeBytes`] encoding.
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+	T: SerializeBytes + ?Sized,
+	S: Serializer,
+{
+	let mut bytes = Vec::new();
+	value
+		.serialize(&mut bytes, SerializationMode::CanonicalTower)
+		.map_err(serde::ser::Error::custom)?;
+	serialize_bytes(&bytes, serializer)
+}
+
+/// Deserializes a value from its [`DeserializeBytes`] encoding.
+///
+/// Fails if the encoding has trailing bytes.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+	T: DeserializeBytes,
+	D: Deserializer<'de>,
+{
+	let bytes = deserialize_bytes(deserializer)?;
+	let mut read_buf = bytes.as_slice();
+	let value = T::deserialize(&mut read_buf, SerializationMode::CanonicalTower)
+		.map_err(de::Error::custom)?;
+	if !read_buf.is_empty() {
+		return Err(de::Error::invalid_length(bytes.len(), &"no trailing bytes"));
+	}
+	Ok(value)
+}
+
+/// Serializes `value` through its [`SerializeBytes`] encoding, with the bytes reversed to
+/// big-endian order.
+pub fn serialize_big_endian<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+	T: SerializeBytes + ?Sized,
+	S: Serializer,
+{
+	let mut bytes = Vec::new();
+	value
+		.serialize(&mut bytes, SerializationMode::CanonicalTower)
+		.map_err(serde::ser::Error::custom)?;
+	bytes.reverse();
+	serialize_bytes(&bytes, serializer)
+}
+
+/// Deserializes a value from its [`DeserializeBytes`] encoding with the bytes in big-endian
+/// order.
+///
+/// Fails if the encoding has leading bytes.
+pub fn deserialize_big_endian<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+	T: DeserializeBytes,
+	D: Deserializer<'de>,
+{
+	let mut bytes = deserialize_bytes(deserializer)?;
+	bytes.reverse();
+	let mut read_buf = bytes.as_slice();
+	let value = T::deserialize(&mut read_buf, SerializationMode::CanonicalTower)
+		.map_err(de::Error::custom)?;
+	if !read_buf.is_empty() {
+		return Err(de::Error::invalid_length(bytes.len(), &"no leading bytes"));
+	}
+	Ok(value)
+}
+
+/// Serializes a byte string, as hex for human-readable formats.
+pub fn serialize_bytes<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+	if serializer.is_human_readable() {
+		serializer.serialize_str(&encode_hex(bytes))
+	} else {
+		serializer.serialize_bytes(bytes)
+	}
+}
+
+/// Deserializes a byte string, as hex for human-readable formats.
+pub fn deserialize_bytes<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+	if deserializer.is_human_readable() {
+		deserializer.deserialize_str(BytesVisitor)
+	} else {
+		deserializer.deserialize_byte_buf(BytesVisitor)
+	}
+}
+
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+	type Value = Vec<u8>;
+
+	fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+		formatter.write_str("a byte string or a hex string")
+	}
+
+	fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+		decode_hex(v).ok_or_else(|| de::Error::invalid_value(de::Unexpected::Str(v), &self))
+	}
+
+	fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+		Ok(v.to_vec())
+	}
+
+	fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+		Ok(v)
+	}
+
+	fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+		let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+		while let Some(byte) = seq.next_element()? {
+			bytes.push(byte);
+		}
+		Ok(bytes)
+	}
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+	const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+	let mut hex = String::with_capacity(2 * bytes.len());
+	for &byte in bytes {
+		hex.push(DIGITS[(byte >> 4) as usize] as char);
+		hex.push(DIGITS[(byte & 0xf) as usize] as char);
+	}
+	hex
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+	let hex = hex.strip_prefix("0x").unwrap_or(hex).as_bytes();
+	if hex.len() % 2 != 0 {
+		return None;
+	}
+
+	let digit = |c: u8| (c as char).to_digit(16).map(|d| d as u8);
+	hex.chunks_exact(2)
+		.map(|pair| Some((digit(pair[0])? << 4) | digit(pair[1])?))
+		.collect()
+}
+
+/// Implements [`Serialize`] and [`Deserialize`] for a type through its [`SerializeBytes`] and
+/// [`DeserializeBytes`] implementations.
+///
+/// This is meant for leaf values with an opaque encoding. The generic parameters of the type are
+/// given in angle brackets before it. `Deserialize` is implemented for the instantiations that
+/// implement [`DeserializeBytes`].
+///
+/// ```ignore
+/// impl_serde_via_bytes!(<F: TowerField> TransparentPolyOracle<F>);
+/// ```
+#[macro_export]
+macro_rules! impl_serde_via_bytes {
+	(<$($param:ident $(: $bound:path)?),* $(,)?> $ty:ty) => {
+		$crate::impl_serde_via_bytes!(
+			@impl [$($param $(: $bound)?),*] $ty, serialize, deserialize
+		);
+	};
+	($ty:ty) => {
+		$crate::impl_serde_via_bytes!(@impl [] $ty, serialize, deserialize);
+	};
+	(@impl [$($generics:tt)*] $ty:ty, $serialize:ident, $deserialize:ident) => {
+		impl<$($generics)*> $crate::serde_encoding::serde::Serialize for $ty
+		where
+			$ty: $crate::SerializeBytes,
+		{
+			fn serialize<S: $crate::serde_encoding::serde::Serializer>(
+				&self,
+				serializer: S,
+			) -> Result<S::Ok, S::Error> {
+				$crate::serde_encoding::$serialize(self, serializer)
+			}
+		}
+
+		impl<'de, $($generics)*> $crate::serde_encoding::serde::Deserialize<'de> for $ty
+		where
+			$ty: $crate::DeserializeBytes,
+		{
+			fn deserialize<D: $crate::serde_encoding::serde::Deserializer<'de>>(
+				deserializer: D,
+			) -> Result<Self, D::Error> {
+				$crate::serde_encoding::$deserialize(deserializer)
+			}
+		}
+	};
+}
+
+/// Implements [`Serialize`] and [`Deserialize`] for a type through its [`SerializeBytes`] and
+/// [`DeserializeBytes`] implementations, with the bytes in big-endian order.
+///
+/// This is meant for leaf values that represent integers, such as field elements, whose
+/// [`SerializeBytes`] encoding is little-endian.
+///
+/// ```ignore
+/// impl_serde_via_big_endian_bytes!(BinaryField8b);
+/// ```
+#[macro_export]
+macro_rules! impl_serde_via_big_endian_bytes {
+	($ty:ty) => {
+		$crate::impl_serde_via_bytes!(
+			@impl [] $ty, serialize_big_endian, deserialize_big_endian
+		);
+	};
+}
+
+#[cfg(test)]
+mod tests {
+	use serde::{Deserialize, Serialize};
+
+	use super::*;
+
+	#[derive(Debug, PartialEq, Serialize, Deserialize)]
+	struct Wrapper {
+		#[serde(with = "crate::serde_encoding")]
+		value: Vec<u32>,
+	}
+
+	fn sample() -> Wrapper {
+		Wrapper {
+			value: vec![1, 0xdeadbeef],
+		}
+	}
+
+	#[test]
+	fn test_json_hex_encoding() {
+		let json = serde_json::to_string(&sample()).unwrap();
+		assert_eq!(json, r#"{"value":"0200000001000000efbeadde"}"#);
+		assert_eq!(serde_json::from_str::<Wrapper>(&json).unwrap(), sample());
+	}
+
+	#[derive(Debug, PartialEq, Serialize, Deserialize)]
+	struct BigEndianWrapper {
+		#[serde(
+			serialize_with = "serialize_big_endian",
+			deserialize_with = "deserialize_big_endian"
+		)]
+		value: u32,
+	}
+
+	#[test]
+	fn test_json_big_endian_hex_encoding() {
+		let wrapper = BigEndianWrapper { value: 0xdeadbeef };
+		let json = serde_json::to_string(&wrapper).unwrap();
+		assert_eq!(json, r#"{"value":"deadbeef"}"#);
+		assert_eq!(serde_json::from_str::<BigEndianWrapper>(&json).unwrap(), wrapper);
+		assert!(serde_json::from_str::<BigEndianWrapper>(r#"{"value":"00deadbeef"}"#).is_err());
+	}
+
+	#[test]
+	fn test_cbor_roundtrip() {
+		let mut cbor = Vec::new();
+		ciborium::into_writer(&sample(), &mut cbor).unwrap();
+		assert_eq!(ciborium::from_reader::<Wrapper, _>(cbor.as_slice()).unwrap(), sample());
+	}
+
+	#[test]
+	fn test_invalid_encodings() {
+		for json in [
+			r#"{"value":"020000000100000"}"#,
+			r#"{"value":"0200000001000000efbeadzz"}"#,
+			r#"{"value":"0200000001000000efbeadde00"}"#,
+			r#"{"value":"01000000"}"#,
+		] {
+			assert!(serde_json::from_str::<Wrapper>(json).is_err(), "{json}");
+		}
+	}
+
+	#[test]
+	fn test_hex() {
+		let bytes = (0..=255).collect::<Vec<u8>>();
+		assert_eq!(decode_hex(&encode_hex(&bytes)), Some(bytes));
+		assert_eq!(decode_hex("0xAbCd"), Some(vec![0xab, 0xcd]));
+	}
+}
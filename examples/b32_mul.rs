@@ -121,6 +121,7 @@ fn main() -> Result<()> {
 		&table_sizes,
 		witness,
 		&make_portable_backend(),
+		None,
 	)?;
 
 	println!("Proof size: {}", ByteSize::b(proof.get_proof_size() as u64));
@@ -131,7 +132,7 @@ fn main() -> Result<()> {
 		Groestl256,
 		Groestl256ByteCompression,
 		HasherChallenger<Groestl256>,
-	>(&ccs, args.log_inv_rate as usize, SECURITY_BITS, &cs_digest, &boundaries, proof)?;
+	>(&ccs, args.log_inv_rate as usize, SECURITY_BITS, &cs_digest, &boundaries, proof, None)?;
 
 	Ok(())
 }
@@ -0,0 +1,150 @@
+// Copyright 2025 Irreducible Inc.
+use anyhow::Result;
+use binius_compute::{ComputeHolder, cpu::alloc::CpuComputeAllocator};
+use binius_core::{constraint_system, fiat_shamir::HasherChallenger};
+use binius_fast_compute::layer::FastCpuLayerHolder;
+use binius_field::{
+	arch::OptimalUnderlier, as_packed_field::PackedType, tower::CanonicalTowerFamily,
+};
+use binius_hal::make_portable_backend;
+use binius_hash::groestl::{Groestl256, Groestl256ByteCompression, Groestl256Parallel};
+use binius_m3::{
+	builder::{B128, ConstraintSystem, WitnessIndex},
+	emulate::rv32im::{Instruction, Opcode, Program, execute},
+	gadgets::rv32im::Rv32im,
+};
+use binius_utils::{checked_arithmetics::log2_ceil_usize, rayon::adjust_thread_pool};
+use bytesize::ByteSize;
+use clap::{Parser, value_parser};
+use tracing_profile::init_tracing;
+
+#[derive(Debug, Parser)]
+struct Args {
+	/// The number of loop iterations executed by the program.
+	#[arg(short, long, default_value_t = 256, value_parser = value_parser!(u32).range(1..))]
+	n_iterations: u32,
+	/// The negative binary logarithm of the Reed–Solomon code rate.
+	#[arg(long, default_value_t = 1, value_parser = value_parser!(u32).range(1..))]
+	log_inv_rate: u32,
+}
+
+const CODE_BASE: u32 = 0x1000;
+const RAM_BASE: u32 = 0x10000;
+
+fn instr(opcode: Opcode, rd: u8, rs1: u8, rs2: u8, imm: i32) -> Instruction {
+	Instruction {
+		opcode,
+		rd,
+		rs1,
+		rs2,
+		imm: imm as u32,
+	}
+}
+
+/// A program mixing loads, stores, multiplication, division and branches.
+///
+/// Reads the iteration count `n` from RAM and computes `acc = (acc + i^2) ^ ((acc + i^2) % i)`
+/// for `i` in `1..=n`, storing every intermediate value to RAM. Exits with the final value.
+fn program(n_iterations: u32) -> Program {
+	Program::from_instructions(
+		CODE_BASE,
+		[
+			instr(Opcode::Lui, 7, 0, 0, RAM_BASE as i32),
+			instr(Opcode::Lw, 6, 7, 0, 0),
+			instr(Opcode::Addi, 10, 0, 0, 0),
+			instr(Opcode::Addi, 5, 0, 0, 1),
+			// loop:
+			instr(Opcode::Mul, 8, 5, 5, 0),
+			instr(Opcode::Add, 10, 10, 8, 0),
+			instr(Opcode::Remu, 9, 10, 5, 0),
+			instr(Opcode::Xor, 10, 10, 9, 0),
+			instr(Opcode::Sw, 0, 7, 10, 4),
+			instr(Opcode::Addi, 5, 5, 0, 1),
+			instr(Opcode::Bgeu, 0, 6, 5, -24),
+			instr(Opcode::Ecall, 0, 10, 0, 0),
+		],
+	)
+	.with_ram(RAM_BASE, vec![n_iterations, 0])
+}
+
+fn main() -> Result<()> {
+	const SECURITY_BITS: usize = 100;
+
+	adjust_thread_pool()
+		.as_ref()
+		.expect("failed to init thread pool");
+
+	let args = Args::parse();
+
+	let _guard = init_tracing().expect("failed to initialize tracing");
+
+	println!("Verifying an RV32IM execution of {} loop iterations", args.n_iterations);
+
+	let program = program(args.n_iterations);
+	let trace_gen_scope =
+		tracing::info_span!("Generating trace", n_iterations = args.n_iterations).entered();
+	let trace = execute(&program, 8 * args.n_iterations as usize + 16)?;
+	println!("Executed {} instructions", trace.events.len());
+
+	let mut cs = ConstraintSystem::new();
+	let rv32im = Rv32im::new(&mut cs, log2_ceil_usize(args.n_iterations as usize + 1));
+
+	let mut allocator = CpuComputeAllocator::new(
+		1 << (log2_ceil_usize(trace.events.len()) + 12
+			- PackedType::<OptimalUnderlier, B128>::LOG_WIDTH),
+	);
+	let allocator = allocator.into_bump_allocator();
+	let mut witness = WitnessIndex::<PackedType<OptimalUnderlier, B128>>::new(&cs, &allocator);
+	rv32im.fill(&mut witness, &program, &trace)?;
+	drop(trace_gen_scope);
+
+	let boundaries = rv32im.boundaries(&program, trace.exit_code)?;
+	let table_sizes = witness.table_sizes();
+	let ccs = cs.compile()?;
+	let cs_digest = ccs.digest::<Groestl256>();
+	let witness = witness.into_multilinear_extension_index();
+
+	let hal_span = tracing::info_span!("HAL Setup", perfetto_category = "phase.main").entered();
+
+	let mut compute_holder = FastCpuLayerHolder::<
+		CanonicalTowerFamily,
+		PackedType<OptimalUnderlier, B128>,
+	>::new(1 << 20, 1 << 28);
+
+	drop(hal_span);
+
+	let proof = constraint_system::prove::<
+		_,
+		OptimalUnderlier,
+		CanonicalTowerFamily,
+		Groestl256Parallel,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+		_,
+		_,
+		_,
+	>(
+		&mut compute_holder.to_data(),
+		&ccs,
+		args.log_inv_rate as usize,
+		SECURITY_BITS,
+		&cs_digest,
+		&boundaries,
+		&table_sizes,
+		witness,
+		&make_portable_backend(),
+		None,
+	)?;
+
+	println!("Proof size: {}", ByteSize::b(proof.get_proof_size() as u64));
+
+	constraint_system::verify::<
+		OptimalUnderlier,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+	>(&ccs, args.log_inv_rate as usize, SECURITY_BITS, &cs_digest, &boundaries, proof, None)?;
+
+	Ok(())
+}